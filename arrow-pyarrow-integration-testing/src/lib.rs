@@ -1,6 +1,7 @@
 //! This library demonstrates a minimal usage of Rust's C data interface to pass
 //! arrays from and to Python.
 
+use std::collections::HashMap;
 use std::error;
 use std::fmt;
 use std::sync::Arc;
@@ -10,7 +11,19 @@ use pyo3::ffi::Py_uintptr_t;
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 
-use arrow2::{array::Array, datatypes::Field, error::ArrowError, ffi};
+use arrow2::{
+    array::{
+        Array, DictionaryArray, Int32Array, MutableDictionaryArray, MutableUtf8Array, TryExtend,
+    },
+    datatypes::Field,
+    error::ArrowError,
+    ffi,
+    io::ipc::{
+        read::{read_file_metadata, FileReader},
+        write::FileWriter,
+    },
+    record_batch::RecordBatch,
+};
 
 /// an error that bridges ArrowError with a Python error
 #[derive(Debug)]
@@ -152,9 +165,85 @@ fn round_trip_field(array: PyObject, py: Python) -> PyResult<PyObject> {
     to_py_field(&field, py)
 }
 
+fn batch_with_dictionary(values: &[i32]) -> Result<RecordBatch, ArrowError> {
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary.try_extend(
+        values
+            .iter()
+            .map(|x| Some(if x % 2 == 0 { "even" } else { "odd" })),
+    )?;
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+
+    RecordBatch::try_from_iter(vec![
+        (
+            "a",
+            Arc::new(Int32Array::from_slice(values)) as Arc<dyn Array>,
+        ),
+        ("b", Arc::new(dictionary) as Arc<dyn Array>),
+    ])
+}
+
+/// Writes an Arrow file to `path` in two sessions: two record batches and then two more
+/// appended to it, with the values `1..=8` in column `a` and their parity in column `b`
+#[pyfunction]
+fn write_appended_ipc_file(path: String) -> PyResult<()> {
+    let batches = [[1, 2], [3, 4], [5, 6], [7, 8]]
+        .iter()
+        .map(|values| batch_with_dictionary(values))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(PyO3ArrowError::from)?;
+
+    let file = std::fs::File::create(&path)?;
+    let mut writer = FileWriter::try_new(file, batches[0].schema(), None, Default::default())
+        .map_err(PyO3ArrowError::from)?;
+    let mut metadata = HashMap::new();
+    metadata.insert("session".to_string(), "1".to_string());
+    writer.set_custom_metadata(metadata);
+    for batch in &batches[..2] {
+        writer.write(batch, None).map_err(PyO3ArrowError::from)?;
+    }
+    writer.finish().map_err(PyO3ArrowError::from)?;
+
+    let file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(&path)?;
+    let mut writer =
+        FileWriter::try_from_existing(file, Default::default()).map_err(PyO3ArrowError::from)?;
+    let mut metadata = HashMap::new();
+    metadata.insert("session".to_string(), "2".to_string());
+    writer.set_custom_metadata(metadata);
+    for batch in &batches[2..] {
+        writer.write(batch, None).map_err(PyO3ArrowError::from)?;
+    }
+    writer.finish().map_err(PyO3ArrowError::from)?;
+    Ok(())
+}
+
+/// Reads the Arrow file at `path`, returning the columns of each of its record batches
+#[pyfunction]
+fn read_ipc_file(path: String, py: Python) -> PyResult<Vec<Vec<PyObject>>> {
+    let mut file = std::fs::File::open(&path)?;
+    let metadata = read_file_metadata(&mut file).map_err(PyO3ArrowError::from)?;
+    let reader = FileReader::new(file, metadata, None);
+
+    reader
+        .map(|batch| {
+            let batch = batch.map_err(PyO3ArrowError::from)?;
+            batch
+                .columns()
+                .iter()
+                .map(|column| to_py_array(column.clone(), py))
+                .collect()
+        })
+        .collect()
+}
+
 #[pymodule]
 fn arrow_pyarrow_integration_testing(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(round_trip_array, m)?)?;
     m.add_function(wrap_pyfunction!(round_trip_field, m)?)?;
+    m.add_function(wrap_pyfunction!(write_appended_ipc_file, m)?)?;
+    m.add_function(wrap_pyfunction!(read_ipc_file, m)?)?;
     Ok(())
 }
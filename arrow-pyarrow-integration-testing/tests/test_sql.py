@@ -16,6 +16,8 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import os
+import tempfile
 import unittest
 import decimal
 
@@ -57,6 +59,17 @@ class TestCase(unittest.TestCase):
         assert a.to_pylist() == b.to_pylist()
         assert a.type == b.type
 
+    def test_float16(self):
+        import numpy
+
+        values = numpy.array([0.5, numpy.nan, -1.5, 65504.0, 1e-7], dtype=numpy.float16)
+        a = pyarrow.array(values, mask=numpy.array([False, False, True, False, False]))
+        b = arrow_pyarrow_integration_testing.round_trip_array(a)
+
+        b.validate(full=True)
+        assert a.type == b.type == pyarrow.float16()
+        assert a.equals(b)
+
     def test_primitive_sliced(self):
         a = pyarrow.array([0, None, 2, 3, 4]).slice(1, 2)
         b = arrow_pyarrow_integration_testing.round_trip_array(a)
@@ -308,6 +321,45 @@ class TestCase(unittest.TestCase):
         assert field == result
         assert field.metadata == result.metadata
 
+    def test_ipc_file_append(self):
+        """
+        Reads a file written in two sessions, the second appending to the first
+        """
+        with tempfile.TemporaryDirectory() as directory:
+            path = os.path.join(directory, "appended.arrow")
+            arrow_pyarrow_integration_testing.write_appended_ipc_file(path)
+
+            reader = pyarrow.ipc.open_file(path)
+            assert reader.num_record_batches == 4
+            table = reader.read_all()
+            values = list(range(1, 9))
+            assert table.column("a").to_pylist() == values
+            assert table.column("b").to_pylist() == [
+                "even" if x % 2 == 0 else "odd" for x in values
+            ]
+            del reader, table
+
+    def test_ipc_file_float16(self):
+        """
+        Reads a file with a halffloat column written by pyarrow
+        """
+        import numpy
+
+        values = numpy.array([0.5, -1.5, 65504.0, 1e-7, -0.0], dtype=numpy.float16)
+        a = pyarrow.array(values, mask=numpy.array([False, True, False, False, False]))
+        batch = pyarrow.record_batch([a], names=["a"])
+        with tempfile.TemporaryDirectory() as directory:
+            path = os.path.join(directory, "float16.arrow")
+            with pyarrow.ipc.new_file(path, batch.schema) as writer:
+                writer.write_batch(batch)
+
+            batches = arrow_pyarrow_integration_testing.read_ipc_file(path)
+            assert len(batches) == 1
+            (b,) = batches[0]
+            b.validate(full=True)
+            assert b.type == pyarrow.float16()
+            assert a.equals(b)
+
     # see https://issues.apache.org/jira/browse/ARROW-13855
     def _test_field_extension(self):
         field = pyarrow.field("aa", UuidType())
@@ -1,27 +1,43 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
 
 use arrow2::array::*;
-use arrow2::util::bench_util::*;
-use arrow2::{
-    compute::arithmetics::basic::add, compute::arithmetics::basic::div_scalar, types::NativeType,
+use arrow2::compute::arithmetics::basic::{
+    add, add_assign, div_scalar, mul_scalar, mul_scalar_assign, sub_scalar, sub_scalar_assign,
+    NativeArithmetics,
 };
+use arrow2::compute::arithmetics::decimal;
+use arrow2::util::bench_util::*;
 use num_traits::NumCast;
 use std::ops::{Add, Div};
 
 fn bench_div_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T)
 where
-    T: NativeType + Div<Output = T> + NumCast,
+    T: NativeArithmetics + Div<Output = T> + NumCast,
 {
     criterion::black_box(div_scalar(lhs, rhs));
 }
 
 fn bench_add<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>)
 where
-    T: NativeType + Add<Output = T> + NumCast,
+    T: NativeArithmetics + Add<Output = T> + NumCast,
 {
     criterion::black_box(add(lhs, rhs));
 }
 
+fn bench_chain(lhs: &PrimitiveArray<u64>, rhs: &PrimitiveArray<u64>) {
+    let result = add(lhs, rhs);
+    let result = mul_scalar(&result, &2);
+    criterion::black_box(sub_scalar(&result, &1));
+}
+
+fn bench_chain_assign(lhs: PrimitiveArray<u64>, rhs: &PrimitiveArray<u64>) {
+    let mut result = lhs.into_mut().right().unwrap();
+    add_assign(&mut result, rhs);
+    mul_scalar_assign(&mut result, &2);
+    sub_scalar_assign(&mut result, &1);
+    criterion::black_box(result);
+}
+
 fn add_benchmark(c: &mut Criterion) {
     (10..=20).step_by(2).for_each(|log2_size| {
         let size = 2usize.pow(log2_size);
@@ -40,6 +56,27 @@ fn add_benchmark(c: &mut Criterion) {
         c.bench_function(&format!("add 2^{}", log2_size), |b| {
             b.iter(|| bench_add(&arr_a, &arr_b))
         });
+
+        c.bench_function(&format!("add mul sub 2^{}", log2_size), |b| {
+            b.iter_batched(
+                || PrimitiveArray::from_slice(arr_a.values().as_slice()),
+                |lhs| bench_chain(&lhs, &arr_b),
+                BatchSize::LargeInput,
+            )
+        });
+        c.bench_function(&format!("add mul sub assign 2^{}", log2_size), |b| {
+            b.iter_batched(
+                || PrimitiveArray::from_slice(arr_a.values().as_slice()),
+                |lhs| bench_chain_assign(lhs, &arr_b),
+                BatchSize::LargeInput,
+            )
+        });
+
+        let dec_a = create_decimal_array(size, 18, 2, 0.1, 42);
+        let dec_b = create_decimal_array(size, 18, 2, 0.1, 43);
+        c.bench_function(&format!("saturating_add decimal 2^{}", log2_size), |b| {
+            b.iter(|| criterion::black_box(decimal::saturating_add(&dec_a, &dec_b)))
+        });
     });
 }
 
@@ -2,6 +2,7 @@ use std::iter::FromIterator;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
+use arrow2::array::MutableBooleanArray;
 use arrow2::bitmap::*;
 
 fn add_benchmark(c: &mut Criterion) {
@@ -13,7 +14,7 @@ fn add_benchmark(c: &mut Criterion) {
         c.bench_function(&format!("bitmap extend aligned 2^{}", log2_size), |b| {
             let mut bitmap1 = MutableBitmap::new();
             b.iter(|| {
-                bitmap1.extend_from_bitmap(&bitmap2);
+                bitmap1.extend_from_bitmap(&bitmap2, 0, size);
                 bitmap1.clear();
             })
         });
@@ -22,7 +23,7 @@ fn add_benchmark(c: &mut Criterion) {
             let mut bitmap1 = MutableBitmap::with_capacity(1);
             b.iter(|| {
                 bitmap1.push(true);
-                bitmap1.extend_from_bitmap(&bitmap2);
+                bitmap1.extend_from_bitmap(&bitmap2, 0, size);
                 bitmap1.clear();
             })
         });
@@ -69,6 +70,34 @@ fn add_benchmark(c: &mut Criterion) {
                 })
             },
         );
+
+        c.bench_function(
+            &format!("boolean extend_from_bitmap unaligned 2^{}", log2_size),
+            |b| {
+                let mut array = MutableBooleanArray::with_capacity(size + 1);
+                b.iter(|| {
+                    array.push(Some(true));
+                    array.extend_from_bitmap(&bitmap2, 1, size - 1);
+                    array = MutableBooleanArray::with_capacity(size + 1);
+                })
+            },
+        );
+
+        let sliced = bitmap2.clone().slice(1, size - 1);
+        c.bench_function(
+            &format!(
+                "boolean extend_trusted_len_values unaligned 2^{}",
+                log2_size
+            ),
+            |b| {
+                let mut array = MutableBooleanArray::with_capacity(size + 1);
+                b.iter(|| {
+                    array.push(Some(true));
+                    array.extend_trusted_len_values(sliced.iter());
+                    array = MutableBooleanArray::with_capacity(size + 1);
+                })
+            },
+        );
     });
 }
 
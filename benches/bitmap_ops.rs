@@ -11,7 +11,7 @@ fn add_benchmark(c: &mut Criterion) {
     (10..=20).step_by(2).for_each(|log2_size| {
         let size = 2usize.pow(log2_size);
 
-        let bitmap: Bitmap = (0..size).into_iter().map(|x| x % 3 == 0).collect();
+        let bitmap: Bitmap = (0..size).map(|x| x % 3 == 0).collect();
         c.bench_function(&format!("bitmap aligned not 2^{}", log2_size), |b| {
             b.iter(|| {
                 let r = !&bitmap;
@@ -53,7 +53,7 @@ fn add_benchmark(c: &mut Criterion) {
             })
         });
 
-        let bitmap1: Bitmap = (0..size).into_iter().map(|x| x % 4 == 0).collect();
+        let bitmap1: Bitmap = (0..size).map(|x| x % 4 == 0).collect();
         c.bench_function(&format!("bitmap aligned or 2^{}", log2_size), |b| {
             b.iter(|| bench_arrow2(&bitmap, &bitmap1))
         });
@@ -0,0 +1,43 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::{MutableUtf8Array, TryPush};
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=20).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+        let values = (0..size)
+            .map(|x| format!("{}", x % 1000))
+            .collect::<Vec<_>>();
+
+        c.bench_function(&format!("push 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let mut array = MutableUtf8Array::<i32>::new();
+                for x in values.iter() {
+                    array.try_push(Some(x)).unwrap();
+                }
+                array
+            })
+        });
+
+        c.bench_function(&format!("push_unchecked 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let mut array = MutableUtf8Array::<i32>::with_capacities(size, size * 3);
+                for x in values.iter() {
+                    unsafe { array.push_unchecked(Some(x)) };
+                }
+                array
+            })
+        });
+
+        c.bench_function(&format!("extend_trusted_len 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let mut array = MutableUtf8Array::<i32>::new();
+                array.extend_trusted_len(values.iter().map(Some));
+                array
+            })
+        });
+    })
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
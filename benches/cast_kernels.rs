@@ -91,6 +91,21 @@ fn add_benchmark(c: &mut Criterion) {
         .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
     let time_ms_array = create_primitive_array::<i64>(size, 0.1)
         .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    // timestamps between 1970 and 2050, so that they are representable as dates
+    let time_s_range_array = create_primitive_array_in_range::<i64>(
+        size,
+        DataType::Timestamp(TimeUnit::Second, None),
+        0..2524608000,
+        0.1,
+        42,
+    );
+    let time_s_tz_range_array = time_s_range_array.clone().to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+01:00".to_string()),
+    ));
+    let date32_range_array =
+        create_primitive_array_in_range::<i32>(size, DataType::Date32, 0..29220, 0.1, 42);
+    let bool_array = create_boolean_array(size, 0.1, 0.5);
     let utf8_date_array = build_utf8_date_array(512, true);
     let utf8_date_time_array = build_utf8_date_time_array(512, true);
 
@@ -162,6 +177,22 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| cast_array(&f32_array, DataType::Utf8))
     });
 
+    c.bench_function("cast timestamp_s to utf8 512", |b| {
+        b.iter(|| cast_array(&time_s_range_array, DataType::Utf8))
+    });
+    c.bench_function("cast timestamp_s with tz to utf8 512", |b| {
+        b.iter(|| cast_array(&time_s_tz_range_array, DataType::Utf8))
+    });
+    c.bench_function("cast f64 to string 512", |b| {
+        b.iter(|| cast_array(&f64_array, DataType::Utf8))
+    });
+    c.bench_function("cast date32 to string 512", |b| {
+        b.iter(|| cast_array(&date32_range_array, DataType::Utf8))
+    });
+    c.bench_function("cast bool to string 512", |b| {
+        b.iter(|| cast_array(&bool_array, DataType::Utf8))
+    });
+
     c.bench_function("cast timestamp_ms to i64 512", |b| {
         b.iter(|| cast_array(&time_ms_array, DataType::Int64))
     });
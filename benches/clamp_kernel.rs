@@ -0,0 +1,34 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::compute::clamp::clamp_primitive;
+use arrow2::compute::comparison::primitive::{gt_scalar, lt_scalar};
+use arrow2::compute::if_then_else::if_then_else;
+use arrow2::util::bench_util::create_primitive_array;
+
+// the same as `clamp_primitive`, via two comparisons and two zips
+fn clamp_via_comparisons(array: &Float32Array, min: f32, max: f32) -> Box<dyn Array> {
+    let min_array = Float32Array::from_vec(vec![min; array.len()]);
+    let max_array = Float32Array::from_vec(vec![max; array.len()]);
+    let lower = if_then_else(&lt_scalar(array, min), &min_array, array).unwrap();
+    let lower = lower.as_any().downcast_ref::<Float32Array>().unwrap();
+    if_then_else(&gt_scalar(lower, max), &max_array, lower).unwrap()
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=20).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+
+        let array = create_primitive_array::<f32>(size, 0.1);
+
+        c.bench_function(&format!("clamp 2^{} f32", log2_size), |b| {
+            b.iter(|| clamp_primitive(&array, Some(0.25), Some(0.75)))
+        });
+        c.bench_function(&format!("clamp via comparisons 2^{} f32", log2_size), |b| {
+            b.iter(|| clamp_via_comparisons(&array, 0.25, 0.75))
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -34,7 +34,7 @@ fn add_benchmark(c: &mut Criterion) {
             b.iter(|| eq(&arr_a, &arr_b))
         });
 
-        c.bench_function(&format!("utf8 2^{}", log2_size), |b| {
+        c.bench_function(&format!("utf8 scalar 2^{}", log2_size), |b| {
             b.iter(|| eq_scalar(&arr_a, &Utf8Scalar::<i32>::from(Some("abc"))))
         });
     })
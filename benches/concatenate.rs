@@ -1,8 +1,12 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use arrow2::{
+    array::Array,
     compute::concatenate::concatenate,
-    util::bench_util::{create_boolean_array, create_primitive_array},
+    util::bench_util::{
+        create_boolean_array, create_fixed_size_binary_array, create_primitive_array,
+        create_string_array,
+    },
 };
 
 fn add_benchmark(c: &mut Criterion) {
@@ -42,6 +46,25 @@ fn add_benchmark(c: &mut Criterion) {
                 let _ = concatenate(&[&array1, &array2]);
             })
         });
+
+        let array1 = create_fixed_size_binary_array(8, 16, 0.5, 42);
+        let array2 = create_fixed_size_binary_array(size + 1, 16, 0.5, 43);
+
+        c.bench_function(&format!("fixed size binary concat 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let _ = concatenate(&[&array1, &array2]);
+            })
+        });
+    });
+
+    let arrays = (0..1000)
+        .map(|seed| create_string_array::<i32>(1000, 4, 0.1, seed))
+        .collect::<Vec<_>>();
+    let arrays = arrays.iter().map(|x| x as &dyn Array).collect::<Vec<_>>();
+    c.bench_function("utf8 concat 1000 arrays of 1000 strings", |b| {
+        b.iter(|| {
+            let _ = concatenate(&arrays);
+        })
     });
 }
 
@@ -1,6 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use arrow2::bitmap::utils::count_zeros;
+use arrow2::bitmap::{utils::count_zeros, Bitmap};
 
 fn add_benchmark(c: &mut Criterion) {
     (10..=20).step_by(2).for_each(|log2_size| {
@@ -17,7 +17,27 @@ fn add_benchmark(c: &mut Criterion) {
         c.bench_function(&format!("count_zeros offset 2^{}", log2_size), |b| {
             b.iter(|| count_zeros(&bytes, 10, bytes.len() * 8 - 10))
         });
-    })
+    });
+
+    // 64M bits
+    let length = 2usize.pow(26);
+    let bitmap = (0..length).map(|x| x % 3 != 0).collect::<Bitmap>();
+    for offset in [1, 3, 7, 63] {
+        c.bench_function(&format!("bitmap count_zeros offset {} 2^26", offset), |b| {
+            b.iter(|| bitmap.count_zeros(offset, length - offset - 1))
+        });
+        c.bench_function(
+            &format!("bitmap slice null_count offset {} 2^26", offset),
+            |b| {
+                b.iter(|| {
+                    bitmap
+                        .clone()
+                        .slice(offset, length / 3 - offset)
+                        .null_count()
+                })
+            },
+        );
+    }
 }
 
 criterion_group!(benches, add_benchmark);
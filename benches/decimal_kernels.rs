@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::compute::arithmetics::decimal::{add, mul};
+use arrow2::compute::arity::binary;
+use arrow2::compute::cast::{cast, CastOptions};
+use arrow2::compute::comparison::{eq_scalar, lt_scalar};
+use arrow2::compute::sort::{sort_to_indices, SortOptions};
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+use arrow2::io::csv::write;
+use arrow2::record_batch::RecordBatch;
+use arrow2::scalar::PrimitiveScalar;
+use arrow2::util::bench_util::*;
+
+fn write_batch(batch: &RecordBatch) -> Result<()> {
+    let writer = &mut write::WriterBuilder::new().from_writer(vec![]);
+
+    let options = write::SerializeOptions::default();
+    write::write_batch(writer, batch, &options)
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    (16..=22).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+
+        // values of 18 digits declared with the maximum precision, so that neither their sum
+        // nor their product overflows it
+        let data_type = DataType::Decimal(38, 2);
+        let arr_a = create_decimal_array(size, 18, 2, 0.1, 42).to(data_type.clone());
+        let arr_b = create_decimal_array(size, 18, 2, 0.1, 43).to(data_type.clone());
+
+        c.bench_function(&format!("decimal add 2^{}", log2_size), |b| {
+            b.iter(|| criterion::black_box(add(&arr_a, &arr_b)))
+        });
+        c.bench_function(&format!("i128 add 2^{}", log2_size), |b| {
+            b.iter(|| {
+                criterion::black_box(binary(
+                    &arr_a,
+                    &arr_b,
+                    data_type.clone(),
+                    |a: i128, b: i128| a.wrapping_add(b),
+                ))
+            })
+        });
+        c.bench_function(&format!("decimal mul 2^{}", log2_size), |b| {
+            b.iter(|| criterion::black_box(mul(&arr_a, &arr_b)))
+        });
+
+        let scalar = PrimitiveScalar::<i128>::new(data_type.clone(), Some(1_00));
+        c.bench_function(&format!("decimal eq scalar 2^{}", log2_size), |b| {
+            b.iter(|| criterion::black_box(eq_scalar(&arr_a, &scalar)))
+        });
+        c.bench_function(&format!("decimal lt scalar 2^{}", log2_size), |b| {
+            b.iter(|| criterion::black_box(lt_scalar(&arr_a, &scalar)))
+        });
+
+        c.bench_function(&format!("decimal sort_to_indices 2^{}", log2_size), |b| {
+            b.iter(|| sort_to_indices::<i32>(&arr_a, &SortOptions::default(), None).unwrap())
+        });
+
+        let floats = cast(&arr_a, &DataType::Float64, CastOptions::default()).unwrap();
+        c.bench_function(&format!("decimal cast to f64 2^{}", log2_size), |b| {
+            b.iter(|| cast(&arr_a, &DataType::Float64, CastOptions::default()).unwrap())
+        });
+        c.bench_function(&format!("decimal cast from f64 2^{}", log2_size), |b| {
+            b.iter(|| cast(floats.as_ref(), &data_type, CastOptions::default()).unwrap())
+        });
+
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(arr_a.clone()) as Arc<dyn Array>)]).unwrap();
+        c.bench_function(&format!("decimal csv write 2^{}", log2_size), |b| {
+            b.iter(|| write_batch(&batch))
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,24 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::compute::dictionary::{decode, encode};
+use arrow2::datatypes::DataType;
+use arrow2::util::bench_util::*;
+
+fn add_benchmark(c: &mut Criterion) {
+    let size = 10_000_000;
+    let cardinality = 1000;
+
+    let dictionary = create_dictionary_array::<u16>(size, cardinality, 0.1, 42);
+    let array = decode(&dictionary).unwrap();
+
+    c.bench_function("encode utf8 10M rows 1k distinct", |b| {
+        b.iter(|| encode(array.as_ref(), &DataType::UInt16).unwrap())
+    });
+
+    c.bench_function("decode utf8 10M rows 1k distinct", |b| {
+        b.iter(|| decode(&dictionary).unwrap())
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,41 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::compute::find::{contains_scalar, starts_with_scalar, strpos_scalar};
+use arrow2::util::bench_util::create_string_array;
+
+// the same as `contains_scalar`, searching each value independently
+fn contains_per_value(array: &Utf8Array<i32>, needle: &str) -> BooleanArray {
+    array
+        .iter()
+        .map(|x| x.map(|x| x.contains(needle)))
+        .collect()
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=20).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+
+        let array = create_string_array::<i32>(size, 16, 0.1, 42);
+
+        for needle in ["a", "ab", "abcd"] {
+            c.bench_function(
+                &format!("contains_scalar 2^{} needle {}", log2_size, needle.len()),
+                |b| b.iter(|| contains_scalar(&array, needle)),
+            );
+            c.bench_function(
+                &format!("contains per value 2^{} needle {}", log2_size, needle.len()),
+                |b| b.iter(|| contains_per_value(&array, needle)),
+            );
+        }
+        c.bench_function(&format!("strpos_scalar 2^{}", log2_size), |b| {
+            b.iter(|| strpos_scalar(&array, "ab"))
+        });
+        c.bench_function(&format!("starts_with_scalar 2^{}", log2_size), |b| {
+            b.iter(|| starts_with_scalar(&array, "ab"))
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::datatypes::{DataType, Field};
+use arrow2::io::csv::read;
+
+/// Returns a CSV file of 10 columns (half `i64`, half `f64`) and `size` rows.
+fn write(size: usize) -> (Vec<u8>, Vec<Field>) {
+    let fields = (0..10)
+        .map(|i| {
+            let data_type = if i % 2 == 0 {
+                DataType::Int64
+            } else {
+                DataType::Float64
+            };
+            Field::new(format!("c{}", i), data_type, true)
+        })
+        .collect::<Vec<_>>();
+
+    let mut data = String::new();
+    for row in 0..size {
+        let row = (0..10)
+            .map(|i| {
+                if i % 2 == 0 {
+                    format!("{}", row * i)
+                } else {
+                    format!("{}.{}", row, i)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        data.push_str(&row);
+        data.push('\n');
+    }
+    (data.into_bytes(), fields)
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    // chunks are deserialized on rayon's global pool while read on the current thread
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build_global()
+        .unwrap();
+
+    (10..=16).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+        let (data, fields) = write(size);
+        let chunk_size = 1024;
+
+        let reader = || {
+            read::ReaderBuilder::new()
+                .has_headers(false)
+                .from_reader(data.as_slice())
+        };
+
+        c.bench_function(&format!("read csv 1 thread 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let length = read::deserialize_iter(reader(), chunk_size, &fields, None)
+                    .map(|columns| columns.unwrap().len())
+                    .sum::<usize>();
+                assert_eq!(length, size);
+            })
+        });
+
+        c.bench_function(&format!("read csv 4 threads 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let chunks = read::ChunkIterator::new(reader(), chunk_size);
+                let length = read::ParallelDeserializer::new(chunks, fields.clone(), None, 8)
+                    .map(|columns| columns.unwrap().len())
+                    .sum::<usize>();
+                assert_eq!(length, size);
+            })
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
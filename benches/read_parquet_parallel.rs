@@ -0,0 +1,95 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::compute::cast::{cast, CastOptions};
+use arrow2::datatypes::{DataType, Field, IntegerType, Schema};
+use arrow2::error::Result;
+use arrow2::io::parquet::{read, write::*};
+use arrow2::record_batch::RecordBatch;
+use arrow2::util::bench_util::create_primitive_array;
+
+/// Writes a file of 50 columns (half `i64`, half dictionary-encoded utf8) compressed with snappy.
+fn write(size: usize) -> Result<Vec<u8>> {
+    let values = (0..size)
+        .map(|i| Some(format!("value {}", i % 100)))
+        .collect::<Utf8Array<i32>>();
+    let dict_type = DataType::Dictionary(IntegerType::UInt32, Box::new(DataType::Utf8), false);
+
+    let (fields, columns): (Vec<_>, Vec<_>) = (0..50)
+        .map(|i| {
+            let array: Arc<dyn Array> = if i % 2 == 0 {
+                Arc::new(create_primitive_array::<i64>(size, 0.1))
+            } else {
+                cast(&values, &dict_type, CastOptions::default())
+                    .unwrap()
+                    .into()
+            };
+            (
+                Field::new(&format!("c{}", i), array.data_type().clone(), true),
+                array,
+            )
+        })
+        .unzip();
+    let encodings = fields
+        .iter()
+        .map(|field| match field.data_type() {
+            DataType::Dictionary(..) => Encoding::RleDictionary,
+            _ => Encoding::Plain,
+        })
+        .collect();
+    let schema = Schema::new(fields);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Snappy,
+        version: Version::V1,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let row_groups =
+        RowGroupIterator::try_new(vec![Ok(batch)].into_iter(), &schema, options, encodings)?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok(writer.into_inner())
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=16).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+        let buffer = write(size).unwrap();
+        let mut reader = Cursor::new(buffer);
+        let metadata = read::read_metadata(&mut reader).unwrap();
+
+        c.bench_function(&format!("read 50 columns serial 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let columns = read::read_columns(&mut reader, &metadata, 0, None).unwrap();
+                for column in columns {
+                    assert_eq!(column.deserialize().unwrap().len(), size);
+                }
+            })
+        });
+
+        c.bench_function(&format!("read 50 columns parallel 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let columns = read::read_columns(&mut reader, &metadata, 0, None).unwrap();
+                for array in read::deserialize_parallel(columns).unwrap() {
+                    assert_eq!(array.len(), size);
+                }
+            })
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
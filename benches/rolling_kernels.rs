@@ -0,0 +1,33 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::compute::window::*;
+use arrow2::util::bench_util::*;
+
+fn bench_rolling(arr_a: &PrimitiveArray<f64>, window: usize, agg: RollingAgg) {
+    rolling(criterion::black_box(arr_a), window, 1, agg).unwrap();
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    let size = 2usize.pow(20);
+    let arr_a = create_primitive_array::<f64>(size, 0.1);
+
+    [10, 10_000].iter().for_each(|&window| {
+        [
+            RollingAgg::Sum,
+            RollingAgg::Mean,
+            RollingAgg::Min,
+            RollingAgg::Max,
+        ]
+        .iter()
+        .for_each(|&agg| {
+            c.bench_function(
+                &format!("rolling {:?} 2^20 f64 window {}", agg, window),
+                |b| b.iter(|| bench_rolling(&arr_a, window, agg)),
+            );
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use criterion::{criterion_group, criterion_main, Criterion};
@@ -24,6 +26,42 @@ fn bench_take(values: &dyn Array, indices: &PrimitiveArray<i32>) {
     criterion::black_box(take::take(values, indices).unwrap());
 }
 
+fn bench_take_ranges(values: &dyn Array, ranges: &[(usize, usize)]) {
+    criterion::black_box(take::take_ranges(values, ranges).unwrap());
+}
+
+fn add_ranges_benchmark(c: &mut Criterion) {
+    // 1000 runs of 10k rows, in a random order
+    let runs = 1000;
+    let run_length = 10_000;
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut starts = (0..runs).map(|i| i * run_length).collect::<Vec<_>>();
+    for i in (1..starts.len()).rev() {
+        starts.swap(i, rng.gen_range(0..=i));
+    }
+    let ranges = starts
+        .iter()
+        .map(|start| (*start, run_length))
+        .collect::<Vec<_>>();
+    let indices = ranges
+        .iter()
+        .flat_map(|(start, length)| (*start as i32..(start + length) as i32).map(Some))
+        .collect::<PrimitiveArray<i32>>();
+
+    let size = runs * run_length;
+    for (name, values) in [
+        ("i32", create_primitive_array::<i32>(size, 0.0)),
+        ("i32 nulls", create_primitive_array::<i32>(size, 0.2)),
+    ] {
+        c.bench_function(&format!("take {} 1000 runs of 10k", name), |b| {
+            b.iter(|| bench_take(&values, &indices))
+        });
+        c.bench_function(&format!("take_ranges {} 1000 runs of 10k", name), |b| {
+            b.iter(|| bench_take_ranges(&values, &ranges))
+        });
+    }
+}
+
 fn add_benchmark(c: &mut Criterion) {
     (10..=20).step_by(2).for_each(|log2_size| {
         let size = 2usize.pow(log2_size);
@@ -71,6 +109,40 @@ fn add_benchmark(c: &mut Criterion) {
         c.bench_function(&format!("take str values nulls 2^{}", log2_size), |b| {
             b.iter(|| bench_take(&values_nulls, &indices))
         });
+
+        let values = create_binary_array::<i32>(size, 4, 0.2, 42);
+        c.bench_function(&format!("take binary values nulls 2^{}", log2_size), |b| {
+            b.iter(|| bench_take(&values, &indices))
+        });
+
+        let values = create_dictionary_array::<i32>(size, 64, 0.2, 42);
+        c.bench_function(&format!("take dictionary 2^{}", log2_size), |b| {
+            b.iter(|| bench_take(&values, &indices))
+        });
+
+        let values = create_list_array::<i32>(size, 0..8, 0.2, 42, &|length| {
+            Arc::new(create_primitive_array_with_seed::<i32>(length, 0.1, 43))
+        });
+        c.bench_function(&format!("take list 2^{}", log2_size), |b| {
+            b.iter(|| bench_take(&values, &indices))
+        });
+
+        let values = create_struct_array(
+            size,
+            0.2,
+            42,
+            &[
+                ("a", &|length| {
+                    Arc::new(create_primitive_array_with_seed::<i32>(length, 0.1, 43))
+                }),
+                ("b", &|length| {
+                    Arc::new(create_boolean_array_with_seed(length, 0.1, 0.5, 44))
+                }),
+            ],
+        );
+        c.bench_function(&format!("take struct 2^{}", log2_size), |b| {
+            b.iter(|| bench_take(&values, &indices))
+        });
     });
 
     let values = create_string_array::<i32>(512, 4, 0.0, 42);
@@ -99,5 +171,5 @@ fn add_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, add_benchmark);
+criterion_group!(benches, add_benchmark, add_ranges_benchmark);
 criterion_main!(benches);
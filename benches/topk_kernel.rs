@@ -0,0 +1,55 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::compute::sort::{sort_to_indices, SortOptions};
+use arrow2::compute::topk::topk;
+use arrow2::util::bench_util::*;
+
+// nulls last, as otherwise the first `k` slots are nulls and no value is compared
+const OPTIONS: SortOptions = SortOptions {
+    descending: false,
+    nulls_first: false,
+    nans_greatest: true,
+};
+
+fn bench_topk(array: &dyn Array, k: usize) {
+    criterion::black_box(topk(criterion::black_box(array), k, OPTIONS).unwrap());
+}
+
+fn bench_sort_limit(array: &dyn Array, k: usize) {
+    criterion::black_box(
+        sort_to_indices::<u32>(criterion::black_box(array), &OPTIONS, Some(k)).unwrap(),
+    );
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("topk");
+    // sorting 10M rows takes long; fewer samples keep the suite reasonably fast
+    group.sample_size(10);
+
+    let size = 10_000_000;
+    let k = 10;
+
+    let array = create_primitive_array::<i64>(size, 0.1);
+    group.bench_function("topk i64 10M k=10", |b| b.iter(|| bench_topk(&array, k)));
+    group.bench_function("sort limit i64 10M k=10", |b| {
+        b.iter(|| bench_sort_limit(&array, k))
+    });
+
+    let array = create_primitive_array::<f64>(size, 0.1);
+    group.bench_function("topk f64 10M k=10", |b| b.iter(|| bench_topk(&array, k)));
+    group.bench_function("sort limit f64 10M k=10", |b| {
+        b.iter(|| bench_sort_limit(&array, k))
+    });
+
+    let array = create_string_array::<i32>(size, 8, 0.1, 42);
+    group.bench_function("topk utf8 10M k=10", |b| b.iter(|| bench_topk(&array, k)));
+    group.bench_function("sort limit utf8 10M k=10", |b| {
+        b.iter(|| bench_sort_limit(&array, k))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use arrow2::array::*;
+use arrow2::compute::lower::lower;
+use arrow2::compute::upper::upper;
+use arrow2::util::bench_util::create_string_array;
+
+// an array where 10% of the values contain a multi-byte character
+fn create_multi_byte_array(length: usize, size: usize, seed: u64) -> Utf8Array<i32> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    create_string_array::<i32>(length, size, 0.1, seed)
+        .iter()
+        .map(|x| {
+            x.map(|x| {
+                if rng.gen::<f32>() < 0.1 {
+                    format!("{}é", x)
+                } else {
+                    x.to_string()
+                }
+            })
+        })
+        .collect()
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=20).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+
+        let ascii = create_string_array::<i32>(size, 16, 0.1, 42);
+        let multi_byte = create_multi_byte_array(size, 16, 42);
+
+        c.bench_function(&format!("lower ascii 2^{}", log2_size), |b| {
+            b.iter(|| lower(&ascii).unwrap())
+        });
+        c.bench_function(&format!("lower multi-byte 2^{}", log2_size), |b| {
+            b.iter(|| lower(&multi_byte).unwrap())
+        });
+        c.bench_function(&format!("upper ascii 2^{}", log2_size), |b| {
+            b.iter(|| upper(&ascii).unwrap())
+        });
+        c.bench_function(&format!("upper multi-byte 2^{}", log2_size), |b| {
+            b.iter(|| upper(&multi_byte).unwrap())
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::{BinaryArray, Utf8Array};
+use arrow2::datatypes::DataType;
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=20).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+
+        [("ascii", "aaa"), ("non-ascii", "aπa")]
+            .iter()
+            .for_each(|(name, value)| {
+                let array = Utf8Array::<i32>::from_trusted_len_values_iter(
+                    std::iter::repeat(value).take(size),
+                );
+                let offsets = array.offsets().clone();
+                let values = array.values().clone();
+
+                c.bench_function(&format!("utf8 try_new {} 2^{}", name, log2_size), |b| {
+                    b.iter(|| {
+                        Utf8Array::<i32>::try_new(
+                            DataType::Utf8,
+                            offsets.clone(),
+                            values.clone(),
+                            None,
+                        )
+                        .unwrap()
+                    })
+                });
+
+                c.bench_function(
+                    &format!("utf8 try_new_unchecked_utf8 {} 2^{}", name, log2_size),
+                    |b| {
+                        b.iter(|| unsafe {
+                            Utf8Array::<i32>::try_new_unchecked_utf8(
+                                DataType::Utf8,
+                                offsets.clone(),
+                                values.clone(),
+                                None,
+                            )
+                            .unwrap()
+                        })
+                    },
+                );
+
+                c.bench_function(
+                    &format!("utf8 from_data_unchecked {} 2^{}", name, log2_size),
+                    |b| {
+                        b.iter(|| unsafe {
+                            Utf8Array::<i32>::from_data_unchecked(
+                                DataType::Utf8,
+                                offsets.clone(),
+                                values.clone(),
+                                None,
+                            )
+                        })
+                    },
+                );
+
+                c.bench_function(&format!("binary try_new {} 2^{}", name, log2_size), |b| {
+                    b.iter(|| {
+                        BinaryArray::<i32>::try_new(
+                            DataType::Binary,
+                            offsets.clone(),
+                            values.clone(),
+                            None,
+                        )
+                        .unwrap()
+                    })
+                });
+            });
+    })
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
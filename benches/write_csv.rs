@@ -3,6 +3,7 @@ use std::sync::Arc;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use arrow2::array::*;
+use arrow2::compute::dictionary::decode;
 use arrow2::error::Result;
 use arrow2::io::csv::write;
 use arrow2::record_batch::RecordBatch;
@@ -39,12 +40,38 @@ fn add_benchmark(c: &mut Criterion) {
             b.iter(|| write_batch(&batch))
         });
 
+        // every value requires quoting and escaping
+        let array = Utf8Array::<i32>::from_iter_values(
+            (0..size).map(|x| format!("a \"quoted\", value {}", x)),
+        );
+        let batch = make_batch(array);
+
+        c.bench_function(
+            &format!("csv write utf8 with quotes 2^{}", log2_size),
+            |b| b.iter(|| write_batch(&batch)),
+        );
+
         let array = create_primitive_array::<f64>(size, 0.1);
         let batch = make_batch(array);
 
         c.bench_function(&format!("csv write f64 2^{}", log2_size), |b| {
             b.iter(|| write_batch(&batch))
         });
+
+        // a dictionary column and its equivalent flat column
+        let array = create_dictionary_array::<u16>(size, 100, 0.1, 42);
+        let flat = decode(&array).unwrap();
+        let batch = make_batch(array);
+
+        c.bench_function(&format!("csv write dictionary utf8 2^{}", log2_size), |b| {
+            b.iter(|| write_batch(&batch))
+        });
+
+        let batch = RecordBatch::try_from_iter([("a", Arc::from(flat) as Arc<dyn Array>)]).unwrap();
+
+        c.bench_function(&format!("csv write decoded utf8 2^{}", log2_size), |b| {
+            b.iter(|| write_batch(&batch))
+        });
     });
 }
 
@@ -1,74 +1,71 @@
-use crossbeam_channel::unbounded;
+use crossbeam_channel::bounded;
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::thread;
 use std::time::SystemTime;
 
-use arrow2::{error::Result, io::csv::read, record_batch::RecordBatch};
+use arrow2::array::Array;
+use arrow2::columns::Columns;
+use arrow2::{error::Result, io::csv::read};
 
-fn parallel_read(path: &str) -> Result<Vec<RecordBatch>> {
-    let batch_size = 100;
+fn parallel_read(path: &str) -> Result<Vec<Columns<Arc<dyn Array>>>> {
+    let chunk_size = 100;
     let has_header = true;
     let projection = None;
+    // the maximum number of chunks waiting to be deserialized
+    let max_in_flight = 8;
 
-    // prepare a channel to send serialized records from threads
-    let (tx, rx) = unbounded();
+    // a bounded channel stops the reader when consumers are slower than it
+    let (tx, rx) = bounded(max_in_flight);
+    let (results_tx, results_rx) = bounded(max_in_flight);
 
     let mut reader = read::ReaderBuilder::new().from_path(path)?;
-    let schema = read::infer_schema(&mut reader, Some(batch_size * 10), has_header, &read::infer)?;
-    let schema = Arc::new(schema);
+    let schema = read::infer_schema(&mut reader, Some(chunk_size * 10), has_header, &read::infer)?;
+    let fields = Arc::new(schema.fields);
 
     let start = SystemTime::now();
-    // spawn a thread to produce `Vec<ByteRecords>` (IO bounded)
-    let child = thread::spawn(move || {
-        let mut line_number = 0;
-        let mut size = 1;
-        while size > 0 {
-            let mut rows = vec![read::ByteRecord::default(); batch_size];
-            let rows_read = read::read_rows(&mut reader, 0, &mut rows).unwrap();
-            rows.truncate(rows_read);
-            line_number += rows.len();
-            size = rows.len();
-            tx.send((rows, line_number)).unwrap();
+    // spawn a thread to produce `RowsChunk`s (IO bounded)
+    let producer = thread::spawn(move || {
+        for (index, chunk) in read::ChunkIterator::new(reader, chunk_size).enumerate() {
+            tx.send((index, chunk)).unwrap();
         }
     });
 
-    let mut children = Vec::new();
-    // use 3 consumers of to decompress, decode and deserialize.
+    // use 3 consumers to deserialize (CPU bounded)
+    let mut consumers = Vec::new();
     for _ in 0..3 {
         let rx_consumer = rx.clone();
-        let consumer_schema = schema.clone();
-        let child = thread::spawn(move || {
-            let (rows, line_number) = rx_consumer.recv().unwrap();
-            let start = SystemTime::now();
-            println!("consumer start - {}", line_number);
-            let batch = read::deserialize_batch(
-                &rows,
-                consumer_schema.fields(),
-                projection,
-                0,
-                read::deserialize_column,
-            )
-            .unwrap();
-            println!(
-                "consumer end - {:?}: {}",
-                start.elapsed().unwrap(),
-                line_number,
-            );
-            batch
+        let tx_consumer = results_tx.clone();
+        let consumer_fields = fields.clone();
+        let consumer = thread::spawn(move || {
+            for (index, chunk) in rx_consumer {
+                let columns =
+                    chunk.and_then(|chunk| read::deserialize(&chunk, &consumer_fields, projection));
+                tx_consumer.send((index, columns)).unwrap();
+            }
         });
-        children.push(child);
+        consumers.push(consumer);
     }
+    drop(results_tx);
 
-    child.join().expect("child thread panicked");
+    // consumers finish out of order: restore the order of the file
+    let mut pending = HashMap::new();
+    let mut result = vec![];
+    for (index, columns) in results_rx {
+        pending.insert(index, columns);
+        while let Some(columns) = pending.remove(&result.len()) {
+            result.push(columns?);
+        }
+    }
 
-    let batches = children
-        .into_iter()
-        .map(|x| x.join().unwrap())
-        .collect::<Vec<_>>();
+    producer.join().expect("producer thread panicked");
+    for consumer in consumers {
+        consumer.join().expect("consumer thread panicked");
+    }
     println!("Finished - {:?}", start.elapsed().unwrap());
 
-    Ok(batches)
+    Ok(result)
 }
 
 fn main() -> Result<()> {
@@ -76,9 +73,9 @@ fn main() -> Result<()> {
     let args: Vec<String> = env::args().collect();
     let file_path = &args[1];
 
-    let batches = parallel_read(file_path)?;
-    for batch in batches {
-        println!("{}", batch.num_rows())
+    let chunks = parallel_read(file_path)?;
+    for columns in chunks {
+        println!("{}", columns.len())
     }
     Ok(())
 }
@@ -2,6 +2,7 @@ use std::fs::File;
 use std::sync::Arc;
 
 use arrow2::array::{Int32Array, Utf8Array};
+use arrow2::columns::Columns;
 use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::write;
@@ -10,7 +11,10 @@ use arrow2::record_batch::RecordBatch;
 fn write_batches(path: &str, schema: &Schema, batches: &[RecordBatch]) -> Result<()> {
     let file = File::create(path)?;
 
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions {
+        compression: None,
+        ..Default::default()
+    };
     let mut writer = write::FileWriter::try_new(file, schema, None, options)?;
 
     for batch in batches {
@@ -36,7 +40,13 @@ fn main() -> Result<()> {
 
     let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(a), Arc::new(b)])?;
 
-    // write it
-    write_batches(file_path, &schema, &[batch])?;
+    // split it in batches of at most 2 rows
+    let batches = Columns::from(batch.clone())
+        .chunks(2)
+        .map(|chunk| RecordBatch::try_new(batch.schema().clone(), chunk.into_arrays()))
+        .collect::<Result<Vec<_>>>()?;
+
+    // write them
+    write_batches(file_path, &schema, &batches)?;
     Ok(())
 }
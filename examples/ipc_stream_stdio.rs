@@ -0,0 +1,28 @@
+//! Copies an Arrow stream from stdin to stdout, e.g.
+//! `cat data.arrows | cargo run --example ipc_stream_stdio --features io_ipc > copy.arrows`
+use std::io::{stdin, stdout};
+
+use arrow2::error::Result;
+use arrow2::io::ipc::read::{read_stream_metadata, StreamReader, StreamState};
+use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+
+fn main() -> Result<()> {
+    let stdin = stdin();
+    let mut reader = stdin.lock();
+    let metadata = read_stream_metadata(&mut reader)?;
+    let schema = metadata.schema.clone();
+    let ipc_fields = metadata.ipc_schema.fields.clone();
+    let reader = StreamReader::new(reader, metadata);
+
+    let stdout = stdout();
+    let mut writer = StreamWriter::new(stdout.lock(), WriteOptions::default());
+    writer.start(&schema, &ipc_fields)?;
+    for state in reader {
+        match state? {
+            StreamState::Some(batch) => writer.write(&batch, &ipc_fields)?,
+            // stdin blocks until bytes are available, so it has none only once it is closed
+            StreamState::Waiting => break,
+        }
+    }
+    writer.finish()
+}
@@ -0,0 +1,23 @@
+use std::fs::File;
+
+use arrow2::error::Result;
+use arrow2::io::orc::read;
+
+fn main() -> Result<()> {
+    use std::env;
+    let args: Vec<String> = env::args().collect();
+
+    let path = &args[1];
+
+    let file = File::open(path)?;
+
+    // read the first column only; `None` reads all columns
+    let reader = read::Reader::try_new(file, Some(vec![0]))?;
+    println!("{:#?}", reader.schema());
+
+    // each item is the `Columns` of a stripe
+    for columns in reader {
+        println!("{:#?}", columns?.arrays());
+    }
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use std::fs::File;
+
+use arrow2::{
+    array::{Array, Int32Array, Int64Array, Utf8Array},
+    columns::Columns,
+    datatypes::{DataType, Field, Schema, TimeUnit},
+    error::Result,
+    io::orc::write::{write_file, Compression, WriteOptions},
+};
+
+fn main() -> Result<()> {
+    use std::env;
+    let args: Vec<String> = env::args().collect();
+
+    let path = &args[1];
+
+    let ints = Int32Array::from(&[Some(0), Some(1), None, Some(3), Some(4)]);
+    let strings = Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("d"), Some("e")]);
+    // timestamps with a timezone are written as instants
+    let timestamp = DataType::Timestamp(TimeUnit::Millisecond, Some("+00:00".to_string()));
+    let timestamps = Int64Array::from([
+        Some(0),
+        Some(1_420_070_401_500),
+        None,
+        Some(-1_500),
+        Some(1_000_000_000_123),
+    ])
+    .to(timestamp.clone());
+    let schema = Schema::new(vec![
+        Field::new("c1", DataType::Int32, true),
+        Field::new("c2", DataType::Utf8, true),
+        Field::new("c3", timestamp, true),
+    ]);
+
+    // each `Columns` is written as a stripe
+    let columns = Columns::new(vec![
+        Box::new(ints) as Box<dyn Array>,
+        Box::new(strings),
+        Box::new(timestamps),
+    ]);
+
+    let options = WriteOptions {
+        compression: Compression::Uncompressed,
+    };
+
+    let mut file = File::create(path)?;
+    write_file(&mut file, [Ok(columns)], &schema, options)?;
+
+    Ok(())
+}
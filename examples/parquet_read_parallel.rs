@@ -5,47 +5,28 @@ use std::time::SystemTime;
 
 use crossbeam_channel::unbounded;
 
-use arrow2::{
-    array::Array, error::Result, io::parquet::read, io::parquet::read::MutStreamingIterator,
-    record_batch::RecordBatch,
-};
+use arrow2::{array::Array, error::Result, io::parquet::read, record_batch::RecordBatch};
 
 fn parallel_read(path: &str, row_group: usize) -> Result<RecordBatch> {
-    // prepare a channel to send compressed pages across threads.
+    // prepare a channel to send columns' compressed pages across threads.
     let (tx, rx) = unbounded();
 
     let mut file = File::open(path)?;
     let file_metadata = read::read_metadata(&mut file)?;
     let arrow_schema = Arc::new(read::get_schema(&file_metadata)?);
+    let num_fields = arrow_schema.fields().len();
 
     let start = SystemTime::now();
-    // spawn a thread to produce `Vec<CompressedPage>` (IO bounded)
+    // spawn a thread to produce the compressed pages of each field (IO bounded)
     let producer = thread::spawn(move || {
-        for (field_i, field) in file_metadata.schema().fields().iter().enumerate() {
+        for field_i in 0..num_fields {
             let start = SystemTime::now();
-
-            let mut columns = read::get_column_iterator(
-                &mut file,
-                &file_metadata,
-                row_group,
-                field_i,
-                None,
-                vec![],
-            );
-
             println!("produce start - field: {}", field_i);
 
-            let mut column_chunks = vec![];
-            while let read::State::Some(mut new_iter) = columns.advance().unwrap() {
-                if let Some((pages, metadata)) = new_iter.get() {
-                    let pages = pages.collect::<Vec<_>>();
+            let mut columns =
+                read::read_columns(&mut file, &file_metadata, row_group, Some(&[field_i])).unwrap();
 
-                    column_chunks.push((pages, metadata.clone()));
-                }
-                columns = new_iter;
-            }
-            // todo: create API to allow sending each column (and not column chunks) to be processed in parallel
-            tx.send((field_i, field.clone(), column_chunks)).unwrap();
+            tx.send((field_i, columns.pop().unwrap())).unwrap();
             println!(
                 "produce end - {:?}: {} {}",
                 start.elapsed().unwrap(),
@@ -60,17 +41,13 @@ fn parallel_read(path: &str, row_group: usize) -> Result<RecordBatch> {
     let consumers = (0..2)
         .map(|i| {
             let rx_consumer = rx.clone();
-            let arrow_schema_consumer = arrow_schema.clone();
             thread::spawn(move || {
                 let mut arrays = vec![];
-                while let Ok((field_i, parquet_field, column_chunks)) = rx_consumer.recv() {
+                while let Ok((field_i, column)) = rx_consumer.recv() {
                     let start = SystemTime::now();
-                    let field = &arrow_schema_consumer.fields()[field_i];
                     println!("consumer {} start - {}", i, field_i);
 
-                    let columns = read::ReadColumnIterator::new(parquet_field, column_chunks);
-
-                    let array = read::column_iter_to_array(columns, field, vec![]).map(|x| x.0);
+                    let array = column.deserialize();
                     println!(
                         "consumer {} end - {:?}: {}",
                         i,
@@ -4,12 +4,7 @@ use std::io::BufReader;
 use std::sync::Arc;
 use std::time::SystemTime;
 
-use rayon::prelude::*;
-
-use arrow2::{
-    error::Result, io::parquet::read, io::parquet::read::MutStreamingIterator,
-    record_batch::RecordBatch,
-};
+use arrow2::{error::Result, io::parquet::read, record_batch::RecordBatch};
 
 fn parallel_read(path: &str, row_group: usize) -> Result<RecordBatch> {
     let mut file = BufReader::new(File::open(path)?);
@@ -17,52 +12,17 @@ fn parallel_read(path: &str, row_group: usize) -> Result<RecordBatch> {
     let arrow_schema = Arc::new(read::get_schema(&file_metadata)?);
 
     // IO-bounded
-    let columns = file_metadata
-        .schema()
-        .fields()
-        .iter()
-        .enumerate()
-        .map(|(field_i, field)| {
-            let start = SystemTime::now();
-            println!("read start - field: {}", field_i);
-            let mut columns = read::get_column_iterator(
-                &mut file,
-                &file_metadata,
-                row_group,
-                field_i,
-                None,
-                vec![],
-            );
-
-            let mut column_chunks = vec![];
-            while let read::State::Some(mut new_iter) = columns.advance().unwrap() {
-                if let Some((pages, metadata)) = new_iter.get() {
-                    let pages = pages.collect::<Vec<_>>();
-
-                    column_chunks.push((pages, metadata.clone()));
-                }
-                columns = new_iter;
-            }
-            println!(
-                "read end - {:?}: {} {}",
-                start.elapsed().unwrap(),
-                field_i,
-                row_group
-            );
-            (field_i, field.clone(), column_chunks)
-        })
-        .collect::<Vec<_>>();
+    let start = SystemTime::now();
+    let columns = read::read_columns(&mut file, &file_metadata, row_group, None)?;
+    println!("read - {:?}", start.elapsed().unwrap());
 
     // CPU-bounded
-    let columns = columns
-        .into_par_iter()
-        .map(|(field_i, parquet_field, column_chunks)| {
-            let columns = read::ReadColumnIterator::new(parquet_field, column_chunks);
-            let field = &arrow_schema.fields()[field_i];
-
-            read::column_iter_to_array(columns, field, vec![]).map(|x| x.0.into())
-        })
-        .collect::<Result<Vec<_>>>()?;
+    let start = SystemTime::now();
+    let columns = read::deserialize_parallel(columns)?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    println!("deserialize - {:?}", start.elapsed().unwrap());
 
     RecordBatch::try_new(arrow_schema, columns)
 }
@@ -0,0 +1,62 @@
+use std::fs::File;
+use std::sync::Arc;
+
+use arrow2::{
+    array::{Int64Array, Utf8Array},
+    datatypes::{DataType, Field, Schema},
+    error::Result,
+    io::parquet::write::{
+        write_file_with_bloom_filters, BloomFilterOptions, BloomFilterRowGroupIterator,
+        Compression, Encoding, Version, WriteOptions,
+    },
+    record_batch::RecordBatch,
+};
+
+fn main() -> Result<()> {
+    use std::env;
+    let args: Vec<String> = env::args().collect();
+
+    let path = &args[1];
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("name", DataType::Utf8, false),
+    ]));
+    // each batch is written as a row group, whose ids and names are in `[start, start + 1000)`
+    let batches = [0, 1000].iter().map(|start| {
+        let ids = Int64Array::from_values(*start..start + 1000);
+        let names =
+            Utf8Array::<i32>::from_iter_values((*start..start + 1000).map(|x| x.to_string()));
+        RecordBatch::try_new(schema.clone(), vec![Arc::new(ids), Arc::new(names)])
+    });
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let bloom_filter = BloomFilterOptions {
+        ndv: 1000,
+        fpp: 0.01,
+    };
+
+    let row_groups = BloomFilterRowGroupIterator::try_new(
+        batches,
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::Plain],
+        vec![Some(bloom_filter), Some(bloom_filter)],
+    )?;
+
+    let mut file = File::create(path)?;
+    let parquet_schema = row_groups.parquet_schema().clone();
+    let _ = write_file_with_bloom_filters(
+        &mut file,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use arrow2::array::{mutable_from_data_type, Array, MutableArray};
+use arrow2::columns::Columns;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::print;
+use arrow2::scalar::{BooleanScalar, ListScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+
+/// Converts rows of type-erased values into [`Columns`] with the fields of `schema`.
+/// A missing value at the end of a row is a null.
+fn rows_to_columns(
+    schema: &Schema,
+    rows: &[Vec<Box<dyn Scalar>>],
+) -> Result<Columns<Arc<dyn Array>>> {
+    // one mutable array per field, whatever its data type (errors for e.g. unions)
+    let mut columns = schema
+        .fields()
+        .iter()
+        .map(|field| mutable_from_data_type(field.data_type(), rows.len()))
+        .collect::<Result<Vec<_>>>()?;
+
+    for row in rows {
+        for (index, column) in columns.iter_mut().enumerate() {
+            match row.get(index) {
+                // errors if the value is not of the data type of the field
+                Some(value) => column.try_push_scalar(value.as_ref())?,
+                None => column.extend_nulls(1),
+            }
+        }
+    }
+
+    Columns::try_new(columns.iter_mut().map(|column| column.as_arc()).collect())
+}
+
+fn main() -> Result<()> {
+    let list_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+        Field::new("scores", list_type.clone(), true),
+        Field::new("active", DataType::Boolean, true),
+    ]);
+
+    let scores = |values: &[i32]| -> Box<dyn Scalar> {
+        let values = arrow2::array::Int32Array::from_slice(values);
+        Box::new(ListScalar::<i32>::new(
+            list_type.clone(),
+            Some(Arc::new(values)),
+        ))
+    };
+
+    // e.g. values from a row-oriented source
+    let rows: Vec<Vec<Box<dyn Scalar>>> = vec![
+        vec![
+            Box::new(PrimitiveScalar::new(DataType::Int64, Some(1i64))),
+            Box::new(Utf8Scalar::<i32>::new(Some("alice"))),
+            scores(&[10, 20]),
+            Box::new(BooleanScalar::new(Some(true))),
+        ],
+        vec![
+            Box::new(PrimitiveScalar::new(DataType::Int64, Some(2i64))),
+            Box::new(Utf8Scalar::<i32>::new(None::<&str>)),
+            scores(&[]),
+        ],
+        vec![
+            Box::new(PrimitiveScalar::new(DataType::Int64, Some(3i64))),
+            Box::new(Utf8Scalar::<i32>::new(Some("carol"))),
+            Box::new(ListScalar::<i32>::new(list_type.clone(), None)),
+            Box::new(BooleanScalar::new(Some(false))),
+        ],
+    ];
+
+    let columns = rows_to_columns(&schema, &rows)?;
+    println!("{}", print::write_with_schema(&schema, &[columns]));
+    Ok(())
+}
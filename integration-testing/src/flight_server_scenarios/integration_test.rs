@@ -109,7 +109,10 @@ impl FlightService for FlightServiceImpl {
             .get(&key)
             .ok_or_else(|| Status::not_found(format!("Could not find flight. {}", key)))?;
 
-        let options = ipc::write::WriteOptions { compression: None };
+        let options = ipc::write::WriteOptions {
+            compression: None,
+            ..Default::default()
+        };
 
         let schema = std::iter::once(Ok(serialize_schema(
             &flight.schema,
@@ -120,19 +123,21 @@ impl FlightService for FlightServiceImpl {
             .chunks
             .iter()
             .enumerate()
-            .flat_map(|(counter, batch)| {
+            .map(|(counter, batch)| {
                 let (dictionary_flight_data, mut batch_flight_data) =
-                    serialize_batch(batch, &flight.ipc_schema.fields, &options);
+                    serialize_batch(batch, &flight.ipc_schema.fields, &options)
+                        .map_err(|e| Status::internal(e.to_string()))?;
 
                 // Only the record batch's FlightData gets app_metadata
                 let metadata = counter.to_string().into_bytes();
                 batch_flight_data.app_metadata = metadata;
 
-                dictionary_flight_data
+                Ok(dictionary_flight_data
                     .into_iter()
-                    .chain(std::iter::once(batch_flight_data))
-                    .map(Ok)
-            });
+                    .chain(std::iter::once(batch_flight_data)))
+            })
+            .collect::<Result<Vec<_>, Status>>()?;
+        let batches = batches.into_iter().flatten().map(Ok);
 
         let output = futures::stream::iter(schema.chain(batches).collect::<Vec<_>>());
 
@@ -0,0 +1,32 @@
+import os
+
+import pyarrow as pa
+
+PYARROW_PATH = "fixtures/pyarrow3/ipc"
+
+
+def write_schema(schema: pa.Schema, path: str):
+    # `Schema.serialize` writes the schema message that starts an IPC stream
+    os.makedirs(PYARROW_PATH, exist_ok=True)
+    with open(f"{PYARROW_PATH}/{path}", "wb") as f:
+        f.write(schema.serialize().to_pybytes())
+
+
+write_schema(
+    pa.schema(
+        [
+            pa.field("a", pa.decimal128(10, 2)),
+            pa.field("b", pa.decimal128(38, 0)),
+        ]
+    ),
+    "decimal128.schema",
+)
+write_schema(
+    pa.schema(
+        [
+            pa.field("a", pa.decimal128(10, 2)),
+            pa.field("b", pa.decimal256(40, 2)),
+        ]
+    ),
+    "decimal256.schema",
+)
@@ -0,0 +1,31 @@
+import os
+
+import pyarrow as pa
+
+PYARROW_PATH = "fixtures/pyarrow3/ipc"
+
+
+def sparse() -> pa.UnionArray:
+    # [1, "a", None, 2]
+    types = pa.array([0, 1, 0, 0], type=pa.int8())
+    a = pa.array([1, None, None, 2], type=pa.int32())
+    b = pa.array([None, "a", None, None], type=pa.string())
+    return pa.UnionArray.from_sparse(types, [a, b], field_names=["a", "b"])
+
+
+def dense() -> pa.UnionArray:
+    # [1, "a", None, 2], with type ids different from the positions of the fields
+    types = pa.array([5, 7, 5, 5], type=pa.int8())
+    offsets = pa.array([0, 0, 1, 2], type=pa.int32())
+    a = pa.array([1, None, 2], type=pa.int32())
+    b = pa.array(["a"], type=pa.string())
+    return pa.UnionArray.from_dense(
+        types, offsets, [a, b], field_names=["a", "b"], type_codes=[5, 7]
+    )
+
+
+batch = pa.record_batch([sparse(), dense()], names=["sparse", "dense"])
+
+os.makedirs(PYARROW_PATH, exist_ok=True)
+with pa.ipc.new_file(f"{PYARROW_PATH}/union.arrow_file", batch.schema) as writer:
+    writer.write_batch(batch)
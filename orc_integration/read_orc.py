@@ -0,0 +1,37 @@
+# Reads the file written by `examples/orc_write.rs` with pyorc (the bindings of the C++ ORC
+# library), an independent reader, to check that arrow2 writes ORC files readers accept. CI runs:
+#
+#   cargo run --features io_orc --example orc_write -- fixtures/arrow2/example.orc
+#   python orc_integration/read_orc.py fixtures/arrow2/example.orc
+import sys
+from datetime import datetime, timedelta, timezone
+
+import pyorc
+
+EPOCH = datetime(1970, 1, 1, tzinfo=timezone.utc)
+
+
+def timestamp(ms: int) -> datetime:
+    return EPOCH + timedelta(milliseconds=ms)
+
+
+with open(sys.argv[1], "rb") as f:
+    # timestamps are read in UTC
+    reader = pyorc.Reader(f)
+
+    # timestamps with a timezone are instants
+    expected = "struct<c1:int,c2:string,c3:timestamp with local time zone>"
+    assert str(reader.schema) == expected, str(reader.schema)
+    assert len(reader) == 5
+
+    rows = list(reader)
+    assert [row[0] for row in rows] == [0, 1, None, 3, 4], rows
+    assert [row[1] for row in rows] == ["a", None, "c", "d", "e"], rows
+    expected = [
+        timestamp(0),
+        timestamp(1_420_070_401_500),
+        None,
+        timestamp(-1_500),
+        timestamp(1_000_000_000_123),
+    ]
+    assert [row[2] for row in rows] == expected, rows
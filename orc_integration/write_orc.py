@@ -0,0 +1,61 @@
+# Writes ORC files with pyorc (the bindings of the C++ ORC library) to `fixtures/pyorc`, that are
+# read by the tests of arrow2's ORC reader (`tests/it/io/orc/read.rs`). The files are written
+# with RLEv2 (the default of ORC 0.12), PRESENT streams (every column has nulls) and timestamps.
+import os
+from datetime import date, datetime, timedelta, timezone
+
+import pyorc
+
+PATH = "fixtures/pyorc"
+
+SCHEMA = (
+    "struct<bool:boolean,i8:tinyint,i16:smallint,i32:int,i64:bigint,f32:float,f64:double,"
+    "utf8:string,binary:binary,date:date,timestamp:timestamp>"
+)
+
+EPOCH = datetime(1970, 1, 1, tzinfo=timezone.utc)
+
+
+def wrap(value: int, bits: int) -> int:
+    # the value of the `bits` least significant bits of `value`, as a signed integer
+    value &= (1 << bits) - 1
+    return value - (1 << bits) if value >= 1 << (bits - 1) else value
+
+
+def row(x: int):
+    if x % 7 == 0:
+        return (None,) * 11
+    value = x * x
+    return (
+        value % 3 == 0,
+        wrap(value, 8),
+        wrap(value, 16),
+        -value,
+        value << 40,
+        value / 4,
+        value / 4,
+        f"value {value % 100}",
+        f"value {value}".encode(),
+        date(1970, 1, 1) + timedelta(days=value - 20000),
+        EPOCH + timedelta(microseconds=value * 1_000_001 - 10 ** 12),
+    )
+
+
+def write(path: str, compression: pyorc.CompressionKind, dictionary: bool):
+    os.makedirs(PATH, exist_ok=True)
+    with open(f"{PATH}/{path}", "wb") as f:
+        with pyorc.Writer(
+            f,
+            SCHEMA,
+            compression=compression,
+            # 1.0 writes every string column with a dictionary, 0.0 none
+            dict_key_size_threshold=1.0 if dictionary else 0.0,
+        ) as writer:
+            for x in range(1000):
+                writer.write(row(x))
+
+
+write("uncompressed.orc", pyorc.CompressionKind.NONE, False)
+write("zlib.orc", pyorc.CompressionKind.ZLIB, False)
+write("snappy.orc", pyorc.CompressionKind.SNAPPY, False)
+write("dictionary.orc", pyorc.CompressionKind.ZLIB, True)
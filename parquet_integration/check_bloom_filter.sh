@@ -0,0 +1,31 @@
+#!/bin/sh
+# Checks that the bloom filters written by arrow2 are read by independent readers:
+# the data by pyarrow and the bloom filters by parquet-mr's `parquet` command line
+# (parquet-cli >= 1.12), which must be on the path.
+set -e
+
+FILE=fixtures/arrow2/bloom_filter.parquet
+mkdir -p fixtures/arrow2
+cargo run --features io_parquet,io_parquet_compression --example parquet_write_bloom_filter -- $FILE
+
+# the file is otherwise unchanged
+python3 -c "
+import pyarrow.parquet as pq
+
+table = pq.read_table('$FILE')
+assert table.column('id').to_pylist() == list(range(2000))
+assert table.column('name').to_pylist() == [str(x) for x in range(2000)]
+assert pq.ParquetFile('$FILE').num_row_groups == 2
+"
+
+# each row group has ids and names in `[start, start + 1000)`
+OUTPUT=$(parquet bloom-filter -c id -v 500,1500 $FILE)
+echo "$OUTPUT"
+echo "$OUTPUT" | grep -q "value 500 maybe exists"
+echo "$OUTPUT" | grep -q "value 500 NOT exists"
+echo "$OUTPUT" | grep -q "value 1500 maybe exists"
+echo "$OUTPUT" | grep -q "value 1500 NOT exists"
+
+OUTPUT=$(parquet bloom-filter -c name -v a $FILE)
+echo "$OUTPUT"
+test "$(echo "$OUTPUT" | grep -c "value a NOT exists")" -eq 2
@@ -0,0 +1,43 @@
+# Writes a parquet file with bloom filters with Spark (parquet-mr) to
+# `fixtures/bloom_filter/spark.parquet`, that is read by the test `golden_bloom_filter` of
+# `tests/it/io/parquet/bloom_filter.rs`. Writing bloom filters requires parquet-mr >= 1.12,
+# i.e. Spark >= 3.2.
+import glob
+import os
+import shutil
+
+from pyspark.sql import SparkSession
+from pyspark.sql.types import LongType, StringType, StructField, StructType
+
+PATH = "fixtures/bloom_filter"
+
+spark = SparkSession.builder.master("local[1]").getOrCreate()
+
+schema = StructType(
+    [
+        StructField("id", LongType(), True),
+        StructField("name", StringType(), False),
+    ]
+)
+# ids and names in `[0, 1000)`, with every tenth id null
+rows = [(None if x % 10 == 0 else x, str(x)) for x in range(1000)]
+
+directory = os.path.join(PATH, "spark")
+(
+    spark.createDataFrame(rows, schema)
+    .coalesce(1)
+    .write.mode("overwrite")
+    # pages that are not dictionary-encoded, so that only the bloom filters can prune values
+    .option("parquet.enable.dictionary", "false")
+    .option("parquet.bloom.filter.enabled#id", "true")
+    .option("parquet.bloom.filter.expected.ndv#id", "1000")
+    .option("parquet.bloom.filter.enabled#name", "true")
+    .option("parquet.bloom.filter.expected.ndv#name", "1000")
+    .parquet(directory)
+)
+spark.stop()
+
+# Spark writes a directory of files; keep its single data file
+(part,) = glob.glob(os.path.join(directory, "part-*.parquet"))
+shutil.move(part, os.path.join(PATH, "spark.parquet"))
+shutil.rmtree(directory)
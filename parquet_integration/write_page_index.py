@@ -0,0 +1,37 @@
+# Writes a parquet file with a page index with Arrow C++ to `fixtures/page_index/pyarrow.parquet`,
+# that is read by the test `golden_page_index` of `tests/it/io/parquet/file.rs`.
+# Writing the page index requires pyarrow>=13, while the other fixtures are written with pyarrow 3.
+import os
+
+import pyarrow as pa
+import pyarrow.parquet
+
+PATH = "fixtures/page_index"
+
+int64 = [1, 2, 3, None, None, None, 7, 8, 9]
+string = ["a", "b", "c", "d", "e", "f", "g", "h", "i"]
+table = pa.table(
+    {
+        "int64": pa.array(int64, pa.int64()),
+        "string": pa.array(string, pa.utf8()),
+    }
+)
+
+os.makedirs(PATH, exist_ok=True)
+# one data page per batch of 3 rows
+pa.parquet.write_table(
+    table,
+    f"{PATH}/pyarrow.parquet",
+    use_dictionary=False,
+    compression="snappy",
+    data_page_size=1,
+    write_batch_size=3,
+    write_statistics=True,
+    write_page_index=True,
+)
+
+# the page index is written and can be read back
+metadata = pa.parquet.ParquetFile(f"{PATH}/pyarrow.parquet").metadata
+for i in range(metadata.num_columns):
+    column = metadata.row_group(0).column(i)
+    assert column.has_column_index and column.has_offset_index
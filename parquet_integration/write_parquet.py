@@ -229,6 +229,93 @@ def case_struct(size):
     )
 
 
+def case_map(size):
+    items = [
+        [("a", 1), ("b", None)],
+        None,
+        [],
+        [("c", 3)],
+        [("a", 4)],
+        None,
+        [("d", None)],
+        [],
+        [("e", 5), ("f", 6)],
+        [("g", 7)],
+    ]
+    schema = pa.schema([pa.field("map", pa.map_(pa.utf8(), pa.int64()))])
+    return (
+        {"map": items * size},
+        schema,
+        f"map_nullable_{size*10}.parquet",
+    )
+
+
+def case_dict(size):
+    int64 = [0, 1, None, 3, None, 1, 0, None, 3, 3]
+    float64 = [0.0, 1.0, None, 3.0, None, 1.0, 0.0, None, 3.0, 3.0]
+    string = ["Hello", None, "aa", "", None, "aa", None, None, "Hello", "aa"]
+    fields = [
+        pa.field("int64", pa.dictionary(pa.int32(), pa.int64())),
+        pa.field("float64", pa.dictionary(pa.int32(), pa.float64())),
+        pa.field("string", pa.dictionary(pa.int32(), pa.utf8())),
+    ]
+    schema = pa.schema(fields)
+    return (
+        {
+            "int64": pa.array(int64 * size).dictionary_encode(),
+            "float64": pa.array(float64 * size).dictionary_encode(),
+            "string": pa.array(string * size).dictionary_encode(),
+        },
+        schema,
+        f"dict_nullable_{size*10}.parquet",
+    )
+
+
+# the encodings of the columns of `case_encoded`
+ENCODINGS = {
+    "int64": "DELTA_BINARY_PACKED",
+    "float64": "BYTE_STREAM_SPLIT",
+    "string": "DELTA_BYTE_ARRAY",
+    "string_delta_length": "DELTA_LENGTH_BYTE_ARRAY",
+}
+
+
+def case_encoded(size, required):
+    case = case_basic_required if required else case_basic_nullable
+    data, schema, _ = case(size)
+    fields = [
+        schema.field("int64"),
+        schema.field("float64"),
+        schema.field("string"),
+        schema.field("string").with_name("string_delta_length"),
+    ]
+    data = {
+        "int64": data["int64"],
+        "float64": data["float64"],
+        "string": data["string"],
+        "string_delta_length": data["string"],
+    }
+    required = "required" if required else "nullable"
+    return data, pa.schema(fields), f"encoded_{required}_{size*10}.parquet"
+
+
+def write_encoded(required: bool, page_version: int):
+    # writing DELTA_BYTE_ARRAY requires pyarrow >= 13
+    data, schema, path = case_encoded(1, required)
+
+    base_path = f"{PYARROW_PATH}/v{page_version}"
+    t = pa.table(data, schema=schema)
+    os.makedirs(base_path, exist_ok=True)
+    pa.parquet.write_table(
+        t,
+        f"{base_path}/{path}",
+        use_dictionary=False,
+        column_encoding=ENCODINGS,
+        write_statistics=True,
+        data_page_version=f"{page_version}.0",
+    )
+
+
 def write_pyarrow(
     case,
     size: int,
@@ -268,12 +355,23 @@ def write_pyarrow(
     )
 
 
-for case in [case_basic_nullable, case_basic_required, case_nested, case_struct]:
+for case in [
+    case_basic_nullable,
+    case_basic_required,
+    case_nested,
+    case_struct,
+    case_map,
+    case_dict,
+]:
     for version in [1, 2]:
         for use_dict in [True, False]:
             for compression in ["lz4", None, "snappy"]:
                 write_pyarrow(case, 1, version, use_dict, False, compression)
 
+for version in [1, 2]:
+    for required in [True, False]:
+        write_encoded(required, version)
+
 
 def case_benches(size):
     assert size % 8 == 0
@@ -0,0 +1,44 @@
+# Writes a parquet file with Spark to `fixtures/spark/int96.parquet`, that is read by the test
+# `golden_spark_int96` of `tests/it/io/parquet/read.rs`. Like the versions of Spark before 3.0,
+# timestamps are written as INT96 without a logical or converted type, in the legacy format
+# and calendar, and without an arrow schema in the file's metadata.
+import glob
+import os
+import shutil
+from datetime import datetime, timezone
+
+from pyspark.sql import SparkSession
+from pyspark.sql.types import LongType, StructField, StructType, TimestampType
+
+PATH = "fixtures/spark"
+
+spark = (
+    SparkSession.builder.master("local[1]")
+    .config("spark.sql.session.timeZone", "UTC")
+    .config("spark.sql.parquet.outputTimestampType", "INT96")
+    .config("spark.sql.parquet.writeLegacyFormat", "true")
+    .config("spark.sql.legacy.parquet.int96RebaseModeInWrite", "LEGACY")
+    .getOrCreate()
+)
+
+schema = StructType(
+    [
+        StructField("id", LongType(), False),
+        StructField("timestamp", TimestampType(), True),
+    ]
+)
+rows = [
+    (0, datetime(1970, 1, 1, tzinfo=timezone.utc)),
+    (1, None),
+    (2, datetime(2020, 9, 13, 12, 26, 40, 123456, tzinfo=timezone.utc)),
+    (3, datetime(1950, 6, 15, 12, 30, 0, 500000, tzinfo=timezone.utc)),
+]
+
+directory = os.path.join(PATH, "int96")
+spark.createDataFrame(rows, schema).coalesce(1).write.mode("overwrite").parquet(directory)
+spark.stop()
+
+# Spark writes a directory of files; keep its single data file
+(part,) = glob.glob(os.path.join(directory, "part-*.parquet"))
+shutil.move(part, os.path.join(PATH, "int96.parquet"))
+shutil.rmtree(directory)
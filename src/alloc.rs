@@ -0,0 +1,44 @@
+//! Contains [`total_allocated_bytes`], a global counter of the memory owned by the buffers
+//! and bitmaps of this crate, used to check that operations release what they allocate.
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+// If this number is not zero after all buffers have been dropped, there is a memory leak
+static ALLOCATIONS: AtomicIsize = AtomicIsize::new(0);
+
+/// Returns the total number of bytes currently allocated to [`Buffer`](crate::buffer::Buffer)s
+/// and [`Bitmap`](crate::bitmap::Bitmap)s (and thus to arrays) of this crate.
+///
+/// A region is counted by its allocated capacity from the moment a buffer or bitmap takes
+/// ownership of it until it is released, either by being dropped or by being converted back
+/// into a mutable container (e.g. [`Buffer::into_mut`](crate::buffer::Buffer::into_mut)).
+/// Regions shared by clones and slices are counted once. Regions owned by mutable containers
+/// (e.g. [`Vec`] or [`MutableBitmap`](crate::bitmap::MutableBitmap)) and regions imported via
+/// FFI are not counted.
+///
+/// The counter is global to the process: measurements are only meaningful when no other
+/// thread is allocating or releasing buffers at the same time. Counting has a (small) cost on
+/// every allocation and release, which is why it requires the feature `alloc_tracking`.
+/// # Example
+/// ```
+/// use arrow2::alloc::total_allocated_bytes;
+/// use arrow2::array::Int32Array;
+///
+/// let before = total_allocated_bytes();
+/// let array = Int32Array::from_vec(Vec::with_capacity(10));
+/// assert_eq!(total_allocated_bytes() - before, 40);
+/// drop(array);
+/// assert_eq!(total_allocated_bytes(), before);
+/// ```
+pub fn total_allocated_bytes() -> isize {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+#[inline]
+pub(crate) fn track_allocation(bytes: usize) {
+    ALLOCATIONS.fetch_add(bytes as isize, Ordering::Relaxed);
+}
+
+#[inline]
+pub(crate) fn track_deallocation(bytes: usize) {
+    ALLOCATIONS.fetch_sub(bytes as isize, Ordering::Relaxed);
+}
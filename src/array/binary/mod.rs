@@ -1,8 +1,14 @@
-use crate::{bitmap::Bitmap, buffer::Buffer, datatypes::DataType};
+use crate::{
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
 
 use super::{
-    display_fmt, display_helper,
-    specification::{check_offsets, check_offsets_minimal},
+    display::hex_string,
+    display_fmt,
+    specification::{check_offsets, check_offsets_minimal, try_check_offsets},
     Array, GenericBinaryArray, Offset,
 };
 
@@ -79,6 +85,40 @@ impl<O: Offset> BinaryArray<O> {
         }
     }
 
+    /// Returns a new [`BinaryArray`], validating all its invariants. Use this constructor when
+    /// the `offsets` and `values` come from an untrusted source.
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s physical type is not equal to `Binary` or `LargeBinary`.
+    /// * The `offsets` are empty, not monotonically increasing or start with a negative value.
+    /// * The last offset is larger than the values' length.
+    /// * The validity is not `None` and its length is different from `offsets.len() - 1`.
+    pub fn try_new(
+        data_type: DataType,
+        offsets: Buffer<O>,
+        values: Buffer<u8>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self> {
+        try_check_offsets(&offsets, values.len())?;
+        if matches!(&validity, Some(validity) if validity.len() != offsets.len() - 1) {
+            return Err(ArrowError::oos(
+                "The validity length of a BinaryArray must equal the number of values",
+            ));
+        }
+        if data_type.to_physical_type() != Self::default_data_type().to_physical_type() {
+            return Err(ArrowError::oos(
+                "BinaryArray can only be initialized with DataType::Binary or DataType::LargeBinary",
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            offsets,
+            values,
+            validity,
+        })
+    }
+
     /// Returns the default [`DataType`], `DataType::Binary` or `DataType::LargeBinary`
     pub fn default_data_type() -> DataType {
         if O::is_large() {
@@ -248,8 +288,7 @@ impl<O: Offset> Array for BinaryArray<O> {
 
 impl<O: Offset> std::fmt::Display for BinaryArray<O> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let a = |x: &[u8]| display_helper(x.iter().map(|x| Some(format!("{:b}", x)))).join(" ");
-        let iter = self.iter().map(|x| x.map(a));
+        let iter = self.iter().map(|x| x.map(hex_string));
         let head = if O::is_large() {
             "LargeBinaryArray"
         } else {
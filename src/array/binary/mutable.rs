@@ -1,10 +1,14 @@
 use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
-    array::{specification::check_offsets, Array, MutableArray, Offset, TryExtend, TryPush},
+    array::{
+        downcast_scalar, specification::check_offsets, Array, MutableArray, Offset, TryExtend,
+        TryPush,
+    },
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
+    scalar::{BinaryScalar, Scalar},
     trusted_len::TrustedLen,
 };
 
@@ -104,12 +108,13 @@ impl<O: Offset> MutableBinaryArray<O> {
         }
     }
 
-    /// Reserves `additional` slots.
-    pub fn reserve(&mut self, additional: usize) {
+    /// Reserves `additional` elements and `additional_values` on the values buffer.
+    pub fn reserve(&mut self, additional: usize, additional_values: usize) {
         self.offsets.reserve(additional);
         if let Some(x) = self.validity.as_mut() {
             x.reserve(additional)
         }
+        self.values.reserve(additional_values);
     }
 
     #[inline]
@@ -146,6 +151,19 @@ impl<O: Offset> MutableBinaryArray<O> {
         let a: BinaryArray<O> = self.into();
         Arc::new(a)
     }
+    /// Shortens the [`MutableBinaryArray`] to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    pub fn truncate(&mut self, len: usize) {
+        if len + 1 >= self.offsets.len() {
+            return;
+        }
+        self.offsets.truncate(len + 1);
+        self.values.truncate(self.offsets[len].to_usize());
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+    }
+
     /// Shrinks the capacity of the [`MutableBinaryArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -166,6 +184,17 @@ impl<O: Offset> MutableBinaryArray<O> {
     pub fn offsets(&self) -> &Vec<O> {
         &self.offsets
     }
+
+    /// returns the capacity (in bytes) of its values.
+    pub fn values_capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    /// returns the capacity of its offsets, i.e. the number of items plus one
+    /// that can be pushed without reallocating them.
+    pub fn offsets_capacity(&self) -> usize {
+        self.offsets.capacity()
+    }
 }
 
 impl<O: Offset> MutableArray for MutableBinaryArray<O> {
@@ -212,6 +241,15 @@ impl<O: Offset> MutableArray for MutableBinaryArray<O> {
         self.push::<&[u8]>(None)
     }
 
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let value = downcast_scalar::<BinaryScalar<O>>(&self.data_type, value)?;
+        self.try_push(value.value())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
@@ -398,7 +436,7 @@ impl<O: Offset, T: AsRef<[u8]>> Extend<Option<T>> for MutableBinaryArray<O> {
 impl<O: Offset, T: AsRef<[u8]>> TryExtend<Option<T>> for MutableBinaryArray<O> {
     fn try_extend<I: IntoIterator<Item = Option<T>>>(&mut self, iter: I) -> Result<()> {
         let mut iter = iter.into_iter();
-        self.reserve(iter.size_hint().0);
+        self.reserve(iter.size_hint().0, 0);
         iter.try_for_each(|x| self.try_push(x))
     }
 }
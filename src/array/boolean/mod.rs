@@ -1,6 +1,7 @@
 use crate::{
     bitmap::Bitmap,
     datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
 };
 
 use super::{display_fmt, Array};
@@ -34,6 +35,37 @@ impl BooleanArray {
         Self::from_data(data_type, bitmap.clone(), Some(bitmap))
     }
 
+    /// Returns a new [`BooleanArray`] from its values and validity, validating its invariants.
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s physical type is not [`PhysicalType::Boolean`].
+    /// * The validity is not `None` and its length is different from `values`'s length
+    pub fn try_new(data_type: DataType, values: Bitmap, validity: Option<Bitmap>) -> Result<Self> {
+        if data_type.to_physical_type() != PhysicalType::Boolean {
+            return Err(ArrowError::InvalidArgumentError(
+                "BooleanArray can only be initialized with a DataType whose physical type is Boolean"
+                    .to_string(),
+            ));
+        }
+        if matches!(&validity, Some(validity) if validity.len() != values.len()) {
+            return Err(ArrowError::InvalidArgumentError(
+                "The validity length of a BooleanArray must equal its values' length".to_string(),
+            ));
+        }
+        Ok(Self {
+            data_type,
+            values,
+            validity,
+        })
+    }
+
+    /// Returns a new [`BooleanArray`] from its values and validity.
+    /// # Panics
+    /// This function panics iff [`BooleanArray::try_new`] errors.
+    pub fn new(data_type: DataType, values: Bitmap, validity: Option<Bitmap>) -> Self {
+        Self::try_new(data_type, values, validity).unwrap()
+    }
+
     /// The canonical method to create a [`BooleanArray`] out of low-end APIs.
     /// # Panics
     /// This function panics iff:
@@ -84,6 +116,16 @@ impl BooleanArray {
         }
     }
 
+    /// Deconstructs the [`BooleanArray`] into its data type, values and validity.
+    pub fn into_data(self) -> (DataType, Bitmap, Option<Bitmap>) {
+        let Self {
+            data_type,
+            values,
+            validity,
+        } = self;
+        (data_type, values, validity)
+    }
+
     /// Sets the validity bitmap on this [`BooleanArray`].
     /// # Panic
     /// This function panics iff `validity.len() != self.len()`.
@@ -2,10 +2,11 @@ use std::iter::FromIterator;
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryPush},
-    bitmap::MutableBitmap,
+    array::{downcast_scalar, Array, MutableArray, TryExtend, TryPush},
+    bitmap::{Bitmap, MutableBitmap},
     datatypes::{DataType, PhysicalType},
     error::Result,
+    scalar::{BooleanScalar, Scalar},
     trusted_len::TrustedLen,
 };
 
@@ -104,6 +105,27 @@ impl MutableBooleanArray {
         }
     }
 
+    /// Extends the [`MutableBooleanArray`] with `additional` repetitions of `value`.
+    #[inline]
+    pub fn extend_constant(&mut self, additional: usize, value: Option<bool>) {
+        if let Some(value) = value {
+            self.values.extend_constant(additional, value);
+            if let Some(validity) = self.validity.as_mut() {
+                validity.extend_constant(additional, true)
+            }
+        } else {
+            if let Some(validity) = self.validity.as_mut() {
+                validity.extend_constant(additional, false)
+            } else {
+                let mut validity = MutableBitmap::with_capacity(self.values.capacity());
+                validity.extend_constant(self.len(), true);
+                validity.extend_constant(additional, false);
+                self.validity = Some(validity)
+            }
+            self.values.extend_constant(additional, false);
+        }
+    }
+
     /// Extends the [`MutableBooleanArray`] from an iterator of values of trusted len.
     /// This differs from `extend_trusted_len` which accepts in iterator of optional values.
     #[inline]
@@ -135,6 +157,18 @@ impl MutableBooleanArray {
         self.values.extend_from_trusted_len_iter_unchecked(iterator)
     }
 
+    /// Extends the [`MutableBooleanArray`] with the `length` bits of `bitmap` starting at
+    /// `offset` as (valid) values. This copies whole bytes or words when possible.
+    /// # Panics
+    /// Panics iff `offset + length > bitmap.len()`.
+    #[inline]
+    pub fn extend_from_bitmap(&mut self, bitmap: &Bitmap, offset: usize, length: usize) {
+        self.values.extend_from_bitmap(bitmap, offset, length);
+        if let Some(validity) = self.validity.as_mut() {
+            validity.extend_constant(length, true);
+        }
+    }
+
     /// Extends the [`MutableBooleanArray`] from an iterator of trusted len.
     #[inline]
     pub fn extend_trusted_len<I, P>(&mut self, iterator: I)
@@ -294,6 +328,15 @@ impl MutableBooleanArray {
         unsafe { Self::try_from_trusted_len_iter_unchecked(iterator) }
     }
 
+    /// Shortens the [`MutableBooleanArray`] to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    pub fn truncate(&mut self, len: usize) {
+        self.values.truncate(len);
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+    }
+
     /// Shrinks the capacity of the [`MutableBooleanArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -470,6 +513,20 @@ impl MutableArray for MutableBooleanArray {
         self.push(None)
     }
 
+    fn extend_nulls(&mut self, additional: usize) {
+        self.extend_constant(additional, None)
+    }
+
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let value = downcast_scalar::<BooleanScalar>(&self.data_type, value)?;
+        self.push(value.value());
+        Ok(())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
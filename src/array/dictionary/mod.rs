@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
-    bitmap::Bitmap,
+    bitmap::{Bitmap, MutableBitmap},
     datatypes::{DataType, IntegerType},
     scalar::{new_scalar, Scalar},
     types::NativeType,
@@ -50,6 +50,10 @@ impl DictionaryKey for u64 {
 
 /// An [`Array`] whose values are encoded by keys. This [`Array`] is useful when the cardinality of
 /// values is low compared to the length of the [`Array`].
+///
+/// A slot is null iff its key is null or its key points to a null value. Like every other
+/// [`Array`], [`Array::validity`] is the validity of the keys; the validity of the slots is
+/// given by [`DictionaryArray::value_validity_merged`].
 #[derive(Debug, Clone)]
 pub struct DictionaryArray<K: DictionaryKey> {
     data_type: DataType,
@@ -89,6 +93,15 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         }
     }
 
+    /// Returns this [`DictionaryArray`] with its values declared as ordered iff `is_ordered`,
+    /// i.e. whether the order of its keys corresponds to the order of its values.
+    pub fn with_is_ordered(mut self, is_ordered: bool) -> Self {
+        if let DataType::Dictionary(_, _, ordered) = &mut self.data_type {
+            *ordered = is_ordered;
+        }
+        self
+    }
+
     /// Creates a new [`DictionaryArray`] by slicing the existing [`DictionaryArray`].
     /// # Panics
     /// iff `offset + length > self.len()`.
@@ -138,6 +151,30 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         self.keys.validity()
     }
 
+    /// Returns the validity of the slots of this [`DictionaryArray`], i.e. the validity of its
+    /// keys merged with the validity of the values they point to, or `None` iff every slot is valid.
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow2::array::{Array, DictionaryArray, Int32Array, Utf8Array};
+    ///
+    /// let values = Arc::new(Utf8Array::<i32>::from([Some("a"), None])) as Arc<dyn Array>;
+    /// let keys = Int32Array::from([Some(0), Some(1), None]);
+    /// let array = DictionaryArray::<i32>::from_data(keys, values);
+    /// assert_eq!(array.value_validity_merged(), Some([true, false, false].into()));
+    /// ```
+    pub fn value_validity_merged(&self) -> Option<Bitmap> {
+        if self.values.null_count() == 0 {
+            return self.keys.validity().cloned();
+        }
+        let validity = self
+            .keys
+            .iter()
+            .map(|key| matches!(key, Some(key) if self.values.is_valid(key.to_usize().unwrap())))
+            .collect::<MutableBitmap>();
+        Some(validity.into())
+    }
+
     /// Returns the keys of the [`DictionaryArray`]. These keys can be used to fetch values
     /// from `values`.
     #[inline]
@@ -151,6 +188,12 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         &self.values
     }
 
+    /// Returns whether the values of this [`DictionaryArray`] are declared as ordered.
+    #[inline]
+    pub fn is_ordered(&self) -> bool {
+        Self::get_is_ordered(&self.data_type)
+    }
+
     /// Returns the value of the [`DictionaryArray`] at position `i`.
     #[inline]
     pub fn value(&self, index: usize) -> Box<dyn Scalar> {
@@ -171,6 +214,14 @@ impl<K: DictionaryKey> DictionaryArray<K> {
             _ => panic!("DictionaryArray must be initialized with DataType::Dictionary"),
         }
     }
+
+    pub(crate) fn get_is_ordered(data_type: &DataType) -> bool {
+        match data_type {
+            DataType::Dictionary(_, _, is_ordered) => *is_ordered,
+            DataType::Extension(_, inner, _) => Self::get_is_ordered(inner),
+            _ => panic!("DictionaryArray must be initialized with DataType::Dictionary"),
+        }
+    }
 }
 
 impl<K: DictionaryKey> Array for DictionaryArray<K> {
@@ -204,15 +255,16 @@ impl<K: DictionaryKey> Array for DictionaryArray<K> {
     }
 }
 
-impl<K: DictionaryKey> std::fmt::Display for DictionaryArray<K>
-where
-    PrimitiveArray<K>: std::fmt::Display,
-{
+impl<K: DictionaryKey> std::fmt::Display for DictionaryArray<K> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let display = get_value_display(self);
         let new_lines = false;
         let head = &format!("{:?}", self.data_type());
-        let iter = self.iter().enumerate().map(|(i, x)| x.map(|_| display(i)));
+        let validity = self.value_validity_merged();
+        let iter = (0..self.len()).map(|i| {
+            let is_valid = validity.as_ref().map(|x| x.get_bit(i)).unwrap_or(true);
+            is_valid.then(|| display(i))
+        });
         display_fmt(iter, head, f, new_lines)
     }
 }
@@ -3,12 +3,17 @@ use std::{collections::hash_map::DefaultHasher, sync::Arc};
 
 use hash_hasher::HashedMap;
 
-use crate::array::TryExtend;
+use crate::array::{TryExtend, TryPush};
 use crate::{
-    array::{primitive::MutablePrimitiveArray, Array, MutableArray},
+    array::{
+        downcast_scalar, primitive::MutablePrimitiveArray, Array, MutableArray, MutableBinaryArray,
+        MutableBooleanArray, MutableFixedSizeBinaryArray, MutableUtf8Array, Offset,
+    },
     bitmap::MutableBitmap,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{ArrowError, Result},
+    scalar::{BinaryScalar, BooleanScalar, DictionaryScalar, PrimitiveScalar, Scalar, Utf8Scalar},
+    types::NativeType,
 };
 
 use super::{DictionaryArray, DictionaryKey};
@@ -18,7 +23,9 @@ use super::{DictionaryArray, DictionaryKey};
 pub struct MutableDictionaryArray<K: DictionaryKey, M: MutableArray> {
     data_type: DataType,
     keys: MutablePrimitiveArray<K>,
-    map: HashedMap<u64, K>,
+    // the keys of the interned values by their hash. Values whose hashes collide share a bucket
+    // and are told apart by comparing them against the values.
+    map: HashedMap<u64, Vec<K>>,
     values: M,
 }
 
@@ -67,31 +74,72 @@ impl<K: DictionaryKey, M: MutableArray + Default> Default for MutableDictionaryA
 }
 
 impl<K: DictionaryKey, M: MutableArray> MutableDictionaryArray<K, M> {
-    /// Returns whether the value should be pushed to the values or not
-    pub fn try_push_valid<T: Hash>(&mut self, value: &T) -> Result<bool> {
+    /// Pushes a new valid entry, interning `value`: if `value` was pushed before,
+    /// its key is re-used; otherwise `value` is pushed to the values under a new key.
+    /// Returns the key of `value`.
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the number of distinct values exceeds `K`'s
+    /// maximum value (e.g. more than 256 distinct values for `K = u8`); use a wider key type
+    /// in that case.
+    pub fn try_push_valid<T>(&mut self, value: T) -> Result<K>
+    where
+        M: TryPush<Option<T>> + ValueEq<T>,
+    {
         let mut hasher = DefaultHasher::new();
-        value.hash(&mut hasher);
+        M::hash_value(&value, &mut hasher);
         let hash = hasher.finish();
-        match self.map.get(&hash) {
-            Some(key) => {
-                self.keys.push(Some(*key));
-                Ok(false)
-            }
+        let values = &self.values;
+        let interned = self.map.get(&hash).and_then(|bucket| {
+            bucket
+                .iter()
+                .find(|key| values.value_eq(key.to_usize().unwrap(), &value))
+                .copied()
+        });
+        let key = match interned {
+            Some(key) => key,
             None => {
-                let key = K::from_usize(self.map.len()).ok_or(ArrowError::Overflow)?;
-                self.map.insert(hash, key);
+                let key = K::from_usize(self.values.len()).ok_or(ArrowError::Overflow)?;
+                self.values.try_push(Some(value))?;
+                self.map.entry(hash).or_default().push(key);
+                key
+            }
+        };
+        self.keys.push(Some(key));
+        Ok(key)
+    }
+
+    /// Pushes a new valid entry whose value is already in the values under `key`.
+    /// # Errors
+    /// Errors iff `key` is not a valid index of the values.
+    pub fn try_push_key(&mut self, key: K) -> Result<()> {
+        match key.to_usize() {
+            Some(index) if index < self.values.len() => {
                 self.keys.push(Some(key));
-                Ok(true)
+                Ok(())
             }
+            _ => Err(ArrowError::InvalidArgumentError(format!(
+                "The key {:?} is out of bounds of the dictionary's {} values",
+                key,
+                self.values.len()
+            ))),
         }
     }
 
+    /// Pushes a new valid entry whose value is already in the values under `key`.
+    /// # Panics
+    /// Panics iff `key` is not a valid index of the values.
+    pub fn push_key(&mut self, key: K) {
+        self.try_push_key(key).unwrap()
+    }
+
     /// pushes a null value
     pub fn push_null(&mut self) {
         self.keys.push(None)
     }
 
     /// returns a mutable reference to the inner values.
+    /// Values pushed directly to it are not interned, but can be referenced via
+    /// [`MutableDictionaryArray::try_push_key`].
     pub fn mut_values(&mut self) -> &mut M {
         &mut self.values
     }
@@ -101,12 +149,24 @@ impl<K: DictionaryKey, M: MutableArray> MutableDictionaryArray<K, M> {
         &self.values
     }
 
+    /// returns a reference to the keys pushed so far.
+    pub fn keys(&self) -> &MutablePrimitiveArray<K> {
+        &self.keys
+    }
+
     /// converts itself into `Arc<dyn Array>`
     pub fn into_arc(self) -> Arc<dyn Array> {
         let a: DictionaryArray<K> = self.into();
         Arc::new(a)
     }
 
+    /// Shortens the [`MutableDictionaryArray`] to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    /// The values already interned in the dictionary are kept.
+    pub fn truncate(&mut self, len: usize) {
+        self.keys.truncate(len);
+    }
+
     /// Shrinks the capacity of the [`MutableDictionaryArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -152,25 +212,238 @@ impl<K: DictionaryKey, M: 'static + MutableArray> MutableArray for MutableDictio
     fn push_null(&mut self) {
         self.keys.push(None)
     }
+
+    fn extend_nulls(&mut self, additional: usize) {
+        self.keys.extend_constant(additional, None)
+    }
+
+    /// Pushes a [`DictionaryScalar`], interning its value like [`Self::try_push_valid`] when the
+    /// value is a non-null boolean, integer, float, utf8 or binary.
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let value = match downcast_scalar::<DictionaryScalar<K>>(&self.data_type, value)?.value() {
+            Some(value) => value.as_ref(),
+            None => {
+                self.push_null();
+                return Ok(());
+            }
+        };
+        let hash = hash_scalar(value);
+        let values = &self.values;
+        let interned = hash
+            .and_then(|hash| self.map.get(&hash))
+            .and_then(|bucket| {
+                bucket
+                    .iter()
+                    .find(|key| scalar_eq(values, key.to_usize().unwrap(), value))
+                    .copied()
+            });
+        let key = match interned {
+            Some(key) => key,
+            None => {
+                let key = K::from_usize(self.values.len()).ok_or(ArrowError::Overflow)?;
+                self.values.try_push_scalar(value)?;
+                if let Some(hash) = hash {
+                    self.map.entry(hash).or_default().push(key);
+                }
+                key
+            }
+        };
+        self.keys.push(Some(key));
+        Ok(())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
 }
 
-impl<K, M, T: Hash> TryExtend<Option<T>> for MutableDictionaryArray<K, M>
+/// Returns the hash of the value of `scalar`, equal to the hash of the same value pushed via
+/// [`MutableDictionaryArray::try_push_valid`], or `None` if the value is not interned.
+fn hash_scalar(scalar: &dyn Scalar) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+    macro_rules! hash {
+        ($array:ty, $scalar:ty) => {
+            <$array as ValueEq<_>>::hash_value(
+                &scalar.as_any().downcast_ref::<$scalar>()?.value()?,
+                &mut hasher,
+            )
+        };
+    }
+    use PrimitiveType::*;
+    match scalar.data_type().to_physical_type() {
+        PhysicalType::Boolean => hash!(MutableBooleanArray, BooleanScalar),
+        PhysicalType::Primitive(primitive) => match primitive {
+            Int8 => hash!(MutablePrimitiveArray<i8>, PrimitiveScalar<i8>),
+            Int16 => hash!(MutablePrimitiveArray<i16>, PrimitiveScalar<i16>),
+            Int32 => hash!(MutablePrimitiveArray<i32>, PrimitiveScalar<i32>),
+            Int64 => hash!(MutablePrimitiveArray<i64>, PrimitiveScalar<i64>),
+            Int128 => hash!(MutablePrimitiveArray<i128>, PrimitiveScalar<i128>),
+            UInt8 => hash!(MutablePrimitiveArray<u8>, PrimitiveScalar<u8>),
+            UInt16 => hash!(MutablePrimitiveArray<u16>, PrimitiveScalar<u16>),
+            UInt32 => hash!(MutablePrimitiveArray<u32>, PrimitiveScalar<u32>),
+            UInt64 => hash!(MutablePrimitiveArray<u64>, PrimitiveScalar<u64>),
+            Float32 => hash!(MutablePrimitiveArray<f32>, PrimitiveScalar<f32>),
+            Float64 => hash!(MutablePrimitiveArray<f64>, PrimitiveScalar<f64>),
+            _ => return None,
+        },
+        PhysicalType::Utf8 => hash!(MutableUtf8Array<i32>, Utf8Scalar<i32>),
+        PhysicalType::LargeUtf8 => hash!(MutableUtf8Array<i64>, Utf8Scalar<i64>),
+        PhysicalType::Binary => hash!(MutableBinaryArray<i32>, BinaryScalar<i32>),
+        PhysicalType::LargeBinary => hash!(MutableBinaryArray<i64>, BinaryScalar<i64>),
+        _ => return None,
+    }
+    Some(hasher.finish())
+}
+
+/// Returns whether the (valid) value at `index` of `values` equals the value of `scalar`,
+/// for the values interned by [`MutableArray::try_push_scalar`].
+fn scalar_eq(values: &dyn MutableArray, index: usize, scalar: &dyn Scalar) -> bool {
+    macro_rules! eq {
+        ($array:ty, $scalar:ty) => {
+            match (
+                values.as_any().downcast_ref::<$array>(),
+                scalar.as_any().downcast_ref::<$scalar>(),
+            ) {
+                (Some(array), Some(scalar)) => scalar
+                    .value()
+                    .map(|value| array.value_eq(index, &value))
+                    .unwrap_or(false),
+                _ => false,
+            }
+        };
+    }
+    use PrimitiveType::*;
+    match scalar.data_type().to_physical_type() {
+        PhysicalType::Boolean => eq!(MutableBooleanArray, BooleanScalar),
+        PhysicalType::Primitive(primitive) => match primitive {
+            Int8 => eq!(MutablePrimitiveArray<i8>, PrimitiveScalar<i8>),
+            Int16 => eq!(MutablePrimitiveArray<i16>, PrimitiveScalar<i16>),
+            Int32 => eq!(MutablePrimitiveArray<i32>, PrimitiveScalar<i32>),
+            Int64 => eq!(MutablePrimitiveArray<i64>, PrimitiveScalar<i64>),
+            Int128 => eq!(MutablePrimitiveArray<i128>, PrimitiveScalar<i128>),
+            UInt8 => eq!(MutablePrimitiveArray<u8>, PrimitiveScalar<u8>),
+            UInt16 => eq!(MutablePrimitiveArray<u16>, PrimitiveScalar<u16>),
+            UInt32 => eq!(MutablePrimitiveArray<u32>, PrimitiveScalar<u32>),
+            UInt64 => eq!(MutablePrimitiveArray<u64>, PrimitiveScalar<u64>),
+            Float32 => eq!(MutablePrimitiveArray<f32>, PrimitiveScalar<f32>),
+            Float64 => eq!(MutablePrimitiveArray<f64>, PrimitiveScalar<f64>),
+            _ => false,
+        },
+        PhysicalType::Utf8 => eq!(MutableUtf8Array<i32>, Utf8Scalar<i32>),
+        PhysicalType::LargeUtf8 => eq!(MutableUtf8Array<i64>, Utf8Scalar<i64>),
+        PhysicalType::Binary => eq!(MutableBinaryArray<i32>, BinaryScalar<i32>),
+        PhysicalType::LargeBinary => eq!(MutableBinaryArray<i64>, BinaryScalar<i64>),
+        _ => false,
+    }
+}
+
+/// Trait of [`MutableArray`]s whose values can be interned by a [`MutableDictionaryArray`]:
+/// values are looked up by their hash, and a value is only re-used if it is equal to the one
+/// being pushed, so that values whose hashes collide get different keys.
+pub trait ValueEq<T> {
+    /// Returns whether the value at `index` equals `value`.
+    /// # Panics
+    /// Implementations may panic iff `index >= self.len()`.
+    fn value_eq(&self, index: usize, value: &T) -> bool;
+
+    /// Feeds `value` into `state`. Values that are [`ValueEq::value_eq`] must have equal hashes.
+    fn hash_value<H: Hasher>(value: &T, state: &mut H);
+}
+
+/// Primitives are compared and hashed by their bytes, so that floats can be interned: all NaNs
+/// with the same bits share a key, while `-0.0` and `0.0` get different keys.
+impl<T: NativeType> ValueEq<T> for MutablePrimitiveArray<T> {
+    #[inline]
+    fn value_eq(&self, index: usize, value: &T) -> bool {
+        self.values()[index].to_le_bytes().as_ref() == value.to_le_bytes().as_ref()
+    }
+
+    #[inline]
+    fn hash_value<H: Hasher>(value: &T, state: &mut H) {
+        value.to_le_bytes().as_ref().hash(state)
+    }
+}
+
+impl ValueEq<bool> for MutableBooleanArray {
+    #[inline]
+    fn value_eq(&self, index: usize, value: &bool) -> bool {
+        self.values().get(index) == *value
+    }
+
+    #[inline]
+    fn hash_value<H: Hasher>(value: &bool, state: &mut H) {
+        value.hash(state)
+    }
+}
+
+#[inline]
+fn offsets_value<'a, O: Offset>(offsets: &[O], values: &'a [u8], index: usize) -> &'a [u8] {
+    &values[offsets[index].to_usize()..offsets[index + 1].to_usize()]
+}
+
+impl<O: Offset, T: AsRef<str> + Hash> ValueEq<T> for MutableUtf8Array<O> {
+    #[inline]
+    fn value_eq(&self, index: usize, value: &T) -> bool {
+        offsets_value(self.offsets(), self.values(), index) == value.as_ref().as_bytes()
+    }
+
+    #[inline]
+    fn hash_value<H: Hasher>(value: &T, state: &mut H) {
+        value.hash(state)
+    }
+}
+
+impl<O: Offset, T: AsRef<[u8]> + Hash> ValueEq<T> for MutableBinaryArray<O> {
+    #[inline]
+    fn value_eq(&self, index: usize, value: &T) -> bool {
+        offsets_value(self.offsets(), self.values(), index) == value.as_ref()
+    }
+
+    #[inline]
+    fn hash_value<H: Hasher>(value: &T, state: &mut H) {
+        value.hash(state)
+    }
+}
+
+impl<T: AsRef<[u8]> + Hash> ValueEq<T> for MutableFixedSizeBinaryArray {
+    #[inline]
+    fn value_eq(&self, index: usize, value: &T) -> bool {
+        self.value(index) == value.as_ref()
+    }
+
+    #[inline]
+    fn hash_value<H: Hasher>(value: &T, state: &mut H) {
+        value.hash(state)
+    }
+}
+
+impl<K, M, T> TryExtend<Option<T>> for MutableDictionaryArray<K, M>
 where
     K: DictionaryKey,
-    M: MutableArray + TryExtend<Option<T>>,
+    M: MutableArray + TryPush<Option<T>> + ValueEq<T>,
 {
     fn try_extend<II: IntoIterator<Item = Option<T>>>(&mut self, iter: II) -> Result<()> {
         for value in iter {
-            if let Some(value) = value {
-                if self.try_push_valid(&value)? {
-                    self.mut_values().try_extend(std::iter::once(Some(value)))?;
-                }
-            } else {
-                self.push_null();
-            }
+            self.try_push(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K, M, T> TryPush<Option<T>> for MutableDictionaryArray<K, M>
+where
+    K: DictionaryKey,
+    M: MutableArray + TryPush<Option<T>> + ValueEq<T>,
+{
+    fn try_push(&mut self, item: Option<T>) -> Result<()> {
+        if let Some(value) = item {
+            self.try_push_valid(value)?;
+        } else {
+            self.push_null();
         }
         Ok(())
     }
@@ -2,6 +2,7 @@ use crate::{
     array::*,
     datatypes::{DataType, IntervalUnit, TimeUnit},
     temporal_conversions,
+    types::f16,
 };
 
 macro_rules! dyn_display {
@@ -17,11 +18,24 @@ macro_rules! dyn_primitive {
     }};
 }
 
+/// Returns the lowercase hexadecimal representation of `bytes`, two characters per byte.
+pub(super) fn hex_string(bytes: &[u8]) -> String {
+    const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+    let mut string = String::with_capacity(bytes.len() * 2);
+    bytes.iter().for_each(|x| {
+        string.push(HEX_CHARS[(x >> 4) as usize] as char);
+        string.push(HEX_CHARS[(x & 0xf) as usize] as char);
+    });
+    string
+}
+
 /// Returns a function of index returning the string representation of the _value_ of `array`.
+/// Binary values are represented in lowercase hexadecimal.
 /// This does not take nulls into account.
 pub fn get_value_display<'a>(array: &'a dyn Array) -> Box<dyn Fn(usize) -> String + 'a> {
     use DataType::*;
-    match array.data_type() {
+    // extension types are displayed as their storage type
+    match array.data_type().to_logical_type() {
         Null => Box::new(|_: usize| "".to_string()),
         Boolean => {
             let a = array.as_any().downcast_ref::<BooleanArray>().unwrap();
@@ -35,7 +49,7 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Box<dyn Fn(usize) -> Strin
         UInt16 => dyn_primitive!(array, u16, |x| x),
         UInt32 => dyn_primitive!(array, u32, |x| x),
         UInt64 => dyn_primitive!(array, u64, |x| x),
-        Float16 => unreachable!(),
+        Float16 => dyn_primitive!(array, f16, |x| x),
         Float32 => dyn_primitive!(array, f32, |x| x),
         Float64 => dyn_primitive!(array, f64, |x| x),
         Date32 => dyn_primitive!(array, i32, temporal_conversions::date32_to_date),
@@ -56,24 +70,23 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Box<dyn Fn(usize) -> Strin
         Time64(_) => unreachable!(), // remaining are not valid
         Timestamp(time_unit, tz) => {
             if let Some(tz) = tz {
-                let timezone = temporal_conversions::parse_offset(tz);
-                match timezone {
-                    Ok(timezone) => {
+                match temporal_conversions::Timezone::try_new(tz) {
+                    Ok(temporal_conversions::Timezone::Offset(timezone)) => {
                         dyn_primitive!(array, i64, |time| {
                             temporal_conversions::timestamp_to_datetime(time, *time_unit, &timezone)
                         })
                     }
                     #[cfg(feature = "chrono-tz")]
-                    Err(_) => {
-                        let timezone = temporal_conversions::parse_offset_tz(tz).unwrap();
+                    Ok(temporal_conversions::Timezone::Tz(timezone)) => {
                         dyn_primitive!(array, i64, |time| {
                             temporal_conversions::timestamp_to_datetime(time, *time_unit, &timezone)
                         })
                     }
-                    #[cfg(not(feature = "chrono-tz"))]
-                    _ => panic!(
-                        "Invalid Offset format (must be [-]00:00) or chrono-tz feature not active"
-                    ),
+                    // timestamps are stored in UTC, which is displayed when the timezone can't
+                    // be parsed (or its parsing requires the feature `chrono-tz`)
+                    Err(_) => dyn_primitive!(array, i64, |time| {
+                        temporal_conversions::timestamp_to_datetime(time, *time_unit, &chrono::Utc)
+                    }),
                 }
             } else {
                 dyn_primitive!(array, i64, |time| {
@@ -85,58 +98,38 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Box<dyn Fn(usize) -> Strin
             dyn_primitive!(array, i32, |x| format!("{}m", x))
         }
         Interval(IntervalUnit::DayTime) => {
-            dyn_primitive!(array, days_ms, |x: days_ms| format!(
-                "{}d{}ms",
-                x.days(),
-                x.milliseconds()
-            ))
+            dyn_primitive!(array, days_ms, |x: days_ms| x.to_string())
         }
-
         Interval(IntervalUnit::MonthDayNano) => {
-            dyn_primitive!(array, months_days_ns, |x: months_days_ns| format!(
-                "{}m{}d{}ns",
-                x.months(),
-                x.days(),
-                x.ns()
-            ))
+            dyn_primitive!(array, months_days_ns, |x: months_days_ns| x.to_string())
         }
         Duration(TimeUnit::Second) => dyn_primitive!(array, i64, |x| format!("{}s", x)),
         Duration(TimeUnit::Millisecond) => dyn_primitive!(array, i64, |x| format!("{}ms", x)),
         Duration(TimeUnit::Microsecond) => dyn_primitive!(array, i64, |x| format!("{}us", x)),
         Duration(TimeUnit::Nanosecond) => dyn_primitive!(array, i64, |x| format!("{}ns", x)),
-        Binary => dyn_display!(array, BinaryArray<i32>, |x: &[u8]| {
-            x.iter().fold("".to_string(), |mut acc, x| {
-                acc.push_str(&format!("{:#010b}", x));
-                acc
-            })
-        }),
-        LargeBinary => dyn_display!(array, BinaryArray<i64>, |x: &[u8]| {
-            x.iter().fold("".to_string(), |mut acc, x| {
-                acc.push_str(&format!("{:#010b}", x));
-                acc
-            })
-        }),
-        FixedSizeBinary(_) => dyn_display!(array, FixedSizeBinaryArray, |x: &[u8]| {
-            x.iter().fold("".to_string(), |mut acc, x| {
-                acc.push_str(&format!("{:#010b}", x));
-                acc
-            })
-        }),
+        Binary => dyn_display!(array, BinaryArray<i32>, hex_string),
+        LargeBinary => dyn_display!(array, BinaryArray<i64>, hex_string),
+        FixedSizeBinary(_) => dyn_display!(array, FixedSizeBinaryArray, hex_string),
         Utf8 => dyn_display!(array, Utf8Array<i32>, |x| x),
         LargeUtf8 => dyn_display!(array, Utf8Array<i64>, |x| x),
         Decimal(_, scale) => {
             // The number 999.99 has a precision of 5 and scale of 2
-            let scale = *scale as u32;
-            let display = move |x| {
-                let base = x / 10i128.pow(scale);
-                let decimals = x - base * 10i128.pow(scale);
-                format!("{}.{}", base, decimals)
+            let scale = *scale;
+            let display = move |x: i128| {
+                if scale == 0 {
+                    return x.to_string();
+                }
+                let factor = 10i128.pow(scale as u32);
+                let base = (x / factor).abs();
+                let decimals = (x % factor).abs();
+                let sign = if x < 0 { "-" } else { "" };
+                format!("{}{}.{:0width$}", sign, base, decimals, width = scale)
             };
             dyn_primitive!(array, i128, display)
         }
         List(_) => {
             let f = |x: Box<dyn Array>| {
-                let display = get_value_display(x.as_ref());
+                let display = get_display(x.as_ref());
                 let string_values = (0..x.len()).map(display).collect::<Vec<String>>();
                 format!("[{}]", string_values.join(", "))
             };
@@ -144,7 +137,7 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Box<dyn Fn(usize) -> Strin
         }
         FixedSizeList(_, _) => {
             let f = |x: Box<dyn Array>| {
-                let display = get_value_display(x.as_ref());
+                let display = get_display(x.as_ref());
                 let string_values = (0..x.len()).map(display).collect::<Vec<String>>();
                 format!("[{}]", string_values.join(", "))
             };
@@ -152,7 +145,7 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Box<dyn Fn(usize) -> Strin
         }
         LargeList(_) => {
             let f = |x: Box<dyn Array>| {
-                let display = get_value_display(x.as_ref());
+                let display = get_display(x.as_ref());
                 let string_values = (0..x.len()).map(display).collect::<Vec<String>>();
                 format!("[{}]", string_values.join(", "))
             };
@@ -173,13 +166,24 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Box<dyn Fn(usize) -> Strin
                 }
             })
         }),
-        Map(_, _) => todo!(),
+        Map(_, _) => {
+            let f = |x: Box<dyn Array>| {
+                let x = x.as_any().downcast_ref::<StructArray>().unwrap();
+                let keys = get_value_display(x.values()[0].as_ref());
+                let values = get_display(x.values()[1].as_ref());
+                let string_values = (0..x.len())
+                    .map(|i| format!("{}: {}", keys(i), values(i)))
+                    .collect::<Vec<String>>();
+                format!("{{{}}}", string_values.join(", "))
+            };
+            dyn_display!(array, MapArray, f)
+        }
         Struct(_) => {
             let a = array.as_any().downcast_ref::<StructArray>().unwrap();
             let displays = a
                 .values()
                 .iter()
-                .map(|x| get_value_display(x.as_ref()))
+                .map(|x| get_display(x.as_ref()))
                 .collect::<Vec<_>>();
             Box::new(move |row: usize| {
                 let mut string = displays
@@ -206,7 +210,7 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Box<dyn Fn(usize) -> Strin
                 get_display(array.fields()[field].as_ref())(index)
             })
         }
-        Extension(_, _, _) => todo!(),
+        Extension(_, _, _) => unreachable!(),
     }
 }
 
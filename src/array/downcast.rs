@@ -0,0 +1,136 @@
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::{
+    Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, ListArray, PrimitiveArray,
+    StructArray, Utf8Array,
+};
+
+#[inline]
+fn downcast<'a, A: 'static>(array: &'a dyn Array, expected: &dyn std::fmt::Debug) -> Result<&'a A> {
+    array.as_any().downcast_ref::<A>().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Expected an array of type {:?}, but the array is of type {:?}",
+            expected,
+            array.data_type()
+        ))
+    })
+}
+
+/// Checked downcasts of an [`Array`] to its concrete types.
+///
+/// Unlike `array.as_any().downcast_ref::<A>().unwrap()`, these return an error naming
+/// the expected and the actual [`DataType`] when the array is not of the requested type.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, AsArray, Int32Array};
+///
+/// let array = &Int32Array::from_slice(&[1, 2]) as &dyn Array;
+/// assert_eq!(array.as_primitive::<i32>().unwrap().value(1), 2);
+/// assert!(array.as_utf8().is_err());
+/// ```
+pub trait AsArray {
+    /// Downcasts itself to a [`PrimitiveArray`] of `T`, of any logical type whose
+    /// physical type is `T` (e.g. `Timestamp` for `i64`).
+    /// # Error
+    /// Errors iff the array is not a [`PrimitiveArray`] of `T`.
+    fn as_primitive<T: NativeType>(&self) -> Result<&PrimitiveArray<T>>;
+
+    /// Downcasts itself to a [`PrimitiveArray`] of `i128`, i.e. an array of logical type
+    /// [`DataType::Decimal`].
+    /// # Error
+    /// Errors iff the array is not of logical type [`DataType::Decimal`].
+    fn as_decimal(&self) -> Result<&PrimitiveArray<i128>>;
+
+    /// Downcasts itself to a [`BooleanArray`].
+    /// # Error
+    /// Errors iff the array is not a [`BooleanArray`].
+    fn as_boolean(&self) -> Result<&BooleanArray>;
+
+    /// Downcasts itself to a [`Utf8Array<i32>`].
+    /// # Error
+    /// Errors iff the array is not a [`Utf8Array<i32>`].
+    fn as_utf8(&self) -> Result<&Utf8Array<i32>>;
+
+    /// Downcasts itself to a [`Utf8Array<i64>`].
+    /// # Error
+    /// Errors iff the array is not a [`Utf8Array<i64>`].
+    fn as_large_utf8(&self) -> Result<&Utf8Array<i64>>;
+
+    /// Downcasts itself to a [`BinaryArray<i32>`].
+    /// # Error
+    /// Errors iff the array is not a [`BinaryArray<i32>`].
+    fn as_binary(&self) -> Result<&BinaryArray<i32>>;
+
+    /// Downcasts itself to a [`BinaryArray<i64>`].
+    /// # Error
+    /// Errors iff the array is not a [`BinaryArray<i64>`].
+    fn as_large_binary(&self) -> Result<&BinaryArray<i64>>;
+
+    /// Downcasts itself to a [`ListArray<i32>`].
+    /// # Error
+    /// Errors iff the array is not a [`ListArray<i32>`].
+    fn as_list(&self) -> Result<&ListArray<i32>>;
+
+    /// Downcasts itself to a [`ListArray<i64>`].
+    /// # Error
+    /// Errors iff the array is not a [`ListArray<i64>`].
+    fn as_large_list(&self) -> Result<&ListArray<i64>>;
+
+    /// Downcasts itself to a [`StructArray`].
+    /// # Error
+    /// Errors iff the array is not a [`StructArray`].
+    fn as_struct(&self) -> Result<&StructArray>;
+
+    /// Downcasts itself to a [`DictionaryArray`] with keys of type `K`.
+    /// # Error
+    /// Errors iff the array is not a [`DictionaryArray`] with keys of type `K`.
+    fn as_dictionary<K: DictionaryKey>(&self) -> Result<&DictionaryArray<K>>;
+}
+
+impl AsArray for dyn Array + '_ {
+    fn as_primitive<T: NativeType>(&self) -> Result<&PrimitiveArray<T>> {
+        downcast(self, &T::PRIMITIVE)
+    }
+
+    fn as_decimal(&self) -> Result<&PrimitiveArray<i128>> {
+        downcast(self, &format_args!("Decimal"))
+    }
+
+    fn as_boolean(&self) -> Result<&BooleanArray> {
+        downcast(self, &DataType::Boolean)
+    }
+
+    fn as_utf8(&self) -> Result<&Utf8Array<i32>> {
+        downcast(self, &DataType::Utf8)
+    }
+
+    fn as_large_utf8(&self) -> Result<&Utf8Array<i64>> {
+        downcast(self, &DataType::LargeUtf8)
+    }
+
+    fn as_binary(&self) -> Result<&BinaryArray<i32>> {
+        downcast(self, &DataType::Binary)
+    }
+
+    fn as_large_binary(&self) -> Result<&BinaryArray<i64>> {
+        downcast(self, &DataType::LargeBinary)
+    }
+
+    fn as_list(&self) -> Result<&ListArray<i32>> {
+        downcast(self, &format_args!("List"))
+    }
+
+    fn as_large_list(&self) -> Result<&ListArray<i64>> {
+        downcast(self, &format_args!("LargeList"))
+    }
+
+    fn as_struct(&self) -> Result<&StructArray> {
+        downcast(self, &format_args!("Struct"))
+    }
+
+    fn as_dictionary<K: DictionaryKey>(&self) -> Result<&DictionaryArray<K>> {
+        downcast(self, &format_args!("Dictionary({:?})", K::KEY_TYPE))
+    }
+}
@@ -5,10 +5,10 @@ pub(super) fn equal<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &Dictionary
         return false;
     };
 
-    // if x is not valid and y is but its child is not, the slots are equal.
-    lhs.iter().zip(rhs.iter()).all(|(x, y)| match (&x, &y) {
-        (None, Some(y)) => !y.is_valid(),
-        (Some(x), None) => !x.is_valid(),
-        _ => x == y,
+    // a null key and a key pointing to a null value are both a null slot
+    lhs.iter().zip(rhs.iter()).all(|(x, y)| {
+        let x = x.filter(|x| x.is_valid());
+        let y = y.filter(|y| y.is_valid());
+        x == y
     })
 }
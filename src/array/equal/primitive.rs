@@ -1,5 +1,24 @@
-use crate::{array::PrimitiveArray, types::NativeType};
+use crate::{
+    array::{Array, PrimitiveArray},
+    types::NativeType,
+};
 
 pub(super) fn equal<T: NativeType>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> bool {
-    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+    if lhs.data_type() != rhs.data_type() || lhs.len() != rhs.len() {
+        return false;
+    }
+    // validities are compared in chunks first, which is cheaper than comparing item by item
+    let equal_validities = match (lhs.validity(), rhs.validity()) {
+        (Some(lhs), Some(rhs)) => lhs == rhs,
+        (Some(validity), None) | (None, Some(validity)) => validity.null_count() == 0,
+        (None, None) => true,
+    };
+    if !equal_validities {
+        return false;
+    }
+    if lhs.null_count() == 0 {
+        lhs.values().as_slice() == rhs.values().as_slice()
+    } else {
+        lhs.iter().eq(rhs.iter())
+    }
 }
@@ -1,54 +1,39 @@
-use crate::array::{Array, StructArray};
+use crate::{
+    array::{Array, StructArray},
+    bitmap::utils::SlicesIterator,
+};
 
 pub(super) fn equal(lhs: &StructArray, rhs: &StructArray) -> bool {
-    lhs.data_type() == rhs.data_type()
-        && lhs.len() == rhs.len()
-        && match (lhs.validity(), rhs.validity()) {
-            (None, None) => lhs.values().iter().eq(rhs.values().iter()),
-            (Some(l_validity), Some(r_validity)) => lhs
-                .values()
-                .iter()
-                .zip(rhs.values().iter())
-                .all(|(lhs, rhs)| {
-                    l_validity.iter().zip(r_validity.iter()).enumerate().all(
-                        |(i, (lhs_is_valid, rhs_is_valid))| {
-                            if lhs_is_valid && rhs_is_valid {
-                                lhs.slice(i, 1) == rhs.slice(i, 1)
-                            } else {
-                                lhs_is_valid == rhs_is_valid
-                            }
-                        },
-                    )
-                }),
-            (Some(l_validity), None) => {
-                lhs.values()
-                    .iter()
-                    .zip(rhs.values().iter())
-                    .all(|(lhs, rhs)| {
-                        l_validity.iter().enumerate().all(|(i, lhs_is_valid)| {
-                            if lhs_is_valid {
-                                lhs.slice(i, 1) == rhs.slice(i, 1)
-                            } else {
-                                // rhs is always valid => different
-                                false
-                            }
-                        })
-                    })
+    if lhs.data_type() != rhs.data_type() || lhs.len() != rhs.len() {
+        return false;
+    }
+    // a missing validity is equivalent to a validity with all bits set
+    let validity = match (lhs.validity(), rhs.validity()) {
+        (Some(lhs), Some(rhs)) => {
+            if lhs != rhs {
+                return false;
             }
-            (None, Some(r_validity)) => {
-                lhs.values()
-                    .iter()
-                    .zip(rhs.values().iter())
-                    .all(|(lhs, rhs)| {
-                        r_validity.iter().enumerate().all(|(i, rhs_is_valid)| {
-                            if rhs_is_valid {
-                                lhs.slice(i, 1) == rhs.slice(i, 1)
-                            } else {
-                                // lhs is always valid => different
-                                false
-                            }
-                        })
-                    })
+            Some(lhs)
+        }
+        (Some(validity), None) | (None, Some(validity)) => {
+            if validity.null_count() != 0 {
+                return false;
             }
+            None
         }
+        (None, None) => None,
+    };
+
+    match validity.filter(|x| x.null_count() > 0) {
+        // the slots of the children in null slots of the struct are not compared
+        Some(validity) => lhs.values().iter().zip(rhs.values().iter()).all(|(l, r)| {
+            SlicesIterator::new(validity)
+                .all(|(start, len)| l.slice(start, len).as_ref() == r.slice(start, len).as_ref())
+        }),
+        None => lhs
+            .values()
+            .iter()
+            .zip(rhs.values().iter())
+            .all(|(l, r)| l.as_ref() == r.as_ref()),
+    }
 }
@@ -65,6 +65,11 @@ impl<'a> FixedSizeBinaryArray {
             self.validity.as_ref().map(|x| x.iter()),
         )
     }
+
+    /// Returns iterator over the values of [`FixedSizeBinaryArray`]
+    pub fn values_iter(&'a self) -> FixedSizeBinaryValuesIter<'a, FixedSizeBinaryArray> {
+        FixedSizeBinaryValuesIter::new(self)
+    }
 }
 
 impl<'a> IntoIterator for &'a MutableFixedSizeBinaryArray {
@@ -87,4 +92,9 @@ impl<'a> MutableFixedSizeBinaryArray {
             self.validity().as_ref().map(|x| x.iter()),
         )
     }
+
+    /// Returns iterator over the values of [`MutableFixedSizeBinaryArray`]
+    pub fn values_iter(&'a self) -> FixedSizeBinaryValuesIter<'a, MutableFixedSizeBinaryArray> {
+        FixedSizeBinaryValuesIter::new(self)
+    }
 }
@@ -1,4 +1,9 @@
-use crate::{bitmap::Bitmap, buffer::Buffer, datatypes::DataType, error::Result};
+use crate::{
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
 
 use super::{display_fmt, Array};
 
@@ -25,9 +30,10 @@ impl FixedSizeBinaryArray {
 
     /// Returns a new null [`FixedSizeBinaryArray`].
     pub fn new_null(data_type: DataType, length: usize) -> Self {
+        let size = Self::get_size(&data_type);
         Self::from_data(
             data_type,
-            Buffer::new_zeroed(length),
+            Buffer::new_zeroed(length * size),
             Some(Bitmap::new_zeroed(length)),
         )
     }
@@ -142,21 +148,35 @@ impl FixedSizeBinaryArray {
     /// Panics iff the data_type is not supported for the physical type.
     #[inline]
     pub fn to(self, data_type: DataType) -> Self {
+        self.try_to(data_type).unwrap()
+    }
+
+    /// Returns a new [`FixedSizeBinary`] with a different logical type, sharing its values
+    /// and validity (including their offsets) with `self`. This is `O(1)`.
+    /// # Error
+    /// Errors iff the data_type is not supported for the physical type.
+    #[inline]
+    pub fn try_to(self, data_type: DataType) -> Result<Self> {
         match (
             data_type.to_logical_type(),
             self.data_type().to_logical_type(),
         ) {
             (DataType::FixedSizeBinary(size_a), DataType::FixedSizeBinary(size_b))
                 if size_a == size_b => {}
-            _ => panic!("Wrong DataType"),
+            _ => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot change the data type of an array from {:?} to {:?} as their physical layouts differ",
+                    self.data_type, data_type
+                )))
+            }
         }
 
-        Self {
+        Ok(Self {
             size: self.size,
             data_type,
             values: self.values,
             validity: self.validity,
-        }
+        })
     }
 
     /// Returns the size
@@ -230,6 +250,21 @@ impl FixedSizeBinaryArray {
             .unwrap()
             .into()
     }
+
+    /// Creates a [`FixedSizeBinaryArray`] from a slice of `[u8]`.
+    /// # Errors
+    /// Errors iff the length of any of the items is not equal to `size`.
+    pub fn try_from_slice<T: AsRef<[u8]>, P: AsRef<[T]>>(slice: P, size: usize) -> Result<Self> {
+        MutableFixedSizeBinaryArray::try_from_iter(slice.as_ref().iter().map(Some), size)
+            .map(|x| x.into())
+    }
+
+    /// Creates a [`FixedSizeBinaryArray`] from a slice of `[u8]`.
+    /// # Panics
+    /// Panics iff the length of any of the items is not equal to `size`.
+    pub fn from_slice<T: AsRef<[u8]>, P: AsRef<[T]>>(slice: P, size: usize) -> Self {
+        Self::try_from_slice(slice, size).unwrap()
+    }
 }
 
 pub trait FixedSizeBinaryValues {
@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray},
+    array::{downcast_scalar, Array, MutableArray, TryExtend, TryPush},
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
+    scalar::{FixedSizeBinaryScalar, Scalar},
 };
 
 use super::{FixedSizeBinaryArray, FixedSizeBinaryValues};
@@ -128,6 +129,47 @@ impl MutableFixedSizeBinaryArray {
         Ok(primitive)
     }
 
+    /// Sets position `index` to `value`.
+    /// Note that if it is the first time a null appears in this array,
+    /// this initializes the validity bitmap (`O(N)`).
+    /// # Panic
+    /// Panics iff `index >= self.len()` or the size of `value` is not equal to its own size.
+    pub fn set(&mut self, index: usize, value: Option<&[u8]>) {
+        assert!(index < self.len());
+        let size = self.size;
+        let slot = &mut self.values[index * size..(index + 1) * size];
+        match value {
+            Some(bytes) => {
+                assert_eq!(
+                    bytes.len(),
+                    size,
+                    "FixedSizeBinaryArray requires every item to be of its length"
+                );
+                slot.copy_from_slice(bytes);
+            }
+            None => slot.iter_mut().for_each(|x| *x = 0),
+        }
+
+        if value.is_none() && self.validity.is_none() {
+            // When the validity is None, all elements so far are valid. When one of the elements is set fo null,
+            // the validity must be initialized.
+            let mut validity = MutableBitmap::new();
+            validity.extend_constant(self.len(), true);
+            self.validity = Some(validity);
+        }
+        if let Some(x) = self.validity.as_mut() {
+            x.set(index, value.is_some())
+        }
+    }
+
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional * self.size);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
     /// returns the (fixed) size of the [`MutableFixedSizeBinaryArray`].
     #[inline]
     pub fn size(&self) -> usize {
@@ -155,6 +197,15 @@ impl MutableFixedSizeBinaryArray {
         std::slice::from_raw_parts(self.values.as_ptr().add(i * self.size), self.size)
     }
 
+    /// Shortens the [`MutableFixedSizeBinaryArray`] to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    pub fn truncate(&mut self, len: usize) {
+        self.values.truncate(len * self.size);
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+    }
+
     /// Shrinks the capacity of the [`MutableFixedSizeBinaryArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -215,7 +266,16 @@ impl MutableArray for MutableFixedSizeBinaryArray {
     }
 
     fn push_null(&mut self) {
-        self.values.resize(self.values.len() + self.size, 0);
+        self.push::<&[u8]>(None);
+    }
+
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let value = downcast_scalar::<FixedSizeBinaryScalar>(&self.data_type, value)?;
+        self.try_push(value.value())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
     }
 
     fn shrink_to_fit(&mut self) {
@@ -235,6 +295,21 @@ impl FixedSizeBinaryValues for MutableFixedSizeBinaryArray {
     }
 }
 
+impl<P: AsRef<[u8]>> TryExtend<Option<P>> for MutableFixedSizeBinaryArray {
+    fn try_extend<I: IntoIterator<Item = Option<P>>>(&mut self, iter: I) -> Result<()> {
+        let mut iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.try_for_each(|x| self.try_push(x))
+    }
+}
+
+impl<P: AsRef<[u8]>> TryPush<Option<P>> for MutableFixedSizeBinaryArray {
+    #[inline]
+    fn try_push(&mut self, item: Option<P>) -> Result<()> {
+        self.try_push(item)
+    }
+}
+
 impl PartialEq for MutableFixedSizeBinaryArray {
     fn eq(&self, other: &Self) -> bool {
         self.iter().eq(other.iter())
@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryPush},
+    array::{downcast_scalar, Array, MutableArray, TryExtend, TryPush},
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
+    scalar::{new_scalar, FixedSizeListScalar, Scalar},
 };
 
 use super::FixedSizeListArray;
@@ -41,6 +42,22 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
         }
     }
 
+    /// Creates a new [`MutableFixedSizeListArray`] from an (empty) [`MutableArray`] and a
+    /// [`DataType::FixedSizeList`] whose child is of the data type of `values`.
+    /// # Panics
+    /// This function panics iff `values` is not empty or `data_type` is not a
+    /// [`DataType::FixedSizeList`].
+    pub fn new_from(values: M, data_type: DataType) -> Self {
+        let (_, size) = FixedSizeListArray::get_child_and_size(&data_type);
+        assert_eq!(values.len(), 0);
+        Self {
+            size,
+            data_type,
+            values,
+            validity: None,
+        }
+    }
+
     /// The inner values
     pub fn values(&self) -> &M {
         &self.values
@@ -74,6 +91,15 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
             None => self.init_validity(),
         }
     }
+    /// Shortens the [`MutableFixedSizeListArray`] to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    pub fn truncate(&mut self, len: usize) {
+        self.values.truncate(len * self.size);
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+    }
+
     /// Shrinks the capacity of the [`MutableFixedSizeListArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -132,6 +158,31 @@ impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
         }
     }
 
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let value = downcast_scalar::<FixedSizeListScalar>(&self.data_type, value)?;
+        if value.is_valid() {
+            let values = value.values().as_ref();
+            let size = self.values.len();
+            let result = (0..values.len()).try_for_each(|index| {
+                self.values
+                    .try_push_scalar(new_scalar(values, index).as_ref())
+            });
+            if let Err(error) = result.and_then(|_| self.try_push_valid()) {
+                // roll back the values of this row so that a failed push leaves the array unchanged
+                self.values.truncate(size);
+                return Err(error);
+            }
+            Ok(())
+        } else {
+            self.push_null();
+            Ok(())
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
@@ -61,7 +61,15 @@ impl<'a, O: Offset> GrowableBinary<'a, O> {
         let offsets = std::mem::take(&mut self.offsets);
         let values = std::mem::take(&mut self.values);
 
-        BinaryArray::<O>::from_data(data_type, offsets.into(), values.into(), validity.into())
+        // Safety: the offsets were extended from valid offsets of the bound arrays
+        unsafe {
+            BinaryArray::<O>::from_data_unchecked(
+                data_type,
+                offsets.into(),
+                values.into(),
+                validity.into(),
+            )
+        }
     }
 }
 
@@ -99,11 +107,14 @@ impl<'a, O: Offset> Growable<'a> for GrowableBinary<'a, O> {
 
 impl<'a, O: Offset> From<GrowableBinary<'a, O>> for BinaryArray<O> {
     fn from(val: GrowableBinary<'a, O>) -> Self {
-        BinaryArray::<O>::from_data(
-            val.data_type,
-            val.offsets.into(),
-            val.values.into(),
-            val.validity.into(),
-        )
+        // Safety: the offsets were extended from valid offsets of the bound arrays
+        unsafe {
+            BinaryArray::<O>::from_data_unchecked(
+                val.data_type,
+                val.offsets.into(),
+                val.values.into(),
+                val.validity.into(),
+            )
+        }
     }
 }
@@ -16,7 +16,7 @@ use super::{
 /// This growable does not perform collision checks and instead concatenates
 /// the values of each [`DictionaryArray`] one after the other.
 pub struct GrowableDictionary<'a, K: DictionaryKey> {
-    keys_values: Vec<&'a [K]>,
+    keys: Vec<&'a PrimitiveArray<K>>,
     key_values: Vec<K>,
     key_validity: MutableBitmap,
     offsets: Vec<usize>,
@@ -51,11 +51,6 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
         };
 
         let arrays_keys = arrays.iter().map(|array| array.keys()).collect::<Vec<_>>();
-        let keys_values = arrays_keys
-            .iter()
-            .map(|array| array.values().as_slice())
-            .collect::<Vec<_>>();
-
         let extend_null_bits = arrays
             .iter()
             .map(|array| build_extend_null_bits(array.keys(), use_validity))
@@ -71,7 +66,7 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
         Self {
             offsets,
             values,
-            keys_values,
+            keys: arrays_keys,
             key_values: Vec::with_capacity(capacity),
             key_validity: MutableBitmap::with_capacity(capacity),
             extend_null_bits,
@@ -95,14 +90,16 @@ impl<'a, T: DictionaryKey> Growable<'a> for GrowableDictionary<'a, T> {
     fn extend(&mut self, index: usize, start: usize, len: usize) {
         (self.extend_null_bits[index])(&mut self.key_validity, start, len);
 
-        let values = &self.keys_values[index][start..start + len];
+        let keys = self.keys[index];
         let offset = self.offsets[index];
-        self.key_values.extend(
-            values
-                .iter()
-                // `.unwrap_or(0)` because this operation does not check for null values, which may contain any key.
-                .map(|x| T::from_usize(offset + x.to_usize().unwrap_or(0)).unwrap()),
-        );
+        self.key_values.extend((start..start + len).map(|i| {
+            // null keys may contain any value, including one that overflows once offsetted
+            if keys.is_null(i) {
+                T::default()
+            } else {
+                T::from_usize(offset + keys.value(i).to_usize().unwrap()).unwrap()
+            }
+        }));
     }
 
     #[inline]
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MapArray},
+    bitmap::MutableBitmap,
+};
+
+use super::{
+    make_growable,
+    utils::{build_extend_null_bits, extend_offsets, ExtendNullBits},
+    Growable,
+};
+
+fn extend_offset_values(growable: &mut GrowableMap<'_>, index: usize, start: usize, len: usize) {
+    let array = growable.arrays[index];
+    let offsets = array.offsets();
+
+    if array.null_count() == 0 {
+        // offsets
+        extend_offsets::<i32>(
+            &mut growable.offsets,
+            &mut growable.last_offset,
+            &offsets[start..start + len + 1],
+        );
+
+        let end = offsets[start + len] as usize;
+        let start = offsets[start] as usize;
+        let len = end - start;
+        growable.values.extend(index, start, len)
+    } else {
+        growable.offsets.reserve(len);
+
+        let new_offsets = &mut growable.offsets;
+        let inner_values = &mut growable.values;
+        let last_offset = &mut growable.last_offset;
+        (start..start + len).for_each(|i| {
+            if array.is_valid(i) {
+                let len = offsets[i + 1] - offsets[i];
+                // compute the new offset
+                *last_offset += len;
+
+                // append value
+                inner_values.extend(index, offsets[i] as usize, len as usize);
+            }
+            // append offset
+            new_offsets.push(*last_offset);
+        })
+    }
+}
+
+/// Concrete [`Growable`] for the [`MapArray`].
+pub struct GrowableMap<'a> {
+    arrays: Vec<&'a MapArray>,
+    validity: MutableBitmap,
+    values: Box<dyn Growable<'a> + 'a>,
+    offsets: Vec<i32>,
+    last_offset: i32, // always equal to the last offset at `offsets`.
+    extend_null_bits: Vec<ExtendNullBits<'a>>,
+}
+
+impl<'a> GrowableMap<'a> {
+    /// Creates a new [`GrowableMap`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(arrays: Vec<&'a MapArray>, mut use_validity: bool, capacity: usize) -> Self {
+        // if any of the arrays has nulls, insertions from any array requires setting bits
+        // as there is at least one array with nulls.
+        if !use_validity & arrays.iter().any(|array| array.null_count() > 0) {
+            use_validity = true;
+        };
+
+        let extend_null_bits = arrays
+            .iter()
+            .map(|array| build_extend_null_bits(*array, use_validity))
+            .collect();
+
+        let inner = arrays
+            .iter()
+            .map(|array| array.field().as_ref())
+            .collect::<Vec<_>>();
+        let values = make_growable(&inner, false, 0);
+
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+
+        Self {
+            arrays,
+            offsets,
+            values,
+            validity: MutableBitmap::with_capacity(capacity),
+            last_offset: 0,
+            extend_null_bits,
+        }
+    }
+
+    fn to(&mut self) -> MapArray {
+        let validity = std::mem::take(&mut self.validity);
+        let offsets = std::mem::take(&mut self.offsets);
+        let values = self.values.as_arc();
+
+        MapArray::from_data(
+            self.arrays[0].data_type().clone(),
+            offsets.into(),
+            values,
+            validity.into(),
+        )
+    }
+}
+
+impl<'a> Growable<'a> for GrowableMap<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        (self.extend_null_bits[index])(&mut self.validity, start, len);
+        extend_offset_values(self, index, start, len);
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        self.offsets
+            .resize(self.offsets.len() + additional, self.last_offset);
+        self.validity.extend_constant(additional, false);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a> From<GrowableMap<'a>> for MapArray {
+    fn from(mut val: GrowableMap<'a>) -> Self {
+        val.to()
+    }
+}
@@ -16,8 +16,12 @@ mod primitive;
 pub use primitive::GrowablePrimitive;
 mod list;
 pub use list::GrowableList;
+mod map;
+pub use map::GrowableMap;
 mod structure;
 pub use structure::GrowableStruct;
+mod union;
+pub use union::GrowableUnion;
 mod fixed_size_list;
 pub use fixed_size_list::GrowableFixedSizeList;
 mod utf8;
@@ -111,7 +115,8 @@ pub fn make_growable<'a>(
             use_validity,
             capacity
         ),
-        Union | Map => todo!(),
+        Map => dyn_growable!(map::GrowableMap, arrays, use_validity, capacity),
+        Union => dyn_growable!(union::GrowableUnion, arrays, use_validity, capacity),
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 let arrays = arrays
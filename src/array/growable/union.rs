@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use crate::array::{Array, UnionArray};
+
+use super::{make_growable, Growable};
+
+/// Concrete [`Growable`] for the [`UnionArray`].
+pub struct GrowableUnion<'a> {
+    arrays: Vec<&'a UnionArray>,
+    types: Vec<i8>,
+    // `None` for sparse unions
+    offsets: Option<Vec<i32>>,
+    // the current length of each field (used to compute dense offsets)
+    lengths: Vec<i32>,
+    fields: Vec<Box<dyn Growable<'a> + 'a>>,
+}
+
+impl<'a> GrowableUnion<'a> {
+    /// Creates a new [`GrowableUnion`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(arrays: Vec<&'a UnionArray>, use_validity: bool, capacity: usize) -> Self {
+        let first = arrays[0];
+        let is_sparse = first.offsets().is_none();
+
+        let fields = (0..first.fields().len())
+            .map(|i| {
+                make_growable(
+                    &arrays
+                        .iter()
+                        .map(|x| x.fields()[i].as_ref())
+                        .collect::<Vec<_>>(),
+                    use_validity,
+                    capacity,
+                )
+            })
+            .collect::<Vec<Box<dyn Growable>>>();
+
+        Self {
+            lengths: vec![0; fields.len()],
+            arrays,
+            fields,
+            offsets: if is_sparse {
+                None
+            } else {
+                Some(Vec::with_capacity(capacity))
+            },
+            types: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn to(&mut self) -> UnionArray {
+        let types = std::mem::take(&mut self.types);
+        let fields = std::mem::take(&mut self.fields);
+        let offsets = std::mem::take(&mut self.offsets);
+        let fields = fields.into_iter().map(|mut x| x.as_arc()).collect();
+
+        UnionArray::from_data(
+            self.arrays[0].data_type().clone(),
+            types.into(),
+            fields,
+            offsets.map(|x| x.into()),
+        )
+    }
+}
+
+impl<'a> Growable<'a> for GrowableUnion<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        let array = self.arrays[index];
+
+        let types = &array.types()[start..start + len];
+        self.types.extend_from_slice(types);
+        if let Some(offsets) = self.offsets.as_mut() {
+            // in a dense union, each slot is copied from its field
+            let fields = &mut self.fields;
+            let lengths = &mut self.lengths;
+            (start..start + len).for_each(|i| {
+                let (field, slot) = array.index(i);
+                fields[field].extend(index, slot, 1);
+                offsets.push(lengths[field]);
+                lengths[field] += 1;
+            })
+        } else {
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend(index, start, len))
+        }
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        // nulls are represented as nulls of the first field
+        let type_ = UnionArray::get_type_ids(self.arrays[0].data_type())
+            .map(|ids| ids[0] as i8)
+            .unwrap_or(0);
+        self.types.resize(self.types.len() + additional, type_);
+        if let Some(offsets) = self.offsets.as_mut() {
+            self.fields[0].extend_validity(additional);
+            let length = self.lengths[0];
+            offsets.extend(length..length + additional as i32);
+            self.lengths[0] += additional as i32;
+        } else {
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend_validity(additional))
+        }
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a> From<GrowableUnion<'a>> for UnionArray {
+    fn from(mut val: GrowableUnion<'a>) -> Self {
+        val.to()
+    }
+}
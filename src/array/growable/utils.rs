@@ -3,14 +3,13 @@ use crate::{
     bitmap::MutableBitmap,
 };
 
+/// Extends `buffer` with `offsets[1..]` rebased so that `offsets[0]` maps to `last_offset`,
+/// updating `last_offset` accordingly.
 pub(super) fn extend_offsets<T: Offset>(buffer: &mut Vec<T>, last_offset: &mut T, offsets: &[T]) {
-    buffer.reserve(offsets.len() - 1);
-    offsets.windows(2).for_each(|offsets| {
-        // compute the new offset
-        let length = offsets[1] - offsets[0];
-        *last_offset += length;
-        buffer.push(*last_offset);
-    });
+    // a constant delta is added to every offset, which the compiler can vectorize
+    let delta = *last_offset - offsets[0];
+    buffer.extend(offsets[1..].iter().map(|offset| *offset + delta));
+    *last_offset += offsets[offsets.len() - 1] - offsets[0];
 }
 
 // function used to extend nulls from arrays. This function's lifetime is bound to the array
@@ -20,9 +19,7 @@ pub(super) type ExtendNullBits<'a> = Box<dyn Fn(&mut MutableBitmap, usize, usize
 pub(super) fn build_extend_null_bits(array: &dyn Array, use_validity: bool) -> ExtendNullBits {
     if let Some(bitmap) = array.validity() {
         Box::new(move |validity, start, len| {
-            assert!(start + len <= bitmap.len());
-            let (slice, offset, _) = bitmap.as_slice();
-            validity.extend_from_slice(slice, start + offset, len);
+            validity.extend_from_bitmap(bitmap, start, len);
         })
     } else if use_validity {
         Box::new(|validity, _, len| {
@@ -4,9 +4,14 @@ use crate::{
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::{DataType, Field},
+    error::{ArrowError, Result},
 };
 
-use super::{debug_fmt, new_empty_array, specification::check_offsets, Array, Offset};
+use super::{
+    debug_fmt, new_empty_array,
+    specification::{check_offsets, try_check_offsets},
+    Array, Offset,
+};
 
 mod ffi;
 mod iterator;
@@ -76,6 +81,54 @@ impl<O: Offset> ListArray<O> {
         }
     }
 
+    /// Returns a new [`ListArray`], validating all its invariants. Use this constructor when the
+    /// `offsets` come from an untrusted source.
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s physical type is not consistent with the offset `O`.
+    /// * The `data_type`'s inner type is not equal to the `values`' data type.
+    /// * The `offsets` are empty, not monotonically increasing, start with a negative value or
+    ///   are larger than the values' length.
+    /// * The validity is not `None` and its length is different from `offsets.len() - 1`.
+    pub fn try_new(
+        data_type: DataType,
+        offsets: Buffer<O>,
+        values: Arc<dyn Array>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self> {
+        try_check_offsets(&offsets, values.len())?;
+        if matches!(&validity, Some(validity) if validity.len() != offsets.len() - 1) {
+            return Err(ArrowError::oos(
+                "The validity length of a ListArray must equal the number of values",
+            ));
+        }
+
+        let child_data_type = match (O::is_large(), data_type.to_logical_type()) {
+            (false, DataType::List(child)) | (true, DataType::LargeList(child)) => {
+                child.data_type()
+            }
+            _ => {
+                return Err(ArrowError::oos(
+                    "ListArray<i32> expects DataType::List and ListArray<i64> expects DataType::LargeList",
+                ))
+            }
+        };
+        if child_data_type != values.data_type() {
+            return Err(ArrowError::oos(format!(
+                "The child's datatype ({:?}) must match the inner type of the 'data_type' ({:?})",
+                values.data_type(),
+                child_data_type
+            )));
+        }
+
+        Ok(Self {
+            data_type,
+            offsets,
+            values,
+            validity,
+        })
+    }
+
     /// Returns a slice of this [`ListArray`].
     /// # Panics
     /// panics iff `offset + length >= self.len()`
@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray, Offset, TryExtend, TryPush},
+    array::{downcast_scalar, Array, MutableArray, Offset, TryExtend, TryPush},
     bitmap::MutableBitmap,
     datatypes::{DataType, Field},
     error::{ArrowError, Result},
+    scalar::{new_scalar, ListScalar, Scalar},
 };
 
 use super::ListArray;
@@ -127,6 +128,9 @@ impl<O: Offset, M: MutableArray> MutableListArray<O, M> {
     #[inline]
     /// Needs to be called when a valid value was extended to this array.
     /// This is a relatively low level function, prefer `try_push` when you can.
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the length of the values exceeds `O`'s maximum value.
+    /// When `O = i32`, consider using a `LargeList` (`O = i64`) instead.
     pub fn try_push_valid(&mut self) -> Result<()> {
         let size = self.values.len();
         let size = O::from_usize(size).ok_or(ArrowError::Overflow)?;
@@ -139,6 +143,52 @@ impl<O: Offset, M: MutableArray> MutableListArray<O, M> {
         Ok(())
     }
 
+    /// Extends this array with new entries whose values were already pushed to
+    /// [`MutableListArray::mut_values`], one length per entry (`None` for a null entry).
+    /// This is a relatively low level function, prefer `try_extend` when you can.
+    /// # Errors
+    /// This function errors, without modifying the array, iff:
+    /// * the sum of all lengths is not equal to the number of values pushed since the last entry
+    /// * the offsets exceed `O`'s maximum value ([`ArrowError::Overflow`]). When `O = i32`,
+    ///   consider using a `LargeList` (`O = i64`) instead.
+    pub fn try_extend_from_lengths<II: IntoIterator<Item = Option<usize>>>(
+        &mut self,
+        lengths: II,
+    ) -> Result<()> {
+        let lengths = lengths.into_iter().collect::<Vec<_>>();
+
+        let start = self.last_offset().to_usize();
+        let total = lengths
+            .iter()
+            .flatten()
+            .try_fold(start, |acc, length| acc.checked_add(*length))
+            .ok_or(ArrowError::Overflow)?;
+        // offsets are monotonic: if the last one fits in `O`, all of them do
+        O::from_usize(total).ok_or(ArrowError::Overflow)?;
+        if total != self.values.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "the sum of all lengths ({}) must be equal to the number of new values ({})",
+                total - start,
+                self.values.len() - start
+            )));
+        }
+
+        self.offsets.reserve(lengths.len());
+        let mut size = start;
+        for length in lengths {
+            if let Some(length) = length {
+                size += length;
+                self.offsets.push(O::from_usize(size).unwrap());
+                if let Some(validity) = &mut self.validity {
+                    validity.push(true)
+                }
+            } else {
+                self.push_null();
+            }
+        }
+        Ok(())
+    }
+
     #[inline]
     fn push_null(&mut self) {
         self.offsets.push(self.last_offset());
@@ -183,6 +233,19 @@ impl<O: Offset, M: MutableArray> MutableListArray<O, M> {
         Arc::new(a)
     }
 
+    /// Shortens the [`MutableListArray`] to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    pub fn truncate(&mut self, len: usize) {
+        if len + 1 >= self.offsets.len() {
+            return;
+        }
+        self.offsets.truncate(len + 1);
+        self.values.truncate(self.offsets[len].to_usize());
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+    }
+
     /// Shrinks the capacity of the [`MutableListArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -193,7 +256,7 @@ impl<O: Offset, M: MutableArray> MutableListArray<O, M> {
     }
 }
 
-impl<O: Offset, M: MutableArray + Default + 'static> MutableArray for MutableListArray<O, M> {
+impl<O: Offset, M: MutableArray + 'static> MutableArray for MutableListArray<O, M> {
     fn len(&self) -> usize {
         self.offsets.len() - 1
     }
@@ -236,6 +299,32 @@ impl<O: Offset, M: MutableArray + Default + 'static> MutableArray for MutableLis
     fn push_null(&mut self) {
         self.push_null()
     }
+
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let value = downcast_scalar::<ListScalar<O>>(&self.data_type, value)?;
+        if value.is_valid() {
+            let values = value.values().as_ref();
+            let size = self.values.len();
+            let result = (0..values.len()).try_for_each(|index| {
+                self.values
+                    .try_push_scalar(new_scalar(values, index).as_ref())
+            });
+            if let Err(error) = result.and_then(|_| self.try_push_valid()) {
+                // roll back the values of this row so that a failed push leaves the array unchanged
+                self.values.truncate(size);
+                return Err(error);
+            }
+            Ok(())
+        } else {
+            self.push_null();
+            Ok(())
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit();
     }
@@ -6,14 +6,16 @@ use crate::{
     datatypes::{DataType, Field},
 };
 
-use super::{new_empty_array, specification::check_offsets, Array};
+use super::{debug_fmt, new_empty_array, specification::check_offsets, Array, StructArray};
 
 mod ffi;
 mod iterator;
 pub use iterator::*;
+mod mutable;
+pub use mutable::*;
 
 /// An array representing a (key, value), both of arbitrary logical types.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct MapArray {
     data_type: DataType,
     // invariant: field.len() == offsets.len() - 1
@@ -110,6 +112,18 @@ impl MapArray {
             validity,
         }
     }
+
+    /// Sets the validity bitmap on this [`MapArray`].
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
+            panic!("validity should be as least as large as the array")
+        }
+        let mut arr = self.clone();
+        arr.validity = validity;
+        arr
+    }
 }
 
 // Accessors
@@ -132,6 +146,30 @@ impl MapArray {
         &self.field
     }
 
+    /// Returns the keys of all entries of this [`MapArray`], i.e. the first child of [`MapArray::field`].
+    #[inline]
+    pub fn keys(&self) -> &Arc<dyn Array> {
+        &self.entries().values()[0]
+    }
+
+    /// Returns the values of all entries of this [`MapArray`], i.e. the second child of [`MapArray::field`].
+    #[inline]
+    pub fn values(&self) -> &Arc<dyn Array> {
+        &self.entries().values()[1]
+    }
+
+    /// Returns whether the keys of this [`MapArray`] are declared as sorted in its [`DataType`].
+    #[inline]
+    pub fn keys_sorted(&self) -> bool {
+        matches!(self.data_type.to_logical_type(), DataType::Map(_, true))
+    }
+
+    #[inline]
+    fn entries(&self) -> &StructArray {
+        // `from_data` guarantees that `field` is a `Struct` with two fields
+        self.field.as_any().downcast_ref::<StructArray>().unwrap()
+    }
+
     /// Returns the element at index `i`.
     #[inline]
     pub fn value(&self, i: usize) -> Box<dyn Array> {
@@ -187,7 +225,13 @@ impl Array for MapArray {
         Box::new(self.slice_unchecked(offset, length))
     }
 
-    fn with_validity(&self, _validity: Option<Bitmap>) -> Box<dyn Array> {
-        Box::new(self.clone())
+    fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
+        Box::new(self.with_validity(validity))
+    }
+}
+
+impl std::fmt::Debug for MapArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        debug_fmt(self.iter(), "MapArray", f, true)
     }
 }
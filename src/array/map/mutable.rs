@@ -0,0 +1,304 @@
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MutableArray, StructArray, TryExtend, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::{DataType, Field},
+    error::{ArrowError, Result},
+};
+
+use super::MapArray;
+
+/// The mutable version of [`MapArray`]. Each row is composed by zero or more
+/// `(key, value)` entries, whose keys and values are pushed to `K` and `V` respectively.
+#[derive(Debug)]
+pub struct MutableMapArray<K: MutableArray, V: MutableArray> {
+    data_type: DataType,
+    offsets: Vec<i32>,
+    keys: K,
+    values: V,
+    validity: Option<MutableBitmap>,
+}
+
+impl<K: MutableArray + Default, V: MutableArray + Default> MutableMapArray<K, V> {
+    /// Creates a new empty [`MutableMapArray`].
+    pub fn new() -> Self {
+        Self::new_with_capacity(K::default(), V::default(), 0)
+    }
+
+    /// Creates a new [`MutableMapArray`] with a capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::new_with_capacity(K::default(), V::default(), capacity)
+    }
+}
+
+impl<K: MutableArray + Default, V: MutableArray + Default> Default for MutableMapArray<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: MutableArray, V: MutableArray> MutableMapArray<K, V> {
+    /// Returns a default [`DataType`] of a map whose keys and values are of type `key` and `value`:
+    /// the entries are named "entries", "key" and "value", only values are nullable,
+    /// and keys are not sorted.
+    pub fn default_datatype(key: DataType, value: DataType) -> DataType {
+        let entries = DataType::Struct(vec![
+            Field::new("key", key, false),
+            Field::new("value", value, true),
+        ]);
+        DataType::Map(Box::new(Field::new("entries", entries, false)), false)
+    }
+
+    /// Creates a new [`MutableMapArray`] from a pair of (empty) [`MutableArray`]s, a [`DataType`]
+    /// and a capacity.
+    /// # Panics
+    /// This function panics iff:
+    /// * `keys` or `values` are not empty
+    /// * `data_type` is not a [`DataType::Map`] whose entries are a `Struct` with two fields
+    pub fn new_from(keys: K, values: V, data_type: DataType, capacity: usize) -> Self {
+        assert_eq!(keys.len(), 0);
+        assert_eq!(values.len(), 0);
+        if let DataType::Struct(inner) = MapArray::get_field(&data_type).data_type() {
+            assert_eq!(
+                inner.len(),
+                2,
+                "MapArray expects its inner `Struct` to have 2 fields (keys and maps)"
+            );
+        } else {
+            panic!("MapArray expects `DataType::Struct` as its inner logical type")
+        }
+
+        let mut offsets = Vec::<i32>::with_capacity(capacity + 1);
+        offsets.push(0);
+        Self {
+            data_type,
+            offsets,
+            keys,
+            values,
+            validity: None,
+        }
+    }
+
+    /// Creates a new [`MutableMapArray`] from a pair of (empty) [`MutableArray`]s and a capacity,
+    /// using [`MutableMapArray::default_datatype`].
+    pub fn new_with_capacity(keys: K, values: V, capacity: usize) -> Self {
+        let data_type =
+            Self::default_datatype(keys.data_type().clone(), values.data_type().clone());
+        Self::new_from(keys, values, data_type, capacity)
+    }
+
+    /// Needs to be called when a valid row was extended to this array, i.e. after
+    /// the keys and values of the row were pushed to [`Self::mut_keys`] and [`Self::mut_values`].
+    /// This is a relatively low level function, prefer `try_push` when you can.
+    /// # Errors
+    /// Errors if the number of keys and values differ or if the offsets overflow.
+    #[inline]
+    pub fn try_push_valid(&mut self) -> Result<()> {
+        if self.keys.len() != self.values.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "A MapArray must have the same number of keys and values".to_string(),
+            ));
+        }
+        let size = i32::try_from(self.keys.len()).map_err(|_| ArrowError::Overflow)?;
+        assert!(size >= *self.offsets.last().unwrap());
+
+        self.offsets.push(size);
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.offsets.push(*self.offsets.last().unwrap());
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.offsets.len() - 1;
+
+        let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// The offsets
+    pub fn offsets(&self) -> &Vec<i32> {
+        &self.offsets
+    }
+
+    /// The keys
+    pub fn keys(&self) -> &K {
+        &self.keys
+    }
+
+    /// The keys
+    pub fn mut_keys(&mut self) -> &mut K {
+        &mut self.keys
+    }
+
+    /// The values
+    pub fn values(&self) -> &V {
+        &self.values
+    }
+
+    /// The values
+    pub fn mut_values(&mut self) -> &mut V {
+        &mut self.values
+    }
+
+    fn take_entries(&mut self) -> Arc<dyn Array> {
+        let entries = MapArray::get_field(&self.data_type).data_type().clone();
+        Arc::new(StructArray::from_data(
+            entries,
+            vec![self.keys.as_arc(), self.values.as_arc()],
+            None,
+        ))
+    }
+
+    /// Converts itself into an [`Array`].
+    pub fn into_arc(self) -> Arc<dyn Array> {
+        let a: MapArray = self.into();
+        Arc::new(a)
+    }
+
+    /// Shortens the [`MutableMapArray`] to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    pub fn truncate(&mut self, len: usize) {
+        if len + 1 >= self.offsets.len() {
+            return;
+        }
+        self.offsets.truncate(len + 1);
+        let size = self.offsets[len] as usize;
+        self.keys.truncate(size);
+        self.values.truncate(size);
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+    }
+
+    /// Shrinks the capacity of the [`MutableMapArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+}
+
+impl<K: MutableArray, V: MutableArray> From<MutableMapArray<K, V>> for MapArray {
+    fn from(mut other: MutableMapArray<K, V>) -> Self {
+        let field = other.take_entries();
+        MapArray::from_data(
+            other.data_type,
+            other.offsets.into(),
+            field,
+            other.validity.map(|x| x.into()),
+        )
+    }
+}
+
+impl<K, V, I, KT, VT> TryPush<Option<I>> for MutableMapArray<K, V>
+where
+    K: MutableArray + TryPush<KT>,
+    V: MutableArray + TryPush<VT>,
+    I: IntoIterator<Item = (KT, VT)>,
+{
+    #[inline]
+    fn try_push(&mut self, item: Option<I>) -> Result<()> {
+        if let Some(entries) = item {
+            let size = self.keys.len();
+            let result = entries.into_iter().try_for_each(|(key, value)| {
+                self.keys.try_push(key)?;
+                self.values.try_push(value)
+            });
+            if let Err(error) = result.and_then(|_| self.try_push_valid()) {
+                // roll back the entries of this row so that keys and values stay aligned
+                self.keys.truncate(size);
+                self.values.truncate(size);
+                return Err(error);
+            }
+        } else {
+            self.push_null();
+        }
+        Ok(())
+    }
+}
+
+impl<K, V, I, KT, VT> TryExtend<Option<I>> for MutableMapArray<K, V>
+where
+    K: MutableArray + TryPush<KT>,
+    V: MutableArray + TryPush<VT>,
+    I: IntoIterator<Item = (KT, VT)>,
+{
+    fn try_extend<II: IntoIterator<Item = Option<I>>>(&mut self, iter: II) -> Result<()> {
+        for items in iter {
+            self.try_push(items)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: MutableArray + 'static, V: MutableArray + 'static> MutableArray for MutableMapArray<K, V> {
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        let field = self.take_entries();
+        Box::new(MapArray::from_data(
+            self.data_type.clone(),
+            std::mem::replace(&mut self.offsets, vec![0]).into(),
+            field,
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        ))
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        let field = self.take_entries();
+        Arc::new(MapArray::from_data(
+            self.data_type.clone(),
+            std::mem::replace(&mut self.offsets, vec![0]).into(),
+            field,
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        ))
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
+use crate::datatypes::PhysicalType;
+use crate::types::NativeType;
+
+use super::*;
+
+/// The memory regions and child arrays already counted, identified by their address.
+type Seen = HashSet<usize>;
+
+fn buffer<T: NativeType>(buffer: &Buffer<T>, seen: &mut Seen) -> usize {
+    if seen.insert(buffer.as_ptr().as_ptr() as usize) {
+        buffer.allocated_bytes()
+    } else {
+        0
+    }
+}
+
+fn bitmap(bitmap: &Bitmap, seen: &mut Seen) -> usize {
+    if seen.insert(bitmap.as_ptr().as_ptr() as usize) {
+        bitmap.allocated_bytes()
+    } else {
+        0
+    }
+}
+
+fn validity(validity: Option<&Bitmap>, seen: &mut Seen) -> usize {
+    validity.map(|x| bitmap(x, seen)).unwrap_or(0)
+}
+
+fn child(array: &Arc<dyn Array>, overhead: bool, seen: &mut Seen) -> usize {
+    if seen.insert(Arc::as_ptr(array) as *const u8 as usize) {
+        memory_size(array.as_ref(), overhead, seen)
+    } else {
+        0
+    }
+}
+
+macro_rules! dyn_binary {
+    ($array:expr, $ty:ty, $seen:expr) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        buffer(array.offsets(), $seen)
+            + buffer(array.values(), $seen)
+            + validity(array.validity(), $seen)
+    }};
+}
+
+macro_rules! dyn_list {
+    ($array:expr, $ty:ty, $overhead:expr, $seen:expr) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        buffer(array.offsets(), $seen)
+            + validity(array.validity(), $seen)
+            + child(array.values(), $overhead, $seen)
+    }};
+}
+
+/// Returns the size of the memory regions of `array` and of its children that were not yet `seen`,
+/// plus, when `overhead` is true, the size of the structs of `array` and of its children.
+fn memory_size<A: Array + ?Sized>(array: &A, overhead: bool, seen: &mut Seen) -> usize {
+    use PhysicalType::*;
+    let buffers = match array.data_type().to_physical_type() {
+        Null => 0,
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            bitmap(array.values(), seen) + validity(array.validity(), seen)
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$T>>()
+                .unwrap();
+            buffer(array.values(), seen) + validity(array.validity(), seen)
+        }),
+        Binary => dyn_binary!(array, BinaryArray<i32>, seen),
+        LargeBinary => dyn_binary!(array, BinaryArray<i64>, seen),
+        Utf8 => dyn_binary!(array, Utf8Array<i32>, seen),
+        LargeUtf8 => dyn_binary!(array, Utf8Array<i64>, seen),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            buffer(array.values(), seen) + validity(array.validity(), seen)
+        }
+        List => dyn_list!(array, ListArray<i32>, overhead, seen),
+        LargeList => dyn_list!(array, ListArray<i64>, overhead, seen),
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            validity(array.validity(), seen) + child(array.values(), overhead, seen)
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            validity(array.validity(), seen)
+                + array
+                    .values()
+                    .iter()
+                    .map(|x| child(x, overhead, seen))
+                    .sum::<usize>()
+        }
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            buffer(array.types(), seen)
+                + array
+                    .offsets()
+                    .as_ref()
+                    .map(|x| buffer(x, seen))
+                    .unwrap_or(0)
+                + array
+                    .fields()
+                    .iter()
+                    .map(|x| child(x, overhead, seen))
+                    .sum::<usize>()
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            // the keys are part of the struct of the dictionary: only their regions are counted
+            memory_size(array.keys(), false, seen) + child(array.values(), overhead, seen)
+        }),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            buffer(array.offsets(), seen)
+                + validity(array.validity(), seen)
+                + child(array.field(), overhead, seen)
+        }
+    };
+    if overhead {
+        buffers + std::mem::size_of_val(array)
+    } else {
+        buffers
+    }
+}
+
+pub(super) fn buffer_memory_size<A: Array + ?Sized>(array: &A) -> usize {
+    memory_size(array, false, &mut Seen::new())
+}
+
+pub(super) fn array_memory_size<A: Array + ?Sized>(array: &A) -> usize {
+    memory_size(array, true, &mut Seen::new())
+}
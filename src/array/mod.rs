@@ -11,6 +11,7 @@
 //! * [`BinaryArray`] and [`MutableBinaryArray`], an array of opaque variable length values
 //! * [`ListArray`] and [`MutableListArray`], an array of arrays (e.g. `[[1, 2], None, [], [None]]`)
 //! * [`StructArray`], an array of arrays identified by a string (e.g. `{"a": [1, 2], "b": [true, false]}`)
+//! * [`MapArray`] and [`MutableMapArray`], an array of `(key, value)` entries (e.g. `[{"a": 1}, None, {}]`)
 //! All immutable arrays implement the trait object [`Array`] and that can be downcasted
 //! to a concrete struct based on [`PhysicalType`](crate::datatypes::PhysicalType) available from [`Array::data_type`].
 //! All immutable arrays are backed by [`Buffer`](crate::buffer::Buffer) and thus cloning and slicing them is `O(1)`.
@@ -19,7 +20,8 @@
 //! can be operated in-place.
 use std::any::Any;
 
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
+use crate::scalar::Scalar;
 use crate::types::{days_ms, months_days_ns};
 use crate::{
     bitmap::{Bitmap, MutableBitmap},
@@ -103,6 +105,39 @@ pub trait Array: Send + Sync {
     /// # Panic
     /// This function panics iff `validity.len() < self.len()`.
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array>;
+
+    /// Returns the size in bytes of the memory regions (buffers and bitmaps) backing this
+    /// [`Array`] and its children, i.e. the memory that is kept alive by it.
+    /// # Implementation
+    /// Each memory region is counted once, by its allocated capacity, regardless of how many
+    /// times and with which offset and length it is referenced by this [`Array`] and its children.
+    /// Therefore, a sliced array reports the size of the regions it shares with the original array,
+    /// and regions shared by different arrays are counted in the size of each of them.
+    /// Regions allocated outside of Rust (e.g. via FFI) are counted by their length.
+    ///
+    /// This is `O(C)` where `C` is the number of children of this [`Array`], recursively.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{Array, Int32Array};
+    /// use arrow2::bitmap::Bitmap;
+    /// use arrow2::datatypes::DataType;
+    ///
+    /// let validity = Bitmap::from_u8_vec(vec![0b101], 3);
+    /// let array = Int32Array::from_data(DataType::Int32, vec![1, 2, 3].into(), Some(validity));
+    /// // 3 * 4 bytes of values and 1 byte of validity
+    /// assert_eq!(array.get_buffer_memory_size(), 13);
+    /// assert_eq!(array.slice(1, 1).get_buffer_memory_size(), 13);
+    /// ```
+    fn get_buffer_memory_size(&self) -> usize {
+        memory::buffer_memory_size(self)
+    }
+
+    /// Returns the size in bytes of this [`Array`]: the size of its memory regions, as
+    /// in [`Array::get_buffer_memory_size`], plus the size of its struct and of the structs of
+    /// its children.
+    fn get_array_memory_size(&self) -> usize {
+        memory::array_memory_size(self)
+    }
 }
 
 /// A trait describing a mutable array; i.e. an array whose values can be changed.
@@ -144,6 +179,26 @@ pub trait MutableArray: std::fmt::Debug + Send + Sync {
     /// Adds a new null element to the array.
     fn push_null(&mut self);
 
+    /// Adds `additional` null elements to the array.
+    fn extend_nulls(&mut self, additional: usize) {
+        (0..additional).for_each(|_| self.push_null())
+    }
+
+    /// Adds the value of `value` to the array, or a null element if `value` is null.
+    /// This allows building arrays from type-erased values without downcasting the array.
+    /// # Errors
+    /// This function errors iff
+    /// * the [`DataType`] of `value` is not the [`DataType`] of the array
+    /// * the array does not support pushing scalars (e.g. [`MutableMapArray`])
+    /// * pushing the value errors, e.g. when the offsets of the array overflow
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let _ = value;
+        Err(ArrowError::NotYetImplemented(format!(
+            "pushing scalars to a mutable array of {:?}",
+            self.data_type()
+        )))
+    }
+
     /// Whether `index` is valid / set.
     /// # Panic
     /// Panics if `index >= self.len()`.
@@ -155,10 +210,102 @@ pub trait MutableArray: std::fmt::Debug + Send + Sync {
             .unwrap_or(true)
     }
 
+    /// Shortens the array to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to the length of the array.
+    /// Nested arrays use it to roll back children when pushing a value fails half-way.
+    /// # Panics
+    /// The provided implementation panics iff `len` is smaller than the length of the array;
+    /// the mutable arrays of this crate override it.
+    fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            panic!(
+                "truncating a mutable array of {:?} is not implemented",
+                self.data_type()
+            )
+        }
+    }
+
     /// Shrink the array to fit its length.
     fn shrink_to_fit(&mut self);
 }
 
+impl MutableArray for Box<dyn MutableArray> {
+    fn data_type(&self) -> &DataType {
+        (**self).data_type()
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        (**self).validity()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        (**self).as_box()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        (**self).as_arc()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        (**self).as_any()
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn Any {
+        (**self).as_mut_any()
+    }
+
+    fn push_null(&mut self) {
+        (**self).push_null()
+    }
+
+    fn extend_nulls(&mut self, additional: usize) {
+        (**self).extend_nulls(additional)
+    }
+
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        (**self).try_push_scalar(value)
+    }
+
+    fn is_valid(&self, index: usize) -> bool {
+        (**self).is_valid(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        (**self).truncate(len)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        (**self).shrink_to_fit()
+    }
+}
+
+/// Returns `value` downcasted to `S`.
+/// # Errors
+/// Errors iff `value` is not of `data_type` or it is not an `S`.
+pub(crate) fn downcast_scalar<'a, S: Scalar + 'static>(
+    data_type: &DataType,
+    value: &'a dyn Scalar,
+) -> Result<&'a S> {
+    if value.data_type() != data_type {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "A scalar of {:?} cannot be pushed to a mutable array of {:?}",
+            value.data_type(),
+            data_type
+        )));
+    }
+    value.as_any().downcast_ref::<S>().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "A scalar of {:?} must be a {}",
+            data_type,
+            std::any::type_name::<S>()
+        ))
+    })
+}
+
 macro_rules! general_dyn {
     ($array:expr, $ty:ty, $f:expr) => {{
         let array = $array.as_any().downcast_ref::<$ty>().unwrap();
@@ -195,7 +342,7 @@ macro_rules! with_match_primitive_type {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::{days_ms, months_days_ns};
+    use crate::types::{days_ms, months_days_ns, f16};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
@@ -208,6 +355,7 @@ macro_rules! with_match_primitive_type {(
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -237,7 +385,7 @@ impl std::fmt::Debug for dyn Array + '_ {
                     fmt_dyn!(self, DictionaryArray::<$T>, f)
                 })
             }
-            Map => todo!(),
+            Map => fmt_dyn!(self, MapArray, f),
         }
     }
 }
@@ -300,6 +448,125 @@ pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
     }
 }
 
+/// Creates a new empty [`MutableArray`] of [`DataType`] `data_type` with capacity for
+/// `capacity` elements. Nested types are backed by `Box<dyn MutableArray>` children, so that
+/// the array can be built via [`MutableArray::try_push_scalar`] and
+/// [`MutableArray::extend_nulls`] without downcasting it.
+/// # Example
+/// ```rust
+/// use arrow2::array::{mutable_from_data_type, Array, Int32Array};
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::PrimitiveScalar;
+/// # fn main() -> arrow2::error::Result<()> {
+/// let mut array = mutable_from_data_type(&DataType::Int32, 2)?;
+/// array.try_push_scalar(&PrimitiveScalar::new(DataType::Int32, Some(1i32)))?;
+/// array.extend_nulls(2);
+///
+/// let array = array.as_box();
+/// assert_eq!(array.as_ref(), &Int32Array::from(&[Some(1), None, None]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors with [`ArrowError::NotYetImplemented`] iff `data_type` is or contains a
+/// [`DataType::Null`] or a [`DataType::Union`], for which there is no [`MutableArray`], or a
+/// [`DataType::Map`], whose [`MutableMapArray`] does not support
+/// [`MutableArray::try_push_scalar`].
+pub fn mutable_from_data_type(
+    data_type: &DataType,
+    capacity: usize,
+) -> Result<Box<dyn MutableArray>> {
+    use crate::datatypes::PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Null | Union | Map => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "a MutableArray of {:?} that supports pushing scalars",
+                data_type
+            )))
+        }
+        Boolean => Box::new(MutableBooleanArray::from_data(
+            data_type.clone(),
+            MutableBitmap::with_capacity(capacity),
+            None,
+        )),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            Box::new(MutablePrimitiveArray::<$T>::with_capacity_from(capacity, data_type.clone()))
+        }),
+        Binary => Box::new(MutableBinaryArray::<i32>::from_data(
+            data_type.clone(),
+            vec![0],
+            vec![],
+            None,
+        )),
+        LargeBinary => Box::new(MutableBinaryArray::<i64>::from_data(
+            data_type.clone(),
+            vec![0],
+            vec![],
+            None,
+        )),
+        FixedSizeBinary => {
+            let size = FixedSizeBinaryArray::get_size(data_type);
+            Box::new(MutableFixedSizeBinaryArray::from_data(
+                data_type.clone(),
+                Vec::with_capacity(capacity * size),
+                None,
+            ))
+        }
+        Utf8 => Box::new(MutableUtf8Array::<i32>::from_data(
+            data_type.clone(),
+            vec![0],
+            vec![],
+            None,
+        )),
+        LargeUtf8 => Box::new(MutableUtf8Array::<i64>::from_data(
+            data_type.clone(),
+            vec![0],
+            vec![],
+            None,
+        )),
+        List => {
+            let values = mutable_from_data_type(ListArray::<i32>::get_child_type(data_type), 0)?;
+            Box::new(MutableListArray::<i32, _>::new_from(
+                values,
+                data_type.clone(),
+                capacity,
+            ))
+        }
+        LargeList => {
+            let values = mutable_from_data_type(ListArray::<i64>::get_child_type(data_type), 0)?;
+            Box::new(MutableListArray::<i64, _>::new_from(
+                values,
+                data_type.clone(),
+                capacity,
+            ))
+        }
+        FixedSizeList => {
+            let (field, size) = FixedSizeListArray::get_child_and_size(data_type);
+            let values = mutable_from_data_type(field.data_type(), capacity * size)?;
+            Box::new(MutableFixedSizeListArray::new_from(
+                values,
+                data_type.clone(),
+            ))
+        }
+        Struct => {
+            let values = StructArray::get_fields(data_type)
+                .iter()
+                .map(|field| mutable_from_data_type(field.data_type(), capacity))
+                .collect::<Result<_>>()?;
+            Box::new(MutableStructArray::new(data_type.clone(), values))
+        }
+        Dictionary(key_type) => {
+            let values = match data_type.to_logical_type() {
+                DataType::Dictionary(_, values, _) => mutable_from_data_type(values, 0)?,
+                _ => unreachable!(),
+            };
+            match_integer_type!(key_type, |$T| {
+                Box::new(MutableDictionaryArray::<$T, _>::from(values))
+            })
+        }
+    })
+}
+
 macro_rules! clone_dyn {
     ($array:expr, $ty:ty) => {{
         let f = |x: &$ty| Box::new(x.clone());
@@ -342,12 +609,15 @@ mod binary;
 mod boolean;
 mod dictionary;
 mod display;
+mod downcast;
 mod fixed_size_binary;
 mod fixed_size_list;
 mod list;
 mod map;
+mod memory;
 mod null;
 mod primitive;
+mod retype;
 mod specification;
 mod struct_;
 mod union;
@@ -358,21 +628,23 @@ mod ffi;
 pub mod growable;
 pub mod ord;
 
-pub use display::get_display;
+pub use display::{get_display, get_value_display};
+pub use downcast::AsArray;
 pub use equal::equal;
 
 pub use crate::types::Offset;
 pub use binary::{BinaryArray, BinaryValueIter, MutableBinaryArray};
 pub use boolean::{BooleanArray, MutableBooleanArray};
-pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray};
+pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray, ValueEq};
 pub use fixed_size_binary::{FixedSizeBinaryArray, MutableFixedSizeBinaryArray};
 pub use fixed_size_list::{FixedSizeListArray, MutableFixedSizeListArray};
 pub use list::{ListArray, MutableListArray};
-pub use map::MapArray;
+pub use map::{MapArray, MutableMapArray};
 pub use null::NullArray;
 pub use primitive::*;
-pub use struct_::StructArray;
-pub use union::UnionArray;
+pub use retype::to_data_type;
+pub use struct_::{MutableStructArray, StructArray};
+pub use union::{MutableUnionArray, UnionArray};
 pub use utf8::{MutableUtf8Array, Utf8Array, Utf8ValuesIter};
 
 pub(crate) use self::ffi::offset_buffers_children_dictionary;
@@ -37,6 +37,30 @@ pub fn total_cmp_f64(l: &f64, r: &f64) -> std::cmp::Ordering {
     left.cmp(&right)
 }
 
+macro_rules! total_cmp_with_nans {
+    ($name:ident, $ty:ty, $total_cmp:ident) => {
+        /// Compares two floats according to IEEE 754 total ordering, except that every NaN,
+        /// regardless of its sign and payload, is equal to any other NaN and greater than any
+        /// other value (or smaller, when `nans_greatest` is `false`).
+        ///
+        /// Like IEEE 754 total ordering, this ordering considers `-0.0` smaller than `+0.0`.
+        #[inline]
+        pub fn $name(l: &$ty, r: &$ty, nans_greatest: bool) -> Ordering {
+            match (l.is_nan(), r.is_nan()) {
+                (false, false) => $total_cmp(l, r),
+                (true, true) => Ordering::Equal,
+                (true, false) if nans_greatest => Ordering::Greater,
+                (false, true) if nans_greatest => Ordering::Less,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+            }
+        }
+    };
+}
+
+total_cmp_with_nans!(total_cmp_f32_with_nans, f32, total_cmp_f32);
+total_cmp_with_nans!(total_cmp_f64_with_nans, f64, total_cmp_f64);
+
 /// Total order of all native types whose Rust implementation
 /// that support total order.
 #[inline]
@@ -75,7 +99,7 @@ fn compare_boolean(left: &dyn Array, right: &dyn Array) -> DynComparator {
     Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
 }
 
-fn compare_f32(left: &dyn Array, right: &dyn Array) -> DynComparator {
+fn compare_f32(left: &dyn Array, right: &dyn Array, nans_greatest: bool) -> DynComparator {
     let left = left
         .as_any()
         .downcast_ref::<PrimitiveArray<f32>>()
@@ -86,10 +110,10 @@ fn compare_f32(left: &dyn Array, right: &dyn Array) -> DynComparator {
         .downcast_ref::<PrimitiveArray<f32>>()
         .unwrap()
         .clone();
-    Box::new(move |i, j| total_cmp_f32(&left.value(i), &right.value(j)))
+    Box::new(move |i, j| total_cmp_f32_with_nans(&left.value(i), &right.value(j), nans_greatest))
 }
 
-fn compare_f64(left: &dyn Array, right: &dyn Array) -> DynComparator {
+fn compare_f64(left: &dyn Array, right: &dyn Array, nans_greatest: bool) -> DynComparator {
     let left = left
         .as_any()
         .downcast_ref::<PrimitiveArray<f64>>()
@@ -100,7 +124,7 @@ fn compare_f64(left: &dyn Array, right: &dyn Array) -> DynComparator {
         .downcast_ref::<PrimitiveArray<f64>>()
         .unwrap()
         .clone();
-    Box::new(move |i, j| total_cmp_f64(&left.value(i), &right.value(j)))
+    Box::new(move |i, j| total_cmp_f64_with_nans(&left.value(i), &right.value(j), nans_greatest))
 }
 
 fn compare_string<O: Offset>(left: &dyn Array, right: &dyn Array) -> DynComparator {
@@ -131,14 +155,22 @@ fn compare_binary<O: Offset>(left: &dyn Array, right: &dyn Array) -> DynComparat
     Box::new(move |i, j| left.value(i).cmp(right.value(j)))
 }
 
-fn compare_dict<K>(left: &DictionaryArray<K>, right: &DictionaryArray<K>) -> Result<DynComparator>
+fn compare_dict<K>(
+    left: &DictionaryArray<K>,
+    right: &DictionaryArray<K>,
+    nans_greatest: bool,
+) -> Result<DynComparator>
 where
     K: DictionaryKey,
 {
     let left_keys = left.keys().values().clone();
     let right_keys = right.keys().values().clone();
 
-    let comparator = build_compare(left.values().as_ref(), right.values().as_ref())?;
+    let comparator = build_compare_with_nans(
+        left.values().as_ref(),
+        right.values().as_ref(),
+        nans_greatest,
+    )?;
 
     Ok(Box::new(move |i: usize, j: usize| {
         let key_left = left_keys[i].to_usize().unwrap();
@@ -148,10 +180,10 @@ where
 }
 
 macro_rules! dyn_dict {
-    ($key:ty, $lhs:expr, $rhs:expr) => {{
+    ($key:ty, $lhs:expr, $rhs:expr, $nans_greatest:expr) => {{
         let lhs = $lhs.as_any().downcast_ref().unwrap();
         let rhs = $rhs.as_any().downcast_ref().unwrap();
-        compare_dict::<$key>(lhs, rhs)?
+        compare_dict::<$key>(lhs, rhs, $nans_greatest)?
     }};
 }
 
@@ -174,8 +206,24 @@ macro_rules! dyn_dict {
 /// ```
 /// # Error
 /// The arrays' [`DataType`] must be equal and the types must have a natural order.
+/// # Floats
+/// Floats are compared with [`total_cmp_f32_with_nans`] and [`total_cmp_f64_with_nans`],
+/// with NaNs greater than any other value. Use [`build_compare_with_nans`] to order them
+/// otherwise.
 // This is a factory of comparisons.
 pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    build_compare_with_nans(left, right, true)
+}
+
+/// Same as [`build_compare`], but NaNs are greater than any other value iff `nans_greatest`
+/// and smaller than any other value otherwise.
+/// # Error
+/// The arrays' [`DataType`] must be equal and the types must have a natural order.
+pub fn build_compare_with_nans(
+    left: &dyn Array,
+    right: &dyn Array,
+    nans_greatest: bool,
+) -> Result<DynComparator> {
     use DataType::*;
     use IntervalUnit::*;
     use TimeUnit::*;
@@ -209,22 +257,36 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         | (Duration(Millisecond), Duration(Millisecond))
         | (Duration(Microsecond), Duration(Microsecond))
         | (Duration(Nanosecond), Duration(Nanosecond)) => compare_primitives::<i64>(left, right),
-        (Float32, Float32) => compare_f32(left, right),
-        (Float64, Float64) => compare_f64(left, right),
+        (Float32, Float32) => compare_f32(left, right, nans_greatest),
+        (Float64, Float64) => compare_f64(left, right, nans_greatest),
         (Utf8, Utf8) => compare_string::<i32>(left, right),
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
         (Binary, Binary) => compare_binary::<i32>(left, right),
         (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
         (Dictionary(key_type_lhs, ..), Dictionary(key_type_rhs, ..)) => {
             match (key_type_lhs, key_type_rhs) {
-                (IntegerType::UInt8, IntegerType::UInt8) => dyn_dict!(u8, left, right),
-                (IntegerType::UInt16, IntegerType::UInt16) => dyn_dict!(u16, left, right),
-                (IntegerType::UInt32, IntegerType::UInt32) => dyn_dict!(u32, left, right),
-                (IntegerType::UInt64, IntegerType::UInt64) => dyn_dict!(u64, left, right),
-                (IntegerType::Int8, IntegerType::Int8) => dyn_dict!(i8, left, right),
-                (IntegerType::Int16, IntegerType::Int16) => dyn_dict!(i16, left, right),
-                (IntegerType::Int32, IntegerType::Int32) => dyn_dict!(i32, left, right),
-                (IntegerType::Int64, IntegerType::Int64) => dyn_dict!(i64, left, right),
+                (IntegerType::UInt8, IntegerType::UInt8) => {
+                    dyn_dict!(u8, left, right, nans_greatest)
+                }
+                (IntegerType::UInt16, IntegerType::UInt16) => {
+                    dyn_dict!(u16, left, right, nans_greatest)
+                }
+                (IntegerType::UInt32, IntegerType::UInt32) => {
+                    dyn_dict!(u32, left, right, nans_greatest)
+                }
+                (IntegerType::UInt64, IntegerType::UInt64) => {
+                    dyn_dict!(u64, left, right, nans_greatest)
+                }
+                (IntegerType::Int8, IntegerType::Int8) => dyn_dict!(i8, left, right, nans_greatest),
+                (IntegerType::Int16, IntegerType::Int16) => {
+                    dyn_dict!(i16, left, right, nans_greatest)
+                }
+                (IntegerType::Int32, IntegerType::Int32) => {
+                    dyn_dict!(i32, left, right, nans_greatest)
+                }
+                (IntegerType::Int64, IntegerType::Int64) => {
+                    dyn_dict!(i64, left, right, nans_greatest)
+                }
                 (lhs, _) => {
                     return Err(ArrowError::InvalidArgumentError(format!(
                         "Dictionaries do not support keys of type {:?}",
@@ -1,8 +1,10 @@
+use either::Either;
+
 use crate::{
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::*,
-    error::ArrowError,
+    error::{ArrowError, Result},
     types::{days_ms, months_days_ns, NativeType},
 };
 
@@ -170,20 +172,93 @@ impl<T: NativeType> PrimitiveArray<T> {
     /// Panics iff the data_type is not supported for the physical type.
     #[inline]
     pub fn to(self, data_type: DataType) -> Self {
+        self.try_to(data_type).unwrap()
+    }
+
+    /// Returns a new [`PrimitiveArray`] with a different logical type, sharing its values
+    /// and validity (including their offsets) with `self`. This is `O(1)`.
+    /// # Error
+    /// Errors iff the data_type is not supported for the physical type.
+    #[inline]
+    pub fn try_to(self, data_type: DataType) -> Result<Self> {
         if !data_type.to_physical_type().eq_primitive(T::PRIMITIVE) {
-            Err(ArrowError::InvalidArgumentError(format!(
-                "Type {} does not support logical type {:?}",
-                std::any::type_name::<T>(),
-                data_type
-            )))
-            .unwrap()
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot change the data type of an array from {:?} to {:?} as their physical layouts differ",
+                self.data_type, data_type
+            )));
         }
-        Self {
+        Ok(Self {
             data_type,
             values: self.values,
             validity: self.validity,
+        })
+    }
+
+    /// Tries to convert this [`PrimitiveArray`] into a [`MutablePrimitiveArray`] without
+    /// copying, so that its memory can be re-used (e.g. by in-place kernels).
+    /// This succeeds iff both its values and validity are not shared and not offsetted
+    /// (see [`Buffer::into_mut`]). Otherwise, returns itself back.
+    /// # Example
+    /// ```
+    /// use arrow2::array::PrimitiveArray;
+    ///
+    /// let array = PrimitiveArray::from_slice([1i32, 2, 3]);
+    /// let mut array = array.into_mut().unwrap_right();
+    /// array.values_mut_slice()[0] = 10;
+    /// let array: PrimitiveArray<i32> = array.into();
+    /// assert_eq!(array, PrimitiveArray::from_slice([10, 2, 3]));
+    /// ```
+    pub fn into_mut(self) -> Either<Self, MutablePrimitiveArray<T>> {
+        let Self {
+            data_type,
+            values,
+            validity,
+        } = self;
+        let validity = match validity.map(|x| x.into_mut()) {
+            Some(Either::Left(validity)) => {
+                return Either::Left(Self::from_data(data_type, values, Some(validity)))
+            }
+            Some(Either::Right(validity)) => Some(validity),
+            None => None,
+        };
+        match values.into_mut() {
+            Either::Left(values) => Either::Left(Self::from_data(
+                data_type,
+                values,
+                validity.map(|x| x.into()),
+            )),
+            Either::Right(values) => Either::Right(MutablePrimitiveArray::from_data(
+                data_type, values, validity,
+            )),
         }
     }
+
+    /// Tries to convert this [`PrimitiveArray`] into the [`Vec`] of its values without copying.
+    /// This succeeds iff it has no null values and its values are not shared and not offsetted
+    /// (see [`Buffer::into_vec`]). Otherwise, returns itself back.
+    /// # Example
+    /// ```
+    /// use arrow2::array::PrimitiveArray;
+    ///
+    /// let vec = vec![1i32, 2, 3];
+    /// let ptr = vec.as_ptr();
+    /// let array = PrimitiveArray::from_vec(vec);
+    /// let vec = array.into_vec().unwrap();
+    /// assert_eq!(vec.as_ptr(), ptr);
+    /// ```
+    pub fn into_vec(self) -> std::result::Result<Vec<T>, Self> {
+        if self.null_count() > 0 {
+            return Err(self);
+        }
+        let Self {
+            data_type,
+            values,
+            validity,
+        } = self;
+        values
+            .into_vec()
+            .map_err(|values| Self::from_data(data_type, values, validity))
+    }
 }
 
 impl<T: NativeType> Array for PrimitiveArray<T> {
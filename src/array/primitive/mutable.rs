@@ -1,10 +1,11 @@
 use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryPush},
+    array::{downcast_scalar, Array, MutableArray, TryExtend, TryPush},
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
+    scalar::{PrimitiveScalar, Scalar},
     trusted_len::TrustedLen,
     types::NativeType,
 };
@@ -77,6 +78,15 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
     pub fn into_data(self) -> (DataType, Vec<T>, Option<MutableBitmap>) {
         (self.data_type, self.values, self.validity)
     }
+
+    /// Converts itself into the [`Vec`] of its values, returning itself back iff it has
+    /// null values.
+    pub fn into_vec(self) -> std::result::Result<Vec<T>, Self> {
+        match &self.validity {
+            Some(validity) if validity.null_count() > 0 => Err(self),
+            _ => Ok(self.values),
+        }
+    }
 }
 
 impl<T: NativeType> Default for MutablePrimitiveArray<T> {
@@ -255,6 +265,15 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
         Arc::new(a)
     }
 
+    /// Shortens the [`MutablePrimitiveArray`] to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    pub fn truncate(&mut self, len: usize) {
+        self.values.truncate(len);
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+    }
+
     /// Shrinks the capacity of the [`MutablePrimitiveArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -316,6 +335,22 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
         assert_eq!(values.len(), self.values.len());
         self.values = values;
     }
+
+    /// Applies a function `f` to the values of this array, in place.
+    /// This does not change the validity of the array, nor does it reallocate its values.
+    pub fn apply_values<F: FnOnce(&mut [T])>(&mut self, f: F) {
+        f(&mut self.values);
+    }
+
+    /// Applies a function `f` to the validity of this array, if any.
+    /// # Panic
+    /// Panics iff `f` changes the length of the validity.
+    pub fn apply_validity<F: FnOnce(&mut MutableBitmap)>(&mut self, f: F) {
+        if let Some(validity) = self.validity.as_mut() {
+            f(validity);
+            assert_eq!(validity.len(), self.values.len());
+        }
+    }
 }
 
 impl<T: NativeType> Extend<Option<T>> for MutablePrimitiveArray<T> {
@@ -383,6 +418,20 @@ impl<T: NativeType> MutableArray for MutablePrimitiveArray<T> {
         self.push(None)
     }
 
+    fn extend_nulls(&mut self, additional: usize) {
+        self.extend_constant(additional, None)
+    }
+
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let value = downcast_scalar::<PrimitiveScalar<T>>(&self.data_type, value)?;
+        self.push(value.value());
+        Ok(())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
@@ -0,0 +1,203 @@
+use std::sync::Arc;
+
+use crate::{
+    datatypes::{DataType, Field},
+    error::{ArrowError, Result},
+};
+
+use super::*;
+
+fn incompatible(from: &DataType, to: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "Cannot change the data type of an array from {:?} to {:?} as their physical layouts differ",
+        from, to
+    ))
+}
+
+// retypes each child to the data type of its new field
+fn retype_children(
+    children: &[Arc<dyn Array>],
+    fields: &[Field],
+    from: &DataType,
+    to: &DataType,
+) -> Result<Vec<Arc<dyn Array>>> {
+    if children.len() != fields.len() {
+        return Err(incompatible(from, to));
+    }
+    children
+        .iter()
+        .zip(fields.iter())
+        .map(|(child, field)| retype_child(child, field.data_type(), from, to))
+        .collect()
+}
+
+fn retype_child(
+    child: &Arc<dyn Array>,
+    data_type: &DataType,
+    from: &DataType,
+    to: &DataType,
+) -> Result<Arc<dyn Array>> {
+    if child.data_type() == data_type {
+        Ok(child.clone())
+    } else {
+        to_data_type(child.as_ref(), data_type.clone())
+            .map(Arc::from)
+            .map_err(|_| incompatible(from, to))
+    }
+}
+
+/// Returns a new [`Array`] with the same buffers as `array` and [`DataType`] `data_type`,
+/// i.e. it changes the logical type of `array` without touching its data. This is `O(1)` in
+/// the number of slots; nested arrays are retyped recursively.
+///
+/// This is used to go to and from extension types, between data types with the same
+/// physical representation (e.g. `Int32` and `Date32`, or `Int64` and `Timestamp`), and to
+/// change the logical type of the values of a dictionary.
+/// # Example
+/// ```
+/// use arrow2::array::{to_data_type, Array, Int32Array};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Int32Array::from_slice(&[1, 2]);
+/// let dates = to_data_type(&array, DataType::Date32).unwrap();
+/// assert_eq!(dates.data_type(), &DataType::Date32);
+///
+/// assert!(to_data_type(&array, DataType::Int64).is_err());
+/// ```
+/// # Error
+/// Errors iff the physical layouts of `array`'s data type and `data_type` differ, including
+/// the sizes of fixed-size types and the number of fields of nested types, or iff either is
+/// an extension of a dictionary (whose data type is fixed by its keys and values).
+pub fn to_data_type(array: &dyn Array, data_type: DataType) -> Result<Box<dyn Array>> {
+    let from = array.data_type();
+    if from.to_physical_type() != data_type.to_physical_type() {
+        return Err(incompatible(from, &data_type));
+    }
+
+    use crate::datatypes::PhysicalType::*;
+    Ok(match from.to_physical_type() {
+        Null => Box::new(NullArray::from_data(data_type, array.len())),
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            Box::new(BooleanArray::from_data(
+                data_type,
+                array.values().clone(),
+                array.validity().cloned(),
+            ))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            Box::new(array.clone().try_to(data_type)?)
+        }),
+        Binary => with_binary::<i32>(array, data_type),
+        LargeBinary => with_binary::<i64>(array, data_type),
+        Utf8 => with_utf8::<i32>(array, data_type),
+        LargeUtf8 => with_utf8::<i64>(array, data_type),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            Box::new(array.clone().try_to(data_type)?)
+        }
+        List => with_list::<i32>(array, data_type)?,
+        LargeList => with_list::<i64>(array, data_type)?,
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let (_, size) = FixedSizeListArray::get_child_and_size(from);
+            let (field, new_size) = FixedSizeListArray::get_child_and_size(&data_type);
+            if size != new_size {
+                return Err(incompatible(from, &data_type));
+            }
+            let values = retype_child(array.values(), field.data_type(), from, &data_type)?;
+            Box::new(FixedSizeListArray::from_data(
+                data_type,
+                values,
+                array.validity().cloned(),
+            ))
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let fields = StructArray::get_fields(&data_type);
+            let values = retype_children(array.values(), fields, from, &data_type)?;
+            Box::new(StructArray::from_data(
+                data_type,
+                values,
+                array.validity().cloned(),
+            ))
+        }
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let fields = UnionArray::get_fields(&data_type);
+            let children = retype_children(array.fields(), fields, from, &data_type)?;
+            Box::new(UnionArray::from_data(
+                data_type,
+                array.types().clone(),
+                children,
+                array.offsets().clone(),
+            ))
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let field = MapArray::get_field(&data_type);
+            let field = retype_child(array.field(), field.data_type(), from, &data_type)?;
+            Box::new(MapArray::from_data(
+                data_type,
+                array.offsets().clone(),
+                field,
+                array.validity().cloned(),
+            ))
+        }
+        Dictionary(key_type) => {
+            let (values, is_ordered) = match (from, &data_type) {
+                (DataType::Dictionary(_, _, _), DataType::Dictionary(_, values, is_ordered)) => {
+                    (values, *is_ordered)
+                }
+                _ => return Err(incompatible(from, &data_type)),
+            };
+            match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let values = retype_child(array.values(), values, from, &data_type)?;
+                Box::new(
+                    DictionaryArray::<$T>::from_data(array.keys().clone(), values)
+                        .with_is_ordered(is_ordered),
+                )
+            })
+        }
+    })
+}
+
+fn with_binary<O: Offset>(array: &dyn Array, data_type: DataType) -> Box<dyn Array> {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    Box::new(BinaryArray::<O>::from_data(
+        data_type,
+        array.offsets().clone(),
+        array.values().clone(),
+        array.validity().cloned(),
+    ))
+}
+
+fn with_utf8<O: Offset>(array: &dyn Array, data_type: DataType) -> Box<dyn Array> {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    // Safety: the array is a valid utf8 array
+    Box::new(unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            data_type,
+            array.offsets().clone(),
+            array.values().clone(),
+            array.validity().cloned(),
+        )
+    })
+}
+
+fn with_list<O: Offset>(array: &dyn Array, data_type: DataType) -> Result<Box<dyn Array>> {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let child = ListArray::<O>::get_child_type(&data_type);
+    let values = retype_child(array.values(), child, array.data_type(), &data_type)?;
+    Ok(Box::new(ListArray::<O>::from_data(
+        data_type,
+        array.offsets().clone(),
+        values,
+        array.validity().cloned(),
+    )))
+}
@@ -1,3 +1,4 @@
+use crate::error::{ArrowError, Result};
 use crate::types::Offset;
 
 pub fn check_offsets_minimal<O: Offset>(offsets: &[O], values_len: usize) -> usize {
@@ -64,3 +65,76 @@ pub fn check_offsets<O: Offset>(offsets: &[O], values_len: usize) {
     // assert bounds
     assert!(last.to_usize() <= values_len);
 }
+
+/// Checks that `offsets` are valid offsets of a buffer of length `values_len`.
+/// # Errors
+/// This function errors iff:
+/// * `offsets` is empty, or
+/// * the first offset is negative, or
+/// * the `offsets` are not monotonically increasing, or
+/// * the last offset is larger than `values_len`.
+pub fn try_check_offsets<O: Offset>(offsets: &[O], values_len: usize) -> Result<()> {
+    let first = *offsets
+        .first()
+        .ok_or_else(|| ArrowError::oos("The offsets must have at least one element"))?;
+    if first < O::zero() {
+        return Err(ArrowError::oos("The first offset must not be negative"));
+    }
+
+    let mut last = first;
+    if !offsets.iter().skip(1).all(|&end| {
+        let monotone = last <= end;
+        last = end;
+        monotone
+    }) {
+        return Err(ArrowError::oos(
+            "The offsets must be monotonically increasing",
+        ));
+    }
+    if last.to_usize() > values_len {
+        return Err(ArrowError::oos(format!(
+            "The last offset ({}) must be smaller or equal to the length of the values ({})",
+            last.to_usize(),
+            values_len
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that `offsets` are valid offsets of `values` and that every slice of `values` between
+/// two consecutive offsets is valid utf8.
+///
+/// Instead of validating every slice, this validates the whole region of `values` spanned by
+/// `offsets` and that every offset is on a char boundary, which is equivalent.
+/// # Errors
+/// This function errors iff the offsets are invalid (see [`try_check_offsets`]) or any slice
+/// is not valid utf8.
+pub fn try_check_offsets_and_utf8<O: Offset>(offsets: &[O], values: &[u8]) -> Result<()> {
+    try_check_offsets(offsets, values.len())?;
+
+    let start = offsets[0].to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+    let values = &values[start..end];
+    if values.is_ascii() {
+        return Ok(());
+    }
+
+    simdutf8::basic::from_utf8(values)
+        .map_err(|_| ArrowError::oos("A non-utf8 string was passed."))?;
+
+    // a byte on a char boundary is not a utf8 continuation byte (`0b10xxxxxx`)
+    let is_boundary = |offset: usize| match values.get(offset) {
+        Some(byte) => (*byte as i8) >= -0x40,
+        None => true,
+    };
+    if offsets
+        .iter()
+        .all(|offset| is_boundary(offset.to_usize() - start))
+    {
+        Ok(())
+    } else {
+        Err(ArrowError::oos(
+            "The offsets of a utf8 array must be on char boundaries",
+        ))
+    }
+}
@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use crate::{
-    bitmap::Bitmap,
+    bitmap::{utils::combine_validities, Bitmap},
+    columns::Columns,
     datatypes::{DataType, Field},
 };
 
@@ -9,6 +10,8 @@ use super::{new_empty_array, new_null_array, Array};
 
 mod ffi;
 mod iterator;
+mod mutable;
+pub use mutable::*;
 
 /// A [`StructArray`] is a nested [`Array`] with an optional validity representing
 /// multiple [`Array`] with the same number of rows.
@@ -169,6 +172,42 @@ impl StructArray {
     pub fn fields(&self) -> &[Field] {
         Self::get_fields(&self.data_type)
     }
+
+    /// Returns the values of the first field named `name`, if any.
+    /// The values do not take the validity of this [`StructArray`] into account;
+    /// use [`StructArray::flatten`] or [`crate::compute::struct_::get_field`] for that.
+    pub fn field_by_name(&self, name: &str) -> Option<&Arc<dyn Array>> {
+        self.fields()
+            .iter()
+            .position(|field| field.name() == name)
+            .map(|index| &self.values[index])
+    }
+
+    /// Returns the values of each field of this [`StructArray`] as [`Columns`],
+    /// where a slot is null whenever its row is null in this [`StructArray`].
+    /// # Panics
+    /// This function panics iff this [`StructArray`] has nulls and a field is a
+    /// [`UnionArray`](super::UnionArray), whose validity cannot be set.
+    pub fn flatten(&self) -> Columns<Arc<dyn Array>> {
+        Columns::new(
+            self.values
+                .iter()
+                .map(|values| push_validity(values, self.validity()))
+                .collect(),
+        )
+    }
+}
+
+/// Returns `array` with the slots null in `validity` set to null.
+fn push_validity(array: &Arc<dyn Array>, validity: Option<&Bitmap>) -> Arc<dyn Array> {
+    match validity {
+        // a null array is already null everywhere
+        Some(validity) if array.data_type().to_logical_type() != &DataType::Null => {
+            let validity = combine_validities(&[Some(validity), array.validity()]);
+            array.with_validity(validity).into()
+        }
+        _ => array.clone(),
+    }
 }
 
 impl StructArray {
@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{downcast_scalar, Array, MutableArray},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    scalar::{Scalar, StructScalar},
+};
+
+use super::StructArray;
+
+/// The mutable version of [`StructArray`]. Each row is built by pushing one item to every
+/// child (see [`MutableStructArray::value`]) followed by [`MutableStructArray::push`].
+/// # Implementation
+/// This struct does not allocate a validity until one is required (i.e. push a null to it).
+#[derive(Debug)]
+pub struct MutableStructArray {
+    data_type: DataType,
+    values: Vec<Box<dyn MutableArray>>,
+    validity: Option<MutableBitmap>,
+    // the number of rows pushed, tracked explicitly so that it does not depend on the children
+    length: usize,
+}
+
+impl MutableStructArray {
+    /// Creates a new [`MutableStructArray`] from a [`DataType::Struct`] and its (empty) children.
+    /// # Panics
+    /// This function panics iff:
+    /// * `data_type` is not a [`DataType::Struct`] or it has no fields
+    /// * the number of fields is different from the number of `values`
+    /// * any of `values` is not empty
+    pub fn new(data_type: DataType, values: Vec<Box<dyn MutableArray>>) -> Self {
+        assert!(values.iter().all(|x| x.is_empty()));
+        Self::from_data(data_type, values, None)
+    }
+
+    /// Canonical method to create a new [`MutableStructArray`].
+    /// # Panics
+    /// This function panics iff:
+    /// * `data_type` is not a [`DataType::Struct`] or it has no fields
+    /// * the number of fields is different from the number of `values`
+    /// * any of `values` has a different length than the first element
+    /// * the validity is not `None` and its length is different from `values`'s length.
+    pub fn from_data(
+        data_type: DataType,
+        values: Vec<Box<dyn MutableArray>>,
+        validity: Option<MutableBitmap>,
+    ) -> Self {
+        let fields = StructArray::get_fields(&data_type);
+        assert!(!fields.is_empty());
+        assert_eq!(fields.len(), values.len());
+        let length = values.first().map(|x| x.len()).unwrap_or(0);
+        assert!(values.iter().all(|x| x.len() == length));
+        if let Some(ref validity) = validity {
+            assert_eq!(length, validity.len());
+        }
+        Self {
+            data_type,
+            values,
+            validity,
+            length,
+        }
+    }
+
+    /// Returns the child at position `i` downcasted to `A`, or `None` if it is not an `A`.
+    /// # Panics
+    /// Panics iff `i >= self.value_builders().len()`.
+    pub fn value<A: MutableArray + 'static>(&mut self, i: usize) -> Option<&mut A> {
+        self.values[i].as_mut_any().downcast_mut::<A>()
+    }
+
+    /// The children of this [`MutableStructArray`].
+    pub fn value_builders(&self) -> &[Box<dyn MutableArray>] {
+        &self.values
+    }
+
+    /// The mutable children of this [`MutableStructArray`].
+    /// Items pushed to them must be followed by [`MutableStructArray::push`].
+    pub fn value_builders_mut(&mut self) -> &mut [Box<dyn MutableArray>] {
+        &mut self.values
+    }
+
+    /// Finalizes a new row, whose items were already pushed to each of the children.
+    /// # Errors
+    /// Errors iff any of the children does not have exactly one item more than rows in this array.
+    pub fn try_push(&mut self, valid: bool) -> Result<()> {
+        let len = self.length + 1;
+        if self.values.iter().any(|x| x.len() != len) {
+            return Err(ArrowError::InvalidArgumentError(
+                "MutableStructArray requires one item to be pushed to every child before a row is pushed"
+                    .to_string(),
+            ));
+        }
+        self.length = len;
+        match &mut self.validity {
+            Some(validity) => validity.push(valid),
+            None => {
+                if !valid {
+                    self.init_validity()
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Finalizes a new row, whose items were already pushed to each of the children.
+    /// # Panics
+    /// Panics iff any of the children does not have exactly one item more than rows in this array.
+    pub fn push(&mut self, valid: bool) {
+        self.try_push(valid).unwrap()
+    }
+
+    // called after the first null row was pushed, so `self.length > 0`
+    fn init_validity(&mut self) {
+        let len = self.length;
+        let mut validity = MutableBitmap::with_capacity(len);
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// Shortens the [`MutableStructArray`] to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.length {
+            return;
+        }
+        self.values.iter_mut().for_each(|x| x.truncate(len));
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+        self.length = len;
+    }
+
+    /// Shrinks the capacity of the [`MutableStructArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.values.iter_mut().for_each(|x| x.shrink_to_fit());
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+}
+
+impl MutableArray for MutableStructArray {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.length = 0;
+        Box::new(StructArray::from_data(
+            self.data_type.clone(),
+            self.values.iter_mut().map(|x| x.as_arc()).collect(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        ))
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.length = 0;
+        Arc::new(StructArray::from_data(
+            self.data_type.clone(),
+            self.values.iter_mut().map(|x| x.as_arc()).collect(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        ))
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.values.iter_mut().for_each(|x| x.push_null());
+        self.push(false)
+    }
+
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let value = downcast_scalar::<StructScalar>(&self.data_type, value)?;
+        if !value.is_valid() {
+            self.push_null();
+            return Ok(());
+        }
+        let values = value.values();
+        // validate every field before pushing so that an invalid scalar does not push a partial row
+        let fields = StructArray::get_fields(&self.data_type);
+        if values.len() != fields.len()
+            || values
+                .iter()
+                .zip(fields.iter())
+                .any(|(value, field)| value.data_type() != field.data_type())
+        {
+            return Err(ArrowError::InvalidArgumentError(
+                "The values of a StructScalar must be of the data types of its fields".to_string(),
+            ));
+        }
+        let length = self.length;
+        let result = self
+            .values
+            .iter_mut()
+            .zip(values.iter())
+            .try_for_each(|(array, value)| array.try_push_scalar(value.as_ref()));
+        if let Err(error) = result.and_then(|_| self.try_push(true)) {
+            // roll back the children that were pushed so that they keep the length of the array
+            self.values.iter_mut().for_each(|x| x.truncate(length));
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
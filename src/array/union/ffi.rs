@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::{array::FromFfi, error::Result, ffi};
+use crate::{array::FromFfi, buffer::Buffer, error::Result, ffi};
 
 use super::super::{ffi::ToFfi, Array};
 use super::UnionArray;
@@ -22,11 +22,28 @@ unsafe impl ToFfi for UnionArray {
     }
 
     fn offset(&self) -> Option<usize> {
-        Some(self.types.offset())
+        let offset = self.types.offset();
+        let is_aligned = if let Some(offsets) = &self.offsets {
+            offsets.offset() == offset
+        } else {
+            // the fields of a sparse union are sliced and thus can't be offset by the union's offset
+            offset == 0
+        };
+        if is_aligned {
+            Some(offset)
+        } else {
+            None
+        }
     }
 
     fn to_ffi_aligned(&self) -> Self {
-        self.clone()
+        let mut new = self.clone();
+        new.types = Buffer::from_slice(self.types.as_slice());
+        new.offsets = self
+            .offsets
+            .as_ref()
+            .map(|x| Buffer::from_slice(x.as_slice()));
+        new
     }
 }
 
@@ -36,7 +53,7 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
         let data_type = field.data_type().clone();
         let fields = Self::get_fields(field.data_type());
 
-        let mut types = unsafe { array.buffer::<i8>(0) }?;
+        let types = unsafe { array.buffer::<i8>(0) }?;
         let offsets = if Self::is_sparse(&data_type) {
             None
         } else {
@@ -52,8 +69,14 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
             })
             .collect::<Result<Vec<Arc<dyn Array>>>>()?;
 
-        if offset > 0 {
-            types = types.slice(offset, length);
+        // the buffers are already offset; the fields of a sparse union are offset by the union's offset
+        let fields = if offset > 0 && offsets.is_none() {
+            fields
+                .iter()
+                .map(|x| x.slice(offset, length).into())
+                .collect()
+        } else {
+            fields
         };
 
         Ok(Self::from_data(data_type, types, fields, offsets))
@@ -5,11 +5,13 @@ use crate::{
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::{DataType, Field, UnionMode},
-    scalar::{new_scalar, Scalar},
+    scalar::{new_scalar, Scalar, UnionScalar},
 };
 
 mod ffi;
 mod iterator;
+mod mutable;
+pub use mutable::*;
 
 type FieldEntry = (usize, Arc<dyn Array>);
 
@@ -31,7 +33,6 @@ pub struct UnionArray {
     fields: Vec<Arc<dyn Array>>,
     offsets: Option<Buffer<i32>>,
     data_type: DataType,
-    offset: usize,
 }
 
 impl UnionArray {
@@ -78,7 +79,6 @@ impl UnionArray {
                 fields,
                 offsets,
                 types: Buffer::new(),
-                offset: 0,
             }
         } else {
             panic!("Union struct must be created with the corresponding Union DataType")
@@ -125,25 +125,23 @@ impl UnionArray {
             fields,
             offsets,
             types,
-            offset: 0,
         }
     }
 
     /// Returns a slice of this [`UnionArray`].
     /// # Implementation
     /// This operation is `O(F)` where `F` is the number of fields.
+    /// The types (and offsets) are sliced. The fields of a sparse union are also sliced,
+    /// while the fields of a dense union are not, as its offsets index them directly.
     /// # Panic
     /// This function panics iff `offset + length >= self.len()`.
     #[inline]
     pub fn slice(&self, offset: usize, length: usize) -> Self {
-        Self {
-            data_type: self.data_type.clone(),
-            fields: self.fields.clone(),
-            fields_hash: self.fields_hash.clone(),
-            types: self.types.clone().slice(offset, length),
-            offsets: self.offsets.clone(),
-            offset: self.offset + offset,
-        }
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new array cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
     }
 
     /// Returns a slice of this [`UnionArray`].
@@ -153,13 +151,30 @@ impl UnionArray {
     /// The caller must ensure that `offset + length <= self.len()`.
     #[inline]
     pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        let (fields, offsets) = if let Some(offsets) = &self.offsets {
+            (
+                self.fields.clone(),
+                Some(offsets.clone().slice_unchecked(offset, length)),
+            )
+        } else {
+            let fields = self
+                .fields
+                .iter()
+                .map(|x| x.slice_unchecked(offset, length).into())
+                .collect::<Vec<Arc<dyn Array>>>();
+            (fields, None)
+        };
+        let fields_hash = self.fields_hash.as_ref().map(|hash| {
+            hash.iter()
+                .map(|(type_, (i, _))| (*type_, (*i, fields[*i].clone())))
+                .collect()
+        });
         Self {
             data_type: self.data_type.clone(),
-            fields: self.fields.clone(),
-            fields_hash: self.fields_hash.clone(),
+            fields,
+            fields_hash,
             types: self.types.clone().slice_unchecked(offset, length),
-            offsets: self.offsets.clone(),
-            offset: self.offset + offset,
+            offsets,
         }
     }
 }
@@ -219,7 +234,12 @@ impl UnionArray {
         let type_ = self.types()[index];
         let field = self.field(type_);
         let index = self.field_slot(index);
-        new_scalar(field.as_ref(), index)
+        let value = new_scalar(field.as_ref(), index);
+        Box::new(UnionScalar::new(
+            self.data_type.clone(),
+            type_,
+            value.into(),
+        ))
     }
 }
 
@@ -266,6 +286,13 @@ impl UnionArray {
         Self::get_all(data_type).0
     }
 
+    /// Returns the type ids of the [`DataType::Union`], if any.
+    /// # Panic
+    /// Panics iff `data_type`'s logical type is not [`DataType::Union`].
+    pub fn get_type_ids(data_type: &DataType) -> Option<&[i32]> {
+        Self::get_all(data_type).1
+    }
+
     /// Returns whether the [`DataType::Union`] is sparse or not.
     /// # Panic
     /// Panics iff `data_type`'s logical type is not [`DataType::Union`].
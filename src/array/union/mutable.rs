@@ -0,0 +1,260 @@
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use crate::{
+    array::{downcast_scalar, Array, MutableArray},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    scalar::{Scalar, UnionScalar},
+};
+
+use super::UnionArray;
+
+/// The mutable version of [`UnionArray`]. Each slot is built by pushing one item to the child
+/// of its type (see [`MutableUnionArray::value`]) followed by [`MutableUnionArray::push`].
+/// In a sparse union, the other children receive a null in that slot.
+/// # Implementation
+/// Like [`UnionArray`], this struct has no validity: a null slot is a null of its child.
+#[derive(Debug)]
+pub struct MutableUnionArray {
+    data_type: DataType,
+    types: Vec<i8>,
+    fields: Vec<Box<dyn MutableArray>>,
+    // `None` iff the union is sparse
+    offsets: Option<Vec<i32>>,
+    // the number of items of each child that belong to pushed slots
+    lengths: Vec<usize>,
+}
+
+impl MutableUnionArray {
+    /// Creates a new [`MutableUnionArray`] from a [`DataType::Union`] and its (empty) children.
+    /// # Panics
+    /// This function panics iff:
+    /// * `data_type` is not a [`DataType::Union`] or it has no fields
+    /// * the number of fields is different from the number of `fields`
+    /// * any of `fields` is not empty or its data type differs from its field's
+    pub fn new(data_type: DataType, fields: Vec<Box<dyn MutableArray>>) -> Self {
+        let (f, _, mode) = UnionArray::get_all(&data_type);
+        assert!(!f.is_empty());
+        assert_eq!(f.len(), fields.len());
+        assert!(f
+            .iter()
+            .zip(fields.iter())
+            .all(|(f, array)| f.data_type() == array.data_type()));
+        assert!(fields.iter().all(|x| x.is_empty()));
+
+        let offsets = if mode.is_sparse() { None } else { Some(vec![]) };
+        Self {
+            data_type,
+            types: vec![],
+            lengths: vec![0; fields.len()],
+            fields,
+            offsets,
+        }
+    }
+
+    /// Returns the child at position `i` downcasted to `A`, or `None` if it is not an `A`.
+    /// # Panics
+    /// Panics iff `i >= self.fields().len()`.
+    pub fn value<A: MutableArray + 'static>(&mut self, i: usize) -> Option<&mut A> {
+        self.fields[i].as_mut_any().downcast_mut::<A>()
+    }
+
+    /// The children of this [`MutableUnionArray`].
+    pub fn fields(&self) -> &[Box<dyn MutableArray>] {
+        &self.fields
+    }
+
+    /// The types of the slots pushed so far.
+    pub fn types(&self) -> &[i8] {
+        &self.types
+    }
+
+    /// The offsets of the slots pushed so far, `None` iff the union is sparse.
+    pub fn offsets(&self) -> Option<&[i32]> {
+        self.offsets.as_deref()
+    }
+
+    /// Returns the position of the child of type `type_`.
+    fn field_index(&self, type_: i8) -> Result<usize> {
+        let index = match UnionArray::get_type_ids(&self.data_type) {
+            Some(ids) => ids.iter().position(|id| *id == type_ as i32),
+            None => Some(type_ as usize).filter(|index| *index < self.fields.len()),
+        };
+        index.ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The type {} is not a type of the union {:?}",
+                type_, self.data_type
+            ))
+        })
+    }
+
+    /// Finalizes a new slot of type `type_`, whose item was already pushed to the child of
+    /// that type.
+    /// # Errors
+    /// Errors iff:
+    /// * `type_` is not a type of this union
+    /// * the child of `type_` does not have exactly one new item or any other child has new items
+    /// * the offset of the slot in a dense union exceeds `i32::MAX` ([`ArrowError::Overflow`])
+    pub fn try_push(&mut self, type_: i8) -> Result<()> {
+        let index = self.field_index(type_)?;
+        let has_one_new_item = self
+            .fields
+            .iter()
+            .zip(self.lengths.iter())
+            .enumerate()
+            .all(|(i, (field, length))| field.len() == *length + (i == index) as usize);
+        if !has_one_new_item {
+            return Err(ArrowError::InvalidArgumentError(
+                "MutableUnionArray requires one item to be pushed to the child of the type of a slot before the slot is pushed"
+                    .to_string(),
+            ));
+        }
+        match &mut self.offsets {
+            Some(offsets) => {
+                let offset =
+                    i32::try_from(self.lengths[index]).map_err(|_| ArrowError::Overflow)?;
+                offsets.push(offset);
+                self.lengths[index] += 1;
+            }
+            None => {
+                self.fields
+                    .iter_mut()
+                    .enumerate()
+                    .filter(|(i, _)| *i != index)
+                    .for_each(|(_, field)| field.push_null());
+                self.lengths.iter_mut().for_each(|length| *length += 1);
+            }
+        }
+        self.types.push(type_);
+        Ok(())
+    }
+
+    /// Finalizes a new slot of type `type_`, whose item was already pushed to the child of
+    /// that type.
+    /// # Panics
+    /// Panics iff [`MutableUnionArray::try_push`] errors.
+    pub fn push(&mut self, type_: i8) {
+        self.try_push(type_).unwrap()
+    }
+
+    /// Shortens the [`MutableUnionArray`] to its first `len` slots, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.types.len() {
+            return;
+        }
+        self.types.truncate(len);
+        if let Some(offsets) = &mut self.offsets {
+            offsets.truncate(len);
+            let mut lengths = vec![0; self.fields.len()];
+            for type_ in &self.types {
+                // the types were validated when they were pushed
+                lengths[self.field_index(*type_).unwrap()] += 1;
+            }
+            self.lengths = lengths;
+        } else {
+            self.lengths.iter_mut().for_each(|length| *length = len);
+        }
+        self.fields
+            .iter_mut()
+            .zip(self.lengths.iter())
+            .for_each(|(field, length)| field.truncate(*length));
+    }
+
+    /// Shrinks the capacity of the [`MutableUnionArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        if let Some(offsets) = &mut self.offsets {
+            offsets.shrink_to_fit()
+        }
+        self.fields.iter_mut().for_each(|x| x.shrink_to_fit());
+    }
+
+    fn take_array(&mut self) -> UnionArray {
+        self.lengths.iter_mut().for_each(|length| *length = 0);
+        UnionArray::from_data(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            self.fields.iter_mut().map(|x| x.as_arc()).collect(),
+            self.offsets.as_mut().map(|x| std::mem::take(x).into()),
+        )
+    }
+}
+
+impl From<MutableUnionArray> for UnionArray {
+    fn from(mut other: MutableUnionArray) -> Self {
+        other.take_array()
+    }
+}
+
+impl MutableArray for MutableUnionArray {
+    fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.take_array())
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.take_array())
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Pushes a null of the first child, as [`UnionArray::new_null`] does.
+    fn push_null(&mut self) {
+        let type_ = UnionArray::get_type_ids(&self.data_type)
+            .map(|ids| ids[0] as i8)
+            .unwrap_or(0);
+        self.fields[0].push_null();
+        self.push(type_)
+    }
+
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let value = downcast_scalar::<UnionScalar>(&self.data_type, value)?;
+        let index = self.field_index(value.type_())?;
+        let length = self.fields[index].len();
+        self.fields[index].try_push_scalar(value.value().as_ref())?;
+        if let Err(error) = self.try_push(value.type_()) {
+            // roll back the child so that it keeps the length of the array
+            self.fields[index].truncate(length);
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    fn is_valid(&self, index: usize) -> bool {
+        let field = self.field_index(self.types[index]).unwrap();
+        let slot = self
+            .offsets
+            .as_ref()
+            .map(|offsets| offsets[index] as usize)
+            .unwrap_or(index);
+        self.fields[field].is_valid(slot)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
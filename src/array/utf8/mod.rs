@@ -2,9 +2,13 @@ use crate::{bitmap::Bitmap, buffer::Buffer, datatypes::DataType};
 
 use super::{
     display_fmt,
-    specification::{check_offsets_and_utf8, check_offsets_minimal},
+    specification::{
+        check_offsets_and_utf8, check_offsets_minimal, try_check_offsets,
+        try_check_offsets_and_utf8,
+    },
     Array, GenericBinaryArray, Offset,
 };
+use crate::error::{ArrowError, Result};
 
 mod ffi;
 mod from;
@@ -93,6 +97,63 @@ impl<O: Offset> Utf8Array<O> {
         }
     }
 
+    /// Returns a new [`Utf8Array`], validating all its invariants. Use this constructor when the
+    /// `offsets` and `values` come from an untrusted source.
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s physical type is not consistent with the offset `O`.
+    /// * The `offsets` are empty, not monotonically increasing, start with a negative value or
+    ///   are larger than the values' length.
+    /// * Any slice of `values` between two consecutive `offsets` is not valid utf8.
+    /// * The validity is not `None` and its length is different from `offsets.len() - 1`.
+    pub fn try_new(
+        data_type: DataType,
+        offsets: Buffer<O>,
+        values: Buffer<u8>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self> {
+        try_check_offsets_and_utf8(&offsets, &values)?;
+        // Safety: utf8 was validated above
+        unsafe { Self::try_new_unchecked_utf8(data_type, offsets, values, validity) }
+    }
+
+    /// The same as [`Utf8Array::try_new`] but without validating that `values` are utf8: it only
+    /// validates the `offsets`, the validity and the `data_type`, whose cost does not depend on
+    /// the length of `values`.
+    /// # Safety
+    /// Every slice of `values` between two consecutive `offsets` MUST be valid utf8.
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s physical type is not consistent with the offset `O`.
+    /// * The `offsets` are empty, not monotonically increasing, start with a negative value or
+    ///   are larger than the values' length.
+    /// * The validity is not `None` and its length is different from `offsets.len() - 1`.
+    pub unsafe fn try_new_unchecked_utf8(
+        data_type: DataType,
+        offsets: Buffer<O>,
+        values: Buffer<u8>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self> {
+        try_check_offsets(&offsets, values.len())?;
+        if matches!(&validity, Some(validity) if validity.len() != offsets.len() - 1) {
+            return Err(ArrowError::oos(
+                "The validity length of a Utf8Array must equal the number of values",
+            ));
+        }
+        if data_type.to_physical_type() != Self::default_data_type().to_physical_type() {
+            return Err(ArrowError::oos(
+                "Utf8Array can only be initialized with DataType::Utf8 or DataType::LargeUtf8",
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            offsets,
+            values,
+            validity,
+        })
+    }
+
     /// Returns the default [`DataType`], `DataType::Utf8` or `DataType::LargeUtf8`
     pub fn default_data_type() -> DataType {
         if O::is_large() {
@@ -2,12 +2,14 @@ use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
     array::{
+        downcast_scalar,
         specification::{check_offsets_and_utf8, check_offsets_minimal},
         Array, MutableArray, Offset, TryExtend, TryPush,
     },
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
+    scalar::{Scalar, Utf8Scalar},
     trusted_len::TrustedLen,
 };
 
@@ -154,6 +156,62 @@ impl<O: Offset> MutableUtf8Array<O> {
         self.try_push(value).unwrap()
     }
 
+    /// Pushes a new element to the array from its utf8-encoded bytes, without checking that
+    /// they are valid utf8.
+    /// # Safety
+    /// The caller must ensure that `value`, when `Some`, is valid utf8.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
+    #[inline]
+    pub unsafe fn push_unchecked<T: AsRef<[u8]>>(&mut self, value: Option<T>) {
+        match value {
+            Some(value) => {
+                self.values.extend_from_slice(value.as_ref());
+                self.offsets.push(O::from_usize(self.values.len()).unwrap());
+                if let Some(validity) = &mut self.validity {
+                    validity.push(true)
+                }
+            }
+            None => {
+                self.offsets.push(self.last_offset());
+                match &mut self.validity {
+                    Some(validity) => validity.push(false),
+                    None => self.init_validity(),
+                }
+            }
+        }
+    }
+
+    /// Extends the [`MutableUtf8Array`] with `additional` repetitions of `value`.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
+    pub fn extend_constant<T: AsRef<str>>(&mut self, additional: usize, value: Option<T>) {
+        if let Some(value) = value {
+            let value = value.as_ref().as_bytes();
+            self.values.reserve(value.len() * additional);
+            self.offsets.reserve(additional);
+            for _ in 0..additional {
+                self.values.extend_from_slice(value);
+                self.offsets.push(O::from_usize(self.values.len()).unwrap());
+            }
+            if let Some(validity) = self.validity.as_mut() {
+                validity.extend_constant(additional, true)
+            }
+        } else {
+            if let Some(validity) = self.validity.as_mut() {
+                validity.extend_constant(additional, false)
+            } else {
+                let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+                validity.extend_constant(self.len(), true);
+                validity.extend_constant(additional, false);
+                self.validity = Some(validity)
+            }
+            let last_offset = self.last_offset();
+            self.offsets
+                .resize(self.offsets.len() + additional, last_offset);
+        }
+    }
+
     fn init_validity(&mut self) {
         let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
         validity.extend_constant(self.len(), true);
@@ -167,6 +225,19 @@ impl<O: Offset> MutableUtf8Array<O> {
         Arc::new(a)
     }
 
+    /// Shortens the [`MutableUtf8Array`] to its first `len` elements, dropping the others.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    pub fn truncate(&mut self, len: usize) {
+        if len + 1 >= self.offsets.len() {
+            return;
+        }
+        self.offsets.truncate(len + 1);
+        self.values.truncate(self.offsets[len].to_usize());
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+    }
+
     /// Shrinks the capacity of the [`MutableUtf8Array`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -187,6 +258,17 @@ impl<O: Offset> MutableUtf8Array<O> {
     pub fn offsets(&self) -> &Vec<O> {
         &self.offsets
     }
+
+    /// returns the capacity (in bytes) of its values.
+    pub fn values_capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    /// returns the capacity of its offsets, i.e. the number of items plus one
+    /// that can be pushed without reallocating them.
+    pub fn offsets_capacity(&self) -> usize {
+        self.offsets.capacity()
+    }
 }
 
 impl<O: Offset> MutableArray for MutableUtf8Array<O> {
@@ -246,6 +328,19 @@ impl<O: Offset> MutableArray for MutableUtf8Array<O> {
         self.push::<&str>(None)
     }
 
+    fn extend_nulls(&mut self, additional: usize) {
+        self.extend_constant::<&str>(additional, None)
+    }
+
+    fn try_push_scalar(&mut self, value: &dyn Scalar) -> Result<()> {
+        let value = downcast_scalar::<Utf8Scalar<O>>(&self.data_type, value)?;
+        self.try_push(value.value())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
@@ -1,10 +1,11 @@
-use std::ops::{BitAnd, BitOr, BitXor, Not};
+use std::convert::TryInto;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
 
 use crate::trusted_len::TrustedLen;
 
 use super::{
-    utils::{BitChunk, BitChunkIterExact, BitChunksExact},
-    Bitmap,
+    utils::{BitChunk, BitChunkIterExact, BitChunks, BitChunksExact},
+    Bitmap, MutableBitmap,
 };
 
 /// # Safety
@@ -98,26 +99,78 @@ where
     Bitmap::from_u8_vec(buffer, length)
 }
 
+/// Returns a mask of the bits of the remainder chunk of a bitmap of `length` bits.
+#[inline]
+pub(crate) fn remainder_mask(length: usize) -> u64 {
+    let remainder = length % 64;
+    if remainder == 0 {
+        0
+    } else {
+        (1u64 << remainder) - 1
+    }
+}
+
+fn binary_impl<I, J, F>(lhs: I, rhs: J, op: F, length: usize) -> Bitmap
+where
+    I: BitChunkIterExact<u64>,
+    J: BitChunkIterExact<u64>,
+    F: Fn(u64, u64) -> u64,
+{
+    // bits past `length` are cleared so that they are not counted
+    let rem = op(lhs.remainder(), rhs.remainder()) & remainder_mask(length);
+    let mut set_bits = rem.count_ones() as usize;
+
+    let chunks = lhs
+        .zip(rhs)
+        .map(|(left, right)| {
+            let chunk = op(left, right);
+            set_bits += chunk.count_ones() as usize;
+            chunk
+        })
+        .chain(std::iter::once(rem));
+
+    let buffer = chunk_iter_to_vec(chunks);
+
+    // Safety: `set_bits` was computed over the first `length` bits
+    unsafe { Bitmap::from_u8_vec_with_null_count(buffer, length, length - set_bits) }
+}
+
 /// Apply a bitwise operation `op` to two inputs and return the result as a [`Bitmap`].
+/// # Implementation
+/// Both inputs are read in chunks of `u64` irrespectively of their offsets and the
+/// null count of the result is computed on the same pass.
+/// # Panics
+/// This function panics iff `lhs.len() != rhs.len()`.
 pub fn binary<F>(lhs: &Bitmap, rhs: &Bitmap, op: F) -> Bitmap
 where
     F: Fn(u64, u64) -> u64,
 {
     assert_eq!(lhs.len(), rhs.len());
-    let lhs_chunks = lhs.chunks();
-    let rhs_chunks = rhs.chunks();
-    let rem_lhs = lhs_chunks.remainder();
-    let rem_rhs = rhs_chunks.remainder();
-
-    let chunks = lhs_chunks
-        .zip(rhs_chunks)
-        .map(|(left, right)| op(left, right));
-
-    let buffer = chunk_iter_to_vec(chunks.chain(std::iter::once(op(rem_lhs, rem_rhs))));
-
     let length = lhs.len();
-
-    Bitmap::from_u8_vec(buffer, length)
+    let (lhs_slice, lhs_offset, _) = lhs.as_slice();
+    let (rhs_slice, rhs_offset, _) = rhs.as_slice();
+
+    match (lhs_offset == 0, rhs_offset == 0) {
+        (true, true) => binary_impl(
+            BitChunksExact::<u64>::new(lhs_slice, length),
+            BitChunksExact::<u64>::new(rhs_slice, length),
+            op,
+            length,
+        ),
+        (true, false) => binary_impl(
+            BitChunksExact::<u64>::new(lhs_slice, length),
+            rhs.chunks::<u64>(),
+            op,
+            length,
+        ),
+        (false, true) => binary_impl(
+            lhs.chunks::<u64>(),
+            BitChunksExact::<u64>::new(rhs_slice, length),
+            op,
+            length,
+        ),
+        (false, false) => binary_impl(lhs.chunks::<u64>(), rhs.chunks::<u64>(), op, length),
+    }
 }
 
 fn unary_impl<F, I>(iter: I, op: F, length: usize) -> Bitmap
@@ -125,13 +178,21 @@ where
     I: BitChunkIterExact<u64>,
     F: Fn(u64) -> u64,
 {
-    let rem = op(iter.remainder());
+    let rem = op(iter.remainder()) & remainder_mask(length);
+    let mut set_bits = rem.count_ones() as usize;
 
-    let iterator = iter.map(op).chain(std::iter::once(rem));
+    let iterator = iter
+        .map(|x| {
+            let chunk = op(x);
+            set_bits += chunk.count_ones() as usize;
+            chunk
+        })
+        .chain(std::iter::once(rem));
 
     let buffer = chunk_iter_to_vec(iterator);
 
-    Bitmap::from_u8_vec(buffer, length)
+    // Safety: `set_bits` was computed over the first `length` bits
+    unsafe { Bitmap::from_u8_vec_with_null_count(buffer, length, length - set_bits) }
 }
 
 /// Apply a bitwise operation `op` to one input and return the result as a [`Bitmap`].
@@ -149,6 +210,76 @@ where
     }
 }
 
+// applies `op` in-place over chunks of `lhs` and `rhs`, keeping the bits past `lhs.len()` untouched
+fn binary_assign_impl<I, F>(lhs: &mut MutableBitmap, mut rhs: I, op: F)
+where
+    I: BitChunkIterExact<u64>,
+    F: Fn(u64, u64) -> u64,
+{
+    let length = lhs.len();
+    let (chunks, remainder) = lhs.as_mut_slice().split_at_mut(length / 64 * 8);
+
+    chunks
+        .chunks_exact_mut(8)
+        .zip(rhs.by_ref())
+        .for_each(|(chunk, right)| {
+            let left = u64::from_ne_bytes(chunk.try_into().unwrap());
+            chunk.copy_from_slice(&op(left, right).to_ne_bytes());
+        });
+
+    let remainder = &mut remainder[..(length % 64).saturating_add(7) / 8];
+    if !remainder.is_empty() {
+        let mut bytes = [0u8; 8];
+        bytes[..remainder.len()].copy_from_slice(remainder);
+        let left = u64::from_ne_bytes(bytes);
+        let mask = remainder_mask(length);
+        let result = (op(left, rhs.remainder()) & mask) | (left & !mask);
+        let len = remainder.len();
+        remainder.copy_from_slice(&result.to_ne_bytes()[..len]);
+    }
+}
+
+/// Apply a bitwise operation `op` to two inputs, writing the result to `lhs`.
+/// # Panics
+/// This function panics iff `lhs.len() != rhs.len()`.
+pub fn binary_assign<F>(lhs: &mut MutableBitmap, rhs: &Bitmap, op: F)
+where
+    F: Fn(u64, u64) -> u64,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let (slice, offset, length) = rhs.as_slice();
+    if offset == 0 {
+        binary_assign_impl(lhs, BitChunksExact::<u64>::new(slice, length), op)
+    } else {
+        binary_assign_impl(lhs, rhs.chunks::<u64>(), op)
+    }
+}
+
+/// Apply a bitwise operation `op` to one input, writing the result to it.
+pub fn unary_assign<F>(lhs: &mut MutableBitmap, op: F)
+where
+    F: Fn(u64) -> u64,
+{
+    let length = lhs.len();
+    let (chunks, remainder) = lhs.as_mut_slice().split_at_mut(length / 64 * 8);
+
+    chunks.chunks_exact_mut(8).for_each(|chunk| {
+        let value = u64::from_ne_bytes(chunk.try_into().unwrap());
+        chunk.copy_from_slice(&op(value).to_ne_bytes());
+    });
+
+    let remainder = &mut remainder[..(length % 64).saturating_add(7) / 8];
+    if !remainder.is_empty() {
+        let mut bytes = [0u8; 8];
+        bytes[..remainder.len()].copy_from_slice(remainder);
+        let value = u64::from_ne_bytes(bytes);
+        let mask = remainder_mask(length);
+        let result = (op(value) & mask) | (value & !mask);
+        let len = remainder.len();
+        remainder.copy_from_slice(&result.to_ne_bytes()[..len]);
+    }
+}
+
 // create a new [`Bitmap`] semantically equal to ``bitmap`` but with an offset equal to ``offset``
 pub(crate) fn align(bitmap: &Bitmap, new_offset: usize) -> Bitmap {
     let length = bitmap.len();
@@ -161,40 +292,89 @@ pub(crate) fn align(bitmap: &Bitmap, new_offset: usize) -> Bitmap {
     bitmap.slice(new_offset, length)
 }
 
-#[inline]
-fn and(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
-    binary(lhs, rhs, |x, y| x & y)
-}
+impl Bitmap {
+    /// Returns the bitwise AND of `self` and `rhs`.
+    /// # Panics
+    /// This function panics iff `self.len() != rhs.len()`.
+    #[inline]
+    pub fn and(&self, rhs: &Bitmap) -> Bitmap {
+        binary(self, rhs, |x, y| x & y)
+    }
 
-#[inline]
-fn or(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
-    binary(lhs, rhs, |x, y| x | y)
-}
+    /// Returns the bitwise OR of `self` and `rhs`.
+    /// # Panics
+    /// This function panics iff `self.len() != rhs.len()`.
+    #[inline]
+    pub fn or(&self, rhs: &Bitmap) -> Bitmap {
+        binary(self, rhs, |x, y| x | y)
+    }
 
-#[inline]
-fn xor(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
-    binary(lhs, rhs, |x, y| x ^ y)
+    /// Returns the bitwise XOR of `self` and `rhs`.
+    /// # Panics
+    /// This function panics iff `self.len() != rhs.len()`.
+    #[inline]
+    pub fn xor(&self, rhs: &Bitmap) -> Bitmap {
+        binary(self, rhs, |x, y| x ^ y)
+    }
 }
 
 fn eq(lhs: &Bitmap, rhs: &Bitmap) -> bool {
     if lhs.len() != rhs.len() {
         return false;
     }
+    lhs.eq_with_offset(0, rhs, 0, lhs.len())
+}
 
-    let mut lhs_chunks = lhs.chunks::<u64>();
-    let mut rhs_chunks = rhs.chunks::<u64>();
-
-    let equal_chunks = lhs_chunks
-        .by_ref()
-        .zip(rhs_chunks.by_ref())
-        .all(|(left, right)| left == right);
+impl Bitmap {
+    /// Returns whether the `length` bits of `self` starting at `offset` are equal to the
+    /// `length` bits of `other` starting at `other_offset`.
+    /// # Implementation
+    /// The comparison is performed in chunks of `u64` irrespectively of the offsets.
+    /// # Panics
+    /// This function panics iff any of the ranges is out of bounds.
+    pub fn eq_with_offset(
+        &self,
+        offset: usize,
+        other: &Bitmap,
+        other_offset: usize,
+        length: usize,
+    ) -> bool {
+        assert!(offset + length <= self.len());
+        assert!(other_offset + length <= other.len());
+
+        let (lhs, lhs_offset, _) = self.as_slice();
+        let (rhs, rhs_offset, _) = other.as_slice();
+        let mut lhs_chunks = BitChunks::<u64>::new(lhs, lhs_offset + offset, length);
+        let mut rhs_chunks = BitChunks::<u64>::new(rhs, rhs_offset + other_offset, length);
+
+        let equal_chunks = lhs_chunks
+            .by_ref()
+            .zip(rhs_chunks.by_ref())
+            .all(|(left, right)| left == right);
+
+        if !equal_chunks {
+            return false;
+        }
+        let mask = remainder_mask(length);
+        lhs_chunks.remainder() & mask == rhs_chunks.remainder() & mask
+    }
 
-    if !equal_chunks {
-        return false;
+    /// Returns whether `self` and `other` have at least one set bit at the same position.
+    /// # Panics
+    /// This function panics iff `self.len() != other.len()`.
+    pub fn intersects_with(&self, other: &Bitmap) -> bool {
+        assert_eq!(self.len(), other.len());
+        let mut lhs_chunks = self.chunks::<u64>();
+        let mut rhs_chunks = other.chunks::<u64>();
+
+        let intersects = lhs_chunks
+            .by_ref()
+            .zip(rhs_chunks.by_ref())
+            .any(|(left, right)| left & right != 0);
+
+        intersects
+            || lhs_chunks.remainder() & rhs_chunks.remainder() & remainder_mask(self.len()) != 0
     }
-    let lhs_remainder = lhs_chunks.remainder_iter();
-    let rhs_remainder = rhs_chunks.remainder_iter();
-    lhs_remainder.zip(rhs_remainder).all(|(x, y)| x == y)
 }
 
 impl PartialEq for Bitmap {
@@ -207,7 +387,7 @@ impl<'a, 'b> BitOr<&'b Bitmap> for &'a Bitmap {
     type Output = Bitmap;
 
     fn bitor(self, rhs: &'b Bitmap) -> Bitmap {
-        or(self, rhs)
+        self.or(rhs)
     }
 }
 
@@ -215,7 +395,7 @@ impl<'a, 'b> BitAnd<&'b Bitmap> for &'a Bitmap {
     type Output = Bitmap;
 
     fn bitand(self, rhs: &'b Bitmap) -> Bitmap {
-        and(self, rhs)
+        self.and(rhs)
     }
 }
 
@@ -223,7 +403,7 @@ impl<'a, 'b> BitXor<&'b Bitmap> for &'a Bitmap {
     type Output = Bitmap;
 
     fn bitxor(self, rhs: &'b Bitmap) -> Bitmap {
-        xor(self, rhs)
+        self.xor(rhs)
     }
 }
 
@@ -234,3 +414,30 @@ impl Not for &Bitmap {
         unary(self, |a| !a)
     }
 }
+
+impl<'a> BitAndAssign<&'a Bitmap> for MutableBitmap {
+    fn bitand_assign(&mut self, rhs: &'a Bitmap) {
+        binary_assign(self, rhs, |x, y| x & y)
+    }
+}
+
+impl<'a> BitOrAssign<&'a Bitmap> for MutableBitmap {
+    fn bitor_assign(&mut self, rhs: &'a Bitmap) {
+        binary_assign(self, rhs, |x, y| x | y)
+    }
+}
+
+impl<'a> BitXorAssign<&'a Bitmap> for MutableBitmap {
+    fn bitxor_assign(&mut self, rhs: &'a Bitmap) {
+        binary_assign(self, rhs, |x, y| x ^ y)
+    }
+}
+
+impl Not for MutableBitmap {
+    type Output = MutableBitmap;
+
+    fn not(mut self) -> MutableBitmap {
+        unary_assign(&mut self, |a| !a);
+        self
+    }
+}
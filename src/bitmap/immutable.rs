@@ -1,7 +1,15 @@
+use std::any::Any;
 use std::iter::FromIterator;
+use std::ptr::NonNull;
 use std::sync::Arc;
 
-use crate::{buffer::bytes::Bytes, trusted_len::TrustedLen};
+use either::Either;
+
+use crate::{
+    buffer::bytes::{Bytes, Deallocation},
+    error::{ArrowError, Result},
+    trusted_len::TrustedLen,
+};
 
 use super::{
     utils::{count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter},
@@ -86,6 +94,54 @@ impl Bitmap {
         Bitmap::from_bytes(vec.into(), length)
     }
 
+    /// Creates a new [`Bitmap`] from [`Vec`], a length and its number of unset bits.
+    /// This function is `O(1)`
+    /// # Safety
+    /// The caller must ensure that `null_count` is the number of unset bits among the
+    /// first `length` bits of `vec`.
+    /// # Panic
+    /// Panics iff `length <= buffer.len() * 8`
+    #[inline]
+    pub(crate) unsafe fn from_u8_vec_with_null_count(
+        vec: Vec<u8>,
+        length: usize,
+        null_count: usize,
+    ) -> Self {
+        assert!(length <= vec.len() * 8);
+        Self {
+            length,
+            offset: 0,
+            bytes: Arc::new(vec.into()),
+            null_count,
+        }
+    }
+
+    /// Creates a new [`Bitmap`] of `length` bits from a region of memory kept alive by `owner`,
+    /// without copying it. `owner` is dropped once the last [`Bitmap`] sharing the region
+    /// (including clones and slices) is dropped.
+    /// # Errors
+    /// This function errors iff `ptr` is null.
+    /// # Safety
+    /// The caller must ensure that, for as long as `owner` is alive, `ptr` is valid for reads of
+    /// `length.saturating_add(7) / 8` bytes and the region is not mutated.
+    pub unsafe fn from_owned_ptr(
+        ptr: *const u8,
+        length: usize,
+        owner: Arc<dyn Any + Send + Sync>,
+    ) -> Result<Self> {
+        let ptr = NonNull::new(ptr as *mut u8).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "A Bitmap cannot be created from a null pointer".to_string(),
+            )
+        })?;
+        let bytes = Bytes::new(
+            ptr,
+            length.saturating_add(7) / 8,
+            Deallocation::Owner(owner),
+        );
+        Ok(Self::from_bytes(bytes, length))
+    }
+
     /// Creates a new [`Bitmap`] from a slice and length.
     /// # Panic
     /// Panics iff `length <= bytes.len() * 8`
@@ -98,9 +154,30 @@ impl Bitmap {
     /// Counts the nulls (unset bits) starting from `offset` bits and for `length` bits.
     #[inline]
     pub fn null_count_range(&self, offset: usize, length: usize) -> usize {
+        self.count_zeros(offset, length)
+    }
+
+    /// Counts the unset bits starting from `offset` bits and for `length` bits.
+    /// # Implementation
+    /// This function is `O(length)` and counts bits in chunks of `u64`.
+    /// # Panics
+    /// Panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn count_zeros(&self, offset: usize, length: usize) -> usize {
+        assert!(offset + length <= self.length);
         count_zeros(&self.bytes, self.offset + offset, length)
     }
 
+    /// Counts the set bits starting from `offset` bits and for `length` bits.
+    /// # Implementation
+    /// This function is `O(length)` and counts bits in chunks of `u64`.
+    /// # Panics
+    /// Panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn count_ones(&self, offset: usize, length: usize) -> usize {
+        length - self.count_zeros(offset, length)
+    }
+
     /// Returns the number of unset bits on this [`Bitmap`].
     #[inline]
     pub fn null_count(&self) -> usize {
@@ -122,10 +199,12 @@ impl Bitmap {
     /// The caller must ensure that `self.offset + offset + length <= self.len()`
     #[inline]
     pub unsafe fn slice_unchecked(mut self, offset: usize, length: usize) -> Self {
-        // count the smallest chunk
-        if length < self.length / 2 {
-            // count the null values in the slice
-            self.null_count = count_zeros(&self.bytes, offset, length);
+        if self.null_count == 0 || self.null_count == self.length {
+            // all bits are equal and so are the bits of the slice
+            self.null_count = if self.null_count == 0 { 0 } else { length };
+        } else if length < self.length / 2 {
+            // the slice is the smallest chunk: count its null values
+            self.null_count = count_zeros(&self.bytes, self.offset + offset, length);
         } else {
             // subtract the null count of the chunks we slice off
             let start_end = self.offset + offset + length;
@@ -170,11 +249,49 @@ impl Bitmap {
         self.bytes.ptr()
     }
 
+    /// Returns the size in bytes of the memory region of this [`Bitmap`], which may be shared
+    /// with other bitmaps. This is independent of its offset and length.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        self.bytes.allocated_bytes()
+    }
+
     /// Returns a pointer to the start of this [`Bitmap`] (ignores `offsets`)
     /// This pointer is allocated iff `self.len() > 0`.
     pub(crate) fn offset(&self) -> usize {
         self.offset
     }
+
+    /// Tries to convert this [`Bitmap`] into a [`MutableBitmap`] without copying.
+    /// This succeeds iff this [`Bitmap`] is the sole owner of its memory region, it is not
+    /// offsetted and the region was allocated by Rust (i.e. not via FFI).
+    /// Otherwise, returns itself back.
+    pub fn into_mut(self) -> Either<Self, MutableBitmap> {
+        if self.offset != 0 {
+            return Either::Left(self);
+        }
+        let length = self.length;
+        let null_count = self.null_count;
+        match Arc::try_unwrap(self.bytes) {
+            Ok(bytes) => match bytes.into_vec() {
+                Ok(mut vec) => {
+                    vec.truncate(length.saturating_add(7) / 8);
+                    Either::Right(MutableBitmap::from_vec(vec, length))
+                }
+                Err(bytes) => Either::Left(Self {
+                    bytes: Arc::new(bytes),
+                    offset: 0,
+                    length,
+                    null_count,
+                }),
+            },
+            Err(bytes) => Either::Left(Self {
+                bytes,
+                offset: 0,
+                length,
+                null_count,
+            }),
+        }
+    }
 }
 
 impl<P: AsRef<[bool]>> From<P> for Bitmap {
@@ -4,8 +4,8 @@ use std::iter::FromIterator;
 use crate::bitmap::utils::merge_reversed;
 use crate::trusted_len::TrustedLen;
 
-use super::utils::{count_zeros, fmt, get_bit, set, set_bit, BitmapIter};
-use super::Bitmap;
+use super::utils::{count_zeros, fmt, get_bit, set, set_bit, BitChunks, BitmapIter};
+use super::{chunk_iter_to_vec, Bitmap};
 
 /// A container to store booleans. [`MutableBitmap`] is semantically equivalent
 /// to [`Vec<bool>`], but each value is stored as a single bit, thereby achieving a compression of 8x.
@@ -49,6 +49,22 @@ impl MutableBitmap {
         self.buffer.clear();
     }
 
+    /// Shortens the [`MutableBitmap`] to its first `len` bits.
+    /// This is a no-op if `len` is greater than or equal to its length.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.length {
+            return;
+        }
+        self.buffer.truncate(len.saturating_add(7) / 8);
+        if len % 8 != 0 {
+            // unset the remaining bits of the last byte
+            let last = self.buffer.last_mut().unwrap();
+            *last &= 0b11111111u8 >> (8 - len % 8);
+        }
+        self.length = len;
+    }
+
     /// Initializes a zeroed [`MutableBitmap`].
     #[inline]
     pub fn from_len_zeroed(length: usize) -> Self {
@@ -209,6 +225,43 @@ impl MutableBitmap {
         set_bit(self.buffer.as_mut_slice(), index, value)
     }
 
+    /// Sets the `length` positions starting at `start` to `value`.
+    /// # Implementation
+    /// All but the first and last bytes of the range are set at once.
+    /// # Panics
+    /// Panics iff `start + length > self.len()`.
+    pub fn set_range(&mut self, start: usize, length: usize, value: bool) {
+        assert!(start + length <= self.length);
+        if length == 0 {
+            return;
+        }
+        #[inline]
+        fn apply(byte: &mut u8, mask: u8, value: bool) {
+            if value {
+                *byte |= mask
+            } else {
+                *byte &= !mask
+            }
+        }
+
+        let end = start + length;
+        let first = start / 8;
+        let last = (end - 1) / 8;
+        let buffer = self.buffer.as_mut_slice();
+        if first == last {
+            let mask = (0b11111111u8 >> (8 - length)) << (start % 8);
+            apply(&mut buffer[first], mask, value);
+            return;
+        }
+        apply(&mut buffer[first], 0b11111111u8 << (start % 8), value);
+        apply(
+            &mut buffer[last],
+            0b11111111u8 >> (8 - (end - last * 8)),
+            value,
+        );
+        buffer[first + 1..last].fill(if value { 0b11111111u8 } else { 0 });
+    }
+
     /// Shrinks the capacity of the [`MutableBitmap`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.buffer.shrink_to_fit();
@@ -225,6 +278,18 @@ impl MutableBitmap {
         assert!(length <= buffer.len() * 8);
         Self { buffer, length }
     }
+
+    /// Initializes a [`MutableBitmap`] from an iterator of `u64` chunks of 64 bits each,
+    /// of which the first `length` bits are used.
+    /// # Panic
+    /// Panics iff the iterator yields fewer than `length` bits.
+    #[inline]
+    pub fn from_chunk_iter<I: TrustedLen<Item = u64>>(iterator: I, length: usize) -> Self {
+        let mut buffer = chunk_iter_to_vec(iterator);
+        assert!(length <= buffer.len() * 8);
+        buffer.truncate(length.saturating_add(7) / 8);
+        Self { buffer, length }
+    }
 }
 
 impl From<MutableBitmap> for Bitmap {
@@ -569,11 +634,48 @@ impl MutableBitmap {
         self.length += length;
     }
 
+    fn extend_unaligned_source(&mut self, slice: &[u8], offset: usize, length: usize) {
+        debug_assert!(offset % 8 != 0); // assumed invariant
+
+        // complete the last byte of `self` bit by bit so that `self` becomes aligned
+        let own_offset = self.length % 8;
+        let head = if own_offset == 0 {
+            0
+        } else {
+            std::cmp::min(8 - own_offset, length)
+        };
+        if head > 0 {
+            self.extend_from_trusted_len_iter(BitmapIter::new(slice, offset, head));
+        }
+        let offset = offset + head;
+        let length = length - head;
+        if length == 0 {
+            return;
+        }
+        if offset % 8 == 0 {
+            return self.extend_aligned(slice, offset, length);
+        }
+
+        // copy the remaining bits in words of `u64`, shifted to remove `offset`
+        let chunks = BitChunks::<u64>::new(slice, offset, length);
+        let remainder = chunks.remainder();
+        let remainder_len = chunks.remainder_len();
+        self.buffer.reserve(length.saturating_add(7) / 8);
+        for chunk in chunks {
+            self.buffer.extend_from_slice(&chunk.to_ne_bytes());
+        }
+        self.buffer
+            .extend_from_slice(&remainder.to_ne_bytes()[..remainder_len.saturating_add(7) / 8]);
+        self.length += length;
+    }
+
     /// Extends the [`MutableBitmap`] from a slice of bytes with optional offset.
     /// This is the fastest way to extend a [`MutableBitmap`].
     /// # Implementation
     /// When both [`MutableBitmap`]'s length and `offset` are both multiples of 8,
-    /// this function performs a memcopy. Else, it extends [`MutableBitmap`] bit by bit.
+    /// this function performs a memcopy. Else, it copies the bits in bytes or words of `u64`
+    /// shifted accordingly, and only the bits up to the next byte boundary are extended one
+    /// by one.
     #[inline]
     pub fn extend_from_slice(&mut self, slice: &[u8], offset: usize, length: usize) {
         assert!(offset + length <= slice.len() * 8);
@@ -585,18 +687,20 @@ impl MutableBitmap {
         match (is_aligned, other_is_aligned) {
             (true, true) => self.extend_aligned(slice, offset, length),
             (false, true) => self.extend_unaligned(slice, offset, length),
-            // todo: further optimize the other branches.
-            _ => self.extend_from_trusted_len_iter(BitmapIter::new(slice, offset, length)),
+            (_, false) => self.extend_unaligned_source(slice, offset, length),
         }
         // internal invariant:
         debug_assert_eq!(self.length.saturating_add(7) / 8, self.buffer.len());
     }
 
-    /// Extends the [`MutableBitmap`] from a [`Bitmap`].
+    /// Extends the [`MutableBitmap`] from the `length` bits of a [`Bitmap`] starting at `offset`.
+    /// # Panics
+    /// Panics iff `offset + length > bitmap.len()`.
     #[inline]
-    pub fn extend_from_bitmap(&mut self, bitmap: &Bitmap) {
-        let (slice, offset, length) = bitmap.as_slice();
-        self.extend_from_slice(slice, offset, length);
+    pub fn extend_from_bitmap(&mut self, bitmap: &Bitmap, offset: usize, length: usize) {
+        assert!(offset + length <= bitmap.len());
+        let (slice, bitmap_offset, _) = bitmap.as_slice();
+        self.extend_from_slice(slice, bitmap_offset + offset, length);
     }
 
     /// Returns the slice of bytes of this [`MutableBitmap`].
@@ -606,6 +710,14 @@ impl MutableBitmap {
         let len = (self.length).saturating_add(7) / 8;
         &self.buffer[..len]
     }
+
+    /// Returns the mutable slice of bytes of this [`MutableBitmap`].
+    /// Note that the last byte may not be fully used.
+    #[inline]
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [u8] {
+        let len = (self.length).saturating_add(7) / 8;
+        &mut self.buffer[..len]
+    }
 }
 
 impl Default for MutableBitmap {
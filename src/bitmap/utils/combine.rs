@@ -0,0 +1,62 @@
+use crate::bitmap::{chunk_iter_to_vec, remainder_mask, Bitmap};
+
+/// Returns the intersection of `validities`, where `None` represents a validity whose slots are
+/// all valid. The result is `None` iff every validity is `None`.
+/// # Implementation
+/// The bitmaps are read in chunks of `u64` irrespectively of their offsets and are combined
+/// in a single pass over all of them.
+/// # Panics
+/// This function panics iff the bitmaps do not have the same length.
+pub fn combine_validities(validities: &[Option<&Bitmap>]) -> Option<Bitmap> {
+    combine_validities_with_null_count(validities).0
+}
+
+/// Same as [`combine_validities`], but also returns the number of unset bits of the result
+/// (zero when the result is `None`).
+/// # Panics
+/// This function panics iff the bitmaps do not have the same length.
+pub fn combine_validities_with_null_count(
+    validities: &[Option<&Bitmap>],
+) -> (Option<Bitmap>, usize) {
+    let bitmaps = validities.iter().flatten().copied().collect::<Vec<_>>();
+    match bitmaps.as_slice() {
+        [] => (None, 0),
+        [bitmap] => (Some((*bitmap).clone()), bitmap.null_count()),
+        _ => {
+            let length = bitmaps[0].len();
+            assert!(
+                bitmaps.iter().all(|bitmap| bitmap.len() == length),
+                "validities must have the same length"
+            );
+            let mut chunks = bitmaps
+                .iter()
+                .map(|bitmap| bitmap.chunks::<u64>())
+                .collect::<Vec<_>>();
+
+            // bits past `length` are cleared so that they are not counted
+            let remainder = chunks
+                .iter()
+                .fold(!0u64, |acc, chunks| acc & chunks.remainder())
+                & remainder_mask(length);
+            let mut set_bits = remainder.count_ones() as usize;
+
+            let words = std::iter::repeat_with(|| {
+                let word = chunks
+                    .iter_mut()
+                    .fold(!0u64, |acc, chunks| acc & chunks.next().unwrap());
+                set_bits += word.count_ones() as usize;
+                word
+            })
+            .take(length / 64)
+            .chain(std::iter::once(remainder));
+
+            let buffer = chunk_iter_to_vec(words);
+
+            // Safety: `set_bits` was computed over the first `length` bits
+            let bitmap =
+                unsafe { Bitmap::from_u8_vec_with_null_count(buffer, length, length - set_bits) };
+            let null_count = bitmap.null_count();
+            (Some(bitmap), null_count)
+        }
+    }
+}
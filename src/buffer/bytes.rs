@@ -1,10 +1,13 @@
 //! This module contains an implementation of a contiguous immutable memory region that knows
 //! how to de-allocate itself, [`Bytes`].
 
+use std::any::Any;
 use std::slice;
 use std::{fmt::Debug, fmt::Formatter};
 use std::{ptr::NonNull, sync::Arc};
 
+#[cfg(feature = "alloc_tracking")]
+use crate::alloc;
 use crate::ffi;
 use crate::types::NativeType;
 
@@ -14,6 +17,8 @@ pub enum Deallocation {
     Native(usize),
     // Foreign interface, via a callback
     Foreign(Arc<ffi::ArrowArray>),
+    // Memory owned by an arbitrary object, released when the last reference to it is dropped
+    Owner(Arc<dyn Any + Send + Sync>),
 }
 
 impl Debug for Deallocation {
@@ -22,17 +27,24 @@ impl Debug for Deallocation {
             Deallocation::Native(capacity) => {
                 write!(f, "Deallocation::Native {{ capacity: {} }}", capacity)
             }
-            Deallocation::Foreign(_) => {
-                write!(f, "Deallocation::Foreign {{ capacity: unknown }}")
-            }
+            Deallocation::Foreign(array) => write!(
+                f,
+                "Deallocation::Foreign {{ capacity: unknown, references: {} }}",
+                Arc::strong_count(array)
+            ),
+            Deallocation::Owner(owner) => write!(
+                f,
+                "Deallocation::Owner {{ capacity: unknown, references: {} }}",
+                Arc::strong_count(owner)
+            ),
         }
     }
 }
 
 /// A continuous, fixed-size, immutable memory region that knows how to de-allocate itself.
 ///
-/// In the most common case, this buffer is allocated using [`allocate_aligned`](alloc::allocate_aligned)
-/// and deallocated accordingly [`free_aligned`](alloc::free_aligned).
+/// In the most common case, this buffer is allocated by a [`Vec`] and deallocated accordingly,
+/// which is tracked by `alloc::total_allocated_bytes` when the feature `alloc_tracking` is active.
 /// When the region is allocated by a foreign allocator, [Deallocation::Foreign], this calls the
 /// foreign deallocator to deallocate the region when it is no longer needed.
 pub struct Bytes<T: NativeType> {
@@ -82,6 +94,34 @@ impl<T: NativeType> Bytes<T> {
     pub fn ptr(&self) -> NonNull<T> {
         self.ptr
     }
+
+    /// Returns the size in bytes of this memory region: its allocated capacity when it was
+    /// allocated by Rust and its length otherwise (e.g. via FFI), whose capacity is unknown.
+    #[inline]
+    pub fn allocated_bytes(&self) -> usize {
+        let items = match self.deallocation {
+            Deallocation::Native(capacity) => capacity,
+            Deallocation::Foreign(_) | Deallocation::Owner(_) => self.len,
+        };
+        items * std::mem::size_of::<T>()
+    }
+
+    /// Converts itself into a [`Vec`] without copying, returning itself back when its
+    /// memory region was allocated by a foreign allocator.
+    #[inline]
+    pub fn into_vec(self) -> std::result::Result<Vec<T>, Self> {
+        match self.deallocation {
+            Deallocation::Native(capacity) => {
+                #[cfg(feature = "alloc_tracking")]
+                alloc::track_deallocation(capacity * std::mem::size_of::<T>());
+                let vec = unsafe { Vec::from_raw_parts(self.ptr.as_ptr(), self.len, capacity) };
+                // the region is now owned by `vec`
+                std::mem::forget(self);
+                Ok(vec)
+            }
+            Deallocation::Foreign(_) | Deallocation::Owner(_) => Err(self),
+        }
+    }
 }
 
 impl<T: NativeType> Drop for Bytes<T> {
@@ -89,10 +129,14 @@ impl<T: NativeType> Drop for Bytes<T> {
     fn drop(&mut self) {
         match &self.deallocation {
             Deallocation::Native(capacity) => unsafe {
+                #[cfg(feature = "alloc_tracking")]
+                alloc::track_deallocation(*capacity * std::mem::size_of::<T>());
                 let _ = Vec::from_raw_parts(self.ptr.as_ptr(), self.len, *capacity);
             },
             // foreign interface knows how to deallocate itself.
             Deallocation::Foreign(_) => (),
+            // the owner releases the region when its last reference is dropped.
+            Deallocation::Owner(_) => (),
         }
     }
 }
@@ -127,6 +171,8 @@ impl<T: NativeType> From<Vec<T>> for Bytes<T> {
         let ptr = NonNull::new(data.as_mut_ptr()).unwrap();
         let len = data.len();
         let capacity = data.capacity();
+        #[cfg(feature = "alloc_tracking")]
+        alloc::track_allocation(capacity * std::mem::size_of::<T>());
 
         let result = unsafe { Bytes::new(ptr, len, Deallocation::Native(capacity)) };
         // so that the memory region is not deallocated.
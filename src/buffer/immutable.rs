@@ -1,8 +1,14 @@
-use std::{iter::FromIterator, sync::Arc, usize};
+use std::{any::Any, iter::FromIterator, ptr::NonNull, sync::Arc, usize};
 
-use crate::{trusted_len::TrustedLen, types::NativeType};
+use either::Either;
 
-use super::bytes::Bytes;
+use crate::{
+    error::{ArrowError, Result},
+    trusted_len::TrustedLen,
+    types::NativeType,
+};
+
+use super::bytes::{Bytes, Deallocation};
 
 /// [`Buffer`] is a contiguous memory region that can
 /// be shared across thread boundaries.
@@ -59,6 +65,39 @@ impl<T: NativeType> Buffer<T> {
         data.as_ref().to_vec().into()
     }
 
+    /// Creates a [`Buffer`] of `len` items from a region of memory kept alive by `owner`,
+    /// without copying it. `owner` is dropped once the last [`Buffer`] sharing the region
+    /// (including clones and slices) is dropped; e.g. `owner` may hold a memory map or an
+    /// allocation of a foreign library whose `Drop` releases it.
+    /// # Errors
+    /// This function errors iff `ptr` is null or it is not aligned to `T`.
+    /// # Safety
+    /// The caller must ensure that, for as long as `owner` is alive, `ptr` is valid for reads of
+    /// `len * std::mem::size_of::<T>()` bytes and the region is not mutated.
+    pub unsafe fn from_owned_ptr(
+        ptr: *const T,
+        len: usize,
+        owner: Arc<dyn Any + Send + Sync>,
+    ) -> Result<Self> {
+        let ptr = NonNull::new(ptr as *mut T).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "A Buffer cannot be created from a null pointer".to_string(),
+            )
+        })?;
+        if ptr.as_ptr().align_offset(std::mem::align_of::<T>()) != 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A Buffer of {} requires its pointer to be aligned to {} bytes",
+                std::any::type_name::<T>(),
+                std::mem::align_of::<T>()
+            )));
+        }
+        Ok(Self::from_bytes(Bytes::new(
+            ptr,
+            len,
+            Deallocation::Owner(owner),
+        )))
+    }
+
     /// Auxiliary method to create a new Buffer
     pub(crate) fn from_bytes(bytes: Bytes<T>) -> Self {
         let length = bytes.len();
@@ -118,11 +157,80 @@ impl<T: NativeType> Buffer<T> {
         self.data.ptr()
     }
 
+    /// Returns the size in bytes of the memory region of this buffer, which may be shared with
+    /// other buffers. This is independent of its offset and length.
+    #[inline]
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        self.data.allocated_bytes()
+    }
+
     /// Returns the offset of this buffer.
     #[inline]
     pub fn offset(&self) -> usize {
         self.offset
     }
+
+    /// Tries to convert this [`Buffer`] into a [`Vec`] without copying.
+    /// This succeeds iff this [`Buffer`] is the sole owner of its memory region, it is not
+    /// offsetted and the region was allocated by Rust (i.e. not via FFI).
+    /// Otherwise, returns itself back.
+    /// # Example
+    /// ```
+    /// # use arrow2::buffer::Buffer;
+    /// # use either::Either;
+    /// let buffer: Buffer<u32> = vec![1, 2, 3].into();
+    /// let shared = buffer.clone();
+    /// // shared => it can't be converted
+    /// let buffer = buffer.into_mut().unwrap_left();
+    /// drop(shared);
+    /// assert_eq!(buffer.into_mut(), Either::Right(vec![1, 2, 3]));
+    /// ```
+    pub fn into_mut(self) -> Either<Self, Vec<T>> {
+        if self.offset != 0 {
+            return Either::Left(self);
+        }
+        let length = self.length;
+        match Arc::try_unwrap(self.data) {
+            Ok(bytes) => match bytes.into_vec() {
+                Ok(mut vec) => {
+                    vec.truncate(length);
+                    Either::Right(vec)
+                }
+                Err(bytes) => Either::Left(Self {
+                    data: Arc::new(bytes),
+                    offset: 0,
+                    length,
+                }),
+            },
+            Err(data) => Either::Left(Self {
+                data,
+                offset: 0,
+                length,
+            }),
+        }
+    }
+
+    /// Tries to convert this [`Buffer`] into a [`Vec`] without copying, under the same
+    /// conditions as [`Buffer::into_mut`]. Otherwise, returns itself back.
+    ///
+    /// Together with `Buffer::from(vec)`, which is also zero-copy, this allows a [`Vec`] to be
+    /// handed to arrow2 and back (e.g. to a BLAS routine) without copying it.
+    /// # Example
+    /// ```
+    /// # use arrow2::buffer::Buffer;
+    /// let vec = vec![1u32, 2, 3];
+    /// let ptr = vec.as_ptr();
+    /// let buffer: Buffer<u32> = vec.into();
+    /// let vec = buffer.into_vec().unwrap();
+    /// assert_eq!(vec.as_ptr(), ptr);
+    /// ```
+    #[inline]
+    pub fn into_vec(self) -> std::result::Result<Vec<T>, Self> {
+        match self.into_mut() {
+            Either::Left(buffer) => Err(buffer),
+            Either::Right(vec) => Ok(vec),
+        }
+    }
 }
 
 impl<T: NativeType> Buffer<T> {
@@ -0,0 +1,153 @@
+//! Contains [`ChunkedColumns`], a sequence of [`Columns`] that represents their
+//! concatenation.
+use std::sync::Arc;
+
+use crate::array::Array;
+use crate::columns::Columns;
+use crate::error::{ArrowError, Result};
+
+/// A sequence of [`Columns`] (its chunks) whose arrays have the same [`DataType`]s,
+/// representing the concatenation of all chunks.
+///
+/// [`DataType`]: crate::datatypes::DataType
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkedColumns {
+    chunks: Vec<Columns<Arc<dyn Array>>>,
+    length: usize,
+}
+
+impl ChunkedColumns {
+    /// Creates a new [`ChunkedColumns`].
+    /// # Panic
+    /// Iff the chunks do not have the same number of arrays with the same [`DataType`]s.
+    ///
+    /// [`DataType`]: crate::datatypes::DataType
+    pub fn new(chunks: Vec<Columns<Arc<dyn Array>>>) -> Self {
+        Self::try_new(chunks).unwrap()
+    }
+
+    /// Creates a new [`ChunkedColumns`].
+    /// # Error
+    /// Iff the chunks do not have the same number of arrays with the same [`DataType`]s.
+    ///
+    /// [`DataType`]: crate::datatypes::DataType
+    pub fn try_new(chunks: Vec<Columns<Arc<dyn Array>>>) -> Result<Self> {
+        if let Some(first) = chunks.first() {
+            let is_compatible = |chunk: &Columns<Arc<dyn Array>>| {
+                chunk.arrays().len() == first.arrays().len()
+                    && chunk
+                        .arrays()
+                        .iter()
+                        .zip(first.arrays().iter())
+                        .all(|(lhs, rhs)| lhs.data_type() == rhs.data_type())
+            };
+            if !chunks.iter().all(is_compatible) {
+                return Err(ArrowError::InvalidArgumentError(
+                    "ChunkedColumns require all its chunks to have arrays of the same data types"
+                        .to_string(),
+                ));
+            }
+        }
+        let length = chunks.iter().map(|chunk| chunk.len()).sum();
+        Ok(Self { chunks, length })
+    }
+
+    /// Returns the chunks of this [`ChunkedColumns`].
+    pub fn chunks(&self) -> &[Columns<Arc<dyn Array>>] {
+        &self.chunks
+    }
+
+    /// Consumes [`ChunkedColumns`] into its chunks.
+    pub fn into_chunks(self) -> Vec<Columns<Arc<dyn Array>>> {
+        self.chunks
+    }
+
+    /// Returns the total length (number of rows) over all chunks.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns whether [`ChunkedColumns`] has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a new [`ChunkedColumns`] with the rows `[offset, offset + length)` of `self`.
+    /// The rows may span multiple chunks, in which case the result has one (sliced) chunk
+    /// per chunk of `self` that it touches. This operation does not copy data.
+    /// # Panic
+    /// Iff `offset + length > self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new ChunkedColumns cannot exceed the existing length"
+        );
+        let end = offset + length;
+        let mut start = 0;
+        let mut chunks = vec![];
+        for chunk in &self.chunks {
+            let chunk_end = start + chunk.len();
+            if chunk_end > offset && start < end {
+                let chunk_offset = offset.saturating_sub(start);
+                let chunk_length = end.min(chunk_end) - start - chunk_offset;
+                chunks.push(chunk.slice(chunk_offset, chunk_length));
+            }
+            if chunk_end >= end {
+                break;
+            }
+            start = chunk_end;
+        }
+        Self { chunks, length }
+    }
+
+    /// Returns a new [`ChunkedColumns`] whose chunks have `chunk_size` rows each (the last may
+    /// be shorter). Chunks that already have the requested size are not copied; rows spanning
+    /// multiple chunks are concatenated.
+    /// # Panic
+    /// Iff `chunk_size == 0`.
+    /// # Error
+    /// Iff the arrays cannot be concatenated.
+    #[cfg(feature = "compute_concatenate")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "compute_concatenate")))]
+    pub fn rechunk(&self, chunk_size: usize) -> Result<Self> {
+        use crate::compute::concatenate::concatenate;
+
+        assert!(chunk_size > 0, "chunk_size must be larger than zero");
+        let chunks = (0..self.length)
+            .step_by(chunk_size)
+            .map(|offset| {
+                let mut pieces = self
+                    .slice(offset, chunk_size.min(self.length - offset))
+                    .into_chunks();
+                if pieces.len() == 1 {
+                    return Ok(pieces.pop().unwrap());
+                }
+                let num_arrays = pieces[0].arrays().len();
+                let arrays = (0..num_arrays)
+                    .map(|i| {
+                        let arrays = pieces
+                            .iter()
+                            .map(|piece| piece.arrays()[i].as_ref())
+                            .collect::<Vec<_>>();
+                        concatenate(&arrays).map(Arc::from)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Columns::try_new(arrays)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            chunks,
+            length: self.length,
+        })
+    }
+}
+
+impl From<Columns<Arc<dyn Array>>> for ChunkedColumns {
+    fn from(columns: Columns<Arc<dyn Array>>) -> Self {
+        let length = columns.len();
+        Self {
+            chunks: vec![columns],
+            length,
+        }
+    }
+}
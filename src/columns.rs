@@ -7,7 +7,7 @@ use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
 
 /// A vector of [`Array`] where every array has the same length.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Columns<A: AsRef<dyn Array>> {
     arrays: Vec<A>,
 }
@@ -52,6 +52,11 @@ impl<A: AsRef<dyn Array>> Columns<A> {
             .unwrap_or_default()
     }
 
+    /// returns whether [`Columns`] has no rows
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Consumes [`Columns`] into its underlying arrays.
     /// The arrays are guaranteed to have the same length
     pub fn into_arrays(self) -> Vec<A> {
@@ -59,6 +64,64 @@ impl<A: AsRef<dyn Array>> Columns<A> {
     }
 }
 
+impl Columns<Arc<dyn Array>> {
+    /// Returns a slice of this [`Columns`], slicing every array to `[offset, offset + length)`.
+    /// This operation is `O(C)` where `C` is the number of arrays and does not copy data.
+    /// # Panic
+    /// Iff `offset + length > self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new Columns cannot exceed the existing length"
+        );
+        Self {
+            arrays: self
+                .arrays
+                .iter()
+                .map(|array| array.slice(offset, length).into())
+                .collect(),
+        }
+    }
+
+    /// Returns the first `n` rows of this [`Columns`] (or all its rows if it has fewer than `n`).
+    /// Like [`Columns::slice`], this does not copy data.
+    pub fn head(&self, n: usize) -> Self {
+        self.slice(0, n.min(self.len()))
+    }
+
+    /// Returns the last `n` rows of this [`Columns`] (or all its rows if it has fewer than `n`).
+    /// Like [`Columns::slice`], this does not copy data.
+    pub fn tail(&self, n: usize) -> Self {
+        let length = n.min(self.len());
+        self.slice(self.len() - length, length)
+    }
+
+    /// Returns an iterator of consecutive [`Columns`] of `chunk_size` rows each (the last may be
+    /// shorter) that together cover all rows of `self`. Each item is a zero-copy slice of `self`.
+    /// # Panic
+    /// Iff `chunk_size == 0`.
+    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = Self> + '_ {
+        assert!(chunk_size > 0, "chunk_size must be larger than zero");
+        let length = self.len();
+        (0..length)
+            .step_by(chunk_size)
+            .map(move |offset| self.slice(offset, chunk_size.min(length - offset)))
+    }
+}
+
+/// Two [`Columns`] are equal iff they have the same number of arrays and every pair of arrays is
+/// logically equal (see [`PartialEq`] of [`Array`]), irrespectively of how the arrays are held.
+impl<A: AsRef<dyn Array>, B: AsRef<dyn Array>> PartialEq<Columns<B>> for Columns<A> {
+    fn eq(&self, other: &Columns<B>) -> bool {
+        self.arrays.len() == other.arrays.len()
+            && self
+                .arrays
+                .iter()
+                .zip(other.arrays.iter())
+                .all(|(lhs, rhs)| lhs.as_ref() == rhs.as_ref())
+    }
+}
+
 impl<A: AsRef<dyn Array>> From<Columns<A>> for Vec<A> {
     fn from(c: Columns<A>) -> Self {
         c.into_arrays()
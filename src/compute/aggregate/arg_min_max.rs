@@ -0,0 +1,227 @@
+use std::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::array::ord::total_cmp_f64_with_nans;
+use crate::array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array};
+use crate::compute::arity::unary;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::{f16, NativeType};
+
+/// Returns the index of the first non-null item of `iter` that no other item `replaces`,
+/// where `replaces(new, current)` declares whether `new` is strictly more extreme than `current`.
+fn arg_extremum<T, I, F>(iter: I, replaces: F) -> Option<usize>
+where
+    I: Iterator<Item = Option<T>>,
+    F: Fn(&T, &T) -> bool,
+{
+    iter.enumerate()
+        .filter_map(|(index, value)| value.map(|value| (index, value)))
+        .reduce(|current, new| {
+            if replaces(&new.1, &current.1) {
+                new
+            } else {
+                current
+            }
+        })
+        .map(|(index, _)| index)
+}
+
+/// Compares two floats in the same order as [`super::min_float`]: NaNs are greater than any other
+/// value when `nans_greatest` is `true` and smaller than any other value otherwise,
+/// and `-0.0` is smaller than `+0.0`.
+fn float_cmp<T: Float>(lhs: T, rhs: T, nans_greatest: bool) -> Ordering {
+    // `f16`, `f32` and `f64` convert to `f64` losslessly, preserving NaNs and the sign of zeros
+    let (lhs, rhs) = (lhs.to_f64().unwrap(), rhs.to_f64().unwrap());
+    total_cmp_f64_with_nans(&lhs, &rhs, nans_greatest)
+}
+
+/// Returns the index of the minimum value of the array, or `None` if the array is empty or
+/// all its values are null. Ties are resolved to the first occurrence.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+pub fn arg_min_primitive<T: NativeType + Ord>(array: &PrimitiveArray<T>) -> Option<usize> {
+    arg_extremum(array.iter(), |new, current| new < current)
+}
+
+/// Returns the index of the maximum value of the array, or `None` if the array is empty or
+/// all its values are null. Ties are resolved to the first occurrence.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+pub fn arg_max_primitive<T: NativeType + Ord>(array: &PrimitiveArray<T>) -> Option<usize> {
+    arg_extremum(array.iter(), |new, current| new > current)
+}
+
+/// Returns the index of the minimum value of a floating point array, where values are
+/// ordered as in [`super::min_float`]. Ties are resolved to the first occurrence.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+pub fn arg_min_float<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    nans_greatest: bool,
+) -> Option<usize> {
+    arg_extremum(array.iter(), |new, current| {
+        float_cmp(**new, **current, nans_greatest) == Ordering::Less
+    })
+}
+
+/// Returns the index of the maximum value of a floating point array, where values are
+/// ordered as in [`super::max_float`]. Ties are resolved to the first occurrence.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+pub fn arg_max_float<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    nans_greatest: bool,
+) -> Option<usize> {
+    arg_extremum(array.iter(), |new, current| {
+        float_cmp(**new, **current, nans_greatest) == Ordering::Greater
+    })
+}
+
+/// Returns the index of the minimum value in the string array, according to the natural order.
+/// Ties are resolved to the first occurrence.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+pub fn arg_min_string<O: Offset>(array: &Utf8Array<O>) -> Option<usize> {
+    arg_extremum(array.iter(), |new, current| new < current)
+}
+
+/// Returns the index of the maximum value in the string array, according to the natural order.
+/// Ties are resolved to the first occurrence.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+pub fn arg_max_string<O: Offset>(array: &Utf8Array<O>) -> Option<usize> {
+    arg_extremum(array.iter(), |new, current| new > current)
+}
+
+/// Returns the index of the minimum value in the binary array, according to the natural order.
+/// Ties are resolved to the first occurrence.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+pub fn arg_min_binary<O: Offset>(array: &BinaryArray<O>) -> Option<usize> {
+    arg_extremum(array.iter(), |new, current| new < current)
+}
+
+/// Returns the index of the maximum value in the binary array, according to the natural order.
+/// Ties are resolved to the first occurrence.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+pub fn arg_max_binary<O: Offset>(array: &BinaryArray<O>) -> Option<usize> {
+    arg_extremum(array.iter(), |new, current| new > current)
+}
+
+/// Returns the index of the first `false` of the boolean array, or of its first non-null
+/// value if it has no `false`.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+/// ```
+/// use arrow2::{
+///   array::BooleanArray,
+///   compute::aggregate::arg_min_boolean,
+/// };
+///
+/// let a = BooleanArray::from(vec![Some(true), None, Some(false), Some(false)]);
+/// assert_eq!(arg_min_boolean(&a), Some(2))
+/// ```
+pub fn arg_min_boolean(array: &BooleanArray) -> Option<usize> {
+    arg_extremum(array.iter(), |new, current| new < current)
+}
+
+/// Returns the index of the first `true` of the boolean array, or of its first non-null
+/// value if it has no `true`.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+/// ```
+/// use arrow2::{
+///   array::BooleanArray,
+///   compute::aggregate::arg_max_boolean,
+/// };
+///
+/// let a = BooleanArray::from(vec![None, Some(false), Some(true), Some(true)]);
+/// assert_eq!(arg_max_boolean(&a), Some(2))
+/// ```
+pub fn arg_max_boolean(array: &BooleanArray) -> Option<usize> {
+    arg_extremum(array.iter(), |new, current| new > current)
+}
+
+macro_rules! dyn_arg {
+    ($array_ty:ty, $array:expr, $f:expr) => {{
+        let array = $array.as_any().downcast_ref::<$array_ty>().unwrap();
+        $f(array)
+    }};
+}
+
+macro_rules! dyn_arg_min_max {
+    ($name:literal, $array:expr, $primitive:ident, $float:ident, $string:ident, $binary:ident, $boolean:ident) => {{
+        let array = $array;
+        Ok(match array.data_type().to_logical_type() {
+            DataType::Boolean => dyn_arg!(BooleanArray, array, $boolean),
+            DataType::Int8 => dyn_arg!(PrimitiveArray<i8>, array, $primitive),
+            DataType::Int16 => dyn_arg!(PrimitiveArray<i16>, array, $primitive),
+            DataType::Int32
+            | DataType::Date32
+            | DataType::Time32(_)
+            | DataType::Interval(IntervalUnit::YearMonth) => {
+                dyn_arg!(PrimitiveArray<i32>, array, $primitive)
+            }
+            DataType::Int64
+            | DataType::Date64
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _)
+            | DataType::Duration(_) => dyn_arg!(PrimitiveArray<i64>, array, $primitive),
+            DataType::Decimal(_, _) => dyn_arg!(PrimitiveArray<i128>, array, $primitive),
+            DataType::UInt8 => dyn_arg!(PrimitiveArray<u8>, array, $primitive),
+            DataType::UInt16 => dyn_arg!(PrimitiveArray<u16>, array, $primitive),
+            DataType::UInt32 => dyn_arg!(PrimitiveArray<u32>, array, $primitive),
+            DataType::UInt64 => dyn_arg!(PrimitiveArray<u64>, array, $primitive),
+            // `f16` is compared via its (lossless) conversion to `f32`
+            DataType::Float16 => dyn_arg!(PrimitiveArray<f16>, array, |x| {
+                $float(&unary(x, |x: f16| x.to_f32(), DataType::Float32), true)
+            }),
+            DataType::Float32 => {
+                dyn_arg!(PrimitiveArray<f32>, array, |x| $float(x, true))
+            }
+            DataType::Float64 => {
+                dyn_arg!(PrimitiveArray<f64>, array, |x| $float(x, true))
+            }
+            DataType::Utf8 => dyn_arg!(Utf8Array<i32>, array, $string),
+            DataType::LargeUtf8 => dyn_arg!(Utf8Array<i64>, array, $string),
+            DataType::Binary => dyn_arg!(BinaryArray<i32>, array, $binary),
+            DataType::LargeBinary => dyn_arg!(BinaryArray<i64>, array, $binary),
+            _ => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The `{}` operator does not support type `{:?}`",
+                    $name,
+                    array.data_type(),
+                )))
+            }
+        })
+    }};
+}
+
+/// Returns the index of the minimum of [`Array`], or `None` when the array is empty or all its
+/// elements are null. Ties are resolved to the first occurrence and floats are ordered as in
+/// [`super::min_float`], with NaNs greater than any other value.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+/// # Error
+/// Errors iff the type does not support this operation.
+pub fn arg_min(array: &dyn Array) -> Result<Option<usize>> {
+    dyn_arg_min_max!(
+        "arg_min",
+        array,
+        arg_min_primitive,
+        arg_min_float,
+        arg_min_string,
+        arg_min_binary,
+        arg_min_boolean
+    )
+}
+
+/// Returns the index of the maximum of [`Array`], or `None` when the array is empty or all its
+/// elements are null. Ties are resolved to the first occurrence and floats are ordered as in
+/// [`super::max_float`], with NaNs greater than any other value.
+/// The index is relative to the array, i.e. for a sliced array it is relative to the slice.
+/// # Error
+/// Errors iff the type does not support this operation.
+pub fn arg_max(array: &dyn Array) -> Result<Option<usize>> {
+    dyn_arg_min_max!(
+        "arg_max",
+        array,
+        arg_max_primitive,
+        arg_max_float,
+        arg_max_string,
+        arg_max_binary,
+        arg_max_boolean
+    )
+}
@@ -27,6 +27,8 @@ macro_rules! dyn_binary {
 /// the visible size of the buffer, not its total capacity.
 ///
 /// FFI buffers are included in this estimation.
+///
+/// See [`Array::get_buffer_memory_size`] for the exact size of the memory kept alive by an array.
 pub fn estimated_bytes_size(array: &dyn Array) -> usize {
     use PhysicalType::*;
     match array.data_type().to_physical_type() {
@@ -61,7 +63,7 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
                 + validity_size(array.validity())
         }
         FixedSizeList => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             estimated_bytes_size(array.values().as_ref()) + validity_size(array.validity())
         }
         LargeList => {
@@ -1,13 +1,15 @@
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
+use crate::compute::arity::unary;
 use crate::datatypes::{DataType, IntervalUnit};
 use crate::error::{ArrowError, Result};
 use crate::scalar::*;
 use crate::types::simd::*;
-use crate::types::NativeType;
+use crate::types::{f16, NativeType};
 use crate::{
     array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array},
     bitmap::Bitmap,
 };
+use num_traits::Float;
 
 /// Trait describing a type describing multiple lanes with an order relationship
 /// consistent with the same order of `T`.
@@ -227,7 +229,8 @@ where
 }
 
 /// Returns the minimum value in the array, according to the natural order.
-/// For floating point arrays any NaN values are considered to be greater than any other non-null value
+/// For floating point arrays NaN values are ignored unless every non-null value is NaN
+/// (see [`min_float`] for an ordering of floats consistent with [`crate::compute::sort`]).
 pub fn min_primitive<T>(array: &PrimitiveArray<T>) -> Option<T>
 where
     T: NativeType + Simd,
@@ -249,7 +252,8 @@ where
 }
 
 /// Returns the maximum value in the array, according to the natural order.
-/// For floating point arrays any NaN values are considered to be greater than any other non-null value
+/// For floating point arrays NaN values are ignored unless every non-null value is NaN
+/// (see [`max_float`] for an ordering of floats consistent with [`crate::compute::sort`]).
 pub fn max_primitive<T>(array: &PrimitiveArray<T>) -> Option<T>
 where
     T: NativeType + Simd,
@@ -270,6 +274,67 @@ where
     })
 }
 
+// whether any non-null value of `array` fulfills `predicate`
+fn any_valid<T: NativeType, F: Fn(T) -> bool>(array: &PrimitiveArray<T>, predicate: F) -> bool {
+    if let Some(validity) = array.validity() {
+        array
+            .values()
+            .iter()
+            .zip(validity.iter())
+            .any(|(x, is_valid)| is_valid && predicate(*x))
+    } else {
+        array.values().iter().any(|x| predicate(*x))
+    }
+}
+
+/// Returns the minimum value of a floating point array, ordering values in the same way as
+/// [`crate::compute::sort`]: NaNs are greater than any other value when `nans_greatest` is
+/// `true` and smaller than any other value otherwise, and `-0.0` is smaller than `+0.0`.
+pub fn min_float<T>(array: &PrimitiveArray<T>, nans_greatest: bool) -> Option<T>
+where
+    T: NativeType + Simd + Float,
+    T::Simd: SimdOrd<T>,
+{
+    // NaNs are ignored by `min_primitive` unless all values are NaN
+    let min = min_primitive(array)?;
+    if !nans_greatest && any_valid(array, |x| x.is_nan()) {
+        return Some(T::nan());
+    }
+    Some(
+        if min.is_zero() && any_valid(array, |x| x.is_zero() && x.is_sign_negative()) {
+            -T::zero()
+        } else if min.is_zero() {
+            T::zero()
+        } else {
+            min
+        },
+    )
+}
+
+/// Returns the maximum value of a floating point array, ordering values in the same way as
+/// [`crate::compute::sort`]: NaNs are greater than any other value when `nans_greatest` is
+/// `true` and smaller than any other value otherwise, and `-0.0` is smaller than `+0.0`.
+pub fn max_float<T>(array: &PrimitiveArray<T>, nans_greatest: bool) -> Option<T>
+where
+    T: NativeType + Simd + Float,
+    T::Simd: SimdOrd<T>,
+{
+    // NaNs are ignored by `max_primitive` unless all values are NaN
+    let max = max_primitive(array)?;
+    if nans_greatest && any_valid(array, |x| x.is_nan()) {
+        return Some(T::nan());
+    }
+    Some(
+        if max.is_zero() && any_valid(array, |x| x.is_zero() && x.is_sign_positive()) {
+            T::zero()
+        } else if max.is_zero() {
+            -T::zero()
+        } else {
+            max
+        },
+    )
+}
+
 /// Returns the maximum value in the binary array, according to the natural order.
 pub fn max_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
     min_max_binary(array, |a, b| a < b)
@@ -353,6 +418,34 @@ macro_rules! dyn_primitive {
     }};
 }
 
+// `f16` is aggregated via its (lossless) conversion to `f32`
+macro_rules! dyn_f16 {
+    ($array:expr, $f:ident) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f16>>()
+            .unwrap();
+        let array = unary(array, |x| x.to_f32(), DataType::Float32);
+        Box::new(PrimitiveScalar::<f16>::new(
+            $array.data_type().clone(),
+            $f::<f32>(&array, true).map(f16::from_f32),
+        ))
+    }};
+}
+
+macro_rules! dyn_float {
+    ($ty:ty, $array:expr, $f:ident) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        Box::new(PrimitiveScalar::<$ty>::new(
+            $array.data_type().clone(),
+            $f::<$ty>(array, true),
+        ))
+    }};
+}
+
 macro_rules! dyn_generic {
     ($array_ty:ty, $scalar_ty:ty, $array:expr, $f:ident) => {{
         let array = $array.as_any().downcast_ref::<$array_ty>().unwrap();
@@ -361,10 +454,11 @@ macro_rules! dyn_generic {
 }
 
 /// Returns the maximum of [`Array`]. The scalar is null when all elements are null.
+/// Floats are ordered as in [`max_float`], with NaNs greater than any other value.
 /// # Error
 /// Errors iff the type does not support this operation.
 pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
-    Ok(match array.data_type() {
+    Ok(match array.data_type().to_logical_type() {
         DataType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, max_boolean),
         DataType::Int8 => dyn_primitive!(i8, array, max_primitive),
         DataType::Int16 => dyn_primitive!(i16, array, max_primitive),
@@ -383,9 +477,9 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         DataType::UInt16 => dyn_primitive!(u16, array, max_primitive),
         DataType::UInt32 => dyn_primitive!(u32, array, max_primitive),
         DataType::UInt64 => dyn_primitive!(u64, array, max_primitive),
-        DataType::Float16 => unreachable!(),
-        DataType::Float32 => dyn_primitive!(f32, array, max_primitive),
-        DataType::Float64 => dyn_primitive!(f64, array, max_primitive),
+        DataType::Float16 => dyn_f16!(array, max_float),
+        DataType::Float32 => dyn_float!(f32, array, max_float),
+        DataType::Float64 => dyn_float!(f64, array, max_float),
         DataType::Utf8 => dyn_generic!(Utf8Array<i32>, Utf8Scalar<i32>, array, max_string),
         DataType::LargeUtf8 => dyn_generic!(Utf8Array<i64>, Utf8Scalar<i64>, array, max_string),
         DataType::Binary => dyn_generic!(BinaryArray<i32>, BinaryScalar<i32>, array, max_binary),
@@ -402,10 +496,11 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
 }
 
 /// Returns the minimum of [`Array`]. The scalar is null when all elements are null.
+/// Floats are ordered as in [`min_float`], with NaNs greater than any other value.
 /// # Error
 /// Errors iff the type does not support this operation.
 pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
-    Ok(match array.data_type() {
+    Ok(match array.data_type().to_logical_type() {
         DataType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, min_boolean),
         DataType::Int8 => dyn_primitive!(i8, array, min_primitive),
         DataType::Int16 => dyn_primitive!(i16, array, min_primitive),
@@ -424,9 +519,9 @@ pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         DataType::UInt16 => dyn_primitive!(u16, array, min_primitive),
         DataType::UInt32 => dyn_primitive!(u32, array, min_primitive),
         DataType::UInt64 => dyn_primitive!(u64, array, min_primitive),
-        DataType::Float16 => unreachable!(),
-        DataType::Float32 => dyn_primitive!(f32, array, min_primitive),
-        DataType::Float64 => dyn_primitive!(f64, array, min_primitive),
+        DataType::Float16 => dyn_f16!(array, min_float),
+        DataType::Float32 => dyn_float!(f32, array, min_float),
+        DataType::Float64 => dyn_float!(f64, array, min_float),
         DataType::Utf8 => dyn_generic!(Utf8Array<i32>, Utf8Scalar<i32>, array, min_string),
         DataType::LargeUtf8 => dyn_generic!(Utf8Array<i64>, Utf8Scalar<i64>, array, min_string),
         DataType::Binary => dyn_generic!(BinaryArray<i32>, BinaryScalar<i32>, array, min_binary),
@@ -5,6 +5,16 @@ pub use sum::*;
 mod min_max;
 pub use min_max::*;
 
+mod arg_min_max;
+pub use arg_min_max::*;
+
 mod memory;
 pub use memory::*;
+
+mod statistics;
+pub use statistics::*;
+
+mod variance;
+pub use variance::*;
+
 mod simd;
@@ -0,0 +1,242 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use num_traits::AsPrimitive;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::compute::arity::unary;
+use crate::compute::utils::total_cmp;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
+use crate::scalar::{PrimitiveScalar, Scalar};
+use crate::types::{f16, NativeType};
+
+/// Returns the most frequent non-null value of the array, or `None` if the array is empty or
+/// all its values are null. Ties are resolved to the smallest value.
+///
+/// Values are counted by their physical representation, e.g. two floats are the same value iff
+/// their bits are equal (so that `-0.0` and `0.0` are counted separately). NaNs are greater
+/// than any other value when resolving ties.
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::mode_primitive;
+///
+/// let array = Int32Array::from(&[Some(3), Some(1), None, Some(3), Some(1), Some(2)]);
+/// assert_eq!(mode_primitive(&array), Some(1));
+/// ```
+pub fn mode_primitive<T>(array: &PrimitiveArray<T>) -> Option<T>
+where
+    T: NativeType + PartialOrd,
+    T::Bytes: Hash + Eq,
+{
+    let mut counts = HashMap::<T::Bytes, (T, usize)>::new();
+    array
+        .iter()
+        .flatten()
+        .for_each(|x| counts.entry(x.to_le_bytes()).or_insert((*x, 0)).1 += 1);
+
+    counts
+        .values()
+        .copied()
+        .reduce(|current, new| {
+            let ordering = new
+                .1
+                .cmp(&current.1)
+                .then_with(|| total_cmp(&current.0, &new.0));
+            if ordering == Ordering::Greater {
+                new
+            } else {
+                current
+            }
+        })
+        .map(|(value, _)| value)
+}
+
+/// The method used by [`quantile_primitive`] to compute a quantile that lies between two
+/// values `i <= j` of the array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuantileInterpolation {
+    /// `i + (j - i) * fraction`, where `fraction` is the fractional part of the quantile's position.
+    Linear,
+    /// `i`
+    Lower,
+    /// `j`
+    Higher,
+    /// `i` or `j`, whichever is nearest to the quantile's position, or `i` when both are as near.
+    Nearest,
+    /// `(i + j) / 2`
+    Midpoint,
+}
+
+/// Returns the `q`-th quantile of the non-null values of the array, or `None` if the array is
+/// empty or all its values are null. The quantile's position is `q * (n - 1)` of the `n`
+/// non-null values in ascending order, and positions between two values are resolved
+/// according to `interpolation`.
+///
+/// The relevant values are found via a selection over a copy of the values of the array
+/// (O(n) on average) rather than by sorting them. NaNs are greater than any other value.
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::{quantile_primitive, QuantileInterpolation};
+///
+/// let array = Int32Array::from(&[Some(4), None, Some(1), Some(2), Some(3)]);
+/// assert_eq!(quantile_primitive(&array, 0.5, QuantileInterpolation::Linear), Some(2.5));
+/// assert_eq!(quantile_primitive(&array, 0.5, QuantileInterpolation::Lower), Some(2.0));
+/// ```
+/// # Panics
+/// This function panics iff `q` is not in `[0, 1]`.
+pub fn quantile_primitive<T>(
+    array: &PrimitiveArray<T>,
+    q: f64,
+    interpolation: QuantileInterpolation,
+) -> Option<f64>
+where
+    T: NativeType + PartialOrd + AsPrimitive<f64>,
+{
+    assert!(
+        (0.0..=1.0).contains(&q),
+        "quantile must be in [0, 1], got {}",
+        q
+    );
+    let mut values = array.iter().flatten().copied().collect::<Vec<_>>();
+    if values.is_empty() {
+        return None;
+    }
+
+    let position = q * (values.len() - 1) as f64;
+    let index = position.floor() as usize;
+    let fraction = position - index as f64;
+
+    let (_, lower, greater) = values.select_nth_unstable_by(index, total_cmp);
+    let lower: f64 = lower.as_();
+    if fraction == 0.0 {
+        return Some(lower);
+    }
+    // `fraction > 0` implies that `index` is not the last position, i.e. `greater` is not empty
+    let higher: f64 = greater
+        .iter()
+        .min_by(|a, b| total_cmp(*a, *b))
+        .unwrap()
+        .as_();
+
+    Some(match interpolation {
+        QuantileInterpolation::Linear => lower + (higher - lower) * fraction,
+        QuantileInterpolation::Lower => lower,
+        QuantileInterpolation::Higher => higher,
+        QuantileInterpolation::Nearest if fraction > 0.5 => higher,
+        QuantileInterpolation::Nearest => lower,
+        QuantileInterpolation::Midpoint => (lower + higher) / 2.0,
+    })
+}
+
+macro_rules! dyn_mode {
+    ($ty:ty, $array:expr) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        Box::new(PrimitiveScalar::<$ty>::new(
+            $array.data_type().clone(),
+            mode_primitive::<$ty>(array),
+        ))
+    }};
+}
+
+/// Returns the most frequent non-null value of [`Array`] as in [`mode_primitive`]. The scalar
+/// is null when the array is empty or all its elements are null.
+/// # Error
+/// Errors iff the type does not support this operation.
+pub fn mode(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    Ok(match array.data_type().to_logical_type() {
+        DataType::Int8 => dyn_mode!(i8, array),
+        DataType::Int16 => dyn_mode!(i16, array),
+        DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => dyn_mode!(i32, array),
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => dyn_mode!(i64, array),
+        DataType::Decimal(_, _) => dyn_mode!(i128, array),
+        DataType::UInt8 => dyn_mode!(u8, array),
+        DataType::UInt16 => dyn_mode!(u16, array),
+        DataType::UInt32 => dyn_mode!(u32, array),
+        DataType::UInt64 => dyn_mode!(u64, array),
+        // `f16` is counted via its (lossless) conversion to `f32`
+        DataType::Float16 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            let array = unary(array, |x| x.to_f32(), DataType::Float32);
+            Box::new(PrimitiveScalar::<f16>::new(
+                DataType::Float16,
+                mode_primitive(&array).map(f16::from_f32),
+            ))
+        }
+        DataType::Float32 => dyn_mode!(f32, array),
+        DataType::Float64 => dyn_mode!(f64, array),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The `mode` operator does not support type `{:?}`",
+                array.data_type(),
+            )))
+        }
+    })
+}
+
+macro_rules! dyn_quantile {
+    ($ty:ty, $array:expr, $q:expr, $interpolation:expr) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        quantile_primitive::<$ty>(array, $q, $interpolation)
+    }};
+}
+
+/// Returns the `q`-th quantile of the non-null values of [`Array`] as in [`quantile_primitive`],
+/// or `None` when the array is empty or all its elements are null.
+/// # Error
+/// Errors iff the type does not support this operation or `q` is not in `[0, 1]`.
+pub fn quantile(
+    array: &dyn Array,
+    q: f64,
+    interpolation: QuantileInterpolation,
+) -> Result<Option<f64>> {
+    if !(0.0..=1.0).contains(&q) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The quantile must be in [0, 1], got {}",
+            q
+        )));
+    }
+    Ok(match array.data_type().to_logical_type() {
+        DataType::Int8 => dyn_quantile!(i8, array, q, interpolation),
+        DataType::Int16 => dyn_quantile!(i16, array, q, interpolation),
+        DataType::Int32 => dyn_quantile!(i32, array, q, interpolation),
+        DataType::Int64 => dyn_quantile!(i64, array, q, interpolation),
+        DataType::UInt8 => dyn_quantile!(u8, array, q, interpolation),
+        DataType::UInt16 => dyn_quantile!(u16, array, q, interpolation),
+        DataType::UInt32 => dyn_quantile!(u32, array, q, interpolation),
+        DataType::UInt64 => dyn_quantile!(u64, array, q, interpolation),
+        DataType::Float16 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            let array = unary(array, |x| x.to_f32(), DataType::Float32);
+            quantile_primitive(&array, q, interpolation)
+        }
+        DataType::Float32 => dyn_quantile!(f32, array, q, interpolation),
+        DataType::Float64 => dyn_quantile!(f64, array, q, interpolation),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The `quantile` operator does not support type `{:?}`",
+                array.data_type(),
+            )))
+        }
+    })
+}
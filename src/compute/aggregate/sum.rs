@@ -3,11 +3,12 @@ use std::ops::Add;
 use multiversion::multiversion;
 
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
+use crate::compute::arity::unary;
 use crate::datatypes::{DataType, IntervalUnit};
 use crate::error::{ArrowError, Result};
 use crate::scalar::*;
 use crate::types::simd::*;
-use crate::types::NativeType;
+use crate::types::{f16, NativeType};
 use crate::{
     array::{Array, PrimitiveArray},
     bitmap::Bitmap,
@@ -145,7 +146,7 @@ pub fn can_sum(data_type: &DataType) -> bool {
 /// # Error
 /// Errors iff the operation is not supported.
 pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
-    Ok(match array.data_type() {
+    Ok(match array.data_type().to_logical_type() {
         DataType::Int8 => dyn_sum!(i8, array),
         DataType::Int16 => dyn_sum!(i16, array),
         DataType::Int32
@@ -163,7 +164,18 @@ pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         DataType::UInt16 => dyn_sum!(u16, array),
         DataType::UInt32 => dyn_sum!(u32, array),
         DataType::UInt64 => dyn_sum!(u64, array),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => {
+            // summed as `f32` and rounded to the nearest `f16` at the end
+            let values = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            let values = unary(values, |x| x.to_f32(), DataType::Float32);
+            Box::new(PrimitiveScalar::<f16>::new(
+                array.data_type().clone(),
+                sum_primitive::<f32>(&values).map(f16::from_f32),
+            ))
+        }
         DataType::Float32 => dyn_sum!(f32, array),
         DataType::Float64 => dyn_sum!(f64, array),
         _ => {
@@ -0,0 +1,229 @@
+use num_traits::AsPrimitive;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::{f16, NativeType};
+
+/// Single-pass and numerically stable accumulator of the count, mean and sum of squared
+/// differences from the mean (`m2`) of a set of values, from which their [`mean`](Self::mean),
+/// [`variance`](Self::variance) and [`stddev`](Self::stddev) are derived.
+///
+/// Values are accumulated with Welford's algorithm, which does not suffer from the
+/// catastrophic cancellation of the naive `sum(x^2) - sum(x)^2 / n`. Accumulators of
+/// different sets of values (e.g. of different chunks of a column) can be combined via
+/// [`merge`](Self::merge).
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::VarianceAccumulator;
+///
+/// let mut lhs = VarianceAccumulator::default();
+/// lhs.extend_primitive(&Int32Array::from(&[Some(1), None, Some(2)]));
+/// let mut rhs = VarianceAccumulator::default();
+/// rhs.extend_primitive(&Int32Array::from_slice(&[3, 4]));
+///
+/// lhs.merge(&rhs);
+/// assert_eq!(lhs.count(), 4);
+/// assert_eq!(lhs.mean(), Some(2.5));
+/// assert_eq!(lhs.variance(1), Some(5.0 / 3.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VarianceAccumulator {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl VarianceAccumulator {
+    /// Returns a new [`VarianceAccumulator`] from its parts. `m2` is the sum of the squared
+    /// differences between each value and `mean`.
+    pub fn from_parts(count: usize, mean: f64, m2: f64) -> Self {
+        Self { count, mean, m2 }
+    }
+
+    /// Returns the number of accumulated values.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the sum of the squared differences between each accumulated value and their mean.
+    #[inline]
+    pub fn m2(&self) -> f64 {
+        self.m2
+    }
+
+    /// Accumulates `value`.
+    #[inline]
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Accumulates every non-null value of `array`.
+    pub fn extend_primitive<T: NativeType + AsPrimitive<f64>>(
+        &mut self,
+        array: &PrimitiveArray<T>,
+    ) {
+        if array.null_count() == 0 {
+            array.values().iter().for_each(|x| self.push(x.as_()))
+        } else {
+            array.iter().flatten().for_each(|x| self.push(x.as_()))
+        }
+    }
+
+    /// Combines the values accumulated by `other` into `self`, as if they had been
+    /// accumulated by `self`.
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let other_weight = other.count as f64 / count as f64;
+        self.mean += delta * other_weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * other_weight;
+        self.count = count;
+    }
+
+    /// Returns the mean of the accumulated values, or `None` if no value was accumulated.
+    #[inline]
+    pub fn mean(&self) -> Option<f64> {
+        if self.count > 0 {
+            Some(self.mean)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the variance of the accumulated values with `ddof` delta degrees of freedom,
+    /// i.e. `m2 / (count - ddof)`: `0` for the population variance and `1` for the sample
+    /// variance. Returns `None` if `count <= ddof`.
+    #[inline]
+    pub fn variance(&self, ddof: u8) -> Option<f64> {
+        let ddof = ddof as usize;
+        (self.count > ddof).then(|| self.m2 / (self.count - ddof) as f64)
+    }
+
+    /// Returns the standard deviation of the accumulated values with `ddof` delta degrees
+    /// of freedom, i.e. the square root of [`variance`](Self::variance).
+    #[inline]
+    pub fn stddev(&self, ddof: u8) -> Option<f64> {
+        self.variance(ddof).map(|x| x.sqrt())
+    }
+}
+
+/// Returns the mean of the non-null values of the array, or `None` if the array is empty
+/// or all its values are null.
+pub fn mean_primitive<T: NativeType + AsPrimitive<f64>>(array: &PrimitiveArray<T>) -> Option<f64> {
+    let mut accumulator = VarianceAccumulator::default();
+    accumulator.extend_primitive(array);
+    accumulator.mean()
+}
+
+/// Returns the variance of the non-null values of the array with `ddof` delta degrees
+/// of freedom (`0` for the population variance and `1` for the sample variance), or `None`
+/// if the array has `ddof` or fewer non-null values.
+/// ```
+/// use arrow2::array::Float64Array;
+/// use arrow2::compute::aggregate::var_primitive;
+///
+/// let array = Float64Array::from(&[Some(1.0), None, Some(2.0), Some(3.0)]);
+/// assert_eq!(var_primitive(&array, 0), Some(2.0 / 3.0));
+/// assert_eq!(var_primitive(&array, 1), Some(1.0));
+/// ```
+pub fn var_primitive<T: NativeType + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+    ddof: u8,
+) -> Option<f64> {
+    let mut accumulator = VarianceAccumulator::default();
+    accumulator.extend_primitive(array);
+    accumulator.variance(ddof)
+}
+
+/// Returns the standard deviation of the non-null values of the array with `ddof` delta
+/// degrees of freedom (`0` for the population and `1` for the sample standard deviation),
+/// or `None` if the array has `ddof` or fewer non-null values.
+pub fn stddev_primitive<T: NativeType + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+    ddof: u8,
+) -> Option<f64> {
+    let mut accumulator = VarianceAccumulator::default();
+    accumulator.extend_primitive(array);
+    accumulator.stddev(ddof)
+}
+
+macro_rules! dyn_extend {
+    ($ty:ty, $accumulator:expr, $array:expr) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        $accumulator.extend_primitive(array)
+    }};
+}
+
+/// Returns a [`VarianceAccumulator`] of the non-null values of [`Array`].
+/// # Error
+/// Errors iff the type does not support this operation.
+pub fn variance_accumulator(array: &dyn Array) -> Result<VarianceAccumulator> {
+    let mut accumulator = VarianceAccumulator::default();
+    match array.data_type().to_logical_type() {
+        DataType::Int8 => dyn_extend!(i8, accumulator, array),
+        DataType::Int16 => dyn_extend!(i16, accumulator, array),
+        DataType::Int32 => dyn_extend!(i32, accumulator, array),
+        DataType::Int64 => dyn_extend!(i64, accumulator, array),
+        DataType::UInt8 => dyn_extend!(u8, accumulator, array),
+        DataType::UInt16 => dyn_extend!(u16, accumulator, array),
+        DataType::UInt32 => dyn_extend!(u32, accumulator, array),
+        DataType::UInt64 => dyn_extend!(u64, accumulator, array),
+        DataType::Float16 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            array
+                .iter()
+                .flatten()
+                .for_each(|x| accumulator.push(x.to_f32() as f64))
+        }
+        DataType::Float32 => dyn_extend!(f32, accumulator, array),
+        DataType::Float64 => dyn_extend!(f64, accumulator, array),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Statistical moments are not supported for type `{:?}`",
+                array.data_type(),
+            )))
+        }
+    };
+    Ok(accumulator)
+}
+
+/// Returns the mean of the non-null values of [`Array`], or `None` when the array is empty
+/// or all its elements are null.
+/// # Error
+/// Errors iff the type does not support this operation.
+pub fn mean(array: &dyn Array) -> Result<Option<f64>> {
+    variance_accumulator(array).map(|x| x.mean())
+}
+
+/// Returns the variance of the non-null values of [`Array`] as in [`var_primitive`].
+/// # Error
+/// Errors iff the type does not support this operation.
+pub fn var(array: &dyn Array, ddof: u8) -> Result<Option<f64>> {
+    variance_accumulator(array).map(|x| x.variance(ddof))
+}
+
+/// Returns the standard deviation of the non-null values of [`Array`] as in [`stddev_primitive`].
+/// # Error
+/// Errors iff the type does not support this operation.
+pub fn stddev(array: &dyn Array, ddof: u8) -> Result<Option<f64>> {
+    variance_accumulator(array).map(|x| x.stddev(ddof))
+}
@@ -4,7 +4,7 @@ use std::ops::Add;
 use num_traits::{ops::overflowing::OverflowingAdd, CheckedAdd, SaturatingAdd, WrappingAdd};
 
 use crate::{
-    array::PrimitiveArray,
+    array::{MutablePrimitiveArray, PrimitiveArray},
     bitmap::Bitmap,
     compute::{
         arithmetics::{
@@ -13,6 +13,7 @@ use crate::{
         arity::{
             binary, binary_checked, binary_with_bitmap, unary, unary_checked, unary_with_bitmap,
         },
+        arity_assign,
     },
 };
 
@@ -39,6 +40,28 @@ where
     binary(lhs, rhs, lhs.data_type().clone(), |a, b| a + b)
 }
 
+/// Adds `lhs` to `rhs` in-place, re-using the memory of `lhs`.
+/// The validity of the result is the intersection of both validities.
+/// # Panics
+/// Panics if the arrays have different lengths or if the operation overflows.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::add_assign;
+/// use arrow2::array::{MutablePrimitiveArray, PrimitiveArray};
+///
+/// let mut a = MutablePrimitiveArray::from([None, Some(6), None, Some(6)]);
+/// let b = PrimitiveArray::from([Some(5), None, None, Some(6)]);
+/// add_assign(&mut a, &b);
+/// let expected = PrimitiveArray::from([None, None, None, Some(12)]);
+/// assert_eq!(PrimitiveArray::from(a), expected)
+/// ```
+pub fn add_assign<T>(lhs: &mut MutablePrimitiveArray<T>, rhs: &PrimitiveArray<T>)
+where
+    T: NativeArithmetics + Add<Output = T>,
+{
+    arity_assign::binary(lhs, rhs, |a, b| a + b)
+}
+
 /// Wrapping addition of two [`PrimitiveArray`]s.
 /// It wraps around at the boundary of the type if the result overflows.
 ///
@@ -207,6 +230,27 @@ where
     unary(lhs, |a| a + rhs, lhs.data_type().clone())
 }
 
+/// Adds `lhs` to a scalar `rhs` in-place, re-using the memory of `lhs`.
+/// # Panics
+/// Panics if the operation overflows.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::add_scalar_assign;
+/// use arrow2::array::{MutablePrimitiveArray, PrimitiveArray};
+///
+/// let mut a = MutablePrimitiveArray::from([None, Some(6), None, Some(6)]);
+/// add_scalar_assign(&mut a, &1);
+/// let expected = PrimitiveArray::from([None, Some(7), None, Some(7)]);
+/// assert_eq!(PrimitiveArray::from(a), expected)
+/// ```
+pub fn add_scalar_assign<T>(lhs: &mut MutablePrimitiveArray<T>, rhs: &T)
+where
+    T: NativeArithmetics + Add<Output = T>,
+{
+    let rhs = *rhs;
+    arity_assign::unary(lhs, |a| a + rhs)
+}
+
 /// Wrapping addition of a scalar T to a [`PrimitiveArray`] of type T.
 /// It do nothing if the result overflows.
 ///
@@ -5,10 +5,11 @@ use num_traits::{CheckedDiv, NumCast};
 
 use crate::datatypes::PrimitiveType;
 use crate::{
-    array::{Array, PrimitiveArray},
+    array::{Array, MutablePrimitiveArray, PrimitiveArray},
     compute::{
         arithmetics::{ArrayCheckedDiv, ArrayDiv},
         arity::{binary, binary_checked, unary, unary_checked},
+        arity_assign,
         utils::check_same_len,
     },
 };
@@ -49,6 +50,42 @@ where
     }
 }
 
+/// Divides `lhs` by `rhs` in-place, re-using the memory of `lhs`.
+/// The validity of the result is the intersection of both validities.
+/// # Panics
+/// Panics if the arrays have different lengths or if the operation overflows.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::div_assign;
+/// use arrow2::array::{MutablePrimitiveArray, PrimitiveArray};
+///
+/// let mut a = MutablePrimitiveArray::from([None, Some(6), None, Some(6)]);
+/// let b = PrimitiveArray::from([Some(5), None, None, Some(6)]);
+/// div_assign(&mut a, &b);
+/// let expected = PrimitiveArray::from([None, None, None, Some(1)]);
+/// assert_eq!(PrimitiveArray::from(a), expected)
+/// ```
+pub fn div_assign<T>(lhs: &mut MutablePrimitiveArray<T>, rhs: &PrimitiveArray<T>)
+where
+    T: NativeArithmetics + Div<Output = T>,
+{
+    if rhs.null_count() == 0 {
+        arity_assign::binary(lhs, rhs, |a, b| a / b)
+    } else {
+        assert_eq!(lhs.values().len(), rhs.len());
+        arity_assign::merge_validity(lhs, rhs.validity());
+        // null slots of `rhs` may contain zeros and are thus skipped
+        lhs.values_mut_slice()
+            .iter_mut()
+            .zip(rhs.iter())
+            .for_each(|(l, r)| {
+                if let Some(r) = r {
+                    *l = *l / *r
+                }
+            })
+    }
+}
+
 /// Checked division of two primitive arrays. If the result from the division
 /// overflows, the result for the operation will change the validity array
 /// making this operation None
@@ -176,6 +213,27 @@ where
     }
 }
 
+/// Divides `lhs` by a scalar `rhs` in-place, re-using the memory of `lhs`.
+/// # Panics
+/// Panics if the operation overflows.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::div_scalar_assign;
+/// use arrow2::array::{MutablePrimitiveArray, PrimitiveArray};
+///
+/// let mut a = MutablePrimitiveArray::from([None, Some(6), None, Some(6)]);
+/// div_scalar_assign(&mut a, &1);
+/// let expected = PrimitiveArray::from([None, Some(6), None, Some(6)]);
+/// assert_eq!(PrimitiveArray::from(a), expected)
+/// ```
+pub fn div_scalar_assign<T>(lhs: &mut MutablePrimitiveArray<T>, rhs: &T)
+where
+    T: NativeArithmetics + Div<Output = T>,
+{
+    let rhs = *rhs;
+    arity_assign::unary(lhs, |a| a / rhs)
+}
+
 /// Checked division of a primitive array of type T by a scalar T. If the
 /// divisor is zero then the validity array is changed to None.
 ///
@@ -22,9 +22,13 @@ use std::ops::Neg;
 
 use num_traits::{CheckedNeg, WrappingNeg};
 
-use crate::{array::PrimitiveArray, types::NativeType};
+use crate::{
+    array::{MutablePrimitiveArray, PrimitiveArray},
+    types::{days_ms, months_days_ns, NativeType},
+};
 
 use super::super::arity::{unary, unary_checked};
+use super::super::arity_assign;
 
 /// Trait describing a [`NativeType`] whose semantics of arithmetic in Arrow equals
 /// the semantics in Rust.
@@ -41,6 +45,9 @@ impl NativeArithmetics for i32 {}
 impl NativeArithmetics for i64 {}
 impl NativeArithmetics for f32 {}
 impl NativeArithmetics for f64 {}
+// intervals only implement (component-wise) addition, subtraction and negation
+impl NativeArithmetics for days_ms {}
+impl NativeArithmetics for months_days_ns {}
 
 /// Negates values from array.
 ///
@@ -61,6 +68,25 @@ where
     unary(array, |a| -a, array.data_type().clone())
 }
 
+/// Negates values from array in-place, re-using the memory of `array`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::negate_assign;
+/// use arrow2::array::{MutablePrimitiveArray, PrimitiveArray};
+///
+/// let mut a = MutablePrimitiveArray::from([None, Some(6), None, Some(7)]);
+/// negate_assign(&mut a);
+/// let expected = PrimitiveArray::from([None, Some(-6), None, Some(-7)]);
+/// assert_eq!(PrimitiveArray::from(a), expected)
+/// ```
+pub fn negate_assign<T>(array: &mut MutablePrimitiveArray<T>)
+where
+    T: NativeType + Neg<Output = T>,
+{
+    arity_assign::unary(array, |a| -a)
+}
+
 /// Checked negates values from array.
 ///
 /// # Examples
@@ -3,11 +3,12 @@
 
 use crate::{
     array::PrimitiveArray,
+    bitmap::utils::combine_validities,
     buffer::Buffer,
     compute::{
         arithmetics::{ArrayCheckedDiv, ArrayDiv},
         arity::{binary, binary_checked, unary},
-        utils::{check_same_len, combine_validities},
+        utils::check_same_len,
     },
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -288,7 +289,7 @@ pub fn adaptive_div(
         });
         let values = Buffer::from_trusted_len_iter(iter);
 
-        let validity = combine_validities(lhs.validity(), rhs.validity());
+        let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
         Ok(PrimitiveArray::<i128>::from_data(
             DataType::Decimal(res_p, res_s),
@@ -2,11 +2,12 @@
 
 use crate::{
     array::PrimitiveArray,
+    bitmap::utils::combine_validities,
     buffer::Buffer,
     compute::{
         arithmetics::{ArrayCheckedSub, ArraySaturatingSub, ArraySub},
         arity::{binary, binary_checked},
-        utils::{check_same_len, combine_validities},
+        utils::check_same_len,
     },
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -223,7 +224,7 @@ pub fn adaptive_sub(
         });
         let values = Buffer::from_trusted_len_iter(iter);
 
-        let validity = combine_validities(lhs.validity(), rhs.validity());
+        let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
         Ok(PrimitiveArray::<i128>::from_data(
             DataType::Decimal(res_p, res_s),
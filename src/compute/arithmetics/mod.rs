@@ -20,7 +20,9 @@ use crate::{
     array::{Array, DictionaryArray, PrimitiveArray},
     bitmap::Bitmap,
     datatypes::{DataType, IntervalUnit, TimeUnit},
+    error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
+    types::{days_ms, months_days_ns},
 };
 
 // Macro to evaluate match branch in arithmetic function.
@@ -37,11 +39,11 @@ macro_rules! primitive {
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith {
-    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )? $(, intervals = $op_intervals:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
-        match (lhs.data_type(), rhs.data_type()) {
+        match (lhs.data_type().to_logical_type(), rhs.data_type().to_logical_type()) {
             (Int8, Int8) => primitive!(lhs, rhs, $op, i8),
             (Int16, Int16) => primitive!(lhs, rhs, $op, i16),
             (Int32, Int32) => primitive!(lhs, rhs, $op, i32),
@@ -79,10 +81,16 @@ macro_rules! arith {
             }
             )?
             $ (
-            (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
+            (Date32, Interval(IntervalUnit::MonthDayNano)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
-                time::$op_interval(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+                time::$op_interval::<i32>(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+            }
+            (Date64, Interval(IntervalUnit::MonthDayNano))
+            | (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                time::$op_interval::<i64>(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
             )?
             $ (
@@ -92,6 +100,14 @@ macro_rules! arith {
                 time::$op_timestamp(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
             )?
+            $ (
+            (Interval(IntervalUnit::DayTime), Interval(IntervalUnit::DayTime)) => {
+                primitive!(lhs, rhs, $op_intervals, days_ms)
+            }
+            (Interval(IntervalUnit::MonthDayNano), Interval(IntervalUnit::MonthDayNano)) => {
+                primitive!(lhs, rhs, $op_intervals, months_days_ns)
+            }
+            )?
             _ => todo!(
                 "Addition of {:?} with {:?} is not supported",
                 lhs.data_type(),
@@ -130,11 +146,11 @@ macro_rules! primitive_scalar {
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith_scalar {
-    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )? $(, intervals = $op_intervals:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
-        match (lhs.data_type(), rhs.data_type()) {
+        match (lhs.data_type().to_logical_type(), rhs.data_type().to_logical_type()) {
             (Int8, Int8) => primitive_scalar!(lhs, rhs, $op, i8),
             (Int16, Int16) => primitive_scalar!(lhs, rhs, $op, i16),
             (Int32, Int32) => primitive_scalar!(lhs, rhs, $op, i32),
@@ -172,10 +188,16 @@ macro_rules! arith_scalar {
             }
             )?
             $ (
-            (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
+            (Date32, Interval(IntervalUnit::MonthDayNano)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                time::$op_interval::<i32>(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+            }
+            (Date64, Interval(IntervalUnit::MonthDayNano))
+            | (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
-                time::$op_interval(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+                time::$op_interval::<i64>(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
             )?
             $ (
@@ -185,6 +207,14 @@ macro_rules! arith_scalar {
                 time::$op_timestamp(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
             )?
+            $ (
+            (Interval(IntervalUnit::DayTime), Interval(IntervalUnit::DayTime)) => {
+                primitive_scalar!(lhs, rhs, $op_intervals, days_ms)
+            }
+            (Interval(IntervalUnit::MonthDayNano), Interval(IntervalUnit::MonthDayNano)) => {
+                primitive_scalar!(lhs, rhs, $op_intervals, months_days_ns)
+            }
+            )?
             _ => todo!(
                 "Addition of {:?} with {:?} is not supported",
                 lhs.data_type(),
@@ -206,7 +236,8 @@ pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         rhs,
         add,
         duration = add_duration,
-        interval = add_interval
+        interval = add_interval,
+        intervals = add
     )
 }
 
@@ -222,7 +253,8 @@ pub fn add_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
         rhs,
         add_scalar,
         duration = add_duration_scalar,
-        interval = add_interval_scalar
+        interval = add_interval_scalar,
+        intervals = add_scalar
     )
 }
 
@@ -250,7 +282,17 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Microsecond), Duration(_))
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
+            | (Date32, Interval(IntervalUnit::MonthDayNano))
+            | (Date64, Interval(IntervalUnit::MonthDayNano))
             | (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano))
+            | (
+                Interval(IntervalUnit::DayTime),
+                Interval(IntervalUnit::DayTime)
+            )
+            | (
+                Interval(IntervalUnit::MonthDayNano),
+                Interval(IntervalUnit::MonthDayNano)
+            )
     )
 }
 
@@ -267,7 +309,9 @@ pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         sub,
         decimal = sub,
         duration = subtract_duration,
-        timestamp = subtract_timestamps
+        interval = subtract_interval,
+        timestamp = subtract_timestamps,
+        intervals = sub
     )
 }
 
@@ -283,7 +327,9 @@ pub fn sub_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
         rhs,
         sub_scalar,
         duration = sub_duration_scalar,
-        timestamp = sub_timestamps_scalar
+        interval = sub_interval_scalar,
+        timestamp = sub_timestamps_scalar,
+        intervals = sub_scalar
     )
 }
 
@@ -311,7 +357,18 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Microsecond), Duration(_))
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
+            | (Date32, Interval(IntervalUnit::MonthDayNano))
+            | (Date64, Interval(IntervalUnit::MonthDayNano))
+            | (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano))
             | (Timestamp(_, None), Timestamp(_, None))
+            | (
+                Interval(IntervalUnit::DayTime),
+                Interval(IntervalUnit::DayTime)
+            )
+            | (
+                Interval(IntervalUnit::MonthDayNano),
+                Interval(IntervalUnit::MonthDayNano)
+            )
     )
 }
 
@@ -414,7 +471,10 @@ macro_rules! with_match_negatable {(
         Int128 => __with_ty__! { i128 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
-        UInt8 | UInt16 | UInt32 | UInt64=> todo!(),
+        UInt8 | UInt16 | UInt32 | UInt64 | Float16 => Err(ArrowError::NotYetImplemented(format!(
+            "Negation of arrays of {:?} is not supported",
+            $key_type
+        ))),
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -426,26 +486,39 @@ macro_rules! with_match_negatable {(
 /// * the opertion is not supported for the logical type (use [`can_neg`] to check)
 /// * the operation overflows
 pub fn neg(array: &dyn Array) -> Box<dyn Array> {
+    try_neg(array).unwrap()
+}
+
+/// Negates an [`Array`], like [`neg`] but returning an error for unsupported types.
+/// # Panic
+/// This function panics iff the operation overflows
+/// # Errors
+/// This function errors iff the operation is not supported for the logical type
+/// (use [`can_neg`] to check)
+pub fn try_neg(array: &dyn Array) -> Result<Box<dyn Array>> {
     use crate::datatypes::PhysicalType::*;
     match array.data_type().to_physical_type() {
         Primitive(primitive) => with_match_negatable!(primitive, |$T| {
             let array = array.as_any().downcast_ref().unwrap();
 
             let result = basic::negate::<$T>(array);
-            Box::new(result) as Box<dyn Array>
+            Ok(Box::new(result) as Box<dyn Array>)
         }),
         Dictionary(key) => match_integer_type!(key, |$T| {
             let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
 
-            let values = neg(array.values().as_ref()).into();
+            let values = try_neg(array.values().as_ref())?.into();
 
-            Box::new(DictionaryArray::<$T>::from_data(array.keys().clone(), values)) as Box<dyn Array>
+            Ok(Box::new(DictionaryArray::<$T>::from_data(array.keys().clone(), values)) as Box<dyn Array>)
         }),
-        _ => todo!(),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Negation of arrays of {:?} is not supported",
+            array.data_type()
+        ))),
     }
 }
 
-/// Whether [`neg`] is supported for a given [`DataType`]
+/// Whether [`neg`] and [`try_neg`] are supported for a given [`DataType`]
 pub fn can_neg(data_type: &DataType) -> bool {
     if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
         return can_neg(values.as_ref());
@@ -15,7 +15,7 @@ use num_traits::AsPrimitive;
 
 use crate::{
     array::PrimitiveArray,
-    compute::arity::{binary, unary},
+    compute::arity::{binary, try_binary, try_unary, unary},
     datatypes::{DataType, TimeUnit},
     error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
@@ -260,7 +260,10 @@ pub fn subtract_timestamps(
     // Matching on both data types from both arrays.
     // Both timestamps have a Timeunit enum in its data type.
     // This enum is used to adjust the scale between the timestamps.
-    match (lhs.data_type(), rhs.data_type()) {
+    match (
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type(),
+    ) {
         // Naive timestamp comparison. It doesn't take into account timezones
         // from the Timestamp timeunit.
         (DataType::Timestamp(timeunit_a, None), DataType::Timestamp(timeunit_b, None)) => {
@@ -311,39 +314,56 @@ pub fn sub_timestamps_scalar(
     Ok(unary(lhs, op, DataType::Duration(*timeunit_a)))
 }
 
-/// Adds an interval to a [`DataType::Timestamp`].
-pub fn add_interval(
-    timestamp: &PrimitiveArray<i64>,
+/// Adds an interval to a [`DataType::Timestamp`], [`DataType::Date32`] or [`DataType::Date64`].
+///
+/// Months are added first, clamping the day to the last day of the resulting month
+/// (e.g. `2021-01-31 + 1m` is `2021-02-28`), followed by days and nanoseconds.
+/// For [`DataType::Date32`], nanoseconds carry over to days.
+pub fn add_interval<T>(
+    time: &PrimitiveArray<T>,
     interval: &PrimitiveArray<months_days_ns>,
-) -> Result<PrimitiveArray<i64>> {
-    match timestamp.data_type().to_logical_type() {
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + AsPrimitive<i64>,
+    i64: AsPrimitive<T>,
+{
+    let data_type = time.data_type().clone();
+    match time.data_type().to_logical_type() {
         DataType::Timestamp(time_unit, Some(timezone_str)) => {
             let time_unit = *time_unit;
             let timezone = temporal_conversions::parse_offset(timezone_str);
             match timezone {
-                Ok(timezone) => Ok(binary(
-                    timestamp,
+                Ok(timezone) => try_binary(
+                    time,
                     interval,
-                    timestamp.data_type().clone(),
-                    |timestamp, interval| {
-                        temporal_conversions::add_interval(
-                            timestamp, time_unit, interval, &timezone,
+                    data_type,
+                    |timestamp: T, interval| {
+                        temporal_conversions::try_add_interval(
+                            timestamp.as_(),
+                            time_unit,
+                            interval,
+                            &timezone,
                         )
+                        .map(|x| x.as_())
                     },
-                )),
+                ),
                 #[cfg(feature = "chrono-tz")]
                 Err(_) => {
                     let timezone = temporal_conversions::parse_offset_tz(timezone_str)?;
-                    Ok(binary(
-                        timestamp,
+                    try_binary(
+                        time,
                         interval,
-                        timestamp.data_type().clone(),
-                        |timestamp, interval| {
-                            temporal_conversions::add_interval(
-                                timestamp, time_unit, interval, &timezone,
+                        data_type,
+                        |timestamp: T, interval| {
+                            temporal_conversions::try_add_interval(
+                                timestamp.as_(),
+                                time_unit,
+                                interval,
+                                &timezone,
                             )
+                            .map(|x| x.as_())
                         },
-                    ))
+                    )
                 }
                 #[cfg(not(feature = "chrono-tz"))]
                 _ => Err(ArrowError::InvalidArgumentError(format!(
@@ -354,61 +374,83 @@ pub fn add_interval(
         }
         DataType::Timestamp(time_unit, None) => {
             let time_unit = *time_unit;
-            Ok(binary(
-                timestamp,
+            try_binary(
+                time,
                 interval,
-                timestamp.data_type().clone(),
-                |timestamp, interval| {
-                    temporal_conversions::add_naive_interval(timestamp, time_unit, interval)
+                data_type,
+                |timestamp: T, interval| {
+                    temporal_conversions::try_add_naive_interval(timestamp.as_(), time_unit, interval)
+                        .map(|x| x.as_())
                 },
-            ))
+            )
         }
+        DataType::Date32 => try_binary(time, interval, data_type, |days: T, interval| {
+            temporal_conversions::add_interval_date32(days.as_() as i32, interval)
+                .map(|x| (x as i64).as_())
+        }),
+        DataType::Date64 => try_binary(time, interval, data_type, |ms: T, interval| {
+            temporal_conversions::add_interval_date64(ms.as_(), interval).map(|x| x.as_())
+        }),
         _ => Err(ArrowError::InvalidArgumentError(
-            "Adding an interval is only supported for `DataType::Timestamp`".to_string(),
+            "Adding an interval is only supported for `DataType::Timestamp`, `DataType::Date32` and `DataType::Date64`".to_string(),
         )),
     }
 }
 
-/// Adds an interval to a [`DataType::Timestamp`].
-pub fn add_interval_scalar(
-    timestamp: &PrimitiveArray<i64>,
+/// Adds an interval to a [`DataType::Timestamp`], [`DataType::Date32`] or [`DataType::Date64`].
+/// See [`add_interval`] for details.
+pub fn add_interval_scalar<T>(
+    time: &PrimitiveArray<T>,
     interval: &PrimitiveScalar<months_days_ns>,
-) -> Result<PrimitiveArray<i64>> {
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + AsPrimitive<i64>,
+    i64: AsPrimitive<T>,
+{
     let interval = if let Some(interval) = interval.value() {
         interval
     } else {
-        return Ok(PrimitiveArray::<i64>::new_null(
-            timestamp.data_type().clone(),
-            timestamp.len(),
+        return Ok(PrimitiveArray::<T>::new_null(
+            time.data_type().clone(),
+            time.len(),
         ));
     };
 
-    match timestamp.data_type().to_logical_type() {
+    let data_type = time.data_type().clone();
+    match time.data_type().to_logical_type() {
         DataType::Timestamp(time_unit, Some(timezone_str)) => {
             let time_unit = *time_unit;
             let timezone = temporal_conversions::parse_offset(timezone_str);
             match timezone {
-                Ok(timezone) => Ok(unary(
-                    timestamp,
-                    |timestamp| {
-                        temporal_conversions::add_interval(
-                            timestamp, time_unit, interval, &timezone,
+                Ok(timezone) => try_unary(
+                    time,
+                    |timestamp: T| {
+                        temporal_conversions::try_add_interval(
+                            timestamp.as_(),
+                            time_unit,
+                            interval,
+                            &timezone,
                         )
+                        .map(|x| x.as_())
                     },
-                    timestamp.data_type().clone(),
-                )),
+                    data_type,
+                ),
                 #[cfg(feature = "chrono-tz")]
                 Err(_) => {
                     let timezone = temporal_conversions::parse_offset_tz(timezone_str)?;
-                    Ok(unary(
-                        timestamp,
-                        |timestamp| {
-                            temporal_conversions::add_interval(
-                                timestamp, time_unit, interval, &timezone,
+                    try_unary(
+                        time,
+                        |timestamp: T| {
+                            temporal_conversions::try_add_interval(
+                                timestamp.as_(),
+                                time_unit,
+                                interval,
+                                &timezone,
                             )
+                            .map(|x| x.as_())
                         },
-                        timestamp.data_type().clone(),
-                    ))
+                        data_type,
+                    )
                 }
                 #[cfg(not(feature = "chrono-tz"))]
                 _ => Err(ArrowError::InvalidArgumentError(format!(
@@ -419,16 +461,69 @@ pub fn add_interval_scalar(
         }
         DataType::Timestamp(time_unit, None) => {
             let time_unit = *time_unit;
-            Ok(unary(
-                timestamp,
-                |timestamp| {
-                    temporal_conversions::add_naive_interval(timestamp, time_unit, interval)
+            try_unary(
+                time,
+                |timestamp: T| {
+                    temporal_conversions::try_add_naive_interval(timestamp.as_(), time_unit, interval)
+                        .map(|x| x.as_())
                 },
-                timestamp.data_type().clone(),
-            ))
+                data_type,
+            )
         }
+        DataType::Date32 => try_unary(
+            time,
+            |days: T| {
+                temporal_conversions::add_interval_date32(days.as_() as i32, interval)
+                    .map(|x| (x as i64).as_())
+            },
+            data_type,
+        ),
+        DataType::Date64 => try_unary(
+            time,
+            |ms: T| temporal_conversions::add_interval_date64(ms.as_(), interval).map(|x| x.as_()),
+            data_type,
+        ),
         _ => Err(ArrowError::InvalidArgumentError(
-            "Adding an interval is only supported for `DataType::Timestamp`".to_string(),
+            "Adding an interval is only supported for `DataType::Timestamp`, `DataType::Date32` and `DataType::Date64`".to_string(),
         )),
     }
 }
+
+/// Subtracts an interval from a [`DataType::Timestamp`], [`DataType::Date32`] or [`DataType::Date64`].
+/// This is equivalent to adding the negated interval; see [`add_interval`] for details.
+/// # Errors
+/// Errors with [`ArrowError::Overflow`] iff a component of an interval is the minimum of its type,
+/// whose negation overflows.
+pub fn subtract_interval<T>(
+    time: &PrimitiveArray<T>,
+    interval: &PrimitiveArray<months_days_ns>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + AsPrimitive<i64>,
+    i64: AsPrimitive<T>,
+{
+    let interval = try_unary(
+        interval,
+        |x| x.checked_neg().ok_or(ArrowError::Overflow),
+        interval.data_type().clone(),
+    )?;
+    add_interval(time, &interval)
+}
+
+/// Subtracts an interval from a [`DataType::Timestamp`], [`DataType::Date32`] or [`DataType::Date64`].
+/// See [`subtract_interval`] for details.
+pub fn sub_interval_scalar<T>(
+    time: &PrimitiveArray<T>,
+    interval: &PrimitiveScalar<months_days_ns>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + AsPrimitive<i64>,
+    i64: AsPrimitive<T>,
+{
+    let value = interval
+        .value()
+        .map(|x| x.checked_neg().ok_or(ArrowError::Overflow))
+        .transpose()?;
+    let interval = PrimitiveScalar::new(interval.data_type().clone(), value);
+    add_interval_scalar(time, &interval)
+}
@@ -1,6 +1,7 @@
 //! Defines kernels suitable to perform operations to primitive arrays.
 
-use super::utils::{check_same_len, combine_validities};
+use super::utils::check_same_len;
+use crate::bitmap::utils::combine_validities;
 use crate::{
     array::PrimitiveArray,
     bitmap::{Bitmap, MutableBitmap},
@@ -115,7 +116,7 @@ where
     // the iteration, then the validity is changed to None to mark the value
     // as Null
     let bitmap: Bitmap = mut_bitmap.into();
-    let validity = combine_validities(array.validity(), Some(&bitmap));
+    let validity = combine_validities(&[array.validity(), Some(&bitmap)]);
 
     PrimitiveArray::<O>::from_data(data_type, values, validity)
 }
@@ -147,7 +148,7 @@ where
 {
     check_same_len(lhs, rhs).unwrap();
 
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
     let values = lhs
         .values()
@@ -174,7 +175,7 @@ where
 {
     check_same_len(lhs, rhs)?;
 
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
     let values = lhs
         .values()
@@ -202,7 +203,7 @@ where
 {
     check_same_len(lhs, rhs).unwrap();
 
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
     let mut mut_bitmap = MutableBitmap::with_capacity(lhs.len());
 
@@ -256,13 +257,11 @@ where
     let values = Buffer::from_trusted_len_iter(values);
 
     let bitmap: Bitmap = mut_bitmap.into();
-    let validity = combine_validities(lhs.validity(), rhs.validity());
-
     // The validity has to be checked against the bitmap created during the
     // creation of the values with the iterator. If an error was found during
     // the iteration, then the validity is changed to None to mark the value
     // as Null
-    let validity = combine_validities(validity.as_ref(), Some(&bitmap));
+    let validity = combine_validities(&[lhs.validity(), rhs.validity(), Some(&bitmap)]);
 
     PrimitiveArray::<T>::from_data(data_type, values, validity)
 }
@@ -0,0 +1,66 @@
+//! Defines generics suitable to perform operations to [`MutablePrimitiveArray`] in-place.
+
+use crate::{
+    array::{MutableArray, MutablePrimitiveArray, PrimitiveArray},
+    bitmap::{Bitmap, MutableBitmap},
+    types::NativeType,
+};
+
+/// Applies an unary function to a [`MutablePrimitiveArray`] in-place.
+/// # Implementation
+/// This will apply the function for all values, including those on null slots.
+/// This implies that the operation must be infallible for any value of the
+/// corresponding type or this function may panic.
+#[inline]
+pub fn unary<I, F>(array: &mut MutablePrimitiveArray<I>, op: F)
+where
+    I: NativeType,
+    F: Fn(I) -> I,
+{
+    array
+        .values_mut_slice()
+        .iter_mut()
+        .for_each(|l| *l = op(*l));
+}
+
+/// Applies a binary function to two arrays, storing the result in `lhs`. The validity
+/// of the result is the intersection of the validities of `lhs` and `rhs`.
+/// # Implementation
+/// This will apply the function for all values, including those on null slots.
+/// This implies that the operation must be infallible for any value of the
+/// corresponding type.
+/// # Panics
+/// This function panics iff the arrays have a different length.
+#[inline]
+pub fn binary<T, D, F>(lhs: &mut MutablePrimitiveArray<T>, rhs: &PrimitiveArray<D>, op: F)
+where
+    T: NativeType,
+    D: NativeType,
+    F: Fn(T, D) -> T,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    merge_validity(lhs, rhs.validity());
+
+    lhs.values_mut_slice()
+        .iter_mut()
+        .zip(rhs.values().iter())
+        .for_each(|(l, r)| *l = op(*l, *r));
+}
+
+/// Sets to null every slot of `lhs` that is null on `rhs`.
+pub(crate) fn merge_validity<T: NativeType>(
+    lhs: &mut MutablePrimitiveArray<T>,
+    rhs: Option<&Bitmap>,
+) {
+    let rhs = match rhs {
+        Some(rhs) if rhs.null_count() > 0 => rhs,
+        _ => return,
+    };
+    if lhs.validity().is_some() {
+        lhs.apply_validity(|validity| *validity &= rhs)
+    } else {
+        let mut validity = MutableBitmap::with_capacity(rhs.len());
+        validity.extend_from_bitmap(rhs, 0, rhs.len());
+        lhs.set_validity(Some(validity))
+    }
+}
@@ -0,0 +1,382 @@
+//! Contains kernels to encode binary arrays as text ([`hex_encode`], [`base64_encode`]) and to
+//! decode text back to binary arrays ([`hex_decode`], [`base64_decode`]).
+//!
+//! [`BinaryArray`], [`BinaryArray<i64>`] and [`FixedSizeBinaryArray`] are encoded to
+//! [`Utf8Array`]s whose offsets have the same width (`i32` for [`FixedSizeBinaryArray`]).
+//! The length of every encoded value is known from the length of the value, so the encoded
+//! values are written into a single buffer allocated upfront.
+use crate::array::{Array, BinaryArray, FixedSizeBinaryArray, Offset, Utf8Array};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+/// An encoding of bytes as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryEncoding {
+    /// Lowercase hexadecimal, two characters per byte. Uppercase characters are also decoded.
+    Hex,
+    /// The standard base64 alphabet, with padding.
+    Base64,
+}
+
+impl BinaryEncoding {
+    /// Returns the length of the encoding of `length` bytes.
+    #[inline]
+    pub fn encoded_len(&self, length: usize) -> usize {
+        match self {
+            BinaryEncoding::Hex => length * 2,
+            // every (possibly incomplete) chunk of 3 bytes is encoded to 4 padded characters
+            BinaryEncoding::Base64 => (length + 2) / 3 * 4,
+        }
+    }
+
+    /// Writes the encoding of `bytes` to `out`, whose length must be `encoded_len(bytes.len())`.
+    #[inline]
+    fn encode(&self, bytes: &[u8], out: &mut [u8]) {
+        match self {
+            BinaryEncoding::Hex => {
+                bytes
+                    .iter()
+                    .zip(out.chunks_exact_mut(2))
+                    .for_each(|(byte, out)| {
+                        out[0] = HEX_CHARS[(byte >> 4) as usize];
+                        out[1] = HEX_CHARS[(byte & 0xf) as usize];
+                    });
+            }
+            BinaryEncoding::Base64 => {
+                base64::encode_config_slice(bytes, base64::STANDARD, out);
+            }
+        }
+    }
+
+    /// Appends the decoding of `text` to `out`. Returns whether `text` is a valid encoding;
+    /// when it is not, nothing is appended to `out`.
+    #[inline]
+    fn decode(&self, text: &[u8], out: &mut Vec<u8>) -> bool {
+        match self {
+            BinaryEncoding::Hex => {
+                if text.len() & 1 == 1 {
+                    return false;
+                }
+                let length = out.len();
+                out.reserve(text.len() / 2);
+                for pair in text.chunks_exact(2) {
+                    match (hex_value(pair[0]), hex_value(pair[1])) {
+                        (Some(high), Some(low)) => out.push(high << 4 | low),
+                        _ => {
+                            out.truncate(length);
+                            return false;
+                        }
+                    }
+                }
+                true
+            }
+            BinaryEncoding::Base64 => {
+                let length = out.len();
+                let is_valid = base64::decode_config_buf(text, base64::STANDARD, out).is_ok();
+                if !is_valid {
+                    out.truncate(length);
+                }
+                is_valid
+            }
+        }
+    }
+}
+
+#[inline]
+fn hex_value(char: u8) -> Option<u8> {
+    match char {
+        b'0'..=b'9' => Some(char - b'0'),
+        b'a'..=b'f' => Some(char - b'a' + 10),
+        b'A'..=b'F' => Some(char - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn encode<'a, O, F>(
+    length: usize,
+    validity: Option<&Bitmap>,
+    value: F,
+    encoding: BinaryEncoding,
+) -> Result<Utf8Array<O>>
+where
+    O: Offset,
+    F: Fn(usize) -> &'a [u8],
+{
+    let is_valid = |index: usize| validity.map(|x| x.get_bit(index)).unwrap_or(true);
+
+    let mut offsets = Vec::<O>::with_capacity(length + 1);
+    offsets.push(O::default());
+    let mut total = 0usize;
+    for index in 0..length {
+        if is_valid(index) {
+            total += encoding.encoded_len(value(index).len());
+        }
+        offsets.push(O::from_usize(total).ok_or(ArrowError::Overflow)?);
+    }
+
+    let mut values = vec![0u8; total];
+    for index in 0..length {
+        if is_valid(index) {
+            let start = offsets[index].to_usize();
+            let end = offsets[index + 1].to_usize();
+            encoding.encode(value(index), &mut values[start..end]);
+        }
+    }
+
+    // Safety: the offsets are monotonically increasing and the values are ASCII
+    Ok(unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            Utf8Array::<O>::default_data_type(),
+            offsets.into(),
+            values.into(),
+            validity.cloned(),
+        )
+    })
+}
+
+/// Encodes every value of `array` according to `encoding`.
+/// # Error
+/// Errors iff the encoded values do not fit in offsets of type `O`.
+pub fn encode_binary<O: Offset>(
+    array: &BinaryArray<O>,
+    encoding: BinaryEncoding,
+) -> Result<Utf8Array<O>> {
+    encode(array.len(), array.validity(), |i| array.value(i), encoding)
+}
+
+/// Encodes every value of `array` according to `encoding`.
+/// # Error
+/// Errors iff the encoded values do not fit in offsets of type `O`.
+pub fn encode_fixed_size_binary<O: Offset>(
+    array: &FixedSizeBinaryArray,
+    encoding: BinaryEncoding,
+) -> Result<Utf8Array<O>> {
+    encode(array.len(), array.validity(), |i| array.value(i), encoding)
+}
+
+/// Decodes the value at `index` of `array` to `values`, or returns whether the slot is null.
+/// Nothing is appended when the value is invalid and `lenient`.
+#[inline]
+fn decode_value<O: Offset>(
+    array: &Utf8Array<O>,
+    index: usize,
+    encoding: BinaryEncoding,
+    lenient: bool,
+    size: Option<usize>,
+    values: &mut Vec<u8>,
+) -> Result<bool> {
+    if !array.is_valid(index) {
+        return Ok(false);
+    }
+    let text = array.value(index);
+    let length = values.len();
+    let is_valid = encoding.decode(text.as_bytes(), values)
+        && size
+            .map(|size| values.len() - length == size)
+            .unwrap_or(true);
+    if is_valid {
+        return Ok(true);
+    }
+    values.truncate(length);
+    if lenient {
+        Ok(false)
+    } else {
+        Err(ArrowError::InvalidArgumentError(match size {
+            Some(size) => format!(
+                "\"{}\" is not the {:?} encoding of {} bytes",
+                text, encoding, size
+            ),
+            None => format!("\"{}\" is not a valid {:?} encoding", text, encoding),
+        }))
+    }
+}
+
+/// Decodes every value of `array` according to `encoding`. Invalid values are decoded to nulls
+/// when `lenient` and error otherwise.
+/// # Error
+/// Errors iff a value is invalid and not `lenient`, or iff the decoded values do not fit in
+/// offsets of type `P`.
+pub fn decode_binary<O: Offset, P: Offset>(
+    array: &Utf8Array<O>,
+    encoding: BinaryEncoding,
+    lenient: bool,
+) -> Result<BinaryArray<P>> {
+    let mut offsets = Vec::<P>::with_capacity(array.len() + 1);
+    offsets.push(P::default());
+    let mut values = Vec::<u8>::new();
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    for index in 0..array.len() {
+        let is_valid = decode_value(array, index, encoding, lenient, None, &mut values)?;
+        validity.push(is_valid);
+        offsets.push(P::from_usize(values.len()).ok_or(ArrowError::Overflow)?);
+    }
+    Ok(BinaryArray::<P>::from_data(
+        BinaryArray::<P>::default_data_type(),
+        offsets.into(),
+        values.into(),
+        validity.into(),
+    ))
+}
+
+/// Decodes every value of `array` according to `encoding` to a [`FixedSizeBinaryArray`] of
+/// values of `size` bytes. Invalid values and values that do not decode to `size` bytes are
+/// decoded to nulls when `lenient` and error otherwise.
+/// # Error
+/// Errors iff a value is invalid or is not decoded to `size` bytes and not `lenient`.
+pub fn decode_fixed_size_binary<O: Offset>(
+    array: &Utf8Array<O>,
+    size: usize,
+    encoding: BinaryEncoding,
+    lenient: bool,
+) -> Result<FixedSizeBinaryArray> {
+    let mut values = Vec::<u8>::with_capacity(array.len() * size);
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    for index in 0..array.len() {
+        let is_valid = decode_value(array, index, encoding, lenient, Some(size), &mut values)?;
+        if !is_valid {
+            values.resize(values.len() + size, 0);
+        }
+        validity.push(is_valid);
+    }
+    Ok(FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(size),
+        values.into(),
+        validity.into(),
+    ))
+}
+
+fn encode_dyn(array: &dyn Array, encoding: BinaryEncoding) -> Result<Box<dyn Array>> {
+    Ok(match array.data_type().to_logical_type() {
+        DataType::Binary => Box::new(encode_binary::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            encoding,
+        )?),
+        DataType::LargeBinary => Box::new(encode_binary::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            encoding,
+        )?),
+        DataType::FixedSizeBinary(_) => Box::new(encode_fixed_size_binary::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            encoding,
+        )?),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{:?} encoding does not support type {:?}",
+                encoding,
+                array.data_type()
+            )))
+        }
+    })
+}
+
+fn decode_utf8<O: Offset>(
+    array: &Utf8Array<O>,
+    data_type: &DataType,
+    encoding: BinaryEncoding,
+    lenient: bool,
+) -> Result<Box<dyn Array>> {
+    Ok(match data_type {
+        DataType::Binary => Box::new(decode_binary::<O, i32>(array, encoding, lenient)?),
+        DataType::LargeBinary => Box::new(decode_binary::<O, i64>(array, encoding, lenient)?),
+        DataType::FixedSizeBinary(size) => {
+            Box::new(decode_fixed_size_binary(array, *size, encoding, lenient)?)
+        }
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{:?} decoding does not support type {:?}",
+                encoding, data_type
+            )))
+        }
+    })
+}
+
+fn decode_dyn(
+    array: &dyn Array,
+    data_type: &DataType,
+    encoding: BinaryEncoding,
+    lenient: bool,
+) -> Result<Box<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::Utf8 => decode_utf8::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            data_type,
+            encoding,
+            lenient,
+        ),
+        DataType::LargeUtf8 => decode_utf8::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            data_type,
+            encoding,
+            lenient,
+        ),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "{:?} decoding does not support type {:?}",
+            encoding,
+            array.data_type()
+        ))),
+    }
+}
+
+/// Encodes every value of a binary [`Array`] as lowercase hexadecimal.
+/// `Binary` and `FixedSizeBinary` are encoded to `Utf8` and `LargeBinary` to `LargeUtf8`.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, BinaryArray, Utf8Array};
+/// use arrow2::compute::binary::hex_encode;
+///
+/// let array = BinaryArray::<i32>::from([Some(b"\x01\xab".as_ref()), None]);
+/// let result = hex_encode(&array).unwrap();
+/// assert_eq!(result.as_ref(), &Utf8Array::<i32>::from([Some("01ab"), None]) as &dyn Array);
+/// ```
+/// # Error
+/// Errors iff the array is not binary or the encoded values overflow its offsets.
+pub fn hex_encode(array: &dyn Array) -> Result<Box<dyn Array>> {
+    encode_dyn(array, BinaryEncoding::Hex)
+}
+
+/// Decodes every value of a `Utf8` or `LargeUtf8` [`Array`] from hexadecimal to an array of
+/// `data_type` (`Binary`, `LargeBinary` or `FixedSizeBinary`). Invalid values (and values
+/// that do not decode to the size of `FixedSizeBinary`) are decoded to nulls when `lenient`.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, BinaryArray, Utf8Array};
+/// use arrow2::compute::binary::hex_decode;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from_slice(["01AB", "zz"]);
+/// let result = hex_decode(&array, &DataType::Binary, true).unwrap();
+/// let expected = BinaryArray::<i32>::from([Some(b"\x01\xab".as_ref()), None]);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// assert!(hex_decode(&array, &DataType::Binary, false).is_err());
+/// ```
+/// # Error
+/// Errors iff the types are not supported or a value is invalid and not `lenient`.
+pub fn hex_decode(
+    array: &dyn Array,
+    data_type: &DataType,
+    lenient: bool,
+) -> Result<Box<dyn Array>> {
+    decode_dyn(array, data_type, BinaryEncoding::Hex, lenient)
+}
+
+/// Encodes every value of a binary [`Array`] as (padded) standard base64, as in [`hex_encode`].
+/// # Error
+/// Errors iff the array is not binary or the encoded values overflow its offsets.
+pub fn base64_encode(array: &dyn Array) -> Result<Box<dyn Array>> {
+    encode_dyn(array, BinaryEncoding::Base64)
+}
+
+/// Decodes every value of a `Utf8` or `LargeUtf8` [`Array`] from (padded) standard base64 to
+/// an array of `data_type`, as in [`hex_decode`].
+/// # Error
+/// Errors iff the types are not supported or a value is invalid and not `lenient`.
+pub fn base64_decode(
+    array: &dyn Array,
+    data_type: &DataType,
+    lenient: bool,
+) -> Result<Box<dyn Array>> {
+    decode_dyn(array, data_type, BinaryEncoding::Base64, lenient)
+}
@@ -5,7 +5,7 @@ use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 use crate::scalar::BooleanScalar;
 
-use super::utils::combine_validities;
+use crate::bitmap::utils::combine_validities;
 
 /// Helper function to implement binary kernels
 fn binary_boolean_kernel<F>(lhs: &BooleanArray, rhs: &BooleanArray, op: F) -> Result<BooleanArray>
@@ -18,7 +18,7 @@ where
         ));
     }
 
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
     let left_buffer = lhs.values();
     let right_buffer = rhs.values();
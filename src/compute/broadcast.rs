@@ -0,0 +1,243 @@
+//! Contains the operator [`broadcast`], that repeats the single slot of an [`Array`], and
+//! [`binary`], that applies a binary kernel to arrays whose length is either equal or one.
+//!
+//! Expression engines often evaluate expressions against a one-row batch (e.g. the result of
+//! evaluating a literal) that must be combined with a batch of many rows. These operators
+//! allow such a batch to be combined with kernels that expect arrays of equal length.
+use std::sync::Arc;
+
+use crate::array::growable::make_growable;
+use crate::array::*;
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::columns::Columns;
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::types::{NativeType, Offset};
+
+fn check_single_slot(array: &dyn Array) -> Result<()> {
+    if array.len() != 1 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Only arrays of length 1 can be broadcasted, but the array has length {}",
+            array.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns an [`Array`] of length `len` whose slots are all equal to the single slot of `array`.
+///
+/// The result has the same [`DataType`] as `array`:
+/// * a null slot results in an array whose all slots are null;
+/// * primitive and boolean values are repeated;
+/// * dictionary-encoded arrays repeat the key of a dictionary with the single value, so that
+///   their values are `O(1)` regardless of `len`;
+/// * variable-sized values (`[Large]Utf8` and `[Large]Binary`) have their offsets computed
+///   arithmetically and their bytes repeated `len` times. Use [`broadcast_to_dictionary`]
+///   to avoid repeating the bytes of large values;
+/// * every other type is built with a [`Growable`](crate::array::growable::Growable).
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::broadcast::broadcast;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["a"]);
+/// let result = broadcast(&array, 3).unwrap();
+/// assert_eq!(result.as_ref(), &Utf8Array::<i32>::from_slice(&["a", "a", "a"]) as &dyn Array);
+/// ```
+/// # Errors
+/// Errors iff the length of `array` is not 1, or iff the repeated values of a variable-sized
+/// array do not fit in its offsets.
+pub fn broadcast(array: &dyn Array, len: usize) -> Result<Box<dyn Array>> {
+    check_single_slot(array)?;
+    if array.is_null(0) {
+        return Ok(new_null_array(array.data_type().clone(), len));
+    }
+
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let mut values = MutableBitmap::with_capacity(len);
+            values.extend_constant(len, array.value(0));
+            Box::new(BooleanArray::from_data(
+                array.data_type().clone(),
+                values.into(),
+                None,
+            ))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            broadcast_primitive::<$T>(array.as_any().downcast_ref().unwrap(), len)
+        }),
+        Utf8 => broadcast_utf8::<i32>(array.as_any().downcast_ref().unwrap(), len)?,
+        LargeUtf8 => broadcast_utf8::<i64>(array.as_any().downcast_ref().unwrap(), len)?,
+        Binary => broadcast_binary::<i32>(array.as_any().downcast_ref().unwrap(), len)?,
+        LargeBinary => broadcast_binary::<i64>(array.as_any().downcast_ref().unwrap(), len)?,
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            Box::new(FixedSizeBinaryArray::from_data(
+                array.data_type().clone(),
+                array.value(0).repeat(len).into(),
+                None,
+            ))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            broadcast_dictionary::<$T>(array.as_any().downcast_ref().unwrap(), len)
+        }),
+        _ => {
+            let mut growable = make_growable(&[array], false, len);
+            (0..len).for_each(|_| growable.extend(0, 0, 1));
+            growable.as_box()
+        }
+    })
+}
+
+fn broadcast_primitive<T: NativeType>(array: &PrimitiveArray<T>, len: usize) -> Box<dyn Array> {
+    Box::new(PrimitiveArray::<T>::from_data(
+        array.data_type().clone(),
+        vec![array.value(0); len].into(),
+        None,
+    ))
+}
+
+/// Returns the offsets of `len` values of `size` bytes each
+fn repeated_offsets<O: Offset>(size: usize, len: usize) -> Result<Vec<O>> {
+    size.checked_mul(len)
+        .and_then(O::from_usize)
+        .ok_or(ArrowError::Overflow)?;
+    // the last offset fits, and so does every offset before it
+    Ok((0..=len)
+        .map(|i| O::from_usize(i * size).unwrap())
+        .collect())
+}
+
+fn broadcast_utf8<O: Offset>(array: &Utf8Array<O>, len: usize) -> Result<Box<dyn Array>> {
+    let value = array.value(0).as_bytes();
+    let offsets = repeated_offsets::<O>(value.len(), len)?;
+    // Safety: the offsets are monotonically increasing and every value is `value`, valid utf8
+    Ok(Box::new(unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            array.data_type().clone(),
+            offsets.into(),
+            value.repeat(len).into(),
+            None,
+        )
+    }))
+}
+
+fn broadcast_binary<O: Offset>(array: &BinaryArray<O>, len: usize) -> Result<Box<dyn Array>> {
+    let value = array.value(0);
+    let offsets = repeated_offsets::<O>(value.len(), len)?;
+    Ok(Box::new(BinaryArray::<O>::from_data(
+        array.data_type().clone(),
+        offsets.into(),
+        value.repeat(len).into(),
+        None,
+    )))
+}
+
+fn broadcast_dictionary<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    len: usize,
+) -> Box<dyn Array> {
+    let key = array.keys().value(0).to_usize().unwrap();
+    let values: Arc<dyn Array> = array.values().slice(key, 1).into();
+    let keys =
+        PrimitiveArray::<K>::from_data(K::PRIMITIVE.into(), vec![K::default(); len].into(), None);
+    let is_ordered = matches!(array.data_type(), DataType::Dictionary(_, _, true));
+    Box::new(DictionaryArray::<K>::from_data(keys, values).with_is_ordered(is_ordered))
+}
+
+/// Returns a [`DictionaryArray`] of length `len` whose slots are all equal to the single slot of
+/// `array`. Its values are `array` itself, so that this is `O(1)` in memory regardless of the
+/// size of the value, at the cost of the result being dictionary-encoded.
+/// # Example
+/// ```
+/// use arrow2::array::{DictionaryArray, Utf8Array};
+/// use arrow2::compute::broadcast::broadcast_to_dictionary;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["a long value"]);
+/// let result = broadcast_to_dictionary::<u32>(&array, 1000).unwrap();
+/// assert_eq!(result.len(), 1000);
+/// assert_eq!(result.values().len(), 1);
+/// ```
+/// # Errors
+/// Errors iff the length of `array` is not 1 or iff `array` is dictionary-encoded (use
+/// [`broadcast`]).
+pub fn broadcast_to_dictionary<K: DictionaryKey>(
+    array: &dyn Array,
+    len: usize,
+) -> Result<DictionaryArray<K>> {
+    check_single_slot(array)?;
+    if let DataType::Dictionary(..) = array.data_type() {
+        return Err(ArrowError::InvalidArgumentError(
+            "A dictionary-encoded array cannot be the values of a dictionary; use `broadcast`"
+                .to_string(),
+        ));
+    }
+    let validity = if array.is_null(0) {
+        Some(Bitmap::new_zeroed(len))
+    } else {
+        None
+    };
+    let keys = PrimitiveArray::<K>::from_data(
+        K::PRIMITIVE.into(),
+        vec![K::default(); len].into(),
+        validity,
+    );
+    Ok(DictionaryArray::<K>::from_data(
+        keys,
+        array.slice(0, 1).into(),
+    ))
+}
+
+/// Applies `op` to `lhs` and `rhs`, broadcasting the one of length 1 (if any) to the length of
+/// the other one, so that kernels over arrays of equal length (e.g.
+/// [`arithmetics::add`](crate::compute::arithmetics::add) or
+/// [`comparison::eq`](crate::compute::comparison::eq)) can be applied to one-row arrays.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, BooleanArray, Int32Array};
+/// use arrow2::compute::broadcast::binary;
+/// use arrow2::compute::comparison::eq;
+///
+/// let lhs = Int32Array::from_slice(&[1, 2, 1]);
+/// let rhs = Int32Array::from_slice(&[1]);
+/// let result = binary(&lhs, &rhs, eq).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false, true]));
+/// ```
+/// # Errors
+/// Errors iff the arrays have different lengths and neither of them has length 1.
+pub fn binary<T, F: Fn(&dyn Array, &dyn Array) -> T>(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    op: F,
+) -> Result<T> {
+    match (lhs.len(), rhs.len()) {
+        (l, r) if l == r => Ok(op(lhs, rhs)),
+        (1, r) => Ok(op(broadcast(lhs, r)?.as_ref(), rhs)),
+        (l, 1) => Ok(op(lhs, broadcast(rhs, l)?.as_ref())),
+        (l, r) => Err(ArrowError::InvalidArgumentError(format!(
+            "Arrays of lengths {} and {} cannot be broadcasted to a common length",
+            l, r
+        ))),
+    }
+}
+
+/// Returns [`Columns`] of `len` rows by broadcasting every array of the one-row `columns`.
+/// # Errors
+/// Errors iff [`broadcast`] errors on any of its arrays, e.g. when `columns` does not have
+/// exactly one row.
+pub fn broadcast_columns<A: AsRef<dyn Array>>(
+    columns: &Columns<A>,
+    len: usize,
+) -> Result<Columns<Box<dyn Array>>> {
+    let arrays = columns
+        .arrays()
+        .iter()
+        .map(|array| broadcast(array.as_ref(), len))
+        .collect::<Result<Vec<_>>>()?;
+    Columns::try_new(arrays)
+}
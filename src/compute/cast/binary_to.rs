@@ -32,6 +32,21 @@ pub fn binary_large_to_binary(
     ))
 }
 
+/// Casts a [`BinaryArray`] to a [`Utf8Array`], where values that are not valid utf8 become null.
+pub fn binary_to_utf8<O: Offset, P: Offset>(from: &BinaryArray<O>) -> Utf8Array<P> {
+    // perf todo: the offsets are equal; we can speed-up this
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
+
+    Utf8Array::<P>::from_trusted_len_iter(iter)
+}
+
+pub(super) fn binary_to_utf8_dyn<O: Offset, P: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(binary_to_utf8::<O, P>(from)))
+}
+
 /// Casts a [`BinaryArray`] to a [`PrimitiveArray`] at best-effort using `lexical_core::parse_partial`, making any uncastable value as zero.
 pub fn partial_binary_to_primitive<O: Offset, T>(
     from: &BinaryArray<O>,
@@ -1,4 +1,4 @@
-use crate::{array::*, buffer::Buffer, types::NativeType};
+use crate::{array::*, types::NativeType};
 use crate::{
     array::{BinaryArray, Offset, Utf8Array},
     error::Result,
@@ -12,18 +12,30 @@ where
     Ok(Box::new(boolean_to_primitive::<T>(array)))
 }
 
-/// Casts the [`BooleanArray`] to a [`PrimitiveArray`].
+/// Casts the [`BooleanArray`] to a [`PrimitiveArray`], casting trues to `1` and falses to `0`.
+/// The bits are expanded in chunks of 64 bits.
 pub fn boolean_to_primitive<T>(from: &BooleanArray) -> PrimitiveArray<T>
 where
     T: NativeType + num_traits::One,
 {
-    let iter = from
-        .values()
-        .iter()
-        .map(|x| if x { T::one() } else { T::default() });
-    let values = Buffer::<T>::from_trusted_len_iter(iter);
+    let chunks = from.values().chunks::<u64>();
 
-    PrimitiveArray::<T>::from_data(T::PRIMITIVE.into(), values, from.validity().cloned())
+    let mut values = Vec::<T>::with_capacity(from.len());
+    let expand = |values: &mut Vec<T>, chunk: u64, length: usize| {
+        values.extend((0..length).map(|i| {
+            if chunk & (1 << i) != 0 {
+                T::one()
+            } else {
+                T::default()
+            }
+        }))
+    };
+    let remainder = chunks.remainder();
+    let remainder_len = chunks.remainder_len();
+    chunks.for_each(|chunk| expand(&mut values, chunk, 64));
+    expand(&mut values, remainder, remainder_len);
+
+    PrimitiveArray::<T>::from_data(T::PRIMITIVE.into(), values.into(), from.validity().cloned())
 }
 
 /// Casts the [`BooleanArray`] to a [`Utf8Array`], casting trues to `"1"` and falses to `"0"`
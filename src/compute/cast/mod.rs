@@ -1,5 +1,7 @@
 //! Defines different casting operators such as [`cast`] or [`primitive_to_binary`].
 
+use std::convert::TryFrom;
+
 use crate::{
     array::*,
     buffer::Buffer,
@@ -29,6 +31,12 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to false
+    /// whether a cast that scales values up (e.g. a timestamp to a finer unit, or a float to a
+    /// decimal) errors when a value does not fit in the target type, instead of returning `None`
+    /// (or wrapping it when `wrapped`), and whether a cast from strings to booleans errors on
+    /// strings that are not booleans, instead of returning `None`.
+    pub strict: bool,
 }
 
 impl CastOptions {
@@ -39,6 +47,34 @@ impl CastOptions {
     }
 }
 
+/// Casts `array` with `cast`, a cast that scales values up. When `options.strict`, errors iff
+/// a value of `array` does not fit in `to_type`.
+fn scaled_cast<F>(
+    array: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+    cast: F,
+) -> Result<Box<dyn Array>>
+where
+    F: FnOnce(CastOptions) -> Result<Box<dyn Array>>,
+{
+    if !options.strict {
+        return cast(options);
+    }
+    // values that don't fit are null when not wrapped
+    let casted = cast(options.with_wrapped(false))?;
+    let overflows = casted.null_count() - array.null_count();
+    if overflows > 0 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Casting from {:?} to {:?} overflows {} values",
+            array.data_type(),
+            to_type,
+            overflows
+        )));
+    }
+    Ok(casted)
+}
+
 /// Returns true if this type is numeric: (UInt*, Unit*, or Float*).
 fn is_numeric(t: &DataType) -> bool {
     use DataType::*;
@@ -65,6 +101,10 @@ macro_rules! primitive_dyn {
         let from = $from.as_any().downcast_ref().unwrap();
         Ok(Box::new($expr(from, $arg1, $arg2, $arg3)))
     }};
+    ($from:expr, $expr:tt, $arg1:expr, $arg2:expr, $arg3:expr, $arg4:expr) => {{
+        let from = $from.as_any().downcast_ref().unwrap();
+        Ok(Box::new($expr(from, $arg1, $arg2, $arg3, $arg4)))
+    }};
 }
 
 /// Return true if a value of type `from_type` can be cast into a
@@ -78,16 +118,9 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
     }
 
     match (from_type, to_type) {
-        (
-            Null,
-            Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Float32 | Date32 | Time32(_)
-            | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
-        )
-        | (
-            Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Float32 | Date32 | Time32(_)
-            | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
-            Null,
-        ) => true,
+        (Extension(_, from_type, _), _) => can_cast_types(from_type, to_type),
+        (_, Extension(_, to_type, _)) => can_cast_types(from_type, to_type),
+        (Null, _) | (_, Null) => true,
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (List(list_from), List(list_to)) => {
@@ -96,15 +129,21 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (LargeList(list_from), LargeList(list_to)) => {
             can_cast_types(list_from.data_type(), list_to.data_type())
         }
-        (List(list_from), LargeList(list_to)) if list_from == list_to => true,
-        (LargeList(list_from), List(list_to)) if list_from == list_to => true,
+        (List(list_from), LargeList(list_to)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
+        (LargeList(list_from), List(list_to)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
         (_, List(list_to)) => can_cast_types(from_type, list_to.data_type()),
+        (_, LargeList(list_to)) => can_cast_types(from_type, list_to.data_type()),
         (Dictionary(_, from_value_type, _), Dictionary(_, to_value_type, _)) => {
             can_cast_types(from_value_type, to_value_type)
         }
         (Dictionary(_, value_type, _), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type, _)) => can_cast_types(from_type, value_type),
 
+        (Utf8 | LargeUtf8, Boolean) => true,
         (_, Boolean) => is_numeric(from_type),
         (Boolean, _) => {
             is_numeric(to_type)
@@ -118,16 +157,21 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Utf8, Date64) => true,
         (Utf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
         (Utf8, LargeUtf8) => true,
+        (Utf8, Binary | LargeBinary) => true,
         (Utf8, _) => is_numeric(to_type),
         (LargeUtf8, Date32) => true,
         (LargeUtf8, Date64) => true,
         (LargeUtf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
         (LargeUtf8, Utf8) => true,
+        (LargeUtf8, Binary | LargeBinary) => true,
         (LargeUtf8, _) => is_numeric(to_type),
         (Timestamp(_, _), Utf8) => true,
         (Timestamp(_, _), LargeUtf8) => true,
-        (_, Utf8) => is_numeric(from_type) || from_type == &Binary,
-        (_, LargeUtf8) => is_numeric(from_type) || from_type == &Binary,
+        (Date32 | Date64, Utf8) => true,
+        (Date32 | Date64, LargeUtf8) => true,
+        (_, Utf8 | LargeUtf8) => {
+            is_numeric(from_type) || from_type == &Binary || from_type == &LargeBinary
+        }
 
         (Binary, _) => is_numeric(to_type) || to_type == &LargeBinary,
         (LargeBinary, _) => is_numeric(to_type) || to_type == &Binary,
@@ -234,6 +278,13 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Float64, Int32) => true,
         (Float64, Int64) => true,
         (Float64, Float32) => true,
+
+        (Float16, Float32) => true,
+        (Float16, Float64) => true,
+        (Float32, Float16) => true,
+        (Float64, Float16) => true,
+        (Decimal(_, _), Float64) => true,
+        (Float64, Decimal(_, _)) => true,
         // end numeric casts
 
         // temporal casts
@@ -262,8 +313,10 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Timestamp(_, _), Timestamp(_, _)) => true,
         (Timestamp(_, _), Date32) => true,
         (Timestamp(_, _), Date64) => true,
+        (Date64, Timestamp(_, _)) => true,
         (Int64, Duration(_)) => true,
         (Duration(_), Int64) => true,
+        (Duration(_), Duration(_)) => true,
         (_, _) => false,
     }
 }
@@ -302,17 +355,38 @@ fn cast_list_to_large_list(array: &ListArray<i32>, to_type: &DataType) -> ListAr
     )
 }
 
-fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i32> {
+fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> Result<ListArray<i32>> {
     let offsets = array.offsets();
+    i32::try_from(*offsets.last().unwrap()).map_err(ArrowError::from_external_error)?;
     let offsets = offsets.iter().map(|x| *x as i32);
     let offets = Buffer::from_trusted_len_iter(offsets);
 
-    ListArray::<i32>::from_data(
+    Ok(ListArray::<i32>::from_data(
         to_type.clone(),
         offets,
         array.values().clone(),
         array.validity().cloned(),
-    )
+    ))
+}
+
+/// Casts `array` to a [`ListArray`] of `to_type` whose every item has exactly one element.
+fn cast_to_list<O: Offset>(
+    array: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<O>> {
+    // cast primitive to list's primitive
+    let values = cast(array, ListArray::<O>::get_child_type(to_type), options)?.into();
+    // create offsets, where if array.len() = 2, we have [0,1,2]
+    let offsets = (0..=array.len()).map(|x| O::from_usize(x).unwrap());
+    let offsets = unsafe { Buffer::from_trusted_len_iter_unchecked(offsets) };
+
+    Ok(ListArray::<O>::from_data(
+        to_type.clone(),
+        offsets,
+        values,
+        None,
+    ))
 }
 
 /// Cast `array` to the provided data type and return a new [`Array`] with
@@ -324,16 +398,29 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
+/// * Boolean to numeric: `true` => 1, `false` => 0
+/// * Utf8 to boolean: `"true"` and `"1"` return `true`, `"false"` and `"0"` return `false`
+///   (case-insensitive); any other string returns null (an error when `options.strict`)
+/// * Float16 to and from Float32 and Float64: rounded to the nearest `f16`; out of range
+///   values become infinite
+/// * Decimal to and from Float64: values are scaled by `10^scale`; Float64 values are rounded
+///   to `scale` digits and are null when they don't fit in `precision` digits (an error when
+///   `options.strict`)
 /// * List to List: the underlying data type is cast
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
-/// * Timestamp and Date{32|64}: precision lost when going to higher interval
+/// * Timestamp and Date{32|64}: precision lost when going to higher interval; timestamps are
+///   truncated to midnight UTC
+/// * Timestamp, Date64 and Duration to a finer unit: values that overflow `i64` are None
+///   (or wrapped when `options.wrapped`, or an error when `options.strict`)
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Extension to/from its storage type: zero-copy with data type change
+/// * Null to any type: an array of nulls
+/// * Any type to Null: an array of nulls, discarding its values
 /// Unsupported Casts
 /// * To or from `StructArray`
 /// * List to primitive
-/// * Utf8 to boolean
 /// * Interval and duration
 pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Result<Box<dyn Array>> {
     use DataType::*;
@@ -346,16 +433,15 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
 
     let as_options = options.with_wrapped(true);
     match (from_type, to_type) {
-        (
-            Null,
-            Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Float32 | Date32 | Time32(_)
-            | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
-        )
-        | (
-            Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Float32 | Date32 | Time32(_)
-            | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
-            Null,
-        ) => Ok(new_null_array(to_type.clone(), array.len())),
+        (Extension(_, storage, _), _) => {
+            let array = to_data_type(array, storage.as_ref().clone())?;
+            cast(array.as_ref(), to_type, options)
+        }
+        (_, Extension(_, storage, _)) => {
+            let array = cast(array, storage, options)?;
+            to_data_type(array.as_ref(), to_type.clone())
+        }
+        (Null, _) | (_, Null) => Ok(new_null_array(to_type.clone(), array.len())),
         (Struct(_), _) => Err(ArrowError::NotYetImplemented(
             "Cannot cast from struct to other types".to_string(),
         )),
@@ -375,22 +461,34 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             to_type,
         ))
         .map(|x| Box::new(x) as Box<dyn Array>),
-        (LargeList(lhs), List(rhs)) if lhs == rhs => Ok(cast_large_to_list(
-            array.as_any().downcast_ref().unwrap(),
-            to_type,
-        ))
-        .map(|x| Box::new(x) as Box<dyn Array>),
-
-        (_, List(to)) => {
-            // cast primitive to list's primitive
-            let values = cast(array, to.data_type(), options)?.into();
-            // create offsets, where if array.len() = 2, we have [0,1,2]
-            let offsets =
-                unsafe { Buffer::from_trusted_len_iter_unchecked(0..=array.len() as i32) };
-
-            let list_array = ListArray::<i32>::from_data(to_type.clone(), offsets, values, None);
+        (LargeList(lhs), List(rhs)) if lhs == rhs => {
+            cast_large_to_list(array.as_any().downcast_ref().unwrap(), to_type)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (List(_), LargeList(rhs)) => {
+            // cast the values first, then the offsets
+            let list = cast_list::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+                &List(rhs.clone()),
+                options,
+            )?;
+            Ok(Box::new(cast_list_to_large_list(&list, to_type)))
+        }
+        (LargeList(_), List(rhs)) => {
+            // cast the values first, then the offsets
+            let list = cast_list::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+                &LargeList(rhs.clone()),
+                options,
+            )?;
+            cast_large_to_list(&list, to_type).map(|x| Box::new(x) as Box<dyn Array>)
+        }
 
-            Ok(Box::new(list_array))
+        (_, List(_)) => {
+            cast_to_list::<i32>(array, to_type, options).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (_, LargeList(_)) => {
+            cast_to_list::<i64>(array, to_type, options).map(|x| Box::new(x) as Box<dyn Array>)
         }
 
         (Dictionary(index_type, ..), _) => match_integer_type!(index_type, |$T| {
@@ -410,6 +508,8 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_boolean_dyn::<i64>(array, to_type.clone()),
             Float32 => primitive_to_boolean_dyn::<f32>(array, to_type.clone()),
             Float64 => primitive_to_boolean_dyn::<f64>(array, to_type.clone()),
+            Utf8 => utf8_to_boolean_dyn::<i32>(array, options),
+            LargeUtf8 => utf8_to_boolean_dyn::<i64>(array, options),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -452,6 +552,14 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             LargeUtf8 => Ok(Box::new(utf8_to_large_utf8(
                 array.as_any().downcast_ref().unwrap(),
             ))),
+            Binary => Ok(Box::new(utf8_to_binary::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+                to_type.clone(),
+            ))),
+            LargeBinary => Ok(Box::new(utf8_to_binary::<i64>(
+                &utf8_to_large_utf8(array.as_any().downcast_ref().unwrap()),
+                to_type.clone(),
+            ))),
             Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i32>(array),
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
                 utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone())
@@ -476,6 +584,12 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Date64 => utf8_to_date64_dyn::<i64>(array),
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap())
                 .map(|x| Box::new(x) as Box<dyn Array>),
+            Binary => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap())
+                .map(|x| Box::new(utf8_to_binary::<i32>(&x, to_type.clone())) as Box<dyn Array>),
+            LargeBinary => Ok(Box::new(utf8_to_binary::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+                to_type.clone(),
+            ))),
             Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i64>(array),
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
                 utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone())
@@ -497,16 +611,15 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i32>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i32>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i32>(array),
-            Binary => {
-                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-
-                // perf todo: the offsets are equal; we can speed-up this
-                let iter = array
-                    .iter()
-                    .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
-
-                let array = Utf8Array::<i32>::from_trusted_len_iter(iter);
-                Ok(Box::new(array))
+            Binary => binary_to_utf8_dyn::<i32, i32>(array),
+            LargeBinary => binary_to_utf8_dyn::<i64, i32>(array),
+            Date32 => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(date32_to_utf8::<i32>(from)))
+            }
+            Date64 => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(date64_to_utf8::<i32>(from)))
             }
             Timestamp(from_unit, Some(tz)) => {
                 let from = array.as_any().downcast_ref().unwrap();
@@ -533,16 +646,15 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i64>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i64>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i64>(array),
-            Binary => {
-                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-
-                // perf todo: the offsets are equal; we can speed-up this
-                let iter = array
-                    .iter()
-                    .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
-
-                let array = Utf8Array::<i64>::from_trusted_len_iter(iter);
-                Ok(Box::new(array))
+            Binary => binary_to_utf8_dyn::<i32, i64>(array),
+            LargeBinary => binary_to_utf8_dyn::<i64, i64>(array),
+            Date32 => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(date32_to_utf8::<i64>(from)))
+            }
+            Date64 => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(date64_to_utf8::<i64>(from)))
             }
             Timestamp(from_unit, Some(tz)) => {
                 let from = array.as_any().downcast_ref().unwrap();
@@ -734,6 +846,15 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Float64, Int32) => primitive_to_primitive_dyn::<f64, i32>(array, to_type, options),
         (Float64, Int64) => primitive_to_primitive_dyn::<f64, i64>(array, to_type, options),
         (Float64, Float32) => primitive_to_primitive_dyn::<f64, f32>(array, to_type, options),
+
+        (Float16, Float32) => primitive_dyn!(array, f16_to_f32),
+        (Float16, Float64) => primitive_dyn!(array, f16_to_f64),
+        (Float32, Float16) => primitive_dyn!(array, f32_to_f16),
+        (Float64, Float16) => primitive_dyn!(array, f64_to_f16),
+        (Decimal(_, _), Float64) => primitive_dyn!(array, decimal_to_f64),
+        (Float64, Decimal(precision, scale)) => scaled_cast(array, to_type, options, |_| {
+            primitive_dyn!(array, f64_to_decimal, *precision, *scale)
+        }),
         // end numeric casts
 
         // temporal casts
@@ -781,13 +902,32 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Timestamp(_, _), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Int64, Timestamp(_, _)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Timestamp(from_unit, _), Timestamp(to_unit, tz)) => {
-            primitive_dyn!(array, timestamp_to_timestamp, *from_unit, *to_unit, tz)
+            scaled_cast(array, to_type, options, |options| {
+                primitive_dyn!(
+                    array,
+                    timestamp_to_timestamp,
+                    *from_unit,
+                    *to_unit,
+                    tz,
+                    options
+                )
+            })
         }
         (Timestamp(from_unit, _), Date32) => primitive_dyn!(array, timestamp_to_date32, *from_unit),
-        (Timestamp(from_unit, _), Date64) => primitive_dyn!(array, timestamp_to_date64, *from_unit),
+        (Timestamp(from_unit, _), Date64) => scaled_cast(array, to_type, options, |options| {
+            primitive_dyn!(array, timestamp_to_date64, *from_unit, options)
+        }),
+        (Date64, Timestamp(to_unit, tz)) => scaled_cast(array, to_type, options, |options| {
+            primitive_dyn!(array, date64_to_timestamp, *to_unit, tz, options)
+        }),
 
         (Int64, Duration(_)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Duration(_), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
+        (Duration(from_unit), Duration(to_unit)) => {
+            scaled_cast(array, to_type, options, |options| {
+                primitive_dyn!(array, duration_to_duration, *from_unit, *to_unit, options)
+            })
+        }
 
         (_, _) => Err(ArrowError::NotYetImplemented(format!(
             "Casting from {:?} to {:?} not supported",
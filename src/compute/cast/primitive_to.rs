@@ -4,10 +4,10 @@ use crate::error::Result;
 use crate::{
     array::*,
     bitmap::Bitmap,
-    compute::arity::unary,
+    compute::arity::{unary, unary_checked},
     datatypes::{DataType, TimeUnit},
     temporal_conversions::*,
-    types::NativeType,
+    types::{f16, NativeType},
 };
 
 use super::CastOptions;
@@ -175,11 +175,7 @@ pub fn primitive_to_same_primitive<T>(
 where
     T: NativeType,
 {
-    PrimitiveArray::<T>::from_data(
-        to_type.clone(),
-        from.values().clone(),
-        from.validity().cloned(),
-    )
+    from.clone().to(to_type.clone())
 }
 
 /// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of the same physical type.
@@ -228,6 +224,64 @@ const fn time_unit_multiple(unit: TimeUnit) -> i64 {
     }
 }
 
+/// Conversion of `f16` to `f32`. This conversion is lossless.
+pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
+    unary(from, |x| x.to_f32(), DataType::Float32)
+}
+
+/// Conversion of `f16` to `f64`. This conversion is lossless.
+pub fn f16_to_f64(from: &PrimitiveArray<f16>) -> PrimitiveArray<f64> {
+    unary(from, |x| x.to_f64(), DataType::Float64)
+}
+
+/// Conversion of `f32` to `f16`, rounding to the nearest (ties to even) `f16`.
+/// Values out of range become infinite.
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f32, DataType::Float16)
+}
+
+/// Conversion of `f64` to `f16`, rounding to the nearest (ties to even) `f16`.
+/// Values out of range become infinite.
+pub fn f64_to_f16(from: &PrimitiveArray<f64>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f64, DataType::Float16)
+}
+
+/// Conversion of a [`DataType::Decimal`] array to `f64`. Values beyond 2^53 lose precision.
+/// # Panics
+/// Panics iff `from` is not a [`DataType::Decimal`] array.
+pub fn decimal_to_f64(from: &PrimitiveArray<i128>) -> PrimitiveArray<f64> {
+    let scale = match from.data_type().to_logical_type() {
+        DataType::Decimal(_, scale) => *scale,
+        _ => panic!("decimal_to_f64 expects a Decimal array"),
+    };
+    let div = 10f64.powi(scale as i32);
+    unary(from, |x| x as f64 / div, DataType::Float64)
+}
+
+/// Conversion of `f64` to [`DataType::Decimal`] of `precision` and `scale`, rounding to
+/// the nearest value with `scale` digits. Values that are not finite or whose rounded value
+/// has more than `precision` digits are null.
+pub fn f64_to_decimal(
+    from: &PrimitiveArray<f64>,
+    precision: usize,
+    scale: usize,
+) -> PrimitiveArray<i128> {
+    let mul = 10f64.powi(scale as i32);
+    let max = 10f64.powi(precision as i32);
+    unary_checked(
+        from,
+        |x| {
+            let x = (x * mul).round();
+            if x.is_finite() && x.abs() < max {
+                Some(x as i128)
+            } else {
+                None
+            }
+        },
+        DataType::Decimal(precision, scale),
+    )
+}
+
 /// Conversion of dates
 pub fn date32_to_date64(from: &PrimitiveArray<i32>) -> PrimitiveArray<i64> {
     unary(from, |x| x as i64 * MILLISECONDS_IN_DAY, DataType::Date64)
@@ -258,27 +312,71 @@ pub fn time64ns_to_time64us(from: &PrimitiveArray<i64>) -> PrimitiveArray<i64> {
     unary(from, |x| x / 1000, DataType::Time64(TimeUnit::Microsecond))
 }
 
-/// Conversion of timestamp
-pub fn timestamp_to_date64(from: &PrimitiveArray<i64>, from_unit: TimeUnit) -> PrimitiveArray<i64> {
-    let from_size = time_unit_multiple(from_unit);
-    let to_size = MILLISECONDS;
-    let to_type = DataType::Date64;
-
-    // Scale time_array by (to_size / from_size) using a
-    // single integer operation, but need to avoid integer
-    // math rounding down to zero
+// multiplies every value by `factor`, nulling the values that overflow (or wrapping them when
+// `options.wrapped`)
+fn scale_up(
+    from: &PrimitiveArray<i64>,
+    factor: i64,
+    to_type: DataType,
+    options: CastOptions,
+) -> PrimitiveArray<i64> {
+    if options.wrapped {
+        unary(from, |x| x.wrapping_mul(factor), to_type)
+    } else {
+        unary_checked(from, |x| x.checked_mul(factor), to_type)
+    }
+}
 
+// converts values from a unit of `from_size` to a unit of `to_size` ticks per second
+fn scale_unit(
+    from: &PrimitiveArray<i64>,
+    from_size: i64,
+    to_size: i64,
+    to_type: DataType,
+    options: CastOptions,
+) -> PrimitiveArray<i64> {
+    // we either divide or multiply, depending on size of each unit
     match to_size.cmp(&from_size) {
-        std::cmp::Ordering::Less => unary(from, |x| (x / (from_size / to_size)), to_type),
+        std::cmp::Ordering::Less => unary(from, |x| x / (from_size / to_size), to_type),
         std::cmp::Ordering::Equal => primitive_to_same_primitive(from, &to_type),
-        std::cmp::Ordering::Greater => unary(from, |x| (x * (to_size / from_size)), to_type),
+        std::cmp::Ordering::Greater => scale_up(from, to_size / from_size, to_type, options),
     }
 }
 
-/// Conversion of timestamp
+/// Conversion of timestamp, truncating it to midnight UTC.
+/// Dates that overflow are null, or wrapped when `options.wrapped`.
+pub fn timestamp_to_date64(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    options: CastOptions,
+) -> PrimitiveArray<i64> {
+    let from_size = time_unit_multiple(from_unit) * SECONDS_IN_DAY;
+    let days = unary(from, |x| x.div_euclid(from_size), DataType::Date64);
+    scale_up(&days, MILLISECONDS_IN_DAY, DataType::Date64, options)
+}
+
+/// Conversion of timestamp, truncating it to midnight UTC
 pub fn timestamp_to_date32(from: &PrimitiveArray<i64>, from_unit: TimeUnit) -> PrimitiveArray<i32> {
     let from_size = time_unit_multiple(from_unit) * SECONDS_IN_DAY;
-    unary(from, |x| (x / from_size) as i32, DataType::Date32)
+    unary(from, |x| x.div_euclid(from_size) as i32, DataType::Date32)
+}
+
+/// Conversion of date to timestamp.
+/// Timestamps that overflow are null, or wrapped when `options.wrapped`.
+pub fn date64_to_timestamp(
+    from: &PrimitiveArray<i64>,
+    to_unit: TimeUnit,
+    tz: &Option<String>,
+    options: CastOptions,
+) -> PrimitiveArray<i64> {
+    let to_type = DataType::Timestamp(to_unit, tz.clone());
+    scale_unit(
+        from,
+        MILLISECONDS,
+        time_unit_multiple(to_unit),
+        to_type,
+        options,
+    )
 }
 
 /// Conversion of time
@@ -309,24 +407,84 @@ pub fn time64_to_time32(
     )
 }
 
-/// Conversion of timestamp
+/// Conversion of timestamp.
+/// Timestamps that overflow are null, or wrapped when `options.wrapped`.
 pub fn timestamp_to_timestamp(
     from: &PrimitiveArray<i64>,
     from_unit: TimeUnit,
     to_unit: TimeUnit,
     tz: &Option<String>,
+    options: CastOptions,
 ) -> PrimitiveArray<i64> {
     let from_size = time_unit_multiple(from_unit);
     let to_size = time_unit_multiple(to_unit);
     let to_type = DataType::Timestamp(to_unit, tz.clone());
-    // we either divide or multiply, depending on size of each unit
-    if from_size >= to_size {
-        unary(from, |x| (x / (from_size / to_size)), to_type)
-    } else {
-        unary(from, |x| (x * (to_size / from_size)), to_type)
+    scale_unit(from, from_size, to_size, to_type, options)
+}
+
+/// Conversion of duration.
+/// Durations that overflow are null, or wrapped when `options.wrapped`.
+pub fn duration_to_duration(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+    options: CastOptions,
+) -> PrimitiveArray<i64> {
+    let from_size = time_unit_multiple(from_unit);
+    let to_size = time_unit_multiple(to_unit);
+    scale_unit(
+        from,
+        from_size,
+        to_size,
+        DataType::Duration(to_unit),
+        options,
+    )
+}
+
+/// Returns a [`Utf8Array`] whose every element is the [`std::fmt::Display`] of `op` applied to the
+/// value of `from`. The values are written to a single buffer, without intermediate allocations.
+fn primitive_to_utf8_display<T, O, D, F>(from: &PrimitiveArray<T>, op: F) -> Utf8Array<O>
+where
+    T: NativeType,
+    O: Offset,
+    D: std::fmt::Display,
+    F: Fn(T) -> D,
+{
+    use std::io::Write;
+
+    let mut values: Vec<u8> = Vec::with_capacity(from.len() * 8);
+    let mut offsets: Vec<O> = Vec::with_capacity(from.len() + 1);
+    offsets.push(O::default());
+
+    for x in from.iter() {
+        if let Some(x) = x {
+            // writing to a `Vec` is infallible
+            write!(values, "{}", op(*x)).unwrap();
+        }
+        offsets.push(O::from_usize(values.len()).unwrap());
+    }
+
+    // Safety: `values` was only written to by `Display` implementations, which are valid utf8
+    unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            Utf8Array::<O>::default_data_type(),
+            offsets.into(),
+            values.into(),
+            from.validity().cloned(),
+        )
     }
 }
 
+/// Returns a [`Utf8Array`] where every element is the ISO 8601 representation (`%Y-%m-%d`) of the date.
+pub fn date32_to_utf8<O: Offset>(from: &PrimitiveArray<i32>) -> Utf8Array<O> {
+    primitive_to_utf8_display(from, date32_to_date)
+}
+
+/// Returns a [`Utf8Array`] where every element is the ISO 8601 representation (`%Y-%m-%d`) of the date.
+pub fn date64_to_utf8<O: Offset>(from: &PrimitiveArray<i64>) -> Utf8Array<O> {
+    primitive_to_utf8_display(from, date64_to_date)
+}
+
 fn timestamp_to_utf8_impl<O: Offset, T: chrono::TimeZone>(
     from: &PrimitiveArray<i64>,
     time_unit: TimeUnit,
@@ -335,48 +493,19 @@ fn timestamp_to_utf8_impl<O: Offset, T: chrono::TimeZone>(
 where
     T::Offset: std::fmt::Display,
 {
-    match time_unit {
-        TimeUnit::Nanosecond => {
-            let iter = from.iter().map(|x| {
-                x.map(|x| {
-                    let datetime = timestamp_ns_to_datetime(*x);
-                    let offset = timezone.offset_from_utc_datetime(&datetime);
-                    chrono::DateTime::<T>::from_utc(datetime, offset).to_rfc3339()
-                })
-            });
-            Utf8Array::from_trusted_len_iter(iter)
-        }
-        TimeUnit::Microsecond => {
-            let iter = from.iter().map(|x| {
-                x.map(|x| {
-                    let datetime = timestamp_us_to_datetime(*x);
-                    let offset = timezone.offset_from_utc_datetime(&datetime);
-                    chrono::DateTime::<T>::from_utc(datetime, offset).to_rfc3339()
-                })
-            });
-            Utf8Array::from_trusted_len_iter(iter)
-        }
-        TimeUnit::Millisecond => {
-            let iter = from.iter().map(|x| {
-                x.map(|x| {
-                    let datetime = timestamp_ms_to_datetime(*x);
-                    let offset = timezone.offset_from_utc_datetime(&datetime);
-                    chrono::DateTime::<T>::from_utc(datetime, offset).to_rfc3339()
-                })
-            });
-            Utf8Array::from_trusted_len_iter(iter)
-        }
-        TimeUnit::Second => {
-            let iter = from.iter().map(|x| {
-                x.map(|x| {
-                    let datetime = timestamp_s_to_datetime(*x);
-                    let offset = timezone.offset_from_utc_datetime(&datetime);
-                    chrono::DateTime::<T>::from_utc(datetime, offset).to_rfc3339()
-                })
-            });
-            Utf8Array::from_trusted_len_iter(iter)
-        }
-    }
+    // equivalent to `DateTime::to_rfc3339`, parsed once for all values
+    let items = chrono::format::StrftimeItems::new("%Y-%m-%dT%H:%M:%S%.f%:z").collect::<Vec<_>>();
+    let to_datetime = match time_unit {
+        TimeUnit::Nanosecond => timestamp_ns_to_datetime,
+        TimeUnit::Microsecond => timestamp_us_to_datetime,
+        TimeUnit::Millisecond => timestamp_ms_to_datetime,
+        TimeUnit::Second => timestamp_s_to_datetime,
+    };
+    primitive_to_utf8_display(from, |x| {
+        let datetime = to_datetime(x);
+        let offset = timezone.offset_from_utc_datetime(&datetime);
+        chrono::DateTime::<T>::from_utc(datetime, offset).format_with_items(items.iter())
+    })
 }
 
 #[cfg(feature = "chrono-tz")]
@@ -428,37 +557,9 @@ pub fn naive_timestamp_to_utf8<O: Offset>(
     time_unit: TimeUnit,
 ) -> Utf8Array<O> {
     match time_unit {
-        TimeUnit::Nanosecond => {
-            let iter = from.iter().map(|x| {
-                x.copied()
-                    .map(timestamp_ns_to_datetime)
-                    .map(|x| x.to_string())
-            });
-            Utf8Array::from_trusted_len_iter(iter)
-        }
-        TimeUnit::Microsecond => {
-            let iter = from.iter().map(|x| {
-                x.copied()
-                    .map(timestamp_us_to_datetime)
-                    .map(|x| x.to_string())
-            });
-            Utf8Array::from_trusted_len_iter(iter)
-        }
-        TimeUnit::Millisecond => {
-            let iter = from.iter().map(|x| {
-                x.copied()
-                    .map(timestamp_ms_to_datetime)
-                    .map(|x| x.to_string())
-            });
-            Utf8Array::from_trusted_len_iter(iter)
-        }
-        TimeUnit::Second => {
-            let iter = from.iter().map(|x| {
-                x.copied()
-                    .map(timestamp_s_to_datetime)
-                    .map(|x| x.to_string())
-            });
-            Utf8Array::from_trusted_len_iter(iter)
-        }
+        TimeUnit::Nanosecond => primitive_to_utf8_display(from, timestamp_ns_to_datetime),
+        TimeUnit::Microsecond => primitive_to_utf8_display(from, timestamp_us_to_datetime),
+        TimeUnit::Millisecond => primitive_to_utf8_display(from, timestamp_ms_to_datetime),
+        TimeUnit::Second => primitive_to_utf8_display(from, timestamp_s_to_datetime),
     }
 }
@@ -1,11 +1,18 @@
 use std::convert::TryFrom;
 
-use chrono::Datelike;
+use chrono::format::{parse, Parsed, StrftimeItems};
+use chrono::{Datelike, FixedOffset, NaiveDateTime, NaiveTime, TimeZone};
 
-use crate::{array::*, buffer::Buffer, datatypes::DataType, types::NativeType};
+use crate::{
+    array::*,
+    buffer::Buffer,
+    datatypes::{DataType, TimeUnit},
+    types::NativeType,
+};
 use crate::{
     error::{ArrowError, Result},
     temporal_conversions::{
+        naive_datetime_to_timestamp, parse_offset,
         utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
         utf8_to_timestamp_ns as utf8_to_timestamp_ns_, EPOCH_DAYS_FROM_CE,
     },
@@ -15,6 +22,24 @@ use super::CastOptions;
 
 const RFC3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
 
+/// The formats tried, in order, by [`utf8_to_timestamp`] and [`utf8_to_date64_with_format`]
+/// when no format is provided.
+pub const DEFAULT_TIMESTAMP_FORMATS: &[&str] = &[
+    RFC3339,
+    "%Y-%m-%d %H:%M:%S%.f%:z",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%d",
+    "%m/%d/%Y %H:%M:%S%.f",
+    "%m/%d/%Y %H:%M",
+    "%m/%d/%Y",
+];
+
+/// The formats tried, in order, by [`utf8_to_date32_with_format`] when no format is provided.
+pub const DEFAULT_DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%m/%d/%Y", "%Y/%m/%d"];
+
 /// Casts a [`Utf8Array`] to a [`PrimitiveArray`], making any uncastable value a Null.
 pub fn utf8_to_primitive<O: Offset, T>(from: &Utf8Array<O>, to: &DataType) -> PrimitiveArray<T>
 where
@@ -58,6 +83,55 @@ where
     }
 }
 
+#[inline]
+fn parse_boolean(value: &str) -> Option<bool> {
+    if value.eq_ignore_ascii_case("true") || value == "1" {
+        Some(true)
+    } else if value.eq_ignore_ascii_case("false") || value == "0" {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Casts a [`Utf8Array`] to a [`BooleanArray`], where `"true"` and `"1"` are cast to `true` and
+/// `"false"` and `"0"` are cast to `false` (case-insensitive), making any other value a Null.
+pub fn utf8_to_boolean<O: Offset>(from: &Utf8Array<O>) -> BooleanArray {
+    let iter = from.iter().map(|x| x.and_then(parse_boolean));
+    BooleanArray::from_trusted_len_iter(iter)
+}
+
+/// Casts a [`Utf8Array`] to a [`BooleanArray`] like [`utf8_to_boolean`], but errors on
+/// any non-null value that is neither `"true"`, `"false"`, `"1"` nor `"0"` (case-insensitive).
+/// # Errors
+/// Errors iff a non-null value can't be parsed as a boolean.
+pub fn try_utf8_to_boolean<O: Offset>(from: &Utf8Array<O>) -> Result<BooleanArray> {
+    let iter = from.iter().map(|x| {
+        x.map(|x| {
+            parse_boolean(x).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The value \"{}\" can't be cast to a boolean",
+                    x
+                ))
+            })
+        })
+        .transpose()
+    });
+    BooleanArray::try_from_trusted_len_iter(iter)
+}
+
+pub(super) fn utf8_to_boolean_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    if options.strict {
+        Ok(Box::new(try_utf8_to_boolean::<O>(from)?))
+    } else {
+        Ok(Box::new(utf8_to_boolean::<O>(from)))
+    }
+}
+
 /// Casts a [`Utf8Array`] to a Date32 primitive, making any uncastable value a Null.
 pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
     let iter = from.iter().map(|x| {
@@ -81,7 +155,7 @@ pub fn utf8_to_date64<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i64> {
         x.and_then(|x| {
             x.parse::<chrono::NaiveDateTime>()
                 .ok()
-                .map(|x| x.timestamp_millis())
+                .map(|x| x.and_utc().timestamp_millis())
         })
     });
     PrimitiveArray::<i64>::from_trusted_len_iter(iter).to(DataType::Date64)
@@ -92,6 +166,163 @@ pub(super) fn utf8_to_date64_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn
     Ok(Box::new(utf8_to_date64::<O>(from)))
 }
 
+/// Parses `value` according to `format` into a datetime and its offset, if any was parsed.
+/// Formats without time fields are parsed at midnight.
+fn parse_datetime(value: &str, format: &str) -> Option<(NaiveDateTime, Option<FixedOffset>)> {
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, value, StrftimeItems::new(format)).ok()?;
+    let date = parsed.to_naive_date().ok()?;
+    let time = if parsed.hour_div_12.is_some() || parsed.hour_mod_12.is_some() {
+        parsed.to_naive_time().ok()?
+    } else {
+        NaiveTime::from_hms_opt(0, 0, 0)?
+    };
+    let offset = match parsed.offset {
+        Some(offset) => Some(FixedOffset::east_opt(offset)?),
+        None => None,
+    };
+    Some((date.and_time(time), offset))
+}
+
+fn parse_with_formats(
+    value: &str,
+    format: Option<&str>,
+    default_formats: &[&str],
+) -> Option<(NaiveDateTime, Option<FixedOffset>)> {
+    match format {
+        Some(format) => parse_datetime(value, format),
+        None => default_formats
+            .iter()
+            .find_map(|format| parse_datetime(value, format)),
+    }
+}
+
+/// Converts a `datetime` with an `offset` to UTC, or `None` on overflow.
+fn offset_to_utc(datetime: NaiveDateTime, offset: FixedOffset) -> Option<NaiveDateTime> {
+    datetime.checked_sub_signed(chrono::Duration::seconds(offset.local_minus_utc() as i64))
+}
+
+/// Casts a [`Utf8Array`] to a Date32 primitive according to a `chrono` format string, making
+/// any unparsable value a Null. When `format` is `None`, the formats in [`DEFAULT_DATE_FORMATS`]
+/// are tried in order. Any time parsed by `format` is disregarded.
+pub fn utf8_to_date32_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    format: Option<&str>,
+) -> PrimitiveArray<i32> {
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| parse_with_formats(x, format, DEFAULT_DATE_FORMATS))
+            .map(|(datetime, _)| datetime.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+    });
+    PrimitiveArray::<i32>::from_trusted_len_iter(iter).to(DataType::Date32)
+}
+
+/// Casts a [`Utf8Array`] to a Date64 primitive according to a `chrono` format string, making
+/// any unparsable value a Null. When `format` is `None`, the formats in
+/// [`DEFAULT_TIMESTAMP_FORMATS`] are tried in order. Offsets are disregarded.
+pub fn utf8_to_date64_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    format: Option<&str>,
+) -> PrimitiveArray<i64> {
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| parse_with_formats(x, format, DEFAULT_TIMESTAMP_FORMATS))
+            .and_then(|(datetime, _)| naive_datetime_to_timestamp(&datetime, TimeUnit::Millisecond))
+    });
+    PrimitiveArray::<i64>::from_trusted_len_iter(iter).to(DataType::Date64)
+}
+
+fn utf8_to_timestamp_impl<O: Offset, F>(
+    from: &Utf8Array<O>,
+    format: Option<&str>,
+    time_unit: TimeUnit,
+    to_utc: F,
+) -> PrimitiveArray<i64>
+where
+    F: Fn(NaiveDateTime, Option<FixedOffset>) -> Option<NaiveDateTime>,
+{
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| parse_with_formats(x, format, DEFAULT_TIMESTAMP_FORMATS))
+            .and_then(|(datetime, offset)| to_utc(datetime, offset))
+            .and_then(|datetime| naive_datetime_to_timestamp(&datetime, time_unit))
+    });
+    PrimitiveArray::<i64>::from_trusted_len_iter(iter)
+}
+
+fn utf8_to_tz_timestamp_impl<O: Offset, T: TimeZone>(
+    from: &Utf8Array<O>,
+    format: Option<&str>,
+    time_unit: TimeUnit,
+    tz: &T,
+) -> PrimitiveArray<i64> {
+    utf8_to_timestamp_impl(from, format, time_unit, |datetime, offset| match offset {
+        Some(offset) => offset_to_utc(datetime, offset),
+        // ambiguous and non-existent local datetimes (e.g. at daylight saving time transitions)
+        // have no single instant and are null
+        None => tz
+            .from_local_datetime(&datetime)
+            .single()
+            .map(|x| x.naive_utc()),
+    })
+}
+
+#[cfg(feature = "chrono-tz")]
+fn chrono_tz_utf8_to_timestamp<O: Offset>(
+    from: &Utf8Array<O>,
+    format: Option<&str>,
+    time_unit: TimeUnit,
+    timezone: &str,
+) -> Result<PrimitiveArray<i64>> {
+    let tz = crate::temporal_conversions::parse_offset_tz(timezone)?;
+    Ok(utf8_to_tz_timestamp_impl(from, format, time_unit, &tz))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_utf8_to_timestamp<O: Offset>(
+    _: &Utf8Array<O>,
+    _: Option<&str>,
+    _: TimeUnit,
+    timezone: &str,
+) -> Result<PrimitiveArray<i64>> {
+    Err(ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone
+    )))
+}
+
+/// Casts a [`Utf8Array`] to a [`PrimitiveArray<i64>`] of type `Timestamp(time_unit, timezone)`
+/// according to a `chrono` format string. When `format` is `None`, the formats in
+/// [`DEFAULT_TIMESTAMP_FORMATS`] are tried in order.
+/// # Implementation
+/// * Fractional seconds are parsed up to nanosecond precision and truncated to `time_unit`.
+/// * Values without time fields are parsed at midnight.
+/// * Values with an offset are converted to UTC, also when `timezone` is `None`.
+/// * When `timezone` is `Some`, values without an offset are interpreted in `timezone`.
+///   Values that are ambiguous or do not exist in `timezone` (e.g. at daylight saving time
+///   transitions) are null.
+/// * Null elements remain null; unparsable elements and elements whose timestamp does not fit
+///   in an `i64` of `time_unit` are null.
+///
+/// The feature `"chrono-tz"` enables IANA and zoneinfo formats for `timezone`.
+/// # Error
+/// This function errors iff `timezone` is not parsable to an offset.
+pub fn utf8_to_timestamp<O: Offset>(
+    from: &Utf8Array<O>,
+    format: Option<&str>,
+    time_unit: TimeUnit,
+    timezone: Option<String>,
+) -> Result<PrimitiveArray<i64>> {
+    let array = match timezone.as_deref() {
+        None => utf8_to_timestamp_impl(from, format, time_unit, |datetime, offset| match offset {
+            Some(offset) => offset_to_utc(datetime, offset),
+            None => Some(datetime),
+        }),
+        Some(tz) => match parse_offset(tz) {
+            Ok(tz) => utf8_to_tz_timestamp_impl(from, format, time_unit, &tz),
+            Err(_) => chrono_tz_utf8_to_timestamp(from, format, time_unit, tz)?,
+        },
+    };
+    Ok(array.to(DataType::Timestamp(time_unit, timezone)))
+}
+
 pub(super) fn utf8_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
     from: &dyn Array,
 ) -> Result<Box<dyn Array>> {
@@ -142,6 +373,16 @@ pub fn utf8_to_timestamp_ns<O: Offset>(
     utf8_to_timestamp_ns_(from, RFC3339, timezone)
 }
 
+/// Conversion of utf8 to binary, which shares the offsets and values of `from`.
+pub fn utf8_to_binary<O: Offset>(from: &Utf8Array<O>, to_data_type: DataType) -> BinaryArray<O> {
+    BinaryArray::<O>::from_data(
+        to_data_type,
+        from.offsets().clone(),
+        from.values().clone(),
+        from.validity().cloned(),
+    )
+}
+
 /// Conversion of utf8
 pub fn utf8_to_large_utf8(from: &Utf8Array<i32>) -> Utf8Array<i64> {
     let data_type = Utf8Array::<i64>::default_data_type();
@@ -0,0 +1,199 @@
+//! Contains the operator [`clamp`], that clips the values of an array to (optional) bounds in a
+//! single pass.
+//!
+//! Bounds are inclusive and nulls are preserved. Comparisons follow [`PartialOrd`], so that NaN
+//! values pass through unchanged and a NaN bound does not clip any value.
+use super::arity::unary;
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::scalar::{PrimitiveScalar, Scalar};
+use crate::types::NativeType;
+
+/// Returns `array` with values smaller than `min` replaced by `min` and values larger than `max`
+/// replaced by `max`. A bound of `None` does not clip any value.
+/// # Example
+/// ```rust
+/// # use arrow2::array::Int32Array;
+/// # use arrow2::error::Result;
+/// # use arrow2::compute::clamp::clamp_primitive;
+/// # fn main() -> Result<()> {
+/// let array = Int32Array::from(&[Some(-5), None, Some(3), Some(10)]);
+/// let result = clamp_primitive(&array, Some(0), Some(5))?;
+/// assert_eq!(result, Int32Array::from(&[Some(0), None, Some(3), Some(5)]));
+///
+/// let result = clamp_primitive(&array, None, Some(5))?;
+/// assert_eq!(result, Int32Array::from(&[Some(-5), None, Some(3), Some(5)]));
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff `min` is larger than `max`.
+pub fn clamp_primitive<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<PrimitiveArray<T>> {
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            return Err(ArrowError::InvalidArgumentError(
+                "The lower bound of clamp must not be larger than its upper bound".to_string(),
+            ));
+        }
+    }
+    let data_type = array.data_type().clone();
+    Ok(match (min, max) {
+        (Some(min), Some(max)) => unary(
+            array,
+            |x| {
+                if x < min {
+                    min
+                } else if x > max {
+                    max
+                } else {
+                    x
+                }
+            },
+            data_type,
+        ),
+        (Some(min), None) => unary(array, |x| if x < min { min } else { x }, data_type),
+        (None, Some(max)) => unary(array, |x| if x > max { max } else { x }, data_type),
+        (None, None) => array.clone(),
+    })
+}
+
+/// Returns `array`, of [`DataType::Decimal`], with its values clipped to `min` and `max`, whose
+/// values have the scale of `array`.
+/// # Errors
+/// This function errors iff
+/// * `array` is not of [`DataType::Decimal`]
+/// * a bound does not fit in the precision of `array`
+/// * `min` is larger than `max`
+pub fn clamp_decimal(
+    array: &PrimitiveArray<i128>,
+    min: Option<i128>,
+    max: Option<i128>,
+) -> Result<PrimitiveArray<i128>> {
+    let precision = match array.data_type() {
+        DataType::Decimal(precision, _) => *precision,
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(
+                "clamp_decimal expects an array of DataType::Decimal".to_string(),
+            ))
+        }
+    };
+    let max_value = 10i128
+        .checked_pow(precision as u32)
+        .map(|x| x - 1)
+        .unwrap_or(i128::MAX);
+    if min
+        .into_iter()
+        .chain(max)
+        .any(|bound| bound > max_value || bound < -max_value)
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The bounds of clamp must fit in the precision of the array ({})",
+            precision
+        )));
+    }
+    clamp_primitive(array, min, max)
+}
+
+/// Returns whether [`clamp`] is implemented for `data_type`.
+pub fn can_clamp(data_type: &DataType) -> bool {
+    use PrimitiveType::*;
+    match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => {
+            !matches!(primitive, Float16 | DaysMs | MonthDayNano)
+                && !matches!(data_type.to_logical_type(), DataType::Interval(_))
+        }
+        _ => false,
+    }
+}
+
+fn bound<T: NativeType>(bound: &dyn Scalar, data_type: &DataType) -> Result<Option<T>> {
+    if bound.data_type() != data_type {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The bounds of clamp must be of the data type of the array ({:?}), got {:?}",
+            data_type,
+            bound.data_type()
+        )));
+    }
+    Ok(bound
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<T>>()
+        .unwrap()
+        .value())
+}
+
+/// Returns `array` with its values clipped to the scalars `min` and `max`, that must be of the
+/// data type of `array`. A null bound does not clip any value.
+/// # Example
+/// ```rust
+/// # use arrow2::array::{Array, Float64Array};
+/// # use arrow2::datatypes::DataType;
+/// # use arrow2::error::Result;
+/// # use arrow2::compute::clamp::clamp;
+/// # use arrow2::scalar::PrimitiveScalar;
+/// # fn main() -> Result<()> {
+/// let array = Float64Array::from(&[Some(-1.5), Some(f64::NAN), None, Some(0.5)]);
+/// let min = PrimitiveScalar::<f64>::new(DataType::Float64, Some(0.0));
+/// let max = PrimitiveScalar::<f64>::new(DataType::Float64, None);
+/// let result = clamp(&array, &min, &max)?;
+///
+/// let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+/// assert_eq!(result.value(0), 0.0);
+/// assert!(result.value(1).is_nan());
+/// assert!(result.is_null(2));
+/// assert_eq!(result.value(3), 0.5);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff
+/// * the data type of `array` is not supported (see [`can_clamp`])
+/// * the bounds are not of the data type of `array`
+/// * `min` is larger than `max`, or, for decimals, a bound does not fit in the precision
+pub fn clamp(array: &dyn Array, min: &dyn Scalar, max: &dyn Scalar) -> Result<Box<dyn Array>> {
+    let data_type = array.data_type();
+    if !can_clamp(data_type) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "clamp of {:?}",
+            data_type
+        )));
+    }
+    if let DataType::Decimal(_, _) = data_type {
+        let array = array.as_any().downcast_ref().unwrap();
+        let min = bound(min, data_type)?;
+        let max = bound(max, data_type)?;
+        return clamp_decimal(array, min, max).map(|x| Box::new(x) as Box<dyn Array>);
+    }
+
+    macro_rules! clamp {
+        ($type:ty) => {{
+            let array = array.as_any().downcast_ref().unwrap();
+            let min = bound::<$type>(min, data_type)?;
+            let max = bound::<$type>(max, data_type)?;
+            clamp_primitive::<$type>(array, min, max).map(|x| Box::new(x) as Box<dyn Array>)
+        }};
+    }
+
+    use PrimitiveType::*;
+    match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => match primitive {
+            Int8 => clamp!(i8),
+            Int16 => clamp!(i16),
+            Int32 => clamp!(i32),
+            Int64 => clamp!(i64),
+            Int128 => clamp!(i128),
+            UInt8 => clamp!(u8),
+            UInt16 => clamp!(u16),
+            UInt32 => clamp!(u32),
+            UInt64 => clamp!(u64),
+            Float32 => clamp!(f32),
+            Float64 => clamp!(f64),
+            Float16 | DaysMs | MonthDayNano => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
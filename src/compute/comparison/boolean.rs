@@ -5,7 +5,7 @@ use crate::{
     datatypes::DataType,
 };
 
-use super::super::utils::combine_validities;
+use crate::bitmap::utils::combine_validities;
 
 /// Evaluate `op(lhs, rhs)` for [`BooleanArray`]s using a specified
 /// comparison function.
@@ -14,7 +14,7 @@ where
     F: Fn(u64, u64) -> u64,
 {
     assert_eq!(lhs.len(), rhs.len());
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
     let values = binary(lhs.values(), rhs.values(), op);
 
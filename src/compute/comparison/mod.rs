@@ -45,14 +45,18 @@
 //! ```
 
 use crate::array::*;
-use crate::datatypes::DataType;
+use crate::datatypes::{DataType, PrimitiveType};
 use crate::scalar::*;
+use crate::types::f16;
 
 pub mod binary;
 pub mod boolean;
 pub mod primitive;
 pub mod utf8;
 
+mod rows;
+pub use rows::{eq_rows, neq_rows};
+
 mod simd;
 pub use simd::{Simd8, Simd8Lanes};
 
@@ -75,11 +79,21 @@ macro_rules! with_match_primitive_cmp {(
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        Float16 => unreachable!(),
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
 })}
 
+// `f16` has no native comparison; it is compared via its (lossless) conversion to `f32`
+fn f16_to_f32(array: &dyn Array) -> PrimitiveArray<f32> {
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f16>>()
+        .unwrap();
+    crate::compute::arity::unary(array, |x| x.to_f32(), DataType::Float32)
+}
+
 macro_rules! compare {
     ($lhs:expr, $rhs:expr, $op:tt) => {{
         let lhs = $lhs;
@@ -96,6 +110,11 @@ macro_rules! compare {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 boolean::$op(lhs, rhs)
             }
+            Primitive(PrimitiveType::Float16) => {
+                let lhs = f16_to_f32(lhs);
+                let rhs = f16_to_f32(rhs);
+                primitive::$op::<f32>(&lhs, &rhs)
+            }
             Primitive(primitive) => with_match_primitive_cmp!(primitive, |$T| {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
@@ -215,6 +234,12 @@ macro_rules! compare_scalar {
                 // validity checked above
                 boolean::$op(lhs, rhs.value().unwrap())
             }
+            Primitive(PrimitiveType::Float16) => {
+                let lhs = f16_to_f32(lhs);
+                let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<f16>>().unwrap();
+                // validity checked above
+                primitive::$op::<f32>(&lhs, rhs.value().unwrap().to_f32())
+            }
             Primitive(primitive) => with_match_primitive_cmp!(primitive, |$T| {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
@@ -355,6 +380,7 @@ fn can_compare(data_type: &DataType) -> bool {
             | DataType::UInt16
             | DataType::UInt32
             | DataType::UInt64
+            | DataType::Float16
             | DataType::Float32
             | DataType::Float64
             | DataType::Utf8
@@ -6,8 +6,8 @@ use crate::{
     types::NativeType,
 };
 
-use super::super::utils::combine_validities;
 use super::simd::{Simd8, Simd8Lanes};
+use crate::bitmap::utils::combine_validities;
 
 pub(crate) fn compare_values_op<T, F>(lhs: &[T], rhs: &[T], op: F) -> MutableBitmap
 where
@@ -44,7 +44,7 @@ where
     T: NativeType + Simd8,
     F: Fn(T::Simd, T::Simd) -> u8,
 {
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
     let values = compare_values_op(lhs.values(), rhs.values(), op);
 
@@ -0,0 +1,221 @@
+//! Row-wise logical equality between two arrays of any (including nested) type.
+use std::cmp::Ordering;
+
+use crate::array::ord::{total_cmp_f32_with_nans, total_cmp_f64_with_nans};
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::types::{f16, Offset};
+
+macro_rules! with_match_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    use crate::types::{days_ms, months_days_ns, f16};
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        Int128 => __with_ty__! { i128 },
+        DaysMs => __with_ty__! { days_ms },
+        MonthDayNano => __with_ty__! { months_days_ns },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float16 => __with_ty__! { f16 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+    }
+})}
+
+#[inline]
+fn downcast<T: 'static>(array: &dyn Array) -> &T {
+    array.as_any().downcast_ref::<T>().unwrap()
+}
+
+/// Returns whether the `length` consecutive rows of `lhs` starting at `lhs_start`
+/// are equal to the ones of `rhs` starting at `rhs_start`.
+fn range_eq(
+    lhs: &dyn Array,
+    lhs_start: usize,
+    rhs: &dyn Array,
+    rhs_start: usize,
+    length: usize,
+) -> bool {
+    (0..length).all(|k| row_eq(lhs, lhs_start + k, rhs, rhs_start + k))
+}
+
+fn list_row_eq<O: Offset>(lhs: &ListArray<O>, i: usize, rhs: &ListArray<O>, j: usize) -> bool {
+    let lhs_start = lhs.offsets()[i].to_usize();
+    let lhs_length = lhs.offsets()[i + 1].to_usize() - lhs_start;
+    let rhs_start = rhs.offsets()[j].to_usize();
+    let rhs_length = rhs.offsets()[j + 1].to_usize() - rhs_start;
+    lhs_length == rhs_length
+        && range_eq(
+            lhs.values().as_ref(),
+            lhs_start,
+            rhs.values().as_ref(),
+            rhs_start,
+            lhs_length,
+        )
+}
+
+/// Returns whether row `i` of `lhs` is logically equal to row `j` of `rhs`, where two
+/// null rows are equal and a null row is different from any non-null row. Nested types
+/// are compared recursively, so that nulls at inner levels follow the same rule.
+/// Floats are compared by their total order, so that NaNs are equal to each other.
+fn row_eq(lhs: &dyn Array, i: usize, rhs: &dyn Array, j: usize) -> bool {
+    use PhysicalType::*;
+    let physical_type = lhs.data_type().to_physical_type();
+    if matches!(physical_type, Dictionary(_) | Union) {
+        // dictionaries are compared by their (logical) values, not by their keys, and
+        // a null key is equal to a valid key pointing to a null value.
+        return lhs.slice(i, 1).as_ref() == rhs.slice(j, 1).as_ref();
+    }
+
+    match (lhs.is_valid(i), rhs.is_valid(j)) {
+        (true, true) => {}
+        (false, false) => return true,
+        _ => return false,
+    }
+
+    match physical_type {
+        Null => true,
+        Boolean => downcast::<BooleanArray>(lhs).value(i) == downcast::<BooleanArray>(rhs).value(j),
+        Primitive(PrimitiveType::Float16) => {
+            let lhs = downcast::<PrimitiveArray<f16>>(lhs).value(i).to_f32();
+            let rhs = downcast::<PrimitiveArray<f16>>(rhs).value(j).to_f32();
+            total_cmp_f32_with_nans(&lhs, &rhs, true) == Ordering::Equal
+        }
+        Primitive(PrimitiveType::Float32) => {
+            let lhs = downcast::<PrimitiveArray<f32>>(lhs).value(i);
+            let rhs = downcast::<PrimitiveArray<f32>>(rhs).value(j);
+            total_cmp_f32_with_nans(&lhs, &rhs, true) == Ordering::Equal
+        }
+        Primitive(PrimitiveType::Float64) => {
+            let lhs = downcast::<PrimitiveArray<f64>>(lhs).value(i);
+            let rhs = downcast::<PrimitiveArray<f64>>(rhs).value(j);
+            total_cmp_f64_with_nans(&lhs, &rhs, true) == Ordering::Equal
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            downcast::<PrimitiveArray<$T>>(lhs).value(i) == downcast::<PrimitiveArray<$T>>(rhs).value(j)
+        }),
+        Binary => {
+            downcast::<BinaryArray<i32>>(lhs).value(i) == downcast::<BinaryArray<i32>>(rhs).value(j)
+        }
+        LargeBinary => {
+            downcast::<BinaryArray<i64>>(lhs).value(i) == downcast::<BinaryArray<i64>>(rhs).value(j)
+        }
+        FixedSizeBinary => {
+            downcast::<FixedSizeBinaryArray>(lhs).value(i)
+                == downcast::<FixedSizeBinaryArray>(rhs).value(j)
+        }
+        Utf8 => {
+            downcast::<Utf8Array<i32>>(lhs).value(i) == downcast::<Utf8Array<i32>>(rhs).value(j)
+        }
+        LargeUtf8 => {
+            downcast::<Utf8Array<i64>>(lhs).value(i) == downcast::<Utf8Array<i64>>(rhs).value(j)
+        }
+        List => list_row_eq::<i32>(downcast(lhs), i, downcast(rhs), j),
+        LargeList => list_row_eq::<i64>(downcast(lhs), i, downcast(rhs), j),
+        FixedSizeList => {
+            let lhs = downcast::<FixedSizeListArray>(lhs);
+            let rhs = downcast::<FixedSizeListArray>(rhs);
+            let (_, size) = FixedSizeListArray::get_child_and_size(lhs.data_type());
+            range_eq(
+                lhs.values().as_ref(),
+                i * size,
+                rhs.values().as_ref(),
+                j * size,
+                size,
+            )
+        }
+        Struct => {
+            let lhs = downcast::<StructArray>(lhs);
+            let rhs = downcast::<StructArray>(rhs);
+            lhs.values()
+                .iter()
+                .zip(rhs.values().iter())
+                .all(|(lhs, rhs)| row_eq(lhs.as_ref(), i, rhs.as_ref(), j))
+        }
+        Map => {
+            let lhs = downcast::<MapArray>(lhs);
+            let rhs = downcast::<MapArray>(rhs);
+            let lhs_start = lhs.offsets()[i] as usize;
+            let lhs_length = lhs.offsets()[i + 1] as usize - lhs_start;
+            let rhs_start = rhs.offsets()[j] as usize;
+            let rhs_length = rhs.offsets()[j + 1] as usize - rhs_start;
+            lhs_length == rhs_length
+                && range_eq(
+                    lhs.field().as_ref(),
+                    lhs_start,
+                    rhs.field().as_ref(),
+                    rhs_start,
+                    lhs_length,
+                )
+        }
+        Dictionary(_) | Union => unreachable!(),
+    }
+}
+
+/// Returns, for every row, whether `lhs` and `rhs` are logically equal. Contrarily to [`super::eq`],
+/// this operation supports every type (including nested types such as `List` and `Struct`) and
+/// treats nulls as values: two null rows are equal and a null row differs from a non-null row.
+/// Nulls at inner levels (e.g. the items of a list or the fields of a struct) follow the same rule.
+/// The result has no nulls. Floats are compared by their total order: NaNs are equal to each
+/// other and `-0.0` differs from `+0.0`.
+///
+/// This is the equality used by e.g. `DISTINCT` and `GROUP BY`.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::comparison::eq_rows;
+///
+/// let data = vec![
+///     Some(vec![Some(1), None]),
+///     None,
+///     Some(vec![Some(1), Some(2)]),
+/// ];
+/// let mut lhs = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// lhs.try_extend(data).unwrap();
+/// let lhs: ListArray<i32> = lhs.into();
+///
+/// let data = vec![
+///     Some(vec![Some(1), None]),
+///     None,
+///     Some(vec![Some(1), None]),
+/// ];
+/// let mut rhs = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// rhs.try_extend(data).unwrap();
+/// let rhs: ListArray<i32> = rhs.into();
+///
+/// let result = eq_rows(&lhs, &rhs);
+/// assert_eq!(result, BooleanArray::from_slice([true, true, false]));
+/// ```
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+pub fn eq_rows(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    assert_eq!(lhs.data_type(), rhs.data_type());
+    assert_eq!(lhs.len(), rhs.len());
+
+    let values: Bitmap = (0..lhs.len()).map(|i| row_eq(lhs, i, rhs, i)).collect();
+    BooleanArray::from_data(DataType::Boolean, values, None)
+}
+
+/// Returns, for every row, whether `lhs` and `rhs` are logically distinct, i.e. the negation of [`eq_rows`].
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+pub fn neq_rows(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    assert_eq!(lhs.data_type(), rhs.data_type());
+    assert_eq!(lhs.len(), rhs.len());
+
+    let values: Bitmap = (0..lhs.len()).map(|i| !row_eq(lhs, i, rhs, i)).collect();
+    BooleanArray::from_data(DataType::Boolean, values, None)
+}
@@ -5,7 +5,7 @@ use crate::{
     datatypes::DataType,
 };
 
-use super::super::utils::combine_validities;
+use crate::bitmap::utils::combine_validities;
 
 /// Evaluate `op(lhs, rhs)` for [`Utf8Array`]s using a specified
 /// comparison function.
@@ -15,7 +15,7 @@ where
     F: Fn(&str, &str) -> bool,
 {
     assert_eq!(lhs.len(), rhs.len());
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
     let values = lhs
         .values_iter()
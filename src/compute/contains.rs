@@ -1,14 +1,19 @@
-//! Declares the [`contains`] operator
+//! Declares the [`contains`], [`contains_scalar`] and [`in_set`] operators
+use std::collections::HashSet;
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, ListArray, Offset, PrimitiveArray, Utf8Array},
+    array::{
+        Array, AsArray, BinaryArray, BooleanArray, ListArray, Offset, PrimitiveArray, Utf8Array,
+    },
     bitmap::Bitmap,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType},
     error::{ArrowError, Result},
+    scalar::{BinaryScalar, BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar},
+    trusted_len::TrustedLen,
     types::NativeType,
 };
 
-use super::utils::combine_validities;
+use crate::bitmap::utils::combine_validities;
 
 /// Checks if a [`GenericListArray`] contains a value in the [`PrimitiveArray`]
 /// The validity will be equal to the `And` of both arrays.
@@ -28,7 +33,7 @@ where
         ));
     }
 
-    let validity = combine_validities(list.validity(), values.validity());
+    let validity = combine_validities(&[list.validity(), values.validity()]);
 
     let values = list.iter().zip(values.iter()).map(|(list, values)| {
         if list.is_none() | values.is_none() {
@@ -62,7 +67,7 @@ where
         ));
     }
 
-    let validity = combine_validities(list.validity(), values.validity());
+    let validity = combine_validities(&[list.validity(), values.validity()]);
 
     let values = list.iter().zip(values.iter()).map(|(list, values)| {
         if list.is_none() | values.is_none() {
@@ -96,7 +101,7 @@ where
         ));
     }
 
-    let validity = combine_validities(list.validity(), values.validity());
+    let validity = combine_validities(&[list.validity(), values.validity()]);
 
     let values = list.iter().zip(values.iter()).map(|(list, values)| {
         if list.is_none() | values.is_none() {
@@ -116,10 +121,7 @@ where
 macro_rules! primitive {
     ($list:expr, $values:expr, $l_ty:ty, $r_ty:ty) => {{
         let list = $list.as_any().downcast_ref::<ListArray<$l_ty>>().unwrap();
-        let values = $values
-            .as_any()
-            .downcast_ref::<PrimitiveArray<$r_ty>>()
-            .unwrap();
+        let values = $values.as_primitive::<$r_ty>()?;
         contains_primitive(list, values)
     }};
 }
@@ -131,43 +133,43 @@ pub fn contains(list: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
 
     match (list_data_type, values_data_type) {
         (DataType::List(_), DataType::Utf8) => {
-            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            let values = values.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let list = list.as_list()?;
+            let values = values.as_utf8()?;
             contains_utf8(list, values)
         }
         (DataType::List(_), DataType::LargeUtf8) => {
-            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            let values = values.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let list = list.as_list()?;
+            let values = values.as_large_utf8()?;
             contains_utf8(list, values)
         }
         (DataType::LargeList(_), DataType::LargeUtf8) => {
-            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            let values = values.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let list = list.as_large_list()?;
+            let values = values.as_large_utf8()?;
             contains_utf8(list, values)
         }
         (DataType::LargeList(_), DataType::Utf8) => {
-            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            let values = values.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let list = list.as_large_list()?;
+            let values = values.as_utf8()?;
             contains_utf8(list, values)
         }
         (DataType::List(_), DataType::Binary) => {
-            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            let values = values.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            let list = list.as_list()?;
+            let values = values.as_binary()?;
             contains_binary(list, values)
         }
         (DataType::List(_), DataType::LargeBinary) => {
-            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            let values = values.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let list = list.as_list()?;
+            let values = values.as_large_binary()?;
             contains_binary(list, values)
         }
         (DataType::LargeList(_), DataType::LargeBinary) => {
-            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            let values = values.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let list = list.as_large_list()?;
+            let values = values.as_large_binary()?;
             contains_binary(list, values)
         }
         (DataType::LargeList(_), DataType::Binary) => {
-            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            let values = values.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            let list = list.as_large_list()?;
+            let values = values.as_binary()?;
             contains_binary(list, values)
         }
         (DataType::List(_), DataType::Int8) => primitive!(list, values, i32, i8),
@@ -196,3 +198,260 @@ pub fn contains(list: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
         ))),
     }
 }
+
+macro_rules! scalar_values {
+    ($list:expr, $value:expr, $scalar:ty, $array:ty) => {{
+        let value = $value.as_any().downcast_ref::<$scalar>().unwrap();
+        let value = value.value();
+        Bitmap::from_trusted_len_iter($list.iter().map(|list| {
+            list.map(|list| {
+                let list = list.as_any().downcast_ref::<$array>().unwrap();
+                list.iter().any(|x| x == value)
+            })
+            .unwrap_or(false)
+        }))
+    }};
+}
+
+fn contains_scalar_list<O: Offset>(
+    list: &ListArray<O>,
+    value: &dyn Scalar,
+) -> Result<BooleanArray> {
+    if list.values().data_type() != value.data_type() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Contains requires the inner array to be of the same logical type".to_string(),
+        ));
+    }
+    if !value.is_valid() {
+        return Ok(BooleanArray::new_null(DataType::Boolean, list.len()));
+    }
+
+    let validity = list.validity().cloned();
+
+    use PhysicalType::*;
+    let values = match value.data_type().to_physical_type() {
+        Boolean => scalar_values!(list, value, BooleanScalar, BooleanArray),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let value = value.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            let value = value.value().unwrap();
+            Bitmap::from_trusted_len_iter(list.iter().map(|list| {
+                list.map(|list| {
+                    let list = list.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+                    list.iter().any(|x| x == Some(&value))
+                })
+                .unwrap_or(false)
+            }))
+        }),
+        Utf8 => scalar_values!(list, value, Utf8Scalar<i32>, Utf8Array<i32>),
+        LargeUtf8 => scalar_values!(list, value, Utf8Scalar<i64>, Utf8Array<i64>),
+        Binary => scalar_values!(list, value, BinaryScalar<i32>, BinaryArray<i32>),
+        LargeBinary => scalar_values!(list, value, BinaryScalar<i64>, BinaryArray<i64>),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Contains is not supported for scalars of logical type \"{:?}\"",
+                value.data_type()
+            )))
+        }
+    };
+
+    Ok(BooleanArray::from_data(DataType::Boolean, values, validity))
+}
+
+/// Returns whether each list of `list` contains `value`.
+/// The result is null for null lists and, when `value` is null, for every row.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::contains::contains_scalar;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3)])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = contains_scalar(&list, &PrimitiveScalar::<i32>::from(Some(1))).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(false)]));
+/// ```
+/// # Errors
+/// Errors iff `list` is not a list, the type of `value` differs from the type of the values of
+/// `list`, or the type is not supported.
+pub fn contains_scalar(list: &dyn Array, value: &dyn Scalar) -> Result<BooleanArray> {
+    match list.data_type() {
+        DataType::List(_) => {
+            let list = list.as_list()?;
+            contains_scalar_list(list, value)
+        }
+        DataType::LargeList(_) => {
+            let list = list.as_large_list()?;
+            contains_scalar_list(list, value)
+        }
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "Contains requires a list, not \"{:?}\"",
+            other
+        ))),
+    }
+}
+
+/// Options of [`in_set_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InSetOptions {
+    /// Whether values not in a set that contains a null are null (`true`, as in SQL's `IN`)
+    /// or `false` (`false`).
+    pub null_if_set_has_nulls: bool,
+}
+
+fn in_set_impl<T, I, F>(
+    values: I,
+    validity: Option<&Bitmap>,
+    contains: F,
+    null_if_not_found: bool,
+) -> BooleanArray
+where
+    I: TrustedLen<Item = T>,
+    F: Fn(T) -> bool,
+{
+    let values = Bitmap::from_trusted_len_iter(values.map(contains));
+    let validity = if null_if_not_found {
+        combine_validities(&[validity, Some(&values)])
+    } else {
+        validity.cloned()
+    };
+    BooleanArray::from_data(DataType::Boolean, values, validity)
+}
+
+fn in_set_primitive<T: NativeType>(
+    array: &PrimitiveArray<T>,
+    set: &PrimitiveArray<T>,
+    null_if_not_found: bool,
+) -> BooleanArray {
+    // `NativeType` is not `Hash`; values are hashed by their (little-endian) bytes
+    let set = set
+        .iter()
+        .flatten()
+        .map(|x| x.to_le_bytes().as_ref().to_vec())
+        .collect::<HashSet<_>>();
+    in_set_impl(
+        array.values().iter(),
+        array.validity(),
+        |x| set.contains(x.to_le_bytes().as_ref()),
+        null_if_not_found,
+    )
+}
+
+fn in_set_boolean(
+    array: &BooleanArray,
+    set: &BooleanArray,
+    null_if_not_found: bool,
+) -> BooleanArray {
+    let has_true = set.iter().any(|x| x == Some(true));
+    let has_false = set.iter().any(|x| x == Some(false));
+    in_set_impl(
+        array.values().iter(),
+        array.validity(),
+        |x| if x { has_true } else { has_false },
+        null_if_not_found,
+    )
+}
+
+fn in_set_utf8<O: Offset>(
+    array: &Utf8Array<O>,
+    set: &Utf8Array<O>,
+    null_if_not_found: bool,
+) -> BooleanArray {
+    let set = set.iter().flatten().collect::<HashSet<_>>();
+    in_set_impl(
+        array.values_iter(),
+        array.validity(),
+        |x| set.contains(x),
+        null_if_not_found,
+    )
+}
+
+fn in_set_binary<O: Offset>(
+    array: &BinaryArray<O>,
+    set: &BinaryArray<O>,
+    null_if_not_found: bool,
+) -> BooleanArray {
+    let set = set.iter().flatten().collect::<HashSet<_>>();
+    in_set_impl(
+        array.values_iter(),
+        array.validity(),
+        |x| set.contains(x),
+        null_if_not_found,
+    )
+}
+
+/// Returns whether each value of `array` is in `set`, SQL's `array IN (set)`.
+/// The result is null wherever `array` is null; values not in `set` are `false`.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::contains::in_set;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None]);
+/// let set = Utf8Array::<i32>::from_slice(&["a", "c"]);
+///
+/// let result = in_set(&array, &set).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+/// ```
+/// # Errors
+/// Errors iff `array` and `set` have different logical types or the type is not supported.
+pub fn in_set(array: &dyn Array, set: &dyn Array) -> Result<BooleanArray> {
+    in_set_with_options(array, set, InSetOptions::default())
+}
+
+/// Returns whether each value of `array` is in `set`, according to `options`.
+/// The set is hashed once, so this is `O(N + S)` where `N` and `S` are the lengths of
+/// `array` and `set`. Floats are compared by their bits (e.g. `NaN` is in a set containing it).
+/// # Errors
+/// Errors iff `array` and `set` have different logical types or the type is not supported.
+pub fn in_set_with_options(
+    array: &dyn Array,
+    set: &dyn Array,
+    options: InSetOptions,
+) -> Result<BooleanArray> {
+    if array.data_type() != set.data_type() {
+        return Err(ArrowError::InvalidArgumentError(
+            "in_set requires the array and the set to be of the same logical type".to_string(),
+        ));
+    }
+    let null_if_not_found = options.null_if_set_has_nulls && set.null_count() > 0;
+
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let set = set.as_any().downcast_ref().unwrap();
+            Ok(in_set_boolean(array, set, null_if_not_found))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            let set = set.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            Ok(in_set_primitive(array, set, null_if_not_found))
+        }),
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let set = set.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            Ok(in_set_utf8(array, set, null_if_not_found))
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let set = set.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            Ok(in_set_utf8(array, set, null_if_not_found))
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            let set = set.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            Ok(in_set_binary(array, set, null_if_not_found))
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let set = set.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            Ok(in_set_binary(array, set, null_if_not_found))
+        }
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "in_set is not supported for logical type \"{:?}\"",
+            array.data_type()
+        ))),
+    }
+}
@@ -0,0 +1,125 @@
+//! Contains cumulative operators ([`cum_sum`], [`cum_min`], [`cum_max`] and [`cum_prod`])
+//! over [`PrimitiveArray`]s, where each slot of the result is the aggregate of every
+//! slot of the array up to (and including) it.
+//!
+//! Every operator has a `skip_nulls` option:
+//! * when `true`, a null slot results in a null, but does not reset the accumulator, i.e.
+//!   the following slots aggregate every non-null slot before them;
+//! * when `false`, every slot from the first null onwards is null.
+//!
+//! The `checked_*` variants null out every slot from the first overflow onwards.
+use std::ops::{Add, Mul};
+
+use num_traits::{CheckedAdd, CheckedMul};
+
+use crate::array::{MutablePrimitiveArray, PrimitiveArray};
+use crate::types::NativeType;
+
+/// Applies the cumulative operation `op` to `array`, where `op` returns `None` on overflow.
+fn cumulative<T, F>(array: &PrimitiveArray<T>, skip_nulls: bool, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> Option<T>,
+{
+    let mut result =
+        MutablePrimitiveArray::<T>::with_capacity_from(array.len(), array.data_type().clone());
+
+    let mut accumulator: Option<T> = None;
+    let mut stopped = false;
+    for value in array.iter() {
+        if stopped {
+            result.push(None);
+            continue;
+        }
+        match value {
+            Some(value) => {
+                let value = match accumulator {
+                    Some(accumulator) => op(accumulator, *value),
+                    None => Some(*value),
+                };
+                stopped = value.is_none();
+                accumulator = value;
+                result.push(value);
+            }
+            None => {
+                stopped = !skip_nulls;
+                result.push(None);
+            }
+        }
+    }
+    result.into()
+}
+
+/// Returns the cumulative sum of `array`.
+/// # Implementation
+/// Overflows follow the semantics of `+` on `T` (i.e. they panic in debug mode);
+/// use [`checked_cum_sum`] to null out values from the first overflow onwards.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::cumulative::cum_sum;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(2), Some(3)]);
+/// assert_eq!(cum_sum(&array, true), Int32Array::from(&[Some(1), None, Some(3), Some(6)]));
+/// assert_eq!(cum_sum(&array, false), Int32Array::from(&[Some(1), None, None, None]));
+/// ```
+pub fn cum_sum<T>(array: &PrimitiveArray<T>, skip_nulls: bool) -> PrimitiveArray<T>
+where
+    T: NativeType + Add<Output = T>,
+{
+    cumulative(array, skip_nulls, |acc, value| Some(acc + value))
+}
+
+/// Returns the cumulative sum of `array`, where every slot from the first overflow onwards is null.
+pub fn checked_cum_sum<T>(array: &PrimitiveArray<T>, skip_nulls: bool) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedAdd<Output = T>,
+{
+    cumulative(array, skip_nulls, |acc, value| acc.checked_add(&value))
+}
+
+/// Returns the cumulative product of `array`.
+/// # Implementation
+/// Overflows follow the semantics of `*` on `T` (i.e. they panic in debug mode);
+/// use [`checked_cum_prod`] to null out values from the first overflow onwards.
+pub fn cum_prod<T>(array: &PrimitiveArray<T>, skip_nulls: bool) -> PrimitiveArray<T>
+where
+    T: NativeType + Mul<Output = T>,
+{
+    cumulative(array, skip_nulls, |acc, value| Some(acc * value))
+}
+
+/// Returns the cumulative product of `array`, where every slot from the first overflow
+/// onwards is null.
+pub fn checked_cum_prod<T>(array: &PrimitiveArray<T>, skip_nulls: bool) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedMul<Output = T>,
+{
+    cumulative(array, skip_nulls, |acc, value| acc.checked_mul(&value))
+}
+
+/// Returns the cumulative minimum of `array`.
+/// # Implementation
+/// Values are compared with [`PartialOrd`]: a NaN never replaces the minimum and,
+/// when it is the first value, it is never replaced.
+pub fn cum_min<T>(array: &PrimitiveArray<T>, skip_nulls: bool) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    cumulative(array, skip_nulls, |acc, value| {
+        Some(if value < acc { value } else { acc })
+    })
+}
+
+/// Returns the cumulative maximum of `array`.
+/// # Implementation
+/// Values are compared with [`PartialOrd`]: a NaN never replaces the maximum and,
+/// when it is the first value, it is never replaced.
+pub fn cum_max<T>(array: &PrimitiveArray<T>, skip_nulls: bool) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    cumulative(array, skip_nulls, |acc, value| {
+        Some(if value > acc { value } else { acc })
+    })
+}
@@ -0,0 +1,69 @@
+//! Contains the operator [`dedup_consecutive`], that marks rows that differ from their
+//! preceding row, the building block of `DISTINCT` over sorted input.
+use std::cmp::Ordering;
+
+use crate::array::{Array, BooleanArray};
+use crate::bitmap::Bitmap;
+use crate::columns::Columns;
+use crate::compute::sort::build_compare;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// Returns a [`BooleanArray`] whose slot `i` is whether row `i` of `columns` differs from row
+/// `i - 1` on any of the columns `sort_columns`. The first row is always marked.
+///
+/// When `columns` is sorted by `sort_columns`, filtering it by the result removes duplicate rows
+/// (in respect to `sort_columns`). Nulls are equal to nulls and NaNs are equal to NaNs.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, BooleanArray, Int32Array, Utf8Array};
+/// use arrow2::columns::Columns;
+/// use arrow2::compute::dedup::dedup_consecutive;
+///
+/// let a = Int32Array::from(&[Some(1), Some(1), None, None, Some(2)]);
+/// let b = Utf8Array::<i32>::from_slice(&["a", "b", "c", "c", "c"]);
+/// let columns = Columns::new(vec![Arc::new(a) as Arc<dyn Array>, Arc::new(b)]);
+///
+/// let result = dedup_consecutive(&columns, &[0]).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false, true, false, true]));
+///
+/// let result = dedup_consecutive(&columns, &[0, 1]).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, true, false, true]));
+/// ```
+/// # Errors
+/// Errors iff `sort_columns` is empty, refers to columns out of bounds, or a column's
+/// [`DataType`] can't be compared (see [`build_compare`](crate::array::ord::build_compare)).
+pub fn dedup_consecutive<A: AsRef<dyn Array>>(
+    columns: &Columns<A>,
+    sort_columns: &[usize],
+) -> Result<BooleanArray> {
+    if sort_columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "dedup_consecutive requires at least one column".to_string(),
+        ));
+    }
+    let arrays = columns.arrays();
+    let comparators = sort_columns
+        .iter()
+        .map(|index| {
+            let array = arrays.get(*index).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "dedup_consecutive can't use the column {} of {} columns",
+                    index,
+                    arrays.len()
+                ))
+            })?;
+            build_compare(array.as_ref(), Default::default())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let values = (0..columns.len()).map(|row| {
+        row == 0
+            || comparators
+                .iter()
+                .any(|comparator| comparator(row - 1, row) != Ordering::Equal)
+    });
+    let values = values.collect::<Bitmap>();
+    Ok(BooleanArray::from_data(DataType::Boolean, values, None))
+}
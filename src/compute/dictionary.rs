@@ -0,0 +1,203 @@
+//! Contains kernels to dictionary-encode an [`Array`] into a [`DictionaryArray`] ([`encode`])
+//! and to decode a [`DictionaryArray`] back into its values ([`decode`]).
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use ahash::RandomState;
+
+use crate::array::{
+    growable::make_growable, new_empty_array, Array, BinaryArray, BooleanArray, DictionaryArray,
+    DictionaryKey, FixedSizeBinaryArray, PrimitiveArray, Utf8Array,
+};
+use crate::datatypes::{DataType, IntegerType, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::take::{can_take, take};
+
+/// Returns the key of every item of `iter`, where keys are assigned to distinct values in
+/// order of first appearance, together with the index of the first appearance of every key.
+/// Null items are assigned the (arbitrary) key `K::default()`.
+fn encode_iter<T, K, I>(iter: I, length: usize) -> Result<(Vec<K>, Vec<usize>)>
+where
+    T: Hash + Eq,
+    K: DictionaryKey,
+    I: Iterator<Item = Option<T>>,
+{
+    let mut map = HashMap::<T, K, RandomState>::default();
+    let mut first_indices = vec![];
+    let mut keys = Vec::<K>::with_capacity(length);
+    for (index, value) in iter.enumerate() {
+        let key = match value {
+            Some(value) => match map.entry(value) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    let key = K::from_usize(first_indices.len()).ok_or(ArrowError::Overflow)?;
+                    first_indices.push(index);
+                    *entry.insert(key)
+                }
+            },
+            None => K::default(),
+        };
+        keys.push(key);
+    }
+    Ok((keys, first_indices))
+}
+
+fn encode_typed<K: DictionaryKey>(array: &dyn Array) -> Result<DictionaryArray<K>> {
+    let length = array.len();
+    let (keys, first_indices) = match array.data_type().to_physical_type() {
+        PhysicalType::Null => {
+            let keys = PrimitiveArray::<K>::new_null(K::PRIMITIVE.into(), length);
+            let values = new_empty_array(array.data_type().clone());
+            return Ok(DictionaryArray::<K>::from_data(keys, values.into()));
+        }
+        PhysicalType::Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            encode_iter(array.iter(), length)?
+        }
+        // values are compared by their bytes, so that e.g. floats are hashable
+        PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            encode_iter(array.iter().map(|x| x.map(|x| x.to_le_bytes())), length)?
+        }),
+        PhysicalType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            encode_iter(array.iter(), length)?
+        }
+        PhysicalType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            encode_iter(array.iter(), length)?
+        }
+        PhysicalType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            encode_iter(array.iter(), length)?
+        }
+        PhysicalType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            encode_iter(array.iter(), length)?
+        }
+        PhysicalType::FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            encode_iter(array.iter(), length)?
+        }
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Dictionary encoding of type {:?} is not supported",
+                array.data_type()
+            )))
+        }
+    };
+
+    let mut values = make_growable(&[array], false, first_indices.len());
+    first_indices
+        .iter()
+        .for_each(|index| values.extend(0, *index, 1));
+
+    let keys =
+        PrimitiveArray::<K>::from_data(K::PRIMITIVE.into(), keys.into(), array.validity().cloned());
+    Ok(DictionaryArray::<K>::from_data(keys, values.as_arc()))
+}
+
+/// Dictionary-encodes `array` into a [`DictionaryArray`] whose keys are of type `key_type`.
+///
+/// The values of the dictionary are the distinct non-null values of `array`, in order
+/// of their first appearance, and every null of `array` is a null key. Values are
+/// compared by their physical representation, e.g. two floats are equal iff their
+/// bits are equal (so that `NaN`s are equal to each other but `-0.0` differs from `0.0`).
+///
+/// This kernel computes the keys in a single hash pass over `array`, without casting it.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, DictionaryArray, Utf8Array, UInt8Array};
+/// use arrow2::compute::dictionary::{decode, encode};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("b"), Some("a"), None, Some("b")]);
+/// let encoded = encode(&array, &DataType::UInt8).unwrap();
+///
+/// let dictionary = encoded.as_any().downcast_ref::<DictionaryArray<u8>>().unwrap();
+/// assert_eq!(dictionary.keys(), &UInt8Array::from(&[Some(0), Some(1), None, Some(0)]));
+/// assert_eq!(dictionary.values().as_ref(), &Utf8Array::<i32>::from_slice(&["b", "a"]) as &dyn Array);
+///
+/// assert_eq!(decode(encoded.as_ref()).unwrap().as_ref(), &array as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff:
+/// * `key_type` is not an integer type;
+/// * the type of `array` is not supported (e.g. nested types);
+/// * the number of distinct values of `array` is not representable by `key_type`
+///   ([`ArrowError::Overflow`]), in which case a wider key type should be used.
+pub fn encode(array: &dyn Array, key_type: &DataType) -> Result<Box<dyn Array>> {
+    let key_type = match key_type {
+        DataType::Int8 => IntegerType::Int8,
+        DataType::Int16 => IntegerType::Int16,
+        DataType::Int32 => IntegerType::Int32,
+        DataType::Int64 => IntegerType::Int64,
+        DataType::UInt8 => IntegerType::UInt8,
+        DataType::UInt16 => IntegerType::UInt16,
+        DataType::UInt32 => IntegerType::UInt32,
+        DataType::UInt64 => IntegerType::UInt64,
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The keys of a dictionary must be of an integer type, got {:?}",
+                key_type
+            )))
+        }
+    };
+    match_integer_type!(key_type, |$T| {
+        encode_typed::<$T>(array).map(|x| Box::new(x) as Box<dyn Array>)
+    })
+}
+
+fn decode_typed<K: DictionaryKey>(array: &DictionaryArray<K>) -> Result<Box<dyn Array>> {
+    let keys = array.keys();
+    let values = array.values().as_ref();
+
+    if can_take(values.data_type()) {
+        // the slots of null keys are arbitrary and are ignored by `take`
+        let indices = keys
+            .values()
+            .iter()
+            .map(|key| key.to_u64().unwrap_or(u64::MAX))
+            .collect::<Vec<_>>();
+        let indices = PrimitiveArray::<u64>::from_data(
+            DataType::UInt64,
+            indices.into(),
+            keys.validity().cloned(),
+        );
+        take(values, &indices)
+    } else {
+        let mut growable = make_growable(&[values], true, keys.len());
+        keys.iter().for_each(|key| match key {
+            Some(key) => growable.extend(0, key.to_usize().unwrap(), 1),
+            None => growable.extend_validity(1),
+        });
+        Ok(growable.as_box())
+    }
+}
+
+/// Decodes a [`DictionaryArray`] into an array of its values' [`DataType`] and the same length,
+/// where every slot is the value of the dictionary at the slot's key.
+///
+/// A slot is null iff either its key is null or its key points to a null value.
+/// # Errors
+/// This function errors iff `array` is not a [`DictionaryArray`].
+/// # Panics
+/// This function panics iff a non-null key of `array` is out of bounds of its values.
+pub fn decode(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            decode_typed(array)
+        }),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "Only dictionary arrays can be decoded, got {:?}",
+            array.data_type()
+        ))),
+    }
+}
@@ -0,0 +1,110 @@
+//! Contains the [`explode`] kernel, that flattens [`ListArray`]s into their values, used to
+//! implement operations such as SQL's `UNNEST`.
+use crate::{
+    array::{growable::make_growable, Array, ListArray, Offset, PrimitiveArray},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+/// Options of [`explode_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExplodeOptions {
+    /// Whether each null or empty list emits a single null value (`true`) or
+    /// is dropped (`false`).
+    pub keep_empty: bool,
+}
+
+fn explode_list<O: Offset>(
+    array: &ListArray<O>,
+    options: ExplodeOptions,
+) -> (Box<dyn Array>, PrimitiveArray<i64>) {
+    let offsets = array.offsets();
+    let values = array.values().as_ref();
+    let start = offsets[0].to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+
+    let mut indices = Vec::<i64>::with_capacity(end - start);
+
+    if !options.keep_empty && array.null_count() == 0 {
+        // every value of the child within the offsets is emitted, in order
+        offsets.windows(2).enumerate().for_each(|(row, window)| {
+            let length = (window[1] - window[0]).to_usize();
+            indices.resize(indices.len() + length, row as i64);
+        });
+        return (
+            values.slice(start, end - start),
+            PrimitiveArray::from_vec(indices),
+        );
+    }
+
+    let mut growable = make_growable(&[values], true, end - start);
+    offsets.windows(2).enumerate().for_each(|(row, window)| {
+        let length = if array.is_valid(row) {
+            (window[1] - window[0]).to_usize()
+        } else {
+            0
+        };
+        if length > 0 {
+            growable.extend(0, window[0].to_usize(), length);
+            indices.resize(indices.len() + length, row as i64);
+        } else if options.keep_empty {
+            growable.extend_validity(1);
+            indices.push(row as i64);
+        }
+    });
+    (growable.as_box(), PrimitiveArray::from_vec(indices))
+}
+
+/// Flattens a [`ListArray`] into the values of its lists, dropping null and empty lists.
+/// Returns the values and, for each value, the index of the row of `list` it belongs to,
+/// which can be used to [`take`](crate::compute::take::take) the sibling columns of `list`.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, MutableListArray, MutablePrimitiveArray, ListArray, TryExtend};
+/// use arrow2::compute::explode::explode;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![]), Some(vec![Some(3)])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let (values, indices) = explode(&list).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from_slice([1, 2, 3]) as &dyn Array);
+/// assert_eq!(indices.values().as_slice(), &[0, 0, 3]);
+/// ```
+/// # Errors
+/// Errors iff `list` is not a [`ListArray`].
+pub fn explode(list: &dyn Array) -> Result<(Box<dyn Array>, PrimitiveArray<i64>)> {
+    explode_with_options(list, ExplodeOptions::default())
+}
+
+/// Flattens a [`ListArray`] into the values of its lists, according to `options`.
+/// Returns the values and, for each value, the index of the row of `list` it belongs to.
+/// # Errors
+/// Errors iff `list` is not a [`ListArray`].
+pub fn explode_with_options(
+    list: &dyn Array,
+    options: ExplodeOptions,
+) -> Result<(Box<dyn Array>, PrimitiveArray<i64>)> {
+    match list.data_type().to_logical_type() {
+        DataType::List(_) => {
+            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            Ok(explode_list(list, options))
+        }
+        DataType::LargeList(_) => {
+            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            Ok(explode_list(list, options))
+        }
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "explode is only supported for lists, not {:?}",
+            other
+        ))),
+    }
+}
+
+/// Checks if an array of type `data_type` can be exploded.
+pub fn can_explode(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::List(_) | DataType::LargeList(_)
+    )
+}
@@ -1,14 +1,168 @@
 //! Contains operators to filter arrays such as [`filter`].
 use crate::array::growable::{make_growable, Growable};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
+use crate::compute::take::take_ranges;
 use crate::datatypes::DataType;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
+use crate::types::Index;
 use crate::{array::*, types::NativeType};
 
 /// Function that can filter arbitrary arrays
 pub type Filter<'a> = Box<dyn Fn(&dyn Array) -> Box<dyn Array> + 'a + Send + Sync>;
 
+/// Selectivity below which [`filter`] gathers primitive values by the indices of the
+/// selected slots instead of scanning every slot of the mask.
+const INDICES_SELECTIVITY: f64 = 0.125;
+
+/// Average length of the runs of selected slots above which [`filter`] copies each run at once
+/// via [`take_ranges`] instead of selecting slot by slot.
+const RANGES_MIN_RUN_LENGTH: usize = 32;
+
+/// Calls `f` with the index of every set bit of `bitmap`, in increasing order.
+/// Words of 64 bits are scanned at once via `trailing_zeros`, so that runs of unset
+/// bits are skipped quickly.
+#[inline]
+fn for_each_set_bit<F: FnMut(usize)>(bitmap: &Bitmap, mut f: F) {
+    let mut for_each_in_chunk = |base: usize, mut chunk: u64| {
+        while chunk != 0 {
+            f(base + chunk.trailing_zeros() as usize);
+            // unset the lowest set bit
+            chunk &= chunk - 1;
+        }
+    };
+
+    let chunks = bitmap.chunks::<u64>();
+    let remainder_len = chunks.remainder_len();
+    let remainder = chunks.remainder();
+    let mut base = 0;
+    for chunk in chunks {
+        for_each_in_chunk(base, chunk);
+        base += 64;
+    }
+    if remainder_len > 0 {
+        // the bits past the end of the bitmap are unspecified
+        for_each_in_chunk(base, remainder & ((1u64 << remainder_len) - 1));
+    }
+}
+
+/// Returns the number of runs of consecutive set bits of `bitmap`.
+fn count_runs(bitmap: &Bitmap) -> usize {
+    let mut runs = 0;
+    // the last bit of the previous chunk
+    let mut previous = 0u64;
+    let mut count_in_chunk = |chunk: u64| {
+        // a run starts at every set bit whose previous bit is unset
+        runs += (chunk & !(chunk << 1 | previous)).count_ones() as usize;
+        previous = chunk >> 63;
+    };
+
+    let chunks = bitmap.chunks::<u64>();
+    let remainder_len = chunks.remainder_len();
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        count_in_chunk(chunk);
+    }
+    if remainder_len > 0 {
+        // the bits past the end of the bitmap are unspecified
+        count_in_chunk(remainder & ((1u64 << remainder_len) - 1));
+    }
+    runs
+}
+
+// the bitmap of the slots of `mask` that are selected, i.e. that are `true` and not null
+fn selected_slots(mask: &BooleanArray) -> Bitmap {
+    match mask.validity() {
+        Some(validity) => mask.values() & validity,
+        None => mask.values().clone(),
+    }
+}
+
+/// Returns the indices of the slots of `mask` that are `true`, in increasing order. Nulls are
+/// treated as `false`. This is the representation of the selection `mask` that [`take`] expects.
+///
+/// [`take`]: crate::compute::take::take
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, UInt32Array};
+/// use arrow2::compute::filter::mask_to_indices;
+///
+/// let mask = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+/// let indices = mask_to_indices::<u32>(&mask).unwrap();
+/// assert_eq!(indices, UInt32Array::from_slice(&[0, 3]));
+/// ```
+/// # Errors
+/// This function errors iff the length of `mask` does not fit in `I`.
+pub fn mask_to_indices<I: Index>(mask: &BooleanArray) -> Result<PrimitiveArray<I>> {
+    if I::from_usize(mask.len()).is_none() {
+        return Err(ArrowError::Overflow);
+    }
+    let selected = selected_slots(mask);
+
+    let mut indices = Vec::<I>::with_capacity(selected.len() - selected.null_count());
+    // `I` fits every index of `mask`
+    for_each_set_bit(&selected, |index| {
+        indices.push(I::from_usize(index).unwrap())
+    });
+
+    Ok(PrimitiveArray::<I>::from_data(
+        I::PRIMITIVE.into(),
+        indices.into(),
+        None,
+    ))
+}
+
+/// Returns a mask of `length` slots where the slots at `indices` are `true` and all other
+/// slots are `false`, the inverse of [`mask_to_indices`]. Null indices are ignored.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, UInt32Array};
+/// use arrow2::compute::filter::indices_to_mask;
+///
+/// let indices = UInt32Array::from(&[Some(3), None, Some(0)]);
+/// let mask = indices_to_mask(&indices, 5).unwrap();
+/// assert_eq!(mask, BooleanArray::from_slice(&[true, false, false, true, false]));
+/// ```
+/// # Errors
+/// This function errors iff an index is out of bounds of `length`.
+pub fn indices_to_mask<I: Index>(
+    indices: &PrimitiveArray<I>,
+    length: usize,
+) -> Result<BooleanArray> {
+    let mut mask = MutableBitmap::from_len_zeroed(length);
+    for index in indices.iter().flatten() {
+        let index = index.to_usize();
+        if index >= length {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The index {} is out of bounds of a mask of length {}",
+                index, length
+            )));
+        }
+        mask.set(index, true);
+    }
+    Ok(BooleanArray::from_data(
+        DataType::Boolean,
+        mask.into(),
+        None,
+    ))
+}
+
+/// Returns the fraction of the slots of `mask` that are selected by it (i.e. that are `true`
+/// and not null), from `0.0` (nothing is selected) to `1.0` (everything is selected).
+///
+/// This is a hint to decide how to apply a selection: the lower the selectivity, the more
+/// [`take`] over the result of [`mask_to_indices`] (or re-using those indices over multiple
+/// arrays) is preferable to [`filter`], which scans every slot of `mask`.
+///
+/// [`take`]: crate::compute::take::take
+pub fn selectivity_hint(mask: &BooleanArray) -> f64 {
+    if mask.is_empty() {
+        return 0.0;
+    }
+    let selected = selected_slots(mask);
+    (selected.len() - selected.null_count()) as f64 / selected.len() as f64
+}
+
 fn filter_nonnull_primitive<T: NativeType>(
     array: &PrimitiveArray<T>,
     mask: &Bitmap,
@@ -20,23 +174,24 @@ fn filter_nonnull_primitive<T: NativeType>(
     if let Some(validity) = array.validity() {
         let mut new_validity = MutableBitmap::with_capacity(filter_count);
 
-        array
-            .values()
-            .iter()
-            .zip(validity.iter())
-            .zip(mask.iter())
-            .filter(|x| x.1)
-            .map(|x| x.0)
-            .for_each(|(item, is_valid)| unsafe {
-                buffer.push(*item);
-                new_validity.push_unchecked(is_valid);
-            });
+        // copy runs of selected slots at once
+        let values = array.values().as_slice();
+        SlicesIterator::new(mask).for_each(|(start, len)| {
+            buffer.extend_from_slice(&values[start..start + len]);
+            new_validity.extend_from_bitmap(validity, start, len);
+        });
 
         PrimitiveArray::<T>::from_data(
             array.data_type().clone(),
             buffer.into(),
             new_validity.into(),
         )
+    } else if (filter_count as f64) < mask.len() as f64 * INDICES_SELECTIVITY {
+        // sparse selection: gather the selected values instead of scanning every slot
+        let values = array.values().as_slice();
+        for_each_set_bit(mask, |index| buffer.push(values[index]));
+
+        PrimitiveArray::<T>::from_data(array.data_type().clone(), buffer.into(), None)
     } else {
         array
             .values()
@@ -139,6 +294,14 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
         return crate::compute::filter::filter(array, &filter);
     }
 
+    // a low-entropy mask (few long runs of selected slots) is cheaper to apply run by run
+    let values = filter.values();
+    let selected = values.len() - values.null_count();
+    if selected >= count_runs(values) * RANGES_MIN_RUN_LENGTH {
+        let ranges = SlicesIterator::new(values).collect::<Vec<_>>();
+        return take_ranges(array, &ranges);
+    }
+
     use crate::datatypes::PhysicalType::*;
     match array.data_type().to_physical_type() {
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
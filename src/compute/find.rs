@@ -0,0 +1,202 @@
+//! Contains operators to find substrings in [`Utf8Array`]s: the predicates [`starts_with`],
+//! [`ends_with`] and [`contains`], and [`strpos`], the position of a substring.
+//!
+//! Each operator has a variant whose needle is a scalar (e.g. [`contains_scalar`]), applied to
+//! every value, and a variant whose needles are an array, applied row by row. Null values or
+//! needles result in null slots.
+//!
+//! An empty needle is a prefix, suffix and substring of every value, at position 1.
+use memchr::memmem::Finder;
+
+use super::utils::{check_same_len, unary_utf8_boolean};
+use crate::array::{BooleanArray, Offset, PrimitiveArray, Utf8Array};
+use crate::bitmap::utils::combine_validities;
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::buffer::Buffer;
+use crate::datatypes::DataType;
+use crate::error::Result;
+
+fn binary_boolean<O: Offset, F: Fn(&str, &str) -> bool>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    op: F,
+) -> Result<BooleanArray> {
+    check_same_len(lhs, rhs)?;
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
+
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| op(lhs, rhs));
+    let values = Bitmap::from_trusted_len_iter(values);
+    Ok(BooleanArray::from_data(DataType::Boolean, values, validity))
+}
+
+/// Returns whether each value of `lhs` starts with the needle of the same row of `rhs`.
+/// # Errors
+/// Errors iff the arrays have different lengths.
+pub fn starts_with<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    binary_boolean(lhs, rhs, |lhs, rhs| lhs.starts_with(rhs))
+}
+
+/// Returns whether each value of `values` starts with `needle`.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::find::starts_with_scalar;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("arrow"), Some("parquet"), None]);
+/// let result = starts_with_scalar(&array, "ar");
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+/// ```
+pub fn starts_with_scalar<O: Offset>(values: &Utf8Array<O>, needle: &str) -> BooleanArray {
+    unary_utf8_boolean(values, |x| x.starts_with(needle))
+}
+
+/// Returns whether each value of `lhs` ends with the needle of the same row of `rhs`.
+/// # Errors
+/// Errors iff the arrays have different lengths.
+pub fn ends_with<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    binary_boolean(lhs, rhs, |lhs, rhs| lhs.ends_with(rhs))
+}
+
+/// Returns whether each value of `values` ends with `needle`.
+pub fn ends_with_scalar<O: Offset>(values: &Utf8Array<O>, needle: &str) -> BooleanArray {
+    unary_utf8_boolean(values, |x| x.ends_with(needle))
+}
+
+/// Returns whether each value of `lhs` contains the needle of the same row of `rhs`.
+/// # Errors
+/// Errors iff the arrays have different lengths.
+pub fn contains<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    binary_boolean(lhs, rhs, |lhs, rhs| lhs.contains(rhs))
+}
+
+/// Calls `f` with the row and the byte position (within the row) of the first occurrence of
+/// `needle` on each row of `array` that contains it.
+///
+/// Instead of searching each value, `needle` is searched over the values of all rows at once and
+/// each occurrence is attributed to the row whose offsets contain it, which is considerably faster
+/// when values are short. Occurrences across two rows are ignored.
+fn find_first<O: Offset, F: FnMut(usize, usize)>(array: &Utf8Array<O>, needle: &str, mut f: F) {
+    let needle = needle.as_bytes();
+    debug_assert!(!needle.is_empty());
+    let finder = Finder::new(needle);
+    let offsets = array.offsets();
+    let values = array.values();
+
+    let end = offsets[array.len()].to_usize();
+    let mut position = offsets[0].to_usize();
+    let mut row = 0;
+    while let Some(found) = finder.find(&values[position..end]) {
+        let found = position + found;
+        // `found < end`, so a row with `offsets[row] <= found < offsets[row + 1]` exists
+        while offsets[row + 1].to_usize() <= found {
+            row += 1;
+        }
+        let row_end = offsets[row + 1].to_usize();
+        if found + needle.len() <= row_end {
+            f(row, found - offsets[row].to_usize());
+            // only the first occurrence of each row is of interest
+            position = row_end;
+        } else {
+            position = found + 1;
+        }
+    }
+}
+
+/// Returns whether each value of `values` contains `needle`.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::find::contains_scalar;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("arrow"), Some("parquet"), None]);
+/// let result = contains_scalar(&array, "rr");
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+/// ```
+pub fn contains_scalar<O: Offset>(values: &Utf8Array<O>, needle: &str) -> BooleanArray {
+    let result = if needle.is_empty() {
+        let mut result = MutableBitmap::with_capacity(values.len());
+        result.extend_constant(values.len(), true);
+        result
+    } else {
+        let mut result = MutableBitmap::from_len_zeroed(values.len());
+        find_first(values, needle, |row, _| result.set(row, true));
+        result
+    };
+    BooleanArray::from_data(DataType::Boolean, result.into(), values.validity().cloned())
+}
+
+/// Returns the 1-based position, in characters, of `byte` in `value`
+#[inline]
+fn char_position<O: Offset>(value: &str, byte: usize) -> O {
+    // continuation bytes of utf8 are `0b10xxxxxx`
+    let position = value.as_bytes()[..byte]
+        .iter()
+        .filter(|x| (**x as i8) >= -0x40)
+        .count();
+    // the number of characters is at most the number of bytes, which fits in `O`
+    O::from_usize(position + 1).unwrap()
+}
+
+fn positions_data_type<O: Offset>() -> DataType {
+    if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    }
+}
+
+/// Returns the 1-based position, in characters, of the first occurrence of the needle of the
+/// same row of `rhs` in each value of `lhs`, or 0 if it does not occur in it.
+/// The result is an `Int32` array for [`DataType::Utf8`] and an `Int64` array for
+/// [`DataType::LargeUtf8`].
+/// # Errors
+/// Errors iff the arrays have different lengths.
+pub fn strpos<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<PrimitiveArray<O>> {
+    check_same_len(lhs, rhs)?;
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
+
+    let values = lhs.values_iter().zip(rhs.values_iter()).map(|(lhs, rhs)| {
+        lhs.find(rhs)
+            .map(|byte| char_position(lhs, byte))
+            .unwrap_or_else(O::zero)
+    });
+    let values = Buffer::from_trusted_len_iter(values);
+    Ok(PrimitiveArray::<O>::from_data(
+        positions_data_type::<O>(),
+        values,
+        validity,
+    ))
+}
+
+/// Returns the 1-based position, in characters, of the first occurrence of `needle` in each
+/// value of `values`, or 0 if it does not occur in it.
+/// The result is an `Int32` array for [`DataType::Utf8`] and an `Int64` array for
+/// [`DataType::LargeUtf8`].
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::find::strpos_scalar;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("héllo"), Some("world"), None]);
+/// let result = strpos_scalar(&array, "l");
+/// assert_eq!(result, Int32Array::from(&[Some(3), Some(4), None]));
+/// ```
+pub fn strpos_scalar<O: Offset>(values: &Utf8Array<O>, needle: &str) -> PrimitiveArray<O> {
+    let positions = if needle.is_empty() {
+        vec![O::one(); values.len()]
+    } else {
+        let mut positions = vec![O::zero(); values.len()];
+        find_first(values, needle, |row, byte| {
+            positions[row] = char_position(values.value(row), byte)
+        });
+        positions
+    };
+    PrimitiveArray::<O>::from_data(
+        positions_data_type::<O>(),
+        positions.into(),
+        values.validity().cloned(),
+    )
+}
@@ -1,4 +1,5 @@
-//! Contains the [`hash`] and typed (e.g. [`hash_primitive`]) operators.
+//! Contains the [`hash`] and typed (e.g. [`hash_primitive`]) operators, as well as
+//! [`hash_columns`] to hash rows of multiple arrays (e.g. the keys of a hash join).
 //! // multiversion does not copy documentation, causing a false positive
 #![allow(missing_docs)]
 use ahash::{CallHasher, RandomState};
@@ -12,7 +13,10 @@ macro_rules! new_state {
 }
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, Offset, PrimitiveArray,
+        Utf8Array,
+    },
     buffer::Buffer,
     datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{ArrowError, Result},
@@ -147,3 +151,158 @@ pub fn can_hash(data_type: &DataType) -> bool {
             | PhysicalType::LargeUtf8
     )
 }
+
+/// The hash of a null slot in [`hash_with_hashes`] and [`hash_columns`].
+const NULL_HASH: u64 = 0x5bd1_e995_5bd1_e995;
+
+/// Combines the hash `rhs` into the (accumulated) hash `lhs`, as done by [`hash_with_hashes`]
+/// and [`hash_columns`]. It is computed as
+/// `lhs ^ (rhs + 0x9e3779b97f4a7c15 + (lhs << 6) + (lhs >> 2))` (with wrapping arithmetic),
+/// which is not commutative: combining the same hashes in a different order results in a
+/// different hash.
+#[inline]
+pub fn combine_hashes(lhs: u64, rhs: u64) -> u64 {
+    lhs ^ rhs
+        .wrapping_add(0x9e37_79b9_7f4a_7c15)
+        .wrapping_add(lhs << 6)
+        .wrapping_add(lhs >> 2)
+}
+
+#[inline]
+fn combine_iter<I: Iterator<Item = Option<u64>>>(iter: I, hashes: &mut [u64]) {
+    hashes
+        .iter_mut()
+        .zip(iter)
+        .for_each(|(hash, x)| *hash = combine_hashes(*hash, x.unwrap_or(NULL_HASH)))
+}
+
+fn combine_dictionary<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    hashes: &mut [u64],
+) -> Result<()> {
+    // the values are hashed once, so that every key is hashed by the hash of its value
+    let values = hash(array.values().as_ref())?;
+    let iter = array.keys().iter().map(|key| {
+        key.and_then(|key| {
+            let index = key.to_usize().unwrap();
+            values.is_valid(index).then(|| values.value(index))
+        })
+    });
+    combine_iter(iter, hashes);
+    Ok(())
+}
+
+/// Combines the element-wise hash of `array` into `hashes` via [`combine_hashes`], i.e.
+/// `hashes[i] = combine_hashes(hashes[i], hash(array[i]))`, without allocating.
+///
+/// The hash of a non-null slot is the same as in [`hash`], and a dictionary-encoded slot is
+/// hashed by its value, so that a [`DictionaryArray`] and its decoded array result in the same hashes.
+/// Null slots (including keys pointing to null values) are hashed by a constant.
+/// Supported DataTypes are those of [`can_hash`], `Null`, and dictionaries whose values are
+/// supported by [`can_hash`].
+/// # Errors
+/// This function errors iff the `DataType` is not supported or iff `hashes.len() != array.len()`.
+pub fn hash_with_hashes(array: &dyn Array, hashes: &mut [u64]) -> Result<()> {
+    if hashes.len() != array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The array has length {} but the hashes have length {}",
+            array.len(),
+            hashes.len()
+        )));
+    }
+    let state = new_state!();
+
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => combine_iter(std::iter::repeat(None), hashes),
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let iter = array.iter().map(|x| x.map(|x| u8::get_hash(&x, &state)));
+            combine_iter(iter, hashes)
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            let iter = array.iter().map(|x| x.map(|x| <$T>::get_hash(x, &state)));
+            combine_iter(iter, hashes)
+        }),
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            let iter = array
+                .iter()
+                .map(|x| x.map(|x| <[u8]>::get_hash(&x, &state)));
+            combine_iter(iter, hashes)
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let iter = array
+                .iter()
+                .map(|x| x.map(|x| <[u8]>::get_hash(&x, &state)));
+            combine_iter(iter, hashes)
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let iter = array
+                .iter()
+                .map(|x| x.map(|x| <[u8]>::get_hash(&x.as_bytes(), &state)));
+            combine_iter(iter, hashes)
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let iter = array
+                .iter()
+                .map(|x| x.map(|x| <[u8]>::get_hash(&x.as_bytes(), &state)));
+            combine_iter(iter, hashes)
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            combine_dictionary(array, hashes)?
+        }),
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Hash not implemented for type {:?}",
+                t
+            )))
+        }
+    };
+    Ok(())
+}
+
+/// Returns the hash of every row of `arrays`, where the hash of row `i` is
+/// `combine_hashes(...combine_hashes(combine_hashes(seed, hash(arrays[0][i])), hash(arrays[1][i]))...)`
+/// (see [`hash_with_hashes`] and [`combine_hashes`]).
+///
+/// The result is deterministic (for a given version of this crate) and depends on the order
+/// of `arrays`, so that two sets of arrays with the same `DataType`s in the same order
+/// (e.g. both sides of a hash join) hash equal rows equally. The result has no nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::hash::hash_columns;
+///
+/// let a = Int32Array::from_slice(&[1, 2, 1]);
+/// let b = Utf8Array::<i32>::from_slice(&["a", "b", "a"]);
+/// let hashes = hash_columns(&[&a, &b], 0).unwrap();
+/// assert_eq!(hashes.value(0), hashes.value(2));
+/// assert_ne!(hashes.value(0), hashes.value(1));
+/// ```
+/// # Errors
+/// This function errors iff the arrays have different lengths or the `DataType` of one
+/// of them is not supported by [`hash_with_hashes`].
+pub fn hash_columns(arrays: &[&dyn Array], seed: u64) -> Result<PrimitiveArray<u64>> {
+    let length = arrays.first().map(|array| array.len()).unwrap_or(0);
+    if arrays.iter().any(|array| array.len() != length) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Hashing columns requires all arrays to have the same length".to_string(),
+        ));
+    }
+
+    let mut hashes = vec![seed; length];
+    arrays
+        .iter()
+        .try_for_each(|array| hash_with_hashes(*array, &mut hashes))?;
+    Ok(PrimitiveArray::<u64>::from_data(
+        DataType::UInt64,
+        hashes.into(),
+        None,
+    ))
+}
@@ -19,21 +19,16 @@
 
 use crate::{
     array::*,
+    bitmap::Bitmap,
     buffer::Buffer,
     datatypes::DataType,
     error::{ArrowError, Result},
-    types::NativeType,
 };
 
-fn unary_offsets_string<O, F>(array: &Utf8Array<O>, op: F) -> PrimitiveArray<O>
-where
-    O: Offset + NativeType,
-    F: Fn(O) -> O,
-{
-    let values = array
-        .offsets()
-        .windows(2)
-        .map(|offset| op(offset[1] - offset[0]));
+/// Returns the lengths of the items delimited by `offsets`, as an `Int32` array for `i32`
+/// offsets and an `Int64` array for `i64` offsets.
+fn offsets_lengths<O: Offset>(offsets: &[O], validity: Option<&Bitmap>) -> PrimitiveArray<O> {
+    let values = offsets.windows(2).map(|offset| offset[1] - offset[0]);
 
     let values = Buffer::from_trusted_len_iter(values);
 
@@ -43,19 +38,29 @@ where
         DataType::Int32
     };
 
-    PrimitiveArray::<O>::from_data(data_type, values, array.validity().cloned())
+    PrimitiveArray::<O>::from_data(data_type, values, validity.cloned())
 }
 
-/// Returns an array of integers with the number of bytes on each string of the array.
+/// Returns an array of integers with the number of bytes on each string or binary of the array.
+/// The result is an `Int32` array for [`DataType::Utf8`] and [`DataType::Binary`] and an
+/// `Int64` array for [`DataType::LargeUtf8`] and [`DataType::LargeBinary`].
 pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
-    match array.data_type() {
+    match array.data_type().to_logical_type() {
         DataType::Utf8 => {
             let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-            Ok(Box::new(unary_offsets_string::<i32, _>(array, |x| x)))
+            Ok(Box::new(offsets_lengths(array.offsets(), array.validity())))
         }
         DataType::LargeUtf8 => {
             let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
-            Ok(Box::new(unary_offsets_string::<i64, _>(array, |x| x)))
+            Ok(Box::new(offsets_lengths(array.offsets(), array.validity())))
+        }
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            Ok(Box::new(offsets_lengths(array.offsets(), array.validity())))
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            Ok(Box::new(offsets_lengths(array.offsets(), array.validity())))
         }
         _ => Err(ArrowError::InvalidArgumentError(format!(
             "length not supported for {:?}",
@@ -64,6 +69,39 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     }
 }
 
+/// Returns an array of integers with the number of items on each list of the array, computed
+/// from its offsets (the values of the lists are not read).
+/// The result is an `Int32` array for [`DataType::List`] and an `Int64` array for
+/// [`DataType::LargeList`].
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::length::list_lengths;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = list_lengths(&list).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(2), None, Some(0)]) as &dyn arrow2::array::Array);
+/// ```
+pub fn list_lengths(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            Ok(Box::new(offsets_lengths(array.offsets(), array.validity())))
+        }
+        DataType::LargeList(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            Ok(Box::new(offsets_lengths(array.offsets(), array.validity())))
+        }
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "list_lengths not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
 /// Checks if an array of type `datatype` can perform length operation
 ///
 /// # Examples
@@ -78,5 +116,8 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
 /// assert_eq!(can_length(&data_type), false);
 /// ```
 pub fn can_length(data_type: &DataType) -> bool {
-    matches!(data_type, DataType::Utf8 | DataType::LargeUtf8)
+    matches!(
+        data_type,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary
+    )
 }
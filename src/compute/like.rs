@@ -6,8 +6,8 @@ use regex::Regex;
 
 use crate::{
     array::{BinaryArray, BooleanArray, Offset, Utf8Array},
+    bitmap::utils::combine_validities,
     bitmap::Bitmap,
-    compute::utils::combine_validities,
     datatypes::DataType,
     error::{ArrowError, Result},
 };
@@ -29,7 +29,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
         ));
     }
 
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
     let mut map = HashMap::new();
 
@@ -176,7 +176,7 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
         ));
     }
 
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(&[lhs.validity(), rhs.validity()]);
 
     let mut map = HashMap::new();
 
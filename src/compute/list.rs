@@ -0,0 +1,460 @@
+//! Contains kernels to access the elements of list arrays, such as [`get`] (SQL's `list[index]`)
+//! and [`slice`], and to transform them, such as [`sort_values`] and [`distinct`].
+use std::cmp::Ordering;
+
+use crate::{
+    array::{
+        ord::build_compare_with_nans, Array, FixedSizeListArray, ListArray, Offset, PrimitiveArray,
+    },
+    bitmap::Bitmap,
+    compute::{
+        sort::{build_compare, SortOptions},
+        take::take,
+    },
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+#[inline]
+fn is_valid(validity: Option<&Bitmap>, row: usize) -> bool {
+    validity.map(|x| x.get_bit(row)).unwrap_or(true)
+}
+
+/// Returns the position in the values of the element `index` of the list `start..end`, if any.
+#[inline]
+fn element(start: usize, end: usize, index: i64) -> Option<i64> {
+    let length = (end - start) as i64;
+    let index = if index < 0 { length + index } else { index };
+    if index >= 0 && index < length {
+        Some(start as i64 + index)
+    } else {
+        None
+    }
+}
+
+/// Returns the range in the values of the list `start..end` trimmed to `offset` and `length`.
+#[inline]
+fn trim(start: usize, end: usize, offset: i64, length: Option<usize>) -> (usize, usize) {
+    let list_length = end - start;
+    let offset = if offset < 0 {
+        list_length.saturating_sub(offset.unsigned_abs() as usize)
+    } else {
+        std::cmp::min(offset as usize, list_length)
+    };
+    let end = length
+        .map(|length| std::cmp::min(offset.saturating_add(length), list_length))
+        .unwrap_or(list_length);
+    (start + offset, start + end)
+}
+
+fn get_impl<I: Iterator<Item = (usize, usize)>>(
+    ranges: I,
+    validity: Option<&Bitmap>,
+    values: &dyn Array,
+    index: i64,
+) -> Result<Box<dyn Array>> {
+    let indices = ranges
+        .enumerate()
+        .map(|(row, (start, end))| {
+            if is_valid(validity, row) {
+                element(start, end, index)
+            } else {
+                None
+            }
+        })
+        .collect::<PrimitiveArray<i64>>();
+    take(values, &indices)
+}
+
+fn slice_impl<O: Offset, I: Iterator<Item = (usize, usize)>>(
+    data_type: DataType,
+    ranges: I,
+    validity: Option<&Bitmap>,
+    values: &dyn Array,
+    offset: i64,
+    length: Option<usize>,
+) -> Result<ListArray<O>> {
+    let (lower, _) = ranges.size_hint();
+    let mut offsets = Vec::<O>::with_capacity(lower + 1);
+    offsets.push(O::zero());
+    let mut indices = Vec::<i64>::new();
+    for (row, (start, end)) in ranges.enumerate() {
+        if is_valid(validity, row) {
+            let (start, end) = trim(start, end, offset, length);
+            indices.extend(start as i64..end as i64);
+        }
+        offsets.push(O::from_usize(indices.len()).ok_or(ArrowError::Overflow)?);
+    }
+    let values = take(values, &PrimitiveArray::from_vec(indices))?;
+    Ok(ListArray::<O>::from_data(
+        data_type,
+        offsets.into(),
+        values.into(),
+        validity.cloned(),
+    ))
+}
+
+/// Returns the indices of `values` that sort each range of `ranges`, with the ranges in order.
+fn sort_ranges<I: Iterator<Item = (usize, usize)>>(
+    ranges: I,
+    validity: Option<&Bitmap>,
+    values: &dyn Array,
+    options: SortOptions,
+) -> Result<PrimitiveArray<i64>> {
+    let compare = build_compare_with_nans(values, values, options.nans_greatest)?;
+
+    let (lower, _) = ranges.size_hint();
+    let mut indices = Vec::<i64>::with_capacity(lower);
+    let mut valids = vec![];
+    let mut nulls = vec![];
+    for (row, (start, end)) in ranges.enumerate() {
+        if !is_valid(validity, row) {
+            // the elements of null lists are kept as they are
+            indices.extend(start as i64..end as i64);
+            continue;
+        }
+        valids.clear();
+        nulls.clear();
+        for index in start..end {
+            if values.is_null(index) {
+                nulls.push(index as i64)
+            } else {
+                valids.push(index)
+            }
+        }
+        // a stable sort, so that equal elements remain in their order
+        if options.descending {
+            valids.sort_by(|lhs, rhs| compare(*rhs, *lhs));
+        } else {
+            valids.sort_by(|lhs, rhs| compare(*lhs, *rhs));
+        }
+        if options.nulls_first {
+            indices.extend_from_slice(&nulls);
+        }
+        indices.extend(valids.iter().map(|x| *x as i64));
+        if !options.nulls_first {
+            indices.extend_from_slice(&nulls);
+        }
+    }
+    Ok(PrimitiveArray::from_vec(indices))
+}
+
+fn sort_list<O: Offset>(list: &ListArray<O>, options: SortOptions) -> Result<ListArray<O>> {
+    let indices = sort_ranges(
+        list_ranges(list),
+        list.validity(),
+        list.values().as_ref(),
+        options,
+    )?;
+    let values = take(list.values().as_ref(), &indices)?;
+
+    let start = list.offsets()[0];
+    let offsets = list.offsets().iter().map(|x| *x - start);
+    Ok(ListArray::<O>::from_data(
+        list.data_type().clone(),
+        offsets.collect::<Vec<_>>().into(),
+        values.into(),
+        list.validity().cloned(),
+    ))
+}
+
+/// Returns the offsets of the lists of `ranges` without duplicates, and the indices of their
+/// elements in `values`. Null lists are empty.
+fn distinct_ranges<O: Offset, I: Iterator<Item = (usize, usize)>>(
+    ranges: I,
+    validity: Option<&Bitmap>,
+    values: &dyn Array,
+) -> Result<(Vec<O>, PrimitiveArray<i64>)> {
+    // nulls are equal to nulls
+    let compare = build_compare(values, SortOptions::default())?;
+
+    let (lower, _) = ranges.size_hint();
+    let mut offsets = Vec::<O>::with_capacity(lower + 1);
+    offsets.push(O::zero());
+    let mut indices = Vec::<i64>::new();
+    let mut sorted = vec![];
+    for (row, (start, end)) in ranges.enumerate() {
+        if is_valid(validity, row) {
+            // equal elements are consecutive and in their order after a stable sort, and thus
+            // the first of each run is the first occurrence of its value
+            sorted.clear();
+            sorted.extend(start..end);
+            sorted.sort_by(|lhs, rhs| compare(*lhs, *rhs));
+            let first = indices.len();
+            indices.extend(
+                sorted
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, index)| {
+                        *i == 0 || compare(sorted[i - 1], **index) != Ordering::Equal
+                    })
+                    .map(|(_, index)| *index as i64),
+            );
+            indices[first..].sort_unstable();
+        }
+        offsets.push(O::from_usize(indices.len()).ok_or(ArrowError::Overflow)?);
+    }
+    Ok((offsets, PrimitiveArray::from_vec(indices)))
+}
+
+fn distinct_impl<O: Offset, I: Iterator<Item = (usize, usize)>>(
+    data_type: DataType,
+    ranges: I,
+    validity: Option<&Bitmap>,
+    values: &dyn Array,
+) -> Result<ListArray<O>> {
+    let (offsets, indices) = distinct_ranges::<O, _>(ranges, validity, values)?;
+    let values = take(values, &indices)?;
+    Ok(ListArray::<O>::from_data(
+        data_type,
+        offsets.into(),
+        values.into(),
+        validity.cloned(),
+    ))
+}
+
+fn list_ranges<O: Offset>(array: &ListArray<O>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    array
+        .offsets()
+        .windows(2)
+        .map(|x| (x[0].to_usize(), x[1].to_usize()))
+}
+
+fn fixed_size_list_ranges(array: &FixedSizeListArray) -> impl Iterator<Item = (usize, usize)> {
+    let size = FixedSizeListArray::get_child_and_size(array.data_type()).1;
+    (0..array.len()).map(move |row| (row * size, (row + 1) * size))
+}
+
+/// Returns the element at position `index` of each list of `list`. A negative `index` counts
+/// from the end of each list (`-1` is its last element).
+/// The result is null for null lists and for lists without an element at `index`.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::get;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3)])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = get(&list, -2).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(&[Some(1), None, None]) as &dyn Array);
+/// ```
+/// # Errors
+/// Errors iff `list` is not a [`ListArray`] or a [`FixedSizeListArray`], or if taking its values
+/// is not supported.
+pub fn get(list: &dyn Array, index: i64) -> Result<Box<dyn Array>> {
+    match list.data_type().to_logical_type() {
+        DataType::List(_) => {
+            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let values = list.values().as_ref();
+            get_impl(list_ranges(list), list.validity(), values, index)
+        }
+        DataType::LargeList(_) => {
+            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let values = list.values().as_ref();
+            get_impl(list_ranges(list), list.validity(), values, index)
+        }
+        DataType::FixedSizeList(_, _) => {
+            let list = list.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = list.values().as_ref();
+            get_impl(fixed_size_list_ranges(list), list.validity(), values, index)
+        }
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "list::get is only supported for lists, not {:?}",
+            other
+        ))),
+    }
+}
+
+/// Returns a new list array whose lists are the lists of `list` starting at `offset`, with at
+/// most `length` elements (or until their end if `None`). A negative `offset` counts from the end
+/// of each list and is clamped to its start.
+/// The result is a [`ListArray<i64>`] for [`DataType::LargeList`] and a [`ListArray<i32>`]
+/// otherwise; null lists remain null.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::slice;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![Some(vec![Some(1), Some(2), Some(3)]), None, Some(vec![Some(4)])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = slice(&list, 1, Some(1)).unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// expected.try_extend(vec![Some(vec![Some(2)]), None, Some(vec![])]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result.as_ref(), &expected as &dyn arrow2::array::Array);
+/// ```
+/// # Errors
+/// Errors iff `list` is not a [`ListArray`] or a [`FixedSizeListArray`], or if taking its values
+/// is not supported.
+pub fn slice(list: &dyn Array, offset: i64, length: Option<usize>) -> Result<Box<dyn Array>> {
+    match list.data_type().to_logical_type() {
+        DataType::List(_) => {
+            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let values = list.values().as_ref();
+            let data_type = list.data_type().clone();
+            slice_impl::<i32, _>(
+                data_type,
+                list_ranges(list),
+                list.validity(),
+                values,
+                offset,
+                length,
+            )
+            .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::LargeList(_) => {
+            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let values = list.values().as_ref();
+            let data_type = list.data_type().clone();
+            slice_impl::<i64, _>(
+                data_type,
+                list_ranges(list),
+                list.validity(),
+                values,
+                offset,
+                length,
+            )
+            .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::FixedSizeList(field, _) => {
+            let data_type = DataType::List(field.clone());
+            let list = list.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = list.values().as_ref();
+            slice_impl::<i32, _>(
+                data_type,
+                fixed_size_list_ranges(list),
+                list.validity(),
+                values,
+                offset,
+                length,
+            )
+            .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "list::slice is only supported for lists, not {:?}",
+            other
+        ))),
+    }
+}
+
+/// Sorts the elements of each list of `list`, returning a list array of the same type, whose
+/// lists have the same lengths and validity as `list` (SQL's `array_sort`).
+///
+/// Elements are ordered according to `options`, with null elements first or last in each list.
+/// Equal elements keep their order.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::sort_values;
+/// use arrow2::compute::sort::SortOptions;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![Some(vec![Some(3), None, Some(1)]), None, Some(vec![])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = sort_values(&list, SortOptions::default()).unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// expected.try_extend(vec![Some(vec![None, Some(1), Some(3)]), None, Some(vec![])]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result.as_ref(), &expected as &dyn arrow2::array::Array);
+/// ```
+/// # Errors
+/// Errors iff `list` is not a [`ListArray`] or a [`FixedSizeListArray`], or if its elements
+/// can't be compared (see [`build_compare`](crate::array::ord::build_compare)) or taken.
+pub fn sort_values(list: &dyn Array, options: SortOptions) -> Result<Box<dyn Array>> {
+    match list.data_type().to_logical_type() {
+        DataType::List(_) => {
+            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            sort_list(list, options).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::LargeList(_) => {
+            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            sort_list(list, options).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::FixedSizeList(_, _) => {
+            let list = list.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = list.values().as_ref();
+            let indices = sort_ranges(
+                fixed_size_list_ranges(list),
+                list.validity(),
+                values,
+                options,
+            )?;
+            let values = take(values, &indices)?;
+            Ok(Box::new(FixedSizeListArray::from_data(
+                list.data_type().clone(),
+                values.into(),
+                list.validity().cloned(),
+            )))
+        }
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "list::sort_values is only supported for lists, not {:?}",
+            other
+        ))),
+    }
+}
+
+/// Returns a new list array whose lists are the lists of `list` without duplicate elements,
+/// keeping the first occurrence of each element (SQL's `array_distinct`). Nulls are equal to
+/// nulls, and thus at most one null remains in each list.
+/// The result is a [`ListArray<i64>`] for [`DataType::LargeList`] and a [`ListArray<i32>`]
+/// otherwise; null lists remain null.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::distinct;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![Some(vec![Some(3), None, Some(1), Some(3), None]), None]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = distinct(&list).unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// expected.try_extend(vec![Some(vec![Some(3), None, Some(1)]), None]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result.as_ref(), &expected as &dyn arrow2::array::Array);
+/// ```
+/// # Errors
+/// Errors iff `list` is not a [`ListArray`] or a [`FixedSizeListArray`], or if its elements
+/// can't be compared (see [`build_compare`](crate::array::ord::build_compare)) or taken.
+pub fn distinct(list: &dyn Array) -> Result<Box<dyn Array>> {
+    match list.data_type().to_logical_type() {
+        DataType::List(_) => {
+            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let data_type = list.data_type().clone();
+            let values = list.values().as_ref();
+            distinct_impl::<i32, _>(data_type, list_ranges(list), list.validity(), values)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::LargeList(_) => {
+            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let data_type = list.data_type().clone();
+            let values = list.values().as_ref();
+            distinct_impl::<i64, _>(data_type, list_ranges(list), list.validity(), values)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::FixedSizeList(field, _) => {
+            let data_type = DataType::List(field.clone());
+            let list = list.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = list.values().as_ref();
+            distinct_impl::<i32, _>(
+                data_type,
+                fixed_size_list_ranges(list),
+                list.validity(),
+                values,
+            )
+            .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "list::distinct is only supported for lists, not {:?}",
+            other
+        ))),
+    }
+}
@@ -63,7 +63,7 @@ use itertools::Itertools;
 
 use crate::array::{
     growable::make_growable,
-    ord::{build_compare, DynComparator},
+    ord::{build_compare_with_nans, DynComparator},
     Array,
 };
 pub use crate::compute::sort::SortOptions;
@@ -479,11 +479,15 @@ pub fn build_comparator<'a>(
         .map(|(lhs_index, rhs_index)| {
             let multi_column_comparator = pairs
                 .iter()
-                .map(move |(arrays, _)| {
+                .map(move |(arrays, options)| {
                     Ok((
                         Box::new(move |row| arrays[lhs_index].is_valid(row)) as IsValid<'a>,
                         Box::new(move |row| arrays[rhs_index].is_valid(row)) as IsValid<'a>,
-                        build_compare(arrays[lhs_index], arrays[rhs_index])?,
+                        build_compare_with_nans(
+                            arrays[lhs_index],
+                            arrays[rhs_index],
+                            options.nans_greatest,
+                        )?,
                     ))
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -19,6 +19,10 @@ pub mod aggregate;
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_arithmetics")))]
 pub mod arithmetics;
 pub mod arity;
+pub mod arity_assign;
+#[cfg(feature = "compute_binary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_binary")))]
+pub mod binary;
 #[cfg(feature = "compute_bitwise")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_bitwise")))]
 pub mod bitwise;
@@ -28,9 +32,15 @@ pub mod boolean;
 #[cfg(feature = "compute_boolean_kleene")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_boolean_kleene")))]
 pub mod boolean_kleene;
+#[cfg(feature = "compute_broadcast")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_broadcast")))]
+pub mod broadcast;
 #[cfg(feature = "compute_cast")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
 pub mod cast;
+#[cfg(feature = "compute_clamp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_clamp")))]
+pub mod clamp;
 #[cfg(feature = "compute_comparison")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_comparison")))]
 pub mod comparison;
@@ -40,9 +50,24 @@ pub mod concatenate;
 #[cfg(feature = "compute_contains")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_contains")))]
 pub mod contains;
+#[cfg(feature = "compute_cumulative")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_cumulative")))]
+pub mod cumulative;
+#[cfg(feature = "compute_dedup")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_dedup")))]
+pub mod dedup;
+#[cfg(feature = "compute_dictionary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_dictionary")))]
+pub mod dictionary;
+#[cfg(feature = "compute_explode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_explode")))]
+pub mod explode;
 #[cfg(feature = "compute_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
+#[cfg(feature = "compute_find")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_find")))]
+pub mod find;
 #[cfg(feature = "compute_hash")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_hash")))]
 pub mod hash;
@@ -58,6 +83,9 @@ pub mod like;
 #[cfg(feature = "compute_limit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_limit")))]
 pub mod limit;
+#[cfg(feature = "compute_list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_list")))]
+pub mod list;
 #[cfg(feature = "compute_lower")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_lower")))]
 pub mod lower;
@@ -73,9 +101,21 @@ pub mod partition;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
+#[cfg(feature = "compute_row")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_row")))]
+pub mod row;
+#[cfg(feature = "compute_sample")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_sample")))]
+pub mod sample;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
+#[cfg(feature = "compute_split")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_split")))]
+pub mod split;
+#[cfg(feature = "compute_struct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_struct")))]
+pub mod struct_;
 #[cfg(feature = "compute_substring")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_substring")))]
 pub mod substring;
@@ -85,9 +125,14 @@ pub mod take;
 #[cfg(feature = "compute_temporal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_temporal")))]
 pub mod temporal;
+#[cfg(feature = "compute_topk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_topk")))]
+pub mod topk;
 #[cfg(feature = "compute_upper")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_upper")))]
 pub mod upper;
+#[cfg(any(feature = "compute_lower", feature = "compute_upper"))]
+mod utf8;
 mod utils;
 #[cfg(feature = "compute_window")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_window")))]
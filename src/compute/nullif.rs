@@ -1,13 +1,13 @@
 //! Contains the operator [`nullif`].
 use crate::array::PrimitiveArray;
 use crate::bitmap::Bitmap;
-use crate::compute::comparison::{primitive_compare_values_op, Simd8, Simd8Lanes};
+use crate::compute::comparison::{neq_rows, primitive_compare_values_op, Simd8, Simd8Lanes};
 use crate::compute::utils::check_same_type;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 use crate::{array::Array, types::NativeType};
 
-use super::utils::combine_validities;
+use crate::bitmap::utils::combine_validities;
 
 /// Returns an array whose validity is null iff `lhs == rhs` or `lhs` is null.
 /// This has the same semantics as postgres.
@@ -41,7 +41,7 @@ pub fn nullif_primitive<T: NativeType + Simd8>(
     let equal = primitive_compare_values_op(lhs.values(), rhs.values(), |lhs, rhs| lhs.neq(rhs));
     let equal: Option<Bitmap> = equal.into();
 
-    let validity = combine_validities(lhs.validity(), equal.as_ref());
+    let validity = combine_validities(&[lhs.validity(), equal.as_ref()]);
 
     Ok(PrimitiveArray::<T>::from_data(
         lhs.data_type().clone(),
@@ -74,9 +74,49 @@ pub fn can_nullif(lhs: &DataType, rhs: &DataType) -> bool {
             | Date64
             | Timestamp(_, _)
             | Duration(_)
+            | List(_)
+            | LargeList(_)
+            | FixedSizeList(_, _)
+            | Struct(_)
     )
 }
 
+/// Returns an array whose validity is null iff `lhs` is logically equal to `rhs` or `lhs` is null.
+/// Rows are compared with [`neq_rows`], so that nested types (e.g. `List` and `Struct`) are
+/// supported and nulls at inner levels are compared as values.
+/// # Example
+/// ```rust
+/// # use arrow2::array::{Array, Int32Array, StructArray};
+/// # use arrow2::datatypes::{DataType, Field};
+/// # use arrow2::compute::nullif::nullif_rows;
+/// # use std::sync::Arc;
+/// let fields = vec![Field::new("a", DataType::Int32, true)];
+/// let lhs = StructArray::from_data(
+///     DataType::Struct(fields.clone()),
+///     vec![Arc::new(Int32Array::from(&[None, Some(1), Some(2)]))],
+///     None,
+/// );
+/// let rhs = StructArray::from_data(
+///     DataType::Struct(fields),
+///     vec![Arc::new(Int32Array::from(&[None, Some(1), Some(1)]))],
+///     None,
+/// );
+/// let result = nullif_rows(&lhs, &rhs);
+///
+/// assert_eq!(result.validity(), Some(&[false, false, true].into()));
+/// ```
+/// # Panics
+/// This function panics iff
+/// * The arguments do not have the same logical type
+/// * The arguments do not have the same length
+pub fn nullif_rows(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
+    let not_equal = neq_rows(lhs, rhs);
+
+    let validity = combine_validities(&[lhs.validity(), Some(not_equal.values())]);
+
+    lhs.with_validity(validity)
+}
+
 /// Returns an array whose validity is null iff `lhs == rhs` or `lhs` is null.
 /// This has the same semantics as postgres.
 /// # Example
@@ -113,7 +153,7 @@ pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
         ));
     }
     use crate::datatypes::DataType::*;
-    match lhs.data_type() {
+    match lhs.data_type().to_logical_type() {
         UInt8 => nullif_primitive::<u8>(
             lhs.as_any().downcast_ref().unwrap(),
             rhs.as_any().downcast_ref().unwrap(),
@@ -164,6 +204,7 @@ pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
             rhs.as_any().downcast_ref().unwrap(),
         )
         .map(|x| Box::new(x) as Box<dyn Array>),
+        List(_) | LargeList(_) | FixedSizeList(_, _) | Struct(_) => Ok(nullif_rows(lhs, rhs)),
         other => Err(ArrowError::NotYetImplemented(format!(
             "Nullif is not implemented for logical datatype {:?}",
             other
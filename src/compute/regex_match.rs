@@ -4,8 +4,9 @@ use std::collections::HashMap;
 
 use regex::Regex;
 
-use super::utils::{combine_validities, unary_utf8_boolean};
+use super::utils::unary_utf8_boolean;
 use crate::array::{BooleanArray, Offset, Utf8Array};
+use crate::bitmap::utils::combine_validities;
 use crate::bitmap::Bitmap;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
@@ -19,7 +20,7 @@ pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Re
     }
 
     let mut map = HashMap::new();
-    let validity = combine_validities(values.validity(), regex.validity());
+    let validity = combine_validities(&[values.validity(), regex.validity()]);
 
     let iterator = values.iter().zip(regex.iter()).map(|(haystack, regex)| {
         if haystack.is_none() | regex.is_none() {
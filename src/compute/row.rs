@@ -0,0 +1,809 @@
+//! Contains [`RowConverter`], that encodes the rows of multiple [`Array`]s into bytes whose
+//! lexicographic (`memcmp`) order is the order of the rows, and decodes them back.
+//!
+//! Comparing rows of multiple columns via these bytes is considerably faster than comparing
+//! each column via dynamic comparators, which makes them useful for sort-merge operations and
+//! grouping by multiple columns.
+//!
+//! Each row is the concatenation of the encoding of its value in every column:
+//! * a null byte: `1` for valid values, and `0` (when nulls are first) or `2` (when nulls are
+//!   last) for nulls, which are not followed by any other byte;
+//! * integers (and decimals, dates, times, timestamps and durations) in big endian, with the
+//!   sign bit flipped for signed integers;
+//! * floats as their bits in big endian, with the sign bit flipped for positive values and
+//!   every bit flipped for negative values, and every NaN encoded as the same NaN;
+//! * booleans as `0` or `1`;
+//! * binary and strings as their bytes with `0` escaped as `[0, 255]`, terminated by `[0, 1]`;
+//! * dictionary-encoded values as their values.
+//!
+//! The bytes of descending columns (except for the null byte) are inverted.
+use std::convert::TryInto;
+
+use num_traits::ToPrimitive;
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, DictionaryArray, MutableBinaryArray, MutableUtf8Array,
+    Offset, PrimitiveArray, Utf8Array,
+};
+use crate::bitmap::MutableBitmap;
+use crate::compute::sort::SortOptions;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// The [`DataType`] of a column of a [`RowConverter`] and how it is ordered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortField {
+    /// The [`DataType`] of the column
+    pub data_type: DataType,
+    /// How the column is ordered
+    pub options: SortOptions,
+}
+
+impl SortField {
+    /// Returns a new [`SortField`].
+    pub fn new(data_type: DataType, options: SortOptions) -> Self {
+        Self { data_type, options }
+    }
+}
+
+/// Rows encoded by a [`RowConverter`], stored as contiguous bytes and offsets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rows {
+    values: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl Rows {
+    /// The number of rows
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Whether there are no rows
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the bytes of the row `index`.
+    /// # Panics
+    /// Iff `index >= self.len()`
+    #[inline]
+    pub fn row(&self, index: usize) -> &[u8] {
+        &self.values[self.offsets[index]..self.offsets[index + 1]]
+    }
+
+    /// Returns an iterator over the bytes of every row.
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.offsets
+            .windows(2)
+            .map(move |x| &self.values[x[0]..x[1]])
+    }
+
+    /// The bytes of all rows
+    pub fn values(&self) -> &[u8] {
+        &self.values
+    }
+
+    /// The offsets of the rows in [`Rows::values`]
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+}
+
+/// Converts columns into [`Rows`] whose bytes order as the rows of the columns, and back.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::row::{RowConverter, SortField};
+/// use arrow2::compute::sort::SortOptions;
+/// use arrow2::datatypes::DataType;
+///
+/// let converter = RowConverter::try_new(vec![
+///     SortField::new(DataType::Int32, SortOptions::default()),
+///     SortField::new(DataType::Utf8, SortOptions { descending: true, ..Default::default() }),
+/// ]).unwrap();
+///
+/// let a = Arc::new(Int32Array::from(&[Some(1), Some(-1), Some(1), None])) as Arc<dyn Array>;
+/// let b = Arc::new(Utf8Array::<i32>::from_slice(&["a", "c", "b", "d"])) as Arc<dyn Array>;
+/// let rows = converter.convert_columns(&[a.clone(), b.clone()]).unwrap();
+///
+/// // nulls first, followed by `-1`, and `1` by descending `b`
+/// assert!(rows.row(3) < rows.row(1));
+/// assert!(rows.row(1) < rows.row(2));
+/// assert!(rows.row(2) < rows.row(0));
+///
+/// let columns = converter.convert_back(&rows).unwrap();
+/// assert_eq!(columns[0].as_ref(), a.as_ref());
+/// assert_eq!(columns[1].as_ref(), b.as_ref());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RowConverter {
+    fields: Vec<SortField>,
+}
+
+impl RowConverter {
+    /// Returns a new [`RowConverter`] of columns of `fields`.
+    /// # Errors
+    /// Errors iff the [`DataType`] of a field is not supported (see [`RowConverter::supports`]).
+    pub fn try_new(fields: Vec<SortField>) -> Result<Self> {
+        if let Some(field) = fields.iter().find(|x| !Self::supports(&x.data_type)) {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Converting {:?} to rows",
+                field.data_type
+            )));
+        }
+        Ok(Self { fields })
+    }
+
+    /// Whether columns of `data_type` can be converted to rows: booleans, integers, floats,
+    /// decimals, temporal types (except intervals), binary, strings, and dictionaries of these.
+    pub fn supports(data_type: &DataType) -> bool {
+        use PrimitiveType::*;
+        match data_type.to_physical_type() {
+            PhysicalType::Boolean
+            | PhysicalType::Binary
+            | PhysicalType::LargeBinary
+            | PhysicalType::Utf8
+            | PhysicalType::LargeUtf8 => true,
+            PhysicalType::Primitive(primitive) => {
+                !matches!(primitive, Float16 | DaysMs | MonthDayNano)
+            }
+            PhysicalType::Dictionary(_) => match data_type.to_logical_type() {
+                DataType::Dictionary(_, values, _) => {
+                    !matches!(values.as_ref(), DataType::Dictionary(..)) && Self::supports(values)
+                }
+                _ => unreachable!(),
+            },
+            _ => false,
+        }
+    }
+
+    /// The fields of this [`RowConverter`]
+    pub fn fields(&self) -> &[SortField] {
+        &self.fields
+    }
+
+    /// Converts `columns` into [`Rows`].
+    /// # Errors
+    /// Errors iff the columns do not match the fields of this converter, or have different
+    /// lengths.
+    pub fn convert_columns<A: AsRef<dyn Array>>(&self, columns: &[A]) -> Result<Rows> {
+        if columns.len() != self.fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The converter has {} fields but {} columns were passed",
+                self.fields.len(),
+                columns.len()
+            )));
+        }
+        let length = columns.first().map(|x| x.as_ref().len()).unwrap_or(0);
+        let columns = columns
+            .iter()
+            .zip(self.fields.iter())
+            .map(|(column, field)| {
+                let column = column.as_ref();
+                if column.data_type() != &field.data_type {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "The column of {:?} does not match the field of {:?}",
+                        column.data_type(),
+                        field.data_type
+                    )));
+                }
+                if column.len() != length {
+                    return Err(ArrowError::InvalidArgumentError(
+                        "All columns must have the same length".to_string(),
+                    ));
+                }
+                Encoder::try_new(column, field.options)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut lengths = vec![0; length];
+        for column in &columns {
+            column.add_lengths(&mut lengths);
+        }
+        let mut offsets = Vec::with_capacity(length + 1);
+        offsets.push(0);
+        let mut total = 0;
+        offsets.extend(lengths.iter().map(|x| {
+            total += x;
+            total
+        }));
+
+        let mut values = vec![0; total];
+        let mut positions = offsets[..length].to_vec();
+        for (column, field) in columns.iter().zip(self.fields.iter()) {
+            column.encode(field.options, &mut values, &mut positions);
+        }
+        Ok(Rows { values, offsets })
+    }
+
+    /// Converts [`Rows`] back into columns. Dictionary-encoded columns are converted back to
+    /// arrays of their values.
+    /// # Errors
+    /// Errors iff `rows` were not converted by a converter with the same fields.
+    pub fn convert_back(&self, rows: &Rows) -> Result<Vec<Box<dyn Array>>> {
+        let mut positions = rows.offsets[..rows.len()].to_vec();
+        let columns = self
+            .fields
+            .iter()
+            .map(|field| {
+                let data_type = match field.data_type.to_logical_type() {
+                    DataType::Dictionary(_, values, _) => values.as_ref().clone(),
+                    _ => field.data_type.clone(),
+                };
+                decode(&rows.values, &mut positions, data_type, field.options)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if positions
+            .iter()
+            .zip(rows.offsets[1..].iter())
+            .any(|(position, end)| position != end)
+        {
+            return Err(ArrowError::InvalidArgumentError(
+                "The rows have more bytes than the fields of the converter".to_string(),
+            ));
+        }
+        Ok(columns)
+    }
+}
+
+/// A column being encoded
+enum Encoder<'a> {
+    Array(&'a dyn Array),
+    /// A dictionary-encoded column, whose values are encoded once and copied to each row
+    Dictionary(Vec<Option<usize>>, Rows),
+}
+
+impl<'a> Encoder<'a> {
+    fn try_new(array: &'a dyn Array, options: SortOptions) -> Result<Self> {
+        match array.data_type().to_physical_type() {
+            PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let values = array.values();
+                let converter = RowConverter {
+                    fields: vec![SortField::new(values.data_type().clone(), options)],
+                };
+                let rows = converter.convert_columns(std::slice::from_ref(values))?;
+                let keys = array
+                    .keys()
+                    .iter()
+                    .map(|key| {
+                        key.map(|key| {
+                            key.to_usize()
+                                .filter(|key| *key < rows.len())
+                                .ok_or_else(|| {
+                                    ArrowError::OutOfSpec(
+                                        "The keys of a dictionary must be valid indices of its values"
+                                            .to_string(),
+                                    )
+                                })
+                        })
+                        .transpose()
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Self::Dictionary(keys, rows))
+            }),
+            _ => Ok(Self::Array(array)),
+        }
+    }
+
+    fn add_lengths(&self, lengths: &mut [usize]) {
+        match self {
+            Self::Array(array) => add_lengths(*array, lengths),
+            Self::Dictionary(keys, rows) => {
+                lengths
+                    .iter_mut()
+                    .zip(keys.iter())
+                    .for_each(|(length, key)| {
+                        *length += key.map(|x| rows.row(x).len()).unwrap_or(1)
+                    });
+            }
+        }
+    }
+
+    fn encode(&self, options: SortOptions, values: &mut [u8], positions: &mut [usize]) {
+        match self {
+            Self::Array(array) => encode(*array, options, values, positions),
+            Self::Dictionary(keys, rows) => {
+                for (position, key) in positions.iter_mut().zip(keys.iter()) {
+                    match key {
+                        Some(key) => {
+                            let row = rows.row(*key);
+                            values[*position..*position + row.len()].copy_from_slice(row);
+                            *position += row.len();
+                        }
+                        None => {
+                            values[*position] = null_byte(options);
+                            *position += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const VALID: u8 = 1;
+
+#[inline]
+fn null_byte(options: SortOptions) -> u8 {
+    if options.nulls_first {
+        0
+    } else {
+        2
+    }
+}
+
+/// A [`NativeType`] whose big-endian encoding of `WIDTH` bytes orders as its values.
+trait FixedEncoding: NativeType {
+    const WIDTH: usize = std::mem::size_of::<Self>();
+
+    fn encode(self, nans_greatest: bool, out: &mut [u8]);
+
+    fn decode(bytes: &[u8]) -> Self;
+}
+
+macro_rules! unsigned {
+    ($type:ty) => {
+        impl FixedEncoding for $type {
+            #[inline]
+            fn encode(self, _: bool, out: &mut [u8]) {
+                out.copy_from_slice(&self.to_be_bytes())
+            }
+
+            #[inline]
+            fn decode(bytes: &[u8]) -> Self {
+                Self::from_be_bytes(bytes.try_into().unwrap())
+            }
+        }
+    };
+}
+
+macro_rules! signed {
+    ($type:ty) => {
+        impl FixedEncoding for $type {
+            #[inline]
+            fn encode(self, _: bool, out: &mut [u8]) {
+                let mut bytes = self.to_be_bytes();
+                bytes[0] ^= 0x80;
+                out.copy_from_slice(&bytes)
+            }
+
+            #[inline]
+            fn decode(bytes: &[u8]) -> Self {
+                let mut bytes: [u8; std::mem::size_of::<Self>()] = bytes.try_into().unwrap();
+                bytes[0] ^= 0x80;
+                Self::from_be_bytes(bytes)
+            }
+        }
+    };
+}
+
+macro_rules! float {
+    ($type:ty, $bits:ty) => {
+        impl FixedEncoding for $type {
+            #[inline]
+            fn encode(self, nans_greatest: bool, out: &mut [u8]) {
+                const SIGN: $bits = 1 << (<$bits>::BITS - 1);
+                let bits = match (self.is_nan(), nans_greatest) {
+                    (true, true) => <$type>::NAN.to_bits(),
+                    (true, false) => (-<$type>::NAN).to_bits(),
+                    (false, _) => self.to_bits(),
+                };
+                let bits = if bits & SIGN == 0 { bits ^ SIGN } else { !bits };
+                out.copy_from_slice(&bits.to_be_bytes())
+            }
+
+            #[inline]
+            fn decode(bytes: &[u8]) -> Self {
+                const SIGN: $bits = 1 << (<$bits>::BITS - 1);
+                let bits = <$bits>::from_be_bytes(bytes.try_into().unwrap());
+                let bits = if bits & SIGN != 0 { bits ^ SIGN } else { !bits };
+                Self::from_bits(bits)
+            }
+        }
+    };
+}
+
+unsigned!(u8);
+unsigned!(u16);
+unsigned!(u32);
+unsigned!(u64);
+signed!(i8);
+signed!(i16);
+signed!(i32);
+signed!(i64);
+signed!(i128);
+float!(f32, u32);
+float!(f64, u64);
+
+/// The number of bytes of the encoding of `value` (without the null byte)
+#[inline]
+fn variable_length(value: &[u8]) -> usize {
+    value.len() + bytecount(value) + 2
+}
+
+#[inline]
+fn bytecount(value: &[u8]) -> usize {
+    value.iter().filter(|x| **x == 0).count()
+}
+
+fn add_fixed_lengths(array: &dyn Array, width: usize, lengths: &mut [usize]) {
+    match array.validity() {
+        Some(validity) => lengths
+            .iter_mut()
+            .zip(validity.iter())
+            .for_each(|(length, is_valid)| *length += 1 + if is_valid { width } else { 0 }),
+        None => lengths.iter_mut().for_each(|length| *length += 1 + width),
+    }
+}
+
+fn add_variable_lengths<'a, I: Iterator<Item = Option<&'a [u8]>>>(
+    values: I,
+    lengths: &mut [usize],
+) {
+    lengths
+        .iter_mut()
+        .zip(values)
+        .for_each(|(length, value)| *length += 1 + value.map(variable_length).unwrap_or(0));
+}
+
+fn add_lengths(array: &dyn Array, lengths: &mut [usize]) {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Boolean => add_fixed_lengths(array, 1, lengths),
+        Primitive(primitive) => {
+            let width = primitive_width(primitive);
+            add_fixed_lengths(array, width, lengths)
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            add_variable_lengths(array.iter(), lengths)
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            add_variable_lengths(array.iter(), lengths)
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            add_variable_lengths(array.iter().map(|x| x.map(|x| x.as_bytes())), lengths)
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            add_variable_lengths(array.iter().map(|x| x.map(|x| x.as_bytes())), lengths)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn primitive_width(primitive: PrimitiveType) -> usize {
+    use PrimitiveType::*;
+    match primitive {
+        Int8 | UInt8 => 1,
+        Int16 | UInt16 => 2,
+        Int32 | UInt32 | Float32 => 4,
+        Int64 | UInt64 | Float64 => 8,
+        Int128 => 16,
+        _ => unreachable!(),
+    }
+}
+
+#[inline]
+fn invert(bytes: &mut [u8]) {
+    bytes.iter_mut().for_each(|x| *x = !*x)
+}
+
+fn encode_fixed<T: FixedEncoding>(
+    array: &PrimitiveArray<T>,
+    options: SortOptions,
+    values: &mut [u8],
+    positions: &mut [usize],
+) {
+    for (position, value) in positions.iter_mut().zip(array.iter()) {
+        match value {
+            Some(value) => {
+                values[*position] = VALID;
+                let out = &mut values[*position + 1..*position + 1 + T::WIDTH];
+                value.encode(options.nans_greatest, out);
+                if options.descending {
+                    invert(out)
+                }
+                *position += 1 + T::WIDTH;
+            }
+            None => {
+                values[*position] = null_byte(options);
+                *position += 1;
+            }
+        }
+    }
+}
+
+fn encode_boolean(
+    array: &BooleanArray,
+    options: SortOptions,
+    values: &mut [u8],
+    positions: &mut [usize],
+) {
+    for (position, value) in positions.iter_mut().zip(array.iter()) {
+        match value {
+            Some(value) => {
+                values[*position] = VALID;
+                values[*position + 1] = if value ^ options.descending { 1 } else { 0 };
+                *position += 2;
+            }
+            None => {
+                values[*position] = null_byte(options);
+                *position += 1;
+            }
+        }
+    }
+}
+
+fn encode_variable<'a, I: Iterator<Item = Option<&'a [u8]>>>(
+    iter: I,
+    options: SortOptions,
+    values: &mut [u8],
+    positions: &mut [usize],
+) {
+    for (position, value) in positions.iter_mut().zip(iter) {
+        match value {
+            Some(value) => {
+                values[*position] = VALID;
+                let start = *position + 1;
+                let mut end = start;
+                for byte in value {
+                    values[end] = *byte;
+                    end += 1;
+                    if *byte == 0 {
+                        values[end] = 0xFF;
+                        end += 1;
+                    }
+                }
+                values[end] = 0;
+                values[end + 1] = 1;
+                end += 2;
+                if options.descending {
+                    invert(&mut values[start..end])
+                }
+                *position = end;
+            }
+            None => {
+                values[*position] = null_byte(options);
+                *position += 1;
+            }
+        }
+    }
+}
+
+fn encode(array: &dyn Array, options: SortOptions, values: &mut [u8], positions: &mut [usize]) {
+    use PhysicalType::*;
+    use PrimitiveType::*;
+
+    macro_rules! fixed {
+        ($type:ty) => {{
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$type>>()
+                .unwrap();
+            encode_fixed(array, options, values, positions)
+        }};
+    }
+
+    match array.data_type().to_physical_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref().unwrap();
+            encode_boolean(array, options, values, positions)
+        }
+        Primitive(Int8) => fixed!(i8),
+        Primitive(Int16) => fixed!(i16),
+        Primitive(Int32) => fixed!(i32),
+        Primitive(Int64) => fixed!(i64),
+        Primitive(Int128) => fixed!(i128),
+        Primitive(UInt8) => fixed!(u8),
+        Primitive(UInt16) => fixed!(u16),
+        Primitive(UInt32) => fixed!(u32),
+        Primitive(UInt64) => fixed!(u64),
+        Primitive(Float32) => fixed!(f32),
+        Primitive(Float64) => fixed!(f64),
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            encode_variable(array.iter(), options, values, positions)
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            encode_variable(array.iter(), options, values, positions)
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let iter = array.iter().map(|x| x.map(|x| x.as_bytes()));
+            encode_variable(iter, options, values, positions)
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let iter = array.iter().map(|x| x.map(|x| x.as_bytes()));
+            encode_variable(iter, options, values, positions)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn out_of_spec() -> ArrowError {
+    ArrowError::InvalidArgumentError(
+        "The rows were not converted by a converter with these fields".to_string(),
+    )
+}
+
+/// Reads the null byte at `position`, returning whether the value is valid.
+#[inline]
+fn read_null_byte(values: &[u8], position: &mut usize, options: SortOptions) -> Result<bool> {
+    let byte = *values.get(*position).ok_or_else(out_of_spec)?;
+    *position += 1;
+    if byte == VALID {
+        Ok(true)
+    } else if byte == null_byte(options) {
+        Ok(false)
+    } else {
+        Err(out_of_spec())
+    }
+}
+
+fn decode_fixed<T: FixedEncoding>(
+    values: &[u8],
+    positions: &mut [usize],
+    data_type: DataType,
+    options: SortOptions,
+) -> Result<Box<dyn Array>> {
+    let mut result = Vec::<T>::with_capacity(positions.len());
+    let mut validity = MutableBitmap::with_capacity(positions.len());
+    let mut bytes = vec![0; T::WIDTH];
+    for position in positions.iter_mut() {
+        let is_valid = read_null_byte(values, position, options)?;
+        validity.push(is_valid);
+        if is_valid {
+            let value = values
+                .get(*position..*position + T::WIDTH)
+                .ok_or_else(out_of_spec)?;
+            bytes.copy_from_slice(value);
+            if options.descending {
+                invert(&mut bytes)
+            }
+            result.push(T::decode(&bytes));
+            *position += T::WIDTH;
+        } else {
+            result.push(T::default());
+        }
+    }
+    Ok(Box::new(PrimitiveArray::<T>::from_data(
+        data_type,
+        result.into(),
+        validity.into(),
+    )))
+}
+
+fn decode_boolean(
+    values: &[u8],
+    positions: &mut [usize],
+    options: SortOptions,
+) -> Result<Box<dyn Array>> {
+    let mut result = Vec::with_capacity(positions.len());
+    for position in positions.iter_mut() {
+        if read_null_byte(values, position, options)? {
+            let byte = *values.get(*position).ok_or_else(out_of_spec)?;
+            *position += 1;
+            result.push(Some((byte == 1) ^ options.descending));
+        } else {
+            result.push(None);
+        }
+    }
+    Ok(Box::new(BooleanArray::from(result)))
+}
+
+/// Decodes the value of a binary or string at `position`, writing its bytes to `buffer`.
+fn decode_variable(
+    values: &[u8],
+    position: &mut usize,
+    options: SortOptions,
+    buffer: &mut Vec<u8>,
+) -> Result<()> {
+    let mask = if options.descending { 0xFF } else { 0 };
+    buffer.clear();
+    loop {
+        let byte = *values.get(*position).ok_or_else(out_of_spec)? ^ mask;
+        *position += 1;
+        if byte != 0 {
+            buffer.push(byte);
+            continue;
+        }
+        let escaped = *values.get(*position).ok_or_else(out_of_spec)? ^ mask;
+        *position += 1;
+        match escaped {
+            0xFF => buffer.push(0),
+            1 => return Ok(()),
+            _ => return Err(out_of_spec()),
+        }
+    }
+}
+
+fn decode_binary<O: Offset>(
+    values: &[u8],
+    positions: &mut [usize],
+    data_type: DataType,
+    options: SortOptions,
+) -> Result<Box<dyn Array>> {
+    let mut result = MutableBinaryArray::<O>::with_capacity(positions.len());
+    let mut buffer = vec![];
+    for position in positions.iter_mut() {
+        if read_null_byte(values, position, options)? {
+            decode_variable(values, position, options, &mut buffer)?;
+            result.push(Some(&buffer));
+        } else {
+            result.push::<&[u8]>(None);
+        }
+    }
+    let result: BinaryArray<O> = result.into();
+    Ok(Box::new(BinaryArray::<O>::from_data(
+        data_type,
+        result.offsets().clone(),
+        result.values().clone(),
+        result.validity().cloned(),
+    )))
+}
+
+fn decode_utf8<O: Offset>(
+    values: &[u8],
+    positions: &mut [usize],
+    data_type: DataType,
+    options: SortOptions,
+) -> Result<Box<dyn Array>> {
+    let mut result = MutableUtf8Array::<O>::with_capacity(positions.len());
+    let mut buffer = vec![];
+    for position in positions.iter_mut() {
+        if read_null_byte(values, position, options)? {
+            decode_variable(values, position, options, &mut buffer)?;
+            let value = std::str::from_utf8(&buffer).map_err(|_| out_of_spec())?;
+            result.push(Some(value));
+        } else {
+            result.push::<&str>(None);
+        }
+    }
+    let result: Utf8Array<O> = result.into();
+    Ok(Box::new(Utf8Array::<O>::from_data(
+        data_type,
+        result.offsets().clone(),
+        result.values().clone(),
+        result.validity().cloned(),
+    )))
+}
+
+fn decode(
+    values: &[u8],
+    positions: &mut [usize],
+    data_type: DataType,
+    options: SortOptions,
+) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    use PrimitiveType::*;
+    match data_type.to_physical_type() {
+        Boolean => decode_boolean(values, positions, options),
+        Primitive(Int8) => decode_fixed::<i8>(values, positions, data_type, options),
+        Primitive(Int16) => decode_fixed::<i16>(values, positions, data_type, options),
+        Primitive(Int32) => decode_fixed::<i32>(values, positions, data_type, options),
+        Primitive(Int64) => decode_fixed::<i64>(values, positions, data_type, options),
+        Primitive(Int128) => decode_fixed::<i128>(values, positions, data_type, options),
+        Primitive(UInt8) => decode_fixed::<u8>(values, positions, data_type, options),
+        Primitive(UInt16) => decode_fixed::<u16>(values, positions, data_type, options),
+        Primitive(UInt32) => decode_fixed::<u32>(values, positions, data_type, options),
+        Primitive(UInt64) => decode_fixed::<u64>(values, positions, data_type, options),
+        Primitive(Float32) => decode_fixed::<f32>(values, positions, data_type, options),
+        Primitive(Float64) => decode_fixed::<f64>(values, positions, data_type, options),
+        Binary => decode_binary::<i32>(values, positions, data_type, options),
+        LargeBinary => decode_binary::<i64>(values, positions, data_type, options),
+        Utf8 => decode_utf8::<i32>(values, positions, data_type, options),
+        LargeUtf8 => decode_utf8::<i64>(values, positions, data_type, options),
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,97 @@
+//! Contains operators to take a random sample of the rows of [`Columns`].
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::array::{Array, PrimitiveArray};
+use crate::columns::Columns;
+use crate::error::{ArrowError, Result};
+
+use super::take::take;
+
+/// Returns `n` random indices in `[0, length)`.
+///
+/// When `with_replacement` is `false`, the indices are distinct and are drawn via a partial
+/// Fisher-Yates shuffle that only stores the `O(n)` positions it swaps, so that a small
+/// sample of a large array does not materialize a permutation of all its rows.
+///
+/// The indices are deterministic for a given `seed` (and version of this crate); when `seed`
+/// is `None`, the generator is seeded from the operating system's entropy.
+/// # Error
+/// This function errors iff `with_replacement` is `false` and `n > length`, or iff
+/// `with_replacement` is `true`, `n > 0` and `length == 0`.
+pub fn sample_indices(
+    length: usize,
+    n: usize,
+    seed: Option<u64>,
+    with_replacement: bool,
+) -> Result<PrimitiveArray<u64>> {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    if with_replacement {
+        if length == 0 && n > 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "Cannot sample with replacement from zero rows".to_string(),
+            ));
+        }
+        let indices = (0..n).map(|_| rng.gen_range(0..length) as u64).collect();
+        return Ok(PrimitiveArray::from_vec(indices));
+    }
+
+    if n > length {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot sample {} rows without replacement from {} rows",
+            n, length
+        )));
+    }
+    // `swapped[i]` is the row currently at position `i` of the (virtual) permutation,
+    // for every position that was swapped; every other position `i` holds row `i`.
+    let mut swapped = HashMap::<usize, usize>::with_capacity(2 * n);
+    let indices = (0..n)
+        .map(|i| {
+            let j = rng.gen_range(i..length);
+            let row_j = swapped.get(&j).copied().unwrap_or(j);
+            let row_i = swapped.get(&i).copied().unwrap_or(i);
+            swapped.insert(j, row_i);
+            row_j as u64
+        })
+        .collect();
+    Ok(PrimitiveArray::from_vec(indices))
+}
+
+/// Returns `n` randomly sampled rows of `columns`, taken from every array.
+/// See [`sample_indices`] for how rows are drawn, the role of `seed` and `with_replacement`.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::columns::Columns;
+/// use arrow2::compute::sample::sample;
+///
+/// let array = Arc::new(Int32Array::from_slice(&[1, 2, 3, 4])) as Arc<dyn Array>;
+/// let columns = Columns::new(vec![array]);
+///
+/// let result = sample(&columns, 2, Some(0), false).unwrap();
+/// assert_eq!(result.len(), 2);
+/// assert_eq!(result, sample(&columns, 2, Some(0), false).unwrap());
+/// ```
+/// # Error
+/// This function errors iff the sample can't be drawn (see [`sample_indices`]) or
+/// iff [`take`] does not support the type of one of the arrays.
+pub fn sample<A: AsRef<dyn Array>>(
+    columns: &Columns<A>,
+    n: usize,
+    seed: Option<u64>,
+    with_replacement: bool,
+) -> Result<Columns<Arc<dyn Array>>> {
+    let indices = sample_indices(columns.len(), n, seed, with_replacement)?;
+    let arrays = columns
+        .iter()
+        .map(|array| take(array.as_ref(), &indices).map(|x| x.into()))
+        .collect::<Result<Vec<_>>>()?;
+    Columns::try_new(arrays)
+}
@@ -1,53 +1,78 @@
 use crate::{
-    array::{BooleanArray, PrimitiveArray},
+    array::{Array, BooleanArray, PrimitiveArray},
     types::Index,
 };
 
 use super::SortOptions;
 
 /// Returns the indices that would sort a [`BooleanArray`].
+/// # Implementation
+/// A counting sort: the number of nulls, `false` and `true` determine where each group starts,
+/// and the indices are placed in a single pass. Within each group, indices are in
+/// ascending order. The only exception are the nulls of a descending sort, whose indices are in
+/// descending order.
+/// Only the first `limit` indices are written.
 pub fn sort_boolean<I: Index>(
     values: &BooleanArray,
-    value_indices: Vec<I>,
-    null_indices: Vec<I>,
     options: &SortOptions,
     limit: Option<usize>,
 ) -> PrimitiveArray<I> {
-    let descending = options.descending;
+    let length = values.len();
+    let limit = limit.unwrap_or(length).min(length);
 
-    // create tuples that are used for sorting
-    let mut valids = value_indices
-        .into_iter()
-        .map(|index| (index, values.value(index.to_usize())))
-        .collect::<Vec<(I, bool)>>();
+    let null_count = values.null_count();
+    let trues = match values.validity() {
+        Some(validity) if null_count > 0 => {
+            let valid_trues = values.values() & validity;
+            length - valid_trues.null_count()
+        }
+        _ => length - values.values().null_count(),
+    };
+    let falses = length - null_count - trues;
 
-    let mut nulls = null_indices;
-
-    if !descending {
-        valids.sort_by(|a, b| a.1.cmp(&b.1));
+    // the start of each group: nulls, `false` and `true`
+    let (lowest, highest) = if options.descending {
+        (trues, falses)
     } else {
-        valids.sort_by(|a, b| b.1.cmp(&a.1));
-        // reverse to keep a stable ordering
-        nulls.reverse();
-    }
-
-    let mut values = Vec::<I>::with_capacity(values.len());
-
-    if options.nulls_first {
-        values.extend_from_slice(nulls.as_slice());
-        values.extend(valids.iter().map(|x| x.0));
+        (falses, trues)
+    };
+    let (null_start, mut lowest_start) = if options.nulls_first {
+        (0, null_count)
+    } else {
+        (lowest + highest, 0)
+    };
+    let mut highest_start = lowest_start + lowest;
+    let (false_start, true_start) = if options.descending {
+        (&mut highest_start, &mut lowest_start)
     } else {
-        // nulls last
-        values.extend(valids.iter().map(|x| x.0));
-        values.extend_from_slice(nulls.as_slice());
-    }
+        (&mut lowest_start, &mut highest_start)
+    };
 
-    // un-efficient; there are much more performant ways of sorting nulls above, anyways.
-    if let Some(limit) = limit {
-        values.truncate(limit);
-        values.shrink_to_fit();
-    }
+    let mut indices = vec![I::default(); limit];
+    let mut nulls = 0;
+    (0..length).for_each(|index| {
+        let position = if !values.is_valid(index) {
+            nulls += 1;
+            // in descending order, nulls are in descending order of their index
+            if options.descending {
+                null_start + null_count - nulls
+            } else {
+                null_start + nulls - 1
+            }
+        } else {
+            let start = if values.value(index) {
+                &mut *true_start
+            } else {
+                &mut *false_start
+            };
+            *start += 1;
+            *start - 1
+        };
+        if position < limit {
+            indices[position] = I::from_usize(index).unwrap();
+        }
+    });
 
     let data_type = I::PRIMITIVE.into();
-    PrimitiveArray::<I>::from_data(data_type, values.into(), None)
+    PrimitiveArray::<I>::from_data(data_type, indices.into(), None)
 }
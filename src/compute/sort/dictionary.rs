@@ -0,0 +1,61 @@
+use crate::array::{DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::bitmap::Bitmap;
+use crate::error::Result;
+use crate::types::Index;
+
+use super::{common, sort_to_indices, SortOptions};
+
+/// Returns the indices that would sort a [`DictionaryArray`].
+/// # Implementation
+/// The dictionary's values are sorted once into a rank table (the position of each value in
+/// the sorted values), so that slots are sorted by comparing integers instead of values.
+/// Slots whose key points to a null value are null.
+pub(super) fn indices_sorted_unstable_by<I: Index, K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    let keys = array.keys();
+    let values = array.values();
+
+    let order = sort_to_indices::<u64>(
+        values.as_ref(),
+        &SortOptions {
+            descending: false,
+            nulls_first: true,
+            nans_greatest: options.nans_greatest,
+        },
+        None,
+    )?;
+    let mut ranks = vec![0usize; values.len()];
+    order
+        .values()
+        .iter()
+        .enumerate()
+        .for_each(|(rank, index)| ranks[*index as usize] = rank);
+
+    let validity = if values.null_count() > 0 {
+        let validity = keys
+            .iter()
+            .map(|key| match key {
+                Some(key) => values.is_valid(key.to_usize().unwrap()),
+                None => false,
+            })
+            .collect::<Bitmap>();
+        Some(validity)
+    } else {
+        keys.validity().cloned()
+    };
+
+    // Note: keys out of bounds of the dictionary panic.
+    let get = |index: usize| ranks[keys.value(index).to_usize().unwrap()];
+    let cmp = |lhs: &usize, rhs: &usize| lhs.cmp(rhs);
+    Ok(common::indices_sorted_unstable_by(
+        validity.as_ref(),
+        get,
+        cmp,
+        array.len(),
+        options,
+        limit,
+    ))
+}
@@ -46,10 +46,7 @@ pub struct SortColumn<'a> {
 ///     },
 ///     SortColumn {
 ///         values: &utf8,
-///         options: Some(SortOptions {
-///             descending: true,
-///             nulls_first: false,
-///         }),
+///         options: Some(SortOptions::new(true, false)),
 ///     },
 /// ], None).unwrap();
 ///
@@ -80,7 +77,7 @@ fn build_is_valid(array: &dyn Array) -> IsValid {
 
 pub(crate) fn build_compare(array: &dyn Array, sort_option: SortOptions) -> Result<DynComparator> {
     let is_valid = build_is_valid(array);
-    let comparator = ord::build_compare(array, array)?;
+    let comparator = ord::build_compare_with_nans(array, array, sort_option.nans_greatest)?;
 
     Ok(match (sort_option.descending, sort_option.nulls_first) {
         (true, true) => Box::new(move |i: usize, j: usize| match (is_valid(i), is_valid(j)) {
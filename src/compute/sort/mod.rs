@@ -13,12 +13,27 @@ use crate::{
 mod binary;
 mod boolean;
 mod common;
+mod dictionary;
 mod lex_sort;
 mod primitive;
+mod scalar;
 mod utf8;
 
 pub(crate) use lex_sort::build_compare;
 pub use lex_sort::{lexsort, lexsort_to_indices, SortColumn};
+pub use scalar::compare_scalars;
+
+// the comparison of floats, with NaNs ordered according to `options.nans_greatest`
+macro_rules! float_cmp {
+    (f32, $options:expr) => {{
+        let nans_greatest = $options.nans_greatest;
+        move |l: &f32, r: &f32| ord::total_cmp_f32_with_nans(l, r, nans_greatest)
+    }};
+    (f64, $options:expr) => {{
+        let nans_greatest = $options.nans_greatest;
+        move |l: &f64, r: &f64| ord::total_cmp_f64_with_nans(l, r, nans_greatest)
+    }};
+}
 
 macro_rules! dyn_sort {
     ($ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
@@ -35,7 +50,8 @@ macro_rules! dyn_sort {
 /// Sort the [`Array`] using [`SortOptions`].
 ///
 /// Performs an unstable sort on values and indices. Nulls are ordered according to the `nulls_first` flag in `options`.
-/// Floats are sorted using IEEE 754 totalOrder
+/// Floats are sorted using IEEE 754 totalOrder, except that all NaNs are equal and ordered
+/// according to `nans_greatest` in `options`. In particular, `-0.0` is sorted before `+0.0`.
 /// # Errors
 /// Errors if the [`DataType`] is not supported.
 pub fn sort(
@@ -43,7 +59,7 @@ pub fn sort(
     options: &SortOptions,
     limit: Option<usize>,
 ) -> Result<Box<dyn Array>> {
-    match values.data_type() {
+    match values.data_type().to_logical_type() {
         DataType::Int8 => dyn_sort!(i8, values, ord::total_cmp, options, limit),
         DataType::Int16 => dyn_sort!(i16, values, ord::total_cmp, options, limit),
         DataType::Int32
@@ -57,12 +73,13 @@ pub fn sort(
         | DataType::Time64(_)
         | DataType::Timestamp(_, None)
         | DataType::Duration(_) => dyn_sort!(i64, values, ord::total_cmp, options, limit),
+        DataType::Decimal(_, _) => dyn_sort!(i128, values, ord::total_cmp, options, limit),
         DataType::UInt8 => dyn_sort!(u8, values, ord::total_cmp, options, limit),
         DataType::UInt16 => dyn_sort!(u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort!(u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort!(u64, values, ord::total_cmp, options, limit),
-        DataType::Float32 => dyn_sort!(f32, values, ord::total_cmp_f32, options, limit),
-        DataType::Float64 => dyn_sort!(f64, values, ord::total_cmp_f64, options, limit),
+        DataType::Float32 => dyn_sort!(f32, values, float_cmp!(f32, options), options, limit),
+        DataType::Float64 => dyn_sort!(f64, values, float_cmp!(f64, options), options, limit),
         _ => {
             let indices = sort_to_indices::<u64>(values, options, limit)?;
             take::take(values, &indices)
@@ -99,17 +116,12 @@ pub fn sort_to_indices<I: Index>(
     options: &SortOptions,
     limit: Option<usize>,
 ) -> Result<PrimitiveArray<I>> {
-    match values.data_type() {
-        DataType::Boolean => {
-            let (v, n) = partition_validity(values);
-            Ok(boolean::sort_boolean(
-                values.as_any().downcast_ref().unwrap(),
-                v,
-                n,
-                options,
-                limit,
-            ))
-        }
+    match values.data_type().to_logical_type() {
+        DataType::Boolean => Ok(boolean::sort_boolean(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        )),
         DataType::Int8 => dyn_sort_indices!(I, i8, values, ord::total_cmp, options, limit),
         DataType::Int16 => dyn_sort_indices!(I, i16, values, ord::total_cmp, options, limit),
         DataType::Int32
@@ -125,12 +137,19 @@ pub fn sort_to_indices<I: Index>(
         | DataType::Duration(_) => {
             dyn_sort_indices!(I, i64, values, ord::total_cmp, options, limit)
         }
+        DataType::Decimal(_, _) => {
+            dyn_sort_indices!(I, i128, values, ord::total_cmp, options, limit)
+        }
         DataType::UInt8 => dyn_sort_indices!(I, u8, values, ord::total_cmp, options, limit),
         DataType::UInt16 => dyn_sort_indices!(I, u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort_indices!(I, u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort_indices!(I, u64, values, ord::total_cmp, options, limit),
-        DataType::Float32 => dyn_sort_indices!(I, f32, values, ord::total_cmp_f32, options, limit),
-        DataType::Float64 => dyn_sort_indices!(I, f64, values, ord::total_cmp_f64, options, limit),
+        DataType::Float32 => {
+            dyn_sort_indices!(I, f32, values, float_cmp!(f32, options), options, limit)
+        }
+        DataType::Float64 => {
+            dyn_sort_indices!(I, f64, values, float_cmp!(f64, options), options, limit)
+        }
         DataType::Utf8 => Ok(utf8::indices_sorted_unstable_by::<I, i32>(
             values.as_any().downcast_ref().unwrap(),
             options,
@@ -202,14 +221,13 @@ pub fn sort_to_indices<I: Index>(
                 ))),
             }
         }
-        DataType::Dictionary(key_type, value_type, _) => match value_type.as_ref() {
-            DataType::Utf8 => Ok(sort_dict::<I, i32>(values, key_type, options, limit)),
-            DataType::LargeUtf8 => Ok(sort_dict::<I, i64>(values, key_type, options, limit)),
-            t => Err(ArrowError::NotYetImplemented(format!(
-                "Sort not supported for dictionary type with keys {:?}",
-                t
-            ))),
-        },
+        DataType::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            dictionary::indices_sorted_unstable_by::<I, $T>(
+                values.as_any().downcast_ref().unwrap(),
+                options,
+                limit,
+            )
+        }),
         t => Err(ArrowError::NotYetImplemented(format!(
             "Sort not supported for data type {:?}",
             t
@@ -217,21 +235,6 @@ pub fn sort_to_indices<I: Index>(
     }
 }
 
-fn sort_dict<I: Index, O: Offset>(
-    values: &dyn Array,
-    key_type: &IntegerType,
-    options: &SortOptions,
-    limit: Option<usize>,
-) -> PrimitiveArray<I> {
-    match_integer_type!(key_type, |$T| {
-        utf8::indices_sorted_unstable_by_dictionary::<I, $T, O>(
-            values.as_any().downcast_ref().unwrap(),
-            options,
-            limit,
-        )
-    })
-}
-
 /// Checks if an array of type `datatype` can be sorted
 ///
 /// # Examples
@@ -259,6 +262,7 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::Time64(_)
         | DataType::Timestamp(_, None)
         | DataType::Duration(_)
+        | DataType::Decimal(_, _)
         | DataType::UInt8
         | DataType::UInt16
         | DataType::UInt32
@@ -282,20 +286,42 @@ pub fn can_sort(data_type: &DataType) -> bool {
                     | DataType::UInt64
             )
         }
-        DataType::Dictionary(_, value_type, _) => {
-            matches!(*value_type.as_ref(), DataType::Utf8 | DataType::LargeUtf8)
-        }
+        DataType::Dictionary(_, value_type, _) => can_sort(value_type.as_ref()),
         _ => false,
     }
 }
 
-/// Options that define how sort kernels should behave
-#[derive(Clone, Copy, Debug)]
+/// Options that define how sort kernels should behave.
+///
+/// Prefer [`SortOptions::new`] or `..Default::default()` over listing every field, so that
+/// adding options does not break existing code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct SortOptions {
     /// Whether to sort in descending order
     pub descending: bool,
     /// Whether to sort nulls first
     pub nulls_first: bool,
+    /// Whether NaNs are greater than any other float (and thus sorted last in ascending order)
+    /// or smaller than any other float. Irrespectively of their sign and payload, all NaNs are
+    /// considered equal to each other.
+    pub nans_greatest: bool,
+}
+
+impl SortOptions {
+    /// Creates new [`SortOptions`] sorting NaNs as greater than any other float.
+    pub fn new(descending: bool, nulls_first: bool) -> Self {
+        Self {
+            descending,
+            nulls_first,
+            nans_greatest: true,
+        }
+    }
+
+    /// Sets whether NaNs are greater than any other float or smaller than any other float.
+    pub fn with_nans_greatest(mut self, nans_greatest: bool) -> Self {
+        self.nans_greatest = nans_greatest;
+        self
+    }
 }
 
 impl Default for SortOptions {
@@ -304,6 +330,7 @@ impl Default for SortOptions {
             descending: false,
             // default to nulls first to match spark's behavior
             nulls_first: true,
+            nans_greatest: true,
         }
     }
 }
@@ -342,9 +369,9 @@ where
         );
 
     if !options.descending {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()))
+        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref(), options.nans_greatest))
     } else {
-        valids.sort_by(|a, b| cmp_array(b.1.as_ref(), a.1.as_ref()))
+        valids.sort_by(|a, b| cmp_array(b.1.as_ref(), a.1.as_ref(), options.nans_greatest))
     }
 
     let values = valids.iter().map(|tuple| tuple.0);
@@ -362,8 +389,8 @@ where
 }
 
 /// Compare two `Array`s based on the ordering defined in [ord](crate::array::ord).
-fn cmp_array(a: &dyn Array, b: &dyn Array) -> Ordering {
-    let cmp_op = ord::build_compare(a, b).unwrap();
+fn cmp_array(a: &dyn Array, b: &dyn Array, nans_greatest: bool) -> Ordering {
+    let cmp_op = ord::build_compare_with_nans(a, b, nans_greatest).unwrap();
     let length = a.len().max(b.len());
 
     for i in 0..length {
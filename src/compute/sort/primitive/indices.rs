@@ -62,6 +62,7 @@ mod tests {
             SortOptions {
                 descending: false,
                 nulls_first: true,
+                nans_greatest: true,
             },
             None,
             &[0, 5, 3, 1, 4, 2],
@@ -76,6 +77,7 @@ mod tests {
             SortOptions {
                 descending: false,
                 nulls_first: false,
+                nans_greatest: true,
             },
             None,
             &[3, 1, 4, 2, 0, 5],
@@ -90,6 +92,7 @@ mod tests {
             SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             },
             None,
             &[0, 5, 2, 1, 4, 3],
@@ -104,6 +107,7 @@ mod tests {
             SortOptions {
                 descending: true,
                 nulls_first: false,
+                nans_greatest: true,
             },
             None,
             &[2, 1, 4, 3, 0, 5],
@@ -119,6 +123,7 @@ mod tests {
             SortOptions {
                 descending: false,
                 nulls_first: true,
+                nans_greatest: true,
             },
             Some(2),
             &[0, 5],
@@ -131,6 +136,7 @@ mod tests {
             SortOptions {
                 descending: false,
                 nulls_first: true,
+                nans_greatest: true,
             },
             Some(4),
             &[0, 5, 3, 1],
@@ -146,6 +152,7 @@ mod tests {
             SortOptions {
                 descending: false,
                 nulls_first: false,
+                nans_greatest: true,
             },
             Some(2),
             &[3, 1],
@@ -158,6 +165,7 @@ mod tests {
             SortOptions {
                 descending: false,
                 nulls_first: false,
+                nans_greatest: true,
             },
             Some(5),
             &[3, 1, 4, 2, 0],
@@ -173,6 +181,7 @@ mod tests {
             SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             },
             Some(2),
             &[0, 5],
@@ -185,6 +194,7 @@ mod tests {
             SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             },
             Some(4),
             &[0, 5, 2, 1],
@@ -200,6 +210,7 @@ mod tests {
             SortOptions {
                 descending: true,
                 nulls_first: false,
+                nans_greatest: true,
             },
             Some(2),
             &[2, 1],
@@ -212,6 +223,7 @@ mod tests {
             SortOptions {
                 descending: true,
                 nulls_first: false,
+                nans_greatest: true,
             },
             Some(5),
             &[2, 1, 4, 3, 0],
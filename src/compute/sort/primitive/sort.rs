@@ -35,7 +35,7 @@ where
 {
     if descending {
         let (before, _, _) = values.select_nth_unstable_by(limit, |x, y| cmp(y, x));
-        before.sort_unstable_by(|x, y| cmp(x, y));
+        before.sort_unstable_by(|x, y| cmp(y, x));
     } else {
         let (before, _, _) = values.select_nth_unstable_by(limit, |x, y| cmp(x, y));
         before.sort_unstable_by(|x, y| cmp(x, y));
@@ -110,12 +110,8 @@ where
         }
 
         // sort all non-null values
-        sort_values(
-            buffer.as_mut_slice(),
-            cmp,
-            options.descending,
-            limit - validity.null_count(),
-        );
+        let values_limit = limit.min(values.len() - validity.null_count());
+        sort_values(buffer.as_mut_slice(), cmp, options.descending, values_limit);
 
         if limit > values.len() - validity.null_count() {
             // extend remaining with nulls
@@ -196,6 +192,7 @@ mod tests {
             SortOptions {
                 descending: false,
                 nulls_first: true,
+                nans_greatest: true,
             },
             &[None, None, Some(2), Some(3), Some(3), Some(5)],
         );
@@ -209,6 +206,7 @@ mod tests {
             SortOptions {
                 descending: false,
                 nulls_first: false,
+                nans_greatest: true,
             },
             &[Some(2), Some(3), Some(3), Some(5), None, None],
         );
@@ -222,6 +220,7 @@ mod tests {
             SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             },
             &[None, None, Some(5), Some(3), Some(3), Some(2)],
         );
@@ -235,6 +234,7 @@ mod tests {
             SortOptions {
                 descending: true,
                 nulls_first: false,
+                nans_greatest: true,
             },
             &[Some(5), Some(3), Some(3), Some(2), None, None],
         );
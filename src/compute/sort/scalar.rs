@@ -0,0 +1,161 @@
+use std::cmp::Ordering;
+
+use crate::array::ord;
+use crate::datatypes::{PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::scalar::*;
+use crate::types::{days_ms, f16, months_days_ns, NativeType};
+
+use super::SortOptions;
+
+// orders two optional values as they would be sorted according to `options`
+#[inline]
+fn compare_options<T, F: Fn(&T, &T) -> Ordering>(
+    lhs: Option<T>,
+    rhs: Option<T>,
+    options: &SortOptions,
+    cmp: F,
+) -> Ordering {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => {
+            let ordering = cmp(&lhs, &rhs);
+            if options.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        }
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) if options.nulls_first => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) if options.nulls_first => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+    }
+}
+
+fn compare_primitive<T: NativeType, F: Fn(&T, &T) -> Ordering>(
+    lhs: &dyn Scalar,
+    rhs: &dyn Scalar,
+    options: &SortOptions,
+    cmp: F,
+) -> Ordering {
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveScalar<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<T>>().unwrap();
+    compare_options(lhs.value(), rhs.value(), options, cmp)
+}
+
+macro_rules! compare_bytes {
+    ($lhs:expr, $rhs:expr, $options:expr, $scalar:ty) => {{
+        let lhs = $lhs.as_any().downcast_ref::<$scalar>().unwrap();
+        let rhs = $rhs.as_any().downcast_ref::<$scalar>().unwrap();
+        compare_options(lhs.value(), rhs.value(), $options, |l, r| l.cmp(r))
+    }};
+}
+
+/// Returns the [`Ordering`] between `lhs` and `rhs`, i.e. whether `lhs` is sorted before
+/// (`Less`), after (`Greater`) or at the same position as (`Equal`) `rhs` when sorting them
+/// with `options`. This is the order that sorting an array with both values produces and can
+/// be used to, for example, keep the `k` smallest [`Scalar`]s of a stream.
+///
+/// Floats are ordered using IEEE 754 totalOrder, except that all NaNs are equal and ordered
+/// according to `options.nans_greatest`; `f16` is compared as `f32`.
+/// Intervals are ordered by their fields (e.g. days and then milliseconds).
+/// # Example
+/// ```
+/// use std::cmp::Ordering;
+/// use arrow2::compute::sort::{compare_scalars, SortOptions};
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let lhs = PrimitiveScalar::<i32>::from(Some(1));
+/// let rhs = PrimitiveScalar::<i32>::from(None);
+/// let options = SortOptions::default(); // nulls first
+/// assert_eq!(compare_scalars(&lhs, &rhs, &options).unwrap(), Ordering::Greater);
+/// ```
+/// # Errors
+/// Errors iff `lhs` and `rhs` have different [`DataType`](crate::datatypes::DataType)s or
+/// the type is not supported (nested types, unions and dictionaries).
+pub fn compare_scalars(
+    lhs: &dyn Scalar,
+    rhs: &dyn Scalar,
+    options: &SortOptions,
+) -> Result<Ordering> {
+    if lhs.data_type() != rhs.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare a scalar of type {:?} with a scalar of type {:?}",
+            lhs.data_type(),
+            rhs.data_type()
+        )));
+    }
+
+    use PhysicalType::*;
+    Ok(match lhs.data_type().to_physical_type() {
+        Null => Ordering::Equal,
+        Boolean => {
+            let lhs = lhs.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            compare_options(lhs.value(), rhs.value(), options, |l, r| l.cmp(r))
+        }
+        Primitive(primitive) => {
+            let nans_greatest = options.nans_greatest;
+            match primitive {
+                PrimitiveType::Int8 => {
+                    compare_primitive::<i8, _>(lhs, rhs, options, ord::total_cmp)
+                }
+                PrimitiveType::Int16 => {
+                    compare_primitive::<i16, _>(lhs, rhs, options, ord::total_cmp)
+                }
+                PrimitiveType::Int32 => {
+                    compare_primitive::<i32, _>(lhs, rhs, options, ord::total_cmp)
+                }
+                PrimitiveType::Int64 => {
+                    compare_primitive::<i64, _>(lhs, rhs, options, ord::total_cmp)
+                }
+                PrimitiveType::Int128 => {
+                    compare_primitive::<i128, _>(lhs, rhs, options, ord::total_cmp)
+                }
+                PrimitiveType::UInt8 => {
+                    compare_primitive::<u8, _>(lhs, rhs, options, ord::total_cmp)
+                }
+                PrimitiveType::UInt16 => {
+                    compare_primitive::<u16, _>(lhs, rhs, options, ord::total_cmp)
+                }
+                PrimitiveType::UInt32 => {
+                    compare_primitive::<u32, _>(lhs, rhs, options, ord::total_cmp)
+                }
+                PrimitiveType::UInt64 => {
+                    compare_primitive::<u64, _>(lhs, rhs, options, ord::total_cmp)
+                }
+                PrimitiveType::Float16 => compare_primitive::<f16, _>(lhs, rhs, options, |l, r| {
+                    ord::total_cmp_f32_with_nans(&l.to_f32(), &r.to_f32(), nans_greatest)
+                }),
+                PrimitiveType::Float32 => compare_primitive::<f32, _>(lhs, rhs, options, |l, r| {
+                    ord::total_cmp_f32_with_nans(l, r, nans_greatest)
+                }),
+                PrimitiveType::Float64 => compare_primitive::<f64, _>(lhs, rhs, options, |l, r| {
+                    ord::total_cmp_f64_with_nans(l, r, nans_greatest)
+                }),
+                PrimitiveType::DaysMs => {
+                    compare_primitive::<days_ms, _>(lhs, rhs, options, |l, r| {
+                        (l.days(), l.milliseconds()).cmp(&(r.days(), r.milliseconds()))
+                    })
+                }
+                PrimitiveType::MonthDayNano => {
+                    compare_primitive::<months_days_ns, _>(lhs, rhs, options, |l, r| {
+                        (l.months(), l.days(), l.ns()).cmp(&(r.months(), r.days(), r.ns()))
+                    })
+                }
+            }
+        }
+        Utf8 => compare_bytes!(lhs, rhs, options, Utf8Scalar<i32>),
+        LargeUtf8 => compare_bytes!(lhs, rhs, options, Utf8Scalar<i64>),
+        Binary => compare_bytes!(lhs, rhs, options, BinaryScalar<i32>),
+        LargeBinary => compare_bytes!(lhs, rhs, options, BinaryScalar<i64>),
+        FixedSizeBinary => compare_bytes!(lhs, rhs, options, FixedSizeBinaryScalar),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Comparing scalars of type {:?} is not supported",
+                lhs.data_type()
+            )))
+        }
+    })
+}
@@ -1,4 +1,3 @@
-use crate::array::{DictionaryArray, DictionaryKey};
 use crate::array::{Offset, PrimitiveArray, Utf8Array};
 use crate::types::Index;
 
@@ -14,26 +13,3 @@ pub(super) fn indices_sorted_unstable_by<I: Index, O: Offset>(
     let cmp = |lhs: &&str, rhs: &&str| lhs.cmp(rhs);
     common::indices_sorted_unstable_by(array.validity(), get, cmp, array.len(), options, limit)
 }
-
-pub(super) fn indices_sorted_unstable_by_dictionary<I: Index, K: DictionaryKey, O: Offset>(
-    array: &DictionaryArray<K>,
-    options: &SortOptions,
-    limit: Option<usize>,
-) -> PrimitiveArray<I> {
-    let keys = array.keys();
-
-    let dict = array
-        .values()
-        .as_any()
-        .downcast_ref::<Utf8Array<O>>()
-        .unwrap();
-
-    let get = |idx| unsafe {
-        let index = keys.value_unchecked(idx as usize);
-        // Note: there is no check that the keys are within bounds of the dictionary.
-        dict.value(index.to_usize().unwrap())
-    };
-
-    let cmp = |lhs: &&str, rhs: &&str| lhs.cmp(rhs);
-    common::indices_sorted_unstable_by(array.validity(), get, cmp, array.len(), options, limit)
-}
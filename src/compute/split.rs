@@ -0,0 +1,154 @@
+//! Contains the operators [`split`] and [`split_regex`], that split each string of a
+//! [`Utf8Array`] into a [`ListArray`] of strings.
+//!
+//! The semantics follow Spark's `split`:
+//! * an empty string results in a list with a single empty string;
+//! * a null string results in a null list;
+//! * consecutive delimiters (and delimiters at the start or end of a string) result in
+//!   empty strings;
+//! * an empty delimiter (or a regex matching the empty string) splits a string into its
+//!   characters;
+//! * when a `limit` is declared, each list has at most `limit` strings, the last of which
+//!   contains the remainder of the string.
+use std::sync::Arc;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+use crate::array::{Array, ListArray, Offset, Utf8Array};
+use crate::error::{ArrowError, Result};
+
+/// Splits every string of `array` by `delimiter`, returning a [`ListArray`] of [`Utf8Array`]
+/// with the same offset type.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::split::split;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a,b,,c"), Some(""), None]);
+/// let result = split(&array, ",", None).unwrap();
+/// assert_eq!(result.value(0).as_ref(), &Utf8Array::<i32>::from_slice(&["a", "b", "", "c"]) as &dyn Array);
+/// assert_eq!(result.value(1).as_ref(), &Utf8Array::<i32>::from_slice(&[""]) as &dyn Array);
+/// assert!(result.is_null(2));
+///
+/// let result = split(&array, ",", Some(2)).unwrap();
+/// assert_eq!(result.value(0).as_ref(), &Utf8Array::<i32>::from_slice(&["a", "b,,c"]) as &dyn Array);
+/// ```
+/// # Errors
+/// Errors iff `limit` is `Some(0)` or iff the offsets of the result overflow `O`.
+pub fn split<O: Offset>(
+    array: &Utf8Array<O>,
+    delimiter: &str,
+    limit: Option<usize>,
+) -> Result<ListArray<O>> {
+    split_matches(array, limit, |value| {
+        value
+            .match_indices(delimiter)
+            .map(|(start, matched)| (start, start + matched.len()))
+    })
+}
+
+/// Splits every string of `array` by the matches of the regular expression `pattern`,
+/// returning a [`ListArray`] of [`Utf8Array`] with the same offset type.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::split::split_regex;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["oneAtwoBthreeC"]);
+/// let result = split_regex(&array, "[ABC]", None).unwrap();
+/// let expected = Utf8Array::<i32>::from_slice(&["one", "two", "three", ""]);
+/// assert_eq!(result.value(0).as_ref(), &expected as &dyn Array);
+/// ```
+/// # Errors
+/// Errors iff `pattern` is not a valid regular expression, iff `limit` is `Some(0)` or iff the
+/// offsets of the result overflow `O`.
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+pub fn split_regex<O: Offset>(
+    array: &Utf8Array<O>,
+    pattern: &str,
+    limit: Option<usize>,
+) -> Result<ListArray<O>> {
+    let regex = Regex::new(pattern)
+        .map_err(|e| ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e)))?;
+    split_matches(array, limit, |value| {
+        regex.find_iter(value).map(|m| (m.start(), m.end()))
+    })
+}
+
+/// Splits every string of `array` by the matches, declared as `(start, end)`, returned by
+/// `matches`. The values and offsets of the strings are built as the matches are found.
+fn split_matches<'a, O, F, I>(
+    array: &'a Utf8Array<O>,
+    limit: Option<usize>,
+    matches: F,
+) -> Result<ListArray<O>>
+where
+    O: Offset,
+    F: Fn(&'a str) -> I,
+    I: Iterator<Item = (usize, usize)>,
+{
+    let limit = match limit {
+        Some(0) => {
+            return Err(ArrowError::InvalidArgumentError(
+                "The limit of split must be larger than 0".to_string(),
+            ))
+        }
+        Some(limit) => limit,
+        None => usize::MAX,
+    };
+
+    let mut values = Vec::<u8>::with_capacity(array.values().len());
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    offsets.push(O::zero());
+    let mut list_offsets = Vec::<O>::with_capacity(array.len() + 1);
+    list_offsets.push(O::zero());
+
+    let mut push = |piece: &str| {
+        values.extend_from_slice(piece.as_bytes());
+        // the values are at most the values of `array`, whose length fits in `O`
+        offsets.push(O::from_usize(values.len()).unwrap());
+    };
+
+    for (index, value) in array.values_iter().enumerate() {
+        if array.is_null(index) {
+            list_offsets.push(*list_offsets.last().unwrap());
+            continue;
+        }
+        let mut last = 0;
+        let mut pieces = 1;
+        for (start, end) in matches(value) {
+            if pieces == limit {
+                break;
+            }
+            // empty matches at the boundaries of the string do not produce empty strings
+            if start == end && (start == 0 || start == value.len()) {
+                continue;
+            }
+            push(&value[last..start]);
+            last = end;
+            pieces += 1;
+        }
+        push(&value[last..]);
+        let length = list_offsets.last().unwrap().to_usize() + pieces;
+        list_offsets.push(O::from_usize(length).ok_or(ArrowError::Overflow)?);
+    }
+
+    // Safety: every string is a slice of a string of `array` delimited by matches of `matches`,
+    // which are char boundaries
+    let values = unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            Utf8Array::<O>::default_data_type(),
+            offsets.into(),
+            values.into(),
+            None,
+        )
+    };
+    Ok(ListArray::<O>::from_data(
+        ListArray::<O>::default_datatype(values.data_type().clone()),
+        list_offsets.into(),
+        Arc::new(values),
+        array.validity().cloned(),
+    ))
+}
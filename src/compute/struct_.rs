@@ -0,0 +1,64 @@
+//! Contains kernels to access the fields of [`StructArray`](crate::array::StructArray)s,
+//! such as [`get_field`] (SQL's `column.a.b`).
+use crate::{
+    array::{Array, AsArray},
+    bitmap::utils::combine_validities,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+/// Returns the values of the (nested) field of `array` at `path`, e.g. `&["a", "b"]` for
+/// `array.a.b`. A slot of the result is null whenever its row is null in `array` or in any of
+/// the intermediate [`StructArray`](crate::array::StructArray)s of `path`.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, StructArray};
+/// use arrow2::compute::struct_::get_field;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// let values = Arc::new(Int32Array::from_slice(&[1, 2, 3])) as Arc<dyn Array>;
+/// let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, false)]);
+/// let array = StructArray::from_data(data_type, vec![values], Some([true, false, true].into()));
+///
+/// let result = get_field(&array, &["a"]).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(&[Some(1), None, Some(3)]) as &dyn Array);
+/// ```
+/// # Errors
+/// Errors iff `path` is empty, an element of `path` other than the last one is not a struct,
+/// a field of `path` does not exist, or the null slots cannot be set on the field's values
+/// (i.e. it is a [`UnionArray`](crate::array::UnionArray)).
+pub fn get_field(array: &dyn Array, path: &[&str]) -> Result<Box<dyn Array>> {
+    if path.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "get_field requires a non-empty path".to_string(),
+        ));
+    }
+
+    let mut current = array;
+    let mut validity = None;
+    for name in path {
+        let array = current.as_struct()?;
+        validity = combine_validities(&[validity.as_ref(), array.validity()]);
+        current = array
+            .field_by_name(name)
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "the struct has no field named \"{}\"",
+                    name
+                ))
+            })?
+            .as_ref();
+    }
+
+    match (validity, current.data_type().to_logical_type()) {
+        (None, _) | (_, DataType::Null) => Ok(current.slice(0, current.len())),
+        (Some(_), DataType::Union(_, _, _)) => Err(ArrowError::InvalidArgumentError(
+            "get_field cannot set the validity of a union".to_string(),
+        )),
+        (Some(validity), _) => {
+            let validity = combine_validities(&[Some(&validity), current.validity()]);
+            Ok(current.with_validity(validity))
+        }
+    }
+}
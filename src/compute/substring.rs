@@ -120,7 +120,7 @@ fn binary_substring<O: Offset>(
 /// `start` can be negative, in which case the start counts from the end of the string.
 /// this function errors when the passed array is not a \[Large\]String array.
 pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<Box<dyn Array>> {
-    match array.data_type() {
+    match array.data_type().to_logical_type() {
         DataType::Binary => Ok(Box::new(binary_substring(
             array
                 .as_any()
@@ -18,9 +18,9 @@
 //! Defines take kernel for [`Array`]
 
 use crate::{
-    array::{new_empty_array, Array, NullArray, PrimitiveArray},
+    array::{growable::make_growable, new_empty_array, Array, NullArray, PrimitiveArray},
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
     types::Index,
 };
 
@@ -31,6 +31,7 @@ mod generic_binary;
 mod list;
 mod primitive;
 mod structure;
+mod union;
 mod utf8;
 
 /// Returns a new [`Array`] with only indices at `indices`. Null indices are taken as nulls.
@@ -88,10 +89,88 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let array = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(list::take::<i64, O>(array, indices)))
         }
+        Union => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(union::take::<O>(array, indices)))
+        }
         t => unimplemented!("Take not supported for data type {:?}", t),
     }
 }
 
+/// Returns a new [`Array`] with the runs of consecutive slots of `array` at `ranges`, in order.
+/// Each range is declared as `(start, length)`. The returned array has a length equal to the sum
+/// of the lengths of `ranges`.
+///
+/// Each run is copied at once (via a [`Growable`](crate::array::growable::Growable)), which
+/// is considerably faster than [`take`] when the selection is composed of long runs, e.g. after
+/// sorting or when concatenating row groups. Use [`indices_to_ranges`] to convert indices to
+/// ranges.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::take::take_ranges;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(4), Some(5)]);
+/// let result = take_ranges(&array, &[(3, 2), (0, 2)]).unwrap();
+/// let expected = Int32Array::from(&[Some(4), Some(5), Some(1), None]);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+/// # Errors
+/// Errors iff a range is out of bounds of `array`.
+pub fn take_ranges(array: &dyn Array, ranges: &[(usize, usize)]) -> Result<Box<dyn Array>> {
+    let mut length = 0usize;
+    for (start, len) in ranges {
+        match start.checked_add(*len) {
+            Some(end) if end <= array.len() => length += len,
+            _ => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The range ({}, {}) is out of bounds of an array of length {}",
+                    start,
+                    len,
+                    array.len()
+                )))
+            }
+        }
+    }
+
+    let mut growable = make_growable(&[array], false, length);
+    ranges
+        .iter()
+        .filter(|(_, len)| *len > 0)
+        .for_each(|(start, len)| growable.extend(0, *start, *len));
+    Ok(growable.as_box())
+}
+
+/// Collapses `indices` into runs of consecutive indices, declared as `(start, length)`, such
+/// that [`take_ranges`] over them is equivalent to [`take`] over `indices`. The fewer runs, the
+/// faster [`take_ranges`] is, e.g. when `indices` is sorted and mostly contiguous.
+/// # Example
+/// ```
+/// use arrow2::array::UInt32Array;
+/// use arrow2::compute::take::indices_to_ranges;
+///
+/// let indices = UInt32Array::from_slice(&[1, 2, 3, 7, 8, 2]);
+/// let ranges = indices_to_ranges(&indices).unwrap();
+/// assert_eq!(ranges, vec![(1, 3), (7, 2), (2, 1)]);
+/// ```
+/// # Errors
+/// Errors iff `indices` has null slots, which can't be represented by ranges.
+pub fn indices_to_ranges<I: Index>(indices: &PrimitiveArray<I>) -> Result<Vec<(usize, usize)>> {
+    if indices.null_count() > 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Null indices cannot be converted to ranges".to_string(),
+        ));
+    }
+    let mut ranges: Vec<(usize, usize)> = vec![];
+    for index in indices.values().iter().map(|x| x.to_usize()) {
+        match ranges.last_mut() {
+            Some((start, len)) if *start + *len == index => *len += 1,
+            _ => ranges.push((index, 1)),
+        }
+    }
+    Ok(ranges)
+}
+
 /// Checks if an array of type `datatype` can perform take operation
 ///
 /// # Examples
@@ -134,5 +213,6 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::List(_)
             | DataType::LargeList(_)
             | DataType::Dictionary(..)
+            | DataType::Union(..)
     )
 }
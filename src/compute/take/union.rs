@@ -0,0 +1,18 @@
+use crate::array::{
+    growable::{Growable, GrowableUnion},
+    PrimitiveArray, UnionArray,
+};
+
+use super::Index;
+
+/// `take` implementation for UnionArrays
+pub fn take<O: Index>(values: &UnionArray, indices: &PrimitiveArray<O>) -> UnionArray {
+    let mut growable = GrowableUnion::new(vec![values], false, indices.len());
+
+    indices.iter().for_each(|index| match index {
+        Some(index) => growable.extend(0, index.to_usize(), 1),
+        None => growable.extend_validity(1),
+    });
+
+    growable.into()
+}
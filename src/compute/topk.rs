@@ -0,0 +1,387 @@
+//! Contains the operator [`topk`], that selects the first `k` slots of an [`Array`] in sort
+//! order without sorting it.
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::compute::sort::{sort_to_indices, SortOptions};
+use crate::compute::take::take;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// Returns the first `k` slots of `array` if it was sorted according to `options`, and the
+/// indices of those slots in `array`, in sort order.
+///
+/// Nulls are ordered according to `options.nulls_first` and floats are ordered like in
+/// [`sort`](crate::compute::sort::sort). Slots that compare equal are returned in the order
+/// of their index.
+/// # Implementation
+/// This is `O(n log(k))`: a heap of the best `k` slots seen so far is kept while the array is
+/// traversed once, so that the whole array is never permuted. When `k >= array.len()`, this
+/// falls back to [`sort_to_indices`].
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, UInt32Array};
+/// use arrow2::compute::sort::SortOptions;
+/// use arrow2::compute::topk::topk;
+///
+/// let array = Int32Array::from(&[Some(5), None, Some(1), Some(4), Some(9)]);
+/// let options = SortOptions::new(true, false);
+/// let (values, indices) = topk(&array, 2, options).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from_slice(&[9, 5]) as &dyn Array);
+/// assert_eq!(indices, UInt32Array::from_slice(&[4, 0]));
+/// ```
+/// # Errors
+/// Errors iff the [`DataType`] of `array` is not supported (see [`can_topk`]) or its length
+/// does not fit in a `u32`.
+pub fn topk(
+    array: &dyn Array,
+    k: usize,
+    options: SortOptions,
+) -> Result<(Box<dyn Array>, PrimitiveArray<u32>)> {
+    if u32::try_from(array.len()).is_err() {
+        return Err(ArrowError::Overflow);
+    }
+    let indices = if k >= array.len() {
+        sort_to_indices::<u32>(array, &options, None)?
+    } else {
+        topk_indices(array, k, &options)?
+    };
+    let values = take(array, &indices)?;
+    Ok((values, indices))
+}
+
+/// Checks if an array of type `datatype` can be passed to [`topk`].
+/// # Examples
+/// ```
+/// use arrow2::compute::topk::can_topk;
+/// use arrow2::datatypes::DataType;
+///
+/// assert_eq!(can_topk(&DataType::Decimal(10, 2)), true);
+/// assert_eq!(can_topk(&DataType::Null), false);
+/// ```
+pub fn can_topk(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth)
+        | DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, None)
+        | DataType::Duration(_)
+        | DataType::Decimal(_, _)
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary => true,
+        DataType::Dictionary(_, value_type, _) => can_topk(value_type.as_ref()),
+        _ => false,
+    }
+}
+
+macro_rules! dyn_topk {
+    ($ty:ty, $array:expr, $cmp:expr, $k:expr, $options:expr) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        primitive_topk::<$ty, _>(array, $cmp, $k, $options)
+    }};
+}
+
+fn topk_indices(array: &dyn Array, k: usize, options: &SortOptions) -> Result<PrimitiveArray<u32>> {
+    let nans_greatest = options.nans_greatest;
+    Ok(match array.data_type().to_logical_type() {
+        DataType::Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let values = array.values();
+            indices_topk(
+                array.validity(),
+                |i| values.get_bit(i),
+                |l: &bool, r: &bool| l.cmp(r),
+                array.len(),
+                k,
+                options,
+            )
+        }
+        DataType::Int8 => dyn_topk!(i8, array, ord::total_cmp, k, options),
+        DataType::Int16 => dyn_topk!(i16, array, ord::total_cmp, k, options),
+        DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => {
+            dyn_topk!(i32, array, ord::total_cmp, k, options)
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, None)
+        | DataType::Duration(_) => dyn_topk!(i64, array, ord::total_cmp, k, options),
+        DataType::Decimal(_, _) => dyn_topk!(i128, array, ord::total_cmp, k, options),
+        DataType::UInt8 => dyn_topk!(u8, array, ord::total_cmp, k, options),
+        DataType::UInt16 => dyn_topk!(u16, array, ord::total_cmp, k, options),
+        DataType::UInt32 => dyn_topk!(u32, array, ord::total_cmp, k, options),
+        DataType::UInt64 => dyn_topk!(u64, array, ord::total_cmp, k, options),
+        DataType::Float32 => dyn_topk!(
+            f32,
+            array,
+            |l: &f32, r: &f32| ord::total_cmp_f32_with_nans(l, r, nans_greatest),
+            k,
+            options
+        ),
+        DataType::Float64 => dyn_topk!(
+            f64,
+            array,
+            |l: &f64, r: &f64| ord::total_cmp_f64_with_nans(l, r, nans_greatest),
+            k,
+            options
+        ),
+        DataType::Utf8 => utf8_topk::<i32>(array.as_any().downcast_ref().unwrap(), k, options),
+        DataType::LargeUtf8 => utf8_topk::<i64>(array.as_any().downcast_ref().unwrap(), k, options),
+        DataType::Binary => binary_topk::<i32>(array.as_any().downcast_ref().unwrap(), k, options),
+        DataType::LargeBinary => {
+            binary_topk::<i64>(array.as_any().downcast_ref().unwrap(), k, options)
+        }
+        DataType::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            dictionary_topk::<$T>(array.as_any().downcast_ref().unwrap(), k, options)?
+        }),
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Top-k is not supported for data type {:?}",
+                t
+            )))
+        }
+    })
+}
+
+fn primitive_topk<T, F>(
+    array: &PrimitiveArray<T>,
+    cmp: F,
+    k: usize,
+    options: &SortOptions,
+) -> PrimitiveArray<u32>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let values = array.values();
+    indices_topk(
+        array.validity(),
+        |i| values[i],
+        cmp,
+        array.len(),
+        k,
+        options,
+    )
+}
+
+fn utf8_topk<O: Offset>(
+    array: &Utf8Array<O>,
+    k: usize,
+    options: &SortOptions,
+) -> PrimitiveArray<u32> {
+    indices_topk(
+        array.validity(),
+        |i| array.value(i),
+        |l: &&str, r: &&str| l.cmp(r),
+        array.len(),
+        k,
+        options,
+    )
+}
+
+fn binary_topk<O: Offset>(
+    array: &BinaryArray<O>,
+    k: usize,
+    options: &SortOptions,
+) -> PrimitiveArray<u32> {
+    indices_topk(
+        array.validity(),
+        |i| array.value(i),
+        |l: &&[u8], r: &&[u8]| l.cmp(r),
+        array.len(),
+        k,
+        options,
+    )
+}
+
+/// Selects the slots of a [`DictionaryArray`] by the rank of their value in the sorted
+/// values, so that slots are compared by integers instead of values. Slots whose key points
+/// to a null value are null.
+fn dictionary_topk<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    k: usize,
+    options: &SortOptions,
+) -> Result<PrimitiveArray<u32>> {
+    let keys = array.keys();
+    let values = array.values();
+
+    let order = sort_to_indices::<u32>(
+        values.as_ref(),
+        &SortOptions {
+            descending: false,
+            nulls_first: true,
+            nans_greatest: options.nans_greatest,
+        },
+        None,
+    )?;
+    let mut ranks = vec![0usize; values.len()];
+    order
+        .values()
+        .iter()
+        .enumerate()
+        .for_each(|(rank, index)| ranks[*index as usize] = rank);
+
+    let validity = array.value_validity_merged();
+    // Note: keys out of bounds of the dictionary panic.
+    Ok(indices_topk(
+        validity.as_ref(),
+        |i| ranks[keys.value(i).to_usize().unwrap()],
+        |l: &usize, r: &usize| l.cmp(r),
+        array.len(),
+        k,
+        options,
+    ))
+}
+
+/// Returns the indices of the first `k < length` slots in sort order, where `get` returns the
+/// value of a valid slot and `cmp` compares two values.
+fn indices_topk<T, G, F>(
+    validity: Option<&Bitmap>,
+    get: G,
+    cmp: F,
+    length: usize,
+    k: usize,
+    options: &SortOptions,
+) -> PrimitiveArray<u32>
+where
+    G: Fn(usize) -> T,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let validity = match validity.filter(|x| x.null_count() > 0) {
+        Some(validity) => validity,
+        None => {
+            let indices = select(0..length, k, get, cmp, options.descending);
+            return PrimitiveArray::<u32>::from_data(DataType::UInt32, indices.into(), None);
+        }
+    };
+    let null_count = validity.null_count();
+    let valids = validity
+        .iter()
+        .enumerate()
+        .filter(|(_, is_valid)| *is_valid)
+        .map(|(index, _)| index);
+    let nulls = validity
+        .iter()
+        .enumerate()
+        .filter(|(_, is_valid)| !*is_valid)
+        .map(|(index, _)| index as u32);
+
+    let indices = if options.nulls_first {
+        let nulls = nulls.take(k);
+        if k > null_count {
+            let valids = select(valids, k - null_count, get, cmp, options.descending);
+            nulls.chain(valids).collect::<Vec<_>>()
+        } else {
+            nulls.collect()
+        }
+    } else {
+        let valid_count = length - null_count;
+        let mut indices = select(valids, k.min(valid_count), get, cmp, options.descending);
+        if k > valid_count {
+            indices.extend(nulls.take(k - valid_count));
+        }
+        indices
+    };
+
+    PrimitiveArray::<u32>::from_data(DataType::UInt32, indices.into(), None)
+}
+
+/// Returns the `k` best `indices` in order, keeping a binary max-heap of the best `k` slots
+/// seen so far, whose root is the worst of them.
+fn select<T, G, F>(
+    indices: impl Iterator<Item = usize>,
+    k: usize,
+    get: G,
+    cmp: F,
+    descending: bool,
+) -> Vec<u32>
+where
+    G: Fn(usize) -> T,
+    F: Fn(&T, &T) -> Ordering,
+{
+    if k == 0 {
+        return vec![];
+    }
+    // ties are broken by index, so that the result is deterministic
+    let order = |lhs: &(T, usize), rhs: &(T, usize)| {
+        let ordering = cmp(&lhs.0, &rhs.0);
+        let ordering = if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+        ordering.then(lhs.1.cmp(&rhs.1))
+    };
+
+    let mut heap: Vec<(T, usize)> = Vec::with_capacity(k);
+    for index in indices {
+        let item = (get(index), index);
+        if heap.len() < k {
+            heap.push(item);
+            sift_up(&mut heap, &order);
+        } else if order(&item, &heap[0]) == Ordering::Less {
+            heap[0] = item;
+            sift_down(&mut heap, &order);
+        }
+    }
+
+    heap.sort_unstable_by(order);
+    heap.into_iter().map(|(_, index)| index as u32).collect()
+}
+
+fn sift_up<T, F: Fn(&T, &T) -> Ordering>(heap: &mut [T], order: &F) {
+    let mut child = heap.len() - 1;
+    while child > 0 {
+        let parent = (child - 1) / 2;
+        if order(&heap[child], &heap[parent]) != Ordering::Greater {
+            break;
+        }
+        heap.swap(child, parent);
+        child = parent;
+    }
+}
+
+fn sift_down<T, F: Fn(&T, &T) -> Ordering>(heap: &mut [T], order: &F) {
+    let mut parent = 0;
+    loop {
+        let left = 2 * parent + 1;
+        if left >= heap.len() {
+            break;
+        }
+        let right = left + 1;
+        let child = if right < heap.len() && order(&heap[right], &heap[left]) == Ordering::Greater {
+            right
+        } else {
+            left
+        };
+        if order(&heap[child], &heap[parent]) != Ordering::Greater {
+            break;
+        }
+        heap.swap(child, parent);
+        parent = child;
+    }
+}
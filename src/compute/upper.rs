@@ -17,7 +17,7 @@
 
 //! Defines kernel to extract a upper case of a \[Large\]StringArray
 
-use super::utils::utf8_apply;
+use super::utf8::utf8_apply_ascii;
 use crate::array::*;
 use crate::{
     datatypes::DataType,
@@ -26,17 +26,21 @@ use crate::{
 
 /// Returns a new `Array` where each of each of the elements is upper-cased.
 /// this function errors when the passed array is not a \[Large\]String array.
+///
+/// When all values are ASCII, the offsets of the array are re-used and only its values are re-written.
 pub fn upper(array: &dyn Array) -> Result<Box<dyn Array>> {
-    match array.data_type() {
-        DataType::LargeUtf8 => Ok(Box::new(utf8_apply(
+    match array.data_type().to_logical_type() {
+        DataType::LargeUtf8 => Ok(Box::new(utf8_apply_ascii(
             str::to_uppercase,
+            u8::to_ascii_uppercase,
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i64>>()
                 .expect("A large string is expected"),
         ))),
-        DataType::Utf8 => Ok(Box::new(utf8_apply(
+        DataType::Utf8 => Ok(Box::new(utf8_apply_ascii(
             str::to_uppercase,
+            u8::to_ascii_uppercase,
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i32>>()
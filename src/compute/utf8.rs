@@ -0,0 +1,52 @@
+//! Helpers shared by the kernels over [`Utf8Array`]s.
+use crate::array::{Offset, Utf8Array};
+use crate::buffer::Buffer;
+
+use super::utils::utf8_apply;
+
+/// Applies `op` to every value of `array`, or `ascii` to every byte of its values when they are
+/// all ASCII.
+///
+/// `ascii` must map ASCII bytes to ASCII bytes, so that the length of every transformed value
+/// equals the length of the original value: the offsets of `array` are then re-used (an `O(1)`
+/// clone when `array` is not offsetted) and only its values are re-written, in a single pass.
+/// `op` must equal `ascii` on ASCII strings.
+pub(super) fn utf8_apply_ascii<O, F>(
+    op: F,
+    ascii: fn(&u8) -> u8,
+    array: &Utf8Array<O>,
+) -> Utf8Array<O>
+where
+    O: Offset,
+    F: Fn(&str) -> String,
+{
+    let offsets = array.offsets();
+    let first = offsets[0];
+    let start = first.to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+    let values = &array.values()[start..end];
+
+    // `is_ascii` checks a word at a time
+    if !values.is_ascii() {
+        return utf8_apply(op, array);
+    }
+
+    let values: Vec<u8> = values.iter().map(ascii).collect();
+    debug_assert!(values.is_ascii());
+
+    let offsets = if start == 0 {
+        offsets.clone()
+    } else {
+        offsets.iter().map(|x| *x - first).collect::<Buffer<O>>()
+    };
+
+    // Safety: the offsets are those of a valid array and the values are ASCII
+    unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            Utf8Array::<O>::default_data_type(),
+            offsets,
+            values.into(),
+            array.validity().cloned(),
+        )
+    }
+}
@@ -5,15 +5,6 @@ use crate::{
     error::{ArrowError, Result},
 };
 
-pub fn combine_validities(lhs: Option<&Bitmap>, rhs: Option<&Bitmap>) -> Option<Bitmap> {
-    match (lhs, rhs) {
-        (Some(lhs), None) => Some(lhs.clone()),
-        (None, Some(rhs)) => Some(rhs.clone()),
-        (None, None) => None,
-        (Some(lhs), Some(rhs)) => Some(lhs & rhs),
-    }
-}
-
 pub fn unary_utf8_boolean<O: Offset, F: Fn(&str) -> bool>(
     values: &Utf8Array<O>,
     op: F,
@@ -59,3 +50,15 @@ pub fn check_same_type(lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
     }
     Ok(())
 }
+
+/// A total order of `T` where NaNs are greater than any other value and equal to each other.
+#[cfg(any(feature = "compute_aggregate", feature = "compute_window"))]
+#[inline]
+pub fn total_cmp<T: PartialOrd>(lhs: &T, rhs: &T) -> std::cmp::Ordering {
+    #[allow(clippy::eq_op)]
+    lhs.partial_cmp(rhs).unwrap_or_else(|| {
+        let lhs_nan = lhs != lhs;
+        let rhs_nan = rhs != rhs;
+        lhs_nan.cmp(&rhs_nan)
+    })
+}
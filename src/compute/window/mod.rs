@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines windowing functions, like `shift`ing
+//! Defines windowing functions, like `shift`ing and [`rolling`] aggregates
 
 use crate::compute::concatenate::concatenate;
 use num_traits::{abs, clamp};
@@ -25,6 +25,9 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+mod rolling;
+pub use rolling::*;
+
 /// Shifts array by defined number of items (to left or right)
 /// A positive value for `offset` shifts the array to the right
 /// a negative value shifts the array to the left.
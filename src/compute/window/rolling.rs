@@ -0,0 +1,465 @@
+//! Aggregates over a trailing (rolling) window of a [`PrimitiveArray`].
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+use num_traits::{AsPrimitive, Float};
+
+use crate::{
+    array::{Array, PrimitiveArray},
+    bitmap::MutableBitmap,
+    compute::utils::total_cmp,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+/// The aggregation computed by [`rolling`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RollingAgg {
+    /// The sum of the values in the window
+    Sum,
+    /// The mean of the values in the window, as `f64`
+    Mean,
+    /// The minimum of the values in the window
+    Min,
+    /// The maximum of the values in the window
+    Max,
+}
+
+/// Options of the rolling kernels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RollingOptions {
+    /// The number of slots of the window. The window of slot `i` is `[i + 1 - window, i]`.
+    pub window: usize,
+    /// The minimum number of non-null values in the window for the result to be non-null.
+    pub min_periods: usize,
+    /// Whether nulls are skipped. When `false`, a null anywhere in the window makes
+    /// the result null.
+    pub skip_nulls: bool,
+}
+
+impl RollingOptions {
+    /// Returns new [`RollingOptions`] that skip nulls.
+    pub fn new(window: usize, min_periods: usize) -> Self {
+        Self {
+            window,
+            min_periods,
+            skip_nulls: true,
+        }
+    }
+}
+
+/// A running sum that can both add and remove values.
+///
+/// Finite values are accumulated with Neumaier's compensated summation so that large values
+/// leaving the window do not erase the small values that remain. Non-finite values are counted
+/// separately so that the sum becomes finite again once they leave the window.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CompensatedSum<F: Float> {
+    sum: F,
+    compensation: F,
+    nans: usize,
+    positive_infinities: usize,
+    negative_infinities: usize,
+}
+
+impl<F: Float> CompensatedSum<F> {
+    #[inline]
+    fn accumulate(&mut self, value: F) {
+        let sum = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation = self.compensation + ((self.sum - sum) + value);
+        } else {
+            self.compensation = self.compensation + ((value - sum) + self.sum);
+        }
+        self.sum = sum;
+    }
+
+    #[inline]
+    fn count(&mut self, value: F, add: bool) {
+        let counter = if value.is_nan() {
+            &mut self.nans
+        } else if value > F::zero() {
+            &mut self.positive_infinities
+        } else {
+            &mut self.negative_infinities
+        };
+        if add {
+            *counter += 1
+        } else {
+            *counter -= 1
+        }
+    }
+
+    /// Adds `value` to the sum.
+    #[inline]
+    pub fn add(&mut self, value: F) {
+        if value.is_finite() {
+            self.accumulate(value)
+        } else {
+            self.count(value, true)
+        }
+    }
+
+    /// Removes `value`, previously added via [`CompensatedSum::add`], from the sum.
+    #[inline]
+    pub fn sub(&mut self, value: F) {
+        if value.is_finite() {
+            self.accumulate(-value)
+        } else {
+            self.count(value, false)
+        }
+    }
+
+    /// Returns the current sum.
+    #[inline]
+    pub fn value(&self) -> F {
+        match (
+            self.nans,
+            self.positive_infinities,
+            self.negative_infinities,
+        ) {
+            (0, 0, 0) => self.sum + self.compensation,
+            (0, _, 0) => F::infinity(),
+            (0, 0, _) => F::neg_infinity(),
+            _ => F::nan(),
+        }
+    }
+}
+
+/// A [`NativeType`] that can be summed over a rolling window.
+pub trait RollingSum: NativeType {
+    /// The state of a running sum of this type.
+    type Sum: Default + Copy;
+
+    /// Adds `value` to `sum`.
+    fn add(sum: &mut Self::Sum, value: Self);
+
+    /// Removes `value` from `sum`.
+    fn sub(sum: &mut Self::Sum, value: Self);
+
+    /// Returns the value of `sum`.
+    fn get(sum: &Self::Sum) -> Self;
+}
+
+macro_rules! integer_rolling_sum {
+    ($type:ty) => {
+        // integers are added and removed exactly (modulo overflow)
+        impl RollingSum for $type {
+            type Sum = $type;
+
+            #[inline]
+            fn add(sum: &mut Self::Sum, value: Self) {
+                *sum = sum.wrapping_add(value)
+            }
+
+            #[inline]
+            fn sub(sum: &mut Self::Sum, value: Self) {
+                *sum = sum.wrapping_sub(value)
+            }
+
+            #[inline]
+            fn get(sum: &Self::Sum) -> Self {
+                *sum
+            }
+        }
+    };
+}
+
+integer_rolling_sum!(u8);
+integer_rolling_sum!(u16);
+integer_rolling_sum!(u32);
+integer_rolling_sum!(u64);
+integer_rolling_sum!(i8);
+integer_rolling_sum!(i16);
+integer_rolling_sum!(i32);
+integer_rolling_sum!(i64);
+integer_rolling_sum!(i128);
+
+macro_rules! float_rolling_sum {
+    ($type:ty) => {
+        impl RollingSum for $type {
+            type Sum = CompensatedSum<$type>;
+
+            #[inline]
+            fn add(sum: &mut Self::Sum, value: Self) {
+                sum.add(value)
+            }
+
+            #[inline]
+            fn sub(sum: &mut Self::Sum, value: Self) {
+                sum.sub(value)
+            }
+
+            #[inline]
+            fn get(sum: &Self::Sum) -> Self {
+                sum.value()
+            }
+        }
+    };
+}
+
+float_rolling_sum!(f32);
+float_rolling_sum!(f64);
+
+// the state of an aggregation over the window, updated as values enter and leave it
+trait WindowState<T: NativeType> {
+    type Output: NativeType;
+
+    // `value` at `index` enters the window
+    fn insert(&mut self, index: usize, value: T);
+
+    // `value` at `index`, the oldest value of the window, leaves it
+    fn remove(&mut self, index: usize, value: T);
+
+    // the aggregate over the `count` (>= 1) non-null values in the window
+    fn get(&self, count: usize) -> Self::Output;
+}
+
+struct SumState<T: RollingSum>(T::Sum);
+
+impl<T: RollingSum> WindowState<T> for SumState<T> {
+    type Output = T;
+
+    #[inline]
+    fn insert(&mut self, _: usize, value: T) {
+        T::add(&mut self.0, value)
+    }
+
+    #[inline]
+    fn remove(&mut self, _: usize, value: T) {
+        T::sub(&mut self.0, value)
+    }
+
+    #[inline]
+    fn get(&self, _: usize) -> T {
+        T::get(&self.0)
+    }
+}
+
+struct MeanState(CompensatedSum<f64>);
+
+impl<T: NativeType + AsPrimitive<f64>> WindowState<T> for MeanState {
+    type Output = f64;
+
+    #[inline]
+    fn insert(&mut self, _: usize, value: T) {
+        self.0.add(value.as_())
+    }
+
+    #[inline]
+    fn remove(&mut self, _: usize, value: T) {
+        self.0.sub(value.as_())
+    }
+
+    #[inline]
+    fn get(&self, count: usize) -> f64 {
+        self.0.value() / count as f64
+    }
+}
+
+// a monotonic deque of the (index, value) that are candidates to be the extreme of the window:
+// its values are ordered such that the front is the extreme of the window.
+struct ExtremeState<T> {
+    deque: VecDeque<(usize, T)>,
+    // the ordering that values closer to the back must have relative to values at the front
+    keep: Ordering,
+}
+
+impl<T: NativeType + PartialOrd> WindowState<T> for ExtremeState<T> {
+    type Output = T;
+
+    #[inline]
+    fn insert(&mut self, index: usize, value: T) {
+        // values that can no longer be the extreme are dropped
+        while let Some((_, back)) = self.deque.back() {
+            if total_cmp(&value, back) == self.keep {
+                break;
+            }
+            self.deque.pop_back();
+        }
+        self.deque.push_back((index, value));
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize, _: T) {
+        if let Some((front, _)) = self.deque.front() {
+            if *front == index {
+                self.deque.pop_front();
+            }
+        }
+    }
+
+    #[inline]
+    fn get(&self, _: usize) -> T {
+        self.deque.front().unwrap().1
+    }
+}
+
+fn check_options(options: &RollingOptions) -> Result<()> {
+    if options.window == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "The window of rolling aggregates must be larger than zero".to_string(),
+        ));
+    }
+    if options.min_periods == 0 || options.min_periods > options.window {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The min_periods of rolling aggregates must be between 1 and the window ({}), got {}",
+            options.window, options.min_periods
+        )));
+    }
+    Ok(())
+}
+
+fn rolling_impl<T, S>(
+    array: &PrimitiveArray<T>,
+    options: &RollingOptions,
+    mut state: S,
+    data_type: DataType,
+) -> Result<PrimitiveArray<S::Output>>
+where
+    T: NativeType,
+    S: WindowState<T>,
+{
+    check_options(options)?;
+    let window = options.window;
+
+    let mut values = Vec::<S::Output>::with_capacity(array.len());
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    let mut count = 0;
+    let mut nulls = 0;
+    for index in 0..array.len() {
+        if array.is_valid(index) {
+            state.insert(index, array.value(index));
+            count += 1;
+        } else {
+            nulls += 1;
+        }
+
+        if index >= window {
+            let leaving = index - window;
+            if array.is_valid(leaving) {
+                state.remove(leaving, array.value(leaving));
+                count -= 1;
+            } else {
+                nulls -= 1;
+            }
+        }
+
+        if count >= options.min_periods && (options.skip_nulls || nulls == 0) {
+            values.push(state.get(count));
+            validity.push(true);
+        } else {
+            values.push(S::Output::default());
+            validity.push(false);
+        }
+    }
+
+    Ok(PrimitiveArray::<S::Output>::from_data(
+        data_type,
+        values.into(),
+        validity.into(),
+    ))
+}
+
+/// Returns the sum of the values in the trailing window of each slot of `array`.
+/// Floats are summed with compensation, so that results do not drift as values enter and
+/// leave the window; integers wrap on overflow.
+/// # Error
+/// Errors iff `options.window == 0` or `options.min_periods` is not in `1..=options.window`.
+pub fn rolling_sum<T: RollingSum>(
+    array: &PrimitiveArray<T>,
+    options: RollingOptions,
+) -> Result<PrimitiveArray<T>> {
+    let state = SumState::<T>(T::Sum::default());
+    rolling_impl(array, &options, state, array.data_type().clone())
+}
+
+/// Returns the mean of the values in the trailing window of each slot of `array`.
+/// # Error
+/// Errors iff `options.window == 0` or `options.min_periods` is not in `1..=options.window`.
+pub fn rolling_mean<T>(
+    array: &PrimitiveArray<T>,
+    options: RollingOptions,
+) -> Result<PrimitiveArray<f64>>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    let state = MeanState(CompensatedSum::default());
+    rolling_impl(array, &options, state, DataType::Float64)
+}
+
+/// Returns the minimum of the values in the trailing window of each slot of `array`.
+/// NaNs are greater than any other value.
+/// # Error
+/// Errors iff `options.window == 0` or `options.min_periods` is not in `1..=options.window`.
+pub fn rolling_min<T>(
+    array: &PrimitiveArray<T>,
+    options: RollingOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    let state = ExtremeState {
+        deque: VecDeque::with_capacity(options.window.min(array.len())),
+        keep: Ordering::Greater,
+    };
+    rolling_impl(array, &options, state, array.data_type().clone())
+}
+
+/// Returns the maximum of the values in the trailing window of each slot of `array`.
+/// NaNs are greater than any other value.
+/// # Error
+/// Errors iff `options.window == 0` or `options.min_periods` is not in `1..=options.window`.
+pub fn rolling_max<T>(
+    array: &PrimitiveArray<T>,
+    options: RollingOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    let state = ExtremeState {
+        deque: VecDeque::with_capacity(options.window.min(array.len())),
+        keep: Ordering::Less,
+    };
+    rolling_impl(array, &options, state, array.data_type().clone())
+}
+
+/// Returns the aggregate `agg` of the non-null values in the trailing window of `window` slots
+/// of each slot of `array`; slots whose window has fewer than `min_periods` non-null values
+/// are null. The result is a [`PrimitiveArray<T>`], or [`PrimitiveArray<f64>`] for
+/// [`RollingAgg::Mean`].
+///
+/// Use [`rolling_sum`], [`rolling_mean`], [`rolling_min`] or [`rolling_max`] with
+/// [`RollingOptions::skip_nulls`] `false` for results to be null whenever the window has a null.
+/// # Examples
+/// ```
+/// use arrow2::array::{Float64Array, Int32Array};
+/// use arrow2::compute::window::{rolling, RollingAgg};
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+/// let result = rolling(&array, 2, 1, RollingAgg::Sum).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(1), Some(1), Some(3), Some(7)]), result.as_ref());
+///
+/// let result = rolling(&array, 2, 2, RollingAgg::Mean).unwrap();
+/// assert_eq!(Float64Array::from(&[None, None, None, Some(3.5)]), result.as_ref());
+/// ```
+/// # Error
+/// Errors iff `window == 0` or `min_periods` is not in `1..=window`.
+pub fn rolling<T>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+    agg: RollingAgg,
+) -> Result<Box<dyn Array>>
+where
+    T: RollingSum + AsPrimitive<f64> + PartialOrd,
+{
+    let options = RollingOptions::new(window, min_periods);
+    Ok(match agg {
+        RollingAgg::Sum => Box::new(rolling_sum(array, options)?),
+        RollingAgg::Mean => Box::new(rolling_mean(array, options)?),
+        RollingAgg::Min => Box::new(rolling_min(array, options)?),
+        RollingAgg::Max => Box::new(rolling_max(array, options)?),
+    })
+}
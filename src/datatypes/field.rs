@@ -79,8 +79,8 @@ impl Field {
             if let Some(self_value) = self.metadata.get(key) {
                 if self_value != from_value {
                     return Err(ArrowError::InvalidArgumentError(format!(
-                        "Fail to merge field due to conflicting metadata data value for key {}",
-                        key
+                        "Fail to merge field \"{}\" due to conflicting metadata value for key {}",
+                        self.name, key
                     )));
                 }
             } else {
@@ -106,9 +106,7 @@ impl Field {
                     }
                 }
                 _ => {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "Fail to merge schema Field due to conflicting datatype".to_string(),
-                    ));
+                    return Err(self.conflicting_data_type(from));
                 }
             },
             DataType::Union(nested_fields, _, _) => match &from.data_type {
@@ -127,9 +125,7 @@ impl Field {
                     }
                 }
                 _ => {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "Fail to merge schema Field due to conflicting datatype".to_string(),
-                    ));
+                    return Err(self.conflicting_data_type(from));
                 }
             },
             DataType::Null
@@ -165,9 +161,7 @@ impl Field {
             | DataType::Map(_, _)
             | DataType::Decimal(_, _) => {
                 if self.data_type != from.data_type {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "Fail to merge schema Field due to conflicting datatype".to_string(),
-                    ));
+                    return Err(self.conflicting_data_type(from));
                 }
             }
         }
@@ -177,4 +171,11 @@ impl Field {
 
         Ok(())
     }
+
+    fn conflicting_data_type(&self, from: &Field) -> ArrowError {
+        ArrowError::InvalidArgumentError(format!(
+            "Fail to merge schema Field \"{}\" due to conflicting datatype ({:?} and {:?})",
+            self.name, self.data_type, from.data_type
+        ))
+    }
 }
@@ -250,7 +250,7 @@ impl DataType {
             UInt16 => PhysicalType::Primitive(PrimitiveType::UInt16),
             UInt32 => PhysicalType::Primitive(PrimitiveType::UInt32),
             UInt64 => PhysicalType::Primitive(PrimitiveType::UInt64),
-            Float16 => unreachable!(),
+            Float16 => PhysicalType::Primitive(PrimitiveType::Float16),
             Float32 => PhysicalType::Primitive(PrimitiveType::Float32),
             Float64 => PhysicalType::Primitive(PrimitiveType::Float64),
             Interval(IntervalUnit::DayTime) => PhysicalType::Primitive(PrimitiveType::DaysMs),
@@ -312,6 +312,7 @@ impl From<PrimitiveType> for DataType {
             PrimitiveType::UInt32 => DataType::UInt32,
             PrimitiveType::UInt64 => DataType::UInt64,
             PrimitiveType::Int128 => DataType::Decimal(32, 32),
+            PrimitiveType::Float16 => DataType::Float16,
             PrimitiveType::Float32 => DataType::Float32,
             PrimitiveType::Float64 => DataType::Float64,
             PrimitiveType::DaysMs => DataType::Interval(IntervalUnit::DayTime),
@@ -332,3 +333,14 @@ pub fn get_extension(metadata: &Metadata) -> Extension {
         None
     }
 }
+
+/// Removes the extension keys from `metadata`, returning the extension they declare, if any.
+/// Used when reading fields, whose extension is represented by [`DataType::Extension`].
+pub(crate) fn remove_extension(metadata: &mut Metadata) -> Extension {
+    if let Some(name) = metadata.remove("ARROW:extension:name") {
+        let metadata = metadata.remove("ARROW:extension:metadata");
+        Some((name, metadata))
+    } else {
+        None
+    }
+}
@@ -17,6 +17,8 @@
 
 use std::collections::HashMap;
 
+use crate::array::Array;
+use crate::columns::Columns;
 use crate::error::{ArrowError, Result};
 
 use super::Field;
@@ -75,6 +77,52 @@ impl Schema {
         Self { fields, metadata }
     }
 
+    /// Creates a new [`Schema`] whose fields are named `names` and have the data type of each
+    /// array of `columns`. A field is nullable iff its array has a validity or nulls.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{Array, Int32Array, Utf8Array};
+    /// use arrow2::columns::Columns;
+    /// use arrow2::datatypes::{DataType, Field, Schema};
+    ///
+    /// let columns = Columns::new(vec![
+    ///     Box::new(Int32Array::from_slice(&[1, 2])) as Box<dyn Array>,
+    ///     Box::new(Utf8Array::<i32>::from(&[Some("a"), None])),
+    /// ]);
+    /// let schema = Schema::try_from_columns(&["a", "b"], &columns).unwrap();
+    /// assert_eq!(
+    ///     schema,
+    ///     Schema::new(vec![
+    ///         Field::new("a", DataType::Int32, false),
+    ///         Field::new("b", DataType::Utf8, true),
+    ///     ])
+    /// );
+    /// ```
+    /// # Errors
+    /// Errors iff the number of `names` differs from the number of arrays of `columns`.
+    pub fn try_from_columns<A: AsRef<dyn Array>>(
+        names: &[&str],
+        columns: &Columns<A>,
+    ) -> Result<Self> {
+        if names.len() != columns.arrays().len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "There must be one name per column: got {} names for {} columns",
+                names.len(),
+                columns.arrays().len()
+            )));
+        }
+        let fields = names
+            .iter()
+            .zip(columns.arrays())
+            .map(|(name, array)| {
+                let array = array.as_ref();
+                let is_nullable = array.validity().is_some() || array.null_count() > 0;
+                Field::new(*name, array.data_type().clone(), is_nullable)
+            })
+            .collect();
+        Ok(Self::new(fields))
+    }
+
     /// Creates a new [`Field`] with metadata.
     #[inline]
     pub fn with_metadata(self, metadata: HashMap<String, String>) -> Self {
@@ -121,9 +169,10 @@ impl Schema {
                     // merge metadata
                     if let Some(old_val) = merged.metadata.get(&key) {
                         if old_val != &value {
-                            return Err(ArrowError::InvalidArgumentError(
-                                "Fail to merge schema due to conflicting metadata.".to_string(),
-                            ));
+                            return Err(ArrowError::InvalidArgumentError(format!(
+                                "Fail to merge schema due to conflicting metadata value for key {}",
+                                key
+                            )));
                         }
                     }
                     merged.metadata.insert(key, value);
@@ -147,6 +196,40 @@ impl Schema {
             })
     }
 
+    /// Returns a new [`Schema`] with only the fields at `indices`, in that order,
+    /// and the same metadata as `self`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use arrow2::datatypes::*;
+    ///
+    /// let schema = Schema::new(vec![
+    ///     Field::new("c1", DataType::Int64, false),
+    ///     Field::new("c2", DataType::Utf8, false),
+    /// ]);
+    ///
+    /// let projected = schema.project(&[1]).unwrap();
+    /// assert_eq!(projected, Schema::new(vec![Field::new("c2", DataType::Utf8, false)]));
+    /// ```
+    /// # Errors
+    /// Errors iff any of `indices` is out of bounds.
+    pub fn project(&self, indices: &[usize]) -> Result<Self> {
+        let fields = indices
+            .iter()
+            .map(|&i| {
+                self.fields.get(i).cloned().ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Unable to project field {} of a schema with {} fields",
+                        i,
+                        self.fields.len()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new_from(fields, self.metadata.clone()))
+    }
+
     /// Returns all [`Field`]s in this schema.
     #[inline]
     pub const fn fields(&self) -> &Vec<Field> {
@@ -22,6 +22,17 @@ pub enum ArrowError {
     Overflow,
     /// Whenever incoming data from the C data interface, IPC or Flight does not fulfil the Arrow specification.
     OutOfSpec(String),
+    /// Whenever deserializing a column from a file format (e.g. CSV, JSON, Avro or Parquet)
+    /// fails. It wraps the underlying error with, when known, the column and the row at
+    /// which it happened.
+    Deserialization {
+        /// The name of the column being deserialized, if the error is specific to a column
+        column: Option<String>,
+        /// The row being deserialized, if known
+        row: Option<usize>,
+        /// The error that made deserialization fail
+        source: Box<ArrowError>,
+    },
 }
 
 impl ArrowError {
@@ -30,6 +41,26 @@ impl ArrowError {
         Self::External("".to_string(), Box::new(error))
     }
 
+    /// Wraps `self` in [`ArrowError::Deserialization`], adding the `column` and `row`
+    /// at which it happened.
+    pub fn context<A: Into<String>>(self, column: A, row: Option<usize>) -> Self {
+        Self::Deserialization {
+            column: Some(column.into()),
+            row,
+            source: Box::new(self),
+        }
+    }
+
+    /// Wraps `self` in [`ArrowError::Deserialization`], adding the `row` at which it
+    /// happened, for errors that are not specific to a column (e.g. a malformed row).
+    pub fn at_row(self, row: usize) -> Self {
+        Self::Deserialization {
+            column: None,
+            row: Some(row),
+            source: Box::new(self),
+        }
+    }
+
     pub(crate) fn oos<A: Into<String>>(msg: A) -> Self {
         Self::OutOfSpec(msg.into())
     }
@@ -74,16 +105,43 @@ impl Display for ArrowError {
                 write!(f, "External format error: {}", desc)
             }
             ArrowError::Overflow => {
-                write!(f, "Operation overflew the backing container.")
+                write!(
+                    f,
+                    "Operation overflew the backing container. Consider using a container with \
+                     larger offsets (e.g. LargeList, LargeUtf8 or LargeBinary) instead."
+                )
             }
             ArrowError::OutOfSpec(message) => {
                 write!(f, "{}", message)
             }
+            ArrowError::Deserialization {
+                column,
+                row,
+                source,
+            } => {
+                write!(f, "Failed to deserialize")?;
+                match (column, row) {
+                    (Some(column), Some(row)) => {
+                        write!(f, " column \"{}\" at row {}", column, row)?
+                    }
+                    (Some(column), None) => write!(f, " column \"{}\"", column)?,
+                    (None, Some(row)) => write!(f, " row {}", row)?,
+                    (None, None) => {}
+                }
+                write!(f, ": {}", source)
+            }
         }
     }
 }
 
-impl Error for ArrowError {}
+impl Error for ArrowError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ArrowError::Deserialization { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 /// Typedef for a [`std::result::Result`] of an [`ArrowError`].
 pub type Result<T> = std::result::Result<T, ArrowError>;
@@ -1,7 +1,7 @@
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
 
-use avro_schema::{Enum, Schema as AvroSchema};
+use avro_schema::{Enum, Fixed, Record, Schema as AvroSchema};
 
 use crate::array::*;
 use crate::datatypes::*;
@@ -13,6 +13,7 @@ use crate::types::months_days_ns;
 use super::super::Block;
 use super::nested::*;
 use super::util;
+use super::Projection;
 
 fn make_mutable(
     data_type: &DataType,
@@ -34,7 +35,8 @@ fn make_mutable(
             Box::new(MutableUtf8Array::<i32>::with_capacity(capacity)) as Box<dyn MutableArray>
         }
         PhysicalType::Dictionary(_) => {
-            if let Some(AvroSchema::Enum(Enum { symbols, .. })) = avro_schema {
+            if let Some(AvroSchema::Enum(Enum { symbols, .. })) = avro_schema.map(non_null_variant)
+            {
                 let values = Utf8Array::<i32>::from_slice(symbols);
                 Box::new(FixedItemsUtf8Dictionary::with_capacity(values, capacity))
                     as Box<dyn MutableArray>
@@ -45,8 +47,10 @@ fn make_mutable(
         _ => match data_type {
             DataType::List(inner) => {
                 let values = make_mutable(inner.data_type(), None, 0)?;
-                Box::new(DynMutableListArray::<i32>::new_with_capacity(
-                    values, capacity,
+                Box::new(DynMutableListArray::<i32>::new_from(
+                    values,
+                    data_type.clone(),
+                    capacity,
                 )) as Box<dyn MutableArray>
             }
             DataType::FixedSizeBinary(size) => Box::new(MutableFixedSizeBinaryArray::with_capacity(
@@ -71,6 +75,35 @@ fn is_union_null_first(avro_field: &AvroSchema) -> bool {
     }
 }
 
+/// Returns whether `avro_field` is a union of null and another schema, i.e. a nullable field.
+pub(super) fn is_nullable(avro_field: &AvroSchema) -> bool {
+    matches!(avro_field, AvroSchema::Union(schemas) if schemas.len() == 2 && schemas.contains(&AvroSchema::Null))
+}
+
+/// Returns the variant of a nullable union that is not null, or `avro_field` itself.
+pub(super) fn non_null_variant(avro_field: &AvroSchema) -> &AvroSchema {
+    match avro_field {
+        AvroSchema::Union(schemas) if is_nullable(avro_field) => {
+            schemas.iter().find(|x| **x != AvroSchema::Null).unwrap()
+        }
+        _ => avro_field,
+    }
+}
+
+#[inline]
+fn read_len(block: &mut &[u8]) -> Result<usize> {
+    util::zigzag_i64(block)?.try_into().map_err(|_| {
+        ArrowError::ExternalFormat("Avro format contains a non-usize number of bytes".to_string())
+    })
+}
+
+#[inline]
+fn advance(block: &[u8], length: usize) -> Result<&[u8]> {
+    block.get(length..).ok_or_else(|| {
+        ArrowError::ExternalFormat("Avro block is shorter than its items".to_string())
+    })
+}
+
 fn deserialize_item<'a>(
     array: &mut dyn MutableArray,
     is_nullable: bool,
@@ -85,7 +118,7 @@ fn deserialize_item<'a>(
             return Ok(block);
         }
     }
-    deserialize_value(array, avro_field, block)
+    deserialize_value(array, non_null_variant(avro_field), block)
 }
 
 fn deserialize_value<'a>(
@@ -95,14 +128,14 @@ fn deserialize_value<'a>(
 ) -> Result<&'a [u8]> {
     let data_type = array.data_type();
     match data_type {
-        DataType::List(inner) => {
+        DataType::List(_) => {
             let avro_inner = if let AvroSchema::Array(inner) = avro_field {
                 inner.as_ref()
             } else {
                 unreachable!()
             };
 
-            let is_nullable = inner.is_nullable();
+            let is_nullable = is_nullable(avro_inner);
             let array = array
                 .as_mut_any()
                 .downcast_mut::<DynMutableListArray<i32>>()
@@ -167,9 +200,16 @@ fn deserialize_value<'a>(
                     array.push(Some(value))
                 }
                 PrimitiveType::Float32 => {
-                    let value =
-                        f32::from_le_bytes(block[..std::mem::size_of::<f32>()].try_into().unwrap());
-                    block = &block[std::mem::size_of::<f32>()..];
+                    // `int` and `long` are promoted to `float`
+                    let value = if let AvroSchema::Int(_) | AvroSchema::Long(_) = avro_field {
+                        util::zigzag_i64(&mut block)? as f32
+                    } else {
+                        let value = f32::from_le_bytes(
+                            block[..std::mem::size_of::<f32>()].try_into().unwrap(),
+                        );
+                        block = &block[std::mem::size_of::<f32>()..];
+                        value
+                    };
                     let array = array
                         .as_mut_any()
                         .downcast_mut::<MutablePrimitiveArray<f32>>()
@@ -177,9 +217,26 @@ fn deserialize_value<'a>(
                     array.push(Some(value))
                 }
                 PrimitiveType::Float64 => {
-                    let value =
-                        f64::from_le_bytes(block[..std::mem::size_of::<f64>()].try_into().unwrap());
-                    block = &block[std::mem::size_of::<f64>()..];
+                    // `int`, `long` and `float` are promoted to `double`
+                    let value = match avro_field {
+                        AvroSchema::Int(_) | AvroSchema::Long(_) => {
+                            util::zigzag_i64(&mut block)? as f64
+                        }
+                        AvroSchema::Float => {
+                            let value = f32::from_le_bytes(
+                                block[..std::mem::size_of::<f32>()].try_into().unwrap(),
+                            );
+                            block = &block[std::mem::size_of::<f32>()..];
+                            value as f64
+                        }
+                        _ => {
+                            let value = f64::from_le_bytes(
+                                block[..std::mem::size_of::<f64>()].try_into().unwrap(),
+                            );
+                            block = &block[std::mem::size_of::<f64>()..];
+                            value
+                        }
+                    };
                     let array = array
                         .as_mut_any()
                         .downcast_mut::<MutablePrimitiveArray<f64>>()
@@ -189,11 +246,7 @@ fn deserialize_value<'a>(
                 _ => unreachable!(),
             },
             PhysicalType::Utf8 => {
-                let len: usize = util::zigzag_i64(&mut block)?.try_into().map_err(|_| {
-                    ArrowError::ExternalFormat(
-                        "Avro format contains a non-usize number of bytes".to_string(),
-                    )
-                })?;
+                let len = read_len(&mut block)?;
                 let data = simdutf8::basic::from_utf8(&block[..len])?;
                 block = &block[len..];
 
@@ -204,11 +257,7 @@ fn deserialize_value<'a>(
                 array.push(Some(data))
             }
             PhysicalType::Binary => {
-                let len: usize = util::zigzag_i64(&mut block)?.try_into().map_err(|_| {
-                    ArrowError::ExternalFormat(
-                        "Avro format contains a non-usize number of bytes".to_string(),
-                    )
-                })?;
+                let len = read_len(&mut block)?;
                 let data = &block[..len];
                 block = &block[len..];
 
@@ -242,7 +291,120 @@ fn deserialize_value<'a>(
     Ok(block)
 }
 
+/// Skips an item of `avro_field` from `block`, without deserializing it.
+fn skip_item<'a>(avro_field: &AvroSchema, mut block: &'a [u8]) -> Result<&'a [u8]> {
+    match avro_field {
+        AvroSchema::Null => {}
+        AvroSchema::Boolean => block = advance(block, 1)?,
+        AvroSchema::Int(_) | AvroSchema::Long(_) | AvroSchema::Enum(_) => {
+            util::zigzag_i64(&mut block)?;
+        }
+        AvroSchema::Float => block = advance(block, std::mem::size_of::<f32>())?,
+        AvroSchema::Double => block = advance(block, std::mem::size_of::<f64>())?,
+        AvroSchema::Bytes(_) | AvroSchema::String(_) => {
+            let len = read_len(&mut block)?;
+            block = advance(block, len)?;
+        }
+        AvroSchema::Fixed(Fixed { size, .. }) => block = advance(block, *size)?,
+        AvroSchema::Array(_) | AvroSchema::Map(_) => loop {
+            // https://avro.apache.org/docs/current/spec.html#binary_encode_complex
+            // items are encoded in blocks; a negative count is followed by the size of the block
+            let count = util::zigzag_i64(&mut block)?;
+            if count == 0 {
+                break;
+            }
+            if count < 0 {
+                let len = read_len(&mut block)?;
+                block = advance(block, len)?;
+                continue;
+            }
+            for _ in 0..count {
+                block = match avro_field {
+                    AvroSchema::Array(inner) => skip_item(inner, block)?,
+                    AvroSchema::Map(inner) => {
+                        let len = read_len(&mut block)?;
+                        skip_item(inner, advance(block, len)?)?
+                    }
+                    _ => unreachable!(),
+                };
+            }
+        },
+        AvroSchema::Union(schemas) => {
+            let variant = util::zigzag_i64(&mut block)?;
+            let schema = usize::try_from(variant)
+                .ok()
+                .and_then(|variant| schemas.get(variant))
+                .ok_or_else(|| {
+                    ArrowError::ExternalFormat(format!("Avro union has no variant {}", variant))
+                })?;
+            block = skip_item(schema, block)?;
+        }
+        AvroSchema::Record(Record { fields, .. }) => {
+            for field in fields {
+                block = skip_item(&field.schema, block)?;
+            }
+        }
+    }
+    Ok(block)
+}
+
+/// Deserializes a [`Block`] into a [`RecordBatch`] of the fields of a [`Projection`].
+/// Fields of the writer's schema that are not projected are skipped without being deserialized
+/// and fields of the reader's schema that the writer's schema does not contain are filled with
+/// their default value.
+/// # Errors
+/// Errors with [`ArrowError::Deserialization`] when an item can't be deserialized, with the
+/// row counted from the start of `block`.
+pub fn deserialize_projected(block: &Block, projection: &Projection) -> Result<RecordBatch> {
+    let rows = block.number_of_rows;
+    let mut block = block.data.as_ref();
+    let schema = projection.schema();
+
+    // create mutables, one per projected field
+    let mut arrays: Vec<Box<dyn MutableArray>> = schema
+        .fields()
+        .iter()
+        .zip(projection.avro_schemas.iter())
+        .map(|(field, avro_schema)| {
+            let data_type = field.data_type().to_logical_type();
+            make_mutable(data_type, Some(avro_schema), rows)
+        })
+        .collect::<Result<_>>()?;
+
+    for row in 0..rows {
+        for (avro_field, target) in projection
+            .writer_avro_schemas
+            .iter()
+            .zip(projection.targets.iter())
+        {
+            block = match target {
+                Some(index) => {
+                    let is_nullable = is_nullable(avro_field);
+                    deserialize_item(arrays[*index].as_mut(), is_nullable, avro_field, block)
+                        .map_err(|error| error.context(schema.field(*index).name(), Some(row)))?
+                }
+                None => skip_item(avro_field, block)?,
+            };
+        }
+        for (index, default) in projection.defaults.iter() {
+            let avro_field = &projection.avro_schemas[*index];
+            deserialize_item(
+                arrays[*index].as_mut(),
+                is_nullable(avro_field),
+                avro_field,
+                default,
+            )?;
+        }
+    }
+    let columns = arrays.iter_mut().map(|array| array.as_arc()).collect();
+
+    RecordBatch::try_new(schema.clone(), columns)
+}
+
 /// Deserializes a [`Block`] into a [`RecordBatch`].
+/// # Errors
+/// Errors with [`ArrowError::Deserialization`] when an item can't be deserialized, with the
+/// row counted from the start of `block`.
 pub fn deserialize(
     block: &Block,
     schema: Arc<Schema>,
@@ -263,13 +425,14 @@ pub fn deserialize(
         .collect::<Result<_>>()?;
 
     // this is _the_ expensive transpose (rows -> columns)
-    for _ in 0..rows {
+    for row in 0..rows {
         for ((array, field), avro_field) in arrays
             .iter_mut()
             .zip(schema.fields().iter())
             .zip(avro_schemas.iter())
         {
-            block = deserialize_item(array.as_mut(), field.is_nullable(), avro_field, block)?
+            block = deserialize_item(array.as_mut(), field.is_nullable(), avro_field, block)
+                .map_err(|error| error.context(field.name(), Some(row)))?
         }
     }
     let columns = arrays.iter_mut().map(|array| array.as_arc()).collect();
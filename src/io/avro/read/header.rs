@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use avro_schema::Schema;
-use serde_json;
+use serde_json::{self, Value};
 
 use crate::error::{ArrowError, Result};
 
@@ -17,6 +17,10 @@ pub(crate) fn deserialize_header(
         .and_then(|bytes| {
             serde_json::from_slice(bytes.as_ref())
                 .map_err(|e| ArrowError::ExternalFormat(e.to_string()))
+        })
+        .and_then(|mut schema| {
+            strip_defaults(&mut schema);
+            serde_json::from_value(schema).map_err(|e| ArrowError::ExternalFormat(e.to_string()))
         })?;
 
     let compression = header.get("avro.codec").and_then(|bytes| {
@@ -29,3 +33,23 @@ pub(crate) fn deserialize_header(
     });
     Ok((schema, compression))
 }
+
+/// Removes the default values of the fields of every record of `schema`. `avro_schema` expects
+/// these to be Avro schemas, but they are JSON values of the field's type (e.g. `0` for an `int`).
+pub(super) fn strip_defaults(schema: &mut Value) {
+    match schema {
+        Value::Array(values) => values.iter_mut().for_each(strip_defaults),
+        Value::Object(map) => {
+            if let Some(Value::Array(fields)) = map.get_mut("fields") {
+                fields
+                    .iter_mut()
+                    .filter_map(|field| field.as_object_mut())
+                    .for_each(|field| {
+                        field.remove("default");
+                    });
+            }
+            map.values_mut().for_each(strip_defaults)
+        }
+        _ => {}
+    }
+}
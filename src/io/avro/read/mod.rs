@@ -10,9 +10,11 @@ mod decompress;
 pub use block::BlockStreamIterator;
 pub use decompress::{decompress_block, Decompressor};
 mod deserialize;
-pub use deserialize::deserialize;
+pub use deserialize::{deserialize, deserialize_projected};
 mod header;
 mod nested;
+mod projection;
+pub use projection::{parse_reader_schema, Projection, ReaderField};
 mod schema;
 mod util;
 
@@ -47,6 +49,7 @@ pub struct Reader<R: Read> {
     iter: Decompressor<R>,
     schema: Arc<Schema>,
     avro_schemas: Vec<AvroSchema>,
+    projection: Option<Projection>,
 }
 
 impl<R: Read> Reader<R> {
@@ -56,9 +59,23 @@ impl<R: Read> Reader<R> {
             iter,
             avro_schemas,
             schema,
+            projection: None,
         }
     }
 
+    /// Sets the [`Projection`] of the [`RecordBatch`]es read by this [`Reader`], whose
+    /// schema becomes the projection's schema.
+    pub fn with_projection(mut self, projection: Projection) -> Self {
+        self.schema = projection.schema().clone();
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Returns the [`Schema`] of the [`RecordBatch`]es read by this [`Reader`].
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
     /// Deconstructs itself into its internal reader
     pub fn into_inner(self) -> R {
         self.iter.into_inner()
@@ -71,10 +88,14 @@ impl<R: Read> Iterator for Reader<R> {
     fn next(&mut self) -> Option<Self::Item> {
         let schema = self.schema.clone();
         let avro_schemas = &self.avro_schemas;
+        let projection = self.projection.as_ref();
 
-        self.iter
-            .next()
-            .transpose()
-            .map(|maybe_block| deserialize(maybe_block?, schema, avro_schemas))
+        self.iter.next().transpose().map(|maybe_block| {
+            let block = maybe_block?;
+            match projection {
+                Some(projection) => deserialize_projected(block, projection),
+                None => deserialize(block, schema, avro_schemas),
+            }
+        })
     }
 }
@@ -28,12 +28,6 @@ impl<O: Offset> DynMutableListArray<O> {
         }
     }
 
-    /// Creates a new [`MutableListArray`] from a [`MutableArray`] and capacity.
-    pub fn new_with_capacity(values: Box<dyn MutableArray>, capacity: usize) -> Self {
-        let data_type = ListArray::<O>::default_datatype(values.data_type().clone());
-        Self::new_from(values, data_type, capacity)
-    }
-
     /// The values
     pub fn mut_values(&mut self) -> &mut dyn MutableArray {
         self.values.as_mut()
@@ -120,6 +114,17 @@ impl<O: Offset> MutableArray for DynMutableListArray<O> {
         self.push_null()
     }
 
+    fn truncate(&mut self, len: usize) {
+        if len + 1 >= self.offsets.len() {
+            return;
+        }
+        self.offsets.truncate(len + 1);
+        self.values.truncate(self.offsets[len].to_usize());
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+    }
+
     fn shrink_to_fit(&mut self) {
         todo!();
     }
@@ -195,6 +200,10 @@ impl MutableArray for FixedItemsUtf8Dictionary {
         self.push_null()
     }
 
+    fn truncate(&mut self, len: usize) {
+        self.keys.truncate(len)
+    }
+
     fn shrink_to_fit(&mut self) {
         todo!();
     }
@@ -0,0 +1,284 @@
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use avro_schema::{Enum, Field as AvroField, Fixed, Record, Schema as AvroSchema};
+use serde_json::Value;
+
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+
+use super::super::write::util::{write_binary, zigzag_encode};
+use super::deserialize::{is_nullable, non_null_variant};
+use super::{convert_schema, header::strip_defaults};
+
+/// A field of the schema that an Avro file is read into (the reader's schema).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReaderField {
+    /// The name of the field, matched against the names of the fields of the writer's schema
+    pub name: String,
+    /// The Avro schema of the field
+    pub schema: AvroSchema,
+    /// The value of the field when the writer's schema does not contain it, as JSON
+    pub default: Option<Value>,
+}
+
+/// Parses the reader's schema, an Avro record in JSON, into its [`ReaderField`]s.
+/// # Error
+/// Errors iff `schema` is not the JSON of an Avro record.
+pub fn parse_reader_schema(schema: &str) -> Result<Vec<ReaderField>> {
+    let mut schema: Value =
+        serde_json::from_str(schema).map_err(|e| ArrowError::ExternalFormat(e.to_string()))?;
+
+    let defaults = schema
+        .get_mut("fields")
+        .and_then(|fields| fields.as_array_mut())
+        .map(|fields| {
+            fields
+                .iter_mut()
+                .map(|field| field.as_object_mut().and_then(|x| x.remove("default")))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    strip_defaults(&mut schema);
+
+    match serde_json::from_value(schema).map_err(|e| ArrowError::ExternalFormat(e.to_string()))? {
+        AvroSchema::Record(Record { fields, .. }) => Ok(fields
+            .into_iter()
+            .zip(defaults)
+            .map(|(field, default)| ReaderField {
+                name: field.name,
+                schema: field.schema,
+                default,
+            })
+            .collect()),
+        other => Err(ArrowError::OutOfSpec(format!(
+            "An avro Schema must be of type Record - it is of type {:?}",
+            other
+        ))),
+    }
+}
+
+/// A resolution of the fields of the reader's schema against the fields of the writer's schema
+/// of an Avro file, following [Avro's schema resolution](https://avro.apache.org/docs/current/spec.html#Schema+Resolution):
+/// * fields of the writer's schema that are not in the reader's schema are skipped
+/// * fields of the reader's schema that are not in the writer's schema are filled with their default
+/// * `int` is promoted to `long`, `float` and `double`, `long` to `float` and `double`, `float`
+///   to `double`, and `string` and `bytes` to each other.
+///
+/// Use it via [`Reader::with_projection`](super::Reader::with_projection) or
+/// [`deserialize_projected`](super::deserialize_projected).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Projection {
+    schema: Arc<Schema>,
+    /// the Avro schemas of the reader's fields
+    pub(super) avro_schemas: Vec<AvroSchema>,
+    /// the Avro schemas of the writer's fields
+    pub(super) writer_avro_schemas: Vec<AvroSchema>,
+    /// the index of the reader's field that each writer's field is read into, if any
+    pub(super) targets: Vec<Option<usize>>,
+    /// the index and the Avro-encoded default of each reader's field missing from the writer
+    pub(super) defaults: Vec<(usize, Vec<u8>)>,
+}
+
+impl Projection {
+    /// Returns a [`Projection`] of the fields of the writer's schema, as returned by
+    /// [`read_metadata`](super::read_metadata), named `names`, in the order of `names`.
+    /// # Error
+    /// Errors iff a name is not a field of the writer's schema.
+    pub fn try_from_names(
+        schema: &Schema,
+        avro_schemas: &[AvroSchema],
+        names: &[&str],
+    ) -> Result<Self> {
+        let indices = names
+            .iter()
+            .map(|name| find_field(schema, name).ok_or_else(|| missing_field(name)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut targets = vec![None; avro_schemas.len()];
+        indices
+            .iter()
+            .enumerate()
+            .for_each(|(target, index)| targets[*index] = Some(target));
+
+        Ok(Self {
+            schema: Arc::new(Schema::new(
+                indices.iter().map(|i| schema.field(*i).clone()).collect(),
+            )),
+            avro_schemas: indices.iter().map(|i| avro_schemas[*i].clone()).collect(),
+            writer_avro_schemas: avro_schemas.to_vec(),
+            targets,
+            defaults: vec![],
+        })
+    }
+
+    /// Returns a [`Projection`] that reads the fields of the writer's schema, as returned by
+    /// [`read_metadata`](super::read_metadata), into the fields of the reader's schema.
+    /// # Error
+    /// Errors iff a field of the reader's schema is neither in the writer's schema nor has a
+    /// valid default, or its type can't be resolved from its type in the writer's schema.
+    pub fn try_new(
+        schema: &Schema,
+        avro_schemas: &[AvroSchema],
+        reader_fields: &[ReaderField],
+    ) -> Result<Self> {
+        let mut targets = vec![None; avro_schemas.len()];
+        let mut defaults = vec![];
+        for (target, field) in reader_fields.iter().enumerate() {
+            match find_field(schema, &field.name) {
+                Some(index) => {
+                    let writer = &avro_schemas[index];
+                    if !can_resolve(writer, &field.schema) {
+                        return Err(ArrowError::InvalidArgumentError(format!(
+                            "The field \"{}\" of type {:?} can't be read as type {:?}",
+                            field.name, writer, field.schema
+                        )));
+                    }
+                    targets[index] = Some(target);
+                }
+                None => {
+                    let default = field.default.as_ref().ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "The field \"{}\" is not in the writer's schema and has no default",
+                            field.name
+                        ))
+                    })?;
+                    let mut buffer = vec![];
+                    encode_default(&field.schema, default, &mut buffer)?;
+                    defaults.push((target, buffer));
+                }
+            }
+        }
+
+        let record = AvroSchema::Record(Record::new(
+            "",
+            reader_fields
+                .iter()
+                .map(|field| AvroField::new(&field.name, field.schema.clone()))
+                .collect(),
+        ));
+
+        Ok(Self {
+            schema: Arc::new(convert_schema(&record)?),
+            avro_schemas: reader_fields.iter().map(|x| x.schema.clone()).collect(),
+            writer_avro_schemas: avro_schemas.to_vec(),
+            targets,
+            defaults,
+        })
+    }
+
+    /// Returns the [`Schema`] of the [`RecordBatch`](crate::record_batch::RecordBatch)es read
+    /// with this projection.
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+}
+
+fn find_field(schema: &Schema, name: &str) -> Option<usize> {
+    schema
+        .fields()
+        .iter()
+        .position(|field| field.name() == name)
+}
+
+fn missing_field(name: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "The field \"{}\" is not in the writer's schema",
+        name
+    ))
+}
+
+/// Returns whether values written as `writer` can be read as `reader`.
+fn can_resolve(writer: &AvroSchema, reader: &AvroSchema) -> bool {
+    if is_nullable(writer) && !is_nullable(reader) {
+        return false;
+    }
+    match (non_null_variant(writer), non_null_variant(reader)) {
+        (writer, reader) if writer == reader => true,
+        (AvroSchema::Int(None), AvroSchema::Long(None))
+        | (AvroSchema::Int(None), AvroSchema::Float)
+        | (AvroSchema::Int(None), AvroSchema::Double)
+        | (AvroSchema::Long(None), AvroSchema::Float)
+        | (AvroSchema::Long(None), AvroSchema::Double)
+        | (AvroSchema::Float, AvroSchema::Double)
+        | (AvroSchema::String(_), AvroSchema::Bytes(None))
+        | (AvroSchema::Bytes(None), AvroSchema::String(_)) => true,
+        (AvroSchema::Array(writer), AvroSchema::Array(reader)) => can_resolve(writer, reader),
+        (AvroSchema::Enum(writer), AvroSchema::Enum(reader)) => writer.symbols == reader.symbols,
+        (AvroSchema::Fixed(writer), AvroSchema::Fixed(reader)) => {
+            writer.size == reader.size && writer.logical == reader.logical
+        }
+        _ => false,
+    }
+}
+
+/// Encodes the JSON `default` of a field of type `schema` as Avro, per
+/// <https://avro.apache.org/docs/current/spec.html#schema_record>.
+fn encode_default(schema: &AvroSchema, default: &Value, buffer: &mut Vec<u8>) -> Result<()> {
+    let invalid = || {
+        ArrowError::InvalidArgumentError(format!(
+            "The default {} is not a value of type {:?}",
+            default, schema
+        ))
+    };
+    // bytes and fixed defaults are strings whose code points are the bytes
+    let to_bytes = |x: &str| {
+        x.chars()
+            .map(|x| u8::try_from(x as u32))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|_| invalid())
+    };
+
+    match (schema, default) {
+        (AvroSchema::Null, Value::Null) => {}
+        (AvroSchema::Boolean, Value::Bool(value)) => buffer.push(*value as u8),
+        (AvroSchema::Int(_), Value::Number(value))
+        | (AvroSchema::Long(_), Value::Number(value)) => {
+            zigzag_encode(value.as_i64().ok_or_else(invalid)?, buffer)?
+        }
+        (AvroSchema::Float, Value::Number(value)) => {
+            buffer.extend_from_slice(&(value.as_f64().ok_or_else(invalid)? as f32).to_le_bytes())
+        }
+        (AvroSchema::Double, Value::Number(value)) => {
+            buffer.extend_from_slice(&value.as_f64().ok_or_else(invalid)?.to_le_bytes())
+        }
+        (AvroSchema::String(_), Value::String(value)) => write_binary(value.as_bytes(), buffer)?,
+        (AvroSchema::Bytes(_), Value::String(value)) => write_binary(&to_bytes(value)?, buffer)?,
+        (AvroSchema::Fixed(Fixed { size, .. }), Value::String(value)) => {
+            let value = to_bytes(value)?;
+            if value.len() != *size {
+                return Err(invalid());
+            }
+            buffer.extend_from_slice(&value)
+        }
+        (AvroSchema::Enum(Enum { symbols, .. }), Value::String(value)) => {
+            let index = symbols
+                .iter()
+                .position(|x| x == value)
+                .ok_or_else(invalid)?;
+            zigzag_encode(index as i64, buffer)?
+        }
+        (AvroSchema::Array(inner), Value::Array(values)) => {
+            if !values.is_empty() {
+                zigzag_encode(values.len() as i64, buffer)?;
+                for value in values {
+                    encode_default(inner, value, buffer)?;
+                }
+            }
+            zigzag_encode(0, buffer)?
+        }
+        // the default of a union is a value of its first variant
+        (AvroSchema::Union(schemas), default) if !schemas.is_empty() => {
+            zigzag_encode(0, buffer)?;
+            encode_default(&schemas[0], default, buffer)?
+        }
+        (AvroSchema::Record(Record { fields, .. }), Value::Object(values)) => {
+            for field in fields {
+                let value = values.get(&field.name).ok_or_else(invalid)?;
+                encode_default(&field.schema, value, buffer)?;
+            }
+        }
+        _ => return Err(invalid()),
+    };
+    Ok(())
+}
@@ -14,7 +14,7 @@ pub use serialize::{can_serialize, new_serializer, BoxSerializer};
 mod block;
 pub use block::*;
 mod compress;
-mod util;
+pub(super) mod util;
 pub use compress::compress;
 
 pub use super::{Block, CompressedBlock};
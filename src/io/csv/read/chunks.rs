@@ -0,0 +1,262 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use super::{deserialize_column, read_rows, ByteRecord, Reader};
+use crate::array::Array;
+use crate::columns::Columns;
+use crate::datatypes::Field;
+use crate::error::Result;
+
+/// A chunk of rows of a CSV file, as read by [`ChunkIterator`]. It is [`Send`] and can thus be
+/// deserialized (via [`deserialize`]) on a different thread than the one reading the file.
+#[derive(Debug, Clone)]
+pub struct RowsChunk {
+    /// The rows of this chunk
+    pub rows: Vec<ByteRecord>,
+    /// The number of rows read before this chunk, used to locate errors
+    pub line_number: usize,
+}
+
+/// An [`Iterator`] of [`RowsChunk`]s of a CSV file. Reading rows is IO-bounded and performs
+/// minimal CPU work (no deserialization).
+pub struct ChunkIterator<R: Read> {
+    reader: Reader<R>,
+    chunk_size: usize,
+    line_number: usize,
+    finished: bool,
+}
+
+impl<R: Read> ChunkIterator<R> {
+    /// Returns a new [`ChunkIterator`] reading `chunk_size` rows of `reader` at a time.
+    /// # Panics
+    /// Iff `chunk_size` is 0.
+    pub fn new(reader: Reader<R>, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "The chunk size must be larger than 0");
+        Self {
+            reader,
+            chunk_size,
+            line_number: 0,
+            finished: false,
+        }
+    }
+
+    /// Returns the inner [`Reader`].
+    pub fn into_inner(self) -> Reader<R> {
+        self.reader
+    }
+}
+
+impl<R: Read> Iterator for ChunkIterator<R> {
+    type Item = Result<RowsChunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let mut rows = vec![ByteRecord::default(); self.chunk_size];
+        let rows_read = match read_rows(&mut self.reader, 0, &mut rows) {
+            Ok(rows_read) => rows_read,
+            Err(error) => {
+                self.finished = true;
+                return Some(Err(error));
+            }
+        };
+        if rows_read < self.chunk_size {
+            self.finished = true;
+            if rows_read == 0 {
+                return None;
+            }
+        }
+        rows.truncate(rows_read);
+
+        let line_number = self.line_number;
+        self.line_number += rows_read;
+        Some(Ok(RowsChunk { rows, line_number }))
+    }
+}
+
+/// Deserializes `chunk` into [`Columns`] with the fields of `fields` (or of those in
+/// `projection`, in its order), using [`deserialize_column`].
+///
+/// This function is CPU-bounded, performs no IO, and can thus be run on a different thread than
+/// the one reading the file (e.g. via rayon).
+/// # Errors
+/// Errors iff a value can't be deserialized to the [`DataType`](crate::datatypes::DataType) of
+/// its field.
+pub fn deserialize(
+    chunk: &RowsChunk,
+    fields: &[Field],
+    projection: Option<&[usize]>,
+) -> Result<Columns<Arc<dyn Array>>> {
+    let deserialize_field = |index: usize| {
+        let field = &fields[index];
+        deserialize_column(
+            &chunk.rows,
+            index,
+            field.data_type().clone(),
+            chunk.line_number,
+        )
+        .map_err(|error| error.context(field.name(), None))
+    };
+    let arrays = match projection {
+        Some(projection) => projection
+            .iter()
+            .map(|index| deserialize_field(*index))
+            .collect::<Result<Vec<_>>>()?,
+        None => (0..fields.len())
+            .map(deserialize_field)
+            .collect::<Result<Vec<_>>>()?,
+    };
+    Columns::try_new(arrays)
+}
+
+/// Returns an [`Iterator`] of [`Columns`] of `chunk_size` rows of `reader`, composing
+/// [`ChunkIterator`] with [`deserialize`] on the current thread.
+/// # Example
+/// ```
+/// use arrow2::datatypes::{DataType, Field};
+/// use arrow2::error::Result;
+/// use arrow2::io::csv::read::{deserialize_iter, ReaderBuilder};
+///
+/// # fn main() -> Result<()> {
+/// let data = "a,b\n1,x\n2,y\n3,z\n";
+/// let reader = ReaderBuilder::new().from_reader(data.as_bytes());
+/// let fields = vec![
+///     Field::new("a", DataType::Int64, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ];
+/// let lengths = deserialize_iter(reader, 2, &fields, None)
+///     .map(|columns| columns.map(|columns| columns.len()))
+///     .collect::<Result<Vec<_>>>()?;
+/// assert_eq!(lengths, vec![2, 1]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn deserialize_iter<'a, R: Read + 'a>(
+    reader: Reader<R>,
+    chunk_size: usize,
+    fields: &'a [Field],
+    projection: Option<&'a [usize]>,
+) -> impl Iterator<Item = Result<Columns<Arc<dyn Array>>>> + 'a {
+    ChunkIterator::new(reader, chunk_size)
+        .map(move |chunk| chunk.and_then(|chunk| deserialize(&chunk, fields, projection)))
+}
+
+#[cfg(feature = "rayon")]
+pub use parallel::ParallelDeserializer;
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use std::collections::HashMap;
+    use std::sync::mpsc::{channel, Receiver, Sender};
+    use std::sync::Arc;
+
+    use super::{deserialize, RowsChunk};
+    use crate::array::Array;
+    use crate::columns::Columns;
+    use crate::datatypes::Field;
+    use crate::error::Result;
+
+    type Deserialized = (usize, Result<Columns<Arc<dyn Array>>>);
+
+    /// An [`Iterator`] that deserializes the [`RowsChunk`]s of `chunks` in parallel, using
+    /// [`rayon`]'s current thread pool, and returns the [`Columns`] in the order of `chunks`.
+    ///
+    /// At most `max_in_flight` chunks are being deserialized or waiting to be returned at any
+    /// time, which bounds the memory used when deserialization is faster than consumption.
+    ///
+    /// Since [`Iterator::next`] blocks until the next chunk is deserialized, this iterator
+    /// should be consumed outside of the thread pool that deserializes the chunks.
+    pub struct ParallelDeserializer<I: Iterator<Item = Result<RowsChunk>>> {
+        chunks: I,
+        fields: Arc<Vec<Field>>,
+        projection: Option<Arc<Vec<usize>>>,
+        max_in_flight: usize,
+        sender: Sender<Deserialized>,
+        receiver: Receiver<Deserialized>,
+        // deserialized chunks that arrived before the chunk to be returned next
+        buffer: HashMap<usize, Result<Columns<Arc<dyn Array>>>>,
+        sent: usize,
+        returned: usize,
+        exhausted: bool,
+    }
+
+    impl<I: Iterator<Item = Result<RowsChunk>>> ParallelDeserializer<I> {
+        /// Returns a new [`ParallelDeserializer`].
+        /// # Panics
+        /// Iff `max_in_flight` is 0.
+        pub fn new(
+            chunks: I,
+            fields: Vec<Field>,
+            projection: Option<Vec<usize>>,
+            max_in_flight: usize,
+        ) -> Self {
+            assert!(
+                max_in_flight > 0,
+                "The number of chunks in flight must be larger than 0"
+            );
+            let (sender, receiver) = channel();
+            Self {
+                chunks,
+                fields: Arc::new(fields),
+                projection: projection.map(Arc::new),
+                max_in_flight,
+                sender,
+                receiver,
+                buffer: HashMap::new(),
+                sent: 0,
+                returned: 0,
+                exhausted: false,
+            }
+        }
+
+        fn send(&mut self) {
+            while !self.exhausted && self.sent - self.returned < self.max_in_flight {
+                let index = self.sent;
+                match self.chunks.next() {
+                    Some(Ok(chunk)) => {
+                        let fields = self.fields.clone();
+                        let projection = self.projection.clone();
+                        let sender = self.sender.clone();
+                        rayon::spawn(move || {
+                            let columns =
+                                deserialize(&chunk, &fields, projection.as_ref().map(|x| &x[..]));
+                            // the receiver is dropped iff the iterator was dropped
+                            let _ = sender.send((index, columns));
+                        });
+                    }
+                    Some(Err(error)) => {
+                        // errors are returned in order, after the chunks read before them
+                        self.buffer.insert(index, Err(error));
+                        self.exhausted = true;
+                    }
+                    None => {
+                        self.exhausted = true;
+                        break;
+                    }
+                }
+                self.sent += 1;
+            }
+        }
+    }
+
+    impl<I: Iterator<Item = Result<RowsChunk>>> Iterator for ParallelDeserializer<I> {
+        type Item = Result<Columns<Arc<dyn Array>>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.send();
+            if self.returned == self.sent {
+                return None;
+            }
+            loop {
+                if let Some(columns) = self.buffer.remove(&self.returned) {
+                    self.returned += 1;
+                    return Some(columns);
+                }
+                // the sender is owned by `self` and thus never disconnected
+                let (index, columns) = self.receiver.recv().unwrap();
+                self.buffer.insert(index, columns);
+            }
+        }
+    }
+}
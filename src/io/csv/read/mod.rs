@@ -1,4 +1,5 @@
 //! APIs to read from CSV
+mod chunks;
 mod deserialize;
 mod reader;
 
@@ -8,6 +9,10 @@ pub use csv::{ByteRecord, Reader, ReaderBuilder};
 mod infer_schema;
 
 pub use super::utils::infer;
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub use chunks::ParallelDeserializer;
+pub use chunks::{deserialize, deserialize_iter, ChunkIterator, RowsChunk};
 pub use deserialize::{deserialize_batch, deserialize_column};
 pub use infer_schema::infer_schema;
 pub use reader::*;
@@ -18,8 +18,6 @@ use crate::{
     types::NativeType,
 };
 
-use super::utils::RFC3339;
-
 #[inline]
 fn to_utf8(bytes: &[u8]) -> Option<&str> {
     simdutf8::basic::from_utf8(bytes).ok()
@@ -128,23 +126,52 @@ fn deserialize_binary<O: Offset, B: ByteRecordGeneric>(
     Arc::new(BinaryArray::<O>::from_trusted_len_iter(iter))
 }
 
+/// Deserializes `column` of `rows` into a [`DictionaryArray`] of utf8 values, interning each value
+/// as it is parsed so that repeated values share the same key.
+#[inline]
+fn deserialize_utf8_dictionary<K: DictionaryKey, O: Offset, B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+) -> Result<Arc<dyn Array>> {
+    let mut array = MutableDictionaryArray::<K, MutableUtf8Array<O>>::new();
+    for row in rows {
+        match row.get(column).and_then(to_utf8) {
+            Some(value) => {
+                array.try_push_valid(value)?;
+            }
+            None => array.push_null(),
+        }
+    }
+    Ok(array.into_arc())
+}
+
+/// Deserializes `column` of `rows` into a [`DictionaryArray`] of binary values, interning each
+/// value as it is parsed so that repeated values share the same key.
 #[inline]
-fn deserialize_datetime<T: chrono::TimeZone>(string: &str, tz: &T) -> Option<i64> {
-    let mut parsed = chrono::format::Parsed::new();
-    let fmt = chrono::format::StrftimeItems::new(RFC3339);
-    if chrono::format::parse(&mut parsed, string, fmt).is_ok() {
-        parsed
-            .to_datetime()
-            .map(|x| x.naive_utc())
-            .map(|x| tz.from_utc_datetime(&x))
-            .map(|x| x.timestamp_nanos())
-            .ok()
-    } else {
-        None
+fn deserialize_binary_dictionary<K: DictionaryKey, O: Offset, B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+) -> Result<Arc<dyn Array>> {
+    let mut array = MutableDictionaryArray::<K, MutableBinaryArray<O>>::new();
+    for row in rows {
+        match row.get(column) {
+            Some(value) => {
+                array.try_push_valid(value)?;
+            }
+            None => array.push_null(),
+        }
     }
+    Ok(array.into_arc())
 }
 
 /// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`.
+///
+/// A `datatype` of [`DataType::Dictionary`] with (large) utf8 or binary values is deserialized
+/// directly into a [`DictionaryArray`]. The keys are only stable within `rows`: two batches
+/// deserialized separately have different dictionaries.
+/// # Errors
+/// Errors if `datatype` is not supported or if the number of distinct values of a
+/// dictionary-encoded column does not fit in its key type.
 #[inline]
 pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
     rows: &[B],
@@ -201,44 +228,16 @@ pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
         Date64 => deserialize_primitive(rows, column, datatype, |bytes| {
             to_utf8(bytes)
                 .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                .map(|x| x.timestamp_millis())
+                .map(|x| x.and_utc().timestamp_millis())
         }),
-        Timestamp(TimeUnit::Nanosecond, None) => {
-            deserialize_primitive(rows, column, datatype, |bytes| {
-                to_utf8(bytes)
-                    .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                    .map(|x| x.timestamp_nanos())
-            })
-        }
-        Timestamp(TimeUnit::Microsecond, None) => {
-            deserialize_primitive(rows, column, datatype, |bytes| {
-                to_utf8(bytes)
-                    .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                    .map(|x| x.timestamp_nanos() / 1000)
-            })
-        }
         Timestamp(time_unit, None) => deserialize_primitive(rows, column, datatype, |bytes| {
             to_utf8(bytes)
-                .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                .map(|x| x.timestamp_nanos())
-                .map(|x| match time_unit {
-                    TimeUnit::Second => x / 1_000_000_000,
-                    TimeUnit::Millisecond => x / 1_000_000,
-                    TimeUnit::Microsecond => x / 1_000,
-                    TimeUnit::Nanosecond => x,
-                })
+                .and_then(|x| temporal_conversions::utf8_to_naive_timestamp_scalar(x, time_unit))
         }),
         Timestamp(time_unit, Some(ref tz)) => {
-            let tz = temporal_conversions::parse_offset(tz)?;
+            let tz = temporal_conversions::Timezone::try_new(tz)?;
             deserialize_primitive(rows, column, datatype, |bytes| {
-                to_utf8(bytes)
-                    .and_then(|x| deserialize_datetime(x, &tz))
-                    .map(|x| match time_unit {
-                        TimeUnit::Second => x / 1_000_000_000,
-                        TimeUnit::Millisecond => x / 1_000_000,
-                        TimeUnit::Microsecond => x / 1_000,
-                        TimeUnit::Nanosecond => x,
-                    })
+                to_utf8(bytes).and_then(|x| tz.utf8_to_timestamp_scalar(x, time_unit))
             })
         }
         Decimal(precision, scale) => deserialize_primitive(rows, column, datatype, |x| {
@@ -248,6 +247,26 @@ pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
         LargeUtf8 => deserialize_utf8::<i64, _>(rows, column),
         Binary => deserialize_binary::<i32, _>(rows, column),
         LargeBinary => deserialize_binary::<i64, _>(rows, column),
+        Dictionary(key_type, ref values, _) => match values.as_ref() {
+            Utf8 => match_integer_type!(key_type, |$T| {
+                deserialize_utf8_dictionary::<$T, i32, _>(rows, column)?
+            }),
+            LargeUtf8 => match_integer_type!(key_type, |$T| {
+                deserialize_utf8_dictionary::<$T, i64, _>(rows, column)?
+            }),
+            Binary => match_integer_type!(key_type, |$T| {
+                deserialize_binary_dictionary::<$T, i32, _>(rows, column)?
+            }),
+            LargeBinary => match_integer_type!(key_type, |$T| {
+                deserialize_binary_dictionary::<$T, i64, _>(rows, column)?
+            }),
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Deserializing type \"{:?}\" is not implemented",
+                    datatype
+                )))
+            }
+        },
         other => {
             return Err(ArrowError::NotYetImplemented(format!(
                 "Deserializing type \"{:?}\" is not implemented",
@@ -289,6 +308,7 @@ where
             let field = &fields[column];
             let data_type = field.data_type();
             deserialize_column(rows, column, data_type.clone(), line_number)
+                .map_err(|error| error.context(field.name(), None))
         })
         .collect::<Result<Vec<_>>>()?;
 
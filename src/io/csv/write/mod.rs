@@ -1,7 +1,7 @@
 //! APIs to write to CSV
 mod serialize;
 
-use super::super::iterator::StreamingIterator;
+use super::super::iterator::{BufStreamingIterator, StreamingIterator};
 
 use std::io::Write;
 
@@ -10,27 +10,56 @@ pub use csv::{ByteRecord, Writer, WriterBuilder};
 
 pub use serialize::*;
 
+use crate::array::Array;
+use crate::columns::Columns;
+use crate::error::ArrowError;
 use crate::record_batch::RecordBatch;
 use crate::{datatypes::Schema, error::Result};
 
+/// A serializer of a column that overrides its built-in serializer: it writes the value
+/// of the array at the given row to the buffer.
+pub type CustomSerializer = Box<dyn Fn(&dyn Array, usize, &mut Vec<u8>)>;
+
 /// Creates serializers that iterate over each column of `batch` and serialize each item according
-/// to `options`.
+/// to `options`, or according to the entry of `custom` of the column when it is `Some`.
 fn new_serializers<'a>(
     batch: &'a RecordBatch,
     options: &'a SerializeOptions,
+    custom: &'a [Option<CustomSerializer>],
 ) -> Result<Vec<Box<dyn StreamingIterator<Item = [u8]> + 'a>>> {
     batch
         .columns()
         .iter()
-        .map(|column| new_serializer(column.as_ref(), options))
+        .enumerate()
+        .map(|(index, column)| match custom.get(index) {
+            Some(Some(serializer)) => Ok(new_custom_serializer(column.as_ref(), serializer)),
+            _ => new_serializer(column.as_ref(), options),
+        })
         .collect()
 }
 
+/// Creates a serializer that calls `serializer` on every non-null row of `array`; null rows
+/// are written as by the built-in serializers, i.e. as an empty field.
+fn new_custom_serializer<'a>(
+    array: &'a dyn Array,
+    serializer: &'a CustomSerializer,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a> {
+    Box::new(BufStreamingIterator::new(
+        0..array.len(),
+        move |index, buf| {
+            if array.is_valid(index) {
+                serializer(array, index, buf)
+            }
+        },
+        vec![],
+    ))
+}
+
 /// Serializes a [`RecordBatch`] as vector of `ByteRecord`.
 /// The vector is guaranteed to have `batch.num_rows()` entries.
 /// Each `ByteRecord` is guaranteed to have `batch.num_columns()` fields.
 pub fn serialize(batch: &RecordBatch, options: &SerializeOptions) -> Result<Vec<ByteRecord>> {
-    let mut serializers = new_serializers(batch, options)?;
+    let mut serializers = new_serializers(batch, options, &[])?;
 
     let mut records = vec![ByteRecord::with_capacity(0, batch.num_columns()); batch.num_rows()];
     records.iter_mut().for_each(|record| {
@@ -48,24 +77,122 @@ pub fn write_batch<W: Write>(
     batch: &RecordBatch,
     options: &SerializeOptions,
 ) -> Result<()> {
-    let mut serializers = new_serializers(batch, options)?;
+    let serializers = new_serializers(batch, options, &[])?;
+    write_rows(writer, batch.num_rows(), serializers)
+}
 
-    let mut record = ByteRecord::with_capacity(0, batch.num_columns());
+/// Writes the data in a `RecordBatch` to `writer`, where the column `i` is serialized by
+/// `serializers[i]` when it is `Some` and according to `options` otherwise.
+///
+/// A custom serializer is only called for the non-null rows of its column: nulls are
+/// written as by the built-in serializers, i.e. as an empty field.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Float64Array, Int32Array};
+/// use arrow2::io::csv::write::{write_batch_with_serializers, SerializeOptions, WriterBuilder};
+/// use arrow2::record_batch::RecordBatch;
+///
+/// let batch = RecordBatch::try_from_iter(vec![
+///     ("a", Arc::new(Float64Array::from(&[Some(1.0), None, Some(0.125)])) as Arc<dyn Array>),
+///     ("b", Arc::new(Int32Array::from_slice(&[1, 2, 3])) as Arc<dyn Array>),
+/// ]).unwrap();
+///
+/// // two decimal places for the column "a", the default for the column "b"
+/// let serializers = vec![
+///     Some(Box::new(|array: &dyn Array, index: usize, buf: &mut Vec<u8>| {
+///         let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+///         buf.extend_from_slice(format!("{:.2}", array.value(index)).as_bytes())
+///     }) as _),
+///     None,
+/// ];
+///
+/// let mut writer = WriterBuilder::new().from_writer(vec![]);
+/// write_batch_with_serializers(&mut writer, &batch, &SerializeOptions::default(), &serializers)
+///     .unwrap();
+/// let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+/// assert_eq!(csv, "1.00,1\n,2\n0.12,3\n");
+/// ```
+/// # Errors
+/// This function errors iff the number of `serializers` differs from the number of columns
+/// of `batch` or iff a column without custom serializer can't be serialized.
+pub fn write_batch_with_serializers<W: Write>(
+    writer: &mut Writer<W>,
+    batch: &RecordBatch,
+    options: &SerializeOptions,
+    serializers: &[Option<CustomSerializer>],
+) -> Result<()> {
+    if serializers.len() != batch.num_columns() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "There must be one (optional) serializer per column: got {} serializers for {} columns",
+            serializers.len(),
+            batch.num_columns()
+        )));
+    }
+    let serializers = new_serializers(batch, options, serializers)?;
+    write_rows(writer, batch.num_rows(), serializers)
+}
 
+/// Writes `columns` to `writer`, where each column is serialized according to the data type of
+/// its field in `schema` and `options` (e.g. an [`Int32Array`](crate::array::Int32Array) of a
+/// field of type [`DataType::Date32`](crate::datatypes::DataType::Date32) is written as dates).
+/// Use [`Schema::try_from_columns`] to derive the schema from `columns` and [`write_header`]
+/// to write its names.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::columns::Columns;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::io::csv::write::{write_columns, write_header, SerializeOptions, WriterBuilder};
+///
+/// let columns = Columns::new(vec![Box::new(Int32Array::from_slice(&[0, 1])) as Box<dyn Array>]);
+/// let schema = Schema::new(vec![Field::new("date", DataType::Date32, false)]);
+///
+/// let mut writer = WriterBuilder::new().from_writer(vec![]);
+/// write_header(&mut writer, &schema).unwrap();
+/// write_columns(&mut writer, &schema, &columns, &SerializeOptions::default()).unwrap();
+/// let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+/// assert_eq!(csv, "date\n1970-01-01 00:00:00\n1970-01-02 00:00:00\n");
+/// ```
+/// # Errors
+/// This function errors iff the number of fields of `schema` differs from the number of
+/// columns, iff the physical type of a field differs from the one of its array or iff a
+/// column can't be serialized.
+pub fn write_columns<W: Write, A: AsRef<dyn Array>>(
+    writer: &mut Writer<W>,
+    schema: &Schema,
+    columns: &Columns<A>,
+    options: &SerializeOptions,
+) -> Result<()> {
+    super::super::check_columns(schema, columns)?;
+    let serializers = schema
+        .fields()
+        .iter()
+        .zip(columns.arrays())
+        .map(|(field, array)| new_typed_serializer(array.as_ref(), field.data_type(), options))
+        .collect::<Result<Vec<_>>>()?;
+    write_rows(writer, columns.len(), serializers)
+}
+
+fn write_rows<W: Write>(
+    writer: &mut Writer<W>,
+    num_rows: usize,
+    mut serializers: Vec<Box<dyn StreamingIterator<Item = [u8]> + '_>>,
+) -> Result<()> {
     // this is where the (expensive) transposition happens: the outer loop is on rows, the inner on columns
-    (0..batch.num_rows()).try_for_each(|_| {
+    (0..num_rows).try_for_each(|_| {
         serializers
             .iter_mut()
             // `unwrap` is infalible because `array.len()` equals `num_rows` on a `RecordBatch`
-            .for_each(|iter| record.push_field(iter.next().unwrap()));
-        writer.write_byte_record(&record)?;
-        record.clear();
+            .try_for_each(|iter| writer.write_field(iter.next().unwrap()))?;
+        // an empty record terminates the fields written above
+        writer.write_record(None::<&[u8]>)?;
         Result::Ok(())
     })?;
     Ok(())
 }
 
-/// Writes a header to `writer` according to `schema`
+/// Writes a header to `writer` with the names of the fields of `schema`
 pub fn write_header<W: Write>(writer: &mut Writer<W>, schema: &Schema) -> Result<()> {
     let fields = schema
         .fields()
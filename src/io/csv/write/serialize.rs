@@ -1,18 +1,19 @@
 use lexical_core::ToLexical;
 
-use crate::datatypes::IntegerType;
 use crate::temporal_conversions;
 use crate::types::NativeType;
 use crate::util::lexical_to_bytes_mut;
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
+    array::{
+        get_display, Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey,
+        PrimitiveArray, Utf8Array,
+    },
     datatypes::{DataType, TimeUnit},
     error::Result,
 };
 
 use super::super::super::iterator::{BufStreamingIterator, StreamingIterator};
-use crate::array::{DictionaryArray, DictionaryKey, Offset};
-use std::any::Any;
+use std::io::Write;
 
 /// Options to serialize logical types to CSV
 /// The default is to format times and dates as `chrono` crate formats them.
@@ -51,6 +52,7 @@ macro_rules! dyn_primitive {
     }};
 }
 
+// dates and times are formatted directly into the buffer, which is infallible
 macro_rules! dyn_date {
     ($ty:ident, $fn:expr, $array:expr, $format:expr) => {{
         let array = $array
@@ -62,7 +64,7 @@ macro_rules! dyn_date {
                 array.iter(),
                 move |x, buf| {
                     if let Some(x) = x {
-                        buf.extend_from_slice(($fn)(*x).format(format).to_string().as_bytes())
+                        let _ = write!(buf, "{}", ($fn)(*x).format(format));
                     }
                 },
                 vec![],
@@ -72,7 +74,7 @@ macro_rules! dyn_date {
                 array.iter(),
                 move |x, buf| {
                     if let Some(x) = x {
-                        buf.extend_from_slice(($fn)(*x).to_string().as_bytes())
+                        let _ = write!(buf, "{}", ($fn)(*x));
                     }
                 },
                 vec![],
@@ -93,9 +95,8 @@ fn timestamp_with_tz_default<'a>(
             move |x, buf| {
                 if let Some(x) = x {
                     let data =
-                        temporal_conversions::timestamp_to_datetime(*x, time_unit, &timezone)
-                            .to_string();
-                    buf.extend_from_slice(data.as_bytes())
+                        temporal_conversions::timestamp_to_datetime(*x, time_unit, &timezone);
+                    let _ = write!(buf, "{}", data);
                 }
             },
             vec![],
@@ -108,9 +109,8 @@ fn timestamp_with_tz_default<'a>(
                 move |x, buf| {
                     if let Some(x) = x {
                         let data =
-                            temporal_conversions::timestamp_to_datetime(*x, time_unit, &timezone)
-                                .to_string();
-                        buf.extend_from_slice(data.as_bytes())
+                            temporal_conversions::timestamp_to_datetime(*x, time_unit, &timezone);
+                        let _ = write!(buf, "{}", data);
                     }
                 },
                 vec![],
@@ -139,10 +139,8 @@ fn timestamp_with_tz_with_format<'a>(
             move |x, buf| {
                 if let Some(x) = x {
                     let data =
-                        temporal_conversions::timestamp_to_datetime(*x, time_unit, &timezone)
-                            .format(format)
-                            .to_string();
-                    buf.extend_from_slice(data.as_bytes())
+                        temporal_conversions::timestamp_to_datetime(*x, time_unit, &timezone);
+                    let _ = write!(buf, "{}", data.format(format));
                 }
             },
             vec![],
@@ -155,10 +153,8 @@ fn timestamp_with_tz_with_format<'a>(
                 move |x, buf| {
                     if let Some(x) = x {
                         let data =
-                            temporal_conversions::timestamp_to_datetime(*x, time_unit, &timezone)
-                                .format(format)
-                                .to_string();
-                        buf.extend_from_slice(data.as_bytes())
+                            temporal_conversions::timestamp_to_datetime(*x, time_unit, &timezone);
+                        let _ = write!(buf, "{}", data.format(format));
                     }
                 },
                 vec![],
@@ -194,13 +190,26 @@ fn timestamp_with_tz<'a>(
 /// * numeric types (i.e. floats, int, uint)
 /// * times and dates
 /// * naive timestamps (timestamps without timezone information)
+/// * dictionaries, whose values are serialized according to their own type
+///
+/// All other logical types are serialized using [`get_display`].
 /// # Error
-/// This function errors if any of the logical types in `batch` is not supported.
+/// This function errors if the timezone of a timestamp is not supported.
 pub fn new_serializer<'a>(
     array: &'a dyn Array,
     options: &'a SerializeOptions,
 ) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
-    Ok(match array.data_type() {
+    new_typed_serializer(array, array.data_type(), options)
+}
+
+/// Returns a [`StreamingIterator`] like [`new_serializer`], that serializes `array` as if it
+/// were of `data_type` (e.g. integers as dates). `data_type` must have the physical type of `array`.
+pub(super) fn new_typed_serializer<'a>(
+    array: &'a dyn Array,
+    data_type: &'a DataType,
+    options: &'a SerializeOptions,
+) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
+    Ok(match data_type.to_logical_type() {
         DataType::Boolean => {
             let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
             Box::new(BufStreamingIterator::new(
@@ -383,49 +392,47 @@ pub fn new_serializer<'a>(
                 vec![],
             ))
         }
-        DataType::Dictionary(keys_dt, values_dt, _) => match &**values_dt {
-            DataType::LargeUtf8 => match *keys_dt {
-                IntegerType::UInt32 => serialize_utf8_dict::<u32, i64>(array.as_any()),
-                IntegerType::UInt64 => serialize_utf8_dict::<u64, i64>(array.as_any()),
-                _ => todo!(),
-            },
-            DataType::Utf8 => match *keys_dt {
-                IntegerType::UInt32 => serialize_utf8_dict::<u32, i32>(array.as_any()),
-                IntegerType::UInt64 => serialize_utf8_dict::<u64, i32>(array.as_any()),
-                _ => todo!(),
-            },
-            _ => {
-                panic!("only dictionary with string values are supported by csv writer")
-            }
-        },
-        dt => panic!("data type: {:?} not supported by csv writer", dt),
+        DataType::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            serialize_dictionary::<$T>(array.as_any().downcast_ref().unwrap(), options)?
+        }),
+        _ => serialize_display(array),
     })
 }
 
-/// Helper for serializing a dictonary array. The generic parameters are:
-/// - `K` for the type of the keys of the dictionary
-/// - `O` for the type of the offsets in the Utf8Array: {i32, i64}
-fn serialize_utf8_dict<'a, K: DictionaryKey, O: Offset>(
-    array: &'a dyn Any,
-) -> Box<dyn StreamingIterator<Item = [u8]> + 'a> {
-    let array = array.downcast_ref::<DictionaryArray<K>>().unwrap();
-    let keys = array.keys();
-    let values = array
-        .values()
-        .as_any()
-        .downcast_ref::<Utf8Array<O>>()
-        .unwrap();
+/// Serializes any array using its [`get_display`] representation. Nulls are serialized as empty.
+fn serialize_display<'a>(array: &'a dyn Array) -> Box<dyn StreamingIterator<Item = [u8]> + 'a> {
+    let display = get_display(array);
     Box::new(BufStreamingIterator::new(
-        keys.iter(),
-        move |x, buf| {
-            if let Some(x) = x {
-                let i = x.to_usize().unwrap();
-                if !values.is_null(i) {
-                    let val = values.value(i);
-                    buf.extend_from_slice(val.as_bytes());
-                }
+        0..array.len(),
+        move |i, buf| buf.extend_from_slice(display(i).as_bytes()),
+        vec![],
+    ))
+}
+
+/// Serializes a [`DictionaryArray`] by looking up the serialized value of each key.
+/// Every value of the dictionary is serialized once (according to its own type), so that
+/// serializing a row only copies the bytes of its value. Null keys are serialized as empty.
+fn serialize_dictionary<'a, K: DictionaryKey>(
+    array: &'a DictionaryArray<K>,
+    options: &'a SerializeOptions,
+) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
+    let mut serializer = new_serializer(array.values().as_ref(), options)?;
+    let mut values = vec![];
+    let mut offsets = Vec::with_capacity(array.values().len() + 1);
+    offsets.push(0);
+    while let Some(value) = serializer.next() {
+        values.extend_from_slice(value);
+        offsets.push(values.len());
+    }
+
+    Ok(Box::new(BufStreamingIterator::new(
+        array.keys().iter(),
+        move |key, buf| {
+            if let Some(key) = key {
+                let index = key.to_usize().unwrap();
+                buf.extend_from_slice(&values[offsets[index]..offsets[index + 1]]);
             }
         },
         vec![],
-    ))
+    )))
 }
@@ -8,7 +8,7 @@ use crate::{
     error::{ArrowError, Result},
     io::ipc::read,
     io::ipc::write,
-    io::ipc::write::common::{encode_columns, DictionaryTracker, EncodedData, WriteOptions},
+    io::ipc::write::{columns_to_encoded_data, EncodedData, WriteOptions},
     record_batch::RecordBatch,
 };
 
@@ -16,22 +16,20 @@ use super::ipc::{IpcField, IpcSchema};
 
 /// Serializes a [`RecordBatch`] to a vector of [`FlightData`] representing the serialized dictionaries
 /// and a [`FlightData`] representing the batch.
+/// # Errors
+/// Errors iff the body of a message exceeds `options.max_message_size`.
 pub fn serialize_batch(
     batch: &RecordBatch,
     fields: &[IpcField],
     options: &WriteOptions,
-) -> (Vec<FlightData>, FlightData) {
-    let mut dictionary_tracker = DictionaryTracker::new(false);
-
+) -> Result<(Vec<FlightData>, FlightData)> {
     let columns = batch.clone().into();
-    let (encoded_dictionaries, encoded_batch) =
-        encode_columns(&columns, fields, &mut dictionary_tracker, options)
-            .expect("DictionaryTracker configured above to not error on replacement");
+    let (encoded_dictionaries, encoded_batch) = columns_to_encoded_data(&columns, fields, options)?;
 
     let flight_dictionaries = encoded_dictionaries.into_iter().map(Into::into).collect();
     let flight_batch = encoded_batch.into();
 
-    (flight_dictionaries, flight_batch)
+    Ok((flight_dictionaries, flight_batch))
 }
 
 impl From<EncodedData> for FlightData {
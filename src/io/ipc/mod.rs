@@ -38,12 +38,12 @@
 //! # use arrow2::record_batch::RecordBatch;
 //! # use arrow2::error::ArrowError;
 //! // Setup the writer
-//! let path = "example.arrow".to_string();
+//! let path = std::env::temp_dir().join("example.arrow");
 //! let mut file = File::create(&path)?;
 //! let x_coord = Field::new("x", DataType::Int32, false);
 //! let y_coord = Field::new("y", DataType::Int32, false);
 //! let schema = Schema::new(vec![x_coord, y_coord]);
-//! let options = WriteOptions {compression: None};
+//! let options = WriteOptions {compression: None, ..Default::default()};
 //! let mut writer = FileWriter::try_new(file, &schema, None, options)?;
 //!
 //! // Setup the data
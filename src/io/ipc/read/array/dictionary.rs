@@ -5,6 +5,7 @@ use std::io::{Read, Seek};
 use arrow_format::ipc;
 
 use crate::array::{DictionaryArray, DictionaryKey};
+use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 
 use super::super::deserialize::Node;
@@ -14,6 +15,7 @@ use super::{read_primitive, skip_primitive};
 #[allow(clippy::too_many_arguments)]
 pub fn read_dictionary<T: DictionaryKey, R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
+    data_type: DataType,
     id: Option<i64>,
     buffers: &mut VecDeque<&ipc::Schema::Buffer>,
     reader: &mut R,
@@ -51,7 +53,8 @@ where
         compression,
     )?;
 
-    Ok(DictionaryArray::<T>::from_data(keys, values))
+    let is_ordered = DictionaryArray::<T>::get_is_ordered(&data_type);
+    Ok(DictionaryArray::<T>::from_data(keys, values).with_is_ordered(is_ordered))
 }
 
 pub fn skip_dictionary(
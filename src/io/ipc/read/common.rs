@@ -6,6 +6,7 @@ use arrow_format::ipc;
 use arrow_format::ipc::Schema::MetadataVersion;
 
 use crate::array::*;
+use crate::columns::Columns;
 use crate::datatypes::{DataType, Field, Schema};
 use crate::error::{ArrowError, Result};
 use crate::io::ipc::{IpcField, IpcSchema};
@@ -268,6 +269,65 @@ pub fn read_dictionary<R: Read + Seek>(
     Ok(())
 }
 
+/// Decodes an IPC message from its `header` (an `ipc::Message` flatbuffer) and its `body`, as
+/// produced by [`columns_to_encoded_data`](crate::io::ipc::write::columns_to_encoded_data) or
+/// received in a Flight `FlightData`, without any stream framing.
+///
+/// Dictionary batches are decoded into `dictionaries` and return `None`; record batches
+/// (whose dictionaries must have been decoded before) return `Some` of their columns.
+/// # Errors
+/// Errors iff the message is not a dictionary nor a record batch, or is not valid with
+/// respect to `schema`.
+pub fn decode_message(
+    header: &[u8],
+    body: &[u8],
+    schema: &Arc<Schema>,
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut Dictionaries,
+) -> Result<Option<Columns<ArrayRef>>> {
+    let message = ipc::Message::root_as_message(header).map_err(|err| {
+        ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+    })?;
+    let mut reader = std::io::Cursor::new(body);
+
+    match message.header_type() {
+        ipc::Message::MessageHeader::RecordBatch => {
+            let batch = message.header_as_record_batch().ok_or_else(|| {
+                ArrowError::OutOfSpec("Unable to read IPC message as record batch".to_string())
+            })?;
+            read_record_batch(
+                batch,
+                schema.clone(),
+                ipc_schema,
+                None,
+                dictionaries,
+                message.version(),
+                &mut reader,
+                0,
+            )
+            .map(|batch| Some(batch.into()))
+        }
+        ipc::Message::MessageHeader::DictionaryBatch => {
+            let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                ArrowError::OutOfSpec("Unable to read IPC message as dictionary batch".to_string())
+            })?;
+            read_dictionary(
+                batch,
+                schema.fields(),
+                ipc_schema,
+                dictionaries,
+                &mut reader,
+                0,
+            )?;
+            Ok(None)
+        }
+        t => Err(ArrowError::OutOfSpec(format!(
+            "Decoding messages other than record and dictionary batches is not supported, unable to decode {:?}",
+            t
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -17,7 +17,7 @@ mod reader;
 mod schema;
 mod stream;
 
-pub use common::{read_dictionary, read_record_batch};
+pub use common::{decode_message, read_dictionary, read_record_batch};
 pub use reader::{read_file_metadata, FileMetadata, FileReader};
 pub use schema::fb_to_schema;
 pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
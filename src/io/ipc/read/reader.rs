@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::io::{Read, Seek, SeekFrom};
 use std::sync::Arc;
 
@@ -26,10 +27,16 @@ pub struct FileMetadata {
     /// The blocks in the file
     ///
     /// A block indicates the regions in the file to read to get data
-    blocks: Vec<ipc::File::Block>,
+    pub(crate) blocks: Vec<ipc::File::Block>,
+
+    /// The blocks of the dictionaries in the file
+    pub(crate) dictionary_blocks: Vec<ipc::File::Block>,
 
     /// Dictionaries associated to each dict_id
-    dictionaries: Dictionaries,
+    pub(crate) dictionaries: Dictionaries,
+
+    /// The custom metadata of the file's footer
+    custom_metadata: HashMap<String, String>,
 
     /// FileMetadata version
     version: ipc::Schema::MetadataVersion,
@@ -40,6 +47,12 @@ impl FileMetadata {
     pub fn schema(&self) -> &Arc<Schema> {
         &self.schema
     }
+
+    /// Returns the custom metadata of the file's footer. This is distinct from the metadata
+    /// of its schema.
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.custom_metadata
+    }
 }
 
 /// Arrow File reader
@@ -169,19 +182,25 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
     let (schema, ipc_schema) = fb_to_schema(ipc_schema)?;
     let schema = Arc::new(schema);
 
-    let dictionary_blocks = footer.dictionaries();
+    let dictionary_blocks = footer.dictionaries().unwrap_or_default();
+    let dictionaries = read_dictionaries(reader, &schema, &ipc_schema, dictionary_blocks)?;
 
-    let dictionaries = if let Some(blocks) = dictionary_blocks {
-        read_dictionaries(reader, &schema, &ipc_schema, blocks)?
-    } else {
-        Default::default()
-    };
+    let custom_metadata = footer
+        .custom_metadata()
+        .map(|list| {
+            list.iter()
+                .filter_map(|kv| Some((kv.key()?.to_string(), kv.value()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
 
     Ok(FileMetadata {
         schema,
         ipc_schema,
         blocks: blocks.to_vec(),
+        dictionary_blocks: dictionary_blocks.to_vec(),
         dictionaries,
+        custom_metadata,
         version: footer.version(),
     })
 }
@@ -8,7 +8,7 @@ mod ipc {
 
 use crate::{
     datatypes::{
-        get_extension, DataType, Extension, Field, IntegerType, IntervalUnit, Metadata, Schema,
+        remove_extension, DataType, Extension, Field, IntegerType, IntervalUnit, Metadata, Schema,
         TimeUnit, UnionMode,
     },
     error::{ArrowError, Result},
@@ -29,9 +29,9 @@ fn try_unzip_vec<A, B, I: Iterator<Item = Result<(A, B)>>>(iter: I) -> Result<(V
 }
 
 fn deserialize_field(ipc_field: ipc::Field) -> Result<(Field, IpcField)> {
-    let metadata = read_metadata(&ipc_field);
+    let mut metadata = read_metadata(&ipc_field);
 
-    let extension = get_extension(&metadata);
+    let extension = remove_extension(&mut metadata);
 
     let (data_type, ipc_field_) = get_data_type(ipc_field, extension, true)?;
 
@@ -80,6 +80,45 @@ fn deserialize_integer(int: ipc::Int) -> Result<IntegerType> {
     })
 }
 
+/// The maximum precision of a 128-bit decimal
+const MAX_DECIMAL128_PRECISION: i32 = 38;
+
+fn deserialize_decimal(decimal: ipc::Decimal) -> Result<DataType> {
+    // the bit width determines the size of each value in the body, and thus the offsets of
+    // every other buffer, so decimals of unsupported widths can't be read
+    match decimal.bitWidth() {
+        128 => {}
+        256 => {
+            return Err(ArrowError::nyi(format!(
+                "Reading 256-bit decimals (with precision {} and scale {})",
+                decimal.precision(),
+                decimal.scale()
+            )))
+        }
+        bit_width => {
+            return Err(ArrowError::oos(format!(
+                "IPC: Decimal bit width must be 128 or 256, got {}",
+                bit_width
+            )))
+        }
+    }
+    let precision = decimal.precision();
+    if !(1..=MAX_DECIMAL128_PRECISION).contains(&precision) {
+        return Err(ArrowError::oos(format!(
+            "IPC: The precision of a 128-bit Decimal must be between 1 and {}, got {}",
+            MAX_DECIMAL128_PRECISION, precision
+        )));
+    }
+    let scale = decimal.scale();
+    if scale < 0 {
+        return Err(ArrowError::nyi(format!(
+            "Decimals with a negative scale ({})",
+            scale
+        )));
+    }
+    Ok(DataType::Decimal(precision as usize, scale as usize))
+}
+
 /// Get the Arrow data type from the flatbuffer Field table
 fn get_data_type(
     field: ipc::Field,
@@ -234,11 +273,10 @@ fn get_data_type(
             (data_type, IpcField::default())
         }
         ipc::Type::Decimal => {
-            let fsb = field
+            let decimal = field
                 .type_as_decimal()
                 .ok_or_else(|| ArrowError::oos("IPC: Decimal type must be a Decimal"))?;
-            let data_type = DataType::Decimal(fsb.precision() as usize, fsb.scale() as usize);
-            (data_type, IpcField::default())
+            (deserialize_decimal(decimal)?, IpcField::default())
         }
         ipc::Type::List => {
             let children = field
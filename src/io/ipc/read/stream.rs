@@ -1,3 +1,4 @@
+use std::convert::TryInto;
 use std::io::Read;
 use std::sync::Arc;
 
@@ -24,7 +25,10 @@ pub struct StreamMetadata {
     pub ipc_schema: IpcSchema,
 }
 
-/// Reads the metadata of the stream
+/// Reads the metadata of the stream.
+///
+/// Unlike [`StreamReader`], this function blocks until the schema message is read, and errors
+/// if `reader` returns [`std::io::ErrorKind::WouldBlock`] or ends before it.
 pub fn read_stream_metadata<R: Read>(reader: &mut R) -> Result<StreamMetadata> {
     // determine metadata length
     let mut meta_size: [u8; 4] = [0; 4];
@@ -90,108 +94,160 @@ impl StreamState {
     }
 }
 
+/// The bytes of the message being read, of which the first `filled` were read.
+#[derive(Default)]
+struct MessageBuffer {
+    data: Vec<u8>,
+    filled: usize,
+}
+
+impl MessageBuffer {
+    /// Reads from `reader` until the buffer has `length` bytes, returning whether it has.
+    ///
+    /// Returns `Ok(false)` when `reader` has no more bytes available, either because it would
+    /// block or because it reached its end, in which case the bytes read so far are retained so
+    /// that reading can resume once more bytes are available.
+    fn fill<R: Read>(&mut self, reader: &mut R, length: usize) -> Result<bool> {
+        if self.data.len() < length {
+            self.data.resize(length, 0);
+        }
+        while self.filled < length {
+            match reader.read(&mut self.data[self.filled..length]) {
+                Ok(0) => return Ok(false),
+                Ok(read) => self.filled += read,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => return Ok(false),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(true)
+    }
+
+    /// The bytes read so far
+    fn filled(&self) -> &[u8] {
+        &self.data[..self.filled]
+    }
+
+    /// Declares the message as consumed. The allocation is kept for the next message.
+    fn clear(&mut self) {
+        self.filled = 0;
+    }
+}
+
 /// Reads the next item, yielding `None` if the stream is done,
 /// and a [`StreamState`] otherwise.
+///
+/// `buffer` contains the bytes of the message being read, and may contain a partial message
+/// when [`StreamState::Waiting`] is returned.
 fn read_next<R: Read>(
     reader: &mut R,
     metadata: &StreamMetadata,
     dictionaries: &mut Dictionaries,
-    message_buffer: &mut Vec<u8>,
-    data_buffer: &mut Vec<u8>,
+    buffer: &mut MessageBuffer,
 ) -> Result<Option<StreamState>> {
-    // determine metadata length
-    let mut meta_length: [u8; 4] = [0; 4];
-
-    match reader.read_exact(&mut meta_length) {
-        Ok(()) => (),
-        Err(e) => {
-            return if e.kind() == std::io::ErrorKind::UnexpectedEof {
-                // Handle EOF without the "0xFFFFFFFF 0x00000000"
-                // valid according to:
-                // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
-                Ok(Some(StreamState::Waiting))
-            } else {
-                Err(ArrowError::from(e))
-            };
+    loop {
+        // determine metadata length
+        // Handle EOF without the "0xFFFFFFFF 0x00000000"
+        // valid according to:
+        // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+        if !buffer.fill(reader, 4)? {
+            return Ok(Some(StreamState::Waiting));
         }
-    }
-
-    let meta_length = {
         // If a continuation marker is encountered, skip over it and read
         // the size from the next four bytes.
-        if meta_length == CONTINUATION_MARKER {
-            reader.read_exact(&mut meta_length)?;
-        }
-        i32::from_le_bytes(meta_length) as usize
-    };
-
-    if meta_length == 0 {
-        // the stream has ended, mark the reader as finished
-        return Ok(None);
-    }
-
-    message_buffer.clear();
-    message_buffer.resize(meta_length, 0);
-    reader.read_exact(message_buffer)?;
-
-    let message = ipc::Message::root_as_message(message_buffer).map_err(|err| {
-        ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
-    })?;
-
-    match message.header_type() {
-        ipc::Message::MessageHeader::Schema => Err(ArrowError::OutOfSpec(
-            "Not expecting a schema when messages are read".to_string(),
-        )),
-        ipc::Message::MessageHeader::RecordBatch => {
-            let batch = message.header_as_record_batch().ok_or_else(|| {
-                ArrowError::OutOfSpec("Unable to read IPC message as record batch".to_string())
-            })?;
-            // read the block that makes up the record batch into a buffer
-            data_buffer.clear();
-            data_buffer.resize(message.bodyLength() as usize, 0);
-            reader.read_exact(data_buffer)?;
-
-            let mut reader = std::io::Cursor::new(data_buffer);
+        let prefix_length = if buffer.filled()[..4] == CONTINUATION_MARKER {
+            if !buffer.fill(reader, 8)? {
+                return Ok(Some(StreamState::Waiting));
+            }
+            8
+        } else {
+            4
+        };
+        let meta_length = i32::from_le_bytes(
+            buffer.filled()[prefix_length - 4..prefix_length]
+                .try_into()
+                .unwrap(),
+        );
+        let meta_length: usize = meta_length
+            .try_into()
+            .map_err(|_| ArrowError::oos("The length of an IPC message must be positive"))?;
 
-            read_record_batch(
-                batch,
-                metadata.schema.clone(),
-                &metadata.ipc_schema,
-                None,
-                dictionaries,
-                metadata.version,
-                &mut reader,
-                0,
-            )
-            .map(|x| Some(StreamState::Some(x)))
+        if meta_length == 0 {
+            // the stream has ended, mark the reader as finished
+            buffer.clear();
+            return Ok(None);
         }
-        ipc::Message::MessageHeader::DictionaryBatch => {
-            let batch = message.header_as_dictionary_batch().ok_or_else(|| {
-                ArrowError::OutOfSpec("Unable to read IPC message as dictionary batch".to_string())
-            })?;
-            // read the block that makes up the dictionary batch into a buffer
-            let mut buf = vec![0; message.bodyLength() as usize];
-            reader.read_exact(&mut buf)?;
 
-            let mut dict_reader = std::io::Cursor::new(buf);
+        let message_end = prefix_length + meta_length;
+        if !buffer.fill(reader, message_end)? {
+            return Ok(Some(StreamState::Waiting));
+        }
+        let body_length = {
+            let message =
+                ipc::Message::root_as_message(&buffer.filled()[prefix_length..message_end])
+                    .map_err(|err| {
+                        ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+                    })?;
+            message.bodyLength() as usize
+        };
+        // read the block that makes up the batch into the buffer
+        if !buffer.fill(reader, message_end + body_length)? {
+            return Ok(Some(StreamState::Waiting));
+        }
 
-            read_dictionary(
-                batch,
-                metadata.schema.fields(),
-                &metadata.ipc_schema,
-                dictionaries,
-                &mut dict_reader,
-                0,
-            )?;
+        let (header, body) = buffer.filled().split_at(message_end);
+        let message = ipc::Message::root_as_message(&header[prefix_length..]).unwrap();
+        let mut body = std::io::Cursor::new(body);
 
+        let state = match message.header_type() {
+            ipc::Message::MessageHeader::Schema => Err(ArrowError::OutOfSpec(
+                "Not expecting a schema when messages are read".to_string(),
+            )),
+            ipc::Message::MessageHeader::RecordBatch => {
+                let batch = message.header_as_record_batch().ok_or_else(|| {
+                    ArrowError::OutOfSpec("Unable to read IPC message as record batch".to_string())
+                })?;
+                read_record_batch(
+                    batch,
+                    metadata.schema.clone(),
+                    &metadata.ipc_schema,
+                    None,
+                    dictionaries,
+                    metadata.version,
+                    &mut body,
+                    0,
+                )
+                .map(|x| Some(StreamState::Some(x)))
+            }
+            ipc::Message::MessageHeader::DictionaryBatch => {
+                let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                    ArrowError::OutOfSpec(
+                        "Unable to read IPC message as dictionary batch".to_string(),
+                    )
+                })?;
+                read_dictionary(
+                    batch,
+                    metadata.schema.fields(),
+                    &metadata.ipc_schema,
+                    dictionaries,
+                    &mut body,
+                    0,
+                )
+                .map(|_| None)
+            }
+            ipc::Message::MessageHeader::NONE => Ok(Some(StreamState::Waiting)),
+            t => Err(ArrowError::OutOfSpec(format!(
+                "Reading types other than record batches not yet supported, unable to read {:?} ",
+                t
+            ))),
+        };
+        // the message was consumed
+        buffer.clear();
+        match state? {
             // read the next message until we encounter a RecordBatch
-            read_next(reader, metadata, dictionaries, message_buffer, data_buffer)
+            None => continue,
+            state => return Ok(state),
         }
-        ipc::Message::MessageHeader::NONE => Ok(Some(StreamState::Waiting)),
-        t => Err(ArrowError::OutOfSpec(format!(
-            "Reading types other than record batches not yet supported, unable to read {:?} ",
-            t
-        ))),
     }
 }
 
@@ -200,14 +256,21 @@ fn read_next<R: Read>(
 /// An [`Iterator`] over an Arrow stream that yields a result of [`StreamState`]s.
 /// This is the recommended way to read an arrow stream (by iterating over its data).
 ///
+/// The reader does not block on partial messages: when the underlying reader returns
+/// [`std::io::ErrorKind::WouldBlock`] or has no more bytes (e.g. a non-blocking pipe or socket),
+/// the bytes read so far are retained and [`StreamState::Waiting`] is returned, so that
+/// iterating again once more bytes are available resumes the message.
+///
+/// The reader is finished after it yields an error, since the position of the next message in
+/// the stream is then unknown.
+///
 /// For a more thorough walkthrough consult [this example](https://github.com/jorgecarleitao/arrow2/tree/main/examples/ipc_pyarrow).
 pub struct StreamReader<R: Read> {
     reader: R,
     metadata: StreamMetadata,
     dictionaries: Dictionaries,
     finished: bool,
-    data_buffer: Vec<u8>,
-    message_buffer: Vec<u8>,
+    buffer: MessageBuffer,
 }
 
 impl<R: Read> StreamReader<R> {
@@ -222,8 +285,7 @@ impl<R: Read> StreamReader<R> {
             metadata,
             dictionaries: Default::default(),
             finished: false,
-            data_buffer: vec![],
-            message_buffer: vec![],
+            buffer: Default::default(),
         }
     }
 
@@ -245,13 +307,17 @@ impl<R: Read> StreamReader<R> {
             &mut self.reader,
             &self.metadata,
             &mut self.dictionaries,
-            &mut self.message_buffer,
-            &mut self.data_buffer,
-        )?;
-        if batch.is_none() {
-            self.finished = true;
+            &mut self.buffer,
+        );
+        match &batch {
+            Ok(None) => self.finished = true,
+            Err(_) => {
+                self.buffer.clear();
+                self.finished = true;
+            }
+            Ok(Some(_)) => {}
         }
-        Ok(batch)
+        batch
     }
 }
 
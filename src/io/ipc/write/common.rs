@@ -1,3 +1,4 @@
+use std::convert::TryFrom;
 use std::sync::Arc;
 
 use arrow_format::ipc;
@@ -12,6 +13,7 @@ use crate::io::ipc::endianess::is_native_little_endian;
 use crate::io::ipc::read::Dictionaries;
 
 use super::super::IpcField;
+use super::serialize::body_size;
 use super::{write, write_dictionary};
 
 /// Compression codec
@@ -29,6 +31,73 @@ pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
     /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
     pub compression: Option<Compression>,
+    /// The maximum size in bytes of the body of a message, or no maximum when `None`.
+    pub max_message_size: Option<usize>,
+    /// Whether record batches whose body exceeds `max_message_size` are split into multiple
+    /// messages. When `false`, writing such a batch errors.
+    pub auto_chunk: bool,
+    /// Whether to error when a schema has two sibling fields with the same name.
+    /// Arrow allows it, but some readers do not.
+    pub unique_field_names: bool,
+}
+
+fn validate_unique_names(fields: &[Field]) -> Result<()> {
+    let mut names = std::collections::HashSet::new();
+    for field in fields {
+        if !names.insert(field.name()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The schema has more than one field named \"{}\"",
+                field.name()
+            )));
+        }
+        use DataType::*;
+        match field.data_type().to_logical_type() {
+            Struct(fields) | Union(fields, ..) => validate_unique_names(fields)?,
+            List(inner) | LargeList(inner) | FixedSizeList(inner, _) | Map(inner, _) => {
+                validate_unique_names(std::slice::from_ref(inner.as_ref()))?
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Validates `schema` against `options`.
+/// # Errors
+/// Iff `options.unique_field_names` and the schema has sibling fields with the same name.
+pub(crate) fn validate_schema(schema: &Schema, options: &WriteOptions) -> Result<()> {
+    if options.unique_field_names {
+        validate_unique_names(schema.fields())
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_message_size(encoded: &EncodedData, options: &WriteOptions) -> Result<()> {
+    match options.max_message_size {
+        Some(max_message_size) if encoded.arrow_data.len() > max_message_size => {
+            Err(ArrowError::OutOfSpec(format!(
+                "The body of an IPC message ({} bytes) exceeds the maximum message size ({} bytes)",
+                encoded.arrow_data.len(),
+                max_message_size
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns `length` as the `i32` that the IPC format uses for the length of the metadata of a
+/// message.
+/// # Errors
+/// Iff `length` does not fit in an `i32`.
+pub(crate) fn metadata_length(length: usize) -> Result<i32> {
+    i32::try_from(length).map_err(|_| {
+        ArrowError::OutOfSpec(format!(
+            "The metadata of an IPC message ({} bytes) exceeds the maximum of {} bytes",
+            length,
+            i32::MAX
+        ))
+    })
 }
 
 fn encode_dictionary(
@@ -172,12 +241,12 @@ fn encode_dictionary(
     }
 }
 
-pub fn encode_columns(
+fn encode_dictionaries(
     columns: &Columns<Arc<dyn Array>>,
     fields: &[IpcField],
     dictionary_tracker: &mut DictionaryTracker,
     options: &WriteOptions,
-) -> Result<(Vec<EncodedData>, EncodedData)> {
+) -> Result<Vec<EncodedData>> {
     let mut encoded_dictionaries = vec![];
 
     for (field, array) in fields.iter().zip(columns.as_ref()) {
@@ -189,12 +258,143 @@ pub fn encode_columns(
             &mut encoded_dictionaries,
         )?;
     }
+    encoded_dictionaries
+        .iter()
+        .try_for_each(|encoded| validate_message_size(encoded, options))?;
+    Ok(encoded_dictionaries)
+}
+
+/// Encodes `columns` into its dictionaries (if any were not yet emitted) and a single message.
+/// # Errors
+/// Iff the body of any message exceeds the maximum message size of `options`.
+pub fn encode_columns(
+    columns: &Columns<Arc<dyn Array>>,
+    fields: &[IpcField],
+    dictionary_tracker: &mut DictionaryTracker,
+    options: &WriteOptions,
+) -> Result<(Vec<EncodedData>, EncodedData)> {
+    let encoded_dictionaries = encode_dictionaries(columns, fields, dictionary_tracker, options)?;
 
     let encoded_message = columns_to_bytes(columns, options);
+    validate_message_size(&encoded_message, options)?;
 
     Ok((encoded_dictionaries, encoded_message))
 }
 
+/// Encodes `columns` into the messages of all its dictionaries followed by the message of the
+/// record batch, each as an [`EncodedData`] without any stream framing, e.g. to build the
+/// `FlightData` of Arrow Flight. Use [`encode_columns`] to only encode the dictionaries that
+/// changed since the previous call.
+/// # Errors
+/// Iff the body of any message exceeds the maximum message size of `options`.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::columns::Columns;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::error::Result;
+/// use arrow2::io::ipc::read::decode_message;
+/// use arrow2::io::ipc::write::{columns_to_encoded_data, default_ipc_fields, WriteOptions};
+/// use arrow2::io::ipc::IpcSchema;
+///
+/// # fn main() -> Result<()> {
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+/// let fields = default_ipc_fields(schema.fields());
+/// let array = Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>;
+/// let columns = Columns::try_new(vec![array])?;
+///
+/// let options = WriteOptions::default();
+/// let (dictionaries, batch) = columns_to_encoded_data(&columns, &fields, &options)?;
+/// assert!(dictionaries.is_empty());
+///
+/// let ipc_schema = IpcSchema { fields, is_little_endian: true };
+/// let mut decoded_dictionaries = Default::default();
+/// let result = decode_message(
+///     &batch.ipc_message,
+///     &batch.arrow_data,
+///     &schema,
+///     &ipc_schema,
+///     &mut decoded_dictionaries,
+/// )?;
+/// assert_eq!(result, Some(columns));
+/// # Ok(())
+/// # }
+/// ```
+pub fn columns_to_encoded_data(
+    columns: &Columns<Arc<dyn Array>>,
+    fields: &[IpcField],
+    options: &WriteOptions,
+) -> Result<(Vec<EncodedData>, EncodedData)> {
+    let mut dictionary_tracker = DictionaryTracker::new(false);
+    encode_columns(columns, fields, &mut dictionary_tracker, options)
+}
+
+/// Encodes `columns` into its dictionaries (if any were not yet emitted) and one or more
+/// messages. When `options.auto_chunk` is set, columns whose body exceeds the maximum message
+/// size are split into multiple messages.
+/// # Errors
+/// Iff the body of a message exceeds the maximum message size and either `options.auto_chunk`
+/// is not set or the message cannot be split further (it has a single row, or is a dictionary).
+pub fn encode_chunked_columns(
+    columns: &Columns<Arc<dyn Array>>,
+    fields: &[IpcField],
+    dictionary_tracker: &mut DictionaryTracker,
+    options: &WriteOptions,
+) -> Result<(Vec<EncodedData>, Vec<EncodedData>)> {
+    let encoded_dictionaries = encode_dictionaries(columns, fields, dictionary_tracker, options)?;
+
+    let mut encoded_messages = vec![];
+    encode_chunks(columns, options, &mut encoded_messages)?;
+
+    Ok((encoded_dictionaries, encoded_messages))
+}
+
+fn encode_chunks(
+    columns: &Columns<Arc<dyn Array>>,
+    options: &WriteOptions,
+    encoded_messages: &mut Vec<EncodedData>,
+) -> Result<()> {
+    let max_message_size = match options.max_message_size {
+        Some(max_message_size) if options.auto_chunk => max_message_size,
+        _ => {
+            let encoded = columns_to_bytes(columns, options);
+            validate_message_size(&encoded, options)?;
+            encoded_messages.push(encoded);
+            return Ok(());
+        }
+    };
+    // plan the chunks from the uncompressed size of the body, so that every row is encoded once
+    let size = columns
+        .arrays()
+        .iter()
+        .map(|array| body_size(array.as_ref()))
+        .sum::<usize>();
+    if size <= max_message_size || columns.len() < 2 {
+        let encoded = columns_to_bytes(columns, options);
+        if encoded.arrow_data.len() <= max_message_size || columns.len() < 2 {
+            validate_message_size(&encoded, options)?;
+            encoded_messages.push(encoded);
+            return Ok(());
+        }
+        // compression grew the body past the maximum: fall back to splitting it in two
+        let half = columns.len() / 2;
+        encode_chunks(&columns.slice(0, half), options, encoded_messages)?;
+        return encode_chunks(
+            &columns.slice(half, columns.len() - half),
+            options,
+            encoded_messages,
+        );
+    }
+    let chunk_size = (columns.len() * max_message_size / size).max(1);
+    (0..columns.len())
+        .step_by(chunk_size)
+        .try_for_each(|offset| {
+            let length = chunk_size.min(columns.len() - offset);
+            encode_chunks(&columns.slice(offset, length), options, encoded_messages)
+        })
+}
+
 /// Write a `RecordBatch` into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the batch's data
 fn columns_to_bytes(columns: &Columns<Arc<dyn Array>>, options: &WriteOptions) -> EncodedData {
@@ -345,6 +545,8 @@ pub struct DictionaryTracker {
 }
 
 impl DictionaryTracker {
+    /// Returns a new [`DictionaryTracker`] that has not written any dictionary, erroring on
+    /// replacements iff `error_on_replacement`.
     pub fn new(error_on_replacement: bool) -> Self {
         Self {
             written: Dictionaries::new(),
@@ -352,6 +554,15 @@ impl DictionaryTracker {
         }
     }
 
+    /// Returns a new [`DictionaryTracker`] that considers the dictionaries in `written`
+    /// (the values of each dictionary by their id) as already written.
+    pub(crate) fn with_written(written: Dictionaries, error_on_replacement: bool) -> Self {
+        Self {
+            written,
+            error_on_replacement,
+        }
+    }
+
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
     /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
@@ -396,6 +607,7 @@ impl DictionaryTracker {
 }
 
 /// Stores the encoded data, which is an ipc::Schema::Message, and optional Arrow data
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct EncodedData {
     /// An encoded ipc::Schema::Message
     pub ipc_message: Vec<u8>,
@@ -4,8 +4,8 @@ use futures::AsyncWriteExt;
 use crate::error::Result;
 
 use super::super::CONTINUATION_MARKER;
-use super::common::pad_to_8;
 use super::common::EncodedData;
+use super::common::{metadata_length, pad_to_8};
 
 /// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
 pub async fn write_message<W: AsyncWrite + Unpin + Send>(
@@ -22,7 +22,7 @@ pub async fn write_message<W: AsyncWrite + Unpin + Send>(
     let aligned_size = (flatbuf_size + prefix_size + a) & !a;
     let padding_bytes = aligned_size - flatbuf_size - prefix_size;
 
-    write_continuation(writer, (aligned_size - prefix_size) as i32).await?;
+    write_continuation(writer, metadata_length(aligned_size - prefix_size)?).await?;
 
     // write the flatbuf
     if flatbuf_size > 0 {
@@ -3,8 +3,8 @@ use std::io::Write;
 use crate::error::Result;
 
 use super::super::CONTINUATION_MARKER;
-use super::common::pad_to_8;
 use super::common::EncodedData;
+use super::common::{metadata_length, pad_to_8};
 
 /// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
 pub fn write_message<W: Write>(writer: &mut W, encoded: EncodedData) -> Result<(usize, usize)> {
@@ -18,7 +18,7 @@ pub fn write_message<W: Write>(writer: &mut W, encoded: EncodedData) -> Result<(
     let aligned_size = (flatbuf_size + prefix_size + a) & !a;
     let padding_bytes = aligned_size - flatbuf_size - prefix_size;
 
-    write_continuation(writer, (aligned_size - prefix_size) as i32)?;
+    write_continuation(writer, metadata_length(aligned_size - prefix_size)?)?;
 
     // write the flatbuf
     if flatbuf_size > 0 {
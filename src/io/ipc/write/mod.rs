@@ -5,7 +5,10 @@ mod serialize;
 mod stream;
 mod writer;
 
-pub use common::{Compression, WriteOptions};
+pub use common::{
+    columns_to_encoded_data, encode_chunked_columns, encode_columns, Compression,
+    DictionaryTracker, EncodedData, WriteOptions,
+};
 pub use schema::schema_to_bytes;
 pub use serialize::{write, write_dictionary};
 pub use stream::StreamWriter;
@@ -628,6 +628,112 @@ pub fn write(
     }
 }
 
+/// Returns the length of the uncompressed body [`write`] produces for `array`, without writing it.
+pub(super) fn body_size(array: &dyn Array) -> usize {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => 0,
+        Boolean => bitmap_size(array.validity()) + padded((array.len() + 7) / 8),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            bitmap_size(array.validity())
+                + padded(array.len() * std::mem::size_of::<$T>())
+        }),
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            binary_size(array.validity(), array.offsets())
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            binary_size(array.validity(), array.offsets())
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            binary_size(array.validity(), array.offsets())
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            binary_size(array.validity(), array.offsets())
+        }
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            bitmap_size(array.validity()) + padded(array.values().len())
+        }
+        List => list_size::<i32>(array),
+        LargeList => list_size::<i64>(array),
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            bitmap_size(array.validity()) + body_size(array.values().as_ref())
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            bitmap_size(array.validity())
+                + array
+                    .values()
+                    .iter()
+                    .map(|array| body_size(array.as_ref()))
+                    .sum::<usize>()
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            body_size(array.keys())
+        }),
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            padded(array.types().len())
+                + array
+                    .offsets()
+                    .as_ref()
+                    .map(|offsets| padded(offsets.len() * std::mem::size_of::<i32>()))
+                    .unwrap_or(0)
+                + array
+                    .fields()
+                    .iter()
+                    .map(|array| body_size(array.as_ref()))
+                    .sum::<usize>()
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = array.offsets();
+            let first = offsets[0] as usize;
+            let last = offsets[offsets.len() - 1] as usize;
+            bitmap_size(array.validity())
+                + padded(offsets.len() * std::mem::size_of::<i32>())
+                + body_size(array.field().slice(first, last - first).as_ref())
+        }
+    }
+}
+
+#[inline]
+fn padded(length: usize) -> usize {
+    length + pad_to_8(length)
+}
+
+#[inline]
+fn bitmap_size(bitmap: Option<&Bitmap>) -> usize {
+    bitmap
+        .map(|bitmap| padded((bitmap.len() + 7) / 8))
+        .unwrap_or(0)
+}
+
+fn binary_size<O: Offset>(validity: Option<&Bitmap>, offsets: &[O]) -> usize {
+    let first = offsets[0].to_usize();
+    let last = offsets[offsets.len() - 1].to_usize();
+    bitmap_size(validity) + padded(std::mem::size_of_val(offsets)) + padded(last - first)
+}
+
+fn list_size<O: Offset>(array: &dyn Array) -> usize {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let offsets = array.offsets();
+    let first = offsets[0].to_usize();
+    let last = offsets[offsets.len() - 1].to_usize();
+    bitmap_size(array.validity())
+        + padded(std::mem::size_of_val(offsets))
+        + body_size(array.values().slice(first, last - first).as_ref())
+}
+
 #[inline]
 fn pad_buffer_to_8(buffer: &mut Vec<u8>, length: usize) {
     let pad_len = pad_to_8(length);
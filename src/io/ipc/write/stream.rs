@@ -6,7 +6,9 @@
 use std::io::Write;
 
 use super::super::IpcField;
-use super::common::{encode_columns, DictionaryTracker, EncodedData, WriteOptions};
+use super::common::{
+    encode_chunked_columns, validate_schema, DictionaryTracker, EncodedData, WriteOptions,
+};
 use super::common_sync::{write_continuation, write_message};
 use super::schema_to_bytes;
 
@@ -44,6 +46,7 @@ impl<W: Write> StreamWriter<W> {
 
     /// Starts the stream
     pub fn start(&mut self, schema: &Schema, ipc_fields: &[IpcField]) -> Result<()> {
+        validate_schema(schema, &self.write_options)?;
         let encoded_message = EncodedData {
             ipc_message: schema_to_bytes(schema, ipc_fields),
             arrow_data: vec![],
@@ -62,7 +65,7 @@ impl<W: Write> StreamWriter<W> {
         }
 
         let columns = batch.clone().into();
-        let (encoded_dictionaries, encoded_message) = encode_columns(
+        let (encoded_dictionaries, encoded_messages) = encode_chunked_columns(
             &columns,
             fields,
             &mut self.dictionary_tracker,
@@ -73,7 +76,9 @@ impl<W: Write> StreamWriter<W> {
             write_message(&mut self.writer, encoded_dictionary)?;
         }
 
-        write_message(&mut self.writer, encoded_message)?;
+        for encoded_message in encoded_messages {
+            write_message(&mut self.writer, encoded_message)?;
+        }
         Ok(())
     }
 
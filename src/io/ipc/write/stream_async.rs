@@ -4,7 +4,7 @@ use futures::AsyncWrite;
 
 use super::super::IpcField;
 pub use super::common::WriteOptions;
-use super::common::{encode_columns, DictionaryTracker, EncodedData};
+use super::common::{encode_chunked_columns, validate_schema, DictionaryTracker, EncodedData};
 use super::common_async::{write_continuation, write_message};
 use super::{default_ipc_fields, schema_to_bytes};
 
@@ -37,6 +37,7 @@ impl<W: AsyncWrite + Unpin + Send> StreamWriter<W> {
 
     /// Starts the stream
     pub async fn start(&mut self, schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Result<()> {
+        validate_schema(schema, &self.write_options)?;
         let encoded_message = if let Some(ipc_fields) = ipc_fields {
             EncodedData {
                 ipc_message: schema_to_bytes(schema, ipc_fields),
@@ -66,9 +67,9 @@ impl<W: AsyncWrite + Unpin + Send> StreamWriter<W> {
             )));
         }
 
-        let (encoded_dictionaries, encoded_message) = if let Some(ipc_fields) = ipc_fields {
+        let (encoded_dictionaries, encoded_messages) = if let Some(ipc_fields) = ipc_fields {
             let columns = batch.clone().into();
-            encode_columns(
+            encode_chunked_columns(
                 &columns,
                 ipc_fields,
                 &mut self.dictionary_tracker,
@@ -77,7 +78,7 @@ impl<W: AsyncWrite + Unpin + Send> StreamWriter<W> {
         } else {
             let ipc_fields = default_ipc_fields(batch.schema().fields());
             let columns = batch.clone().into();
-            encode_columns(
+            encode_chunked_columns(
                 &columns,
                 &ipc_fields,
                 &mut self.dictionary_tracker,
@@ -89,7 +90,9 @@ impl<W: AsyncWrite + Unpin + Send> StreamWriter<W> {
             write_message(&mut self.writer, encoded_dictionary).await?;
         }
 
-        write_message(&mut self.writer, encoded_message).await?;
+        for encoded_message in encoded_messages {
+            write_message(&mut self.writer, encoded_message).await?;
+        }
         Ok(())
     }
 
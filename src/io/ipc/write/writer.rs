@@ -1,18 +1,25 @@
-use std::io::Write;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::{Read, Seek, SeekFrom, Write};
 
 use arrow_format::ipc;
 use arrow_format::ipc::flatbuffers::FlatBufferBuilder;
 
 use super::{
+    super::read::read_file_metadata,
     super::IpcField,
-    super::ARROW_MAGIC,
-    common::{encode_columns, DictionaryTracker, EncodedData, WriteOptions},
+    super::{ARROW_MAGIC, CONTINUATION_MARKER},
+    common::{
+        encode_chunked_columns, metadata_length, validate_schema, DictionaryTracker, EncodedData,
+        WriteOptions,
+    },
     common_sync::{write_continuation, write_message},
     default_ipc_fields, schema, schema_to_bytes,
 };
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::io::ipc::endianess::is_native_little_endian;
 use crate::record_batch::RecordBatch;
 
 /// Arrow file writer
@@ -34,6 +41,8 @@ pub struct FileWriter<W: Write> {
     finished: bool,
     /// Keeps track of dictionaries that have been written
     dictionary_tracker: DictionaryTracker,
+    /// Custom metadata written as part of the IPC footer
+    custom_metadata: HashMap<String, String>,
 }
 
 impl<W: Write> FileWriter<W> {
@@ -44,6 +53,8 @@ impl<W: Write> FileWriter<W> {
         ipc_fields: Option<Vec<IpcField>>,
         options: WriteOptions,
     ) -> Result<Self> {
+        validate_schema(schema, &options)?;
+
         // write magic to header
         writer.write_all(&ARROW_MAGIC[..])?;
         // create an 8-byte boundary after the header
@@ -71,6 +82,7 @@ impl<W: Write> FileWriter<W> {
             record_blocks: vec![],
             finished: false,
             dictionary_tracker: DictionaryTracker::new(true),
+            custom_metadata: HashMap::new(),
         })
     }
 
@@ -78,7 +90,25 @@ impl<W: Write> FileWriter<W> {
         self.writer
     }
 
+    /// Sets the custom metadata written as part of the footer of the file, replacing any
+    /// previous one. This is distinct from the metadata of the schema.
+    pub fn set_custom_metadata(&mut self, metadata: HashMap<String, String>) {
+        self.custom_metadata = metadata;
+    }
+
+    /// Returns the custom metadata written as part of the footer of the file.
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.custom_metadata
+    }
+
     /// Writes [`RecordBatch`] to the file
+    /// # Errors
+    /// This function errors iff:
+    /// * the file was already finished
+    /// * the data types of `batch`'s columns differ from the ones of the schema of the file,
+    ///   e.g. a different number of columns or a column of another type (names are not compared)
+    /// * a dictionary differs from the one already written to the file under the same id
+    /// * writing to the underlying writer fails
     pub fn write(&mut self, batch: &RecordBatch, ipc_fields: Option<&[IpcField]>) -> Result<()> {
         if self.finished {
             return Err(ArrowError::Io(std::io::Error::new(
@@ -87,6 +117,19 @@ impl<W: Write> FileWriter<W> {
             )));
         }
 
+        let fields = batch.schema().fields();
+        if fields.len() != self.schema.fields().len()
+            || fields
+                .iter()
+                .zip(self.schema.fields())
+                .any(|(lhs, rhs)| lhs.data_type() != rhs.data_type())
+        {
+            return Err(ArrowError::InvalidArgumentError(
+                "The record batch must have the same data types as the schema of the file"
+                    .to_string(),
+            ));
+        }
+
         let ipc_fields = if let Some(ipc_fields) = ipc_fields {
             ipc_fields
         } else {
@@ -94,7 +137,7 @@ impl<W: Write> FileWriter<W> {
         };
 
         let columns = batch.clone().into();
-        let (encoded_dictionaries, encoded_message) = encode_columns(
+        let (encoded_dictionaries, encoded_messages) = encode_chunked_columns(
             &columns,
             ipc_fields,
             &mut self.dictionary_tracker,
@@ -104,20 +147,26 @@ impl<W: Write> FileWriter<W> {
         for encoded_dictionary in encoded_dictionaries {
             let (meta, data) = write_message(&mut self.writer, encoded_dictionary)?;
 
-            let block = ipc::File::Block::new(self.block_offsets as i64, meta as i32, data as i64);
+            let block = ipc::File::Block::new(
+                self.block_offsets as i64,
+                metadata_length(meta)?,
+                data as i64,
+            );
             self.dictionary_blocks.push(block);
             self.block_offsets += meta + data;
         }
 
-        let (meta, data) = write_message(&mut self.writer, encoded_message)?;
-        // add a record block for the footer
-        let block = ipc::File::Block::new(
-            self.block_offsets as i64,
-            meta as i32, // TODO: is this still applicable?
-            data as i64,
-        );
-        self.record_blocks.push(block);
-        self.block_offsets += meta + data;
+        for encoded_message in encoded_messages {
+            let (meta, data) = write_message(&mut self.writer, encoded_message)?;
+            // add a record block for the footer
+            let block = ipc::File::Block::new(
+                self.block_offsets as i64,
+                metadata_length(meta)?,
+                data as i64,
+            );
+            self.record_blocks.push(block);
+            self.block_offsets += meta + data;
+        }
         Ok(())
     }
 
@@ -130,6 +179,18 @@ impl<W: Write> FileWriter<W> {
         let dictionaries = fbb.create_vector(&self.dictionary_blocks);
         let record_batches = fbb.create_vector(&self.record_blocks);
         let schema = schema::schema_to_fb_offset(&mut fbb, &self.schema, &self.ipc_fields);
+        let custom_metadata = self
+            .custom_metadata
+            .iter()
+            .map(|(k, v)| {
+                let kv_args = ipc::Schema::KeyValueArgs {
+                    key: Some(fbb.create_string(k.as_str())),
+                    value: Some(fbb.create_string(v.as_str())),
+                };
+                ipc::Schema::KeyValue::create(&mut fbb, &kv_args)
+            })
+            .collect::<Vec<_>>();
+        let custom_metadata = fbb.create_vector(&custom_metadata);
 
         let root = {
             let mut footer_builder = ipc::File::FooterBuilder::new(&mut fbb);
@@ -137,13 +198,14 @@ impl<W: Write> FileWriter<W> {
             footer_builder.add_schema(schema);
             footer_builder.add_dictionaries(dictionaries);
             footer_builder.add_recordBatches(record_batches);
+            footer_builder.add_custom_metadata(custom_metadata);
             footer_builder.finish()
         };
         fbb.finish(root, None);
         let footer_data = fbb.finished_data();
+        let footer_length = metadata_length(footer_data.len())?;
         self.writer.write_all(footer_data)?;
-        self.writer
-            .write_all(&(footer_data.len() as i32).to_le_bytes())?;
+        self.writer.write_all(&footer_length.to_le_bytes())?;
         self.writer.write_all(&ARROW_MAGIC)?;
         self.writer.flush()?;
         self.finished = true;
@@ -151,3 +213,94 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 }
+
+impl<W: Read + Seek + Write> FileWriter<W> {
+    /// Creates a [`FileWriter`] that appends record batches to the existing Arrow file in `writer`.
+    ///
+    /// The schema, dictionaries and custom metadata of the file are read from its footer.
+    /// Record batches are appended after its last block, overwriting its footer, and must have
+    /// the data types of its schema and the same dictionaries as the ones already written.
+    /// [`FileWriter::finish`] writes a new footer with the existing and the appended blocks.
+    ///
+    /// The file is not truncated: when the new footer is shorter than the existing one (e.g. no
+    /// batch was appended and the custom metadata was shrunk) the file must be truncated by the
+    /// caller to the position of `writer` after [`FileWriter::finish`].
+    /// # Errors
+    /// This function errors iff the file is not a valid Arrow file, it was written with a
+    /// different endianness, or its schema is not supported by `options`.
+    pub fn try_from_existing(mut writer: W, options: WriteOptions) -> Result<Self> {
+        writer.seek(SeekFrom::Start(0))?;
+        let metadata = read_file_metadata(&mut writer)?;
+        if metadata.ipc_schema.is_little_endian != is_native_little_endian() {
+            return Err(ArrowError::NotYetImplemented(
+                "Appending to an Arrow file of a different endianness".to_string(),
+            ));
+        }
+        let schema = metadata.schema.as_ref().clone();
+        validate_schema(&schema, &options)?;
+        let custom_metadata = metadata.custom_metadata().clone();
+
+        // the end of the last block, or of the schema message when the file has no blocks
+        let end = metadata
+            .blocks
+            .iter()
+            .chain(metadata.dictionary_blocks.iter())
+            .map(|block| {
+                let offset = usize::try_from(block.offset());
+                let meta_data_length = usize::try_from(block.metaDataLength());
+                let body_length = usize::try_from(block.bodyLength());
+                match (offset, meta_data_length, body_length) {
+                    (Ok(offset), Ok(meta_data_length), Ok(body_length)) => offset
+                        .checked_add(meta_data_length)
+                        .and_then(|end| end.checked_add(body_length))
+                        .ok_or(ArrowError::Overflow),
+                    _ => Err(ArrowError::OutOfSpec(
+                        "The blocks of an Arrow file must have non-negative offsets and lengths"
+                            .to_string(),
+                    )),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .max();
+        let end = if let Some(end) = end {
+            end
+        } else {
+            schema_message_end(&mut writer)?
+        };
+        writer.seek(SeekFrom::Start(end as u64))?;
+
+        Ok(Self {
+            writer,
+            options,
+            ipc_fields: metadata.ipc_schema.fields,
+            schema,
+            block_offsets: end,
+            dictionary_blocks: metadata.dictionary_blocks,
+            record_blocks: metadata.blocks,
+            finished: false,
+            dictionary_tracker: DictionaryTracker::with_written(metadata.dictionaries, true),
+            custom_metadata,
+        })
+    }
+}
+
+/// Returns the position after the schema message, which follows the header of the file.
+fn schema_message_end<R: Read + Seek>(reader: &mut R) -> Result<usize> {
+    // the header is the magic padded to 8 bytes
+    let mut position = 8;
+    reader.seek(SeekFrom::Start(position as u64))?;
+    let mut message_size: [u8; 4] = [0; 4];
+    reader.read_exact(&mut message_size)?;
+    position += 4;
+    if message_size == CONTINUATION_MARKER {
+        reader.read_exact(&mut message_size)?;
+        position += 4;
+    };
+    let message_size = usize::try_from(i32::from_le_bytes(message_size)).map_err(|_| {
+        ArrowError::OutOfSpec(
+            "The schema message of an Arrow file must have a non-negative length".to_string(),
+        )
+    })?;
+    Ok(position + message_size)
+}
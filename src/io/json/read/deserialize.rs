@@ -8,12 +8,13 @@ use num_traits::NumCast;
 use serde_json::Value;
 
 use crate::datatypes::{Field, Schema};
-use crate::error::ArrowError;
+use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
 use crate::{
     array::*,
     bitmap::MutableBitmap,
     datatypes::{DataType, IntervalUnit},
+    temporal_conversions,
     types::NativeType,
 };
 
@@ -92,6 +93,21 @@ fn deserialize_float<T: NativeType + NumCast, A: Borrow<Value>>(
     PrimitiveArray::from_trusted_len_iter(iter).to(data_type)
 }
 
+/// Deserializes timestamps from numbers (as is, in the unit of `data_type`) and from strings
+/// (parsed via `parse`).
+fn deserialize_timestamp<A: Borrow<Value>, F: Fn(&str) -> Option<i64>>(
+    rows: &[A],
+    data_type: DataType,
+    parse: F,
+) -> PrimitiveArray<i64> {
+    let iter = rows.iter().map(|row| match row.borrow() {
+        Value::Number(number) => number.as_i64(),
+        Value::String(string) => parse(string),
+        _ => None,
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(data_type)
+}
+
 fn deserialize_binary<O: Offset, A: Borrow<Value>>(rows: &[A]) -> BinaryArray<O> {
     let iter = rows.iter().map(|row| match row.borrow() {
         Value::String(v) => Some(v.as_bytes()),
@@ -110,37 +126,43 @@ fn deserialize_utf8<O: Offset, A: Borrow<Value>>(rows: &[A]) -> Utf8Array<O> {
     Utf8Array::<O>::from_trusted_len_iter(iter)
 }
 
-fn deserialize_list<O: Offset, A: Borrow<Value>>(rows: &[A], data_type: DataType) -> ListArray<O> {
+fn deserialize_list<O: Offset, A: Borrow<Value>>(
+    rows: &[A],
+    data_type: DataType,
+) -> Result<ListArray<O>> {
     let child = ListArray::<O>::get_child_type(&data_type);
 
     let mut validity = MutableBitmap::with_capacity(rows.len());
     let mut offsets = Vec::<O>::with_capacity(rows.len() + 1);
     let mut inner = vec![];
     offsets.push(O::zero());
-    rows.iter().fold(O::zero(), |mut length, row| {
+    let mut length = 0usize;
+    for row in rows {
         match row.borrow() {
             Value::Array(value) => {
                 inner.extend(value.iter());
                 validity.push(true);
-                // todo make this an Err
-                length += O::from_usize(value.len()).expect("List offset is too large :/");
-                offsets.push(length);
-                length
+                length += value.len();
+                offsets.push(O::from_usize(length).ok_or(ArrowError::Overflow)?);
             }
             _ => {
                 validity.push(false);
-                offsets.push(length);
-                length
+                offsets.push(O::from_usize(length).ok_or(ArrowError::Overflow)?);
             }
         }
-    });
+    }
 
-    let values = _deserialize(&inner, child.clone());
+    let values = _deserialize(&inner, child.clone())?;
 
-    ListArray::<O>::from_data(data_type, offsets.into(), values, validity.into())
+    Ok(ListArray::<O>::from_data(
+        data_type,
+        offsets.into(),
+        values,
+        validity.into(),
+    ))
 }
 
-fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> StructArray {
+fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Result<StructArray> {
     let fields = StructArray::get_fields(&data_type);
 
     let mut values = fields
@@ -165,16 +187,18 @@ fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Stru
 
     let values = values
         .into_iter()
-        .map(|(_, (data_type, values))| _deserialize(&values, data_type.clone()))
-        .collect::<Vec<_>>();
+        .map(|(name, (data_type, values))| {
+            _deserialize(&values, data_type.clone()).map_err(|error| error.context(name, None))
+        })
+        .collect::<Result<Vec<_>>>()?;
 
-    StructArray::from_data(data_type, values, None)
+    Ok(StructArray::from_data(data_type, values, None))
 }
 
 fn deserialize_dictionary<K: DictionaryKey, A: Borrow<Value>>(
     rows: &[A],
     data_type: DataType,
-) -> DictionaryArray<K> {
+) -> Result<DictionaryArray<K>> {
     let child = DictionaryArray::<K>::get_child(&data_type);
 
     let mut map = HashedMap::<u64, K>::default();
@@ -187,25 +211,24 @@ fn deserialize_dictionary<K: DictionaryKey, A: Borrow<Value>>(
         .map(|x| extractor(x.borrow()))
         .map(|item| match item {
             Some((hash, v)) => match map.get(&hash) {
-                Some(key) => Some(*key),
+                Some(key) => Ok(Some(*key)),
                 None => {
-                    // todo: convert this to an error.
-                    let key = K::from_usize(map.len()).unwrap();
+                    let key = K::from_usize(map.len()).ok_or(ArrowError::Overflow)?;
                     inner.push(v);
                     map.insert(hash, key);
-                    Some(key)
+                    Ok(Some(key))
                 }
             },
-            None => None,
+            None => Ok(None),
         })
-        .collect::<PrimitiveArray<K>>();
+        .collect::<Result<PrimitiveArray<K>>>()?;
 
-    let values = _deserialize(&inner, child.clone());
-    DictionaryArray::<K>::from_data(keys, values)
+    let values = _deserialize(&inner, child.clone())?;
+    Ok(DictionaryArray::<K>::from_data(keys, values))
 }
 
-fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Arc<dyn Array> {
-    match &data_type {
+fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Result<Arc<dyn Array>> {
+    Ok(match &data_type {
         DataType::Null => Arc::new(NullArray::from_data(data_type, rows.len())),
         DataType::Boolean => Arc::new(deserialize_boolean(rows)),
         DataType::Int8 => Arc::new(deserialize_int::<i8, _>(rows, data_type)),
@@ -217,58 +240,72 @@ fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Arc<dyn Ar
             Arc::new(deserialize_int::<i32, _>(rows, data_type))
         }
         DataType::Interval(IntervalUnit::DayTime) => {
-            unimplemented!("There is no natural representation of DayTime in JSON.")
+            return Err(ArrowError::NotYetImplemented(
+                "There is no natural representation of DayTime in JSON.".to_string(),
+            ))
+        }
+        DataType::Int64 | DataType::Date64 | DataType::Time64(_) | DataType::Duration(_) => {
+            Arc::new(deserialize_int::<i64, _>(rows, data_type))
+        }
+        DataType::Timestamp(time_unit, None) => {
+            let time_unit = *time_unit;
+            Arc::new(deserialize_timestamp(rows, data_type, |x| {
+                temporal_conversions::utf8_to_naive_timestamp_scalar(x, time_unit)
+            }))
+        }
+        DataType::Timestamp(time_unit, Some(tz)) => {
+            let time_unit = *time_unit;
+            let tz = temporal_conversions::Timezone::try_new(tz)?;
+            Arc::new(deserialize_timestamp(rows, data_type, |x| {
+                tz.utf8_to_timestamp_scalar(x, time_unit)
+            }))
         }
-        DataType::Int64
-        | DataType::Date64
-        | DataType::Time64(_)
-        | DataType::Timestamp(_, _)
-        | DataType::Duration(_) => Arc::new(deserialize_int::<i64, _>(rows, data_type)),
         DataType::UInt8 => Arc::new(deserialize_int::<u8, _>(rows, data_type)),
         DataType::UInt16 => Arc::new(deserialize_int::<u16, _>(rows, data_type)),
         DataType::UInt32 => Arc::new(deserialize_int::<u32, _>(rows, data_type)),
         DataType::UInt64 => Arc::new(deserialize_int::<u64, _>(rows, data_type)),
-        DataType::Float16 => unreachable!(),
+
         DataType::Float32 => Arc::new(deserialize_float::<f32, _>(rows, data_type)),
         DataType::Float64 => Arc::new(deserialize_float::<f64, _>(rows, data_type)),
         DataType::Utf8 => Arc::new(deserialize_utf8::<i32, _>(rows)),
         DataType::LargeUtf8 => Arc::new(deserialize_utf8::<i64, _>(rows)),
-        DataType::List(_) => Arc::new(deserialize_list::<i32, _>(rows, data_type)),
-        DataType::LargeList(_) => Arc::new(deserialize_list::<i64, _>(rows, data_type)),
+        DataType::List(_) => Arc::new(deserialize_list::<i32, _>(rows, data_type)?),
+        DataType::LargeList(_) => Arc::new(deserialize_list::<i64, _>(rows, data_type)?),
         DataType::Binary => Arc::new(deserialize_binary::<i32, _>(rows)),
         DataType::LargeBinary => Arc::new(deserialize_binary::<i64, _>(rows)),
-        DataType::Struct(_) => Arc::new(deserialize_struct(rows, data_type)),
+        DataType::Struct(_) => Arc::new(deserialize_struct(rows, data_type)?),
         DataType::Dictionary(key_type, _, _) => {
             match_integer_type!(key_type, |$T| {
-                Arc::new(deserialize_dictionary::<$T, _>(rows, data_type))
+                Arc::new(deserialize_dictionary::<$T, _>(rows, data_type)?)
             })
         }
-        _ => todo!(),
-        /*
-        DataType::FixedSizeBinary(_) => Box::new(FixedSizeBinaryArray::new_empty(data_type)),
-        DataType::FixedSizeList(_, _) => Box::new(FixedSizeListArray::new_empty(data_type)),
-        DataType::Decimal(_, _) => Box::new(PrimitiveArray::<i128>::new_empty(data_type)),
-        */
-    }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Deserializing {:?} from JSON is not implemented",
+                other
+            )))
+        }
+    })
 }
 
 /// Deserializes `rows` into a [`RecordBatch`] according to `fields`.
 /// This is CPU-bounded.
-pub fn deserialize<A: AsRef<str>>(
-    rows: &[A],
-    fields: Vec<Field>,
-) -> Result<RecordBatch, ArrowError> {
+/// # Errors
+/// Errors with [`ArrowError::Deserialization`] naming the row that is not valid JSON or
+/// the field that can't be deserialized.
+pub fn deserialize<A: AsRef<str>>(rows: &[A], fields: Vec<Field>) -> Result<RecordBatch> {
     let data_type = DataType::Struct(fields);
 
     // convert rows to `Value`
     let rows = rows
         .iter()
-        .map(|row| {
-            let row: Value = serde_json::from_str(row.as_ref()).map_err(ArrowError::from)?;
-            Ok(row)
+        .enumerate()
+        .map(|(index, row)| {
+            serde_json::from_str::<Value>(row.as_ref())
+                .map_err(|e| ArrowError::from_external_error(e).at_row(index))
         })
-        .collect::<Result<Vec<_>, ArrowError>>()?;
+        .collect::<Result<Vec<_>>>()?;
 
-    let (fields, columns, _) = deserialize_struct(&rows, data_type).into_data();
+    let (fields, columns, _) = deserialize_struct(&rows, data_type)?.into_data();
     RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
 }
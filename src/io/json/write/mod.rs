@@ -3,7 +3,7 @@ mod format;
 mod serialize;
 pub use fallible_streaming_iterator::*;
 pub use format::*;
-pub use serialize::serialize;
+pub use serialize::{serialize, serialize_columns};
 
 use crate::{
     error::{ArrowError, Result},
@@ -1,11 +1,22 @@
+use std::fmt::Display;
+use std::io::Write;
+
 use lexical_core::ToLexical;
 use serde_json::Value;
 use streaming_iterator::StreamingIterator;
 
 use crate::bitmap::utils::zip_validity;
+use crate::columns::Columns;
+use crate::datatypes::Schema;
+use crate::error::Result;
 use crate::io::iterator::BufStreamingIterator;
+use crate::temporal_conversions;
 use crate::util::lexical_to_bytes_mut;
-use crate::{array::*, datatypes::DataType, types::NativeType};
+use crate::{
+    array::*,
+    datatypes::{DataType, IntervalUnit, TimeUnit},
+    types::NativeType,
+};
 
 use super::{JsonArray, JsonFormat};
 
@@ -136,6 +147,34 @@ fn list_serializer<'a, O: Offset>(
     ))
 }
 
+fn dictionary_serializer<'a, K: DictionaryKey>(
+    array: &'a DictionaryArray<K>,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    // every value of the dictionary is serialized once, so that serializing a row
+    // only copies the bytes of the value of its key
+    let mut serializer = new_serializer(array.values().as_ref());
+    let mut values = vec![];
+    let mut offsets = Vec::with_capacity(array.values().len() + 1);
+    offsets.push(0);
+    while let Some(value) = serializer.next() {
+        values.extend_from_slice(value);
+        offsets.push(values.len());
+    }
+
+    Box::new(BufStreamingIterator::new(
+        array.keys().iter(),
+        move |key, buf| {
+            if let Some(key) = key {
+                let index = key.to_usize().unwrap();
+                buf.extend_from_slice(&values[offsets[index]..offsets[index + 1]]);
+            } else {
+                buf.extend_from_slice(b"null")
+            }
+        },
+        vec![],
+    ))
+}
+
 #[inline]
 fn utf8_serialize(value: &str, buf: &mut Vec<u8>) {
     if value.as_bytes().is_ascii() {
@@ -157,8 +196,20 @@ fn new_serializer<'a>(
         DataType::Boolean => boolean_serializer(array.as_any().downcast_ref().unwrap()),
         DataType::Int8 => primitive_serializer::<i8>(array.as_any().downcast_ref().unwrap()),
         DataType::Int16 => primitive_serializer::<i16>(array.as_any().downcast_ref().unwrap()),
-        DataType::Int32 => primitive_serializer::<i32>(array.as_any().downcast_ref().unwrap()),
-        DataType::Int64 => primitive_serializer::<i64>(array.as_any().downcast_ref().unwrap()),
+        // temporal types are serialized as their physical representation, as read by `read`
+        DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => {
+            primitive_serializer::<i32>(array.as_any().downcast_ref().unwrap())
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => {
+            primitive_serializer::<i64>(array.as_any().downcast_ref().unwrap())
+        }
         DataType::UInt8 => primitive_serializer::<u8>(array.as_any().downcast_ref().unwrap()),
         DataType::UInt16 => primitive_serializer::<u16>(array.as_any().downcast_ref().unwrap()),
         DataType::UInt32 => primitive_serializer::<u32>(array.as_any().downcast_ref().unwrap()),
@@ -170,10 +221,82 @@ fn new_serializer<'a>(
         DataType::Struct(_) => struct_serializer(array.as_any().downcast_ref().unwrap()),
         DataType::List(_) => list_serializer::<i32>(array.as_any().downcast_ref().unwrap()),
         DataType::LargeList(_) => list_serializer::<i64>(array.as_any().downcast_ref().unwrap()),
+        DataType::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            dictionary_serializer::<$T>(array.as_any().downcast_ref().unwrap())
+        }),
         other => todo!("Writing {:?} to JSON", other),
     }
 }
 
+fn date_serializer<'a, T: NativeType, D: Display, F: Fn(T) -> D + Send + Sync + 'a>(
+    array: &'a PrimitiveArray<T>,
+    convert: F,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    Box::new(BufStreamingIterator::new(
+        array.iter(),
+        move |x, buf| {
+            if let Some(x) = x {
+                let _ = write!(buf, "\"{}\"", convert(*x));
+            } else {
+                buf.extend_from_slice(b"null")
+            }
+        },
+        vec![],
+    ))
+}
+
+fn timestamp_tz_serializer<'a>(
+    array: &'a PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    tz: &str,
+) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>> {
+    match temporal_conversions::parse_offset(tz) {
+        Ok(timezone) => Ok(date_serializer(array, move |x| {
+            temporal_conversions::timestamp_to_datetime(x, time_unit, &timezone)
+        })),
+        #[cfg(feature = "chrono-tz")]
+        _ => {
+            let timezone = temporal_conversions::parse_offset_tz(tz)?;
+            Ok(date_serializer(array, move |x| {
+                temporal_conversions::timestamp_to_datetime(x, time_unit, &timezone)
+            }))
+        }
+        #[cfg(not(feature = "chrono-tz"))]
+        _ => Err(crate::error::ArrowError::InvalidArgumentError(
+            "Invalid Offset format (must be [-]00:00) or chrono-tz feature not active".to_string(),
+        )),
+    }
+}
+
+/// Returns a serializer of `array` as if it were of `data_type`: dates and timestamps are
+/// serialized as strings (e.g. `"2021-06-30"`) and all other types as in [`new_serializer`].
+/// `data_type` must have the physical type of `array`.
+fn new_typed_serializer<'a>(
+    array: &'a dyn Array,
+    data_type: &DataType,
+) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>> {
+    Ok(match data_type.to_logical_type() {
+        DataType::Date32 => date_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            temporal_conversions::date32_to_date,
+        ),
+        DataType::Date64 => date_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            temporal_conversions::date64_to_date,
+        ),
+        DataType::Timestamp(time_unit, None) => {
+            let time_unit = *time_unit;
+            date_serializer(array.as_any().downcast_ref().unwrap(), move |x| {
+                temporal_conversions::timestamp_to_naive_datetime(x, time_unit)
+            })
+        }
+        DataType::Timestamp(time_unit, Some(tz)) => {
+            timestamp_tz_serializer(array.as_any().downcast_ref().unwrap(), *time_unit, tz)?
+        }
+        _ => new_serializer(array),
+    })
+}
+
 fn serialize_item<F: JsonFormat>(
     buffer: &mut Vec<u8>,
     record: &[(&str, &[u8])],
@@ -206,11 +329,20 @@ where
 {
     let num_rows = arrays[0].as_ref().len();
 
-    let mut serializers: Vec<_> = arrays
+    let serializers = arrays
         .iter()
         .map(|array| new_serializer(array.as_ref()))
         .collect();
+    serialize_rows(names, num_rows, serializers, format, buffer)
+}
 
+fn serialize_rows<N: AsRef<str>, F: JsonFormat>(
+    names: &[N],
+    num_rows: usize,
+    mut serializers: Vec<Box<dyn StreamingIterator<Item = [u8]> + '_ + Send + Sync>>,
+    format: F,
+    buffer: &mut Vec<u8>,
+) {
     let mut is_first_row = true;
     (0..num_rows).for_each(|_| {
         let mut record: Vec<(&str, &[u8])> = Default::default();
@@ -226,3 +358,42 @@ where
         is_first_row = false;
     })
 }
+
+/// Serializes `columns` to a valid JSON to `buffer`, where the keys of each row are the names
+/// of the fields of `schema` and each column is serialized according to the data type of its
+/// field: dates and timestamps are written as strings (e.g. an [`Int32Array`] of a field of
+/// type [`DataType::Date32`] is written as `"2021-06-30"`), all other types as in [`serialize`].
+/// Use [`Schema::try_from_columns`] to derive the schema from `columns`.
+/// This is CPU-bounded
+/// # Errors
+/// This function errors iff the number of fields of `schema` differs from the number of
+/// columns, iff the physical type of a field differs from the one of its array or iff the
+/// timezone of a timestamp is not supported.
+pub fn serialize_columns<A, F>(
+    schema: &Schema,
+    columns: &Columns<A>,
+    format: F,
+    buffer: &mut Vec<u8>,
+) -> Result<()>
+where
+    A: AsRef<dyn Array>,
+    F: JsonFormat,
+{
+    super::super::super::check_columns(schema, columns)?;
+    if columns.arrays().is_empty() {
+        return Ok(());
+    }
+    let names = schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<Vec<_>>();
+    let serializers = schema
+        .fields()
+        .iter()
+        .zip(columns.arrays())
+        .map(|(field, array)| new_typed_serializer(array.as_ref(), field.data_type()))
+        .collect::<Result<Vec<_>>>()?;
+    serialize_rows(&names, columns.len(), serializers, format, buffer);
+    Ok(())
+}
@@ -11,7 +11,7 @@ use crate::{
     error::{ArrowError, Result},
     io::ipc::IpcField,
     record_batch::RecordBatch,
-    types::{days_ms, months_days_ns, NativeType},
+    types::{days_ms, f16, months_days_ns, NativeType},
 };
 
 use super::super::{ArrowJsonBatch, ArrowJsonColumn, ArrowJsonDictionaryBatch};
@@ -126,6 +126,19 @@ fn to_decimal(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray
     PrimitiveArray::<i128>::from_data(data_type, values, validity)
 }
 
+fn to_float16(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray<f16> {
+    let validity = to_validity(&json_col.validity);
+    let values = json_col
+        .data
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|value| f16::from_f64(value.as_f64().unwrap()))
+        .collect();
+
+    PrimitiveArray::<f16>::from_data(data_type, values, validity)
+}
+
 fn to_primitive<T: NativeType + NumCast>(
     json_col: &ArrowJsonColumn,
     data_type: DataType,
@@ -256,7 +269,10 @@ fn to_dictionary<K: DictionaryKey>(
         dictionaries,
     )?;
 
-    Ok(Arc::new(DictionaryArray::<K>::from_data(keys, values)))
+    let is_ordered = DictionaryArray::<K>::get_is_ordered(&data_type);
+    Ok(Arc::new(
+        DictionaryArray::<K>::from_data(keys, values).with_is_ordered(is_ordered),
+    ))
 }
 
 /// Construct an [`Array`] from the JSON integration format
@@ -295,6 +311,7 @@ pub fn to_array(
         Primitive(PrimitiveType::UInt16) => Ok(Arc::new(to_primitive::<u16>(json_col, data_type))),
         Primitive(PrimitiveType::UInt32) => Ok(Arc::new(to_primitive::<u32>(json_col, data_type))),
         Primitive(PrimitiveType::UInt64) => Ok(Arc::new(to_primitive::<u64>(json_col, data_type))),
+        Primitive(PrimitiveType::Float16) => Ok(Arc::new(to_float16(json_col, data_type))),
         Primitive(PrimitiveType::Float32) => Ok(Arc::new(to_primitive::<f32>(json_col, data_type))),
         Primitive(PrimitiveType::Float64) => Ok(Arc::new(to_primitive::<f64>(json_col, data_type))),
         Binary => Ok(to_binary::<i32>(json_col, data_type)),
@@ -9,7 +9,7 @@ use crate::{
 };
 
 use crate::datatypes::{
-    get_extension, DataType, Field, IntegerType, IntervalUnit, Metadata, Schema, TimeUnit,
+    remove_extension, DataType, Field, IntegerType, IntervalUnit, Metadata, Schema, TimeUnit,
     UnionMode,
 };
 
@@ -362,13 +362,13 @@ fn deserialize_field(value: &Value) -> Result<Field> {
         }
     };
 
-    let metadata = if let Some(metadata) = map.get("metadata") {
+    let mut metadata = if let Some(metadata) = map.get("metadata") {
         read_metadata(metadata)?
     } else {
         Metadata::default()
     };
 
-    let extension = get_extension(&metadata);
+    let extension = remove_extension(&mut metadata);
 
     let type_ = map
         .get("type")
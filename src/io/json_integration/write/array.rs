@@ -1,3 +1,5 @@
+use serde_json::Value;
+
 use crate::record_batch::RecordBatch;
 use crate::{array::PrimitiveArray, datatypes::DataType};
 
@@ -30,6 +32,28 @@ pub fn from_record_batch(batch: &RecordBatch) -> ArrowJsonBatch {
                     children: None,
                 }
             }
+            DataType::Decimal(_, _) => {
+                let array = col.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
+
+                // decimals are represented as strings, as they may not fit in a JSON number
+                let (validity, data) = array
+                    .iter()
+                    .map(|x| {
+                        let value = x.copied().unwrap_or_default().to_string();
+                        (x.is_some() as u8, Value::String(value))
+                    })
+                    .unzip();
+
+                ArrowJsonColumn {
+                    name: field.name().clone(),
+                    count: col.len(),
+                    validity: Some(validity),
+                    data: Some(data),
+                    offset: None,
+                    type_id: None,
+                    children: None,
+                }
+            }
             _ => ArrowJsonColumn {
                 name: field.name().clone(),
                 count: col.len(),
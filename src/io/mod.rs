@@ -1,5 +1,5 @@
 //! Contains modules to interface with other formats such as [`csv`],
-//! [`parquet`], [`json`], [`ipc`], [`mod@print`] and [`avro`].
+//! [`parquet`], [`json`], [`ipc`], [`mod@print`], [`avro`] and [`orc`].
 #[cfg(any(
     feature = "io_csv_read",
     feature = "io_csv_read_async",
@@ -39,9 +39,47 @@ pub mod parquet;
 #[cfg_attr(docsrs, doc(cfg(feature = "io_avro")))]
 pub mod avro;
 
+#[cfg(feature = "io_orc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_orc")))]
+pub mod orc;
+
 #[cfg(feature = "io_print")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_print")))]
 pub mod print;
 
 #[cfg(any(feature = "io_csv_write", feature = "io_avro", feature = "io_json"))]
 mod iterator;
+
+/// Checks that `columns` can be written according to `schema`, i.e. that they have the same
+/// number of columns and that the data type of each field has the physical type of its array.
+#[cfg(any(feature = "io_csv_write", feature = "io_json", feature = "io_orc"))]
+fn check_columns<A: AsRef<dyn crate::array::Array>>(
+    schema: &crate::datatypes::Schema,
+    columns: &crate::columns::Columns<A>,
+) -> crate::error::Result<()> {
+    use crate::error::ArrowError;
+
+    if schema.fields().len() != columns.arrays().len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The schema has {} fields but there are {} columns",
+            schema.fields().len(),
+            columns.arrays().len()
+        )));
+    }
+    schema
+        .fields()
+        .iter()
+        .zip(columns.arrays())
+        .try_for_each(|(field, array)| {
+            let array = array.as_ref();
+            if field.data_type().to_physical_type() != array.data_type().to_physical_type() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The field \"{}\" of type {:?} cannot be written from an array of type {:?}",
+                    field.name(),
+                    field.data_type(),
+                    array.data_type()
+                )));
+            }
+            Ok(())
+        })
+}
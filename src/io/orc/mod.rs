@@ -0,0 +1,7 @@
+#![deny(missing_docs)]
+#![forbid(unsafe_code)]
+//! Read and write from and to Apache ORC
+mod protobuf;
+
+pub mod read;
+pub mod write;
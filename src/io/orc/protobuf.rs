@@ -0,0 +1,586 @@
+//! Protobuf encoding and decoding of the messages of ORC's metadata, as declared in
+//! <https://github.com/apache/orc/blob/main/proto/orc_proto.proto>.
+use std::convert::TryFrom;
+
+use crate::error::{ArrowError, Result};
+
+const VARINT: u64 = 0;
+const FIXED64: u64 = 1;
+const LENGTH_DELIMITED: u64 = 2;
+const FIXED32: u64 = 5;
+
+pub(super) fn write_varint(mut value: u64, buffer: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn write_uint(field: u64, value: u64, buffer: &mut Vec<u8>) {
+    write_varint(field << 3 | VARINT, buffer);
+    write_varint(value, buffer);
+}
+
+fn write_bytes(field: u64, value: &[u8], buffer: &mut Vec<u8>) {
+    write_varint(field << 3 | LENGTH_DELIMITED, buffer);
+    write_varint(value.len() as u64, buffer);
+    buffer.extend_from_slice(value);
+}
+
+fn write_message<M: Message>(field: u64, message: &M, buffer: &mut Vec<u8>) {
+    let mut data = vec![];
+    message.encode(&mut data);
+    write_bytes(field, &data, buffer);
+}
+
+fn write_packed(field: u64, values: &[u32], buffer: &mut Vec<u8>) {
+    if values.is_empty() {
+        return;
+    }
+    let mut data = vec![];
+    values
+        .iter()
+        .for_each(|value| write_varint(*value as u64, &mut data));
+    write_bytes(field, &data, buffer);
+}
+
+/// Reads a varint from the start of `data`, advancing it
+pub(super) fn read_varint(data: &mut &[u8]) -> Result<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (byte, remaining) = data
+            .split_first()
+            .ok_or_else(|| ArrowError::OutOfSpec("ORC varint is truncated".to_string()))?;
+        *data = remaining;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if *byte < 0x80 {
+            return Ok(value);
+        }
+    }
+    Err(ArrowError::OutOfSpec("ORC varint is too long".to_string()))
+}
+
+fn take<'a>(data: &mut &'a [u8], length: usize) -> Result<&'a [u8]> {
+    if data.len() < length {
+        return Err(ArrowError::OutOfSpec(
+            "ORC protobuf message is truncated".to_string(),
+        ));
+    }
+    let (value, remaining) = data.split_at(length);
+    *data = remaining;
+    Ok(value)
+}
+
+/// The value of a field of a protobuf message
+enum Value<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    /// fixed 32 and 64 bit values, which no field of ORC's metadata we read has
+    Fixed,
+}
+
+impl<'a> Value<'a> {
+    fn uint(&self) -> Result<u64> {
+        match self {
+            Value::Varint(value) => Ok(*value),
+            _ => Err(ArrowError::OutOfSpec(
+                "ORC protobuf field is not a varint".to_string(),
+            )),
+        }
+    }
+
+    fn bytes(&self) -> Result<&'a [u8]> {
+        match self {
+            Value::Bytes(value) => Ok(value),
+            _ => Err(ArrowError::OutOfSpec(
+                "ORC protobuf field is not length-delimited".to_string(),
+            )),
+        }
+    }
+
+    fn string(&self) -> Result<String> {
+        String::from_utf8(self.bytes()?.to_vec())
+            .map_err(|_| ArrowError::OutOfSpec("ORC protobuf string is not utf8".to_string()))
+    }
+
+    fn message<M: Message>(&self) -> Result<M> {
+        M::decode(self.bytes()?)
+    }
+
+    /// Extends `values` by a repeated varint field, packed or not
+    fn extend_packed(&self, values: &mut Vec<u32>) -> Result<()> {
+        match self {
+            Value::Varint(value) => values.push(*value as u32),
+            Value::Bytes(mut data) => {
+                while !data.is_empty() {
+                    values.push(read_varint(&mut data)? as u32);
+                }
+            }
+            Value::Fixed => {
+                return Err(ArrowError::OutOfSpec(
+                    "ORC protobuf field is not a varint".to_string(),
+                ))
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Calls `f` with each field number and value of the message `data`
+fn decode_fields<'a, F: FnMut(u64, Value<'a>) -> Result<()>>(
+    mut data: &'a [u8],
+    mut f: F,
+) -> Result<()> {
+    while !data.is_empty() {
+        let key = read_varint(&mut data)?;
+        let value = match key & 0x7 {
+            VARINT => Value::Varint(read_varint(&mut data)?),
+            LENGTH_DELIMITED => {
+                let length = read_varint(&mut data)? as usize;
+                Value::Bytes(take(&mut data, length)?)
+            }
+            FIXED64 => {
+                take(&mut data, 8)?;
+                Value::Fixed
+            }
+            FIXED32 => {
+                take(&mut data, 4)?;
+                Value::Fixed
+            }
+            other => {
+                return Err(ArrowError::OutOfSpec(format!(
+                    "ORC protobuf wire type {} is not supported",
+                    other
+                )))
+            }
+        };
+        f(key >> 3, value)?;
+    }
+    Ok(())
+}
+
+pub(super) trait Message: Sized {
+    fn encode(&self, buffer: &mut Vec<u8>);
+
+    fn decode(data: &[u8]) -> Result<Self>;
+}
+
+macro_rules! protobuf_enum {
+    ($name:ident, $($variant:ident = $value:expr),+ $(,)?) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub(super) enum $name {
+            $($variant = $value,)+
+        }
+
+        impl TryFrom<u64> for $name {
+            type Error = ArrowError;
+
+            fn try_from(value: u64) -> Result<Self> {
+                match value {
+                    $($value => Ok($name::$variant),)+
+                    other => Err(ArrowError::OutOfSpec(format!(
+                        "{} is not a valid ORC {}",
+                        other,
+                        stringify!($name)
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+protobuf_enum!(
+    CompressionKind,
+    None = 0,
+    Zlib = 1,
+    Snappy = 2,
+    Lzo = 3,
+    Lz4 = 4,
+    Zstd = 5,
+);
+
+protobuf_enum!(
+    TypeKind,
+    Boolean = 0,
+    Byte = 1,
+    Short = 2,
+    Int = 3,
+    Long = 4,
+    Float = 5,
+    Double = 6,
+    String = 7,
+    Binary = 8,
+    Timestamp = 9,
+    List = 10,
+    Map = 11,
+    Struct = 12,
+    Union = 13,
+    Decimal = 14,
+    Date = 15,
+    Varchar = 16,
+    Char = 17,
+    TimestampInstant = 18,
+);
+
+protobuf_enum!(
+    StreamKind,
+    Present = 0,
+    Data = 1,
+    Length = 2,
+    DictionaryData = 3,
+    DictionaryCount = 4,
+    Secondary = 5,
+    RowIndex = 6,
+    BloomFilter = 7,
+    BloomFilterUtf8 = 8,
+    EncryptedIndex = 9,
+    EncryptedData = 10,
+    StripeStatistics = 100,
+    FileStatistics = 101,
+);
+
+protobuf_enum!(
+    ColumnEncodingKind,
+    Direct = 0,
+    Dictionary = 1,
+    DirectV2 = 2,
+    DictionaryV2 = 3,
+);
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct PostScript {
+    pub footer_length: u64,
+    pub compression: CompressionKind,
+    pub compression_block_size: u64,
+    pub version: Vec<u32>,
+    pub metadata_length: u64,
+    pub writer_version: u32,
+}
+
+impl Message for PostScript {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        write_uint(1, self.footer_length, buffer);
+        write_uint(2, self.compression as u64, buffer);
+        write_uint(3, self.compression_block_size, buffer);
+        write_packed(4, &self.version, buffer);
+        write_uint(5, self.metadata_length, buffer);
+        write_uint(6, self.writer_version as u64, buffer);
+        write_bytes(8000, b"ORC", buffer);
+    }
+
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut message = Self {
+            footer_length: 0,
+            compression: CompressionKind::None,
+            // the default of ORC's writers
+            compression_block_size: 256 * 1024,
+            version: vec![],
+            metadata_length: 0,
+            writer_version: 0,
+        };
+        decode_fields(data, |field, value| {
+            match field {
+                1 => message.footer_length = value.uint()?,
+                2 => message.compression = CompressionKind::try_from(value.uint()?)?,
+                3 => message.compression_block_size = value.uint()?,
+                4 => value.extend_packed(&mut message.version)?,
+                5 => message.metadata_length = value.uint()?,
+                6 => message.writer_version = value.uint()? as u32,
+                _ => {}
+            };
+            Ok(())
+        })?;
+        Ok(message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl Message for StripeInformation {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        write_uint(1, self.offset, buffer);
+        write_uint(2, self.index_length, buffer);
+        write_uint(3, self.data_length, buffer);
+        write_uint(4, self.footer_length, buffer);
+        write_uint(5, self.number_of_rows, buffer);
+    }
+
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut message = Self {
+            offset: 0,
+            index_length: 0,
+            data_length: 0,
+            footer_length: 0,
+            number_of_rows: 0,
+        };
+        decode_fields(data, |field, value| {
+            match field {
+                1 => message.offset = value.uint()?,
+                2 => message.index_length = value.uint()?,
+                3 => message.data_length = value.uint()?,
+                4 => message.footer_length = value.uint()?,
+                5 => message.number_of_rows = value.uint()?,
+                _ => {}
+            };
+            Ok(())
+        })?;
+        Ok(message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Type {
+    pub kind: TypeKind,
+    pub subtypes: Vec<u32>,
+    pub field_names: Vec<String>,
+}
+
+impl Message for Type {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        write_uint(1, self.kind as u64, buffer);
+        write_packed(2, &self.subtypes, buffer);
+        self.field_names
+            .iter()
+            .for_each(|name| write_bytes(3, name.as_bytes(), buffer));
+    }
+
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut message = Self {
+            kind: TypeKind::Boolean,
+            subtypes: vec![],
+            field_names: vec![],
+        };
+        decode_fields(data, |field, value| {
+            match field {
+                1 => message.kind = TypeKind::try_from(value.uint()?)?,
+                2 => value.extend_packed(&mut message.subtypes)?,
+                3 => message.field_names.push(value.string()?),
+                _ => {}
+            };
+            Ok(())
+        })?;
+        Ok(message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct ColumnStatistics {
+    pub number_of_values: u64,
+    pub has_null: bool,
+}
+
+impl Message for ColumnStatistics {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        write_uint(1, self.number_of_values, buffer);
+        write_uint(10, self.has_null as u64, buffer);
+    }
+
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut message = Self {
+            number_of_values: 0,
+            has_null: false,
+        };
+        decode_fields(data, |field, value| {
+            match field {
+                1 => message.number_of_values = value.uint()?,
+                10 => message.has_null = value.uint()? != 0,
+                _ => {}
+            };
+            Ok(())
+        })?;
+        Ok(message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Footer {
+    pub header_length: u64,
+    pub content_length: u64,
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<Type>,
+    pub number_of_rows: u64,
+    pub statistics: Vec<ColumnStatistics>,
+    pub row_index_stride: u32,
+}
+
+impl Message for Footer {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        write_uint(1, self.header_length, buffer);
+        write_uint(2, self.content_length, buffer);
+        self.stripes
+            .iter()
+            .for_each(|stripe| write_message(3, stripe, buffer));
+        self.types.iter().for_each(|x| write_message(4, x, buffer));
+        write_uint(6, self.number_of_rows, buffer);
+        self.statistics
+            .iter()
+            .for_each(|x| write_message(7, x, buffer));
+        write_uint(8, self.row_index_stride as u64, buffer);
+    }
+
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut message = Self {
+            header_length: 0,
+            content_length: 0,
+            stripes: vec![],
+            types: vec![],
+            number_of_rows: 0,
+            statistics: vec![],
+            row_index_stride: 0,
+        };
+        decode_fields(data, |field, value| {
+            match field {
+                1 => message.header_length = value.uint()?,
+                2 => message.content_length = value.uint()?,
+                3 => message.stripes.push(value.message()?),
+                4 => message.types.push(value.message()?),
+                6 => message.number_of_rows = value.uint()?,
+                7 => message.statistics.push(value.message()?),
+                8 => message.row_index_stride = value.uint()? as u32,
+                _ => {}
+            };
+            Ok(())
+        })?;
+        Ok(message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Stream {
+    pub kind: StreamKind,
+    pub column: u32,
+    pub length: u64,
+}
+
+impl Message for Stream {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        write_uint(1, self.kind as u64, buffer);
+        write_uint(2, self.column as u64, buffer);
+        write_uint(3, self.length, buffer);
+    }
+
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut message = Self {
+            kind: StreamKind::Present,
+            column: 0,
+            length: 0,
+        };
+        decode_fields(data, |field, value| {
+            match field {
+                1 => message.kind = StreamKind::try_from(value.uint()?)?,
+                2 => message.column = value.uint()? as u32,
+                3 => message.length = value.uint()?,
+                _ => {}
+            };
+            Ok(())
+        })?;
+        Ok(message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct ColumnEncoding {
+    pub kind: ColumnEncodingKind,
+    pub dictionary_size: u32,
+}
+
+impl Message for ColumnEncoding {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        write_uint(1, self.kind as u64, buffer);
+        if matches!(
+            self.kind,
+            ColumnEncodingKind::Dictionary | ColumnEncodingKind::DictionaryV2
+        ) {
+            write_uint(2, self.dictionary_size as u64, buffer);
+        }
+    }
+
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut message = Self {
+            kind: ColumnEncodingKind::Direct,
+            dictionary_size: 0,
+        };
+        decode_fields(data, |field, value| {
+            match field {
+                1 => message.kind = ColumnEncodingKind::try_from(value.uint()?)?,
+                2 => message.dictionary_size = value.uint()? as u32,
+                _ => {}
+            };
+            Ok(())
+        })?;
+        Ok(message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+    pub writer_timezone: String,
+}
+
+impl Message for StripeFooter {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        self.streams
+            .iter()
+            .for_each(|x| write_message(1, x, buffer));
+        self.columns
+            .iter()
+            .for_each(|x| write_message(2, x, buffer));
+        write_bytes(3, self.writer_timezone.as_bytes(), buffer);
+    }
+
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut message = Self {
+            streams: vec![],
+            columns: vec![],
+            writer_timezone: String::new(),
+        };
+        decode_fields(data, |field, value| {
+            match field {
+                1 => message.streams.push(value.message()?),
+                2 => message.columns.push(value.message()?),
+                3 => message.writer_timezone = value.string()?,
+                _ => {}
+            };
+            Ok(())
+        })?;
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let footer = StripeFooter {
+            streams: vec![Stream {
+                kind: StreamKind::DictionaryData,
+                column: 300,
+                length: u64::MAX,
+            }],
+            columns: vec![ColumnEncoding {
+                kind: ColumnEncodingKind::DictionaryV2,
+                dictionary_size: 2,
+            }],
+            writer_timezone: "UTC".to_string(),
+        };
+        let mut data = vec![];
+        footer.encode(&mut data);
+        assert_eq!(StripeFooter::decode(&data)?, footer);
+
+        // unknown stream kinds are an error
+        assert!(StripeFooter::decode(&[0x0a, 0x02, 0x08, 0x0b]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::error::{ArrowError, Result};
+
+use super::super::protobuf::CompressionKind;
+
+/// Decompresses an ORC stream: a sequence of chunks, each with a 3-byte header declaring its
+/// length and whether it is stored uncompressed.
+pub(super) fn decompress(data: &[u8], compression: CompressionKind) -> Result<Vec<u8>> {
+    if compression == CompressionKind::None {
+        return Ok(data.to_vec());
+    }
+
+    let mut data = data;
+    let mut decompressed = vec![];
+    while !data.is_empty() {
+        if data.len() < 3 {
+            return Err(ArrowError::OutOfSpec(
+                "ORC compressed chunk header is truncated".to_string(),
+            ));
+        }
+        let header = u32::from_le_bytes([data[0], data[1], data[2], 0]);
+        let length = (header >> 1) as usize;
+        let is_original = header & 1 == 1;
+        if data.len() < 3 + length {
+            return Err(ArrowError::OutOfSpec(
+                "ORC compressed chunk is truncated".to_string(),
+            ));
+        }
+        let chunk = &data[3..3 + length];
+        data = &data[3 + length..];
+
+        if is_original {
+            decompressed.extend_from_slice(chunk);
+        } else {
+            decompress_chunk(chunk, compression, &mut decompressed)?;
+        }
+    }
+    Ok(decompressed)
+}
+
+#[cfg(feature = "io_orc_compression")]
+fn decompress_chunk(
+    chunk: &[u8],
+    compression: CompressionKind,
+    decompressed: &mut Vec<u8>,
+) -> Result<()> {
+    match compression {
+        CompressionKind::Zlib => {
+            use std::io::Read;
+            let mut decoder = libflate::deflate::Decoder::new(chunk);
+            decoder.read_to_end(decompressed)?;
+            Ok(())
+        }
+        CompressionKind::Snappy => {
+            let chunk = snap::raw::Decoder::new()
+                .decompress_vec(chunk)
+                .map_err(|e| ArrowError::ExternalFormat(e.to_string()))?;
+            decompressed.extend_from_slice(&chunk);
+            Ok(())
+        }
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Decompressing ORC with {:?}",
+            other
+        ))),
+    }
+}
+
+#[cfg(not(feature = "io_orc_compression"))]
+fn decompress_chunk(_: &[u8], compression: CompressionKind, _: &mut Vec<u8>) -> Result<()> {
+    Err(ArrowError::InvalidArgumentError(format!(
+        "Trying to decompress ORC with {:?} but feature 'io_orc_compression' is not active.",
+        compression
+    )))
+}
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::{DataType, TimeUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::{NativeType, Offset};
+
+use super::super::protobuf::{ColumnEncoding, ColumnEncodingKind, StreamKind, TypeKind};
+use super::rle::{decode_booleans, decode_bytes, decode_integers, Version};
+
+/// The seconds of ORC timestamps are relative to 2015-01-01 00:00:00 (in the writer's timezone)
+const TIMESTAMP_BASE_SECONDS: i64 = 1420070400;
+
+/// Returns the arrow [`DataType`] of an ORC type, or an error if it is not supported.
+pub(super) fn to_data_type(kind: TypeKind) -> Result<DataType> {
+    Ok(match kind {
+        TypeKind::Boolean => DataType::Boolean,
+        TypeKind::Byte => DataType::Int8,
+        TypeKind::Short => DataType::Int16,
+        TypeKind::Int => DataType::Int32,
+        TypeKind::Long => DataType::Int64,
+        TypeKind::Float => DataType::Float32,
+        TypeKind::Double => DataType::Float64,
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => DataType::Utf8,
+        TypeKind::Binary => DataType::Binary,
+        TypeKind::Timestamp => DataType::Timestamp(TimeUnit::Nanosecond, None),
+        TypeKind::TimestampInstant => {
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".to_string()))
+        }
+        TypeKind::Date => DataType::Date32,
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Reading ORC columns of type {:?}",
+                other
+            )))
+        }
+    })
+}
+
+/// The decompressed streams of a column of a stripe
+pub(super) struct Column<'a> {
+    pub streams: HashMap<StreamKind, Vec<u8>>,
+    pub encoding: &'a ColumnEncoding,
+    pub number_of_rows: usize,
+}
+
+impl<'a> Column<'a> {
+    fn stream(&self, kind: StreamKind) -> Result<&[u8]> {
+        self.streams
+            .get(&kind)
+            .map(|x| x.as_slice())
+            .ok_or_else(|| {
+                ArrowError::OutOfSpec(format!("ORC column is missing its {:?} stream", kind))
+            })
+    }
+
+    fn version(&self) -> Version {
+        match self.encoding.kind {
+            ColumnEncodingKind::Direct | ColumnEncodingKind::Dictionary => Version::V1,
+            ColumnEncodingKind::DirectV2 | ColumnEncodingKind::DictionaryV2 => Version::V2,
+        }
+    }
+
+    fn integers(&self, kind: StreamKind, length: usize, signed: bool) -> Result<Vec<i64>> {
+        decode_integers(self.stream(kind)?, length, signed, self.version())
+    }
+}
+
+/// Deserializes a column of a stripe into an [`Array`] of `data_type`.
+pub(super) fn deserialize(column: &Column, data_type: &DataType) -> Result<Arc<dyn Array>> {
+    let validity = column
+        .streams
+        .get(&StreamKind::Present)
+        .map(|present| decode_booleans(present, column.number_of_rows))
+        .transpose()?
+        .map(Bitmap::from);
+    // the non-null values are the only ones stored in the streams
+    let length = validity
+        .as_ref()
+        .map(|x| x.len() - x.null_count())
+        .unwrap_or(column.number_of_rows);
+
+    Ok(match data_type {
+        DataType::Boolean => {
+            let values = decode_booleans(column.stream(StreamKind::Data)?, length)?;
+            let values = scatter(values.iter(), &validity, false)
+                .collect::<MutableBitmap>()
+                .into();
+            Arc::new(BooleanArray::from_data(data_type.clone(), values, validity))
+        }
+        DataType::Int8 => {
+            let values = decode_bytes(column.stream(StreamKind::Data)?, length)?;
+            let values = values.into_iter().map(|x| x as i8);
+            primitive(data_type, values, validity)
+        }
+        DataType::Int16 => {
+            let values = column.integers(StreamKind::Data, length, true)?;
+            primitive(data_type, values.into_iter().map(|x| x as i16), validity)
+        }
+        DataType::Int32 | DataType::Date32 => {
+            let values = column.integers(StreamKind::Data, length, true)?;
+            primitive(data_type, values.into_iter().map(|x| x as i32), validity)
+        }
+        DataType::Int64 => {
+            let values = column.integers(StreamKind::Data, length, true)?;
+            primitive(data_type, values.into_iter(), validity)
+        }
+        DataType::Float32 => floats(column, data_type, length, validity, |x| {
+            f32::from_le_bytes(x.try_into().unwrap())
+        })?,
+        DataType::Float64 => floats(column, data_type, length, validity, |x| {
+            f64::from_le_bytes(x.try_into().unwrap())
+        })?,
+        DataType::Utf8 => {
+            let (offsets, values) = binary::<i32>(column, length, &validity)?;
+            Arc::new(Utf8Array::<i32>::try_new(
+                data_type.clone(),
+                offsets,
+                values,
+                validity,
+            )?)
+        }
+        DataType::Binary => {
+            let (offsets, values) = binary::<i32>(column, length, &validity)?;
+            Arc::new(BinaryArray::<i32>::try_new(
+                data_type.clone(),
+                offsets,
+                values,
+                validity,
+            )?)
+        }
+        DataType::Timestamp(_, _) => {
+            let seconds = column.integers(StreamKind::Data, length, true)?;
+            let nanos = column.integers(StreamKind::Secondary, length, false)?;
+            let values = seconds
+                .into_iter()
+                .zip(nanos)
+                .map(|(seconds, nanos)| to_nanoseconds(seconds, nanos))
+                .collect::<Result<Vec<_>>>()?;
+            primitive(data_type, values.into_iter(), validity)
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Reading ORC columns into {:?}",
+                other
+            )))
+        }
+    })
+}
+
+/// Interleaves the non-null `values` with `default` in the null slots of `validity`.
+fn scatter<'a, T: Copy + 'a, I: Iterator<Item = T> + 'a>(
+    values: I,
+    validity: &'a Option<Bitmap>,
+    default: T,
+) -> Box<dyn Iterator<Item = T> + 'a> {
+    match validity {
+        None => Box::new(values),
+        Some(validity) => {
+            let mut values = values;
+            Box::new(validity.iter().map(move |is_valid| {
+                if is_valid {
+                    values.next().unwrap_or(default)
+                } else {
+                    default
+                }
+            }))
+        }
+    }
+}
+
+fn primitive<T: NativeType, I: Iterator<Item = T>>(
+    data_type: &DataType,
+    values: I,
+    validity: Option<Bitmap>,
+) -> Arc<dyn Array> {
+    let values = scatter(values, &validity, T::default()).collect::<Vec<T>>();
+    Arc::new(PrimitiveArray::<T>::from_data(
+        data_type.clone(),
+        values.into(),
+        validity,
+    ))
+}
+
+fn floats<T: NativeType, F: Fn(&[u8]) -> T>(
+    column: &Column,
+    data_type: &DataType,
+    length: usize,
+    validity: Option<Bitmap>,
+    decode: F,
+) -> Result<Arc<dyn Array>> {
+    let size = std::mem::size_of::<T>();
+    let data = column.stream(StreamKind::Data)?;
+    if data.len() < length * size {
+        return Err(ArrowError::OutOfSpec(
+            "ORC stream has fewer values than declared".to_string(),
+        ));
+    }
+    let values = data[..length * size].chunks_exact(size).map(decode);
+    Ok(primitive(data_type, values, validity))
+}
+
+/// Returns the offsets and values of a string or binary column, of the direct or
+/// the dictionary encoding.
+fn binary<O: Offset>(
+    column: &Column,
+    length: usize,
+    validity: &Option<Bitmap>,
+) -> Result<(crate::buffer::Buffer<O>, crate::buffer::Buffer<u8>)> {
+    let (lengths, data, indices) = match column.encoding.kind {
+        ColumnEncodingKind::Direct | ColumnEncodingKind::DirectV2 => {
+            let lengths = column.integers(StreamKind::Length, length, false)?;
+            (lengths, column.stream(StreamKind::Data)?, None)
+        }
+        ColumnEncodingKind::Dictionary | ColumnEncodingKind::DictionaryV2 => {
+            let size = column.encoding.dictionary_size as usize;
+            let lengths = column.integers(StreamKind::Length, size, false)?;
+            let indices = column.integers(StreamKind::Data, length, false)?;
+            let data = match column.streams.get(&StreamKind::DictionaryData) {
+                Some(data) => data.as_slice(),
+                // an empty dictionary has no data
+                None => &[],
+            };
+            (lengths, data, Some(indices))
+        }
+    };
+
+    // the start of each value (of the dictionary) in `data`
+    let mut starts = Vec::with_capacity(lengths.len());
+    let mut total = 0usize;
+    for length in &lengths {
+        starts.push(total);
+        total = usize::try_from(*length)
+            .ok()
+            .and_then(|length| total.checked_add(length))
+            .filter(|total| *total <= data.len())
+            .ok_or_else(|| {
+                ArrowError::OutOfSpec("ORC string lengths exceed its data".to_string())
+            })?;
+    }
+    let value = |index: usize| -> Result<&[u8]> {
+        let start = *starts.get(index).ok_or_else(|| {
+            ArrowError::OutOfSpec("ORC dictionary index is out of bounds".to_string())
+        })?;
+        Ok(&data[start..start + lengths[index] as usize])
+    };
+
+    let mut offsets = Vec::<O>::with_capacity(column.number_of_rows + 1);
+    let mut values = Vec::<u8>::new();
+    offsets.push(O::default());
+    let positions = scatter((0..length).map(Some), validity, None);
+    for position in positions {
+        if let Some(position) = position {
+            let index = match &indices {
+                Some(indices) => indices[position] as usize,
+                None => position,
+            };
+            values.extend_from_slice(value(index)?);
+        }
+        let offset = O::from_usize(values.len()).ok_or(ArrowError::Overflow)?;
+        offsets.push(offset);
+    }
+    Ok((offsets.into(), values.into()))
+}
+
+/// Combines the seconds (relative to [`TIMESTAMP_BASE_SECONDS`]) and the encoded nanoseconds
+/// of a timestamp into nanoseconds since the epoch.
+fn to_nanoseconds(seconds: i64, nanos: i64) -> Result<i64> {
+    let zeros = nanos & 0x7;
+    let mut nanos = nanos >> 3;
+    if zeros != 0 {
+        nanos *= 10i64.pow(zeros as u32 + 1);
+    }
+    let mut seconds = seconds
+        .checked_add(TIMESTAMP_BASE_SECONDS)
+        .ok_or(ArrowError::Overflow)?;
+    // writers add a second to negative seconds with more than 999999 nanoseconds
+    if seconds < 0 && nanos > 999_999 {
+        seconds -= 1;
+    }
+    seconds
+        .checked_mul(1_000_000_000)
+        .and_then(|x| x.checked_add(nanos))
+        .ok_or(ArrowError::Overflow)
+}
@@ -0,0 +1,279 @@
+//! APIs to read from Apache ORC format.
+//!
+//! Each stripe of the file is read into a [`Columns`].
+mod decompress;
+mod deserialize;
+mod rle;
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use crate::array::Array;
+use crate::columns::Columns;
+use crate::datatypes::{Field, Schema};
+use crate::error::{ArrowError, Result};
+
+use super::protobuf::*;
+use decompress::decompress;
+use deserialize::{deserialize, to_data_type, Column};
+
+const MAGIC: &[u8] = b"ORC";
+
+/// The metadata of an ORC file, read by [`read_metadata`]
+#[derive(Debug, Clone)]
+pub struct FileMetadata {
+    /// The [`Schema`] of the file, whose fields are its (top-level) columns
+    pub schema: Schema,
+    /// The number of rows of the file
+    pub number_of_rows: u64,
+    compression: CompressionKind,
+    stripes: Vec<StripeInformation>,
+    /// the column id of each field of the schema
+    columns: Vec<u32>,
+}
+
+impl FileMetadata {
+    /// Returns the number of stripes of the file
+    pub fn number_of_stripes(&self) -> usize {
+        self.stripes.len()
+    }
+}
+
+fn read_exact<R: Read + Seek>(reader: &mut R, offset: u64, length: usize) -> Result<Vec<u8>> {
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut data = vec![0; length];
+    reader.read_exact(&mut data)?;
+    Ok(data)
+}
+
+/// Reads the metadata of an ORC file, i.e. its postscript and footer.
+/// # Errors
+/// Errors when the file is not ORC or when a column's type is not supported (e.g. nested types).
+pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata> {
+    let file_length = reader.seek(SeekFrom::End(0))?;
+    if file_length < MAGIC.len() as u64 + 1 || read_exact(reader, 0, MAGIC.len())? != MAGIC {
+        return Err(ArrowError::OutOfSpec(
+            "The file is not an ORC file".to_string(),
+        ));
+    }
+
+    let postscript_length = read_exact(reader, file_length - 1, 1)?[0] as u64;
+    if postscript_length + 1 > file_length {
+        return Err(ArrowError::OutOfSpec(
+            "The ORC postscript is larger than the file".to_string(),
+        ));
+    }
+    let postscript_offset = file_length - 1 - postscript_length;
+    let postscript = read_exact(reader, postscript_offset, postscript_length as usize)?;
+    let postscript = PostScript::decode(&postscript)?;
+
+    if postscript.footer_length > postscript_offset {
+        return Err(ArrowError::OutOfSpec(
+            "The ORC footer is larger than the file".to_string(),
+        ));
+    }
+    let footer = read_exact(
+        reader,
+        postscript_offset - postscript.footer_length,
+        postscript.footer_length as usize,
+    )?;
+    let footer = Footer::decode(&decompress(&footer, postscript.compression)?)?;
+
+    let root = footer
+        .types
+        .first()
+        .filter(|root| root.kind == TypeKind::Struct)
+        .ok_or_else(|| {
+            ArrowError::OutOfSpec("The root type of an ORC file must be a struct".to_string())
+        })?;
+    if root.subtypes.len() != root.field_names.len() {
+        return Err(ArrowError::OutOfSpec(
+            "The ORC root struct must have a name per field".to_string(),
+        ));
+    }
+    let fields = root
+        .subtypes
+        .iter()
+        .zip(root.field_names.iter())
+        .map(|(column, name)| {
+            let kind = footer
+                .types
+                .get(*column as usize)
+                .ok_or_else(|| {
+                    ArrowError::OutOfSpec(format!("ORC type {} does not exist", column))
+                })?
+                .kind;
+            to_data_type(kind).map(|data_type| Field::new(name, data_type, true))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(FileMetadata {
+        schema: Schema::new(fields),
+        number_of_rows: footer.number_of_rows,
+        compression: postscript.compression,
+        stripes: footer.stripes,
+        columns: root.subtypes.clone(),
+    })
+}
+
+/// Reads the stripe `stripe` of an ORC file into a [`Columns`] with the fields of
+/// `metadata.schema` at `projection`, or all its fields when `projection` is `None`.
+/// # Errors
+/// Errors when the stripe or a projected field does not exist or when the file is invalid.
+pub fn read_stripe<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    stripe: usize,
+    projection: Option<&[usize]>,
+) -> Result<Columns<Arc<dyn Array>>> {
+    let information = metadata.stripes.get(stripe).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "The ORC file has {} stripes but stripe {} was requested",
+            metadata.stripes.len(),
+            stripe
+        ))
+    })?;
+    let fields = match projection {
+        Some(projection) => projection.to_vec(),
+        None => (0..metadata.columns.len()).collect(),
+    };
+    if let Some(field) = fields.iter().find(|x| **x >= metadata.columns.len()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The ORC file has {} fields but field {} was projected",
+            metadata.columns.len(),
+            field
+        )));
+    }
+
+    let footer = read_exact(
+        reader,
+        information.offset + information.index_length + information.data_length,
+        information.footer_length as usize,
+    )?;
+    let footer = StripeFooter::decode(&decompress(&footer, metadata.compression)?)?;
+
+    // the streams are stored in the order of the footer, starting with the index streams
+    let mut locations = HashMap::new();
+    let mut offset = information.offset;
+    for stream in &footer.streams {
+        locations.insert((stream.column, stream.kind), (offset, stream.length));
+        offset += stream.length;
+    }
+
+    let number_of_rows = information.number_of_rows as usize;
+    let arrays = fields
+        .iter()
+        .map(|field| {
+            let column = metadata.columns[*field];
+            let encoding = footer.columns.get(column as usize).ok_or_else(|| {
+                ArrowError::OutOfSpec(format!("ORC column {} has no encoding", column))
+            })?;
+
+            let mut streams = HashMap::new();
+            for kind in [
+                StreamKind::Present,
+                StreamKind::Data,
+                StreamKind::Length,
+                StreamKind::DictionaryData,
+                StreamKind::Secondary,
+            ] {
+                if let Some((offset, length)) = locations.get(&(column, kind)) {
+                    let data = read_exact(reader, *offset, *length as usize)?;
+                    streams.insert(kind, decompress(&data, metadata.compression)?);
+                }
+            }
+
+            let column = Column {
+                streams,
+                encoding,
+                number_of_rows,
+            };
+            deserialize(&column, metadata.schema.fields()[*field].data_type())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Columns::try_new(arrays)
+}
+
+/// Single threaded, blocking reader of ORC; [`Iterator`] of [`Columns`], one per stripe.
+pub struct Reader<R: Read + Seek> {
+    reader: R,
+    metadata: FileMetadata,
+    projection: Option<Vec<usize>>,
+    schema: Schema,
+    stripe: usize,
+}
+
+impl<R: Read + Seek> Reader<R> {
+    /// Creates a new [`Reader`] of the fields of the file at `projection`, or of all its fields
+    /// when `projection` is `None`.
+    /// # Errors
+    /// Errors when the metadata of the file can't be read or when a projected field does not exist.
+    pub fn try_new(mut reader: R, projection: Option<Vec<usize>>) -> Result<Self> {
+        let metadata = read_metadata(&mut reader)?;
+        let schema = match &projection {
+            Some(projection) => {
+                let fields = metadata.schema.fields();
+                let fields = projection
+                    .iter()
+                    .map(|index| {
+                        fields.get(*index).cloned().ok_or_else(|| {
+                            ArrowError::InvalidArgumentError(format!(
+                                "The ORC file has {} fields but field {} was projected",
+                                fields.len(),
+                                index
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Schema::new(fields)
+            }
+            None => metadata.schema.clone(),
+        };
+        Ok(Self {
+            reader,
+            metadata,
+            projection,
+            schema,
+            stripe: 0,
+        })
+    }
+
+    /// Returns the [`Schema`] of the [`Columns`] read by this [`Reader`]
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Returns the [`FileMetadata`] of the file
+    pub fn metadata(&self) -> &FileMetadata {
+        &self.metadata
+    }
+
+    /// Deconstructs itself into its internal reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read + Seek> Iterator for Reader<R> {
+    type Item = Result<Columns<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stripe == self.metadata.number_of_stripes() {
+            return None;
+        }
+        let columns = read_stripe(
+            &mut self.reader,
+            &self.metadata,
+            self.stripe,
+            self.projection.as_deref(),
+        );
+        self.stripe += 1;
+        Some(columns)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.metadata.number_of_stripes() - self.stripe;
+        (remaining, Some(remaining))
+    }
+}
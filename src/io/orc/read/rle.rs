@@ -0,0 +1,339 @@
+//! Decoders of the run length encodings of ORC, as described in
+//! <https://orc.apache.org/specification/ORCv1/#run-length-encoding>.
+use crate::bitmap::utils::bytes_for;
+use crate::bitmap::MutableBitmap;
+use crate::error::{ArrowError, Result};
+
+use super::super::protobuf::read_varint;
+
+/// The version of the integer run length encoding of a column, declared by its encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Version {
+    V1,
+    V2,
+}
+
+fn truncated() -> ArrowError {
+    ArrowError::OutOfSpec("ORC stream has fewer values than declared".to_string())
+}
+
+fn take<'a>(data: &mut &'a [u8], length: usize) -> Result<&'a [u8]> {
+    if data.len() < length {
+        return Err(truncated());
+    }
+    let (value, remaining) = data.split_at(length);
+    *data = remaining;
+    Ok(value)
+}
+
+fn take_byte(data: &mut &[u8]) -> Result<u8> {
+    take(data, 1).map(|x| x[0])
+}
+
+/// Decodes `length` values of the byte run length encoding.
+pub(super) fn decode_bytes(mut data: &[u8], length: usize) -> Result<Vec<u8>> {
+    let mut values = Vec::with_capacity(length);
+    while values.len() < length {
+        let control = take_byte(&mut data)? as i8;
+        if control >= 0 {
+            let run = control as usize + 3;
+            let value = take_byte(&mut data)?;
+            values.resize(values.len() + run, value);
+        } else {
+            let literals = take(&mut data, -(control as i16) as usize)?;
+            values.extend_from_slice(literals);
+        }
+    }
+    values.truncate(length);
+    Ok(values)
+}
+
+/// Decodes `length` values of the boolean run length encoding, whose bits are packed most
+/// significant bit first.
+pub(super) fn decode_booleans(data: &[u8], length: usize) -> Result<MutableBitmap> {
+    let bytes = decode_bytes(data, bytes_for(length))?;
+    let mut bitmap = MutableBitmap::with_capacity(length);
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| byte & (0x80 >> i) != 0))
+        .take(length)
+        .for_each(|x| bitmap.push(x));
+    Ok(bitmap)
+}
+
+#[inline]
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+#[inline]
+fn to_signed(value: u64, signed: bool) -> i64 {
+    if signed {
+        unzigzag(value)
+    } else {
+        value as i64
+    }
+}
+
+/// Decodes `length` values of the integer run length encoding of `version`. `signed` declares
+/// whether the stream is of signed integers (zigzag encoded) or of non-negative integers.
+pub(super) fn decode_integers(
+    data: &[u8],
+    length: usize,
+    signed: bool,
+    version: Version,
+) -> Result<Vec<i64>> {
+    let mut values = Vec::with_capacity(length);
+    match version {
+        Version::V1 => decode_v1(data, length, signed, &mut values)?,
+        Version::V2 => decode_v2(data, length, signed, &mut values)?,
+    }
+    values.truncate(length);
+    Ok(values)
+}
+
+fn decode_v1(mut data: &[u8], length: usize, signed: bool, values: &mut Vec<i64>) -> Result<()> {
+    while values.len() < length {
+        let control = take_byte(&mut data)? as i8;
+        if control >= 0 {
+            let run = control as i64 + 3;
+            let delta = take_byte(&mut data)? as i8 as i64;
+            let base = to_signed(read_varint(&mut data)?, signed);
+            values.extend((0..run).map(|i| base.wrapping_add(i * delta)));
+        } else {
+            for _ in 0..-(control as i16) {
+                values.push(to_signed(read_varint(&mut data)?, signed));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The width in bits of the 5-bit encoded width of the run length encoding v2
+fn decode_width(code: u8) -> u32 {
+    match code {
+        0..=23 => code as u32 + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+/// The smallest width supported by the bit packing that fits `bits`
+fn closest_width(bits: u32) -> u32 {
+    match bits {
+        0..=24 => bits.max(1),
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+/// Reads `length` values bit packed with `width` bits, big endian
+fn read_packed(data: &mut &[u8], width: u32, length: usize) -> Result<Vec<u64>> {
+    let bytes = take(data, bytes_for(length * width as usize))?;
+    let mut bytes = bytes.iter();
+    let mut values = Vec::with_capacity(length);
+    let mut acc = 0u128;
+    let mut bits = 0;
+    for _ in 0..length {
+        while bits < width {
+            acc = acc << 8 | *bytes.next().unwrap() as u128;
+            bits += 8;
+        }
+        bits -= width;
+        values.push((acc >> bits) as u64 & (u64::MAX >> (64 - width)));
+        acc &= (1 << bits) - 1;
+    }
+    Ok(values)
+}
+
+fn read_header_length(header: u8, data: &mut &[u8]) -> Result<usize> {
+    Ok(((header as usize & 1) << 8 | take_byte(data)? as usize) + 1)
+}
+
+fn decode_v2(mut data: &[u8], length: usize, signed: bool, values: &mut Vec<i64>) -> Result<()> {
+    while values.len() < length {
+        let header = take_byte(&mut data)?;
+        match header >> 6 {
+            // short repeat
+            0 => {
+                let bytes = take(&mut data, ((header >> 3) & 0x7) as usize + 1)?;
+                let run = (header & 0x7) as usize + 3;
+                let value = bytes.iter().fold(0u64, |acc, x| acc << 8 | *x as u64);
+                values.resize(values.len() + run, to_signed(value, signed));
+            }
+            // direct
+            1 => {
+                let width = decode_width((header >> 1) & 0x1f);
+                let run = read_header_length(header, &mut data)?;
+                let packed = read_packed(&mut data, width, run)?;
+                values.extend(packed.into_iter().map(|x| to_signed(x, signed)));
+            }
+            // patched base
+            2 => decode_patched_base(header, &mut data, values)?,
+            // delta
+            _ => {
+                let code = (header >> 1) & 0x1f;
+                let run = read_header_length(header, &mut data)?;
+                let base = to_signed(read_varint(&mut data)?, signed);
+                let delta_base = unzigzag(read_varint(&mut data)?);
+
+                values.push(base);
+                if run == 1 {
+                    continue;
+                }
+                let mut last = base.wrapping_add(delta_base);
+                values.push(last);
+                if code == 0 {
+                    for _ in 2..run {
+                        last = last.wrapping_add(delta_base);
+                        values.push(last);
+                    }
+                } else {
+                    // the sign of all deltas is the sign of the first one
+                    for delta in read_packed(&mut data, decode_width(code), run - 2)? {
+                        last = if delta_base < 0 {
+                            last.wrapping_sub(delta as i64)
+                        } else {
+                            last.wrapping_add(delta as i64)
+                        };
+                        values.push(last);
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn decode_patched_base(header: u8, data: &mut &[u8], values: &mut Vec<i64>) -> Result<()> {
+    let width = decode_width((header >> 1) & 0x1f);
+    let run = read_header_length(header, data)?;
+    let third = take_byte(data)?;
+    let base_bytes = (third >> 5) as usize + 1;
+    let patch_width = decode_width(third & 0x1f);
+    let fourth = take_byte(data)?;
+    let gap_width = (fourth >> 5) as u32 + 1;
+    let patches_length = (fourth & 0x1f) as usize;
+
+    // the base is in sign-magnitude representation, big endian
+    let base = take(data, base_bytes)?
+        .iter()
+        .fold(0u64, |acc, x| acc << 8 | *x as u64);
+    let sign_mask = 1u64 << (base_bytes * 8 - 1);
+    let base = if base & sign_mask != 0 {
+        -((base & !sign_mask) as i64)
+    } else {
+        base as i64
+    };
+
+    let mut unpacked = read_packed(data, width, run)?;
+    let patches = read_packed(data, closest_width(gap_width + patch_width), patches_length)?;
+    let patch_mask = u64::MAX >> (64 - patch_width);
+    let mut index = 0;
+    for patch in patches {
+        // a gap larger than 255 is split in entries of 255 without patch
+        index += (patch >> patch_width) as usize;
+        let patch = patch & patch_mask;
+        if patch == 0 {
+            continue;
+        }
+        let value = unpacked.get_mut(index).ok_or_else(|| {
+            ArrowError::OutOfSpec("ORC patched base has a patch out of bounds".to_string())
+        })?;
+        *value |= patch.checked_shl(width).unwrap_or_default();
+    }
+    values.extend(unpacked.into_iter().map(|x| base.wrapping_add(x as i64)));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the examples of https://orc.apache.org/specification/ORCv1/#run-length-encoding
+
+    #[test]
+    fn bytes() -> Result<()> {
+        assert_eq!(decode_bytes(&[0x61, 0x00], 100)?, vec![0; 100]);
+        assert_eq!(decode_bytes(&[0xfe, 0x44, 0x45], 2)?, vec![0x44, 0x45]);
+        assert!(decode_bytes(&[0xfe, 0x44], 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn booleans() -> Result<()> {
+        let bitmap = decode_booleans(&[0xff, 0x80], 8)?;
+        let expected = std::iter::once(true)
+            .chain(std::iter::repeat(false).take(7))
+            .collect::<Vec<_>>();
+        assert_eq!(bitmap.iter().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn v1() -> Result<()> {
+        let values = decode_integers(&[0x61, 0x00, 0x07], 100, false, Version::V1)?;
+        assert_eq!(values, vec![7; 100]);
+
+        let values = decode_integers(&[0x61, 0xff, 0x64], 100, false, Version::V1)?;
+        assert_eq!(values, (1..=100).rev().collect::<Vec<_>>());
+
+        let data = [0xfb, 0x02, 0x03, 0x06, 0x07, 0xb];
+        let values = decode_integers(&data, 5, false, Version::V1)?;
+        assert_eq!(values, vec![2, 3, 6, 7, 11]);
+        Ok(())
+    }
+
+    #[test]
+    fn v2_short_repeat() -> Result<()> {
+        let values = decode_integers(&[0x0a, 0x27, 0x10], 5, false, Version::V2)?;
+        assert_eq!(values, vec![10000; 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn v2_direct() -> Result<()> {
+        let data = [0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef];
+        let values = decode_integers(&data, 4, false, Version::V2)?;
+        assert_eq!(values, vec![23713, 43806, 57005, 48879]);
+        Ok(())
+    }
+
+    #[test]
+    fn v2_patched_base() -> Result<()> {
+        let data = [
+            0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46,
+            0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+        ];
+        let values = decode_integers(&data, 20, false, Version::V2)?;
+        let expected = vec![
+            2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120, 2130,
+            2140, 2150, 2160, 2170, 2180, 2190,
+        ];
+        assert_eq!(values, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn v2_delta() -> Result<()> {
+        let data = [0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46];
+        let values = decode_integers(&data, 10, false, Version::V2)?;
+        assert_eq!(values, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+
+        let values = decode_integers(&[0xc0, 0x0a, 0x01, 0x00], 11, true, Version::V2)?;
+        assert_eq!(values, vec![-1; 11]);
+        Ok(())
+    }
+}
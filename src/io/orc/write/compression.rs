@@ -0,0 +1,53 @@
+use crate::error::Result;
+
+use super::Compression;
+
+/// The maximum length of an uncompressed chunk of a compressed stream
+pub(super) const BLOCK_SIZE: usize = 256 * 1024;
+
+/// Writes `data` to `buffer` compressed as an ORC stream: a sequence of chunks, each with a
+/// 3-byte header declaring its length and whether it is stored uncompressed (when compression
+/// does not reduce its length).
+pub(super) fn compress(data: &[u8], compression: Compression, buffer: &mut Vec<u8>) -> Result<()> {
+    match compression {
+        Compression::Uncompressed => {
+            buffer.extend_from_slice(data);
+            Ok(())
+        }
+        Compression::Zlib => data
+            .chunks(BLOCK_SIZE)
+            .try_for_each(|chunk| compress_chunk(chunk, buffer)),
+    }
+}
+
+#[cfg(feature = "io_orc_compression")]
+fn write_chunk_header(length: usize, is_original: bool, buffer: &mut Vec<u8>) {
+    let header = (length as u32) << 1 | is_original as u32;
+    buffer.extend_from_slice(&header.to_le_bytes()[..3]);
+}
+
+#[cfg(feature = "io_orc_compression")]
+fn compress_chunk(chunk: &[u8], buffer: &mut Vec<u8>) -> Result<()> {
+    use std::io::Write;
+
+    let mut encoder = libflate::deflate::Encoder::new(vec![]);
+    encoder.write_all(chunk)?;
+    let compressed = encoder.finish().into_result()?;
+
+    if compressed.len() < chunk.len() {
+        write_chunk_header(compressed.len(), false, buffer);
+        buffer.extend_from_slice(&compressed);
+    } else {
+        write_chunk_header(chunk.len(), true, buffer);
+        buffer.extend_from_slice(chunk);
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "io_orc_compression"))]
+fn compress_chunk(_: &[u8], _: &mut Vec<u8>) -> Result<()> {
+    Err(crate::error::ArrowError::InvalidArgumentError(
+        "Trying to compress ORC with zlib but feature 'io_orc_compression' is not active."
+            .to_string(),
+    ))
+}
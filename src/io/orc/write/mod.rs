@@ -0,0 +1,206 @@
+//! APIs to write to Apache ORC format.
+//!
+//! Each [`Columns`] written by [`write_file`] is written as one stripe of the file.
+mod compression;
+mod rle;
+mod serialize;
+
+use std::io::Write;
+
+use crate::array::Array;
+use crate::columns::Columns;
+use crate::datatypes::Schema;
+use crate::error::Result;
+
+use super::protobuf::*;
+
+const MAGIC: &[u8] = b"ORC";
+
+/// The compression of the streams and metadata of an ORC file
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Compression {
+    /// Uncompressed
+    Uncompressed,
+    /// Zlib (raw deflate, as ORC readers expect)
+    Zlib,
+}
+
+/// Options to write an ORC file
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct WriteOptions {
+    /// The compression to apply
+    pub compression: Compression,
+}
+
+struct Statistics {
+    number_of_values: u64,
+    has_null: bool,
+}
+
+/// Writes an ORC file to `writer` with one stripe per item of `stripes`, returning
+/// the number of bytes written.
+///
+/// Supported types are booleans, integers, floats, strings, binaries, dates and timestamps.
+/// Timestamps without a timezone are written as ORC's `timestamp` with the writer's timezone
+/// set to UTC, and timestamps with a timezone as ORC's `timestamp with local time zone`
+/// (instants).
+/// # Errors
+/// Errors when a [`Columns`] does not match `schema`, when a data type is not supported,
+/// or when writing to `writer` fails.
+pub fn write_file<W, A, I>(
+    writer: &mut W,
+    stripes: I,
+    schema: &Schema,
+    options: WriteOptions,
+) -> Result<u64>
+where
+    W: Write,
+    A: AsRef<dyn Array>,
+    I: IntoIterator<Item = Result<Columns<A>>>,
+{
+    let fields = schema.fields();
+    let mut types = vec![Type {
+        kind: TypeKind::Struct,
+        subtypes: (1..=fields.len() as u32).collect(),
+        field_names: fields.iter().map(|field| field.name().clone()).collect(),
+    }];
+    for field in fields {
+        types.push(Type {
+            kind: serialize::to_type_kind(field.data_type())?,
+            subtypes: vec![],
+            field_names: vec![],
+        });
+    }
+
+    writer.write_all(MAGIC)?;
+    let mut offset = MAGIC.len() as u64;
+    let mut number_of_rows = 0;
+    let mut stripes_information = vec![];
+    let mut statistics = (0..=fields.len())
+        .map(|_| Statistics {
+            number_of_values: 0,
+            has_null: false,
+        })
+        .collect::<Vec<_>>();
+
+    for columns in stripes {
+        let columns = columns?;
+        super::super::check_columns(schema, &columns)?;
+        if columns.is_empty() {
+            continue;
+        }
+
+        let information = write_stripe(writer, &columns, offset, options, &mut statistics)?;
+        offset += information.data_length + information.footer_length;
+        number_of_rows += information.number_of_rows;
+        stripes_information.push(information);
+    }
+    statistics[0].number_of_values = number_of_rows;
+
+    let footer = Footer {
+        header_length: MAGIC.len() as u64,
+        content_length: offset - MAGIC.len() as u64,
+        stripes: stripes_information,
+        types,
+        number_of_rows,
+        statistics: statistics
+            .into_iter()
+            .map(|x| ColumnStatistics {
+                number_of_values: x.number_of_values,
+                has_null: x.has_null,
+            })
+            .collect(),
+        row_index_stride: 0,
+    };
+    let footer_length = write_message(writer, &footer, options.compression)?;
+
+    let postscript = PostScript {
+        footer_length,
+        compression: match options.compression {
+            Compression::Uncompressed => CompressionKind::None,
+            Compression::Zlib => CompressionKind::Zlib,
+        },
+        compression_block_size: compression::BLOCK_SIZE as u64,
+        version: vec![0, 12],
+        metadata_length: 0,
+        writer_version: 6,
+    };
+    // the postscript is never compressed
+    let mut data = vec![];
+    postscript.encode(&mut data);
+    writer.write_all(&data)?;
+    writer.write_all(&[data.len() as u8])?;
+
+    Ok(offset + footer_length + data.len() as u64 + 1)
+}
+
+/// Writes `message`, compressed, returning the number of bytes written.
+fn write_message<W: Write, M: Message>(
+    writer: &mut W,
+    message: &M,
+    compression: Compression,
+) -> Result<u64> {
+    let mut data = vec![];
+    message.encode(&mut data);
+    let mut compressed = vec![];
+    compression::compress(&data, compression, &mut compressed)?;
+    writer.write_all(&compressed)?;
+    Ok(compressed.len() as u64)
+}
+
+fn write_stripe<W: Write, A: AsRef<dyn Array>>(
+    writer: &mut W,
+    columns: &Columns<A>,
+    offset: u64,
+    options: WriteOptions,
+    statistics: &mut [Statistics],
+) -> Result<StripeInformation> {
+    // the root struct has no streams
+    let mut encodings = vec![ColumnEncoding {
+        kind: ColumnEncodingKind::Direct,
+        dictionary_size: 0,
+    }];
+    let mut streams = vec![];
+    let mut data_length = 0;
+    let mut compressed = vec![];
+    for (index, array) in columns.arrays().iter().enumerate() {
+        let array = array.as_ref();
+        let column = index as u32 + 1;
+        let encoded = serialize::encode(array)?;
+
+        for (kind, data) in encoded.streams {
+            compressed.clear();
+            compression::compress(&data, options.compression, &mut compressed)?;
+            writer.write_all(&compressed)?;
+            data_length += compressed.len() as u64;
+            streams.push(Stream {
+                kind,
+                column,
+                length: compressed.len() as u64,
+            });
+        }
+        encodings.push(ColumnEncoding {
+            kind: encoded.encoding,
+            dictionary_size: 0,
+        });
+
+        let statistics = &mut statistics[column as usize];
+        statistics.number_of_values += (array.len() - array.null_count()) as u64;
+        statistics.has_null |= array.null_count() > 0;
+    }
+
+    let footer = StripeFooter {
+        streams,
+        columns: encodings,
+        writer_timezone: "UTC".to_string(),
+    };
+    let footer_length = write_message(writer, &footer, options.compression)?;
+
+    Ok(StripeInformation {
+        offset,
+        index_length: 0,
+        data_length,
+        footer_length,
+        number_of_rows: columns.len() as u64,
+    })
+}
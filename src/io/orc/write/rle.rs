@@ -0,0 +1,313 @@
+//! Run length encodings of ORC, as described in
+//! <https://orc.apache.org/specification/ORCv1/#run-length-encoding>.
+use super::super::protobuf::write_varint;
+
+/// The maximum number of values of a run (or of literals) of the byte run length encoding
+const MAX_BYTE_LITERALS: usize = 128;
+const MAX_BYTE_RUN: usize = 130;
+/// The maximum number of values of a run of the integer run length encoding v2
+const MAX_INTEGER_RUN: usize = 512;
+const MIN_REPEAT: usize = 3;
+const MAX_SHORT_REPEAT: usize = 10;
+
+/// Encodes `values` with the byte run length encoding.
+pub(super) fn encode_bytes(values: &[u8], buffer: &mut Vec<u8>) {
+    let mut literals_start = 0;
+    let mut i = 0;
+    while i < values.len() {
+        let run = run_length(&values[i..], MAX_BYTE_RUN);
+        if run >= MIN_REPEAT {
+            write_byte_literals(&values[literals_start..i], buffer);
+            buffer.push((run - MIN_REPEAT) as u8);
+            buffer.push(values[i]);
+            literals_start = i + run;
+        }
+        i += run;
+    }
+    write_byte_literals(&values[literals_start..], buffer);
+}
+
+fn write_byte_literals(values: &[u8], buffer: &mut Vec<u8>) {
+    for chunk in values.chunks(MAX_BYTE_LITERALS) {
+        buffer.push((chunk.len() as u8).wrapping_neg());
+        buffer.extend_from_slice(chunk);
+    }
+}
+
+/// Encodes `values` with the boolean run length encoding, i.e. the byte run length encoding
+/// of the values packed in bytes, most significant bit first.
+pub(super) fn encode_booleans<I: Iterator<Item = bool>>(values: I, buffer: &mut Vec<u8>) {
+    let mut bytes = vec![];
+    let mut byte = 0u8;
+    let mut length = 0;
+    for value in values {
+        byte |= (value as u8) << (7 - length % 8);
+        length += 1;
+        if length % 8 == 0 {
+            bytes.push(byte);
+            byte = 0;
+        }
+    }
+    if length % 8 != 0 {
+        bytes.push(byte);
+    }
+    encode_bytes(&bytes, buffer)
+}
+
+/// Encodes `values` with the integer run length encoding v2. `signed` declares whether the
+/// stream is of signed integers (zigzag encoded) or of non-negative integers.
+pub(super) fn encode_integers(values: &[i64], signed: bool, buffer: &mut Vec<u8>) {
+    let mut literals_start = 0;
+    let mut i = 0;
+    while i < values.len() {
+        let run = run_length(&values[i..], MAX_INTEGER_RUN);
+        if run >= MIN_REPEAT {
+            write_integer_literals(&values[literals_start..i], signed, buffer);
+            if run <= MAX_SHORT_REPEAT {
+                write_short_repeat(values[i], run, signed, buffer);
+            } else {
+                // a delta of 0
+                write_delta(&values[i..i + run], signed, buffer);
+            }
+            literals_start = i + run;
+        }
+        i += run;
+    }
+    write_integer_literals(&values[literals_start..], signed, buffer);
+}
+
+/// The number of leading values of `values` equal to the first one, up to `max`
+fn run_length<T: PartialEq>(values: &[T], max: usize) -> usize {
+    values
+        .iter()
+        .take(max)
+        .take_while(|x| **x == values[0])
+        .count()
+}
+
+#[inline]
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+#[inline]
+fn to_unsigned(value: i64, signed: bool) -> u64 {
+    if signed {
+        zigzag(value)
+    } else {
+        value as u64
+    }
+}
+
+#[inline]
+fn bit_width(value: u64) -> u32 {
+    (64 - value.leading_zeros()).max(1)
+}
+
+/// The smallest width supported by the bit packing of the encoding that fits `bits`
+fn closest_width(bits: u32) -> u32 {
+    match bits {
+        0..=24 => bits.max(1),
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+/// The smallest width of [`closest_width`] that is byte aligned (or a power of two below 8)
+/// that fits `bits`, used by the delta encoding as ORC's writers do
+fn closest_aligned_width(bits: u32) -> u32 {
+    match bits {
+        0..=1 => 1,
+        2 => 2,
+        3..=4 => 4,
+        5..=8 => 8,
+        9..=16 => 16,
+        17..=24 => 24,
+        _ => closest_width(bits),
+    }
+}
+
+/// The 5-bit encoding of a width returned by [`closest_width`]
+fn encode_width(width: u32) -> u8 {
+    (match width {
+        1..=24 => width - 1,
+        26 => 24,
+        28 => 25,
+        30 => 26,
+        32 => 27,
+        40 => 28,
+        48 => 29,
+        56 => 30,
+        _ => 31,
+    }) as u8
+}
+
+/// Writes `values` bit packed with `width` bits, big endian
+fn write_packed<I: Iterator<Item = u64>>(values: I, width: u32, buffer: &mut Vec<u8>) {
+    let mut acc = 0u128;
+    let mut bits = 0;
+    for value in values {
+        acc = acc << width | value as u128;
+        bits += width;
+        while bits >= 8 {
+            bits -= 8;
+            buffer.push((acc >> bits) as u8);
+        }
+        acc &= (1 << bits) - 1;
+    }
+    if bits > 0 {
+        buffer.push((acc << (8 - bits)) as u8);
+    }
+}
+
+fn write_header(encoding: u8, width: u8, length: usize, buffer: &mut Vec<u8>) {
+    let length = length - 1;
+    buffer.push(encoding << 6 | width << 1 | (length >> 8) as u8);
+    buffer.push(length as u8);
+}
+
+fn write_short_repeat(value: i64, run: usize, signed: bool, buffer: &mut Vec<u8>) {
+    let value = to_unsigned(value, signed);
+    let bytes = ((bit_width(value) - 1) / 8 + 1) as usize;
+    buffer.push(((bytes - 1) << 3 | (run - MIN_REPEAT)) as u8);
+    buffer.extend_from_slice(&value.to_be_bytes()[8 - bytes..]);
+}
+
+fn write_direct(values: &[i64], signed: bool, buffer: &mut Vec<u8>) {
+    let values = values.iter().map(|x| to_unsigned(*x, signed));
+    let max = values.clone().max().unwrap_or_default();
+    let width = closest_width(bit_width(max));
+
+    write_header(1, encode_width(width), values.len(), buffer);
+    write_packed(values, width, buffer);
+}
+
+/// Writes `values` with the delta encoding and returns `true`, or returns `false` when
+/// they can't be delta-encoded (they are not monotonic or their deltas overflow).
+fn write_delta(values: &[i64], signed: bool, buffer: &mut Vec<u8>) -> bool {
+    let deltas = values
+        .windows(2)
+        .map(|x| x[1].checked_sub(x[0]))
+        .collect::<Option<Vec<_>>>();
+    let deltas = match deltas {
+        Some(deltas) if !deltas.is_empty() => deltas,
+        _ => return false,
+    };
+    let delta_base = deltas[0];
+    let is_fixed = deltas.iter().all(|x| *x == delta_base);
+    // the sign of all deltas is the sign of the first one
+    let is_monotonic = if delta_base >= 0 {
+        deltas.iter().all(|x| *x >= 0)
+    } else {
+        deltas.iter().all(|x| *x <= 0)
+    };
+    if !is_monotonic {
+        return false;
+    }
+
+    let width = if is_fixed {
+        0
+    } else {
+        let max = deltas[1..].iter().map(|x| x.unsigned_abs()).max().unwrap();
+        // a width of 1 is encoded as 2, as an encoded width of 0 denotes a fixed delta
+        closest_aligned_width(bit_width(max)).max(2)
+    };
+    let encoded_width = if is_fixed { 0 } else { encode_width(width) };
+
+    write_header(3, encoded_width, values.len(), buffer);
+    write_varint(to_unsigned(values[0], signed), buffer);
+    write_varint(zigzag(delta_base), buffer);
+    if !is_fixed {
+        write_packed(deltas[1..].iter().map(|x| x.unsigned_abs()), width, buffer);
+    }
+    true
+}
+
+/// Writes literals with the direct or the delta encoding, whichever is smaller.
+fn write_integer_literals(values: &[i64], signed: bool, buffer: &mut Vec<u8>) {
+    let mut delta = vec![];
+    for chunk in values.chunks(MAX_INTEGER_RUN) {
+        let start = buffer.len();
+        write_direct(chunk, signed, buffer);
+        delta.clear();
+        if chunk.len() >= MIN_REPEAT
+            && write_delta(chunk, signed, &mut delta)
+            && delta.len() < buffer.len() - start
+        {
+            buffer.truncate(start);
+            buffer.extend_from_slice(&delta);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the examples of https://orc.apache.org/specification/ORCv1/#run-length-encoding
+
+    #[test]
+    fn bytes() {
+        let mut buffer = vec![];
+        encode_bytes(&[0; 100], &mut buffer);
+        assert_eq!(buffer, vec![0x61, 0x00]);
+
+        let mut buffer = vec![];
+        encode_bytes(&[0x44, 0x45], &mut buffer);
+        assert_eq!(buffer, vec![0xfe, 0x44, 0x45]);
+    }
+
+    #[test]
+    fn booleans() {
+        let mut buffer = vec![];
+        let values = std::iter::once(true).chain(std::iter::repeat(false).take(7));
+        encode_booleans(values, &mut buffer);
+        assert_eq!(buffer, vec![0xff, 0x80]);
+
+        let mut buffer = vec![];
+        encode_booleans(std::iter::repeat(false).take(8 * 100), &mut buffer);
+        assert_eq!(buffer, vec![0x61, 0x00]);
+    }
+
+    #[test]
+    fn short_repeat() {
+        let mut buffer = vec![];
+        encode_integers(&[10000; 5], false, &mut buffer);
+        assert_eq!(buffer, vec![0x0a, 0x27, 0x10]);
+    }
+
+    #[test]
+    fn direct() {
+        let mut buffer = vec![];
+        encode_integers(&[23713, 43806, 57005, 48879], false, &mut buffer);
+        assert_eq!(
+            buffer,
+            vec![0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef]
+        );
+    }
+
+    #[test]
+    fn delta() {
+        let mut buffer = vec![];
+        encode_integers(&[2, 3, 5, 7, 11, 13, 17, 19, 23, 29], false, &mut buffer);
+        assert_eq!(buffer, vec![0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46]);
+    }
+
+    #[test]
+    fn fixed_delta() {
+        // a run longer than a short repeat is a delta of 0
+        let mut buffer = vec![];
+        encode_integers(&[-1; 11], true, &mut buffer);
+        assert_eq!(buffer, vec![0xc0, 0x0a, 0x01, 0x00]);
+
+        let mut buffer = vec![];
+        encode_integers(&(0..100).collect::<Vec<_>>(), true, &mut buffer);
+        assert_eq!(buffer, vec![0xc0, 0x63, 0x00, 0x02]);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::array::*;
+use crate::datatypes::{DataType, TimeUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::super::protobuf::{ColumnEncodingKind, StreamKind, TypeKind};
+use super::rle::{encode_booleans, encode_bytes, encode_integers};
+
+/// The seconds of ORC timestamps are relative to 2015-01-01 00:00:00 (in the writer's timezone)
+const TIMESTAMP_BASE_SECONDS: i64 = 1420070400;
+
+/// The streams of a column of a stripe, in the order they are written
+pub(super) struct EncodedColumn {
+    pub streams: Vec<(StreamKind, Vec<u8>)>,
+    pub encoding: ColumnEncodingKind,
+}
+
+/// Returns the ORC type of `data_type`, or an error if it is not supported.
+pub(super) fn to_type_kind(data_type: &DataType) -> Result<TypeKind> {
+    Ok(match data_type {
+        DataType::Boolean => TypeKind::Boolean,
+        DataType::Int8 => TypeKind::Byte,
+        DataType::Int16 => TypeKind::Short,
+        DataType::Int32 => TypeKind::Int,
+        DataType::Int64 => TypeKind::Long,
+        DataType::Float32 => TypeKind::Float,
+        DataType::Float64 => TypeKind::Double,
+        DataType::Utf8 | DataType::LargeUtf8 => TypeKind::String,
+        DataType::Binary | DataType::LargeBinary => TypeKind::Binary,
+        // timestamps with a timezone are instants, whose seconds are relative to 2015-01-01 UTC
+        DataType::Timestamp(_, Some(_)) => TypeKind::TimestampInstant,
+        DataType::Timestamp(_, None) => TypeKind::Timestamp,
+        DataType::Date32 => TypeKind::Date,
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing {:?} to ORC",
+                other
+            )))
+        }
+    })
+}
+
+/// Encodes `array` into the streams of its column.
+pub(super) fn encode(array: &dyn Array) -> Result<EncodedColumn> {
+    let mut streams = vec![];
+    if array.null_count() > 0 {
+        let mut present = vec![];
+        encode_booleans(
+            (0..array.len()).map(|index| array.is_valid(index)),
+            &mut present,
+        );
+        streams.push((StreamKind::Present, present));
+    }
+
+    let encoding = match array.data_type() {
+        DataType::Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let mut data = vec![];
+            encode_booleans(array.iter().flatten(), &mut data);
+            streams.push((StreamKind::Data, data));
+            ColumnEncodingKind::Direct
+        }
+        DataType::Int8 => {
+            let array = array.as_any().downcast_ref::<Int8Array>().unwrap();
+            let values = array.iter().flatten().map(|x| *x as u8).collect::<Vec<_>>();
+            let mut data = vec![];
+            encode_bytes(&values, &mut data);
+            streams.push((StreamKind::Data, data));
+            ColumnEncodingKind::Direct
+        }
+        DataType::Int16 => integers::<i16>(array, &mut streams),
+        DataType::Int32 | DataType::Date32 => integers::<i32>(array, &mut streams),
+        DataType::Int64 => integers::<i64>(array, &mut streams),
+        DataType::Float32 => floats::<f32>(array, &mut streams),
+        DataType::Float64 => floats::<f64>(array, &mut streams),
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            bytes(array.iter().flatten().map(|x| x.as_bytes()), &mut streams)
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            bytes(array.iter().flatten().map(|x| x.as_bytes()), &mut streams)
+        }
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            bytes(array.iter().flatten(), &mut streams)
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            bytes(array.iter().flatten(), &mut streams)
+        }
+        DataType::Timestamp(unit, _) => {
+            let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
+            timestamps(array, *unit, &mut streams)
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing {:?} to ORC",
+                other
+            )))
+        }
+    };
+    Ok(EncodedColumn { streams, encoding })
+}
+
+fn integers<T: NativeType + Into<i64>>(
+    array: &dyn Array,
+    streams: &mut Vec<(StreamKind, Vec<u8>)>,
+) -> ColumnEncodingKind {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let values = array
+        .iter()
+        .flatten()
+        .map(|x| (*x).into())
+        .collect::<Vec<_>>();
+    let mut data = vec![];
+    encode_integers(&values, true, &mut data);
+    streams.push((StreamKind::Data, data));
+    ColumnEncodingKind::DirectV2
+}
+
+fn floats<T: NativeType>(
+    array: &dyn Array,
+    streams: &mut Vec<(StreamKind, Vec<u8>)>,
+) -> ColumnEncodingKind {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let mut data = Vec::with_capacity(array.len() * std::mem::size_of::<T>());
+    array
+        .iter()
+        .flatten()
+        .for_each(|x| data.extend_from_slice(x.to_le_bytes().as_ref()));
+    streams.push((StreamKind::Data, data));
+    ColumnEncodingKind::Direct
+}
+
+fn bytes<'a, I: Iterator<Item = &'a [u8]>>(
+    values: I,
+    streams: &mut Vec<(StreamKind, Vec<u8>)>,
+) -> ColumnEncodingKind {
+    let mut data = vec![];
+    let mut lengths = vec![];
+    values.for_each(|x| {
+        data.extend_from_slice(x);
+        lengths.push(x.len() as i64);
+    });
+    let mut length = vec![];
+    encode_integers(&lengths, false, &mut length);
+    streams.push((StreamKind::Data, data));
+    streams.push((StreamKind::Length, length));
+    ColumnEncodingKind::DirectV2
+}
+
+/// Splits timestamps into the seconds (relative to [`TIMESTAMP_BASE_SECONDS`]) of the DATA stream
+/// and the nanoseconds of the SECONDARY stream.
+fn timestamps(
+    array: &Int64Array,
+    unit: TimeUnit,
+    streams: &mut Vec<(StreamKind, Vec<u8>)>,
+) -> ColumnEncodingKind {
+    let (per_second, nanos_per_unit) = match unit {
+        TimeUnit::Second => (1, 1_000_000_000),
+        TimeUnit::Millisecond => (1_000, 1_000_000),
+        TimeUnit::Microsecond => (1_000_000, 1_000),
+        TimeUnit::Nanosecond => (1_000_000_000, 1),
+    };
+    let (seconds, nanos): (Vec<i64>, Vec<i64>) = array
+        .iter()
+        .flatten()
+        .map(|x| {
+            let mut seconds = x.div_euclid(per_second);
+            let nanos = x.rem_euclid(per_second) * nanos_per_unit;
+            // readers subtract a second from negative seconds with more than 999999 nanoseconds
+            if seconds < 0 && nanos > 999_999 {
+                seconds += 1;
+            }
+            (seconds - TIMESTAMP_BASE_SECONDS, format_nanos(nanos))
+        })
+        .unzip();
+
+    let mut data = vec![];
+    encode_integers(&seconds, true, &mut data);
+    let mut secondary = vec![];
+    encode_integers(&nanos, false, &mut secondary);
+    streams.push((StreamKind::Data, data));
+    streams.push((StreamKind::Secondary, secondary));
+    ColumnEncodingKind::DirectV2
+}
+
+/// Encodes nanoseconds with their trailing decimal zeros: the 3 least significant bits hold
+/// the number of zeros removed minus one (when more than one is removed).
+fn format_nanos(nanos: i64) -> i64 {
+    if nanos == 0 || nanos % 100 != 0 {
+        return nanos << 3;
+    }
+    let mut nanos = nanos / 100;
+    let mut zeros = 1;
+    while nanos % 10 == 0 && zeros < 7 {
+        nanos /= 10;
+        zeros += 1;
+    }
+    nanos << 3 | zeros
+}
@@ -0,0 +1,466 @@
+//! Split block bloom filters of parquet, as described in
+//! <https://github.com/apache/parquet-format/blob/master/BloomFilter.md>.
+//!
+//! Bloom filters complement the statistics of a column chunk: they allow a row group to be
+//! skipped when a value is not in a column even when the value is within its minimum and maximum
+//! (e.g. of columns of identifiers).
+use std::convert::TryInto;
+
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::scalar::*;
+use crate::types::NativeType;
+
+/// The salts of the split block algorithm, one per word of a block
+const SALT: [u32; 8] = [
+    0x47b6137b, 0x44974d91, 0x8824ad5b, 0xa2b7289d, 0x705495c7, 0x2df1424b, 0x9efc4947, 0x5c6bfb31,
+];
+
+/// The number of bytes of a block
+const BLOCK_BYTES: usize = 32;
+
+/// The maximum number of bytes of a bitset, as recommended by the specification
+pub(super) const MAX_BYTES: usize = 128 * 1024 * 1024;
+
+/// A split block bloom filter: a set of hashes of values that can return false positives but not
+/// false negatives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    blocks: Vec<[u32; 8]>,
+}
+
+impl BloomFilter {
+    /// Returns an empty [`BloomFilter`] sized to hold `ndv` distinct values with a false positive
+    /// probability of `fpp`.
+    /// # Panics
+    /// Iff `fpp` is not in the interval `(0, 1)`.
+    pub fn new(ndv: u64, fpp: f64) -> Self {
+        assert!(
+            fpp > 0.0 && fpp < 1.0,
+            "The false positive probability of a bloom filter must be in (0, 1)"
+        );
+        let bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+        let bytes = ((bits / 8.0) as usize)
+            .clamp(BLOCK_BYTES, MAX_BYTES)
+            .next_power_of_two();
+        Self {
+            blocks: vec![[0; 8]; bytes / BLOCK_BYTES],
+        }
+    }
+
+    /// Returns a [`BloomFilter`] from its bitset, as stored in parquet.
+    /// # Errors
+    /// Errors iff the bitset is empty or its length is not a multiple of 32.
+    pub fn try_from_bitset(bitset: &[u8]) -> Result<Self> {
+        if bitset.is_empty() || bitset.len() % BLOCK_BYTES != 0 {
+            return Err(ArrowError::OutOfSpec(format!(
+                "The bitset of a bloom filter must be a non-empty multiple of {} bytes, but it has {} bytes",
+                BLOCK_BYTES,
+                bitset.len()
+            )));
+        }
+        let blocks = bitset
+            .chunks_exact(BLOCK_BYTES)
+            .map(|block| {
+                let mut words = [0u32; 8];
+                block
+                    .chunks_exact(4)
+                    .zip(words.iter_mut())
+                    .for_each(|(bytes, word)| {
+                        *word = u32::from_le_bytes(bytes.try_into().unwrap())
+                    });
+                words
+            })
+            .collect();
+        Ok(Self { blocks })
+    }
+
+    /// Returns the bitset of this [`BloomFilter`], as stored in parquet.
+    pub fn to_bitset(&self) -> Vec<u8> {
+        self.blocks
+            .iter()
+            .flat_map(|block| block.iter())
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    /// Returns the number of bytes of the bitset of this [`BloomFilter`]
+    pub fn num_bytes(&self) -> usize {
+        self.blocks.len() * BLOCK_BYTES
+    }
+
+    #[inline]
+    fn block_index(&self, hash: u64) -> usize {
+        (((hash >> 32) * self.blocks.len() as u64) >> 32) as usize
+    }
+
+    #[inline]
+    fn mask(hash: u64) -> [u32; 8] {
+        let key = hash as u32;
+        let mut mask = [0u32; 8];
+        mask.iter_mut()
+            .zip(SALT.iter())
+            .for_each(|(mask, salt)| *mask = 1 << (key.wrapping_mul(*salt) >> 27));
+        mask
+    }
+
+    /// Inserts the hash of a value, as returned by [`hash_bytes`].
+    pub fn insert_hash(&mut self, hash: u64) {
+        let index = self.block_index(hash);
+        let block = &mut self.blocks[index];
+        block
+            .iter_mut()
+            .zip(Self::mask(hash).iter())
+            .for_each(|(word, mask)| *word |= mask);
+    }
+
+    /// Returns whether the hash of a value, as returned by [`hash_bytes`], may have been inserted.
+    pub fn contains_hash(&self, hash: u64) -> bool {
+        let block = &self.blocks[self.block_index(hash)];
+        block
+            .iter()
+            .zip(Self::mask(hash).iter())
+            .all(|(word, mask)| word & mask != 0)
+    }
+
+    /// Inserts every non-null value of `array`, hashed as its parquet physical type.
+    /// # Errors
+    /// Errors iff the [`DataType`] of `array` is not supported (see [`can_hash`]).
+    pub fn insert_array(&mut self, array: &dyn Array) -> Result<()> {
+        use DataType::*;
+        match array.data_type().to_logical_type() {
+            // casts below MUST match the casts done when writing the column
+            UInt8 => self.insert_primitive::<u8, _>(array, |x| hash_i32(x as i32)),
+            UInt16 => self.insert_primitive::<u16, _>(array, |x| hash_i32(x as i32)),
+            UInt32 => self.insert_primitive::<u32, _>(array, |x| hash_i32(x as i32)),
+            UInt64 => self.insert_primitive::<u64, _>(array, |x| hash_i64(x as i64)),
+            Int8 => self.insert_primitive::<i8, _>(array, |x| hash_i32(x as i32)),
+            Int16 => self.insert_primitive::<i16, _>(array, |x| hash_i32(x as i32)),
+            Int32 | Date32 | Time32(_) => self.insert_primitive::<i32, _>(array, hash_i32),
+            Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
+                self.insert_primitive::<i64, _>(array, hash_i64)
+            }
+            Float32 => self.insert_primitive::<f32, _>(array, |x| hash_bytes(&x.to_le_bytes())),
+            Float64 => self.insert_primitive::<f64, _>(array, |x| hash_bytes(&x.to_le_bytes())),
+            Utf8 => self.insert_bytes(
+                array
+                    .as_any()
+                    .downcast_ref::<Utf8Array<i32>>()
+                    .unwrap()
+                    .iter()
+                    .flatten()
+                    .map(|x| x.as_bytes()),
+            ),
+            LargeUtf8 => self.insert_bytes(
+                array
+                    .as_any()
+                    .downcast_ref::<Utf8Array<i64>>()
+                    .unwrap()
+                    .iter()
+                    .flatten()
+                    .map(|x| x.as_bytes()),
+            ),
+            Binary => self.insert_bytes(
+                array
+                    .as_any()
+                    .downcast_ref::<BinaryArray<i32>>()
+                    .unwrap()
+                    .iter()
+                    .flatten(),
+            ),
+            LargeBinary => self.insert_bytes(
+                array
+                    .as_any()
+                    .downcast_ref::<BinaryArray<i64>>()
+                    .unwrap()
+                    .iter()
+                    .flatten(),
+            ),
+            FixedSizeBinary(_) => self.insert_bytes(
+                array
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .unwrap()
+                    .iter()
+                    .flatten(),
+            ),
+            Dictionary(key_type, _, _) => {
+                // every value of the dictionary is inserted, a superset of the referenced ones
+                match_integer_type!(key_type, |$T| {
+                    let array = array
+                        .as_any()
+                        .downcast_ref::<DictionaryArray<$T>>()
+                        .unwrap();
+                    return self.insert_array(array.values().as_ref());
+                })
+            }
+            other => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Bloom filters of columns of type {:?}",
+                    other
+                )))
+            }
+        };
+        Ok(())
+    }
+
+    fn insert_primitive<T: NativeType, F: Fn(T) -> u64>(&mut self, array: &dyn Array, hash: F) {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+        array
+            .iter()
+            .flatten()
+            .for_each(|x| self.insert_hash(hash(*x)));
+    }
+
+    fn insert_bytes<'a, I: Iterator<Item = &'a [u8]>>(&mut self, values: I) {
+        values.for_each(|x| self.insert_hash(hash_bytes(x)));
+    }
+
+    /// Returns whether `value` may be in the column chunk of this [`BloomFilter`]: `false` means
+    /// that `value` is definitely not in it, which allows the row group to be skipped when
+    /// evaluating an equality with `value`.
+    ///
+    /// `value` must be of the [`DataType`] of the column (or of its values, for dictionary-encoded
+    /// columns). Null values and values whose type is not supported (see [`can_hash`]) may be
+    /// in the column chunk.
+    pub fn might_contain(&self, value: &dyn Scalar) -> bool {
+        match hash_scalar(value) {
+            Some(hash) => self.contains_hash(hash),
+            None => true,
+        }
+    }
+}
+
+/// Checks whether arrays of `data_type` can be inserted in a [`BloomFilter`].
+pub fn can_hash(data_type: &DataType) -> bool {
+    use DataType::*;
+    match data_type.to_logical_type() {
+        UInt8
+        | UInt16
+        | UInt32
+        | UInt64
+        | Int8
+        | Int16
+        | Int32
+        | Int64
+        | Date32
+        | Date64
+        | Time32(_)
+        | Time64(_)
+        | Timestamp(_, _)
+        | Duration(_)
+        | Float32
+        | Float64
+        | Utf8
+        | LargeUtf8
+        | Binary
+        | LargeBinary
+        | FixedSizeBinary(_) => true,
+        Dictionary(_, values, _) => can_hash(values),
+        _ => false,
+    }
+}
+
+#[inline]
+fn hash_i32(value: i32) -> u64 {
+    hash_bytes(&value.to_le_bytes())
+}
+
+#[inline]
+fn hash_i64(value: i64) -> u64 {
+    hash_bytes(&value.to_le_bytes())
+}
+
+fn primitive_value<T: NativeType>(value: &dyn Scalar) -> Option<T> {
+    value
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<T>>()
+        .unwrap()
+        .value()
+}
+
+/// Returns the hash of `value` as its parquet physical type, or `None` if it is null or its type
+/// is not supported.
+fn hash_scalar(value: &dyn Scalar) -> Option<u64> {
+    use DataType::*;
+    match value.data_type().to_logical_type() {
+        UInt8 => primitive_value::<u8>(value).map(|x| hash_i32(x as i32)),
+        UInt16 => primitive_value::<u16>(value).map(|x| hash_i32(x as i32)),
+        UInt32 => primitive_value::<u32>(value).map(|x| hash_i32(x as i32)),
+        UInt64 => primitive_value::<u64>(value).map(|x| hash_i64(x as i64)),
+        Int8 => primitive_value::<i8>(value).map(|x| hash_i32(x as i32)),
+        Int16 => primitive_value::<i16>(value).map(|x| hash_i32(x as i32)),
+        Int32 | Date32 | Time32(_) => primitive_value::<i32>(value).map(hash_i32),
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
+            primitive_value::<i64>(value).map(hash_i64)
+        }
+        Float32 => primitive_value::<f32>(value).map(|x| hash_bytes(&x.to_le_bytes())),
+        Float64 => primitive_value::<f64>(value).map(|x| hash_bytes(&x.to_le_bytes())),
+        Utf8 => value
+            .as_any()
+            .downcast_ref::<Utf8Scalar<i32>>()
+            .unwrap()
+            .value()
+            .map(|x| hash_bytes(x.as_bytes())),
+        LargeUtf8 => value
+            .as_any()
+            .downcast_ref::<Utf8Scalar<i64>>()
+            .unwrap()
+            .value()
+            .map(|x| hash_bytes(x.as_bytes())),
+        Binary => value
+            .as_any()
+            .downcast_ref::<BinaryScalar<i32>>()
+            .unwrap()
+            .value()
+            .map(hash_bytes),
+        LargeBinary => value
+            .as_any()
+            .downcast_ref::<BinaryScalar<i64>>()
+            .unwrap()
+            .value()
+            .map(hash_bytes),
+        FixedSizeBinary(_) => value
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryScalar>()
+            .unwrap()
+            .value()
+            .map(hash_bytes),
+        _ => None,
+    }
+}
+
+const PRIME_1: u64 = 0x9E3779B185EBCA87;
+const PRIME_2: u64 = 0xC2B2AE3D27D4EB4F;
+const PRIME_3: u64 = 0x165667B19E3779F9;
+const PRIME_4: u64 = 0x85EBCA77C2B2AE63;
+const PRIME_5: u64 = 0x27D4EB2F165667C5;
+
+#[inline]
+fn round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(PRIME_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME_1)
+}
+
+#[inline]
+fn merge_round(acc: u64, value: u64) -> u64 {
+    (acc ^ round(0, value))
+        .wrapping_mul(PRIME_1)
+        .wrapping_add(PRIME_4)
+}
+
+#[inline]
+fn read_u64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes[..8].try_into().unwrap())
+}
+
+/// Returns the hash of `bytes` used by parquet's bloom filters: xxHash64 with a seed of 0
+/// (<https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md>).
+pub fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut remaining = bytes;
+    let mut hash = if bytes.len() >= 32 {
+        let mut accumulators = [
+            PRIME_1.wrapping_add(PRIME_2),
+            PRIME_2,
+            0,
+            0u64.wrapping_sub(PRIME_1),
+        ];
+        while remaining.len() >= 32 {
+            accumulators
+                .iter_mut()
+                .zip(remaining.chunks_exact(8))
+                .for_each(|(acc, lane)| *acc = round(*acc, read_u64(lane)));
+            remaining = &remaining[32..];
+        }
+        let [v1, v2, v3, v4] = accumulators;
+        let hash = v1
+            .rotate_left(1)
+            .wrapping_add(v2.rotate_left(7))
+            .wrapping_add(v3.rotate_left(12))
+            .wrapping_add(v4.rotate_left(18));
+        accumulators
+            .iter()
+            .fold(hash, |hash, acc| merge_round(hash, *acc))
+    } else {
+        PRIME_5
+    };
+    hash = hash.wrapping_add(bytes.len() as u64);
+
+    while remaining.len() >= 8 {
+        hash ^= round(0, read_u64(remaining));
+        hash = hash
+            .rotate_left(27)
+            .wrapping_mul(PRIME_1)
+            .wrapping_add(PRIME_4);
+        remaining = &remaining[8..];
+    }
+    if remaining.len() >= 4 {
+        let value = u32::from_le_bytes(remaining[..4].try_into().unwrap()) as u64;
+        hash ^= value.wrapping_mul(PRIME_1);
+        hash = hash
+            .rotate_left(23)
+            .wrapping_mul(PRIME_2)
+            .wrapping_add(PRIME_3);
+        remaining = &remaining[4..];
+    }
+    for byte in remaining {
+        hash ^= (*byte as u64).wrapping_mul(PRIME_5);
+        hash = hash.rotate_left(11).wrapping_mul(PRIME_1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME_3);
+    hash ^ (hash >> 32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xxhash64() {
+        // reference values of xxHash64 with seed 0
+        assert_eq!(hash_bytes(b""), 0xEF46DB3751D8E999);
+        assert_eq!(hash_bytes(b"a"), 0xD24EC4F1A98C6E5B);
+        assert_eq!(hash_bytes(b"abc"), 0x44BC2CF5AD770999);
+        assert_eq!(
+            hash_bytes(b"Nobody inspects the spammish repetition"),
+            0xFBCEA83C8A378BF1
+        );
+        // longer than a stripe of 32 bytes
+        assert_eq!(
+            hash_bytes(b"0123456789abcdef0123456789abcdef0123456789"),
+            0xA76190C3ACF08A1C
+        );
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(BloomFilter::new(0, 0.5).num_bytes(), 32);
+        // 1M distinct values at 1% are ~1.2MB, rounded to a power of two
+        assert_eq!(
+            BloomFilter::new(1_000_000, 0.01).num_bytes(),
+            2 * 1024 * 1024
+        );
+        assert_eq!(BloomFilter::new(u64::MAX, 0.01).num_bytes(), MAX_BYTES);
+    }
+
+    #[test]
+    fn insert_and_check() {
+        let mut filter = BloomFilter::new(1000, 0.01);
+        (0..1000).for_each(|x| filter.insert_hash(hash_i64(x)));
+        assert!((0..1000).all(|x| filter.contains_hash(hash_i64(x))));
+        let false_positives = (1000..11000)
+            .filter(|x| filter.contains_hash(hash_i64(*x)))
+            .count();
+        assert!(false_positives < 200, "{}", false_positives);
+
+        let bitset = filter.to_bitset();
+        assert_eq!(BloomFilter::try_from_bitset(&bitset).unwrap(), filter);
+        assert!(BloomFilter::try_from_bitset(&bitset[1..]).is_err());
+    }
+}
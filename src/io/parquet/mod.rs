@@ -1,6 +1,8 @@
 //! APIs to read from and write to Parquet format.
 use crate::error::ArrowError;
 
+pub mod bloom_filter;
+pub mod page_index;
 pub mod read;
 pub mod write;
 
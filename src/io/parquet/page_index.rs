@@ -0,0 +1,140 @@
+//! The page index of parquet, as described in
+//! <https://github.com/apache/parquet-format/blob/master/PageIndex.md>.
+//!
+//! The page index of a column chunk is optional and complements its statistics: the
+//! [`ColumnIndex`] contains the min and max values of each of its data pages, and the
+//! [`OffsetIndex`] the location of each of its data pages, so that pages can be skipped without
+//! reading their headers.
+use crate::error::{ArrowError, Result};
+
+/// Whether the min and max values of the (non-null) pages of a [`ColumnIndex`] are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundaryOrder {
+    /// The min and max values are not ordered, or their order is undefined
+    Unordered,
+    /// The min and max values are in ascending order
+    Ascending,
+    /// The min and max values are in descending order
+    Descending,
+}
+
+/// The min and max values of each data page of a column chunk.
+///
+/// The values are plain-encoded, without the length prefix of byte arrays, and empty for pages
+/// that only contain nulls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnIndex {
+    /// Whether each page only contains nulls
+    pub null_pages: Vec<bool>,
+    /// The (possibly truncated) lower bound of the values of each page
+    pub min_values: Vec<Vec<u8>>,
+    /// The (possibly truncated) upper bound of the values of each page
+    pub max_values: Vec<Vec<u8>>,
+    /// Whether the min and max values are ordered
+    pub boundary_order: BoundaryOrder,
+    /// The number of nulls of each page, if known
+    pub null_counts: Option<Vec<i64>>,
+}
+
+/// The location of a data page in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PageLocation {
+    /// The offset of the page (including its header) in the file
+    pub offset: i64,
+    /// The size of the page, including its header
+    pub compressed_page_size: i32,
+    /// The index of the first row of the page in its row group
+    pub first_row_index: i64,
+}
+
+/// The location of each data page of a column chunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetIndex {
+    /// The locations of the data pages, in the order of the file
+    pub page_locations: Vec<PageLocation>,
+}
+
+impl ColumnIndex {
+    pub(crate) fn try_from_thrift(index: parquet_format_async_temp::ColumnIndex) -> Result<Self> {
+        let pages = index.null_pages.len();
+        if index.min_values.len() != pages
+            || index.max_values.len() != pages
+            || index.null_counts.as_ref().map_or(pages, Vec::len) != pages
+        {
+            return Err(ArrowError::OutOfSpec(
+                "The fields of a column index must have one entry per page".to_string(),
+            ));
+        }
+        let boundary_order = match index.boundary_order {
+            parquet_format_async_temp::BoundaryOrder::UNORDERED => BoundaryOrder::Unordered,
+            parquet_format_async_temp::BoundaryOrder::ASCENDING => BoundaryOrder::Ascending,
+            parquet_format_async_temp::BoundaryOrder::DESCENDING => BoundaryOrder::Descending,
+            _ => return Err(ArrowError::OutOfSpec(
+                "The boundary order of a column index must be unordered, ascending or descending"
+                    .to_string(),
+            )),
+        };
+        Ok(Self {
+            null_pages: index.null_pages,
+            min_values: index.min_values,
+            max_values: index.max_values,
+            boundary_order,
+            null_counts: index.null_counts,
+        })
+    }
+
+    pub(crate) fn into_thrift(self) -> parquet_format_async_temp::ColumnIndex {
+        let boundary_order = match self.boundary_order {
+            BoundaryOrder::Unordered => parquet_format_async_temp::BoundaryOrder::UNORDERED,
+            BoundaryOrder::Ascending => parquet_format_async_temp::BoundaryOrder::ASCENDING,
+            BoundaryOrder::Descending => parquet_format_async_temp::BoundaryOrder::DESCENDING,
+        };
+        parquet_format_async_temp::ColumnIndex::new(
+            self.null_pages,
+            self.min_values,
+            self.max_values,
+            boundary_order,
+            self.null_counts,
+        )
+    }
+}
+
+impl OffsetIndex {
+    pub(crate) fn try_from_thrift(index: parquet_format_async_temp::OffsetIndex) -> Result<Self> {
+        let page_locations = index
+            .page_locations
+            .into_iter()
+            .map(|location| {
+                if location.offset < 0
+                    || location.compressed_page_size < 0
+                    || location.first_row_index < 0
+                {
+                    return Err(ArrowError::OutOfSpec(
+                        "The location of a page must be non-negative".to_string(),
+                    ));
+                }
+                Ok(PageLocation {
+                    offset: location.offset,
+                    compressed_page_size: location.compressed_page_size,
+                    first_row_index: location.first_row_index,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { page_locations })
+    }
+
+    pub(crate) fn into_thrift(self) -> parquet_format_async_temp::OffsetIndex {
+        let page_locations = self
+            .page_locations
+            .into_iter()
+            .map(|location| {
+                parquet_format_async_temp::PageLocation::new(
+                    location.offset,
+                    location.compressed_page_size,
+                    location.first_row_index,
+                )
+            })
+            .collect();
+        parquet_format_async_temp::OffsetIndex::new(page_locations)
+    }
+}
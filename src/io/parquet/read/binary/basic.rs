@@ -1,5 +1,5 @@
 use parquet2::{
-    encoding::{delta_length_byte_array, hybrid_rle, Encoding},
+    encoding::{hybrid_rle, Encoding},
     metadata::ColumnDescriptor,
     page::{BinaryPageDict, DataPage},
 };
@@ -11,6 +11,7 @@ use crate::{
 };
 
 use super::super::utils;
+use super::utils::{decode_delta_byte_array, decode_delta_length, SizedBinaryIter};
 
 /// Assumptions: No rep levels
 #[allow(clippy::too_many_arguments)]
@@ -108,73 +109,20 @@ fn read_dict_required<O: Offset>(
     validity.extend_constant(additional, true);
 }
 
-fn read_delta_optional<O: Offset>(
+fn read_optional<'a, O, I>(
     validity_buffer: &[u8],
-    values_buffer: &[u8],
+    mut values_iterator: I,
     additional: usize,
     offsets: &mut Vec<O>,
     values: &mut Vec<u8>,
     validity: &mut MutableBitmap,
-) {
+) where
+    O: Offset,
+    I: Iterator<Item = &'a [u8]>,
+{
     let length = (offsets.len() - 1) + additional;
     let mut last_offset = *offsets.as_mut_slice().last().unwrap();
 
-    // values_buffer: first 4 bytes are len, remaining is values
-    let mut values_iterator = delta_length_byte_array::Decoder::new(values_buffer);
-
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
-
-    // offsets:
-    for run in validity_iterator {
-        match run {
-            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                // the pack may contain more items than needed.
-                let remaining = length - (offsets.len() - 1);
-                let len = std::cmp::min(packed.len() * 8, remaining);
-                for is_valid in BitmapIter::new(packed, 0, len) {
-                    if is_valid {
-                        let value = values_iterator.next().unwrap() as usize;
-                        last_offset += O::from_usize(value).unwrap();
-                    }
-                    offsets.push(last_offset);
-                }
-                validity.extend_from_slice(packed, 0, len);
-            }
-            hybrid_rle::HybridEncoded::Rle(value, additional) => {
-                let is_set = value[0] == 1;
-                validity.extend_constant(additional, is_set);
-                if is_set {
-                    (0..additional).for_each(|_| {
-                        let value = values_iterator.next().unwrap() as usize;
-                        last_offset += O::from_usize(value).unwrap();
-                        offsets.push(last_offset);
-                    })
-                } else {
-                    offsets.resize(offsets.len() + additional, last_offset);
-                }
-            }
-        }
-    }
-
-    // values:
-    let new_values = values_iterator.into_values();
-    values.extend_from_slice(new_values);
-}
-
-fn read_plain_optional<O: Offset>(
-    validity_buffer: &[u8],
-    values_buffer: &[u8],
-    additional: usize,
-    offsets: &mut Vec<O>,
-    values: &mut Vec<u8>,
-    validity: &mut MutableBitmap,
-) {
-    let length = (offsets.len() - 1) + additional;
-    let mut last_offset = *offsets.as_mut_slice().last().unwrap();
-
-    // values_buffer: first 4 bytes are len, remaining is values
-    let mut values_iterator = utils::BinaryIter::new(values_buffer);
-
     let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
 
     for run in validity_iterator {
@@ -232,6 +180,22 @@ pub(super) fn read_plain_required<O: Offset>(
     debug_assert_eq!(a, values.capacity());
 }
 
+pub(super) fn read_required<'a, O, I>(
+    values_iterator: I,
+    offsets: &mut Vec<O>,
+    values: &mut Vec<u8>,
+) where
+    O: Offset,
+    I: Iterator<Item = &'a [u8]>,
+{
+    let mut last_offset = *offsets.as_mut_slice().last().unwrap();
+    for value in values_iterator {
+        last_offset += O::from_usize(value.len()).unwrap();
+        values.extend_from_slice(value);
+        offsets.push(last_offset);
+    }
+}
+
 pub(super) fn extend_from_page<O: Offset>(
     page: &DataPage,
     descriptor: &ColumnDescriptor,
@@ -268,17 +232,47 @@ pub(super) fn extend_from_page<O: Offset>(
                 validity,
             )
         }
-        (Encoding::DeltaLengthByteArray, None, true) => read_delta_optional::<O>(
-            validity_buffer,
-            values_buffer,
-            additional,
-            offsets,
-            values,
-            validity,
-        ),
-        (Encoding::Plain, _, true) => read_plain_optional::<O>(
+        (Encoding::DeltaLengthByteArray, _, true) => {
+            let (lengths, new_values) = decode_delta_length(values_buffer, additional)?;
+            read_optional::<O, _>(
+                validity_buffer,
+                SizedBinaryIter::new(new_values, lengths.into_iter()),
+                additional,
+                offsets,
+                values,
+                validity,
+            )
+        }
+        (Encoding::DeltaLengthByteArray, _, false) => {
+            let (lengths, new_values) = decode_delta_length(values_buffer, additional)?;
+            read_required::<O, _>(
+                SizedBinaryIter::new(new_values, lengths.into_iter()),
+                offsets,
+                values,
+            )
+        }
+        (Encoding::DeltaByteArray, _, true) => {
+            let (lengths, new_values) = decode_delta_byte_array(values_buffer, additional)?;
+            read_optional::<O, _>(
+                validity_buffer,
+                SizedBinaryIter::new(&new_values, lengths.into_iter()),
+                additional,
+                offsets,
+                values,
+                validity,
+            )
+        }
+        (Encoding::DeltaByteArray, _, false) => {
+            let (lengths, new_values) = decode_delta_byte_array(values_buffer, additional)?;
+            read_required::<O, _>(
+                SizedBinaryIter::new(&new_values, lengths.into_iter()),
+                offsets,
+                values,
+            )
+        }
+        (Encoding::Plain, _, true) => read_optional::<O, _>(
             validity_buffer,
-            values_buffer,
+            utils::BinaryIter::new(values_buffer),
             additional,
             offsets,
             values,
@@ -1,89 +1,73 @@
-use std::sync::Arc;
-
 use parquet2::{
-    encoding::{hybrid_rle, Encoding},
+    encoding::Encoding,
     metadata::{ColumnChunkMetaData, ColumnDescriptor},
     page::{BinaryPageDict, DataPage},
     FallibleStreamingIterator,
 };
 
+use super::super::dictionary::{
+    read_dict_optional, read_dict_required, read_plain_optional, read_plain_required,
+    DictionaryValues,
+};
 use super::super::utils as other_utils;
+use super::utils::finish_array;
 use crate::{
-    array::{Array, DictionaryArray, DictionaryKey, Offset, PrimitiveArray, Utf8Array},
-    bitmap::{utils::BitmapIter, MutableBitmap},
+    array::{Array, DictionaryArray, DictionaryKey, Offset, PrimitiveArray},
+    bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
 };
 
-#[allow(clippy::too_many_arguments)]
-fn read_dict_optional<K, O>(
-    validity_buffer: &[u8],
-    indices_buffer: &[u8],
-    additional: usize,
-    dict: &BinaryPageDict,
-    indices: &mut Vec<K>,
-    offsets: &mut Vec<O>,
-    values: &mut Vec<u8>,
-    validity: &mut MutableBitmap,
-) where
-    K: DictionaryKey,
-    O: Offset,
-{
-    let length = indices.len() + additional;
-    values.extend_from_slice(dict.values());
-    offsets.extend(
-        dict.offsets()
-            .iter()
-            .map(|x| O::from_usize(*x as usize).unwrap()),
-    );
+/// The offsets and values of the binary values of the [`DictionaryArray`] being built.
+struct Binary<O: Offset> {
+    offsets: Vec<O>,
+    values: Vec<u8>,
+}
 
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
+impl<O: Offset> Binary<O> {
+    fn new() -> Self {
+        Self {
+            offsets: vec![O::zero()],
+            values: vec![],
+        }
+    }
 
-    let mut new_indices =
-        hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
+    /// Appends the values of `dict`, returning the key of its first value.
+    fn extend(&mut self, dict: &BinaryPageDict) -> Result<usize> {
+        let first_key = self.offsets.len() - 1;
+        let last_offset = self.values.len();
+        self.values.extend_from_slice(dict.values());
+        for offset in dict.offsets().iter().skip(1) {
+            let offset = last_offset + *offset as usize;
+            self.offsets
+                .push(O::from_usize(offset).ok_or(ArrowError::Overflow)?);
+        }
+        Ok(first_key)
+    }
+}
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+impl<O: Offset> DictionaryValues<&[u8]> for Binary<O> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
 
-    for run in validity_iterator {
-        match run {
-            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                let remaining = length - indices.len();
-                let len = std::cmp::min(packed.len() * 8, remaining);
-                for is_valid in BitmapIter::new(packed, 0, len) {
-                    let value = if is_valid {
-                        K::from_u32(new_indices.next().unwrap()).unwrap()
-                    } else {
-                        K::default()
-                    };
-                    indices.push(value);
-                }
-                validity.extend_from_slice(packed, 0, len);
-            }
-            hybrid_rle::HybridEncoded::Rle(value, additional) => {
-                let is_set = value[0] == 1;
-                validity.extend_constant(additional, is_set);
-                if is_set {
-                    (0..additional).for_each(|_| {
-                        let index = K::from_u32(new_indices.next().unwrap()).unwrap();
-                        indices.push(index)
-                    })
-                } else {
-                    indices.resize(indices.len() + additional, *indices.last().unwrap());
-                }
-            }
-        }
+    #[inline]
+    fn push(&mut self, value: &[u8]) -> Result<()> {
+        self.values.extend_from_slice(value);
+        self.offsets
+            .push(O::from_usize(self.values.len()).ok_or(ArrowError::Overflow)?);
+        Ok(())
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn extend_from_page<K, O>(
     page: &DataPage,
     descriptor: &ColumnDescriptor,
+    first_key: &mut Option<usize>,
     indices: &mut Vec<K>,
-    offsets: &mut Vec<O>,
-    values: &mut Vec<u8>,
+    values: &mut Binary<O>,
     validity: &mut MutableBitmap,
 ) -> Result<()>
 where
@@ -98,31 +82,59 @@ where
     let (_, validity_buffer, values_buffer, version) = other_utils::split_buffer(page, descriptor);
 
     match (&page.encoding(), page.dictionary_page(), is_optional) {
-        (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), true) => {
-            read_dict_optional(
-                validity_buffer,
-                values_buffer,
-                additional,
-                dict.as_any().downcast_ref().unwrap(),
-                indices,
-                offsets,
-                values,
-                validity,
-            )
-        }
-        _ => {
-            return Err(other_utils::not_implemented(
-                &page.encoding(),
-                is_optional,
-                page.dictionary_page().is_some(),
-                version,
-                "primitive",
-            ))
+        (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), _) => {
+            // a column chunk has at most one dictionary page, shared by all its data pages
+            let first_key = match first_key {
+                Some(first_key) => *first_key,
+                None => {
+                    let key = values.extend(dict.as_any().downcast_ref().unwrap())?;
+                    *first_key = Some(key);
+                    key
+                }
+            };
+            if is_optional {
+                read_dict_optional(
+                    validity_buffer,
+                    values_buffer,
+                    additional,
+                    first_key,
+                    indices,
+                    validity,
+                )
+            } else {
+                read_dict_required(values_buffer, additional, first_key, indices, validity)
+            }
         }
+        // writers fall back to plain encoding when the dictionary grows too large: its values are
+        // appended to the dictionary's values
+        (Encoding::Plain, _, true) => read_plain_optional(
+            validity_buffer,
+            other_utils::BinaryIter::new(values_buffer),
+            additional,
+            indices,
+            values,
+            validity,
+        ),
+        (Encoding::Plain, _, false) => read_plain_required(
+            other_utils::BinaryIter::new(values_buffer),
+            additional,
+            indices,
+            values,
+            validity,
+        ),
+        _ => Err(other_utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "Binary",
+        )),
     }
-    Ok(())
 }
 
+/// Converts the pages of a (large) utf8 or binary column chunk into a [`DictionaryArray`]
+/// whose values are the (parquet) dictionary of the chunk, without unpacking it.
+/// Values of plain-encoded pages are appended to the values.
 pub fn iter_to_array<K, O, I, E>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
@@ -136,31 +148,30 @@ where
 {
     let capacity = metadata.num_values() as usize;
     let mut indices = Vec::<K>::with_capacity(capacity);
-    let mut values = Vec::<u8>::with_capacity(0);
-    let mut offsets = Vec::<O>::with_capacity(1 + capacity);
+    let mut values = Binary::<O>::new();
     let mut validity = MutableBitmap::with_capacity(capacity);
+    let mut first_key = None;
     while let Some(page) = iter.next()? {
         extend_from_page(
             page,
             metadata.descriptor(),
+            &mut first_key,
             &mut indices,
-            &mut offsets,
             &mut values,
             &mut validity,
         )?
     }
 
-    if offsets.is_empty() {
-        // the array is empty and thus we need to push the first offset ourselves.
-        offsets.push(O::zero());
-    };
     let keys = PrimitiveArray::from_data(K::PRIMITIVE.into(), indices.into(), validity.into());
+    let is_ordered = DictionaryArray::<K>::get_is_ordered(&data_type);
     let data_type = DictionaryArray::<K>::get_child(&data_type).clone();
-    let values = Arc::new(Utf8Array::from_data(
+    let values = finish_array(
         data_type,
-        offsets.into(),
-        values.into(),
-        None,
-    ));
-    Ok(Box::new(DictionaryArray::<K>::from_data(keys, values)))
+        values.offsets,
+        values.values,
+        MutableBitmap::new(),
+    );
+    Ok(Box::new(
+        DictionaryArray::<K>::from_data(keys, values.into()).with_is_ordered(is_ordered),
+    ))
 }
@@ -7,7 +7,8 @@ use parquet2::{
 
 use super::super::nested_utils::*;
 use super::super::utils;
-use super::basic::read_plain_required;
+use super::basic::read_required;
+use super::utils::{decode_delta_byte_array, decode_delta_length, SizedBinaryIter};
 
 use crate::{array::Offset, bitmap::MutableBitmap, error::Result};
 
@@ -37,10 +38,10 @@ fn read_values<'a, O, D, G>(
 }
 
 #[allow(clippy::too_many_arguments)]
-fn read<O: Offset>(
+fn read<'a, O, G>(
     rep_levels: &[u8],
     def_levels: &[u8],
-    values_buffer: &[u8],
+    new_values: G,
     additional: usize,
     rep_level_encoding: (&Encoding, i16),
     def_level_encoding: (&Encoding, i16),
@@ -49,7 +50,10 @@ fn read<O: Offset>(
     offsets: &mut Vec<O>,
     values: &mut Vec<u8>,
     validity: &mut MutableBitmap,
-) {
+) where
+    O: Offset,
+    G: Iterator<Item = &'a [u8]>,
+{
     let max_rep_level = rep_level_encoding.1 as u32;
     let max_def_level = def_level_encoding.1 as u32;
 
@@ -63,7 +67,6 @@ fn read<O: Offset>(
                     get_bit_width(def_level_encoding.1),
                     additional,
                 );
-                let new_values = utils::BinaryIter::new(values_buffer);
                 read_values(
                     def_levels,
                     max_def_level,
@@ -73,7 +76,8 @@ fn read<O: Offset>(
                     validity,
                 )
             } else {
-                read_plain_required(values_buffer, additional, offsets, values)
+                // empty and null lists have no values
+                read_required(new_values, offsets, values)
             }
 
             let def_levels =
@@ -105,26 +109,61 @@ pub(super) fn extend_from_page<O: Offset>(
 
     let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor);
 
+    let rep_level_encoding = (
+        &page.repetition_level_encoding(),
+        descriptor.max_rep_level(),
+    );
+    let def_level_encoding = (
+        &page.definition_level_encoding(),
+        descriptor.max_def_level(),
+    );
+
     match (&page.encoding(), page.dictionary_page()) {
         (Encoding::Plain, None) => read(
             rep_levels,
             def_levels,
-            values_buffer,
+            utils::BinaryIter::new(values_buffer),
             additional,
-            (
-                &page.repetition_level_encoding(),
-                descriptor.max_rep_level(),
-            ),
-            (
-                &page.definition_level_encoding(),
-                descriptor.max_def_level(),
-            ),
+            rep_level_encoding,
+            def_level_encoding,
             is_nullable,
             nested,
             offsets,
             values,
             validity,
         ),
+        (Encoding::DeltaLengthByteArray, _) => {
+            let (lengths, new_values) = decode_delta_length(values_buffer, additional)?;
+            read(
+                rep_levels,
+                def_levels,
+                SizedBinaryIter::new(new_values, lengths.into_iter()),
+                additional,
+                rep_level_encoding,
+                def_level_encoding,
+                is_nullable,
+                nested,
+                offsets,
+                values,
+                validity,
+            )
+        }
+        (Encoding::DeltaByteArray, _) => {
+            let (lengths, new_values) = decode_delta_byte_array(values_buffer, additional)?;
+            read(
+                rep_levels,
+                def_levels,
+                SizedBinaryIter::new(&new_values, lengths.into_iter()),
+                additional,
+                rep_level_encoding,
+                def_level_encoding,
+                is_nullable,
+                nested,
+                offsets,
+                values,
+                validity,
+            )
+        }
         _ => {
             return Err(utils::not_implemented(
                 &page.encoding(),
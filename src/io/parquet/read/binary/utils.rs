@@ -1,9 +1,14 @@
+use std::convert::TryFrom;
+
 use crate::{
     array::{Array, BinaryArray, Offset, Utf8Array},
     bitmap::MutableBitmap,
     datatypes::DataType,
+    error::{ArrowError, Result},
 };
 
+use super::super::delta_bitpacked;
+
 pub(super) fn finish_array<O: Offset>(
     data_type: DataType,
     offsets: Vec<O>,
@@ -26,3 +31,93 @@ pub(super) fn finish_array<O: Offset>(
         _ => unreachable!(),
     }
 }
+
+/// An iterator over consecutive slices of `values` whose lengths are given by `lengths`.
+pub(super) struct SizedBinaryIter<'a, I: Iterator<Item = usize>> {
+    values: &'a [u8],
+    lengths: I,
+}
+
+impl<'a, I: Iterator<Item = usize>> SizedBinaryIter<'a, I> {
+    pub fn new(values: &'a [u8], lengths: I) -> Self {
+        Self { values, lengths }
+    }
+}
+
+impl<'a, I: Iterator<Item = usize>> Iterator for SizedBinaryIter<'a, I> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let length = self.lengths.next()?;
+        let (result, remaining) = self.values.split_at(length);
+        self.values = remaining;
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lengths.size_hint()
+    }
+}
+
+/// Decodes a `DELTA_LENGTH_BYTE_ARRAY`-encoded buffer of at most `max_values` values into the
+/// lengths of its values and their concatenation.
+pub(super) fn decode_delta_length(buffer: &[u8], max_values: usize) -> Result<(Vec<usize>, &[u8])> {
+    let (lengths, consumed) = delta_bitpacked::decode(buffer, 32, max_values)?;
+    let lengths = lengths
+        .into_iter()
+        .map(|length| {
+            usize::try_from(length).map_err(|_| {
+                ArrowError::ExternalFormat("DELTA_LENGTH_BYTE_ARRAY has a negative length".into())
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let values = &buffer[consumed..];
+    let total = lengths
+        .iter()
+        .try_fold(0usize, |total, length| total.checked_add(*length));
+    if total.map(|total| total > values.len()).unwrap_or(true) {
+        return Err(ArrowError::ExternalFormat(
+            "DELTA_LENGTH_BYTE_ARRAY lengths exceed its values".into(),
+        ));
+    }
+    Ok((lengths, values))
+}
+
+/// Decodes a `DELTA_BYTE_ARRAY`-encoded buffer, where each value is the prefix it shares
+/// with the previous value followed by a suffix, of at most `max_values` values into the lengths
+/// of its values and their concatenation.
+pub(super) fn decode_delta_byte_array(
+    buffer: &[u8],
+    max_values: usize,
+) -> Result<(Vec<usize>, Vec<u8>)> {
+    let (prefixes, consumed) = delta_bitpacked::decode(buffer, 32, max_values)?;
+    let (suffix_lengths, mut suffixes) = decode_delta_length(&buffer[consumed..], max_values)?;
+    if prefixes.len() != suffix_lengths.len() {
+        return Err(ArrowError::ExternalFormat(
+            "DELTA_BYTE_ARRAY must have as many prefixes as suffixes".into(),
+        ));
+    }
+
+    let mut lengths = Vec::with_capacity(prefixes.len());
+    let mut values = Vec::with_capacity(suffixes.len());
+    let mut previous = 0..0;
+    for (prefix, suffix) in prefixes.into_iter().zip(suffix_lengths) {
+        let prefix = usize::try_from(prefix).map_err(|_| {
+            ArrowError::ExternalFormat("DELTA_BYTE_ARRAY has a negative prefix length".into())
+        })?;
+        if prefix > previous.len() || suffix > suffixes.len() {
+            return Err(ArrowError::ExternalFormat(
+                "DELTA_BYTE_ARRAY prefix or suffix lengths exceed its values".into(),
+            ));
+        }
+        let start = values.len();
+        values.extend_from_within(previous.start..previous.start + prefix);
+        values.extend_from_slice(&suffixes[..suffix]);
+        suffixes = &suffixes[suffix..];
+        previous = start..values.len();
+        lengths.push(previous.len());
+    }
+    Ok((lengths, values))
+}
@@ -0,0 +1,70 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use parquet_format_async_temp::thrift::protocol::TCompactInputProtocol;
+use parquet_format_async_temp::BloomFilterHeader;
+
+use crate::error::{ArrowError, Result};
+
+use super::super::bloom_filter::{BloomFilter, MAX_BYTES};
+use super::{ColumnChunkMetaData, ParquetError};
+
+/// Reads the [`BloomFilter`] of a column chunk, or returns `None` if the column chunk has none.
+/// # Example
+/// ```no_run
+/// use std::fs::File;
+/// use arrow2::error::Result;
+/// use arrow2::io::parquet::read::{read_bloom_filter, read_metadata};
+/// use arrow2::scalar::PrimitiveScalar;
+/// # fn main() -> Result<()> {
+/// let mut reader = File::open("data.parquet")?;
+/// let metadata = read_metadata(&mut reader)?;
+/// let value = PrimitiveScalar::<i64>::new(arrow2::datatypes::DataType::Int64, Some(42));
+/// // the row groups whose first column may contain 42
+/// let mut groups = vec![];
+/// for (i, group) in metadata.row_groups.iter().enumerate() {
+///     match read_bloom_filter(group.column(0), &mut reader)? {
+///         Some(filter) if !filter.might_contain(&value) => {}
+///         _ => groups.push(i),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the bloom filter can't be read or is not valid.
+pub fn read_bloom_filter<R: Read + Seek>(
+    column: &ColumnChunkMetaData,
+    reader: &mut R,
+) -> Result<Option<BloomFilter>> {
+    let offset = column
+        .clone()
+        .into_thrift()
+        .meta_data
+        .and_then(|metadata| metadata.bloom_filter_offset);
+    let offset = match offset {
+        Some(offset) => offset,
+        None => return Ok(None),
+    };
+    if offset < 0 {
+        return Err(ArrowError::OutOfSpec(
+            "The offset of a bloom filter must be non-negative".to_string(),
+        ));
+    }
+    reader.seek(SeekFrom::Start(offset as u64))?;
+
+    let mut protocol = TCompactInputProtocol::new(&mut *reader);
+    // the algorithm, hash and compression have a single variant each
+    let header = BloomFilterHeader::read_from_in_protocol(&mut protocol)
+        .map_err(|e| ArrowError::from(ParquetError::from(e)))?;
+    let num_bytes = header.num_bytes as usize;
+    if header.num_bytes <= 0 || num_bytes > MAX_BYTES {
+        return Err(ArrowError::OutOfSpec(format!(
+            "The bitset of a bloom filter must have between 1 and {} bytes, but it has {}",
+            MAX_BYTES, header.num_bytes
+        )));
+    }
+
+    let mut bitset = vec![0; num_bytes];
+    reader.read_exact(&mut bitset)?;
+    BloomFilter::try_from_bitset(&bitset).map(Some)
+}
@@ -67,7 +67,14 @@ fn read(
                 let new_values = BitmapIter::new(values_buffer, 0, num_valid_values);
                 read_values(def_levels, max_def_level, new_values, values, validity)
             } else {
-                read_required(values_buffer, additional, values)
+                // empty and null lists have no values
+                let def_levels = HybridRleDecoder::new(
+                    def_levels,
+                    get_bit_width(def_level_encoding.1),
+                    additional,
+                );
+                let num_values = def_levels.filter(|def| *def == max_def_level).count();
+                read_required(values_buffer, num_values, values)
             }
 
             let def_levels =
@@ -0,0 +1,73 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use parquet_format_async_temp::thrift::protocol::TCompactInputProtocol;
+use parquet_format_async_temp::PageHeader;
+
+use crate::error::{ArrowError, Result};
+
+use super::{ColumnChunkMetaData, ParquetError};
+
+/// Verifies the CRC-32 checksums of the pages of a column chunk, returning the number of pages
+/// with a checksum. Pages without a checksum (the field is optional) are not verified.
+/// # Example
+/// ```no_run
+/// use std::fs::File;
+/// use arrow2::error::Result;
+/// use arrow2::io::parquet::read::{read_metadata, verify_page_crcs};
+/// # fn main() -> Result<()> {
+/// let mut reader = File::open("data.parquet")?;
+/// let metadata = read_metadata(&mut reader)?;
+/// for group in &metadata.row_groups {
+///     for column in group.columns() {
+///         verify_page_crcs(column, &mut reader)?;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the pages can't be read or iff the checksum of a page does not match its content.
+pub fn verify_page_crcs<R: Read + Seek>(
+    column: &ColumnChunkMetaData,
+    reader: &mut R,
+) -> Result<usize> {
+    let (start, length) = column.byte_range();
+    reader.seek(SeekFrom::Start(start))?;
+    let mut chunk = vec![];
+    reader.take(length).read_to_end(&mut chunk)?;
+    if chunk.len() as u64 != length {
+        return Err(ArrowError::OutOfSpec(
+            "The column chunk is larger than the file".to_string(),
+        ));
+    }
+
+    let mut verified = 0;
+    let mut remaining = chunk.as_slice();
+    while !remaining.is_empty() {
+        let mut protocol = TCompactInputProtocol::new(&mut remaining);
+        let header = PageHeader::read_from_in_protocol(&mut protocol)
+            .map_err(|e| ArrowError::from(ParquetError::from(e)))?;
+        let size = header.compressed_page_size as usize;
+        if header.compressed_page_size < 0 || size > remaining.len() {
+            return Err(ArrowError::OutOfSpec(
+                "The compressed size of a page exceeds its column chunk".to_string(),
+            ));
+        }
+        let (page, next) = remaining.split_at(size);
+        remaining = next;
+
+        if let Some(crc) = header.crc {
+            let actual = crc::crc32::checksum_ieee(page) as i32;
+            if actual != crc {
+                return Err(ArrowError::OutOfSpec(format!(
+                    "The CRC-32 of a page of column {:?} is {} but its header declares {}",
+                    column.descriptor().path_in_schema(),
+                    actual,
+                    crc
+                )));
+            }
+            verified += 1;
+        }
+    }
+    Ok(verified)
+}
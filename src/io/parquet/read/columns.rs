@@ -0,0 +1,125 @@
+//! APIs to read the column chunks of a row group into memory, so that they can be
+//! deserialized independently (e.g. in a thread pool).
+use std::io::{Read, Seek};
+
+use crate::{
+    array::Array,
+    datatypes::Field,
+    error::{ArrowError, Result},
+};
+
+use super::{
+    column_iter_to_array, get_column_iterator, get_schema, ColumnChunkMetaData, CompressedDataPage,
+    FileMetaData, MutStreamingIterator, ParquetType, ReadColumnIterator, State,
+};
+
+/// The compressed pages of all parquet columns of a field in a row group. It can be
+/// deserialized into an [`Array`] via [`ColumnChunks::deserialize`] without access to the
+/// original reader.
+///
+/// This struct is [`Send`], so that the CPU-bounded deserialization of many fields can be
+/// distributed across threads once their (IO-bounded) pages are read.
+#[derive(Debug)]
+pub struct ColumnChunks {
+    field: Field,
+    parquet_field: ParquetType,
+    chunks: Vec<(Vec<CompressedDataPage>, ColumnChunkMetaData)>,
+}
+
+impl ColumnChunks {
+    /// Returns the arrow [`Field`] of this [`ColumnChunks`].
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
+
+    /// Returns the number of compressed pages of this [`ColumnChunks`].
+    pub fn num_pages(&self) -> usize {
+        self.chunks.iter().map(|(pages, _)| pages.len()).sum()
+    }
+
+    /// Decompresses, decodes and deserializes this [`ColumnChunks`] into an [`Array`].
+    pub fn deserialize(self) -> Result<Box<dyn Array>> {
+        let chunks = self
+            .chunks
+            .into_iter()
+            // `ReadColumnIterator` yields its chunks from the last to the first
+            .rev()
+            .map(|(pages, metadata)| (pages.into_iter().map(Ok).collect(), metadata))
+            .collect();
+        let columns = ReadColumnIterator::new(self.parquet_field, chunks);
+        column_iter_to_array(columns, &self.field, vec![]).map(|x| x.0)
+    }
+}
+
+/// Reads the compressed pages of the fields of row group `row_group` into memory, returning one
+/// [`ColumnChunks`] per field (or per field in `projection`, in its order).
+///
+/// This function is IO-bounded. Use [`ColumnChunks::deserialize`] (or [`deserialize_parallel`]
+/// when the `rayon` feature is active) to convert each of them into an [`Array`].
+/// # Error
+/// This function errors iff:
+/// * the row group or a field in `projection` does not exist
+/// * the arrow schema can't be inferred from the file's metadata
+/// * reading the pages fails
+pub fn read_columns<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    row_group: usize,
+    projection: Option<&[usize]>,
+) -> Result<Vec<ColumnChunks>> {
+    if row_group >= metadata.row_groups.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The row group {} does not exist; the file has {} row groups",
+            row_group,
+            metadata.row_groups.len()
+        )));
+    }
+    let schema = get_schema(metadata)?;
+
+    let indices = projection
+        .map(|x| x.to_vec())
+        .unwrap_or_else(|| (0..schema.fields().len()).collect());
+    if let Some(index) = indices.iter().find(|i| **i >= schema.fields().len()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The field {} does not exist; the file has {} fields",
+            index,
+            schema.fields().len()
+        )));
+    }
+
+    indices
+        .into_iter()
+        .map(|field_index| {
+            let mut columns =
+                get_column_iterator(&mut *reader, metadata, row_group, field_index, None, vec![]);
+            let parquet_field = metadata.schema().fields()[field_index].clone();
+
+            let mut chunks = vec![];
+            while let State::Some(mut new_iter) = columns.advance()? {
+                if let Some((pages, metadata)) = new_iter.get() {
+                    let pages = pages.collect::<std::result::Result<Vec<_>, _>>()?;
+                    chunks.push((pages, metadata.clone()));
+                }
+                columns = new_iter;
+            }
+            Ok(ColumnChunks {
+                field: schema.fields()[field_index].clone(),
+                parquet_field,
+                chunks,
+            })
+        })
+        .collect()
+}
+
+/// Deserializes `columns` into [`Array`]s in parallel, using [`rayon`]'s global thread pool.
+/// The arrays are returned in the same order as `columns`.
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn deserialize_parallel(columns: Vec<ColumnChunks>) -> Result<Vec<Box<dyn Array>>> {
+    use rayon::prelude::*;
+
+    columns
+        .into_par_iter()
+        .map(|column| column.deserialize())
+        .collect()
+}
@@ -0,0 +1,171 @@
+//! Decoding of `DELTA_BINARY_PACKED`-encoded buffers that, unlike the decoder of `parquet2`,
+//! errors (instead of panicking) on buffers that do not follow the specification.
+use std::convert::TryFrom;
+
+use crate::error::{ArrowError, Result};
+
+fn out_of_spec(message: &str) -> ArrowError {
+    ArrowError::OutOfSpec(format!("DELTA_BINARY_PACKED {}", message))
+}
+
+/// Decodes the ULEB128-encoded integer at the start of `buffer`, returning it and its number of
+/// bytes.
+fn decode_uleb128(buffer: &[u8]) -> Result<(u64, usize)> {
+    let mut result = 0u64;
+    for (i, byte) in buffer.iter().enumerate() {
+        let shift = 7 * i;
+        if shift > 63 || (shift == 63 && byte & 0x7f > 1) {
+            return Err(out_of_spec("has an integer that does not fit in 64 bits"));
+        }
+        result |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok((result, i + 1));
+        }
+    }
+    Err(out_of_spec("ends within an integer"))
+}
+
+/// Decodes the zigzag ULEB128-encoded integer at the start of `buffer`, returning it and its
+/// number of bytes.
+fn decode_zigzag(buffer: &[u8]) -> Result<(i64, usize)> {
+    let (value, consumed) = decode_uleb128(buffer)?;
+    Ok(((value >> 1) as i64 ^ -((value & 1) as i64), consumed))
+}
+
+fn decode_usize(buffer: &[u8]) -> Result<(usize, usize)> {
+    let (value, consumed) = decode_uleb128(buffer)?;
+    let value =
+        usize::try_from(value).map_err(|_| out_of_spec("has a size that does not fit in usize"))?;
+    Ok((value, consumed))
+}
+
+/// Decodes a block size or number of miniblocks, which readers represent as 32-bit integers.
+fn decode_u32(buffer: &[u8]) -> Result<(usize, usize)> {
+    let (value, consumed) = decode_uleb128(buffer)?;
+    let value = u32::try_from(value)
+        .map_err(|_| out_of_spec("has a block that does not fit in 32 bits"))?;
+    Ok((value as usize, consumed))
+}
+
+/// Returns the `num_bits`-bit integer starting at bit `offset` of `packed`, packed from the least
+/// significant bit.
+#[inline]
+fn unpack(packed: &[u8], offset: usize, num_bits: usize) -> u64 {
+    if num_bits == 0 {
+        return 0;
+    }
+    let start = offset / 8;
+    let end = (offset + num_bits + 7) / 8;
+    // at most 9 bytes, since `num_bits <= 64`
+    let word = packed[start..end]
+        .iter()
+        .enumerate()
+        .fold(0u128, |word, (i, byte)| {
+            word | (u128::from(*byte) << (8 * i))
+        });
+    let mask = if num_bits == 64 {
+        u64::MAX
+    } else {
+        (1u64 << num_bits) - 1
+    };
+    (word >> (offset % 8)) as u64 & mask
+}
+
+/// Decodes a `DELTA_BINARY_PACKED`-encoded buffer of at most `max_values` integers of
+/// `max_bit_width` bits (32 for `INT32` and 64 for `INT64`), returning its values and the number
+/// of bytes it consumed. Deltas are added with wrapping arithmetic, like they are computed on
+/// write.
+/// # Errors
+/// Errors iff the buffer is not valid, e.g. it is too short, its blocks are not multiples of
+/// 128 values, it has more than `max_values` values or a miniblock is wider than
+/// `max_bit_width` bits.
+pub(super) fn decode(
+    buffer: &[u8],
+    max_bit_width: u8,
+    max_values: usize,
+) -> Result<(Vec<i64>, usize)> {
+    let mut position = 0;
+    let (block_size, consumed) = decode_u32(buffer)?;
+    position += consumed;
+    let (num_mini_blocks, consumed) = decode_u32(&buffer[position..])?;
+    position += consumed;
+    let (total_count, consumed) = decode_usize(&buffer[position..])?;
+    position += consumed;
+    let (first_value, consumed) = decode_zigzag(&buffer[position..])?;
+    position += consumed;
+
+    if block_size == 0 || block_size % 128 != 0 {
+        return Err(out_of_spec(&format!(
+            "block size must be a positive multiple of 128, but it is {}",
+            block_size
+        )));
+    }
+    if num_mini_blocks == 0 || block_size % num_mini_blocks != 0 {
+        return Err(out_of_spec(&format!(
+            "block size {} is not divisible into {} miniblocks",
+            block_size, num_mini_blocks
+        )));
+    }
+    let values_per_mini_block = block_size / num_mini_blocks;
+    if values_per_mini_block % 8 != 0 {
+        return Err(out_of_spec(&format!(
+            "miniblocks must have a multiple of 8 values, but they have {}",
+            values_per_mini_block
+        )));
+    }
+
+    // a miniblock of zero-width deltas has no bytes, so only the page bounds `total_count`
+    if total_count > max_values {
+        return Err(out_of_spec(&format!(
+            "has {} values, but its page has at most {}",
+            total_count, max_values
+        )));
+    }
+    let mut values = Vec::with_capacity(total_count);
+    if total_count == 0 {
+        return Ok((values, position));
+    }
+    let mut value = first_value;
+    values.push(value);
+
+    while values.len() < total_count {
+        let (min_delta, consumed) = decode_zigzag(&buffer[position..])?;
+        position += consumed;
+        let bit_widths = position
+            .checked_add(num_mini_blocks)
+            .and_then(|end| buffer.get(position..end))
+            .ok_or_else(|| out_of_spec("ends within the bit widths of a block"))?;
+        position += num_mini_blocks;
+
+        for &num_bits in bit_widths {
+            if values.len() == total_count {
+                // the remaining miniblocks have no values
+                break;
+            }
+            if num_bits > max_bit_width {
+                return Err(out_of_spec(&format!(
+                    "has a miniblock of {} bits, but the maximum is {}",
+                    num_bits, max_bit_width
+                )));
+            }
+            let num_bits = num_bits as usize;
+            let length = values_per_mini_block
+                .checked_mul(num_bits)
+                .map(|bits| bits / 8)
+                .ok_or_else(|| out_of_spec("has a miniblock that does not fit in usize"))?;
+            let packed = position
+                .checked_add(length)
+                .and_then(|end| buffer.get(position..end))
+                .ok_or_else(|| out_of_spec("ends within a miniblock"))?;
+            position += length;
+
+            let count = values_per_mini_block.min(total_count - values.len());
+            for i in 0..count {
+                let delta = unpack(packed, i * num_bits, num_bits) as i64;
+                value = value.wrapping_add(min_delta.wrapping_add(delta));
+                values.push(value);
+            }
+        }
+    }
+    Ok((values, position))
+}
@@ -0,0 +1,321 @@
+//! Decoding of the keys and values of pages of dictionary-encoded column chunks into the
+//! indices and values of a [`DictionaryArray`](crate::array::DictionaryArray), shared by
+//! all physical types.
+use parquet2::encoding::{bitpacking, ceil8, hybrid_rle};
+
+use crate::{
+    array::DictionaryKey,
+    bitmap::{utils::BitmapIter, MutableBitmap},
+    error::{ArrowError, Result},
+};
+
+/// The values of a [`DictionaryArray`](crate::array::DictionaryArray) being built, to which
+/// values of type `V` are appended.
+pub(super) trait DictionaryValues<V> {
+    /// The number of values, i.e. the key of the next value.
+    fn len(&self) -> usize;
+
+    /// Appends `value`.
+    fn push(&mut self, value: V) -> Result<()>;
+}
+
+impl<A> DictionaryValues<A> for Vec<A> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn push(&mut self, value: A) -> Result<()> {
+        self.push(value);
+        Ok(())
+    }
+}
+
+/// Appends `value` to `values` and a key pointing to it to `indices`.
+#[inline]
+fn push_value<K: DictionaryKey, V, D: DictionaryValues<V>>(
+    value: V,
+    indices: &mut Vec<K>,
+    values: &mut D,
+) -> Result<()> {
+    indices.push(K::from_usize(values.len()).ok_or(ArrowError::Overflow)?);
+    values.push(value)
+}
+
+#[inline]
+fn to_key<K: DictionaryKey>(first_key: usize, index: Option<Result<u32>>) -> Result<K> {
+    let index = index.unwrap_or_else(|| {
+        Err(ArrowError::OutOfSpec(
+            "A dictionary-encoded page has fewer keys than valid values".to_string(),
+        ))
+    })?;
+    K::from_usize(first_key + index as usize).ok_or(ArrowError::Overflow)
+}
+
+#[inline]
+fn to_value<V>(value: Option<V>) -> Result<V> {
+    value.ok_or_else(|| {
+        ArrowError::OutOfSpec("A plain-encoded page has fewer values than valid values".to_string())
+    })
+}
+
+fn out_of_spec_keys(message: &str) -> ArrowError {
+    ArrowError::OutOfSpec(format!(
+        "The keys of a dictionary-encoded page are invalid: {}",
+        message
+    ))
+}
+
+/// Decodes a ULEB128-encoded `u64` from the start of `buffer`, returning it and the number of
+/// bytes it takes.
+fn decode_uleb128(buffer: &[u8]) -> Result<(u64, usize)> {
+    let mut result = 0;
+    for (i, byte) in buffer.iter().enumerate().take(10) {
+        if i == 9 && *byte > 1 {
+            return Err(out_of_spec_keys("a run header overflows 64 bits"));
+        }
+        result |= u64::from(byte & 0b0111_1111) << (7 * i);
+        if byte & 0b1000_0000 == 0 {
+            return Ok((result, i + 1));
+        }
+    }
+    Err(out_of_spec_keys("a run header is truncated"))
+}
+
+enum Run<'a> {
+    Bitpacked(bitpacking::Decoder<'a>),
+    Rle(u32, usize),
+}
+
+/// An iterator over the keys of a dictionary-encoded page. Unlike
+/// [`hybrid_rle::HybridRleDecoder`], it errors instead of panicking on truncated or invalid runs
+/// and ends when the page has no more keys.
+struct Keys<'a> {
+    buffer: &'a [u8],
+    bit_width: u32,
+    run: Run<'a>,
+}
+
+impl<'a> Keys<'a> {
+    fn try_new(indices_buffer: &'a [u8]) -> Result<Self> {
+        // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
+        // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
+        let (bit_width, buffer) = indices_buffer
+            .split_first()
+            .ok_or_else(|| out_of_spec_keys("the page has no bit width"))?;
+        if *bit_width > 32 {
+            return Err(out_of_spec_keys(&format!(
+                "the bit width must be at most 32, but it is {}",
+                bit_width
+            )));
+        }
+        Ok(Self {
+            buffer,
+            bit_width: *bit_width as u32,
+            run: Run::Rle(0, 0),
+        })
+    }
+
+    fn next_run(&mut self) -> Result<Run<'a>> {
+        let (indicator, consumed) = decode_uleb128(self.buffer)?;
+        self.buffer = &self.buffer[consumed..];
+        let length = (indicator >> 1) as usize;
+        if indicator & 1 == 1 {
+            // `length` groups of 8 values, of which the last run may be truncated
+            let bytes = length
+                .saturating_mul(self.bit_width as usize)
+                .min(self.buffer.len());
+            let (packed, buffer) = self.buffer.split_at(bytes);
+            self.buffer = buffer;
+            if packed.is_empty() {
+                return Ok(Run::Rle(0, 0));
+            }
+            let length = packed.len() * 8 / self.bit_width as usize;
+            Ok(Run::Bitpacked(bitpacking::Decoder::new(
+                packed,
+                self.bit_width as u8,
+                length,
+            )))
+        } else {
+            // the value is repeated `length` times, in the bytes required by the bit width
+            let value_bytes = ceil8(self.bit_width as usize);
+            if self.buffer.len() < value_bytes {
+                return Err(out_of_spec_keys("a run is truncated"));
+            }
+            let mut bytes = [0u8; 4];
+            bytes[..value_bytes].copy_from_slice(&self.buffer[..value_bytes]);
+            self.buffer = &self.buffer[value_bytes..];
+            Ok(Run::Rle(u32::from_le_bytes(bytes), length))
+        }
+    }
+}
+
+impl<'a> Iterator for Keys<'a> {
+    type Item = Result<u32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bit_width == 0 {
+            // every key is zero and takes no space
+            return Some(Ok(0));
+        }
+        loop {
+            match &mut self.run {
+                Run::Bitpacked(decoder) => {
+                    if let Some(key) = decoder.next() {
+                        return Some(Ok(key));
+                    }
+                }
+                Run::Rle(key, remaining) => {
+                    if *remaining > 0 {
+                        *remaining -= 1;
+                        return Some(Ok(*key));
+                    }
+                }
+            }
+            if self.buffer.is_empty() {
+                return None;
+            }
+            match self.next_run() {
+                Ok(run) => self.run = run,
+                Err(e) => {
+                    self.buffer = &[];
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Appends the keys of an optional dictionary-encoded page, offset by `first_key`, the key of
+/// the first value of the chunk's dictionary.
+pub(super) fn read_dict_optional<K: DictionaryKey>(
+    validity_buffer: &[u8],
+    indices_buffer: &[u8],
+    additional: usize,
+    first_key: usize,
+    indices: &mut Vec<K>,
+    validity: &mut MutableBitmap,
+) -> Result<()> {
+    let length = indices.len() + additional;
+
+    let mut new_indices = Keys::try_new(indices_buffer)?;
+
+    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+
+    for run in validity_iterator {
+        match run {
+            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
+                let remaining = length - indices.len();
+                let len = std::cmp::min(packed.len() * 8, remaining);
+                for is_valid in BitmapIter::new(packed, 0, len) {
+                    let key = if is_valid {
+                        to_key(first_key, new_indices.next())?
+                    } else {
+                        K::default()
+                    };
+                    indices.push(key);
+                }
+                validity.extend_from_slice(packed, 0, len);
+            }
+            hybrid_rle::HybridEncoded::Rle(value, additional) => {
+                let is_set = value[0] == 1;
+                validity.extend_constant(additional, is_set);
+                if is_set {
+                    for _ in 0..additional {
+                        indices.push(to_key(first_key, new_indices.next())?)
+                    }
+                } else {
+                    indices.resize(indices.len() + additional, K::default());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Appends the keys of a required dictionary-encoded page, offset by `first_key`, the key of
+/// the first value of the chunk's dictionary.
+pub(super) fn read_dict_required<K: DictionaryKey>(
+    indices_buffer: &[u8],
+    additional: usize,
+    first_key: usize,
+    indices: &mut Vec<K>,
+    validity: &mut MutableBitmap,
+) -> Result<()> {
+    let mut new_indices = Keys::try_new(indices_buffer)?;
+
+    for _ in 0..additional {
+        indices.push(to_key(first_key, new_indices.next())?);
+    }
+    validity.extend_constant(additional, true);
+    Ok(())
+}
+
+/// Appends the values of an optional plain-encoded page to `values` and keys pointing to them.
+pub(super) fn read_plain_optional<K, V, D, I>(
+    validity_buffer: &[u8],
+    mut values_iterator: I,
+    additional: usize,
+    indices: &mut Vec<K>,
+    values: &mut D,
+    validity: &mut MutableBitmap,
+) -> Result<()>
+where
+    K: DictionaryKey,
+    D: DictionaryValues<V>,
+    I: Iterator<Item = V>,
+{
+    let length = indices.len() + additional;
+
+    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+
+    for run in validity_iterator {
+        match run {
+            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
+                let remaining = length - indices.len();
+                let len = std::cmp::min(packed.len() * 8, remaining);
+                for is_valid in BitmapIter::new(packed, 0, len) {
+                    if is_valid {
+                        push_value(to_value(values_iterator.next())?, indices, values)?;
+                    } else {
+                        indices.push(K::default());
+                    }
+                }
+                validity.extend_from_slice(packed, 0, len);
+            }
+            hybrid_rle::HybridEncoded::Rle(value, additional) => {
+                let is_set = value[0] == 1;
+                validity.extend_constant(additional, is_set);
+                if is_set {
+                    for _ in 0..additional {
+                        push_value(to_value(values_iterator.next())?, indices, values)?;
+                    }
+                } else {
+                    indices.resize(indices.len() + additional, K::default());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Appends the values of a required plain-encoded page to `values` and keys pointing to them.
+pub(super) fn read_plain_required<K, V, D, I>(
+    mut values_iterator: I,
+    additional: usize,
+    indices: &mut Vec<K>,
+    values: &mut D,
+    validity: &mut MutableBitmap,
+) -> Result<()>
+where
+    K: DictionaryKey,
+    D: DictionaryValues<V>,
+    I: Iterator<Item = V>,
+{
+    for _ in 0..additional {
+        push_value(to_value(values_iterator.next())?, indices, values)?;
+    }
+    validity.extend_constant(additional, true);
+    Ok(())
+}
@@ -29,22 +29,37 @@ pub use parquet2::{
 };
 
 use crate::{
-    array::{Array, DictionaryKey, NullArray, PrimitiveArray, StructArray},
+    array::{Array, DictionaryKey, ListArray, MapArray, NullArray, PrimitiveArray, StructArray},
     datatypes::{DataType, Field, IntervalUnit, TimeUnit},
     error::{ArrowError, Result},
     io::parquet::read::nested_utils::{create_list, init_nested},
 };
 
 mod binary;
+mod bloom_filter;
 mod boolean;
+mod checksum;
+mod columns;
+mod delta_bitpacked;
+mod dictionary;
 mod fixed_size_binary;
 mod nested_utils;
+mod page_index;
 mod primitive;
 mod record_batch;
 pub mod schema;
 pub mod statistics;
 mod utils;
 
+pub use super::bloom_filter::BloomFilter;
+pub use super::page_index::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocation};
+pub use bloom_filter::read_bloom_filter;
+pub use checksum::verify_page_crcs;
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub use columns::deserialize_parallel;
+pub use columns::{read_columns, ColumnChunks};
+pub use page_index::{read_column_index, read_offset_index};
 pub use record_batch::RecordReader;
 pub(crate) use schema::is_type_nullable;
 pub use schema::{get_schema, FileMetaData};
@@ -147,15 +162,16 @@ fn dict_read<
             ParquetType::PrimitiveType {
                 physical_type,
                 logical_type,
+                converted_type,
                 ..
-            } => match (physical_type, logical_type) {
-                (PhysicalType::Int96, _) => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
+            } => match (physical_type, logical_type, converted_type) {
+                (PhysicalType::Int96, _, _) => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
                     iter,
                     metadata,
                     DataType::Timestamp(TimeUnit::Nanosecond, None),
                     int96_to_i64_ns,
                 ),
-                (_, Some(LogicalType::TIMESTAMP(TimestampType { unit, .. }))) => match unit {
+                (_, Some(LogicalType::TIMESTAMP(TimestampType { unit, .. })), _) => match unit {
                     ParquetTimeUnit::MILLIS(_) => {
                         primitive::iter_to_dict_array::<K, _, _, _, _, _>(
                             iter,
@@ -179,6 +195,22 @@ fn dict_read<
                         |x: i64| x,
                     ),
                 },
+                (_, None, Some(PrimitiveConvertedType::TimestampMillis)) => {
+                    primitive::iter_to_dict_array::<K, _, _, _, _, _>(
+                        iter,
+                        metadata,
+                        data_type,
+                        |x: i64| x * 1_000_000,
+                    )
+                }
+                (_, None, Some(PrimitiveConvertedType::TimestampMicros)) => {
+                    primitive::iter_to_dict_array::<K, _, _, _, _, _>(
+                        iter,
+                        metadata,
+                        data_type,
+                        |x: i64| x * 1_000,
+                    )
+                }
                 _ => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
                     iter,
                     metadata,
@@ -191,8 +223,22 @@ fn dict_read<
         Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
             primitive::iter_to_dict_array::<K, _, _, _, _, _>(iter, metadata, data_type, |x: i64| x)
         }
+        UInt64 => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
+            iter,
+            metadata,
+            data_type,
+            |x: i64| x as u64,
+        ),
+        Float32 => {
+            primitive::iter_to_dict_array::<K, _, _, _, _, _>(iter, metadata, data_type, |x: f32| x)
+        }
+        Float64 => {
+            primitive::iter_to_dict_array::<K, _, _, _, _, _>(iter, metadata, data_type, |x: f64| x)
+        }
         Utf8 => binary::iter_to_dict_array::<K, i32, _, _>(iter, metadata, data_type),
         LargeUtf8 => binary::iter_to_dict_array::<K, i64, _, _>(iter, metadata, data_type),
+        Binary => binary::iter_to_dict_array::<K, i32, _, _>(iter, metadata, data_type),
+        LargeBinary => binary::iter_to_dict_array::<K, i64, _, _>(iter, metadata, data_type),
         other => Err(ArrowError::NotYetImplemented(format!(
             "Reading dictionaries of type {:?}",
             other
@@ -213,7 +259,8 @@ fn column_offset(data_type: &DataType) -> usize {
             }
         }
         Union => todo!(),
-        Map => todo!(),
+        // a map is stored as two columns, its keys and its values
+        Map => 1,
     }
 }
 
@@ -242,7 +289,17 @@ fn column_datatype(data_type: &DataType, column: usize) -> DataType {
             }
         }
         Union => todo!(),
-        Map => todo!(),
+        Map => {
+            if let DataType::Map(field, _) = data_type.to_logical_type() {
+                if let DataType::Struct(fields) = field.data_type().to_logical_type() {
+                    DataType::List(Box::new(fields[column].clone()))
+                } else {
+                    unreachable!()
+                }
+            } else {
+                unreachable!()
+            }
+        }
     }
 }
 
@@ -274,16 +331,17 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
             ParquetType::PrimitiveType {
                 physical_type,
                 logical_type,
+                converted_type,
                 ..
-            } => match (physical_type, logical_type) {
-                (PhysicalType::Int96, _) => primitive::iter_to_array(
+            } => match (physical_type, logical_type, converted_type) {
+                (PhysicalType::Int96, _, _) => primitive::iter_to_array(
                     iter,
                     metadata,
                     DataType::Timestamp(TimeUnit::Nanosecond, None),
                     nested,
                     int96_to_i64_ns,
                 ),
-                (_, Some(LogicalType::TIMESTAMP(TimestampType { unit, .. }))) => match unit {
+                (_, Some(LogicalType::TIMESTAMP(TimestampType { unit, .. })), _) => match unit {
                     ParquetTimeUnit::MILLIS(_) => {
                         primitive::iter_to_array(iter, metadata, data_type, nested, |x: i64| {
                             x * 1_000_000
@@ -298,6 +356,15 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
                         primitive::iter_to_array(iter, metadata, data_type, nested, |x: i64| x)
                     }
                 },
+                // files without logical types may still declare the unit in their converted type
+                (_, None, Some(PrimitiveConvertedType::TimestampMillis)) => {
+                    primitive::iter_to_array(iter, metadata, data_type, nested, |x: i64| {
+                        x * 1_000_000
+                    })
+                }
+                (_, None, Some(PrimitiveConvertedType::TimestampMicros)) => {
+                    primitive::iter_to_array(iter, metadata, data_type, nested, |x: i64| x * 1_000)
+                }
                 _ => primitive::iter_to_array(iter, metadata, data_type, nested, |x: i64| x),
             },
             _ => unreachable!(),
@@ -412,26 +479,118 @@ fn finish_array(data_type: DataType, arrays: &mut VecDeque<Box<dyn Array>>) -> B
             }
         }
         Union => todo!(),
-        Map => todo!(),
+        Map => {
+            if let DataType::Map(field, _) = data_type.to_logical_type() {
+                let keys = arrays.pop_front().unwrap();
+                let values = arrays.pop_front().unwrap();
+                let keys = keys.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+                let values = values.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+
+                let entries = StructArray::from_data(
+                    field.data_type().clone(),
+                    vec![keys.values().clone(), values.values().clone()],
+                    None,
+                );
+                Box::new(MapArray::from_data(
+                    data_type,
+                    keys.offsets().clone(),
+                    Arc::new(entries),
+                    keys.validity().cloned(),
+                ))
+            } else {
+                unreachable!()
+            }
+        }
+    }
+}
+
+/// An iterator adapter of the [`CompressedDataPage`]s of a column chunk that drops, without
+/// decompressing them, the leading pages whose rows are all before `skip`, and that stops once
+/// the pages yielded so far contain every row before `end`.
+/// It is only valid for columns whose pages' number of values is their number of rows,
+/// i.e. non-repeated columns.
+struct PageRange<'a, I> {
+    iter: I,
+    skip: usize,
+    end: usize,
+    // the number of rows of all pages consumed so far
+    rows: usize,
+    // the number of rows of all pages dropped so far
+    skipped: &'a mut usize,
+}
+
+impl<'a, I> Iterator for PageRange<'a, I>
+where
+    I: Iterator<Item = std::result::Result<CompressedDataPage, ParquetError>>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.rows >= self.end {
+                return None;
+            }
+            let page = match self.iter.next()? {
+                Ok(page) => page,
+                Err(error) => return Some(Err(error)),
+            };
+            let num_rows = page.num_values();
+            self.rows += num_rows;
+            if self.rows <= self.skip {
+                *self.skipped += num_rows;
+                continue;
+            }
+            return Some(Ok(page));
+        }
     }
 }
 
 /// Returns an [`Array`] built from an iterator of column chunks. It also returns
 /// the two buffers used to decompress and deserialize pages (to be re-used).
+/// # Errors
+/// Errors with [`ArrowError::Deserialization`] naming `field` when its pages can't be deserialized.
 #[allow(clippy::type_complexity)]
 pub fn column_iter_to_array<II, I>(
+    columns: I,
+    field: &Field,
+    buffer: Vec<u8>,
+) -> Result<(Box<dyn Array>, Vec<u8>, Vec<u8>)>
+where
+    II: Iterator<Item = std::result::Result<CompressedDataPage, ParquetError>>,
+    I: ColumnChunkIter<II>,
+{
+    column_iter_to_array_with_limit(columns, field, buffer, 0, None)
+}
+
+/// Returns an [`Array`] with the `limit` rows (or all of them if `None`) after the first `skip`
+/// rows of an iterator of column chunks. It also returns the two buffers used to decompress
+/// and deserialize pages (to be re-used).
+///
+/// For non-repeated columns, the pages whose rows are all before `skip` are neither decompressed
+/// nor decoded, and no page is read past the page containing the last requested row. Rows are
+/// counted over the pages returned by the column chunks, i.e. after any page filter.
+/// # Errors
+/// Errors with [`ArrowError::Deserialization`] naming `field` when its pages can't be deserialized.
+#[allow(clippy::type_complexity)]
+pub fn column_iter_to_array_with_limit<II, I>(
     mut columns: I,
     field: &Field,
     mut buffer: Vec<u8>,
+    skip: usize,
+    limit: Option<usize>,
 ) -> Result<(Box<dyn Array>, Vec<u8>, Vec<u8>)>
 where
     II: Iterator<Item = std::result::Result<CompressedDataPage, ParquetError>>,
     I: ColumnChunkIter<II>,
 {
-    let mut nested_info = vec![];
-    init_nested(field, 0, &mut nested_info);
-
     let data_type = field.data_type().clone();
+    let is_map = matches!(data_type.to_logical_type(), DataType::Map(_, _));
+    let end = limit.map_or(usize::MAX, |limit| skip.saturating_add(limit));
+
+    let mut nested_info = vec![];
+    if !is_map {
+        init_nested(field, 0, &mut nested_info);
+    }
 
     let mut arrays = VecDeque::new();
     let page_buffer;
@@ -440,12 +599,46 @@ where
         match columns.advance()? {
             State::Some(mut new_iter) => {
                 let data_type = column_datatype(&data_type, column);
+                if is_map {
+                    // each column of a map is read as a list of its keys or values
+                    nested_info.clear();
+                    let list = Field::new(field.name(), data_type.clone(), field.is_nullable());
+                    init_nested(&list, 0, &mut nested_info);
+                }
                 if let Some((pages, metadata)) = new_iter.get() {
+                    // the number of values of a page is only its number of rows when the column
+                    // is not repeated. Null columns are not read from their pages.
+                    let is_flat = metadata.descriptor().max_rep_level() == 0
+                        && !matches!(data_type.to_logical_type(), DataType::Null);
+                    let (page_skip, page_end) = if is_flat {
+                        (skip, end)
+                    } else {
+                        (0, usize::MAX)
+                    };
+
+                    let mut skipped = 0;
+                    let pages = PageRange {
+                        iter: pages,
+                        skip: page_skip,
+                        end: page_end,
+                        rows: 0,
+                        skipped: &mut skipped,
+                    };
                     let mut iterator = BasicDecompressor::new(pages, buffer);
 
                     let array =
-                        page_iter_to_array(&mut iterator, &mut nested_info, metadata, data_type)?;
+                        page_iter_to_array(&mut iterator, &mut nested_info, metadata, data_type)
+                            .map_err(|error| error.context(field.name(), None))?;
                     buffer = iterator.into_inner();
+
+                    // the boundaries may be within a page: slice to the exact rows
+                    let offset = (skip - skipped).min(array.len());
+                    let length = (array.len() - offset).min(end - skip);
+                    let array = if offset == 0 && length == array.len() {
+                        array
+                    } else {
+                        array.slice(offset, length)
+                    };
                     arrays.push_back(array)
                 }
                 column += 1;
@@ -0,0 +1,71 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use parquet_format_async_temp::thrift::protocol::TCompactInputProtocol;
+
+use crate::error::{ArrowError, Result};
+
+use super::{ColumnChunkMetaData, ColumnIndex, OffsetIndex, ParquetError};
+
+/// Reads the [`ColumnIndex`] of a column chunk, or returns `None` if the column chunk has none.
+/// # Example
+/// ```no_run
+/// use std::fs::File;
+/// use arrow2::error::Result;
+/// use arrow2::io::parquet::read::{read_column_index, read_metadata};
+/// # fn main() -> Result<()> {
+/// let mut reader = File::open("data.parquet")?;
+/// let metadata = read_metadata(&mut reader)?;
+/// if let Some(index) = read_column_index(metadata.row_groups[0].column(0), &mut reader)? {
+///     // the pages of the first column chunk that only contain nulls
+///     let null_pages = index.null_pages.iter().filter(|is_null| **is_null).count();
+/// }
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the column index can't be read or is not valid.
+pub fn read_column_index<R: Read + Seek>(
+    column: &ColumnChunkMetaData,
+    reader: &mut R,
+) -> Result<Option<ColumnIndex>> {
+    let column_chunk = column.clone().into_thrift();
+    if !seek_index(column_chunk.column_index_offset, reader)? {
+        return Ok(None);
+    }
+    let mut protocol = TCompactInputProtocol::new(reader);
+    let index = parquet_format_async_temp::ColumnIndex::read_from_in_protocol(&mut protocol)
+        .map_err(|e| ArrowError::from(ParquetError::from(e)))?;
+    ColumnIndex::try_from_thrift(index).map(Some)
+}
+
+/// Reads the [`OffsetIndex`] of a column chunk, i.e. the location of each of its data pages and
+/// the index of its first row, or returns `None` if the column chunk has none.
+/// # Errors
+/// Errors iff the offset index can't be read or is not valid.
+pub fn read_offset_index<R: Read + Seek>(
+    column: &ColumnChunkMetaData,
+    reader: &mut R,
+) -> Result<Option<OffsetIndex>> {
+    let column_chunk = column.clone().into_thrift();
+    if !seek_index(column_chunk.offset_index_offset, reader)? {
+        return Ok(None);
+    }
+    let mut protocol = TCompactInputProtocol::new(reader);
+    let index = parquet_format_async_temp::OffsetIndex::read_from_in_protocol(&mut protocol)
+        .map_err(|e| ArrowError::from(ParquetError::from(e)))?;
+    OffsetIndex::try_from_thrift(index).map(Some)
+}
+
+/// Seeks to the start of an index, returning whether the index exists.
+fn seek_index<R: Seek>(offset: Option<i64>, reader: &mut R) -> Result<bool> {
+    match offset {
+        Some(offset) if offset < 0 => Err(ArrowError::OutOfSpec(
+            "The offset of a page index must be non-negative".to_string(),
+        )),
+        Some(offset) => {
+            reader.seek(SeekFrom::Start(offset as u64))?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
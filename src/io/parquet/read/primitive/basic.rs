@@ -1,15 +1,16 @@
 use parquet2::{
     encoding::{hybrid_rle, Encoding},
     page::{DataPage, PrimitivePageDict},
+    schema::types::PhysicalType,
     types::NativeType,
 };
 
 use super::super::utils as other_utils;
-use super::utils::ExactChunksIter;
+use super::utils::{decode_delta, ByteStreamSplitIter, ExactChunksIter};
 use super::ColumnDescriptor;
 use crate::{
     bitmap::{utils::BitmapIter, MutableBitmap},
-    error::Result,
+    error::{ArrowError, Result},
     types::NativeType as ArrowNativeType,
 };
 
@@ -97,9 +98,9 @@ fn read_dict_buffer_required<T, A, F>(
     validity.extend_constant(additional, true);
 }
 
-fn read_nullable<T, A, F>(
+fn read_nullable<T, A, I, F>(
     validity_buffer: &[u8],
-    values_buffer: &[u8],
+    mut chunks: I,
     additional: usize,
     values: &mut Vec<A>,
     validity: &mut MutableBitmap,
@@ -107,10 +108,10 @@ fn read_nullable<T, A, F>(
 ) where
     T: NativeType,
     A: ArrowNativeType,
+    I: Iterator<Item = T>,
     F: Fn(T) -> A,
 {
     let length = additional + values.len();
-    let mut chunks = ExactChunksIter::<T>::new(values_buffer);
 
     let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
 
@@ -146,18 +147,22 @@ fn read_nullable<T, A, F>(
     }
 }
 
-fn read_required<T, A, F>(values_buffer: &[u8], additional: usize, values: &mut Vec<A>, op: F)
+fn read_required<T, A, I, F>(iterator: I, additional: usize, values: &mut Vec<A>, op: F)
 where
     T: NativeType,
     A: ArrowNativeType,
+    I: Iterator<Item = T>,
     F: Fn(T) -> A,
 {
-    assert_eq!(values_buffer.len(), additional * std::mem::size_of::<T>());
-    let iterator = ExactChunksIter::<T>::new(values_buffer);
-
-    let iterator = iterator.map(op);
+    let length = values.len();
+    values.extend(iterator.map(op));
+    assert_eq!(values.len() - length, additional);
+}
 
-    values.extend(iterator);
+/// Whether `T` is a physical type that can be encoded with `DELTA_BINARY_PACKED`
+#[inline]
+pub(super) fn is_integer<T: NativeType>() -> bool {
+    matches!(T::TYPE, PhysicalType::Int32 | PhysicalType::Int64)
 }
 
 pub fn extend_from_page<T, A, F>(
@@ -205,13 +210,51 @@ where
         // it falled back.
         (Encoding::Plain, _, true) => read_nullable(
             validity_buffer,
-            values_buffer,
+            ExactChunksIter::<T>::new(values_buffer),
+            additional,
+            values,
+            validity,
+            op,
+        ),
+        (Encoding::Plain, _, false) => read_required(
+            ExactChunksIter::<T>::new(page.buffer()),
+            additional,
+            values,
+            op,
+        ),
+        (Encoding::ByteStreamSplit, _, true) => read_nullable(
+            validity_buffer,
+            ByteStreamSplitIter::<T>::try_new(values_buffer)?,
             additional,
             values,
             validity,
             op,
         ),
-        (Encoding::Plain, _, false) => read_required(page.buffer(), additional, values, op),
+        (Encoding::ByteStreamSplit, _, false) => read_required(
+            ByteStreamSplitIter::<T>::try_new(values_buffer)?,
+            additional,
+            values,
+            op,
+        ),
+        (Encoding::DeltaBinaryPacked, _, true) if is_integer::<T>() => read_nullable(
+            validity_buffer,
+            decode_delta::<T>(values_buffer, additional)?.into_iter(),
+            additional,
+            values,
+            validity,
+            op,
+        ),
+        (Encoding::DeltaBinaryPacked, _, false) if is_integer::<T>() => {
+            let deltas = decode_delta::<T>(values_buffer, additional)?;
+            if deltas.len() != additional {
+                return Err(ArrowError::OutOfSpec(format!(
+                    "A page with {} values has {} DELTA_BINARY_PACKED values",
+                    additional,
+                    deltas.len()
+                )));
+            }
+            read_required(deltas.into_iter(), additional, values, op)
+        }
         _ => {
             return Err(other_utils::not_implemented(
                 &page.encoding(),
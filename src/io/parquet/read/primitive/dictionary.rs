@@ -1,85 +1,31 @@
 use std::sync::Arc;
 
 use parquet2::{
-    encoding::{hybrid_rle, Encoding},
+    encoding::Encoding,
     page::{DataPage, PrimitivePageDict},
     types::NativeType,
     FallibleStreamingIterator,
 };
 
+use super::super::dictionary::{
+    read_dict_optional, read_dict_required, read_plain_optional, read_plain_required,
+};
 use super::super::utils;
+use super::utils::ExactChunksIter;
 use super::{ColumnChunkMetaData, ColumnDescriptor};
 use crate::{
     array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
-    bitmap::{utils::BitmapIter, MutableBitmap},
+    bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
     types::NativeType as ArrowNativeType,
 };
 
 #[allow(clippy::too_many_arguments)]
-fn read_dict_optional<K, T, A, F>(
-    validity_buffer: &[u8],
-    indices_buffer: &[u8],
-    additional: usize,
-    dict: &PrimitivePageDict<T>,
-    indices: &mut Vec<K>,
-    values: &mut Vec<A>,
-    validity: &mut MutableBitmap,
-    op: F,
-) where
-    K: DictionaryKey,
-    T: NativeType,
-    A: ArrowNativeType,
-    F: Fn(T) -> A,
-{
-    let dict_values = dict.values();
-    values.extend(dict_values.iter().map(|x| op(*x)));
-
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
-
-    let mut new_indices =
-        hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
-
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
-
-    for run in validity_iterator {
-        match run {
-            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                let remaining = additional - indices.len();
-                let len = std::cmp::min(packed.len() * 8, remaining);
-                for is_valid in BitmapIter::new(packed, 0, len) {
-                    let value = if is_valid {
-                        K::from_u32(new_indices.next().unwrap()).unwrap()
-                    } else {
-                        K::default()
-                    };
-                    indices.push(value);
-                }
-                validity.extend_from_slice(packed, 0, len);
-            }
-            hybrid_rle::HybridEncoded::Rle(value, additional) => {
-                let is_set = value[0] == 1;
-                validity.extend_constant(additional, is_set);
-                if is_set {
-                    (0..additional).for_each(|_| {
-                        let index = K::from_u32(new_indices.next().unwrap()).unwrap();
-                        indices.push(index)
-                    })
-                } else {
-                    values.resize(values.len() + additional, A::default());
-                }
-            }
-        }
-    }
-}
-
 fn extend_from_page<K, T, A, F>(
     page: &DataPage,
     descriptor: &ColumnDescriptor,
+    first_key: &mut Option<usize>,
     indices: &mut Vec<K>,
     values: &mut Vec<A>,
     validity: &mut MutableBitmap,
@@ -99,31 +45,64 @@ where
     let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor);
 
     match (&page.encoding(), page.dictionary_page(), is_optional) {
-        (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), true) => {
-            read_dict_optional(
-                validity_buffer,
-                values_buffer,
-                additional,
-                dict.as_any().downcast_ref().unwrap(),
-                indices,
-                values,
-                validity,
-                op,
-            )
-        }
-        _ => {
-            return Err(utils::not_implemented(
-                &page.encoding(),
-                is_optional,
-                page.dictionary_page().is_some(),
-                version,
-                "primitive",
-            ))
+        (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), _) => {
+            // a column chunk has at most one dictionary page, shared by all its data pages
+            let first_key = match first_key {
+                Some(first_key) => *first_key,
+                None => {
+                    let key = values.len();
+                    let dict = dict
+                        .as_any()
+                        .downcast_ref::<PrimitivePageDict<T>>()
+                        .unwrap();
+                    values.extend(dict.values().iter().map(|x| op(*x)));
+                    *first_key = Some(key);
+                    key
+                }
+            };
+            if is_optional {
+                read_dict_optional(
+                    validity_buffer,
+                    values_buffer,
+                    additional,
+                    first_key,
+                    indices,
+                    validity,
+                )
+            } else {
+                read_dict_required(values_buffer, additional, first_key, indices, validity)
+            }
         }
+        // writers fall back to plain encoding when the dictionary grows too large: its values are
+        // appended to the dictionary's values
+        (Encoding::Plain, _, true) => read_plain_optional(
+            validity_buffer,
+            ExactChunksIter::<T>::new(values_buffer).map(op),
+            additional,
+            indices,
+            values,
+            validity,
+        ),
+        (Encoding::Plain, _, false) => read_plain_required(
+            ExactChunksIter::<T>::new(values_buffer).map(op),
+            additional,
+            indices,
+            values,
+            validity,
+        ),
+        _ => Err(utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "primitive",
+        )),
     }
-    Ok(())
 }
 
+/// Converts the pages of a primitive column chunk into a [`DictionaryArray`] whose values are
+/// the (parquet) dictionary of the chunk, without unpacking it.
+/// Values of plain-encoded pages are appended to the values.
 pub fn iter_to_array<K, T, A, I, E, F>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
@@ -140,12 +119,14 @@ where
 {
     let capacity = metadata.num_values() as usize;
     let mut indices = Vec::<K>::with_capacity(capacity);
-    let mut values = Vec::<A>::with_capacity(capacity);
+    let mut values = Vec::<A>::new();
     let mut validity = MutableBitmap::with_capacity(capacity);
+    let mut first_key = None;
     while let Some(page) = iter.next()? {
         extend_from_page(
             page,
             metadata.descriptor(),
+            &mut first_key,
             &mut indices,
             &mut values,
             &mut validity,
@@ -154,7 +135,10 @@ where
     }
 
     let keys = PrimitiveArray::from_data(K::PRIMITIVE.into(), indices.into(), validity.into());
+    let is_ordered = DictionaryArray::<K>::get_is_ordered(&data_type);
     let data_type = DictionaryArray::<K>::get_child(&data_type).clone();
     let values = Arc::new(PrimitiveArray::from_data(data_type, values.into(), None));
-    Ok(Box::new(DictionaryArray::<K>::from_data(keys, values)))
+    Ok(Box::new(
+        DictionaryArray::<K>::from_data(keys, values).with_is_ordered(is_ordered),
+    ))
 }
@@ -7,11 +7,13 @@ use parquet2::{
 
 use super::super::nested_utils::extend_offsets;
 use super::ColumnDescriptor;
-use super::{super::utils, utils::ExactChunksIter, Nested};
-use crate::{
-    bitmap::MutableBitmap, error::Result, trusted_len::TrustedLen,
-    types::NativeType as ArrowNativeType,
+use super::{
+    super::utils,
+    basic::is_integer,
+    utils::{decode_delta, ByteStreamSplitIter, ExactChunksIter},
+    Nested,
 };
+use crate::{bitmap::MutableBitmap, error::Result, types::NativeType as ArrowNativeType};
 
 fn read_values<T, D, G, F, A>(
     def_levels: D,
@@ -41,7 +43,7 @@ fn read_values<T, D, G, F, A>(
 fn read_values_required<T, G, F, A>(new_values: G, op: F, values: &mut Vec<A>)
 where
     T: NativeType,
-    G: TrustedLen<Item = T>,
+    G: Iterator<Item = T>,
     A: ArrowNativeType,
     F: Fn(T) -> A,
 {
@@ -49,10 +51,10 @@ where
 }
 
 #[allow(clippy::too_many_arguments)]
-fn read<T, A, F>(
+fn read<T, A, I, F>(
     rep_levels: &[u8],
     def_levels: &[u8],
-    values_buffer: &[u8],
+    new_values: I,
     additional: usize,
     rep_level_encoding: (&Encoding, i16),
     def_level_encoding: (&Encoding, i16),
@@ -64,10 +66,9 @@ fn read<T, A, F>(
 ) where
     T: NativeType,
     A: ArrowNativeType,
+    I: Iterator<Item = T>,
     F: Fn(T) -> A,
 {
-    let new_values = ExactChunksIter::<T>::new(values_buffer);
-
     let max_rep_level = rep_level_encoding.1 as u32;
     let max_def_level = def_level_encoding.1 as u32;
 
@@ -120,20 +121,49 @@ where
 
     let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor);
 
+    let rep_level_encoding = (
+        &page.repetition_level_encoding(),
+        descriptor.max_rep_level(),
+    );
+    let def_level_encoding = (
+        &page.definition_level_encoding(),
+        descriptor.max_def_level(),
+    );
+
     match (&page.encoding(), page.dictionary_page()) {
         (Encoding::Plain, None) => read(
             rep_levels,
             def_levels,
-            values_buffer,
+            ExactChunksIter::<T>::new(values_buffer),
+            additional,
+            rep_level_encoding,
+            def_level_encoding,
+            is_nullable,
+            nested,
+            values,
+            validity,
+            op,
+        ),
+        (Encoding::ByteStreamSplit, _) => read(
+            rep_levels,
+            def_levels,
+            ByteStreamSplitIter::<T>::try_new(values_buffer)?,
+            additional,
+            rep_level_encoding,
+            def_level_encoding,
+            is_nullable,
+            nested,
+            values,
+            validity,
+            op,
+        ),
+        (Encoding::DeltaBinaryPacked, _) if is_integer::<T>() => read(
+            rep_levels,
+            def_levels,
+            decode_delta::<T>(values_buffer, additional)?.into_iter(),
             additional,
-            (
-                &page.repetition_level_encoding(),
-                descriptor.max_rep_level(),
-            ),
-            (
-                &page.definition_level_encoding(),
-                descriptor.max_def_level(),
-            ),
+            rep_level_encoding,
+            def_level_encoding,
             is_nullable,
             nested,
             values,
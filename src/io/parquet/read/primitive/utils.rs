@@ -4,6 +4,10 @@ use std::{convert::TryInto, hint::unreachable_unchecked};
 
 use parquet2::types::NativeType;
 
+use crate::error::{ArrowError, Result};
+
+use super::super::delta_bitpacked;
+
 pub struct ExactChunksIter<'a, T: NativeType> {
     chunks: std::slice::ChunksExact<'a, u8>,
     phantom: std::marker::PhantomData<T>,
@@ -42,3 +46,89 @@ impl<'a, T: NativeType> Iterator for ExactChunksIter<'a, T> {
 }
 
 unsafe impl<'a, T: NativeType> TrustedLen for ExactChunksIter<'a, T> {}
+
+/// An iterator over the values of a `BYTE_STREAM_SPLIT`-encoded buffer, whose byte `j` of
+/// value `i` is at position `j * n + i`, for `n` values.
+pub struct ByteStreamSplitIter<'a, T: NativeType> {
+    values: &'a [u8],
+    index: usize,
+    length: usize,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<'a, T: NativeType> ByteStreamSplitIter<'a, T> {
+    /// # Errors
+    /// Errors iff the length of `values` is not a multiple of the size of `T`.
+    #[inline]
+    pub fn try_new(values: &'a [u8]) -> Result<Self> {
+        let size = std::mem::size_of::<T>();
+        if values.len() % size != 0 {
+            return Err(ArrowError::OutOfSpec(format!(
+                "BYTE_STREAM_SPLIT values of {} bytes must have a multiple of {} bytes, but they have {}",
+                size,
+                size,
+                values.len()
+            )));
+        }
+        Ok(Self {
+            values,
+            index: 0,
+            length: values.len() / size,
+            phantom: std::marker::PhantomData,
+        })
+    }
+}
+
+impl<'a, T: NativeType> Iterator for ByteStreamSplitIter<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.length {
+            return None;
+        }
+        let size = std::mem::size_of::<T>();
+        // physical types are at most 12 bytes long (int96)
+        let mut bytes = [0u8; 16];
+        bytes
+            .iter_mut()
+            .take(size)
+            .enumerate()
+            .for_each(|(j, byte)| *byte = self.values[j * self.length + self.index]);
+        self.index += 1;
+
+        let bytes: <T as NativeType>::Bytes = match bytes[..size].try_into() {
+            Ok(v) => v,
+            Err(_) => unreachable!(),
+        };
+        Some(T::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.length - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+/// Decodes a `DELTA_BINARY_PACKED`-encoded buffer of at most `max_values` values into values of
+/// `T`, whose physical type must be `INT32` or `INT64`.
+/// # Errors
+/// Errors iff the buffer is not valid.
+pub fn decode_delta<T: NativeType>(values: &[u8], max_values: usize) -> Result<Vec<T>> {
+    let max_bit_width = (std::mem::size_of::<T>() * 8) as u8;
+    let (values, _) = delta_bitpacked::decode(values, max_bit_width, max_values)?;
+    Ok(values.into_iter().map(from_delta).collect())
+}
+
+/// Converts a value decoded from `DELTA_BINARY_PACKED` to `T`, whose physical type must be
+/// `INT32` or `INT64`.
+#[inline]
+fn from_delta<T: NativeType>(value: i64) -> T {
+    let bytes = value.to_le_bytes();
+    let bytes: <T as NativeType>::Bytes = match bytes[..std::mem::size_of::<T>()].try_into() {
+        Ok(v) => v,
+        Err(_) => unreachable!(),
+    };
+    T::from_le_bytes(bytes)
+}
@@ -9,9 +9,11 @@ use crate::{
     record_batch::RecordBatch,
 };
 
+use parquet2::read::get_field_columns;
+
 use super::{
-    column_iter_to_array, get_column_iterator, get_schema, read_metadata, FileMetaData, PageFilter,
-    RowGroupMetaData,
+    column_iter_to_array_with_limit, get_column_iterator, get_schema, read_metadata,
+    schema::dictionary_schema, verify_page_crcs, FileMetaData, PageFilter, RowGroupMetaData,
 };
 
 type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool>;
@@ -25,6 +27,7 @@ pub struct RecordReader<R: Read + Seek> {
     decompress_buffer: Vec<u8>,
     groups_filter: Option<GroupFilter>,
     pages_filter: Option<PageFilter>,
+    verify_page_crcs: bool,
     metadata: FileMetaData,
     current_group: usize,
     remaining_rows: usize,
@@ -82,6 +85,7 @@ impl<R: Read + Seek> RecordReader<R> {
             indices,
             groups_filter,
             pages_filter,
+            verify_page_crcs: false,
             metadata,
             current_group: 0,
             buffer: vec![],
@@ -104,6 +108,24 @@ impl<R: Read + Seek> RecordReader<R> {
     pub fn set_groups_filter(&mut self, groups_filter: GroupFilter) {
         self.groups_filter = Some(groups_filter);
     }
+
+    /// Sets whether the CRC-32 checksums of the pages are verified before the pages of a column
+    /// chunk are read, in which case reading errors on the first page whose checksum does not
+    /// match its content. Pages without a checksum are not verified. Defaults to `false`.
+    pub fn set_verify_page_crcs(&mut self, verify: bool) {
+        self.verify_page_crcs = verify;
+    }
+
+    /// Reads the (large) utf8 and (large) binary fields in `columns` (indices of
+    /// [`RecordReader::schema`]), or all of them if `None`, as
+    /// [`DictionaryArray`](crate::array::DictionaryArray)s with `i32` keys, re-using the
+    /// dictionary of each column chunk instead of unpacking it. [`RecordReader::schema`] is
+    /// updated accordingly.
+    /// Keys are only consistent within a row group: each batch has its own dictionary.
+    pub fn set_read_as_dictionary(&mut self, columns: Option<&[usize]>) {
+        let schema = self.schema.as_ref().clone();
+        self.schema = Arc::new(dictionary_schema(schema, columns));
+    }
 }
 
 impl<R: Read + Seek> Iterator for RecordReader<R> {
@@ -141,6 +163,12 @@ impl<R: Read + Seek> Iterator for RecordReader<R> {
             (b1, b2, Vec::with_capacity(schema.fields().len())),
             |(b1, b2, mut columns), (field_index, field)| {
                 let field_index = self.indices[field_index]; // project into the original schema
+                if self.verify_page_crcs {
+                    let parquet_field = &self.metadata.schema().fields()[field_index];
+                    for column in get_field_columns(&self.metadata, row_group, parquet_field) {
+                        verify_page_crcs(column, &mut self.reader)?;
+                    }
+                }
                 let column_iter = get_column_iterator(
                     &mut self.reader,
                     &self.metadata,
@@ -150,13 +178,13 @@ impl<R: Read + Seek> Iterator for RecordReader<R> {
                     b1,
                 );
 
-                let (array, b1, b2) = column_iter_to_array(column_iter, field, b2)?;
-
-                let array = if array.len() > remaining_rows {
-                    array.slice(0, remaining_rows)
-                } else {
-                    array
-                };
+                let (array, b1, b2) = column_iter_to_array_with_limit(
+                    column_iter,
+                    field,
+                    b2,
+                    0,
+                    Some(remaining_rows),
+                )?;
 
                 columns.push(array.into());
                 Result::Ok((b1, b2, columns))
@@ -276,6 +276,8 @@ fn to_group_type_inner(
     match (logical_type, converted_type) {
         (Some(LogicalType::LIST(_)), _) => to_list(fields, parent_name),
         (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name),
+        (Some(LogicalType::MAP(_)), _) => to_map(fields),
+        (None, Some(GroupConvertedType::Map | GroupConvertedType::MapKeyValue)) => to_map(fields),
         _ => to_struct(fields),
     }
 }
@@ -296,6 +298,29 @@ fn to_struct(fields: &[ParquetType]) -> Result<Option<DataType>> {
         })
 }
 
+/// Converts a parquet map to an arrow map.
+///
+/// A parquet map is a group with a single repeated group (normally named "key_value")
+/// of two fields, the keys and the values, which are mapped to the
+/// `Struct` of a [`DataType::Map`].
+fn to_map(fields: &[ParquetType]) -> Result<Option<DataType>> {
+    let inner = match fields {
+        [inner @ ParquetType::GroupType { fields, .. }] if fields.len() == 2 => inner,
+        _ => {
+            return Err(ArrowError::ExternalFormat(
+                "A parquet MAP must contain a single repeated group with 2 fields".to_string(),
+            ))
+        }
+    };
+    let entries = if let ParquetType::GroupType { fields, .. } = inner {
+        to_struct(fields)?
+    } else {
+        unreachable!()
+    };
+    Ok(entries
+        .map(|entries| DataType::Map(Box::new(Field::new(inner.name(), entries, false)), false)))
+}
+
 /// Entry point for converting parquet group type.
 ///
 /// This function takes care of logical type and repetition.
@@ -840,6 +865,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_map_schema() -> Result<()> {
+        let entries = DataType::Struct(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ]);
+        let arrow_fields = vec![Field::new(
+            "map1",
+            DataType::Map(Box::new(Field::new("key_value", entries, false)), false),
+            true,
+        )];
+
+        let message_type = "
+        message test_schema {
+          OPTIONAL GROUP map1 (MAP) {
+            REPEATED GROUP key_value {
+              REQUIRED BINARY key (UTF8);
+              OPTIONAL INT32 value;
+            }
+          }
+        }
+        ";
+
+        let parquet_schema = SchemaDescriptor::try_from_message(message_type)?;
+        let converted_arrow_schema = parquet_to_arrow_schema(&parquet_schema, &None)?;
+
+        assert_eq!(converted_arrow_schema.fields(), &arrow_fields);
+        Ok(())
+    }
+
     #[test]
     fn test_repeated_nested_schema() -> Result<()> {
         let mut arrow_fields = Vec::new();
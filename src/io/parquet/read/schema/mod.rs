@@ -1,5 +1,5 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use crate::datatypes::Schema;
+use crate::datatypes::{DataType, IntegerType, Schema};
 use crate::error::Result;
 
 mod convert;
@@ -22,6 +22,28 @@ pub fn get_schema(metadata: &FileMetaData) -> Result<Schema> {
     })
 }
 
+/// Returns `schema` with its (large) utf8 and (large) binary fields in `columns`, or all of them
+/// if `None`, converted to dictionaries with `i32` keys. Reading a column with such a field
+/// returns a [`DictionaryArray`](crate::array::DictionaryArray) whose values are built once from
+/// the dictionary page of each column chunk, instead of unpacking them to every row.
+pub fn dictionary_schema(mut schema: Schema, columns: Option<&[usize]>) -> Schema {
+    schema
+        .fields
+        .iter_mut()
+        .enumerate()
+        .filter(|(index, _)| columns.map(|x| x.contains(index)).unwrap_or(true))
+        .for_each(|(_, field)| {
+            if matches!(
+                field.data_type,
+                DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary
+            ) {
+                let values = std::mem::replace(&mut field.data_type, DataType::Null);
+                field.data_type = DataType::Dictionary(IntegerType::Int32, Box::new(values), false);
+            }
+        });
+    schema
+}
+
 pub(crate) fn is_type_nullable(type_: &ParquetType) -> bool {
     is_nullable(type_.get_basic_info())
 }
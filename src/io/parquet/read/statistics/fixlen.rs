@@ -1,4 +1,4 @@
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 
 use super::super::schema;
 use super::primitive::PrimitiveStatistics;
@@ -45,6 +45,17 @@ impl From<&ParquetFixedLenStatistics> for FixedLenStatistics {
     }
 }
 
+/// Returns the `i128` of a big-endian two's complement of `size` bytes, sign-extending it.
+fn decimal_from_be_bytes(value: &[u8], size: usize) -> Option<i128> {
+    if value.len() != size {
+        return None;
+    }
+    let negative = value.first().map(|x| x >> 7 == 1).unwrap_or(false);
+    let mut bytes = if negative { [!0u8; 16] } else { [0u8; 16] };
+    bytes[16 - size..].copy_from_slice(value);
+    Some(i128::from_be_bytes(bytes))
+}
+
 impl TryFrom<(&ParquetFixedLenStatistics, DataType)> for PrimitiveStatistics<i128> {
     type Error = ArrowError;
     fn try_from((stats, data_type): (&ParquetFixedLenStatistics, DataType)) -> Result<Self> {
@@ -58,22 +69,15 @@ impl TryFrom<(&ParquetFixedLenStatistics, DataType)> for PrimitiveStatistics<i12
                 byte_lens
             )))
         } else {
-            let paddings = (0..(16 - byte_lens)).map(|_| 0u8).collect::<Vec<_>>();
-            let max_value = stats.max_value.as_ref().and_then(|value| {
-                [paddings.as_slice(), value]
-                    .concat()
-                    .try_into()
-                    .map(i128::from_be_bytes)
-                    .ok()
-            });
-
-            let min_value = stats.min_value.as_ref().and_then(|value| {
-                [paddings.as_slice(), value]
-                    .concat()
-                    .try_into()
-                    .map(i128::from_be_bytes)
-                    .ok()
-            });
+            let byte_lens = byte_lens as usize;
+            let max_value = stats
+                .max_value
+                .as_ref()
+                .and_then(|value| decimal_from_be_bytes(value, byte_lens));
+            let min_value = stats
+                .min_value
+                .as_ref()
+                .and_then(|value| decimal_from_be_bytes(value, byte_lens));
             Ok(Self {
                 data_type,
                 null_count: stats.null_count,
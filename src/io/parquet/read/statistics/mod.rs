@@ -1,6 +1,5 @@
 //! APIs exposing `parquet2`'s statistics as arrow's statistics.
 use crate::datatypes::DataType;
-use crate::error::ArrowError;
 use parquet2::schema::types::PhysicalType;
 use parquet2::statistics::PrimitiveStatistics as ParquetPrimitiveStatistics;
 use parquet2::statistics::Statistics as ParquetStatistics;
@@ -15,13 +14,35 @@ mod boolean;
 pub use boolean::*;
 mod fixlen;
 pub use fixlen::*;
+mod prune;
+pub use prune::*;
 
 /// Trait representing a deserialized parquet statistics into arrow.
-pub trait Statistics: std::fmt::Debug {
+pub trait Statistics: std::fmt::Debug + private::AsAny {
     /// returns the [`DataType`] of the statistics.
     fn data_type(&self) -> &DataType;
 }
 
+impl dyn Statistics + '_ {
+    /// Returns `self` as [`std::any::Any`] to downcast it to a concrete type.
+    pub fn as_any(&self) -> &dyn std::any::Any {
+        self.to_any()
+    }
+}
+
+mod private {
+    // implemented for every type, so that implementing `Statistics` does not require it
+    pub trait AsAny {
+        fn to_any(&self) -> &dyn std::any::Any;
+    }
+
+    impl<T: std::any::Any> AsAny for T {
+        fn to_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+}
+
 impl PartialEq for &dyn Statistics {
     fn eq(&self, other: &Self) -> bool {
         self.data_type() == other.data_type()
@@ -76,8 +97,12 @@ pub fn deserialize_statistics(stats: &dyn ParquetStatistics) -> Result<Box<dyn S
             let stats = stats.as_any().downcast_ref().unwrap();
             fixlen::statistics_from_fix_len(stats, stats.descriptor.type_())
         }
-        _ => Err(ArrowError::NotYetImplemented(
-            "Reading Fixed-len array statistics is not yet supported".to_string(),
-        )),
+        PhysicalType::Int96 => {
+            let stats = stats
+                .as_any()
+                .downcast_ref::<ParquetPrimitiveStatistics<[u32; 3]>>()
+                .unwrap();
+            Ok(primitive::statistics_from_int96(stats))
+        }
     }
 }
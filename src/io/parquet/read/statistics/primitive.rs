@@ -1,7 +1,10 @@
-use crate::{datatypes::DataType, types::NativeType};
+use crate::{
+    datatypes::{DataType, TimeUnit},
+    types::NativeType,
+};
 use parquet2::schema::types::ParquetType;
 use parquet2::statistics::PrimitiveStatistics as ParquetPrimitiveStatistics;
-use parquet2::types::NativeType as ParquetNativeType;
+use parquet2::types::{int96_to_i64_ns, NativeType as ParquetNativeType};
 
 use super::super::schema;
 use super::Statistics;
@@ -74,3 +77,17 @@ pub(super) fn statistics_from_i64(
         _ => Box::new(PrimitiveStatistics::<i64>::from((stats, data_type))),
     })
 }
+
+/// Deserializes statistics of parquet's (deprecated) `INT96` timestamps into statistics of
+/// `Timestamp(Nanosecond, None)`, the type these columns are read as.
+pub(super) fn statistics_from_int96(
+    stats: &ParquetPrimitiveStatistics<[u32; 3]>,
+) -> Box<dyn Statistics> {
+    Box::new(PrimitiveStatistics::<i64> {
+        data_type: DataType::Timestamp(TimeUnit::Nanosecond, None),
+        null_count: stats.null_count,
+        distinct_count: stats.distinct_count,
+        min_value: stats.min_value.map(int96_to_i64_ns),
+        max_value: stats.max_value.map(int96_to_i64_ns),
+    })
+}
@@ -0,0 +1,185 @@
+use std::cmp::Ordering;
+
+use parquet2::metadata::RowGroupMetaData;
+
+use super::{deserialize_statistics, PrimitiveStatistics, Statistics};
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::scalar::{PrimitiveScalar, Scalar};
+use crate::types::NativeType;
+
+/// A comparison between the values of a column (on the left) and a scalar (on the right).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    /// `column = scalar`
+    Eq,
+    /// `column < scalar`
+    Lt,
+    /// `column <= scalar`
+    LtEq,
+    /// `column > scalar`
+    Gt,
+    /// `column >= scalar`
+    GtEq,
+}
+
+/// Compares two decimals of (possibly) different scales.
+fn cmp_decimals(lhs: i128, lhs_scale: usize, rhs: i128, rhs_scale: usize) -> Ordering {
+    if lhs_scale < rhs_scale {
+        return cmp_decimals(rhs, rhs_scale, lhs, lhs_scale).reverse();
+    }
+    let rescaled = 10i128
+        .checked_pow((lhs_scale - rhs_scale) as u32)
+        .and_then(|factor| rhs.checked_mul(factor));
+    match rescaled {
+        Some(rhs) => lhs.cmp(&rhs),
+        // `|rhs| * 10^(lhs_scale - rhs_scale)` exceeds `i128`, and thus `|lhs|`
+        None if rhs > 0 => Ordering::Less,
+        None => Ordering::Greater,
+    }
+}
+
+/// Returns whether a column whose values are within `[min, max]` may contain a value
+/// satisfying `comparison`, where `cmp` compares a value of the column with the scalar.
+/// Unknown bounds or orderings may satisfy any comparison.
+fn might_satisfy_bounds<T, F: Fn(T) -> Option<Ordering>>(
+    min: Option<T>,
+    max: Option<T>,
+    comparison: Comparison,
+    cmp: F,
+) -> bool {
+    let min = min.and_then(&cmp);
+    let max = max.and_then(&cmp);
+    match comparison {
+        Comparison::Eq => min != Some(Ordering::Greater) && max != Some(Ordering::Less),
+        Comparison::Lt => !matches!(min, Some(Ordering::Greater) | Some(Ordering::Equal)),
+        Comparison::LtEq => min != Some(Ordering::Greater),
+        Comparison::Gt => !matches!(max, Some(Ordering::Less) | Some(Ordering::Equal)),
+        Comparison::GtEq => max != Some(Ordering::Less),
+    }
+}
+
+fn might_satisfy_primitive<T: NativeType + PartialOrd>(
+    statistics: &dyn Statistics,
+    comparison: Comparison,
+    scalar: &dyn Scalar,
+) -> bool {
+    let statistics = statistics
+        .as_any()
+        .downcast_ref::<PrimitiveStatistics<T>>()
+        .unwrap();
+    let scalar = scalar
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<T>>()
+        .unwrap();
+    match scalar.value() {
+        Some(value) => might_satisfy_bounds(
+            statistics.min_value,
+            statistics.max_value,
+            comparison,
+            |x| x.partial_cmp(&value),
+        ),
+        None => false,
+    }
+}
+
+/// Returns whether a column whose statistics are `statistics` may contain values that satisfy
+/// `comparison` against `scalar`. A row group for which this is `false` can be skipped when
+/// reading (see [`RecordReader::set_groups_filter`](super::super::RecordReader::set_groups_filter)).
+///
+/// Decimals are compared by value, also when `scalar` has a different scale than the column.
+/// A null `scalar` is satisfied by no value. Missing minimum or maximum values may satisfy any
+/// comparison.
+/// # Errors
+/// Errors iff the type of `scalar` is not the type of the column, or iff the type is not
+/// supported (only integers, floats and decimals are).
+pub fn might_satisfy(
+    statistics: &dyn Statistics,
+    comparison: Comparison,
+    scalar: &dyn Scalar,
+) -> Result<bool> {
+    use PrimitiveType::*;
+    match (statistics.data_type(), scalar.data_type()) {
+        (DataType::Decimal(_, lhs_scale), DataType::Decimal(_, rhs_scale)) => {
+            let statistics = statistics
+                .as_any()
+                .downcast_ref::<PrimitiveStatistics<i128>>()
+                .unwrap();
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<PrimitiveScalar<i128>>()
+                .unwrap();
+            Ok(match scalar.value() {
+                Some(value) => might_satisfy_bounds(
+                    statistics.min_value,
+                    statistics.max_value,
+                    comparison,
+                    |x| Some(cmp_decimals(x, *lhs_scale, value, *rhs_scale)),
+                ),
+                None => false,
+            })
+        }
+        (lhs, rhs) if lhs != rhs => Err(ArrowError::InvalidArgumentError(format!(
+            "Can't compare statistics of {:?} with a scalar of {:?}",
+            lhs, rhs
+        ))),
+        (data_type, _) => Ok(match data_type.to_physical_type() {
+            PhysicalType::Primitive(Int8) => {
+                might_satisfy_primitive::<i8>(statistics, comparison, scalar)
+            }
+            PhysicalType::Primitive(Int16) => {
+                might_satisfy_primitive::<i16>(statistics, comparison, scalar)
+            }
+            PhysicalType::Primitive(Int32) => {
+                might_satisfy_primitive::<i32>(statistics, comparison, scalar)
+            }
+            PhysicalType::Primitive(Int64) => {
+                might_satisfy_primitive::<i64>(statistics, comparison, scalar)
+            }
+            PhysicalType::Primitive(UInt8) => {
+                might_satisfy_primitive::<u8>(statistics, comparison, scalar)
+            }
+            PhysicalType::Primitive(UInt16) => {
+                might_satisfy_primitive::<u16>(statistics, comparison, scalar)
+            }
+            PhysicalType::Primitive(UInt32) => {
+                might_satisfy_primitive::<u32>(statistics, comparison, scalar)
+            }
+            PhysicalType::Primitive(UInt64) => {
+                might_satisfy_primitive::<u64>(statistics, comparison, scalar)
+            }
+            PhysicalType::Primitive(Float32) => {
+                might_satisfy_primitive::<f32>(statistics, comparison, scalar)
+            }
+            PhysicalType::Primitive(Float64) => {
+                might_satisfy_primitive::<f64>(statistics, comparison, scalar)
+            }
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Comparing statistics of {:?}",
+                    data_type
+                )))
+            }
+        }),
+    }
+}
+
+/// Returns whether the column `column` of the row group `group` may contain values that satisfy
+/// `comparison` against `scalar`. Columns without statistics may satisfy any comparison.
+/// See [`might_satisfy`] for details.
+/// # Errors
+/// Errors iff the statistics can't be deserialized or iff [`might_satisfy`] errors.
+pub fn row_group_might_satisfy(
+    group: &RowGroupMetaData,
+    column: usize,
+    comparison: Comparison,
+    scalar: &dyn Scalar,
+) -> Result<bool> {
+    match group.column(column).statistics() {
+        Some(statistics) => {
+            let statistics = deserialize_statistics(statistics?.as_ref())?;
+            might_satisfy(statistics.as_ref(), comparison, scalar)
+        }
+        None => Ok(true),
+    }
+}
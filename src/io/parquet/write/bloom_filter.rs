@@ -0,0 +1,197 @@
+//! Writing of [`BloomFilter`]s, that `parquet2` does not support.
+//!
+//! [`write_file_with_bloom_filters`] relies on an implementation detail of `parquet2`'s
+//! `write_file`: the footer (the file metadata, its length and the magic number) is written only
+//! after the iterator of row groups returns `None`, and nothing is written after it. Every byte
+//! is forwarded to the writer until the iterator returns `None`; the bytes written after are the
+//! footer, that is parsed and re-written with the offsets of the bloom filters written before it.
+//! A footer that does not have this layout is an error, and the test `footer_is_intercepted`
+//! fails if a release of `parquet2` writes the file in a different order.
+use std::cell::Cell;
+use std::convert::TryInto;
+use std::io::Write;
+
+use parquet2::error::ParquetError;
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
+use parquet_format_async_temp::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader, FileMetaData,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+
+use super::super::bloom_filter::BloomFilter;
+use super::{write_file, KeyValue, RowGroupIter, SchemaDescriptor, WriteOptions};
+
+/// The options of the [`BloomFilter`] of a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomFilterOptions {
+    /// The expected number of distinct values of a column chunk
+    pub ndv: u64,
+    /// The false positive probability of the filter, in `(0, 1)`
+    pub fpp: f64,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            ndv: 1_000_000,
+            fpp: 0.05,
+        }
+    }
+}
+
+/// A [`Write`] that forwards bytes to `writer` until `finished` is set, and buffers them after.
+struct FooterWriter<'a, W: Write> {
+    writer: &'a mut W,
+    finished: &'a Cell<bool>,
+    written: u64,
+    footer: Vec<u8>,
+}
+
+impl<'a, W: Write> Write for FooterWriter<'a, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.finished.get() {
+            self.footer.extend_from_slice(buf);
+            Ok(buf.len())
+        } else {
+            let written = self.writer.write(buf)?;
+            self.written += written as u64;
+            Ok(written)
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Whether `footer` is a parquet footer: the metadata followed by its length and the magic number.
+fn is_footer(footer: &[u8]) -> bool {
+    match footer.len().checked_sub(8) {
+        Some(metadata_len) => {
+            let length: [u8; 4] = footer[metadata_len..metadata_len + 4].try_into().unwrap();
+            i32::from_le_bytes(length) as usize == metadata_len
+                && footer[metadata_len + 4..] == *b"PAR1"
+        }
+        None => false,
+    }
+}
+
+fn to_arrow_error(error: parquet_format_async_temp::thrift::Error) -> ArrowError {
+    ParquetError::from(error).into()
+}
+
+/// Writes a parquet file like [`write_file`], with the [`BloomFilter`]s of each row group
+/// written before its footer. Each row group is declared with one optional [`BloomFilter`] per
+/// parquet column, e.g. as returned by
+/// [`BloomFilterRowGroupIterator`](super::BloomFilterRowGroupIterator).
+/// # Errors
+/// Errors iff [`write_file`] errors or iff the number of [`BloomFilter`]s of a row group does not
+/// match the number of columns of the file.
+pub fn write_file_with_bloom_filters<'a, W, I>(
+    writer: &mut W,
+    row_groups: I,
+    schema: &Schema,
+    parquet_schema: SchemaDescriptor,
+    options: WriteOptions,
+    key_value_metadata: Option<Vec<KeyValue>>,
+) -> Result<u64>
+where
+    W: Write,
+    I: Iterator<Item = Result<(RowGroupIter<'a, ArrowError>, Vec<Option<BloomFilter>>)>>,
+{
+    // parquet2 does not expose the metadata of the row groups until the footer is written.
+    // The footer is thus intercepted, and re-written with the offsets of the bloom filters.
+    let finished = Cell::new(false);
+    let mut filters = vec![];
+    let row_groups = row_groups
+        .map(|row_group| {
+            row_group.map(|(row_group, row_group_filters)| {
+                filters.push(row_group_filters);
+                row_group
+            })
+        })
+        .chain(std::iter::from_fn(|| {
+            finished.set(true);
+            None
+        }));
+
+    let mut footer_writer = FooterWriter {
+        writer,
+        finished: &finished,
+        written: 0,
+        footer: vec![],
+    };
+    write_file(
+        &mut footer_writer,
+        row_groups,
+        schema,
+        parquet_schema,
+        options,
+        key_value_metadata,
+    )?;
+    let FooterWriter {
+        writer,
+        mut written,
+        footer,
+        ..
+    } = footer_writer;
+
+    // the footer is the metadata followed by its length and the magic number (8 bytes)
+    if !is_footer(&footer) {
+        return Err(ArrowError::ExternalFormat(format!(
+            "parquet2 was expected to write only the footer after the last row group, but it wrote {} bytes that are not a footer",
+            footer.len()
+        )));
+    }
+    let mut protocol = TCompactInputProtocol::new(&footer[..footer.len() - 8]);
+    let mut metadata =
+        FileMetaData::read_from_in_protocol(&mut protocol).map_err(to_arrow_error)?;
+
+    for (row_group, filters) in metadata.row_groups.iter_mut().zip(filters) {
+        if filters.len() != row_group.columns.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A row group with {} columns cannot be written with {} bloom filters",
+                row_group.columns.len(),
+                filters.len()
+            )));
+        }
+        for (column, filter) in row_group.columns.iter_mut().zip(filters) {
+            if let (Some(metadata), Some(filter)) = (column.meta_data.as_mut(), filter) {
+                metadata.bloom_filter_offset = Some(written as i64);
+                written += write_bloom_filter(writer, &filter)?;
+            }
+        }
+    }
+
+    let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+    let metadata_len = metadata
+        .write_to_out_protocol(&mut protocol)
+        .map_err(to_arrow_error)?;
+    protocol.flush().map_err(to_arrow_error)?;
+    writer.write_all(&(metadata_len as i32).to_le_bytes())?;
+    writer.write_all(&footer[footer.len() - 4..])?;
+    Ok(written + metadata_len as u64 + 8)
+}
+
+/// Writes the header and the bitset of `filter`, returning the number of bytes written.
+fn write_bloom_filter<W: Write>(writer: &mut W, filter: &BloomFilter) -> Result<u64> {
+    let header = BloomFilterHeader::new(
+        filter.num_bytes() as i32,
+        BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm::new()),
+        BloomFilterHash::XXHASH(XxHash::new()),
+        BloomFilterCompression::UNCOMPRESSED(Uncompressed::new()),
+    );
+    let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+    let header_len = header
+        .write_to_out_protocol(&mut protocol)
+        .map_err(to_arrow_error)?;
+    protocol.flush().map_err(to_arrow_error)?;
+
+    writer.write_all(&filter.to_bitset())?;
+    Ok((header_len + filter.num_bytes()) as u64)
+}
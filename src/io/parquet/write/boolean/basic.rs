@@ -24,13 +24,10 @@ pub(super) fn encode_plain(
     buffer: &mut Vec<u8>,
 ) -> Result<()> {
     if is_optional {
-        let iter = array.iter().flatten().take(
-            array
-                .validity()
-                .as_ref()
-                .map(|x| x.len() - x.null_count())
-                .unwrap_or_else(|| array.len()),
-        );
+        let iter = array
+            .iter()
+            .flatten()
+            .take(array.len() - array.null_count());
         encode(iter, buffer)
     } else {
         let iter = array.values().iter();
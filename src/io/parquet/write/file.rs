@@ -0,0 +1,628 @@
+//! Writing of parquet files with features that `parquet2` does not support, e.g. page checksums
+//! and page indexes.
+//!
+//! `parquet2` does not expose the (compressed) buffers of pages, so [`write_file_with_options`]
+//! lets `parquet2` serialize the pages of each column chunk to memory and writes them itself:
+//! the header of each page is read back, checked against the page given to `parquet2`, and
+//! re-written (e.g. with its checksum). Everything else, i.e. the metadata of the column chunks,
+//! of the row groups and the footer, is derived from the pages as they are written to `writer`;
+//! what `parquet2` writes besides pages is discarded.
+//! The page indexes are written between the last row group and the footer.
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::io::Write;
+use std::rc::Rc;
+
+use parquet2::error::ParquetError;
+use parquet2::page::DataPageHeader;
+use parquet2::schema::types::{physical_type_to_type, BasicTypeInfo};
+use parquet2::schema::Repetition;
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
+use parquet_format_async_temp::{
+    ColumnChunk, ColumnMetaData, Encoding, FileMetaData, PageHeader, PageType, RowGroup, Statistics,
+};
+
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+use crate::io::parquet::page_index::{ColumnIndex, OffsetIndex};
+
+use super::page_index::{compare, order, Order, PageIndexBuilder};
+use super::{
+    to_key_value_metadata, write_file, ColumnDescriptor, CompressedPage, Compression, DynIter,
+    DynStreamingIterator, FallibleStreamingIterator, KeyValue, ParquetType, RowGroupIter,
+    SchemaDescriptor, WriteOptions, CREATED_BY,
+};
+
+/// The magic number at the start and end of a parquet file
+const MAGIC: &[u8; 4] = b"PAR1";
+
+/// The options of [`write_file_with_options`] that [`WriteOptions`] does not cover.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileOptions {
+    /// Whether to write the CRC-32 checksum of each page to its header
+    pub write_page_crc: bool,
+    /// Whether to write the page index, i.e. the [`ColumnIndex`] and [`OffsetIndex`], of each
+    /// column chunk. The min and max values of byte array columns are truncated to 64 bytes.
+    /// Column chunks whose pages have no statistics have no [`ColumnIndex`].
+    pub write_page_index: bool,
+    /// The (leaf) columns by which the rows of every row group are sorted, if any
+    pub sorting_columns: Option<Vec<SortingColumn>>,
+}
+
+/// A (leaf) column by which the rows of a row group are sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SortingColumn {
+    /// The index of the column in the (leaf) columns of the file
+    pub column_idx: i32,
+    /// Whether the values are sorted in descending order
+    pub descending: bool,
+    /// Whether the nulls are before the values
+    pub nulls_first: bool,
+}
+
+impl SortingColumn {
+    /// Returns a new [`SortingColumn`].
+    pub fn new(column_idx: i32, descending: bool, nulls_first: bool) -> Self {
+        Self {
+            column_idx,
+            descending,
+            nulls_first,
+        }
+    }
+
+    fn into_thrift(self) -> parquet_format_async_temp::SortingColumn {
+        parquet_format_async_temp::SortingColumn::new(
+            self.column_idx,
+            self.descending,
+            self.nulls_first,
+        )
+    }
+}
+
+/// A page given to `parquet2`, against which the header `parquet2` writes for it is checked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PageSpec {
+    Dictionary,
+    /// A data page with its type, number of values and compressed size
+    Data(PageType, i32, usize),
+}
+
+impl PageSpec {
+    fn new(page: &CompressedPage) -> Self {
+        match page {
+            CompressedPage::Dict(_) => Self::Dictionary,
+            CompressedPage::Data(page) => {
+                let type_ = match page.header() {
+                    DataPageHeader::V1(_) => PageType::DATA_PAGE,
+                    DataPageHeader::V2(_) => PageType::DATA_PAGE_V2,
+                };
+                Self::Data(type_, page.num_values() as i32, page.compressed_size())
+            }
+        }
+    }
+
+    /// Returns whether `header` is the header of this page.
+    fn matches(&self, header: &PageHeader) -> bool {
+        match self {
+            Self::Dictionary => header.type_ == PageType::DICTIONARY_PAGE,
+            Self::Data(type_, num_values, size) => {
+                header.type_ == *type_
+                    && header.compressed_page_size as usize == *size
+                    && matches!(data_page(header), Ok((values, _, _)) if values == *num_values)
+            }
+        }
+    }
+}
+
+/// A complete part of what `parquet2` wrote.
+enum Segment {
+    /// The start of a row group
+    RowGroup,
+    /// A column chunk, i.e. its pages followed by its metadata, with the pages given to `parquet2`
+    ColumnChunk(Vec<u8>, Vec<PageSpec>),
+}
+
+/// The state shared between the row groups being written and the [`Write`] given to `parquet2`.
+#[derive(Default)]
+struct Shared {
+    /// bytes written by `parquet2` since the last column chunk or row group was requested
+    buffer: Vec<u8>,
+    /// the pages of the column chunk being written, if any
+    pages: Option<Vec<PageSpec>>,
+    segments: Vec<Segment>,
+}
+
+impl Shared {
+    /// Declares the buffered bytes as complete, e.g. because the next column chunk was requested.
+    /// Bytes outside of column chunks (the magic number and the footer) are discarded.
+    fn split(&mut self) {
+        let buffer = std::mem::take(&mut self.buffer);
+        if let Some(pages) = self.pages.take() {
+            self.segments.push(Segment::ColumnChunk(buffer, pages));
+        }
+    }
+}
+
+/// The [`Write`] given to `parquet2`, which writes the pages of the column chunks it receives to
+/// `writer`.
+struct InterceptWriter<'a, W: Write> {
+    writer: &'a mut W,
+    options: &'a FileOptions,
+    /// the (leaf) columns of the file and their compression
+    columns: Vec<ColumnDescriptor>,
+    compression: Compression,
+    shared: Rc<RefCell<Shared>>,
+    /// the number of bytes written to `writer`
+    written: u64,
+    /// the column chunks of each row group written
+    row_groups: Vec<Vec<ColumnChunk>>,
+    /// the page indexes of the column chunks, if written, in the order of the file
+    page_indexes: Vec<(Option<ColumnIndex>, OffsetIndex)>,
+    error: Option<ArrowError>,
+}
+
+impl<'a, W: Write> InterceptWriter<'a, W> {
+    /// Writes the complete segments to `writer`.
+    fn write_segments(&mut self) -> Result<()> {
+        let segments = std::mem::take(&mut self.shared.borrow_mut().segments);
+        for segment in segments {
+            match segment {
+                Segment::RowGroup => self.row_groups.push(vec![]),
+                Segment::ColumnChunk(buffer, pages) => self.write_column_chunk(&buffer, &pages)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the pages of a column chunk and its metadata.
+    fn write_column_chunk(&mut self, mut buffer: &[u8], pages: &[PageSpec]) -> Result<()> {
+        let column = self.row_groups.last().map_or(0, |columns| columns.len());
+        let descriptor = self.columns.get(column).cloned().ok_or_else(|| {
+            ArrowError::OutOfSpec(
+                "A row group has more column chunks than the file has columns".to_string(),
+            )
+        })?;
+        let start = self.written;
+        let mut data_page_offset = None;
+        let mut dictionary_page_offset = None;
+        let mut total_uncompressed_size = 0;
+        let mut num_values = 0;
+        let mut encodings = vec![];
+        let mut statistics = vec![];
+        let mut page_index = if self.options.write_page_index {
+            Some(PageIndexBuilder::new(descriptor.clone(), self.compression))
+        } else {
+            None
+        };
+        for spec in pages {
+            let mut protocol = TCompactInputProtocol::new(&mut buffer);
+            let mut header =
+                PageHeader::read_from_in_protocol(&mut protocol).map_err(to_arrow_error)?;
+            let size = header.compressed_page_size as usize;
+            if !spec.matches(&header) || size > buffer.len() {
+                return Err(ArrowError::OutOfSpec(
+                    "The header of a page does not match the page".to_string(),
+                ));
+            }
+            let (page, remaining) = buffer.split_at(size);
+            buffer = remaining;
+
+            if self.options.write_page_crc {
+                header.crc = Some(crc::crc32::checksum_ieee(page) as i32);
+            }
+            let offset = self.written as i64;
+            if let Some(dictionary) = header.dictionary_page_header.as_ref() {
+                dictionary_page_offset.get_or_insert(offset);
+                push_unique(&mut encodings, dictionary.encoding);
+            } else {
+                data_page_offset.get_or_insert(offset);
+                let (values, encoding, page_statistics) = data_page(&header)?;
+                num_values += values as i64;
+                push_unique(&mut encodings, encoding);
+                push_unique(&mut encodings, Encoding::RLE);
+                statistics.extend(page_statistics.cloned());
+            }
+
+            let header_size =
+                self.write_thrift(|protocol| header.write_to_out_protocol(protocol))?;
+            self.writer.write_all(page)?;
+            self.written += size as u64;
+            if let Some(page_index) = page_index.as_mut() {
+                page_index.push(&header, page, offset, header_size + size)?;
+            }
+            total_uncompressed_size +=
+                (header_size + header.uncompressed_page_size as usize) as i64;
+        }
+        // `buffer` is the metadata of the column chunk written by `parquet2`, which is replaced
+
+        let type_ = match descriptor.type_() {
+            ParquetType::PrimitiveType { physical_type, .. } => {
+                physical_type_to_type(physical_type).0
+            }
+            _ => {
+                return Err(ArrowError::OutOfSpec(
+                    "A column chunk must be of a primitive type".to_string(),
+                ))
+            }
+        };
+        let metadata = ColumnMetaData {
+            type_,
+            encodings,
+            path_in_schema: descriptor.path_in_schema().to_vec(),
+            codec: self.compression.into(),
+            num_values,
+            total_uncompressed_size,
+            total_compressed_size: (self.written - start) as i64,
+            key_value_metadata: None,
+            data_page_offset: data_page_offset.unwrap_or(start as i64),
+            index_page_offset: None,
+            dictionary_page_offset,
+            statistics: reduce_statistics(descriptor.type_(), &statistics),
+            encoding_stats: None,
+            bloom_filter_offset: None,
+        };
+        let column_chunk = ColumnChunk {
+            file_path: None,
+            file_offset: self.written as i64,
+            meta_data: Some(metadata),
+            offset_index_offset: None,
+            offset_index_length: None,
+            column_index_offset: None,
+            column_index_length: None,
+            crypto_metadata: None,
+            encrypted_column_metadata: None,
+        };
+
+        self.write_thrift(|protocol| column_chunk.write_to_out_protocol(protocol))?;
+        self.row_groups
+            .last_mut()
+            .ok_or_else(|| {
+                ArrowError::OutOfSpec("A column chunk must be part of a row group".to_string())
+            })?
+            .push(column_chunk);
+        if let Some(page_index) = page_index {
+            self.page_indexes.push(page_index.finish());
+        }
+        Ok(())
+    }
+
+    /// Writes a thrift struct to `writer`, returning the number of bytes written.
+    fn write_thrift<F>(&mut self, write: F) -> Result<usize>
+    where
+        F: FnOnce(
+            &mut TCompactOutputProtocol<&mut W>,
+        ) -> parquet_format_async_temp::thrift::Result<usize>,
+    {
+        let mut protocol = TCompactOutputProtocol::new(&mut *self.writer);
+        let written = write(&mut protocol).map_err(to_arrow_error)?;
+        protocol.flush().map_err(to_arrow_error)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    /// Writes the page indexes of the column chunks, all column indexes followed by all offset
+    /// indexes, and declares their location in the column chunks.
+    fn write_page_indexes(&mut self) -> Result<()> {
+        let page_indexes = std::mem::take(&mut self.page_indexes)
+            .into_iter()
+            .map(|(column_index, offset_index)| {
+                (
+                    column_index.map(|index| index.into_thrift()),
+                    offset_index.into_thrift(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut row_groups = std::mem::take(&mut self.row_groups);
+        for (column_chunk, (column_index, _)) in
+            row_groups.iter_mut().flatten().zip(page_indexes.iter())
+        {
+            if let Some(column_index) = column_index {
+                let offset = self.written as i64;
+                let length =
+                    self.write_thrift(|protocol| column_index.write_to_out_protocol(protocol))?;
+                column_chunk.column_index_offset = Some(offset);
+                column_chunk.column_index_length = Some(length as i32);
+            }
+        }
+        for (column_chunk, (_, offset_index)) in
+            row_groups.iter_mut().flatten().zip(page_indexes.iter())
+        {
+            let offset = self.written as i64;
+            let length =
+                self.write_thrift(|protocol| offset_index.write_to_out_protocol(protocol))?;
+            column_chunk.offset_index_offset = Some(offset);
+            column_chunk.offset_index_length = Some(length as i32);
+        }
+        self.row_groups = row_groups;
+        Ok(())
+    }
+}
+
+impl<'a, W: Write> Write for InterceptWriter<'a, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if let Err(error) = self.write_segments() {
+            let message = error.to_string();
+            self.error = Some(error);
+            return Err(std::io::Error::other(message));
+        }
+        self.shared.borrow_mut().buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Returns the number of values, the encoding and the statistics of a data page.
+fn data_page(header: &PageHeader) -> Result<(i32, Encoding, Option<&Statistics>)> {
+    if let Some(header) = header.data_page_header.as_ref() {
+        Ok((
+            header.num_values,
+            header.encoding,
+            header.statistics.as_ref(),
+        ))
+    } else if let Some(header) = header.data_page_header_v2.as_ref() {
+        Ok((
+            header.num_values,
+            header.encoding,
+            header.statistics.as_ref(),
+        ))
+    } else {
+        Err(ArrowError::OutOfSpec(
+            "A data page must have a data page header".to_string(),
+        ))
+    }
+}
+
+fn push_unique(encodings: &mut Vec<Encoding>, encoding: Encoding) {
+    if !encodings.contains(&encoding) {
+        encodings.push(encoding);
+    }
+}
+
+/// Returns the statistics of a column chunk from the statistics of its pages. Like `parquet2`,
+/// pages without statistics are ignored. The min and max values are `None` when the values of
+/// the column have no defined order.
+fn reduce_statistics(type_: &ParquetType, pages: &[Statistics]) -> Option<Statistics> {
+    if pages.is_empty() {
+        return None;
+    }
+    let order = order(type_);
+    let null_count = pages
+        .iter()
+        .filter_map(|statistics| statistics.null_count)
+        .fold(None, |acc, null_count| Some(acc.unwrap_or(0) + null_count));
+    let min_value = reduce_values(
+        order,
+        pages.iter().map(|statistics| &statistics.min_value),
+        Ordering::Less,
+    );
+    let max_value = reduce_values(
+        order,
+        pages.iter().map(|statistics| &statistics.max_value),
+        Ordering::Greater,
+    );
+    Some(Statistics {
+        max: None,
+        min: None,
+        null_count,
+        distinct_count: None,
+        max_value,
+        min_value,
+    })
+}
+
+/// Returns the least (`Ordering::Less`) or greatest (`Ordering::Greater`) of `values`.
+fn reduce_values<'b, I: Iterator<Item = &'b Option<Vec<u8>>>>(
+    order: Order,
+    values: I,
+    keep: Ordering,
+) -> Option<Vec<u8>> {
+    if order == Order::Undefined {
+        return None;
+    }
+    let mut result: Option<&Vec<u8>> = None;
+    for value in values.flatten() {
+        result = match result {
+            Some(current) if compare(order, value, current)? != keep => Some(current),
+            _ => Some(value),
+        };
+    }
+    result.cloned()
+}
+
+/// A [`FallibleStreamingIterator`] of the pages of a column chunk that records its pages.
+struct CountedPages<'a> {
+    pages: DynStreamingIterator<'a, CompressedPage, ArrowError>,
+    shared: Rc<RefCell<Shared>>,
+}
+
+impl<'a> FallibleStreamingIterator for CountedPages<'a> {
+    type Item = CompressedPage;
+    type Error = ArrowError;
+
+    fn advance(&mut self) -> Result<()> {
+        self.pages.advance()?;
+        if let Some(page) = self.pages.get() {
+            if let Some(pages) = self.shared.borrow_mut().pages.as_mut() {
+                pages.push(PageSpec::new(page));
+            }
+        }
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.pages.get()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pages.size_hint()
+    }
+}
+
+/// Returns `row_group` with each of its column chunks declared complete once written.
+fn intercept_row_group(
+    row_group: RowGroupIter<ArrowError>,
+    shared: Rc<RefCell<Shared>>,
+) -> RowGroupIter<ArrowError> {
+    let mut columns = row_group;
+    DynIter::new(std::iter::from_fn(move || {
+        // the previous column chunk (if any) was written
+        shared.borrow_mut().split();
+        let column = columns.next()?;
+        let shared = shared.clone();
+        Some(column.map(|pages| {
+            shared.borrow_mut().pages = Some(vec![]);
+            DynStreamingIterator::new(CountedPages { pages, shared })
+        }))
+    }))
+}
+
+fn to_arrow_error(error: parquet_format_async_temp::thrift::Error) -> ArrowError {
+    ParquetError::from(error).into()
+}
+
+/// Writes a parquet file like [`write_file`], with the additional [`FileOptions`].
+/// Each column chunk is buffered in memory before being written to `writer`.
+/// # Errors
+/// Errors iff [`write_file`] errors or a sorting column does not exist.
+pub fn write_file_with_options<'a, W, I>(
+    writer: &mut W,
+    row_groups: I,
+    schema: &Schema,
+    parquet_schema: SchemaDescriptor,
+    options: WriteOptions,
+    file_options: &FileOptions,
+    key_value_metadata: Option<Vec<KeyValue>>,
+) -> Result<u64>
+where
+    W: Write,
+    I: Iterator<Item = Result<RowGroupIter<'a, ArrowError>>>,
+{
+    let columns = parquet_schema.columns().to_vec();
+    if let Some(sorting_columns) = &file_options.sorting_columns {
+        if let Some(column) = sorting_columns
+            .iter()
+            .find(|column| column.column_idx < 0 || column.column_idx as usize >= columns.len())
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The sorting column {} does not exist, as the file has {} columns",
+                column.column_idx,
+                columns.len()
+            )));
+        }
+    }
+    let schema_elements = ParquetType::GroupType {
+        basic_info: BasicTypeInfo::new(
+            parquet_schema.name().to_string(),
+            Repetition::Optional,
+            None,
+            true,
+        ),
+        logical_type: None,
+        converted_type: None,
+        fields: parquet_schema.fields().to_vec(),
+    }
+    .to_thrift()?;
+    let key_value_metadata = to_key_value_metadata(schema, key_value_metadata);
+
+    writer.write_all(MAGIC)?;
+    let shared = Rc::new(RefCell::new(Shared::default()));
+    let mut writer = InterceptWriter {
+        writer,
+        options: file_options,
+        columns,
+        compression: options.compression,
+        shared: shared.clone(),
+        written: MAGIC.len() as u64,
+        row_groups: vec![],
+        page_indexes: vec![],
+        error: None,
+    };
+
+    let mut row_groups = row_groups;
+    let row_groups = std::iter::from_fn(|| {
+        // the previous row group (if any) was written
+        shared.borrow_mut().split();
+        let row_group = row_groups.next()?;
+        Some(row_group.map(|row_group| {
+            shared.borrow_mut().segments.push(Segment::RowGroup);
+            intercept_row_group(row_group, shared.clone())
+        }))
+    });
+
+    // the footer written by `parquet2` is discarded, see [`Shared::split`]
+    let result = write_file(
+        &mut writer,
+        row_groups,
+        schema,
+        parquet_schema,
+        options,
+        None,
+    );
+    if let Some(error) = writer.error.take() {
+        return Err(error);
+    }
+    result?;
+    shared.borrow_mut().split();
+    writer.write_segments()?;
+    writer.write_page_indexes()?;
+    let InterceptWriter {
+        writer,
+        mut written,
+        row_groups,
+        ..
+    } = writer;
+
+    let row_groups = row_groups
+        .into_iter()
+        .map(|columns| {
+            let metadata = columns
+                .iter()
+                .filter_map(|column| column.meta_data.as_ref());
+            // like `parquet2`, the number of rows is the number of values of the column chunks
+            let num_rows = metadata.clone().map(|metadata| metadata.num_values).next();
+            let total_byte_size = metadata
+                .map(|metadata| metadata.total_compressed_size)
+                .sum();
+            RowGroup {
+                columns,
+                total_byte_size,
+                num_rows: num_rows.unwrap_or(0),
+                sorting_columns: file_options
+                    .sorting_columns
+                    .as_ref()
+                    .map(|columns| columns.iter().map(|column| column.into_thrift()).collect()),
+                file_offset: None,
+                total_compressed_size: None,
+                ordinal: None,
+            }
+        })
+        .collect::<Vec<_>>();
+    let num_rows = row_groups.iter().map(|row_group| row_group.num_rows).sum();
+    let metadata = FileMetaData::new(
+        options.version.into(),
+        schema_elements,
+        num_rows,
+        row_groups,
+        Some(key_value_metadata),
+        Some(CREATED_BY.to_string()),
+        None,
+        None,
+        None,
+    );
+
+    // the footer is the metadata followed by its length and the magic number
+    let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+    let metadata_len = metadata
+        .write_to_out_protocol(&mut protocol)
+        .map_err(to_arrow_error)?;
+    protocol.flush().map_err(to_arrow_error)?;
+    writer.write_all(&(metadata_len as i32).to_le_bytes())?;
+    writer.write_all(MAGIC)?;
+    written += metadata_len as u64 + 8;
+    Ok(written)
+}
@@ -8,15 +8,18 @@ use parquet2::{
 
 use super::{binary::ord_binary, utils};
 use crate::{
-    array::{Array, FixedSizeBinaryArray},
+    array::{Array, FixedSizeBinaryArray, PrimitiveArray},
     error::Result,
     io::parquet::read::is_type_nullable,
 };
 
+/// Returns a [`DataPage`] with the values of `array` and `statistics`, which callers compute
+/// (e.g. via [`build_statistics`]) since the order of the values depends on their logical type.
 pub fn array_to_page(
     array: &FixedSizeBinaryArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    statistics: Option<ParquetStatistics>,
 ) -> Result<DataPage> {
     let is_optional = is_type_nullable(descriptor.type_());
     let validity = array.validity();
@@ -44,12 +47,6 @@ pub fn array_to_page(
         buffer.extend_from_slice(array.values());
     }
 
-    let statistics = if options.write_statistics {
-        build_statistics(array, descriptor.clone())
-    } else {
-        None
-    };
-
     utils::build_plain_page(
         buffer,
         array.len(),
@@ -87,3 +84,32 @@ pub(super) fn build_statistics(
         .map(|e| serialize_statistics(&*e))
         .ok()
 }
+
+/// Builds the statistics of decimals written as big-endian two's complement of `size` bytes.
+/// Parquet orders these as signed integers, which differs from the (unsigned) order of
+/// their bytes when values are negative.
+pub(super) fn build_statistics_decimal(
+    array: &PrimitiveArray<i128>,
+    size: usize,
+    descriptor: ColumnDescriptor,
+) -> Option<ParquetStatistics> {
+    let pq_statistics = &ParquetStatistics {
+        max: None,
+        min: None,
+        null_count: Some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: array
+            .iter()
+            .flatten()
+            .max()
+            .map(|x| x.to_be_bytes()[16 - size..].to_vec()),
+        min_value: array
+            .iter()
+            .flatten()
+            .min()
+            .map(|x| x.to_be_bytes()[16 - size..].to_vec()),
+    };
+    deserialize_statistics(pq_statistics, descriptor)
+        .map(|e| serialize_statistics(&*e))
+        .ok()
+}
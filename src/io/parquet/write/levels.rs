@@ -1,4 +1,5 @@
 use parquet2::encoding::hybrid_rle::encode_u32;
+use parquet2::read::levels::get_bit_width;
 use parquet2::write::Version;
 
 use crate::{
@@ -76,6 +77,8 @@ impl<O: Offset> Iterator for RepLevelsIter<'_, O> {
 pub struct DefLevelsIter<'a, O: Offset> {
     iter: std::iter::Zip<std::slice::Windows<'a, O>, Box<dyn Iterator<Item = bool> + 'a>>,
     primitive_validity: Option<BitmapIter<'a>>,
+    is_optional: bool,
+    is_leaf_optional: bool,
     remaining: usize,
     is_valid: bool,
     length: usize,
@@ -83,10 +86,14 @@ pub struct DefLevelsIter<'a, O: Offset> {
 }
 
 impl<'a, O: Offset> DefLevelsIter<'a, O> {
+    /// Returns a new [`DefLevelsIter`] of a list whose nullability is `is_optional`
+    /// and whose items' nullability is `is_leaf_optional`.
     pub fn new(
         offsets: &'a [O],
         validity: Option<&'a Bitmap>,
         primitive_validity: Option<&'a Bitmap>,
+        is_optional: bool,
+        is_leaf_optional: bool,
     ) -> Self {
         let total_size = num_values(offsets);
 
@@ -102,6 +109,8 @@ impl<'a, O: Offset> DefLevelsIter<'a, O> {
         Self {
             iter: offsets.windows(2).zip(validity),
             primitive_validity,
+            is_optional,
+            is_leaf_optional,
             remaining: 0,
             length: 0,
             is_valid: false,
@@ -123,7 +132,8 @@ impl<O: Offset> Iterator for DefLevelsIter<'_, O> {
                 self.is_valid = is_valid;
                 if self.length == 0 {
                     self.total_size -= 1;
-                    return Some(is_valid as u32);
+                    // a null list has level 0 and an empty list has the level of the list
+                    return Some((self.is_optional && is_valid) as u32);
                 }
             } else {
                 return None;
@@ -132,12 +142,17 @@ impl<O: Offset> Iterator for DefLevelsIter<'_, O> {
         self.remaining += 1;
         self.total_size -= 1;
 
-        let (base_def, p_is_valid) = self
+        let p_is_valid = self
             .primitive_validity
             .as_mut()
-            .map(|x| (1, x.next().unwrap() as u32))
-            .unwrap_or((0, 0));
-        let def_ = (base_def + 1) * self.is_valid as u32 + p_is_valid;
+            .map(|x| x.next().unwrap())
+            .unwrap_or(true);
+        let def_ = if self.is_valid {
+            // list (if optional) + repetition + item (if optional and valid)
+            self.is_optional as u32 + 1 + (self.is_leaf_optional && p_is_valid) as u32
+        } else {
+            0
+        };
         Some(def_)
     }
 
@@ -148,15 +163,16 @@ impl<O: Offset> Iterator for DefLevelsIter<'_, O> {
 
 #[derive(Debug)]
 pub struct NestedInfo<'a, O: Offset> {
-    _is_optional: bool,
+    is_optional: bool,
     offsets: &'a [O],
     validity: Option<&'a Bitmap>,
 }
 
 impl<'a, O: Offset> NestedInfo<'a, O> {
+    /// Creates a new [`NestedInfo`] of a list whose nullability is `is_optional`.
     pub fn new(offsets: &'a [O], validity: Option<&'a Bitmap>, is_optional: bool) -> Self {
         Self {
-            _is_optional: is_optional,
+            is_optional,
             offsets,
             validity,
         }
@@ -211,26 +227,36 @@ pub fn write_rep_levels<O: Offset>(
     Ok(())
 }
 
-/// writes the rep levels to a `Vec<u8>`.
+/// writes the def levels to a `Vec<u8>`.
 pub fn write_def_levels<O: Offset>(
     buffer: &mut Vec<u8>,
     nested: &NestedInfo<O>,
     validity: Option<&Bitmap>,
+    is_optional: bool,
     version: Version,
 ) -> Result<()> {
-    let num_bits = 2;
+    let max_level = nested.is_optional as i16 + 1 + is_optional as i16;
+    let num_bits = get_bit_width(max_level) as u8;
+
+    let levels = || {
+        DefLevelsIter::new(
+            nested.offsets,
+            nested.validity,
+            validity,
+            nested.is_optional,
+            is_optional,
+        )
+    };
 
     match version {
         Version::V1 => {
             write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = DefLevelsIter::new(nested.offsets, nested.validity, validity);
-                encode_u32(buffer, levels, num_bits)?;
+                encode_u32(buffer, levels(), num_bits)?;
                 Ok(())
             })?;
         }
         Version::V2 => {
-            let levels = DefLevelsIter::new(nested.offsets, nested.validity, validity);
-            encode_u32(buffer, levels, num_bits)?;
+            encode_u32(buffer, levels(), num_bits)?;
         }
     }
 
@@ -265,8 +291,14 @@ mod tests {
         ]));
         let expected = vec![3u32, 3, 0, 3, 2, 3, 3, 3, 3, 1, 3, 3, 3, 0, 3];
 
-        let result = DefLevelsIter::new(offsets, validity.as_ref(), primitive_validity.as_ref())
-            .collect::<Vec<_>>();
+        let result = DefLevelsIter::new(
+            offsets,
+            validity.as_ref(),
+            primitive_validity.as_ref(),
+            true,
+            true,
+        )
+        .collect::<Vec<_>>();
         assert_eq!(result, expected)
     }
 }
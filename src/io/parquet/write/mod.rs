@@ -1,9 +1,12 @@
 //! APIs to write to Parquet format.
 mod binary;
+mod bloom_filter;
 mod boolean;
 mod dictionary;
+mod file;
 mod fixed_len_bytes;
 mod levels;
+mod page_index;
 mod primitive;
 mod record_batch;
 mod schema;
@@ -12,6 +15,8 @@ mod utils;
 
 pub mod stream;
 
+use std::sync::Arc;
+
 use crate::array::*;
 use crate::bitmap::Bitmap;
 use crate::buffer::Buffer;
@@ -22,6 +27,10 @@ use crate::io::parquet::write::levels::NestedInfo;
 use crate::types::days_ms;
 use crate::types::NativeType;
 
+pub use super::bloom_filter::{can_hash, BloomFilter};
+pub use super::page_index::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocation};
+pub use bloom_filter::{write_file_with_bloom_filters, BloomFilterOptions};
+pub use file::{write_file_with_options, FileOptions, SortingColumn};
 use parquet2::page::DataPage;
 pub use parquet2::{
     compression::Compression,
@@ -36,8 +45,8 @@ pub use parquet2::{
     },
     FallibleStreamingIterator,
 };
-pub use record_batch::RowGroupIterator;
-use schema::schema_to_metadata_key;
+pub use record_batch::{BloomFilterRowGroupIterator, RowGroupIterator};
+use schema::to_key_value_metadata;
 pub use schema::to_parquet_type;
 
 pub(self) fn decimal_length_from_precision(precision: usize) -> usize {
@@ -61,6 +70,9 @@ pub fn to_parquet_schema(schema: &Schema) -> Result<SchemaDescriptor> {
     Ok(SchemaDescriptor::new("root".to_string(), parquet_types))
 }
 
+/// The `created_by` of the files written by this crate
+const CREATED_BY: &str = "Arrow2 - Native Rust implementation of Arrow";
+
 /// Writes
 pub fn write_file<'a, W, I>(
     writer: &mut W,
@@ -74,14 +86,9 @@ where
     W: std::io::Write,
     I: Iterator<Item = Result<RowGroupIter<'a, ArrowError>>>,
 {
-    let key_value_metadata = key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]));
+    let key_value_metadata = Some(to_key_value_metadata(schema, key_value_metadata));
 
-    let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
+    let created_by = Some(CREATED_BY.to_string());
     Ok(parquet_write_file(
         writer,
         row_groups,
@@ -108,6 +115,37 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
     )
 }
 
+/// Returns the arrays written to each of the parquet columns (leaves) of `array`,
+/// in the same order as the columns of [`to_parquet_type`].
+///
+/// Most arrays are written to a single column. A [`MapArray`] is written as two columns,
+/// its keys and its values, each represented as a [`ListArray`] sharing the offsets and validity of the map.
+pub fn to_leaves(array: &Arc<dyn Array>) -> Vec<Arc<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::Map(field, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let fields = if let DataType::Struct(fields) = field.data_type().to_logical_type() {
+                fields
+            } else {
+                unreachable!()
+            };
+            [array.keys(), array.values()]
+                .iter()
+                .zip(fields.iter())
+                .map(|(values, field)| {
+                    Arc::new(ListArray::<i32>::from_data(
+                        DataType::List(Box::new(field.clone())),
+                        array.offsets().clone(),
+                        (*values).clone(),
+                        array.validity().cloned(),
+                    )) as Arc<dyn Array>
+                })
+                .collect()
+        }
+        _ => vec![array.clone()],
+    }
+}
+
 /// Returns an iterator of [`EncodedPage`].
 pub fn array_to_pages(
     array: &dyn Array,
@@ -251,7 +289,12 @@ pub fn array_to_page(
                 values.into(),
                 array.validity().cloned(),
             );
-            fixed_len_bytes::array_to_page(&array, options, descriptor)
+            let statistics = if options.write_statistics {
+                fixed_len_bytes::build_statistics(&array, descriptor.clone())
+            } else {
+                None
+            };
+            fixed_len_bytes::array_to_page(&array, options, descriptor, statistics)
         }
         DataType::Interval(IntervalUnit::DayTime) => {
             let array = array
@@ -269,13 +312,22 @@ pub fn array_to_page(
                 values.into(),
                 array.validity().cloned(),
             );
-            fixed_len_bytes::array_to_page(&array, options, descriptor)
+            let statistics = if options.write_statistics {
+                fixed_len_bytes::build_statistics(&array, descriptor.clone())
+            } else {
+                None
+            };
+            fixed_len_bytes::array_to_page(&array, options, descriptor, statistics)
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.write_statistics {
+                fixed_len_bytes::build_statistics(array, descriptor.clone())
+            } else {
+                None
+            };
+            fixed_len_bytes::array_to_page(array, options, descriptor, statistics)
         }
-        DataType::FixedSizeBinary(_) => fixed_len_bytes::array_to_page(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            descriptor,
-        ),
         DataType::Decimal(precision, _) => {
             let precision = *precision;
             let array = array
@@ -302,6 +354,11 @@ pub fn array_to_page(
                 primitive::array_to_page::<i64, i64>(&array, options, descriptor)
             } else {
                 let size = decimal_length_from_precision(precision);
+                let statistics = if options.write_statistics {
+                    fixed_len_bytes::build_statistics_decimal(array, size, descriptor.clone())
+                } else {
+                    None
+                };
                 let mut values = Vec::<u8>::with_capacity(size * array.len());
                 array.values().iter().for_each(|x| {
                     let bytes = &x.to_be_bytes()[16 - size..];
@@ -312,7 +369,7 @@ pub fn array_to_page(
                     values.into(),
                     array.validity().cloned(),
                 );
-                fixed_len_bytes::array_to_page(&array, options, descriptor)
+                fixed_len_bytes::array_to_page(&array, options, descriptor, statistics)
             }
         }
         DataType::FixedSizeList(_, _) | DataType::List(_) | DataType::LargeList(_) => {
@@ -347,7 +404,8 @@ fn list_array_to_page<O: Offset>(
     options: WriteOptions,
 ) -> Result<DataPage> {
     use DataType::*;
-    let is_optional = is_type_nullable(descriptor.type_());
+    // the nullability of the list is the one of the (top-level) column it belongs to
+    let is_optional = is_type_nullable(descriptor.base_type());
     let nested = NestedInfo::new(offsets, validity, is_optional);
 
     match values.data_type() {
@@ -374,7 +432,6 @@ fn list_array_to_page<O: Offset>(
 
         Utf8 => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
 
             utf8::nested_array_to_page::<i32, O>(
                 values,
@@ -385,7 +442,6 @@ fn list_array_to_page<O: Offset>(
         }
         LargeUtf8 => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
 
             utf8::nested_array_to_page::<i64, O>(
                 values,
@@ -396,7 +452,6 @@ fn list_array_to_page<O: Offset>(
         }
         Binary => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
 
             binary::nested_array_to_page::<i32, O>(
                 values,
@@ -407,7 +462,6 @@ fn list_array_to_page<O: Offset>(
         }
         LargeBinary => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
 
             binary::nested_array_to_page::<i64, O>(
                 values,
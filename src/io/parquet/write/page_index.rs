@@ -0,0 +1,395 @@
+//! The page index of a column chunk, i.e. its [`ColumnIndex`] and [`OffsetIndex`], derived from
+//! the headers of its pages.
+use std::cmp::Ordering;
+use std::convert::TryInto;
+
+use parquet2::compression::{decompress, Compression};
+use parquet2::encoding::{get_length, hybrid_rle::HybridRleDecoder};
+use parquet2::read::levels::get_bit_width;
+use parquet2::schema::types::{LogicalType, ParquetType, PhysicalType, PrimitiveConvertedType};
+use parquet_format_async_temp::{PageHeader, PageType, Statistics};
+
+use crate::error::{ArrowError, Result};
+use crate::io::parquet::page_index::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocation};
+
+use super::ColumnDescriptor;
+
+/// The maximum length of the min and max values of byte array columns in a [`ColumnIndex`].
+const TRUNCATE_LENGTH: usize = 64;
+
+/// How the min and max values of a column compare, see [`order`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Order {
+    Signed,
+    Unsigned,
+    Float,
+    Bytes,
+    Undefined,
+}
+
+/// Builds the page index of a column chunk from its pages, in the order of the file.
+pub(super) struct PageIndexBuilder {
+    descriptor: ColumnDescriptor,
+    compression: Compression,
+    /// the number of rows in the previous pages
+    rows: i64,
+    locations: Vec<PageLocation>,
+    /// the statistics of each page, or `None` if a page has no statistics
+    statistics: Option<Vec<Statistics>>,
+}
+
+impl PageIndexBuilder {
+    pub fn new(descriptor: ColumnDescriptor, compression: Compression) -> Self {
+        Self {
+            descriptor,
+            compression,
+            rows: 0,
+            locations: vec![],
+            statistics: Some(vec![]),
+        }
+    }
+
+    /// Declares a page written at `offset` with `length` bytes (including its header).
+    /// Dictionary pages are not part of the page index and are ignored.
+    pub fn push(
+        &mut self,
+        header: &PageHeader,
+        page: &[u8],
+        offset: i64,
+        length: usize,
+    ) -> Result<()> {
+        let (num_values, statistics) = match header.type_ {
+            PageType::DATA_PAGE => {
+                let data_header = header.data_page_header.as_ref().ok_or_else(|| {
+                    ArrowError::OutOfSpec("A data page must have a data page header".to_string())
+                })?;
+                (data_header.num_values, data_header.statistics.clone())
+            }
+            PageType::DATA_PAGE_V2 => {
+                let data_header = header.data_page_header_v2.as_ref().ok_or_else(|| {
+                    ArrowError::OutOfSpec("A data page must have a data page header".to_string())
+                })?;
+                (data_header.num_values, data_header.statistics.clone())
+            }
+            _ => return Ok(()),
+        };
+        let rows = if self.descriptor.max_rep_level() == 0 {
+            num_values as i64
+        } else {
+            self.count_rows(header, page, num_values as usize)?
+        };
+
+        self.locations.push(PageLocation {
+            offset,
+            compressed_page_size: length as i32,
+            first_row_index: self.rows,
+        });
+        self.rows += rows;
+        match (self.statistics.as_mut(), statistics) {
+            (Some(pages), Some(statistics)) => pages.push(statistics),
+            _ => self.statistics = None,
+        }
+        Ok(())
+    }
+
+    /// Returns the number of rows of a data page of a repeated column, i.e. the number of
+    /// repetition levels equal to zero.
+    fn count_rows(&self, header: &PageHeader, page: &[u8], num_values: usize) -> Result<i64> {
+        let decompressed;
+        let rep_levels = if let Some(header) = header.data_page_header_v2.as_ref() {
+            // the levels of v2 pages are not compressed nor prefixed by their length
+            let length = header.repetition_levels_byte_length as usize;
+            if header.repetition_levels_byte_length < 0 || length > page.len() {
+                return Err(invalid_levels());
+            }
+            &page[..length]
+        } else {
+            let buffer = if self.compression == Compression::Uncompressed {
+                page
+            } else {
+                let mut buffer = vec![0; header.uncompressed_page_size as usize];
+                decompress(self.compression, page, &mut buffer)?;
+                decompressed = buffer;
+                &decompressed
+            };
+            if buffer.len() < 4 {
+                return Err(invalid_levels());
+            }
+            let length = get_length(buffer) as usize;
+            if length > buffer.len() - 4 {
+                return Err(invalid_levels());
+            }
+            &buffer[4..4 + length]
+        };
+        let num_bits = get_bit_width(self.descriptor.max_rep_level());
+        let rows = HybridRleDecoder::new(rep_levels, num_bits, num_values)
+            .filter(|level| *level == 0)
+            .count();
+        Ok(rows as i64)
+    }
+
+    /// Returns the page index of the column chunk. The [`ColumnIndex`] is `None` when a page
+    /// has no statistics.
+    pub fn finish(self) -> (Option<ColumnIndex>, OffsetIndex) {
+        let offset_index = OffsetIndex {
+            page_locations: self.locations,
+        };
+        let descriptor = self.descriptor;
+        let column_index = self
+            .statistics
+            .map(|pages| column_index(descriptor.type_(), pages));
+        (column_index, offset_index)
+    }
+}
+
+fn invalid_levels() -> ArrowError {
+    ArrowError::OutOfSpec("The repetition levels of a data page exceed the page".to_string())
+}
+
+fn column_index(type_: &ParquetType, pages: Vec<Statistics>) -> ColumnIndex {
+    let order = order(type_);
+    let (physical_type, is_utf8) = match type_ {
+        ParquetType::PrimitiveType {
+            physical_type,
+            logical_type,
+            converted_type,
+            ..
+        } => (
+            Some(*physical_type),
+            matches!(
+                converted_type,
+                Some(PrimitiveConvertedType::Utf8)
+                    | Some(PrimitiveConvertedType::Enum)
+                    | Some(PrimitiveConvertedType::Json)
+            ) || matches!(
+                logical_type,
+                Some(LogicalType::STRING(_))
+                    | Some(LogicalType::ENUM(_))
+                    | Some(LogicalType::JSON(_))
+            ),
+        ),
+        _ => (None, false),
+    };
+    let truncate = physical_type == Some(PhysicalType::ByteArray) && order == Order::Bytes;
+
+    let mut null_pages = Vec::with_capacity(pages.len());
+    let mut min_values = Vec::with_capacity(pages.len());
+    let mut max_values = Vec::with_capacity(pages.len());
+    let mut null_counts = Some(Vec::with_capacity(pages.len()));
+    for statistics in pages {
+        match (&mut null_counts, statistics.null_count) {
+            (Some(null_counts), Some(null_count)) => null_counts.push(null_count),
+            _ => null_counts = None,
+        }
+        match (statistics.min_value, statistics.max_value) {
+            (Some(min), Some(max)) => {
+                null_pages.push(false);
+                if truncate && is_utf8 {
+                    min_values.push(truncate_utf8_min(min));
+                    max_values.push(truncate_utf8_max(max));
+                } else if truncate {
+                    min_values.push(truncate_binary_min(min));
+                    max_values.push(truncate_binary_max(max));
+                } else {
+                    min_values.push(min);
+                    max_values.push(max);
+                }
+            }
+            _ => {
+                // a page without min and max only has nulls
+                null_pages.push(true);
+                min_values.push(vec![]);
+                max_values.push(vec![]);
+            }
+        }
+    }
+
+    let boundary_order = boundary_order(order, &null_pages, &min_values, &max_values);
+    ColumnIndex {
+        null_pages,
+        min_values,
+        max_values,
+        boundary_order,
+        null_counts,
+    }
+}
+
+/// Returns how the (plain-encoded) min and max values of a column of `type_` compare.
+pub(super) fn order(type_: &ParquetType) -> Order {
+    let (physical_type, logical_type, converted_type) = match type_ {
+        ParquetType::PrimitiveType {
+            physical_type,
+            logical_type,
+            converted_type,
+            ..
+        } => (physical_type, logical_type, converted_type),
+        _ => return Order::Undefined,
+    };
+    match (physical_type, logical_type, converted_type) {
+        (_, Some(LogicalType::DECIMAL(_)), _)
+        | (_, _, Some(PrimitiveConvertedType::Decimal(_, _))) => {
+            match physical_type {
+                PhysicalType::Int32 | PhysicalType::Int64 => Order::Signed,
+                // big-endian two's complement, whose order is not the lexicographic order
+                _ => Order::Undefined,
+            }
+        }
+        (_, _, Some(PrimitiveConvertedType::Interval)) => Order::Undefined,
+        (PhysicalType::Int32, _, _) | (PhysicalType::Int64, _, _) => {
+            let unsigned = matches!(
+                converted_type,
+                Some(PrimitiveConvertedType::Uint8)
+                    | Some(PrimitiveConvertedType::Uint16)
+                    | Some(PrimitiveConvertedType::Uint32)
+                    | Some(PrimitiveConvertedType::Uint64)
+            ) || matches!(logical_type, Some(LogicalType::INTEGER(int)) if !int.is_signed);
+            if unsigned {
+                Order::Unsigned
+            } else {
+                Order::Signed
+            }
+        }
+        (PhysicalType::Boolean, _, _) => Order::Unsigned,
+        (PhysicalType::Float, _, _) | (PhysicalType::Double, _, _) => Order::Float,
+        (PhysicalType::ByteArray, _, _) | (PhysicalType::FixedLenByteArray(_), _, _) => {
+            Order::Bytes
+        }
+        (PhysicalType::Int96, _, _) => Order::Undefined,
+    }
+}
+
+/// Compares two plain-encoded values.
+pub(super) fn compare(order: Order, lhs: &[u8], rhs: &[u8]) -> Option<Ordering> {
+    match (order, lhs.len()) {
+        (Order::Signed, 4) => Some(
+            i32::from_le_bytes(lhs.try_into().ok()?).cmp(&i32::from_le_bytes(rhs.try_into().ok()?)),
+        ),
+        (Order::Signed, 8) => Some(
+            i64::from_le_bytes(lhs.try_into().ok()?).cmp(&i64::from_le_bytes(rhs.try_into().ok()?)),
+        ),
+        (Order::Unsigned, 1) => Some(lhs.cmp(rhs)),
+        (Order::Unsigned, 4) => Some(
+            u32::from_le_bytes(lhs.try_into().ok()?).cmp(&u32::from_le_bytes(rhs.try_into().ok()?)),
+        ),
+        (Order::Unsigned, 8) => Some(
+            u64::from_le_bytes(lhs.try_into().ok()?).cmp(&u64::from_le_bytes(rhs.try_into().ok()?)),
+        ),
+        (Order::Float, 4) => f32::from_le_bytes(lhs.try_into().ok()?)
+            .partial_cmp(&f32::from_le_bytes(rhs.try_into().ok()?)),
+        (Order::Float, 8) => f64::from_le_bytes(lhs.try_into().ok()?)
+            .partial_cmp(&f64::from_le_bytes(rhs.try_into().ok()?)),
+        (Order::Bytes, _) => Some(lhs.cmp(rhs)),
+        _ => None,
+    }
+}
+
+/// Returns whether the min and max values of the non-null pages are ordered, and how.
+fn boundary_order(
+    order: Order,
+    null_pages: &[bool],
+    min_values: &[Vec<u8>],
+    max_values: &[Vec<u8>],
+) -> BoundaryOrder {
+    let pages = null_pages
+        .iter()
+        .zip(min_values.iter().zip(max_values.iter()))
+        .filter(|(is_null, _)| !**is_null)
+        .map(|(_, values)| values)
+        .collect::<Vec<_>>();
+
+    let mut ascending = true;
+    let mut descending = true;
+    for window in pages.windows(2) {
+        let (min0, max0) = window[0];
+        let (min1, max1) = window[1];
+        match (compare(order, min0, min1), compare(order, max0, max1)) {
+            (Some(min), Some(max)) => {
+                ascending &= min != Ordering::Greater && max != Ordering::Greater;
+                descending &= min != Ordering::Less && max != Ordering::Less;
+            }
+            _ => return BoundaryOrder::Unordered,
+        }
+    }
+    if order == Order::Undefined {
+        BoundaryOrder::Unordered
+    } else if ascending {
+        BoundaryOrder::Ascending
+    } else if descending {
+        BoundaryOrder::Descending
+    } else {
+        BoundaryOrder::Unordered
+    }
+}
+
+/// Truncates a min value to [`TRUNCATE_LENGTH`] bytes, which is a lower bound of the original.
+fn truncate_binary_min(mut value: Vec<u8>) -> Vec<u8> {
+    value.truncate(TRUNCATE_LENGTH);
+    value
+}
+
+/// Truncates a max value to at most [`TRUNCATE_LENGTH`] bytes by incrementing the last byte that
+/// can be incremented, which is an upper bound of the original. Returns the original value
+/// when no such byte exists.
+fn truncate_binary_max(value: Vec<u8>) -> Vec<u8> {
+    if value.len() <= TRUNCATE_LENGTH {
+        return value;
+    }
+    let mut truncated = value[..TRUNCATE_LENGTH].to_vec();
+    while let Some(last) = truncated.pop() {
+        if last < u8::MAX {
+            truncated.push(last + 1);
+            return truncated;
+        }
+    }
+    value
+}
+
+/// Truncates a min value to at most [`TRUNCATE_LENGTH`] bytes at a char boundary, which is a
+/// lower bound of the original. Values that are not utf8 are truncated as binary.
+fn truncate_utf8_min(value: Vec<u8>) -> Vec<u8> {
+    if value.len() <= TRUNCATE_LENGTH {
+        return value;
+    }
+    match std::str::from_utf8(&value) {
+        Ok(string) => {
+            let mut end = TRUNCATE_LENGTH;
+            while !string.is_char_boundary(end) {
+                end -= 1;
+            }
+            value[..end].to_vec()
+        }
+        Err(_) => truncate_binary_min(value),
+    }
+}
+
+/// Truncates a max value to at most [`TRUNCATE_LENGTH`] bytes at a char boundary by
+/// incrementing the last char that can be incremented, which is a (valid utf8) upper bound of
+/// the original. Returns the original value when no such char exists. Values that are not utf8
+/// are truncated as binary.
+fn truncate_utf8_max(value: Vec<u8>) -> Vec<u8> {
+    if value.len() <= TRUNCATE_LENGTH {
+        return value;
+    }
+    let string = match std::str::from_utf8(&value) {
+        Ok(string) => string,
+        Err(_) => return truncate_binary_max(value),
+    };
+    let mut end = TRUNCATE_LENGTH;
+    while !string.is_char_boundary(end) {
+        end -= 1;
+    }
+    let mut chars = string[..end].chars().collect::<Vec<_>>();
+    while let Some(last) = chars.pop() {
+        let next = (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32);
+        if let Some(next) = next {
+            chars.push(next);
+            let truncated = chars.into_iter().collect::<String>();
+            if truncated.len() <= TRUNCATE_LENGTH {
+                return truncated.into_bytes();
+            }
+            chars = truncated.chars().collect();
+            chars.pop();
+        }
+    }
+    value
+}
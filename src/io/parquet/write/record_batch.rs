@@ -1,9 +1,10 @@
 use parquet2::write::Compressor;
 use parquet2::FallibleStreamingIterator;
 
+use super::super::bloom_filter::{can_hash, BloomFilter};
 use super::{
-    array_to_pages, to_parquet_schema, DynIter, DynStreamingIterator, Encoding, RowGroupIter,
-    SchemaDescriptor, WriteOptions,
+    array_to_pages, to_leaves, to_parquet_schema, BloomFilterOptions, DynIter,
+    DynStreamingIterator, Encoding, RowGroupIter, SchemaDescriptor, WriteOptions,
 };
 use crate::{
     datatypes::Schema,
@@ -47,6 +48,41 @@ impl<'a, I: Iterator<Item = Result<RecordBatch>>> RowGroupIterator<I> {
     }
 }
 
+/// Returns the row group of `batch`, whose columns are written to one or more parquet columns
+/// with the same encoding.
+fn to_row_group(
+    batch: &RecordBatch,
+    parquet_schema: &SchemaDescriptor,
+    options: WriteOptions,
+    encodings: &[Encoding],
+) -> RowGroupIter<'static, ArrowError> {
+    let (columns, encodings): (Vec<_>, Vec<_>) = batch
+        .columns()
+        .iter()
+        .zip(encodings.iter())
+        .flat_map(|(array, encoding)| {
+            to_leaves(array)
+                .into_iter()
+                .map(move |leaf| (leaf, *encoding))
+        })
+        .unzip();
+    DynIter::new(
+        columns
+            .into_iter()
+            .zip(parquet_schema.columns().to_vec().into_iter())
+            .zip(encodings.into_iter())
+            .map(move |((array, descriptor), encoding)| {
+                array_to_pages(array.as_ref(), descriptor, options, encoding).map(move |pages| {
+                    let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
+                    let compressed_pages =
+                        Compressor::new(encoded_pages, options.compression, vec![])
+                            .map_err(ArrowError::from);
+                    DynStreamingIterator::new(compressed_pages)
+                })
+            }),
+    )
+}
+
 impl<I: Iterator<Item = Result<RecordBatch>>> Iterator for RowGroupIterator<I> {
     type Item = Result<RowGroupIter<'static, ArrowError>>;
 
@@ -55,24 +91,103 @@ impl<I: Iterator<Item = Result<RecordBatch>>> Iterator for RowGroupIterator<I> {
 
         self.iter.next().map(|batch| {
             let batch = batch?;
-            let columns = batch.columns().to_vec();
-            let encodings = self.encodings.clone();
-            Ok(DynIter::new(
-                columns
-                    .into_iter()
-                    .zip(self.parquet_schema.columns().to_vec().into_iter())
-                    .zip(encodings.into_iter())
-                    .map(move |((array, descriptor), encoding)| {
-                        array_to_pages(array.as_ref(), descriptor, options, encoding).map(
-                            move |pages| {
-                                let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
-                                let compressed_pages =
-                                    Compressor::new(encoded_pages, options.compression, vec![])
-                                        .map_err(ArrowError::from);
-                                DynStreamingIterator::new(compressed_pages)
-                            },
-                        )
-                    }),
+            Ok(to_row_group(
+                &batch,
+                &self.parquet_schema,
+                options,
+                &self.encodings,
+            ))
+        })
+    }
+}
+
+/// An iterator adapter that converts an iterator over [`RecordBatch`] into an iterator
+/// of row groups, each with one optional [`BloomFilter`] per parquet column.
+/// Use it to create an iterator consumable by
+/// [`write_file_with_bloom_filters`](super::write_file_with_bloom_filters).
+pub struct BloomFilterRowGroupIterator<I: Iterator<Item = Result<RecordBatch>>> {
+    iter: RowGroupIterator<I>,
+    bloom_filters: Vec<Option<BloomFilterOptions>>,
+}
+
+impl<I: Iterator<Item = Result<RecordBatch>>> BloomFilterRowGroupIterator<I> {
+    /// Creates a new [`BloomFilterRowGroupIterator`] from an iterator over [`RecordBatch`],
+    /// where the fields of `schema` with [`BloomFilterOptions`] are written with a [`BloomFilter`].
+    /// # Errors
+    /// Errors iff `bloom_filters` does not have one entry per field of `schema`, iff
+    /// a field with [`BloomFilterOptions`] is not of a type supported by [`can_hash`], or iff
+    /// a false positive probability is not in `(0, 1)`.
+    pub fn try_new(
+        iter: I,
+        schema: &Schema,
+        options: WriteOptions,
+        encodings: Vec<Encoding>,
+        bloom_filters: Vec<Option<BloomFilterOptions>>,
+    ) -> Result<Self> {
+        if schema.fields().len() != bloom_filters.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A schema with {} fields requires as many bloom filter options, but {} were provided",
+                schema.fields().len(),
+                bloom_filters.len()
+            )));
+        }
+        for (field, bloom_filter) in schema.fields().iter().zip(bloom_filters.iter()) {
+            if bloom_filter.is_some() && !can_hash(field.data_type()) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The field \"{}\" of type {:?} cannot be written with a bloom filter",
+                    field.name(),
+                    field.data_type()
+                )));
+            }
+            match bloom_filter {
+                Some(bloom_filter) if !(bloom_filter.fpp > 0.0 && bloom_filter.fpp < 1.0) => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "The false positive probability of a bloom filter must be in (0, 1), but it is {}",
+                        bloom_filter.fpp
+                    )))
+                }
+                _ => {}
+            }
+        }
+        let iter = RowGroupIterator::try_new(iter, schema, options, encodings)?;
+        Ok(Self {
+            iter,
+            bloom_filters,
+        })
+    }
+
+    /// Returns the [`SchemaDescriptor`] of the [`BloomFilterRowGroupIterator`].
+    pub fn parquet_schema(&self) -> &SchemaDescriptor {
+        &self.iter.parquet_schema
+    }
+}
+
+impl<I: Iterator<Item = Result<RecordBatch>>> Iterator for BloomFilterRowGroupIterator<I> {
+    type Item = Result<(RowGroupIter<'static, ArrowError>, Vec<Option<BloomFilter>>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let options = self.iter.options;
+        let bloom_filters = &self.bloom_filters;
+        let parquet_schema = &self.iter.parquet_schema;
+        let encodings = &self.iter.encodings;
+
+        self.iter.iter.next().map(|batch| {
+            let batch = batch?;
+            let mut filters = vec![];
+            for (array, bloom_filter) in batch.columns().iter().zip(bloom_filters.iter()) {
+                match bloom_filter {
+                    // only fields of a single parquet column can be hashed (see `try_new`)
+                    Some(bloom_filter) => {
+                        let mut filter = BloomFilter::new(bloom_filter.ndv, bloom_filter.fpp);
+                        filter.insert_array(array.as_ref())?;
+                        filters.push(Some(filter));
+                    }
+                    None => filters.extend(to_leaves(array).iter().map(|_| None)),
+                }
+            }
+            Ok((
+                to_row_group(&batch, parquet_schema, options, encodings),
+                filters,
             ))
         })
     }
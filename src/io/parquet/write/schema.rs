@@ -2,8 +2,8 @@ use parquet2::{
     metadata::KeyValue,
     schema::{
         types::{
-            DecimalType, IntType, LogicalType, ParquetType, PhysicalType, PrimitiveConvertedType,
-            TimeType, TimeUnit as ParquetTimeUnit, TimestampType,
+            DecimalType, GroupConvertedType, IntType, LogicalType, ParquetType, PhysicalType,
+            PrimitiveConvertedType, TimeType, TimeUnit as ParquetTimeUnit, TimestampType,
         },
         Repetition,
     },
@@ -38,6 +38,32 @@ pub fn schema_to_metadata_key(schema: &Schema) -> KeyValue {
     }
 }
 
+/// Returns `key_value_metadata` with the metadata of `schema` whose keys it does not contain
+/// and the IPC-encoded `schema` under `"ARROW:schema"`, so that both non-arrow readers see
+/// the schema's metadata and arrow readers restore the schema (including its fields' metadata).
+pub fn to_key_value_metadata(
+    schema: &Schema,
+    key_value_metadata: Option<Vec<KeyValue>>,
+) -> Vec<KeyValue> {
+    let mut key_value_metadata = key_value_metadata.unwrap_or_default();
+
+    let mut metadata = schema
+        .metadata()
+        .iter()
+        .filter(|(key, _)| !key_value_metadata.iter().any(|x| &x.key == *key))
+        .map(|(key, value)| KeyValue {
+            key: key.clone(),
+            value: Some(value.clone()),
+        })
+        .collect::<Vec<_>>();
+    // the schema's metadata is a `HashMap`; sort it so that files are deterministic
+    metadata.sort_by(|lhs, rhs| lhs.key.cmp(&rhs.key));
+    key_value_metadata.extend(metadata);
+
+    key_value_metadata.push(schema_to_metadata_key(schema));
+    key_value_metadata
+}
+
 /// Creates a [`ParquetType`] from a [`Field`].
 pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
     let name = field.name().clone();
@@ -343,6 +369,31 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
                 None,
             )?)
         }
+        DataType::Map(f, _) => {
+            let fields = if let DataType::Struct(fields) = f.data_type().to_logical_type() {
+                fields
+            } else {
+                unreachable!()
+            };
+            Ok(ParquetType::try_from_group(
+                name,
+                repetition,
+                Some(GroupConvertedType::Map),
+                Some(LogicalType::MAP(Default::default())),
+                vec![ParquetType::try_from_group(
+                    f.name().clone(),
+                    Repetition::Repeated,
+                    None,
+                    None,
+                    fields
+                        .iter()
+                        .map(to_parquet_type)
+                        .collect::<Result<Vec<_>>>()?,
+                    None,
+                )?],
+                None,
+            )?)
+        }
         other => Err(ArrowError::NotYetImplemented(format!(
             "Writing the data type {:?} is not yet implemented",
             other
@@ -11,7 +11,7 @@ use parquet2::{
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 
-use super::schema::schema_to_metadata_key;
+use super::schema::to_key_value_metadata;
 use super::WriteOptions;
 
 /// Writes
@@ -27,12 +27,7 @@ where
     W: std::io::Write,
     I: Stream<Item = Result<RowGroupIter<'a, ArrowError>>>,
 {
-    let key_value_metadata = key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]));
+    let key_value_metadata = Some(to_key_value_metadata(schema, key_value_metadata));
 
     let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
     Ok(parquet_write_stream(
@@ -59,12 +54,7 @@ where
     W: futures::io::AsyncWrite + Unpin + Send,
     I: Stream<Item = Result<RowGroupIter<'a, ArrowError>>>,
 {
-    let key_value_metadata = key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]));
+    let key_value_metadata = Some(to_key_value_metadata(schema, key_value_metadata));
 
     let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
     Ok(parquet_write_stream_stream(
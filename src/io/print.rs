@@ -1,9 +1,25 @@
 //! APIs to represent [`RecordBatch`] as a formatted table.
 
-use crate::{array::get_display, record_batch::RecordBatch};
+use crate::{
+    array::{get_display, Array},
+    columns::Columns,
+    datatypes::{DataType, Field, Schema},
+    record_batch::RecordBatch,
+};
 
 use comfy_table::{Cell, Table};
 
+/// Options to write [`Columns`] as a table via [`write_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WriteOptions {
+    /// The maximum number of characters of a value. Longer values are truncated and end with `...`.
+    /// `None` does not truncate values.
+    pub max_width: Option<usize>,
+    /// The maximum number of rows to write. When there are more rows, they are replaced
+    /// by a single row of `...`. `None` writes all rows.
+    pub max_rows: Option<usize>,
+}
+
 /// Returns a visual representation of multiple [`RecordBatch`]es.
 pub fn write(batches: &[RecordBatch]) -> String {
     create_table(batches).to_string()
@@ -14,10 +30,153 @@ pub fn print(results: &[RecordBatch]) {
     println!("{}", create_table(results))
 }
 
-/// Convert a series of record batches into a table
-fn create_table(results: &[RecordBatch]) -> Table {
+/// Returns a visual representation of multiple [`Columns`] with the fields of `schema`.
+/// The header of each column contains the name and the [`DataType`] of its field.
+pub fn write_with_schema<A: AsRef<dyn Array>>(schema: &Schema, columns: &[Columns<A>]) -> String {
+    write_with_options(schema, columns, &WriteOptions::default())
+}
+
+/// Returns a visual representation of multiple [`Columns`] with the fields of `schema`,
+/// formatted according to `options`.
+/// The header of each column contains the name and the [`DataType`] of its field.
+pub fn write_with_options<A: AsRef<dyn Array>>(
+    schema: &Schema,
+    columns: &[Columns<A>],
+    options: &WriteOptions,
+) -> String {
+    let header = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            Cell::new(format!(
+                "{}\n{}",
+                field.name(),
+                data_type_name(field.data_type())
+            ))
+        })
+        .collect::<Vec<_>>();
+    write_table(header, columns, options)
+}
+
+/// Returns a visual representation of multiple [`Columns`] without a schema.
+/// The header of each column contains its [`DataType`], taken from the first [`Columns`].
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::columns::Columns;
+/// use arrow2::io::print::write_columns;
+///
+/// let columns = Columns::new(vec![
+///     Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+///     Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"])),
+/// ]);
+/// assert_eq!(
+///     write_columns(&[columns]),
+///     "+-------+------+\n\
+///      | Int32 | Utf8 |\n\
+///      +-------+------+\n\
+///      | 1     | a    |\n\
+///      |       | b    |\n\
+///      +-------+------+"
+/// );
+/// ```
+pub fn write_columns<A: AsRef<dyn Array>>(columns: &[Columns<A>]) -> String {
+    let header = columns
+        .first()
+        .map(|columns| {
+            columns
+                .iter()
+                .map(|array| Cell::new(data_type_name(array.as_ref().data_type())))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    write_table(header, columns, &WriteOptions::default())
+}
+
+fn write_table<A: AsRef<dyn Array>>(
+    header: Vec<Cell>,
+    columns: &[Columns<A>],
+    options: &WriteOptions,
+) -> String {
+    let mut table = new_table();
+    let num_fields = header.len();
+    table.set_header(header);
+
+    let mut remaining = options.max_rows.unwrap_or(usize::MAX);
+    for columns in columns {
+        let displays = columns
+            .iter()
+            .map(|array| get_display(array.as_ref()))
+            .collect::<Vec<_>>();
+
+        for row in 0..columns.len() {
+            if remaining == 0 {
+                table.add_row(vec![Cell::new("..."); num_fields]);
+                return table.to_string();
+            }
+            remaining -= 1;
+
+            let cells = displays
+                .iter()
+                .map(|display| Cell::new(truncate(display(row), options.max_width)))
+                .collect::<Vec<_>>();
+            table.add_row(cells);
+        }
+    }
+    table.to_string()
+}
+
+fn new_table() -> Table {
     let mut table = Table::new();
     table.load_preset("||--+-++|    ++++++");
+    table
+}
+
+fn truncate(string: String, max_width: Option<usize>) -> String {
+    match max_width {
+        Some(max_width) if string.chars().count() > max_width => {
+            let mut truncated = string
+                .chars()
+                .take(max_width.saturating_sub(3))
+                .collect::<String>();
+            truncated.push_str("...");
+            truncated
+        }
+        _ => string,
+    }
+}
+
+/// A compact representation of a [`DataType`], where the fields of nested types are
+/// represented by their data type only (struct fields also by their name).
+fn data_type_name(data_type: &DataType) -> String {
+    let field_name = |field: &Field| data_type_name(field.data_type());
+    match data_type {
+        DataType::List(field) => format!("List({})", field_name(field)),
+        DataType::LargeList(field) => format!("LargeList({})", field_name(field)),
+        DataType::FixedSizeList(field, size) => {
+            format!("FixedSizeList({}, {})", field_name(field), size)
+        }
+        DataType::Map(field, _) => format!("Map({})", field_name(field)),
+        DataType::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| format!("{}: {}", field.name(), field_name(field)))
+                .collect::<Vec<_>>();
+            format!("Struct({})", fields.join(", "))
+        }
+        DataType::Dictionary(key_type, values, _) => {
+            format!("Dictionary({:?}, {})", key_type, data_type_name(values))
+        }
+        DataType::Timestamp(unit, Some(tz)) => format!("Timestamp({:?}, {})", unit, tz),
+        DataType::Extension(name, _, _) => name.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// Convert a series of record batches into a table
+fn create_table(results: &[RecordBatch]) -> Table {
+    let mut table = new_table();
 
     if results.is_empty() {
         return table;
@@ -5,11 +5,15 @@
 #![allow(clippy::len_without_is_empty)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc_tracking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc_tracking")))]
+pub mod alloc;
 #[macro_use]
 pub mod array;
 pub mod bitmap;
 pub mod buffer;
-pub(crate) mod columns;
+pub mod chunked;
+pub mod columns;
 pub mod error;
 pub mod scalar;
 pub mod trusted_len;
@@ -19,6 +23,7 @@ pub mod compute;
 pub mod io;
 pub mod record_batch;
 pub mod temporal_conversions;
+pub mod testing;
 
 pub mod datatypes;
 
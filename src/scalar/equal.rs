@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use super::*;
-use crate::types::days_ms;
+use crate::types::{days_ms, f16, months_days_ns};
 
 impl PartialEq for dyn Scalar + '_ {
     fn eq(&self, that: &dyn Scalar) -> bool {
@@ -88,7 +88,12 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
         DataType::Interval(IntervalUnit::DayTime) => {
             dyn_eq!(days_ms, lhs, rhs)
         }
-        DataType::Float16 => unreachable!(),
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            dyn_eq!(months_days_ns, lhs, rhs)
+        }
+        DataType::Float16 => {
+            dyn_eq!(f16, lhs, rhs)
+        }
         DataType::Float32 => {
             dyn_eq!(f32, lhs, rhs)
         }
@@ -125,6 +130,22 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
             let rhs = rhs.as_any().downcast_ref::<ListScalar<i64>>().unwrap();
             lhs == rhs
         }
+        DataType::FixedSizeBinary(_) => {
+            let lhs = lhs
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            let rhs = rhs
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            lhs == rhs
+        }
+        DataType::FixedSizeList(_, _) => {
+            let lhs = lhs.as_any().downcast_ref::<FixedSizeListScalar>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<FixedSizeListScalar>().unwrap();
+            lhs == rhs
+        }
         DataType::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
             let lhs = lhs.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
             let rhs = rhs.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
@@ -135,6 +156,11 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
             let rhs = rhs.as_any().downcast_ref::<StructScalar>().unwrap();
             lhs == rhs
         }
+        DataType::Union(_, _, _) => {
+            let lhs = lhs.as_any().downcast_ref::<UnionScalar>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<UnionScalar>().unwrap();
+            lhs == rhs
+        }
         other => unimplemented!("{:?}", other),
     }
 }
@@ -0,0 +1,50 @@
+use crate::{array::FixedSizeBinaryArray, datatypes::DataType};
+
+use super::Scalar;
+
+/// The [`Scalar`] implementation of fixed size binary ([`Option<Vec<u8>>`]).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FixedSizeBinaryScalar {
+    value: Option<Vec<u8>>,
+    data_type: DataType,
+}
+
+impl FixedSizeBinaryScalar {
+    /// Returns a new [`FixedSizeBinaryScalar`].
+    /// # Panics
+    /// iff
+    /// * the `data_type` is not `FixedSizeBinary`
+    /// * the size of child binary is not equal
+    #[inline]
+    pub fn new<P: Into<Vec<u8>>>(data_type: DataType, value: Option<P>) -> Self {
+        let size = FixedSizeBinaryArray::get_size(&data_type);
+        let value = value.map(|x| x.into());
+        if let Some(value) = &value {
+            assert_eq!(value.len(), size);
+        }
+        Self { value, data_type }
+    }
+
+    /// Its value
+    #[inline]
+    pub fn value(&self) -> Option<&[u8]> {
+        self.value.as_ref().map(|x| x.as_ref())
+    }
+}
+
+impl Scalar for FixedSizeBinaryScalar {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.value.is_some()
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+}
@@ -3,8 +3,8 @@ use crate::{datatypes::DataType, error::ArrowError, types::NativeType};
 use super::Scalar;
 
 /// The implementation of [`Scalar`] for primitive, semantically equivalent to [`Option<T>`]
-/// with [`DataType`].
-#[derive(Debug, Clone, PartialEq)]
+/// with [`DataType`]. It implements [`Eq`] and [`Hash`] iff `T` does (i.e. not for floats).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PrimitiveScalar<T: NativeType> {
     value: Option<T>,
     data_type: DataType,
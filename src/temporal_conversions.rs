@@ -2,8 +2,9 @@
 
 use chrono::{
     format::{parse, Parsed, StrftimeItems},
-    Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime,
+    Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc,
 };
+use std::convert::TryFrom;
 
 use crate::error::Result;
 use crate::{
@@ -244,6 +245,94 @@ pub fn utf8_to_naive_timestamp_ns_scalar(value: &str, fmt: &str) -> Option<i64>
         .ok()
 }
 
+/// Converts `datetime` (in UTC) to a timestamp in `time_unit`, truncating the precision
+/// finer than `time_unit`.
+/// Returns `None` iff the timestamp is not representable by an `i64` in `time_unit`.
+#[inline]
+pub fn naive_datetime_to_timestamp(datetime: &NaiveDateTime, time_unit: TimeUnit) -> Option<i64> {
+    let datetime = datetime.and_utc();
+    match time_unit {
+        TimeUnit::Second => Some(datetime.timestamp()),
+        TimeUnit::Millisecond => Some(datetime.timestamp_millis()),
+        TimeUnit::Microsecond => Some(datetime.timestamp_micros()),
+        TimeUnit::Nanosecond => datetime.timestamp_nanos_opt(),
+    }
+}
+
+/// Parses `value` to a timestamp in `time_unit` consistent with the Arrow's definition of
+/// timestamp with timezone, where `tz` is built from the timezone (either via [`parse_offset`]
+/// or `chrono-tz`).
+/// # Implementation
+/// * values with an offset (RFC3339, e.g. `"2021-01-01T00:00:00+02:00"`) are converted
+///   to the instant they represent, independently of `tz`;
+/// * values without an offset (e.g. `"2021-01-01T00:00:00"`) are the wall-clock time in `tz`.
+///   An ambiguous wall-clock time (e.g. when daylight saving time ends) is its earliest instant,
+///   a non-existent one (e.g. when daylight saving time starts) is `None`;
+/// * the precision finer than `time_unit` is truncated;
+/// * values that are not parsable or not representable in `time_unit` are `None`.
+#[inline]
+pub fn utf8_to_timestamp_scalar<T: chrono::TimeZone>(
+    value: &str,
+    tz: &T,
+    time_unit: TimeUnit,
+) -> Option<i64> {
+    let datetime = match chrono::DateTime::parse_from_rfc3339(value) {
+        Ok(datetime) => datetime.naive_utc(),
+        Err(_) => {
+            let datetime = value.parse::<NaiveDateTime>().ok()?;
+            tz.from_local_datetime(&datetime).earliest()?.naive_utc()
+        }
+    };
+    naive_datetime_to_timestamp(&datetime, time_unit)
+}
+
+/// Parses `value` to a timestamp in `time_unit` consistent with the Arrow's definition of
+/// timestamp without timezone, e.g. `"2021-01-01T00:00:00"`.
+/// The precision finer than `time_unit` is truncated; values that are not parsable or
+/// not representable in `time_unit` are `None`.
+#[inline]
+pub fn utf8_to_naive_timestamp_scalar(value: &str, time_unit: TimeUnit) -> Option<i64> {
+    let datetime = value.parse::<NaiveDateTime>().ok()?;
+    naive_datetime_to_timestamp(&datetime, time_unit)
+}
+
+/// The timezone of a [`DataType::Timestamp`], parsed either via [`parse_offset`] or, with the
+/// feature `"chrono-tz"`, as an IANA timezone.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Timezone {
+    Offset(FixedOffset),
+    #[cfg(feature = "chrono-tz")]
+    Tz(chrono_tz::Tz),
+}
+
+impl Timezone {
+    /// Parses `timezone`.
+    /// # Error
+    /// Errors iff `timezone` is not parsable to an offset.
+    pub(crate) fn try_new(timezone: &str) -> Result<Self> {
+        match parse_offset(timezone) {
+            Ok(offset) => Ok(Self::Offset(offset)),
+            #[cfg(feature = "chrono-tz")]
+            Err(_) => parse_offset_tz(timezone).map(Self::Tz),
+            #[cfg(not(feature = "chrono-tz"))]
+            Err(_) => Err(ArrowError::InvalidArgumentError(format!(
+                "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+                timezone
+            ))),
+        }
+    }
+
+    /// [`utf8_to_timestamp_scalar`] in this timezone.
+    #[inline]
+    pub(crate) fn utf8_to_timestamp_scalar(&self, value: &str, time_unit: TimeUnit) -> Option<i64> {
+        match self {
+            Self::Offset(offset) => utf8_to_timestamp_scalar(value, offset, time_unit),
+            #[cfg(feature = "chrono-tz")]
+            Self::Tz(tz) => utf8_to_timestamp_scalar(value, tz, time_unit),
+        }
+    }
+}
+
 fn utf8_to_timestamp_ns_impl<O: Offset, T: chrono::TimeZone>(
     array: &Utf8Array<O>,
     fmt: &str,
@@ -327,47 +416,61 @@ pub fn utf8_to_naive_timestamp_ns<O: Offset>(
     PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(TimeUnit::Nanosecond, None))
 }
 
-fn add_month(year: i32, month: u32, months: i32) -> chrono::NaiveDate {
-    let new_year = (year * 12 + (month - 1) as i32 + months) / 12;
-    let new_month = (year * 12 + (month - 1) as i32 + months) % 12 + 1;
-    chrono::NaiveDate::from_ymd(new_year, new_month as u32, 1)
+/// Adds `months` to `date`, clamping the day to the last day of the resulting month
+/// (e.g. `2021-01-31` plus one month is `2021-02-28`).
+/// # Errors
+/// Errors iff the resulting date is out of the range of [`NaiveDate`].
+fn add_months(date: NaiveDate, months: i32) -> Result<NaiveDate> {
+    let months = (date.year() * 12 + date.month0() as i32)
+        .checked_add(months)
+        .ok_or(ArrowError::Overflow)?;
+    let year = months.div_euclid(12);
+    let month = months.rem_euclid(12) as u32 + 1;
+
+    let first_of_next_month = if month == 12 {
+        year.checked_add(1)
+            .and_then(|year| NaiveDate::from_ymd_opt(year, 1, 1))
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    };
+    // the last day of the month is the day before the first day of the next month. When the
+    // next month is out of range, the month is the last one of the range, i.e. December.
+    let last_day = first_of_next_month
+        .and_then(|date| date.pred_opt())
+        .map_or(31, |date| date.day());
+    NaiveDate::from_ymd_opt(year, month, date.day().min(last_day)).ok_or(ArrowError::Overflow)
 }
 
-fn get_days_between_months(year: i32, month: u32, months: i32) -> i64 {
-    add_month(year, month, months)
-        .signed_duration_since(chrono::NaiveDate::from_ymd(year, month, 1))
-        .num_days()
+fn get_days_between_months(date: NaiveDate, months: i32) -> Result<i64> {
+    Ok(add_months(date, months)?
+        .signed_duration_since(date)
+        .num_days())
 }
 
 /// Adds an `interval` to a `timestamp` in `time_unit` units without timezone.
+/// # Panics
+/// Panics iff the result is out of range; use [`try_add_naive_interval`] to error instead.
 #[inline]
 pub fn add_naive_interval(timestamp: i64, time_unit: TimeUnit, interval: months_days_ns) -> i64 {
-    // convert seconds to a DateTime of a given offset.
-    let datetime = match time_unit {
-        TimeUnit::Second => timestamp_s_to_datetime(timestamp),
-        TimeUnit::Millisecond => timestamp_ms_to_datetime(timestamp),
-        TimeUnit::Microsecond => timestamp_us_to_datetime(timestamp),
-        TimeUnit::Nanosecond => timestamp_ns_to_datetime(timestamp),
-    };
-
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days = get_days_between_months(datetime.year(), datetime.month(), interval.months())
-        + interval.days() as i64;
-
-    // add; no leap hours are considered
-    let new_datetime_tz = datetime
-        + chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
+    try_add_naive_interval(timestamp, time_unit, interval).unwrap()
+}
 
-    // convert back to the target unit
-    match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos(),
-    }
+/// Adds an `interval` to a `timestamp` in `time_unit` units without timezone.
+/// # Errors
+/// Errors with [`ArrowError::Overflow`] iff the result is out of range.
+#[inline]
+pub fn try_add_naive_interval(
+    timestamp: i64,
+    time_unit: TimeUnit,
+    interval: months_days_ns,
+) -> Result<i64> {
+    // without timezone there are no leap hours, which is equivalent to UTC
+    try_add_interval(timestamp, time_unit, interval, &Utc)
 }
 
 /// Adds an `interval` to a `timestamp` in `time_unit` units and timezone `timezone`.
+/// # Panics
+/// Panics iff the result is out of range; use [`try_add_interval`] to error instead.
 #[inline]
 pub fn add_interval<T: chrono::TimeZone>(
     timestamp: i64,
@@ -375,23 +478,64 @@ pub fn add_interval<T: chrono::TimeZone>(
     interval: months_days_ns,
     timezone: &T,
 ) -> i64 {
+    try_add_interval(timestamp, time_unit, interval, timezone).unwrap()
+}
+
+/// Adds an `interval` to a `timestamp` in `time_unit` units and timezone `timezone`.
+/// # Errors
+/// Errors with [`ArrowError::Overflow`] iff the result is out of range.
+#[inline]
+pub fn try_add_interval<T: chrono::TimeZone>(
+    timestamp: i64,
+    time_unit: TimeUnit,
+    interval: months_days_ns,
+    timezone: &T,
+) -> Result<i64> {
     // convert seconds to a DateTime of a given offset.
     let datetime_tz = timestamp_to_datetime(timestamp, time_unit, timezone);
 
     // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days =
-        get_days_between_months(datetime_tz.year(), datetime_tz.month(), interval.months())
-            + interval.days() as i64;
+    let delta_days = get_days_between_months(datetime_tz.naive_local().date(), interval.months())?
+        + interval.days() as i64;
 
     // add; tz will take care of leap hours
+    // (the days are bounded by the range of dates plus `i32::MAX` and thus fit in a duration)
     let new_datetime_tz = datetime_tz
-        + chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
+        .checked_add_signed(chrono::Duration::days(delta_days))
+        .and_then(|datetime| {
+            datetime.checked_add_signed(chrono::Duration::nanoseconds(interval.ns()))
+        })
+        .ok_or(ArrowError::Overflow)?;
 
     // convert back to the target unit
     match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos(),
+        TimeUnit::Second => Ok(new_datetime_tz.timestamp()),
+        TimeUnit::Millisecond => Ok(new_datetime_tz.timestamp_millis()),
+        TimeUnit::Microsecond => Ok(new_datetime_tz.timestamp_micros()),
+        TimeUnit::Nanosecond => new_datetime_tz
+            .timestamp_nanos_opt()
+            .ok_or(ArrowError::Overflow),
     }
 }
+
+/// Adds an `interval` to a [`DataType::Date32`] (days since epoch).
+/// Nanoseconds carry over to days, rounding towards negative infinity.
+/// # Errors
+/// Errors with [`ArrowError::Overflow`] iff the result is out of range.
+#[inline]
+pub fn add_interval_date32(days: i32, interval: months_days_ns) -> Result<i32> {
+    let milliseconds = try_add_naive_interval(
+        days as i64 * MILLISECONDS_IN_DAY,
+        TimeUnit::Millisecond,
+        interval,
+    )?;
+    i32::try_from(milliseconds.div_euclid(MILLISECONDS_IN_DAY)).map_err(|_| ArrowError::Overflow)
+}
+
+/// Adds an `interval` to a [`DataType::Date64`] (milliseconds since epoch).
+/// # Errors
+/// Errors with [`ArrowError::Overflow`] iff the result is out of range.
+#[inline]
+pub fn add_interval_date64(milliseconds: i64, interval: months_days_ns) -> Result<i64> {
+    try_add_naive_interval(milliseconds, TimeUnit::Millisecond, interval)
+}
@@ -0,0 +1,228 @@
+//! Helpers to compare [`Array`]s and [`Columns`](crate::columns::Columns) in tests.
+//!
+//! [`assert_columns_eq`] and [`columns_from_csv_str`] require the `testing` feature.
+#[cfg(feature = "testing")]
+use std::sync::Arc;
+
+use num_traits::AsPrimitive;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::PhysicalType;
+use crate::types::{NativeType, PrimitiveType};
+#[cfg(feature = "testing")]
+use crate::{columns::Columns, datatypes::DataType, error::ArrowError, error::Result};
+
+/// Asserts that two arrays of floats are approximately equal, i.e. that every pair of slots
+/// satisfies `|lhs - rhs| <= atol + rtol * |rhs|`.
+///
+/// Nulls must be in the same slots of both arrays and `NaN`s are considered equal to each other.
+/// # Panics
+/// This function panics iff:
+/// * the arrays have different data types or lengths
+/// * the arrays are not of physical type `Float32` or `Float64`
+/// * any slot differs, in which case the first differing index is reported
+pub fn assert_allclose(lhs: &dyn Array, rhs: &dyn Array, rtol: f64, atol: f64) {
+    assert_eq!(
+        lhs.data_type(),
+        rhs.data_type(),
+        "arrays have different data types"
+    );
+    assert_eq!(lhs.len(), rhs.len(), "arrays have different lengths");
+
+    match lhs.data_type().to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::Float32) => allclose::<f32>(lhs, rhs, rtol, atol),
+        PhysicalType::Primitive(PrimitiveType::Float64) => allclose::<f64>(lhs, rhs, rtol, atol),
+        _ => panic!(
+            "assert_allclose only supports Float32 and Float64 arrays, got {:?}",
+            lhs.data_type()
+        ),
+    }
+}
+
+fn allclose<T: NativeType + AsPrimitive<f64>>(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    rtol: f64,
+    atol: f64,
+) {
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+
+    lhs.iter()
+        .zip(rhs.iter())
+        .enumerate()
+        .for_each(|(index, (l, r))| {
+            let is_close = match (l, r) {
+                (Some(l), Some(r)) => {
+                    let (l, r): (f64, f64) = (l.as_(), r.as_());
+                    l == r || (l.is_nan() && r.is_nan()) || (l - r).abs() <= atol + rtol * r.abs()
+                }
+                (None, None) => true,
+                _ => false,
+            };
+            assert!(
+                is_close,
+                "arrays differ at index {}: {:?} vs {:?} (rtol {}, atol {})",
+                index, l, r, rtol, atol
+            );
+        });
+}
+
+/// Asserts that `batches`, formatted as a table by [`write_columns`](crate::io::print::write_columns),
+/// are equal to `expected_lines`. The header of the table contains the data type of each column.
+///
+/// On mismatch, the panic message contains both tables and a line diff between them, where
+/// lines only in the expected table start with `-` and lines only in the actual table with `+`.
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::columns::Columns;
+/// use arrow2::testing::assert_columns_eq;
+///
+/// let columns = Columns::new(vec![
+///     Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+///     Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"])),
+/// ]);
+/// assert_columns_eq(
+///     &[
+///         "+-------+------+",
+///         "| Int32 | Utf8 |",
+///         "+-------+------+",
+///         "| 1     | a    |",
+///         "|       | b    |",
+///         "+-------+------+",
+///     ],
+///     &[columns],
+/// );
+/// ```
+/// # Panics
+/// This function panics iff the formatted `batches` differ from `expected_lines`.
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub fn assert_columns_eq<A: AsRef<dyn Array>>(expected_lines: &[&str], batches: &[Columns<A>]) {
+    let formatted = crate::io::print::write_columns(batches);
+    let actual_lines = formatted.lines().collect::<Vec<_>>();
+    if expected_lines != actual_lines.as_slice() {
+        panic!(
+            "columns are different\n\nexpected:\n{}\n\nactual:\n{}\n\ndiff:\n{}\n",
+            expected_lines.join("\n"),
+            formatted,
+            diff(expected_lines, &actual_lines)
+        );
+    }
+}
+
+/// Returns the lines of `expected` and `actual` as an edit script from `expected` to `actual`
+/// based on their longest common subsequence.
+#[cfg(feature = "testing")]
+fn diff(expected: &[&str], actual: &[&str]) -> String {
+    // `lengths[i][j]` is the length of the longest common subsequence of `expected[i..]` and `actual[j..]`
+    let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = if expected[i] == actual[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() && j < actual.len() {
+        if expected[i] == actual[j] {
+            lines.push(format!("  {}", expected[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            lines.push(format!("- {}", expected[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", actual[j]));
+            j += 1;
+        }
+    }
+    lines.extend(expected[i..].iter().map(|line| format!("- {}", line)));
+    lines.extend(actual[j..].iter().map(|line| format!("+ {}", line)));
+    lines.join("\n")
+}
+
+/// Returns [`Columns`] from `data`, a CSV without header whose columns are of `data_types`.
+///
+/// Leading and trailing whitespace of each value is ignored, so that `data` can be indented
+/// inline. Empty values are deserialized as nulls, except for (large) utf8 and binary columns,
+/// where they are empty values.
+/// # Example
+/// ```rust
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::datatypes::DataType;
+/// use arrow2::testing::columns_from_csv_str;
+///
+/// let columns = columns_from_csv_str(
+///     "1, a
+///      , b",
+///     &[DataType::Int32, DataType::Utf8],
+/// )
+/// .unwrap();
+/// let expected = Int32Array::from(&[Some(1), None]);
+/// assert_eq!(columns[0].as_ref(), &expected as &dyn Array);
+/// let expected = Utf8Array::<i32>::from_slice(&["a", "b"]);
+/// assert_eq!(columns[1].as_ref(), &expected as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff `data` is not a valid CSV, a row does not have one value per
+/// data type or a value cannot be parsed as its data type.
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub fn columns_from_csv_str(
+    data: &str,
+    data_types: &[DataType],
+) -> Result<Columns<Arc<dyn Array>>> {
+    use crate::io::csv::read::{deserialize_column, ReaderBuilder};
+
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(data.as_bytes());
+    let rows = reader
+        .byte_records()
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    if let Some((line, row)) = rows
+        .iter()
+        .enumerate()
+        .find(|(_, row)| row.len() != data_types.len())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "row {} has {} values but {} data types were declared",
+            line,
+            row.len(),
+            data_types.len()
+        )));
+    }
+
+    let arrays = data_types
+        .iter()
+        .enumerate()
+        .map(|(column, data_type)| {
+            let array = deserialize_column(&rows, column, data_type.clone(), 0)?;
+            // the CSV reader deserializes values that it cannot parse as nulls
+            if let Some((line, row)) = rows
+                .iter()
+                .enumerate()
+                .find(|(line, row)| array.is_null(*line) && !row[column].is_empty())
+            {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "value \"{}\" of row {} is not a valid {:?}",
+                    String::from_utf8_lossy(&row[column]),
+                    line,
+                    data_type
+                )));
+            }
+            Ok(array)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Columns::try_new(arrays)
+}
@@ -52,6 +52,8 @@ pub enum PrimitiveType {
     UInt32,
     /// An unsigned 64-bit integer.
     UInt64,
+    /// A 16-bit floating point number.
+    Float16,
     /// A 32-bit floating point number.
     Float32,
     /// A 64-bit floating point number.
@@ -78,4 +80,5 @@ mod private {
     impl Sealed for f64 {}
     impl Sealed for super::days_ms {}
     impl Sealed for super::months_days_ns {}
+    impl Sealed for super::f16 {}
 }
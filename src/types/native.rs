@@ -1,5 +1,9 @@
 use std::convert::TryFrom;
-use std::ops::Neg;
+use std::ops::{Add, Neg, Sub};
+
+use num_traits::{CheckedAdd, CheckedNeg, CheckedSub};
+
+use crate::error::ArrowError;
 
 use super::PrimitiveType;
 
@@ -306,20 +310,518 @@ impl std::fmt::Display for months_days_ns {
     }
 }
 
-impl Neg for days_ms {
-    type Output = Self;
+impl days_ms {
+    /// Adds `rhs` to `self` component-wise, returning `None` if any component overflows.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.days().checked_add(rhs.days())?,
+            self.milliseconds().checked_add(rhs.milliseconds())?,
+        ))
+    }
+
+    /// Subtracts `rhs` from `self` component-wise, returning `None` if any component overflows.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.days().checked_sub(rhs.days())?,
+            self.milliseconds().checked_sub(rhs.milliseconds())?,
+        ))
+    }
+
+    /// Negates every component of `self`, returning `None` if any component is `i32::MIN`.
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::new(
+            self.days().checked_neg()?,
+            self.milliseconds().checked_neg()?,
+        ))
+    }
+}
+
+impl months_days_ns {
+    /// Adds `rhs` to `self` component-wise, returning `None` if any component overflows.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_add(rhs.months())?,
+            self.days().checked_add(rhs.days())?,
+            self.ns().checked_add(rhs.ns())?,
+        ))
+    }
+
+    /// Subtracts `rhs` from `self` component-wise, returning `None` if any component overflows.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_sub(rhs.months())?,
+            self.days().checked_sub(rhs.days())?,
+            self.ns().checked_sub(rhs.ns())?,
+        ))
+    }
+
+    /// Negates every component of `self`, returning `None` if any component is its minimum.
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_neg()?,
+            self.days().checked_neg()?,
+            self.ns().checked_neg()?,
+        ))
+    }
+}
+
+// Components of intervals are independent (e.g. 1000ms are not carried over to days), and
+// are thus added, subtracted and negated independently. Like the kernels of
+// `compute::arithmetics`, these operators panic on overflow; use the `checked_*` variants
+// to handle it.
+macro_rules! interval_ops {
+    ($type:ty) => {
+        impl Add for $type {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                self.checked_add(rhs).expect("attempt to add with overflow")
+            }
+        }
+
+        impl Sub for $type {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.checked_sub(rhs)
+                    .expect("attempt to subtract with overflow")
+            }
+        }
+
+        impl Neg for $type {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                self.checked_neg().expect("attempt to negate with overflow")
+            }
+        }
+
+        impl CheckedAdd for $type {
+            #[inline]
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$type>::checked_add(*self, *rhs)
+            }
+        }
+
+        impl CheckedSub for $type {
+            #[inline]
+            fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                <$type>::checked_sub(*self, *rhs)
+            }
+        }
+
+        impl CheckedNeg for $type {
+            #[inline]
+            fn checked_neg(&self) -> Option<Self> {
+                <$type>::checked_neg(*self)
+            }
+        }
+    };
+}
+
+interval_ops!(days_ms);
+interval_ops!(months_days_ns);
+
+impl From<days_ms> for chrono::Duration {
+    #[inline]
+    fn from(interval: days_ms) -> Self {
+        // `i32::MAX` days are about 5.9 million years, well within the range of a duration
+        chrono::Duration::days(interval.days() as i64)
+            + chrono::Duration::milliseconds(interval.milliseconds() as i64)
+    }
+}
+
+/// Splits `duration` into whole days (truncated towards zero) and its remainder in nanoseconds.
+fn split_duration(duration: chrono::Duration) -> Result<(i32, i64), ArrowError> {
+    let days = i32::try_from(duration.num_days()).map_err(|_| {
+        ArrowError::InvalidArgumentError(format!(
+            "The duration {} has more days than an interval supports",
+            duration
+        ))
+    })?;
+    // the remainder is shorter than a day and thus fits in nanoseconds
+    let ns = (duration - chrono::Duration::days(days as i64))
+        .num_nanoseconds()
+        .unwrap();
+    Ok((days, ns))
+}
+
+impl TryFrom<chrono::Duration> for days_ms {
+    type Error = ArrowError;
+
+    /// Converts a duration into whole days (truncated towards zero) and milliseconds.
+    /// # Errors
+    /// Errors iff the number of days does not fit in an `i32` or the duration has a
+    /// sub-millisecond component.
+    fn try_from(duration: chrono::Duration) -> Result<Self, Self::Error> {
+        let (days, ns) = split_duration(duration)?;
+        if ns % 1_000_000 != 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The duration {} has a sub-millisecond component, which a days_ms interval does not support",
+                duration
+            )));
+        }
+        Ok(Self::new(days, (ns / 1_000_000) as i32))
+    }
+}
+
+impl TryFrom<months_days_ns> for chrono::Duration {
+    type Error = ArrowError;
+
+    /// Converts an interval into a duration.
+    /// # Errors
+    /// Errors iff the interval has months, whose duration depends on the date it is added to.
+    fn try_from(interval: months_days_ns) -> Result<Self, Self::Error> {
+        if interval.months() != 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The interval {} has months and thus no fixed duration",
+                interval
+            )));
+        }
+        Ok(chrono::Duration::days(interval.days() as i64)
+            + chrono::Duration::nanoseconds(interval.ns()))
+    }
+}
+
+impl TryFrom<chrono::Duration> for months_days_ns {
+    type Error = ArrowError;
+
+    /// Converts a duration into whole days (truncated towards zero) and nanoseconds.
+    /// # Errors
+    /// Errors iff the number of days does not fit in an `i32`.
+    fn try_from(duration: chrono::Duration) -> Result<Self, Self::Error> {
+        let (days, ns) = split_duration(duration)?;
+        Ok(Self::new(0, days, ns))
+    }
+}
+
+/// The in-memory representation of an IEEE 754-2008 half-precision floating point number
+/// (arrow's `HalfFloat`), stored as its bits.
+///
+/// Arithmetic is not supported on this type; convert it to [`f32`] via [`f16::to_f32`].
+/// With the `half` feature, it converts to and from [`half::f16`].
+#[derive(Copy, Clone, Default)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct f16(u16);
+
+impl f16 {
+    /// Returns a new [`f16`] from its bits.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the bits of this [`f16`].
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns whether this [`f16`] is `NaN`.
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        (self.0 & 0x7C00 == 0x7C00) && (self.0 & 0x03FF != 0)
+    }
+
+    /// Converts a [`f32`] to the nearest [`f16`] (ties to even). Values whose magnitude is
+    /// too large to be represented are converted to infinity.
+    pub fn from_f32(value: f32) -> Self {
+        let x = value.to_bits();
+        let sign = x & 0x8000_0000;
+        let exp = x & 0x7F80_0000;
+        let man = x & 0x007F_FFFF;
+
+        let half_sign = sign >> 16;
+        // infinity or NaN, keeping NaNs quiet
+        if exp == 0x7F80_0000 {
+            let nan_bit = if man == 0 { 0 } else { 0x0200 };
+            return Self((half_sign | 0x7C00 | nan_bit | (man >> 13)) as u16);
+        }
+
+        let half_exp = ((exp >> 23) as i32) - 127 + 15;
+        // overflow => infinity
+        if half_exp >= 0x1F {
+            return Self((half_sign | 0x7C00) as u16);
+        }
+
+        // underflow => subnormal or zero
+        if half_exp <= 0 {
+            if 14 - half_exp > 24 {
+                return Self(half_sign as u16);
+            }
+            let man = man | 0x0080_0000;
+            let mut half_man = man >> (14 - half_exp);
+            let round_bit = 1 << (13 - half_exp);
+            if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+                half_man += 1;
+            }
+            return Self((half_sign | half_man) as u16);
+        }
+
+        let half_exp = (half_exp as u32) << 10;
+        let half_man = man >> 13;
+        let round_bit = 0x0000_1000;
+        let bits = half_sign | half_exp | half_man;
+        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+            // may carry into the exponent, which correctly rounds up to infinity
+            Self((bits + 1) as u16)
+        } else {
+            Self(bits as u16)
+        }
+    }
+
+    /// Converts a [`f64`] to the nearest [`f16`] (ties to even). Values whose magnitude is
+    /// too large to be represented are converted to infinity.
+    pub fn from_f64(value: f64) -> Self {
+        let val = value.to_bits();
+        // the lower 32 bits of the mantissa only matter for rounding
+        let x = (val >> 32) as u32;
+        let low = (val as u32 != 0) as u32;
+        let sign = x & 0x8000_0000;
+        let exp = x & 0x7FF0_0000;
+        let man = x & 0x000F_FFFF;
+
+        let half_sign = sign >> 16;
+        // infinity or NaN, keeping NaNs quiet
+        if exp == 0x7FF0_0000 {
+            let nan_bit = if man == 0 && low == 0 { 0 } else { 0x0200 };
+            return Self((half_sign | 0x7C00 | nan_bit | (man >> 10)) as u16);
+        }
+
+        let half_exp = ((exp >> 20) as i32) - 1023 + 15;
+        // overflow => infinity
+        if half_exp >= 0x1F {
+            return Self((half_sign | 0x7C00) as u16);
+        }
+
+        // underflow => subnormal or zero
+        if half_exp <= 0 {
+            if 10 - half_exp > 21 {
+                return Self(half_sign as u16);
+            }
+            let man = man | 0x0010_0000;
+            let mut half_man = man >> (11 - half_exp);
+            let round_bit = 1 << (10 - half_exp);
+            if (man & round_bit) != 0 && ((man & (3 * round_bit - 1)) | low) != 0 {
+                half_man += 1;
+            }
+            return Self((half_sign | half_man) as u16);
+        }
+
+        let half_exp = (half_exp as u32) << 10;
+        let half_man = man >> 10;
+        let round_bit = 0x0000_0200;
+        let bits = half_sign | half_exp | half_man;
+        if (man & round_bit) != 0 && ((man & (3 * round_bit - 1)) | low) != 0 {
+            // may carry into the exponent, which correctly rounds up to infinity
+            Self((bits + 1) as u16)
+        } else {
+            Self(bits as u16)
+        }
+    }
+
+    /// Converts this [`f16`] to [`f32`]. This conversion is lossless.
+    pub fn to_f32(self) -> f32 {
+        let i = self.0 as u32;
+        let sign = (i & 0x8000) << 16;
+        let exp = i & 0x7C00;
+        let man = i & 0x03FF;
+
+        // signed zero
+        if i & 0x7FFF == 0 {
+            return f32::from_bits(sign);
+        }
+
+        // infinity or NaN
+        if exp == 0x7C00 {
+            return if man == 0 {
+                f32::from_bits(sign | 0x7F80_0000)
+            } else {
+                f32::from_bits(sign | 0x7FC0_0000 | (man << 13))
+            };
+        }
+
+        // subnormal => normalize
+        if exp == 0 {
+            let e = (man as u16).leading_zeros() - 6;
+            let exp = (127 - 15 - e) << 23;
+            let man = (man << (14 + e)) & 0x007F_FFFF;
+            return f32::from_bits(sign | exp | man);
+        }
+
+        let exp = (((exp >> 10) as i32 - 15 + 127) as u32) << 23;
+        f32::from_bits(sign | exp | (man << 13))
+    }
+
+    /// Converts this [`f16`] to [`f64`]. This conversion is lossless.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+}
+
+impl PartialEq for f16 {
+    /// IEEE 754 equality: `NaN` is not equal to anything and `-0 == 0`.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_nan() || other.is_nan() {
+            false
+        } else {
+            (self.0 == other.0) || ((self.0 | other.0) & 0x7FFF == 0)
+        }
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl std::fmt::Debug for f16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.to_f32())
+    }
+}
+
+impl std::fmt::Display for f16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_f32())
+    }
+}
+
+impl From<f16> for f32 {
+    #[inline]
+    fn from(value: f16) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<f16> for f64 {
+    #[inline]
+    fn from(value: f16) -> Self {
+        value.to_f64()
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<half::f16> for f16 {
+    #[inline]
+    fn from(value: half::f16) -> Self {
+        Self(value.to_bits())
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<f16> for half::f16 {
+    #[inline]
+    fn from(value: f16) -> Self {
+        half::f16::from_bits(value.0)
+    }
+}
+
+impl NativeType for f16 {
+    const PRIMITIVE: PrimitiveType = PrimitiveType::Float16;
+    type Bytes = [u8; 2];
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_ne_bytes(&self) -> Self::Bytes {
+        self.0.to_ne_bytes()
+    }
 
-    #[inline(always)]
-    fn neg(self) -> Self::Output {
-        Self::new(-self.days(), -self.milliseconds())
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Self(u16::from_be_bytes(bytes))
     }
 }
 
-impl Neg for months_days_ns {
-    type Output = Self;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f16_to_f32_roundtrip() {
+        // every non-NaN `f16` is exactly representable in `f32` and `f64`
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            if value.is_nan() {
+                assert!(value.to_f32().is_nan());
+                assert!(f16::from_f32(value.to_f32()).is_nan());
+                continue;
+            }
+            assert_eq!(f16::from_f32(value.to_f32()).to_bits(), bits);
+            assert_eq!(f16::from_f64(value.to_f64()).to_bits(), bits);
+        }
+    }
+
+    #[test]
+    fn f16_rounding() {
+        assert_eq!(f16::from_f32(1.0).to_bits(), 0x3C00);
+        assert_eq!(f16::from_f32(-2.0).to_bits(), 0xC000);
+        assert_eq!(f16::from_f32(65504.0).to_bits(), 0x7BFF);
+        // overflow
+        assert_eq!(f16::from_f32(65520.0).to_bits(), 0x7C00);
+        assert_eq!(f16::from_f64(-1e10).to_bits(), 0xFC00);
+        // smallest subnormal and underflow
+        assert_eq!(f16::from_f32(5.960_464_5e-8).to_bits(), 0x0001);
+        assert_eq!(f16::from_f64(1e-10).to_bits(), 0x0000);
+        // ties to even: 1 + 2^-11 is halfway between 1 and 1 + 2^-10
+        assert_eq!(f16::from_f32(1.0 + 2f32.powi(-11)).to_bits(), 0x3C00);
+        assert_eq!(f16::from_f64(1.0 + 2f64.powi(-11)).to_bits(), 0x3C00);
+        // ...but anything above it rounds up, including bits only present in `f64`
+        assert_eq!(
+            f16::from_f64(1.0 + 2f64.powi(-11) + 2f64.powi(-40)).to_bits(),
+            0x3C01
+        );
+        assert!(f16::from_f32(f32::NAN).is_nan());
+        assert_eq!(f16::from_f64(f64::INFINITY).to_bits(), 0x7C00);
+    }
 
-    #[inline(always)]
-    fn neg(self) -> Self::Output {
-        Self::new(-self.months(), -self.days(), -self.ns())
+    #[test]
+    fn f16_eq() {
+        assert_eq!(f16::from_bits(0x0000), f16::from_bits(0x8000));
+        assert_ne!(f16::from_bits(0x7E00), f16::from_bits(0x7E00));
+        assert!(f16::from_f32(1.0) < f16::from_f32(2.0));
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn f16_as_half() {
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let expected = half::f16::from_bits(bits);
+            if value.is_nan() {
+                assert!(expected.is_nan());
+                continue;
+            }
+            assert_eq!(value.to_f32(), expected.to_f32());
+            assert_eq!(half::f16::from(value), expected);
+            assert_eq!(f16::from(expected).to_bits(), bits);
+        }
+        let mut x = -70000.0f32;
+        while x < 70000.0 {
+            assert_eq!(f16::from_f32(x).to_bits(), half::f16::from_f32(x).to_bits());
+            x += 0.37;
+        }
     }
 }
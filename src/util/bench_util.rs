@@ -1,9 +1,29 @@
 //! Utilities for benchmarking
 
+use std::ops::Range;
+use std::sync::Arc;
+
+use rand::distributions::uniform::SampleUniform;
 use rand::distributions::{Alphanumeric, Distribution, Standard};
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
-use crate::{array::*, types::NativeType};
+use crate::bitmap::Bitmap;
+use crate::datatypes::{DataType, Field};
+use crate::{
+    array::*,
+    types::{f16, NativeType},
+};
+
+/// A function returning an [`Array`] with the requested length, used to generate
+/// the children of nested arrays.
+pub type ArrayGenerator<'a> = dyn Fn(usize) -> Arc<dyn Array> + 'a;
+
+/// Samples an [`f16`] uniformly in `[0, 1)`, like [`Standard`] does for `f32`.
+impl Distribution<f16> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f16 {
+        f16::from_f32(rng.gen::<f32>())
+    }
+}
 
 /// Returns fixed seedable RNG
 pub fn seedable_rng() -> StdRng {
@@ -70,6 +90,77 @@ where
         .collect()
 }
 
+/// Creates a new [`BooleanArray`] from random values with a pre-set seed.
+pub fn create_boolean_array_with_seed(
+    size: usize,
+    null_density: f32,
+    true_density: f32,
+    seed: u64,
+) -> BooleanArray {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size)
+        .map(|_| {
+            if rng.gen::<f32>() < null_density {
+                None
+            } else {
+                Some(rng.gen::<f32>() < true_density)
+            }
+        })
+        .collect()
+}
+
+/// Creates a new [`PrimitiveArray`] of `data_type` from random values in `range` with
+/// a pre-set seed, e.g. a [`DataType::Date32`] or [`DataType::Timestamp`] array within
+/// a range of dates.
+/// # Panics
+/// Panics iff `data_type` is not supported by `T` (see [`PrimitiveArray::to`]).
+pub fn create_primitive_array_in_range<T>(
+    size: usize,
+    data_type: DataType,
+    range: Range<T>,
+    null_density: f32,
+    seed: u64,
+) -> PrimitiveArray<T>
+where
+    T: NativeType + SampleUniform + PartialOrd,
+{
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..size)
+        .map(|_| {
+            if rng.gen::<f32>() < null_density {
+                None
+            } else {
+                Some(rng.gen_range(range.clone()))
+            }
+        })
+        .collect::<PrimitiveArray<T>>()
+        .to(data_type)
+}
+
+/// Creates a new [`DataType::Decimal`] array from random values with a pre-set seed.
+/// Every value has at most `precision` digits.
+/// # Panics
+/// Panics iff `precision` is not in `1..=38` or `scale > precision`.
+pub fn create_decimal_array(
+    size: usize,
+    precision: usize,
+    scale: usize,
+    null_density: f32,
+    seed: u64,
+) -> PrimitiveArray<i128> {
+    assert!((1..=38).contains(&precision));
+    assert!(scale <= precision);
+    let max = 10i128.pow(precision as u32);
+    create_primitive_array_in_range(
+        size,
+        DataType::Decimal(precision, scale),
+        -max + 1..max,
+        null_density,
+        seed,
+    )
+}
+
 /// Creates an random (but fixed-seeded) [`Utf8Array`] of a given length, number of characters and null density.
 pub fn create_string_array<O: Offset>(
     length: usize,
@@ -94,3 +185,149 @@ pub fn create_string_array<O: Offset>(
         })
         .collect()
 }
+
+/// Creates an random (but fixed-seeded) [`BinaryArray`] of a given length, number of bytes and null density.
+pub fn create_binary_array<O: Offset>(
+    length: usize,
+    size: usize,
+    null_density: f32,
+    seed: u64,
+) -> BinaryArray<O> {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..length)
+        .map(|_| {
+            if rng.gen::<f32>() < null_density {
+                None
+            } else {
+                Some(
+                    (&mut rng)
+                        .sample_iter(Standard)
+                        .take(size)
+                        .collect::<Vec<u8>>(),
+                )
+            }
+        })
+        .collect()
+}
+
+/// Creates an random (but fixed-seeded) [`FixedSizeBinaryArray`] of a given length, number of bytes per item and null density.
+pub fn create_fixed_size_binary_array(
+    length: usize,
+    size: usize,
+    null_density: f32,
+    seed: u64,
+) -> FixedSizeBinaryArray {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let values = (&mut rng)
+        .sample_iter(Standard)
+        .take(length * size)
+        .collect::<Vec<u8>>();
+    let validity = create_validity(&mut rng, length, null_density);
+
+    FixedSizeBinaryArray::from_data(DataType::FixedSizeBinary(size), values.into(), validity)
+}
+
+/// Creates an random (but fixed-seeded) [`ListArray`] of a given length and null density,
+/// whose items have a number of values uniformly distributed in `lengths`.
+/// `values` is called with the total number of values and must return an [`Array`] of that length.
+pub fn create_list_array<O: Offset>(
+    length: usize,
+    lengths: Range<usize>,
+    null_density: f32,
+    seed: u64,
+    values: &ArrayGenerator,
+) -> ListArray<O> {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut offsets = Vec::with_capacity(length + 1);
+    offsets.push(O::default());
+    let mut total = 0usize;
+    for _ in 0..length {
+        if !lengths.is_empty() {
+            total += rng.gen_range(lengths.clone());
+        }
+        offsets.push(O::from_usize(total).unwrap());
+    }
+    let validity = create_validity(&mut rng, length, null_density);
+
+    let values = values(total);
+    assert_eq!(values.len(), total);
+    let data_type = ListArray::<O>::default_datatype(values.data_type().clone());
+    ListArray::<O>::from_data(data_type, offsets.into(), values, validity)
+}
+
+/// Creates an random (but fixed-seeded) [`StructArray`] of a given length and null density,
+/// whose fields are named and generated by `children`. Each generator is called with `length`.
+pub fn create_struct_array(
+    length: usize,
+    null_density: f32,
+    seed: u64,
+    children: &[(&str, &ArrayGenerator)],
+) -> StructArray {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let values = children
+        .iter()
+        .map(|(_, generator)| generator(length))
+        .collect::<Vec<_>>();
+    let fields = children
+        .iter()
+        .zip(values.iter())
+        .map(|((name, _), array)| Field::new(*name, array.data_type().clone(), true))
+        .collect();
+    let validity = create_validity(&mut rng, length, null_density);
+
+    StructArray::from_data(DataType::Struct(fields), values, validity)
+}
+
+/// Creates an random (but fixed-seeded) [`DictionaryArray`] of a given length and null density,
+/// whose values are `cardinality` distinct strings.
+/// # Panics
+/// Panics iff `cardinality` is zero or not representable by `K`.
+pub fn create_dictionary_array<K: DictionaryKey>(
+    length: usize,
+    cardinality: usize,
+    null_density: f32,
+    seed: u64,
+) -> DictionaryArray<K> {
+    assert!(cardinality > 0);
+    assert!(K::from_usize(cardinality - 1).is_some());
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let keys = (0..length)
+        .map(|_| {
+            if rng.gen::<f32>() < null_density {
+                None
+            } else {
+                K::from_usize(rng.gen_range(0..cardinality))
+            }
+        })
+        .collect::<PrimitiveArray<K>>();
+    // the index guarantees that the values are distinct
+    let values = (0..cardinality)
+        .map(|i| {
+            let suffix = (&mut rng)
+                .sample_iter(&Alphanumeric)
+                .take(4)
+                .map(char::from)
+                .collect::<String>();
+            Some(format!("{}{}", i, suffix))
+        })
+        .collect::<Utf8Array<i32>>();
+
+    DictionaryArray::<K>::from_data(keys, Arc::new(values))
+}
+
+fn create_validity(rng: &mut StdRng, length: usize, null_density: f32) -> Option<Bitmap> {
+    if null_density > 0.0 {
+        Some(
+            (0..length)
+                .map(|_| rng.gen::<f32>() >= null_density)
+                .collect(),
+        )
+    } else {
+        None
+    }
+}
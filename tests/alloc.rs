@@ -0,0 +1,97 @@
+//! Tests of [`total_allocated_bytes`]. The counter is global to the process, so these are
+//! kept in their own test binary and run sequentially within a single test.
+#![cfg(feature = "alloc_tracking")]
+use std::sync::Arc;
+
+use arrow2::alloc::total_allocated_bytes;
+use arrow2::array::*;
+use arrow2::bitmap::{Bitmap, MutableBitmap};
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::DataType;
+
+fn buffers() {
+    let before = total_allocated_bytes();
+
+    let buffer = Buffer::<i64>::from(Vec::with_capacity(4));
+    assert_eq!(total_allocated_bytes() - before, 32);
+    // clones and slices share the region
+    let sliced = buffer.clone().slice(0, 0);
+    assert_eq!(total_allocated_bytes() - before, 32);
+    drop(buffer);
+    assert_eq!(total_allocated_bytes() - before, 32);
+    drop(sliced);
+    assert_eq!(total_allocated_bytes(), before);
+
+    // converting back into a `Vec` releases the region from the counter
+    let buffer = Buffer::<i32>::from(vec![1, 2]);
+    assert_eq!(total_allocated_bytes() - before, 8);
+    let vec = buffer.into_mut().unwrap_right();
+    assert_eq!(total_allocated_bytes(), before);
+    drop(vec);
+}
+
+fn bitmaps() {
+    let before = total_allocated_bytes();
+
+    let mut mutable = MutableBitmap::with_capacity(80);
+    mutable.extend_constant(20, true);
+    assert_eq!(total_allocated_bytes(), before);
+    let bitmap: Bitmap = mutable.into();
+    assert_eq!(total_allocated_bytes() - before, 10);
+    let mutable = bitmap.into_mut().unwrap_right();
+    assert_eq!(total_allocated_bytes(), before);
+    drop(mutable);
+}
+
+fn arrays() {
+    let before = total_allocated_bytes();
+
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("bc")]);
+    let allocated = total_allocated_bytes() - before;
+    assert_eq!(allocated as usize, array.get_buffer_memory_size());
+
+    let array = Arc::new(array) as Arc<dyn Array>;
+    let list = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Utf8),
+        Buffer::from(vec![0, 3]),
+        array.clone(),
+        None,
+    );
+    assert_eq!(total_allocated_bytes() - before, allocated + 8);
+    drop(array);
+    assert_eq!(
+        (total_allocated_bytes() - before) as usize,
+        list.get_buffer_memory_size()
+    );
+    drop(list);
+    assert_eq!(total_allocated_bytes(), before);
+}
+
+#[cfg(feature = "compute_take")]
+fn kernels() {
+    use arrow2::compute::take::take;
+
+    let before = total_allocated_bytes();
+    let values = Int32Array::from(&[Some(1), None, Some(3)]);
+    let indices = UInt32Array::from_slice([2, 0, 2, 1]);
+    let allocated = total_allocated_bytes() - before;
+
+    let result = take(&values, &indices).unwrap();
+    assert_eq!(
+        (total_allocated_bytes() - before - allocated) as usize,
+        result.get_buffer_memory_size()
+    );
+    drop(result);
+    assert_eq!(total_allocated_bytes() - before, allocated);
+}
+
+#[cfg(not(feature = "compute_take"))]
+fn kernels() {}
+
+#[test]
+fn total_allocated() {
+    buffers();
+    bitmaps();
+    arrays();
+    kernels();
+}
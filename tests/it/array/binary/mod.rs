@@ -135,3 +135,26 @@ fn value_unchecked_with_wrong_offsets_panics() {
     // even if `0` is in bounds
     unsafe { array.value_unchecked(0) };
 }
+
+#[test]
+fn try_new() {
+    let offsets = Buffer::from_slice([0, 1, 3]);
+    let values = Buffer::from_slice([0, 159, 146]);
+    let array = BinaryArray::<i32>::try_new(DataType::Binary, offsets, values, None).unwrap();
+    assert_eq!(array.value(1), &[159, 146]);
+
+    let cases: Vec<Vec<i32>> = vec![vec![0, 2, 1], vec![0, 4], vec![-1, 1], vec![]];
+    for offsets in cases {
+        let values = Buffer::from_slice([0, 159, 146]);
+        let result = BinaryArray::<i32>::try_new(DataType::Binary, offsets.into(), values, None);
+        assert!(result.is_err());
+    }
+
+    let offsets = Buffer::from_slice([0, 1]);
+    let values = Buffer::from_slice([0]);
+    assert!(
+        BinaryArray::<i32>::try_new(DataType::Utf8, offsets.clone(), values.clone(), None).is_err()
+    );
+    let validity = Some(Bitmap::from([true, false]));
+    assert!(BinaryArray::<i32>::try_new(DataType::Binary, offsets, values, validity).is_err());
+}
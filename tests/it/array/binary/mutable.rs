@@ -107,3 +107,11 @@ fn extend_trusted_len() {
         Some(&Bitmap::from_u8_slice(&[0b00001011], 4))
     );
 }
+
+#[test]
+fn reserve() {
+    let mut a = MutableBinaryArray::<i32>::new();
+    a.reserve(10, 100);
+    assert!(a.offsets_capacity() >= 11);
+    assert!(a.values_capacity() >= 100);
+}
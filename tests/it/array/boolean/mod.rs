@@ -70,3 +70,35 @@ fn from_iter() {
     let a: BooleanArray = iter.collect();
     assert_eq!(a.len(), 2);
 }
+
+#[test]
+fn try_new() {
+    let values = Bitmap::from([true, false, true]);
+    let array = BooleanArray::try_new(DataType::Boolean, values.clone(), None).unwrap();
+    assert_eq!(array.values(), &values);
+
+    assert!(BooleanArray::try_new(DataType::Int32, values.clone(), None).is_err());
+    assert!(BooleanArray::try_new(DataType::Boolean, values, Some(Bitmap::from([true]))).is_err());
+}
+
+#[test]
+#[should_panic]
+fn new_invalid_validity() {
+    BooleanArray::new(
+        DataType::Boolean,
+        Bitmap::from([true, false]),
+        Some(Bitmap::from([true])),
+    );
+}
+
+#[test]
+fn into_data() {
+    let values = Bitmap::from([true, false, true]);
+    let validity = Some(Bitmap::from([true, true, false]));
+    let array = BooleanArray::new(DataType::Boolean, values.clone(), validity.clone());
+
+    let (data_type, new_values, new_validity) = array.into_data();
+    assert_eq!(data_type, DataType::Boolean);
+    assert_eq!(new_values, values);
+    assert_eq!(new_validity, validity);
+}
@@ -1,5 +1,5 @@
-use arrow2::array::{MutableArray, MutableBooleanArray};
-use arrow2::bitmap::MutableBitmap;
+use arrow2::array::{BooleanArray, MutableArray, MutableBooleanArray};
+use arrow2::bitmap::{Bitmap, MutableBitmap};
 use arrow2::datatypes::DataType;
 use arrow2::error::Result;
 
@@ -97,3 +97,49 @@ fn extend_trusted_len_values() {
     );
     assert_eq!(a.values(), &MutableBitmap::from([false, true, false]));
 }
+
+#[test]
+fn extend_from_bitmap() {
+    let bitmap = Bitmap::from([true, false, true, true, false, false, true, false, true]);
+
+    let mut a = MutableBooleanArray::new();
+    a.extend_from_bitmap(&bitmap, 1, 7);
+    assert_eq!(a.validity(), None);
+    assert_eq!(
+        a.values(),
+        &MutableBitmap::from([false, true, true, false, false, true, false])
+    );
+
+    let mut a = MutableBooleanArray::new();
+    a.push(None);
+    a.extend_from_bitmap(&bitmap, 2, 3);
+    assert_eq!(
+        a.validity(),
+        Some(&MutableBitmap::from([false, true, true, true]))
+    );
+    assert_eq!(a.values(), &MutableBitmap::from([false, true, true, false]));
+}
+
+#[test]
+fn extend_constant() {
+    let mut a = MutableBooleanArray::new();
+    a.extend_constant(2, Some(true));
+    assert_eq!(a.validity(), None);
+    a.push(Some(false));
+    a.extend_constant(2, None);
+    a.push(Some(true));
+    a.extend_constant(1, Some(false));
+    let a: BooleanArray = a.into();
+    assert_eq!(
+        a,
+        BooleanArray::from(&[
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            None,
+            Some(true),
+            Some(false)
+        ])
+    );
+}
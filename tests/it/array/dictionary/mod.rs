@@ -1 +1,47 @@
 mod mutable;
+
+use std::sync::Arc;
+
+use arrow2::array::*;
+
+fn with_null_value() -> DictionaryArray<i32> {
+    let values = Arc::new(Utf8Array::<i32>::from([Some("a"), None, Some("c")])) as Arc<dyn Array>;
+    let keys = Int32Array::from([Some(0), Some(1), None, Some(2)]);
+    DictionaryArray::from_data(keys, values)
+}
+
+#[test]
+fn value_validity_merged() {
+    let array = with_null_value();
+    assert_eq!(
+        array.value_validity_merged(),
+        Some([true, false, false, true].into())
+    );
+    // the validity of the array remains the one of its keys
+    assert_eq!(array.null_count(), 1);
+
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a"])) as Arc<dyn Array>;
+    let array = DictionaryArray::<i32>::from_data(Int32Array::from_slice([0, 0]), values);
+    assert_eq!(array.value_validity_merged(), None);
+}
+
+#[test]
+fn equal_null_value() {
+    let array = with_null_value();
+    let keys = Int32Array::from([Some(0), None, None, Some(2)]);
+    let expected = DictionaryArray::<i32>::from_data(keys, array.values().clone());
+    assert_eq!(array, expected);
+
+    let keys = Int32Array::from([Some(0), Some(0), None, Some(2)]);
+    let other = DictionaryArray::<i32>::from_data(keys, array.values().clone());
+    assert!(array != other);
+}
+
+#[test]
+fn display() {
+    let array = with_null_value();
+    assert_eq!(
+        format!("{}", array),
+        "Dictionary(Int32, Utf8, false)[a, None, None, c]"
+    );
+}
@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::error::Result;
+use arrow2::error::{ArrowError, Result};
 
 #[test]
 fn primitive() -> Result<()> {
@@ -38,3 +38,173 @@ fn binary_natural() -> Result<()> {
     assert_eq!(a.values().len(), 2);
     Ok(())
 }
+
+#[test]
+fn fixed_size_binary() -> Result<()> {
+    let data = vec![Some(b"ab"), None, Some(b"cd"), Some(b"ab")];
+
+    let mut a = MutableDictionaryArray::<u8, _>::from(MutableFixedSizeBinaryArray::new(2));
+    a.try_extend(data)?;
+    assert_eq!(a.len(), 4);
+    assert_eq!(a.values().len(), 2);
+    Ok(())
+}
+
+/// A value whose hash collides with every other one
+struct Colliding(&'static str);
+
+impl std::hash::Hash for Colliding {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        0u8.hash(state)
+    }
+}
+
+impl AsRef<str> for Colliding {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+#[test]
+fn hash_collision() -> Result<()> {
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    assert_eq!(a.try_push_valid(Colliding("a"))?, 0);
+    assert_eq!(a.try_push_valid(Colliding("b"))?, 1);
+    assert_eq!(a.try_push_valid(Colliding("b"))?, 1);
+    assert_eq!(a.try_push_valid(Colliding("a"))?, 0);
+
+    let array: DictionaryArray<i32> = a.into();
+    assert_eq!(
+        array.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b"]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn push_valid_returns_key() -> Result<()> {
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    assert_eq!(a.try_push_valid("a")?, 0);
+    assert_eq!(a.try_push_valid("b")?, 1);
+    assert_eq!(a.try_push_valid("a")?, 0);
+    assert_eq!(a.values().len(), 2);
+
+    a.push_key(1);
+    assert!(a.try_push_key(2).is_err());
+    assert!(a.try_push_key(-1).is_err());
+
+    let a: DictionaryArray<i32> = a.into();
+    assert_eq!(a.keys(), &Int32Array::from_slice(&[0, 1, 0, 1]));
+    Ok(())
+}
+
+#[test]
+fn many_values() -> Result<()> {
+    let data = (0..70_000).map(|x| Some(x.to_string()));
+
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_extend(data.clone())?;
+    a.try_extend(data)?;
+    assert_eq!(a.len(), 140_000);
+    assert_eq!(a.values().len(), 70_000);
+
+    let a: DictionaryArray<i32> = a.into();
+    assert_eq!(a.keys().value(70_000 + 69_999), 69_999);
+    Ok(())
+}
+
+#[test]
+fn overflow() {
+    let data = (0..257).map(|x| Some(x.to_string()));
+
+    let mut a = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    assert!(matches!(a.try_extend(data), Err(ArrowError::Overflow)));
+    assert_eq!(a.values().len(), 256);
+}
+
+#[test]
+fn push_scalar() -> Result<()> {
+    use arrow2::datatypes::DataType;
+    use arrow2::scalar::{DictionaryScalar, Utf8Scalar};
+
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_push(Some("a"))?;
+    let data_type = a.data_type().clone();
+    let scalar = |value: Option<&str>| {
+        DictionaryScalar::<i32>::new(
+            data_type.clone(),
+            value.map(|x| std::sync::Arc::new(Utf8Scalar::<i32>::new(Some(x))) as _),
+        )
+    };
+    a.try_push_scalar(&scalar(Some("b")))?;
+    a.try_push_scalar(&scalar(None))?;
+    // values are interned with the values pushed via `try_push`
+    a.try_push_scalar(&scalar(Some("a")))?;
+    a.try_push_scalar(&scalar(Some("b")))?;
+    a.extend_nulls(2);
+
+    assert_eq!(a.values().len(), 2);
+    let a: DictionaryArray<i32> = a.into();
+    assert_eq!(
+        a.keys(),
+        &Int32Array::from(&[Some(0), Some(1), None, Some(0), Some(1), None, None])
+    );
+
+    // the value must be of the data type of the values
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    let scalar = DictionaryScalar::<i32>::new(
+        DataType::Dictionary(
+            arrow2::datatypes::IntegerType::Int32,
+            Box::new(DataType::Utf8),
+            false,
+        ),
+        Some(std::sync::Arc::new(Utf8Scalar::<i64>::new(Some("a")))),
+    );
+    assert!(a.try_push_scalar(&scalar).is_err());
+    assert_eq!(a.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn float() -> Result<()> {
+    use arrow2::datatypes::DataType;
+    use arrow2::scalar::{DictionaryScalar, PrimitiveScalar};
+    use std::sync::Arc;
+
+    let data = vec![
+        Some(1.0f64),
+        None,
+        Some(f64::NAN),
+        Some(1.0),
+        Some(f64::NAN),
+        Some(0.0),
+        Some(-0.0),
+    ];
+
+    let mut a = MutableDictionaryArray::<i32, MutablePrimitiveArray<f64>>::new();
+    a.try_extend(data)?;
+    // NaNs are interned by their bits; `0.0` and `-0.0` are different values
+    assert_eq!(a.values().len(), 4);
+
+    // scalars are interned with the values pushed via `try_extend`
+    let nan = PrimitiveScalar::<f64>::new(DataType::Float64, Some(f64::NAN));
+    let nan = DictionaryScalar::<i32>::new(a.data_type().clone(), Some(Arc::new(nan)));
+    a.try_push_scalar(&nan)?;
+    assert_eq!(a.values().len(), 4);
+
+    let a: DictionaryArray<i32> = a.into();
+    assert_eq!(
+        a.keys(),
+        &Int32Array::from(&[
+            Some(0),
+            None,
+            Some(1),
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(1)
+        ])
+    );
+    Ok(())
+}
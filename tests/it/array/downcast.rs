@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::ArrowError;
+
+fn error_message<T: std::fmt::Debug>(result: arrow2::error::Result<T>) -> String {
+    match result {
+        Err(ArrowError::InvalidArgumentError(message)) => message,
+        other => panic!("expected an InvalidArgumentError, got {:?}", other),
+    }
+}
+
+#[test]
+fn primitive() {
+    let array = Int64Array::from_slice([1, 2]).to(DataType::Date64);
+    let array = &array as &dyn Array;
+
+    assert_eq!(array.as_primitive::<i64>().unwrap().value(1), 2);
+    assert_eq!(
+        error_message(array.as_primitive::<i32>()),
+        "Expected an array of type Int32, but the array is of type Date64"
+    );
+}
+
+#[test]
+fn decimal() {
+    let array = Int128Array::from_slice([1]).to(DataType::Decimal(5, 2));
+    let array = &array as &dyn Array;
+    assert_eq!(array.as_decimal().unwrap().value(0), 1);
+    assert!(array.as_primitive::<i128>().is_ok());
+
+    let array = Int64Array::from_slice([1]);
+    let array = &array as &dyn Array;
+    assert_eq!(
+        error_message(array.as_decimal()),
+        "Expected an array of type Decimal, but the array is of type Int64"
+    );
+}
+
+#[test]
+fn binary_and_utf8() {
+    let array = Utf8Array::<i32>::from_slice(["a"]);
+    let array = &array as &dyn Array;
+    assert_eq!(array.as_utf8().unwrap().value(0), "a");
+    assert_eq!(
+        error_message(array.as_large_utf8()),
+        "Expected an array of type LargeUtf8, but the array is of type Utf8"
+    );
+    assert_eq!(
+        error_message(array.as_binary()),
+        "Expected an array of type Binary, but the array is of type Utf8"
+    );
+
+    let array = BinaryArray::<i64>::from_slice([b"a"]);
+    let array = &array as &dyn Array;
+    assert!(array.as_large_binary().is_ok());
+    assert!(array.as_boolean().is_err());
+}
+
+#[test]
+fn nested() {
+    let values = Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>;
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, false)]);
+    let array = Arc::new(StructArray::from_data(data_type, vec![values], None)) as Arc<dyn Array>;
+    assert_eq!(array.as_ref().as_struct().unwrap().fields().len(), 1);
+    assert_eq!(
+        error_message(array.as_ref().as_list()),
+        "Expected an array of type List, but the array is of type Struct([Field { name: \"a\", data_type: Int32, nullable: false, metadata: {} }])"
+    );
+
+    let array = BooleanArray::from_slice([true]);
+    let array = &array as &dyn Array;
+    assert!(array.as_boolean().is_ok());
+    assert_eq!(
+        error_message(array.as_large_list()),
+        "Expected an array of type LargeList, but the array is of type Boolean"
+    );
+    assert_eq!(
+        error_message(array.as_struct()),
+        "Expected an array of type Struct, but the array is of type Boolean"
+    );
+}
+
+#[test]
+fn dictionary() {
+    let keys = Int8Array::from_slice([0]);
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a"])) as Arc<dyn Array>;
+    let array = DictionaryArray::<i8>::from_data(keys, values);
+    let array = &array as &dyn Array;
+
+    assert!(array.as_dictionary::<i8>().is_ok());
+    assert_eq!(
+        error_message(array.as_dictionary::<u32>()),
+        "Expected an array of type Dictionary(UInt32), but the array is of type Dictionary(Int8, Utf8, false)"
+    );
+}
@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field};
+
+use super::test_equal;
+
+fn all_valid(len: usize) -> Option<Bitmap> {
+    Some(Bitmap::from(vec![true; len]))
+}
+
+#[test]
+fn primitive_validity_none_vs_all_valid() {
+    let lhs = Int32Array::from_slice(&[1, 2, 3]);
+    let rhs = lhs.with_validity(all_valid(3));
+    test_equal(&lhs, &rhs, true);
+}
+
+#[test]
+fn primitive_sliced_vs_copy() {
+    let array = Int32Array::from(&[Some(0), Some(1), None, Some(3), None, Some(5)]);
+    let sliced = array.slice(1, 4);
+    let copy = Int32Array::from(&[Some(1), None, Some(3), None]);
+    test_equal(&sliced, &copy, true);
+
+    // nulls with different values in their slots
+    let other = Int32Array::from_data(
+        DataType::Int32,
+        vec![1, 100, 3, 200].into(),
+        Some(Bitmap::from([true, false, true, false])),
+    );
+    test_equal(&sliced, &other, true);
+}
+
+#[test]
+fn boolean_validity_none_vs_all_valid() {
+    let lhs = BooleanArray::from_slice(&[true, false, true]);
+    let rhs = lhs.with_validity(all_valid(3));
+    test_equal(&lhs, &rhs, true);
+    test_equal(
+        &lhs.slice(1, 2),
+        &BooleanArray::from_slice(&[false, true]),
+        true,
+    );
+}
+
+#[test]
+fn utf8_sliced_vs_copy() {
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("bb"), Some("c")]);
+    let copy = Utf8Array::<i32>::from(&[None, Some("bb"), Some("c")]);
+    test_equal(&array.slice(1, 3), &copy, true);
+    let copy = copy.with_validity(all_valid(3));
+    test_equal(&array.slice(2, 2), &copy.slice(1, 2), true);
+}
+
+#[test]
+fn list_sliced_vs_copy() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![Some(3), None]),
+        Some(vec![]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data.clone()).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let mut copy = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    copy.try_extend(data[1..].to_vec()).unwrap();
+    let copy: ListArray<i32> = copy.into();
+
+    test_equal(&array.slice(1, 3), &copy, true);
+}
+
+#[test]
+fn struct_sliced_vs_copy() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Boolean, true),
+    ];
+    let data_type = DataType::Struct(fields);
+
+    let array = StructArray::from_data(
+        data_type.clone(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), Some(2), None, Some(4)])),
+            Arc::new(BooleanArray::from(&[
+                Some(true),
+                None,
+                Some(false),
+                Some(true),
+            ])),
+        ],
+        Some(Bitmap::from([true, false, true, true])),
+    );
+    let copy = StructArray::from_data(
+        data_type.clone(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(20), None, Some(4)])),
+            Arc::new(BooleanArray::from(&[Some(false), Some(false), Some(true)])),
+        ],
+        Some(Bitmap::from([false, true, true])),
+    );
+    test_equal(&array.slice(1, 3), &copy, true);
+
+    // all-valid bitmap vs no bitmap
+    let lhs = array.slice(2, 2);
+    let rhs = StructArray::from_data(
+        data_type.clone(),
+        vec![
+            Arc::new(Int32Array::from(&[None, Some(4)])),
+            Arc::new(BooleanArray::from(&[Some(false), Some(true)])),
+        ],
+        None,
+    );
+    test_equal(&lhs, &rhs, true);
+
+    let rhs = StructArray::from_data(
+        data_type,
+        vec![
+            Arc::new(Int32Array::from(&[None, Some(5)])),
+            Arc::new(BooleanArray::from(&[Some(false), Some(true)])),
+        ],
+        None,
+    );
+    test_equal(&lhs, &rhs, false);
+}
+
+#[test]
+fn fixed_size_binary_sliced_vs_copy() {
+    let array = FixedSizeBinaryArray::from_iter(
+        vec![Some(b"ab"), None, Some(b"cd"), Some(b"ef")].into_iter(),
+        2,
+    );
+    let copy = FixedSizeBinaryArray::from_iter(vec![None, Some(b"cd")].into_iter(), 2);
+    test_equal(&array.slice(1, 2), &copy, true);
+}
+
+#[test]
+fn dictionary_sliced_vs_copy() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![Some("a"), None, Some("b"), Some("a")])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+
+    let mut copy = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    copy.try_extend(vec![None, Some("b"), Some("a")]).unwrap();
+    let copy: DictionaryArray<i32> = copy.into();
+
+    test_equal(&array.slice(1, 3), &copy, true);
+}
+
+#[test]
+fn fixed_size_list_sliced_vs_copy() {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 2);
+    let array = FixedSizeListArray::from_data(
+        data_type.clone(),
+        Arc::new(Int32Array::from(&[
+            Some(1),
+            Some(2),
+            None,
+            None,
+            Some(3),
+            None,
+        ])),
+        Some(Bitmap::from([true, false, true])),
+    );
+    let copy = FixedSizeListArray::from_data(
+        data_type,
+        Arc::new(Int32Array::from(&[Some(10), Some(20), Some(3), None])),
+        Some(Bitmap::from([false, true])),
+    );
+    test_equal(&array.slice(1, 2), &copy, true);
+}
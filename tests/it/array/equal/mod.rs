@@ -2,6 +2,7 @@ use arrow2::array::*;
 
 mod dictionary;
 mod fixed_size_list;
+mod layout;
 mod list;
 mod primitive;
 mod utf8;
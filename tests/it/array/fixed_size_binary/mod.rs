@@ -56,3 +56,34 @@ fn from_iter() {
     let a = FixedSizeBinaryArray::from_iter(iter, 2);
     assert_eq!(a.len(), 2);
 }
+
+#[test]
+fn from_slice() {
+    let a = FixedSizeBinaryArray::from_slice([[1u8, 2], [3, 4]], 2);
+    assert_eq!(a.len(), 2);
+    assert_eq!(a.value(1), [3, 4]);
+    assert_eq!(a.validity(), None);
+
+    assert!(FixedSizeBinaryArray::try_from_slice([&[1u8, 2][..], &[3]], 2).is_err());
+}
+
+#[test]
+fn iter_sliced() {
+    let array = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(2),
+        Buffer::from_slice([1, 2, 3, 4, 5, 6, 7, 8]),
+        Some(Bitmap::from([true, false, true, true])),
+    );
+    let array = array.slice(1, 3);
+
+    assert_eq!(unsafe { array.value_unchecked(1) }, [5, 6]);
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![None, Some([5u8, 6].as_ref()), Some([7, 8].as_ref())]
+    );
+    assert_eq!(
+        array.values_iter().collect::<Vec<_>>(),
+        vec![[3u8, 4].as_ref(), [5, 6].as_ref(), [7, 8].as_ref()]
+    );
+    assert_eq!((&array).into_iter().size_hint(), (3, Some(3)));
+}
@@ -66,3 +66,47 @@ fn push_null() {
     let array: FixedSizeBinaryArray = array.into();
     assert_eq!(array.validity(), Some(&Bitmap::from([false])));
 }
+
+#[test]
+fn push_null_trait() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    array.push(Some(b"ab"));
+    MutableArray::push_null(&mut array);
+
+    let array: FixedSizeBinaryArray = array.into();
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, false])));
+    assert_eq!(array.values().as_slice(), b"ab\0\0");
+}
+
+#[test]
+fn set() {
+    let mut array =
+        MutableFixedSizeBinaryArray::try_from_iter(vec![Some(b"ab"), Some(b"bc"), Some(b"cd")], 2)
+            .unwrap();
+    array.set(0, Some(b"zz"));
+    array.set(1, None);
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![Some(b"zz".as_ref()), None, Some(b"cd".as_ref())]
+    );
+
+    array.set(1, Some(b"yy"));
+    let array: FixedSizeBinaryArray = array.into();
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, true, true])));
+    assert_eq!(array.value(1), b"yy");
+}
+
+#[test]
+fn try_extend() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    array
+        .try_extend(vec![Some(b"ab"), None, Some(b"cd")])
+        .unwrap();
+    assert_eq!(array.len(), 3);
+    assert_eq!(
+        array.validity(),
+        Some(&MutableBitmap::from([true, false, true]))
+    );
+
+    assert!(array.try_extend(vec![Some(b"abc")]).is_err());
+}
@@ -82,3 +82,23 @@ fn test_multi() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn null_keys_do_not_overflow() {
+    // the slot of a null key may contain any value, including one that overflows once offsetted
+    let values = Arc::new(Utf8Array::<i32>::from_slice(vec!["a"; 10])) as Arc<dyn Array>;
+    let lhs = DictionaryArray::<i8>::from_data(Int8Array::from_slice([0]), values.clone());
+    let keys = PrimitiveArray::from_data(
+        DataType::Int8,
+        vec![0, i8::MAX].into(),
+        Some([true, false].into()),
+    );
+    let rhs = DictionaryArray::<i8>::from_data(keys, values);
+
+    let mut growable = GrowableDictionary::new(&[&lhs, &rhs], false, 0);
+    growable.extend(0, 0, 1);
+    growable.extend(1, 0, 2);
+    let result: DictionaryArray<i8> = growable.into();
+
+    assert_eq!(result.keys(), &Int8Array::from([Some(0), Some(10), None]));
+}
@@ -0,0 +1,74 @@
+use arrow2::array::{
+    growable::{Growable, GrowableMap},
+    MapArray, MutableMapArray, MutablePrimitiveArray, MutableUtf8Array, TryExtend,
+};
+
+fn create_map_array(data: Vec<Option<Vec<(Option<&str>, Option<i32>)>>>) -> MapArray {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn basic() {
+    let data = vec![
+        Some(vec![(Some("a"), Some(1)), (Some("b"), Some(2))]),
+        Some(vec![(Some("c"), None)]),
+        Some(vec![]),
+    ];
+    let array = create_map_array(data);
+
+    let mut a = GrowableMap::new(vec![&array], false, 0);
+    a.extend(0, 0, 2);
+
+    let result: MapArray = a.into();
+
+    let expected = create_map_array(vec![
+        Some(vec![(Some("a"), Some(1)), (Some("b"), Some(2))]),
+        Some(vec![(Some("c"), None)]),
+    ]);
+
+    assert_eq!(result, expected)
+}
+
+#[test]
+fn null_offset() {
+    let data = vec![
+        Some(vec![(Some("a"), Some(1))]),
+        None,
+        Some(vec![(Some("c"), Some(3))]),
+    ];
+    let array = create_map_array(data);
+    let array = array.slice(1, 2);
+
+    let mut a = GrowableMap::new(vec![&array], false, 0);
+    a.extend(0, 0, 2);
+
+    let result: MapArray = a.into();
+
+    let expected = create_map_array(vec![None, Some(vec![(Some("c"), Some(3))])]);
+
+    assert_eq!(result, expected)
+}
+
+#[test]
+fn many() {
+    let array1 = create_map_array(vec![Some(vec![(Some("a"), Some(1))]), None]);
+    let array2 = create_map_array(vec![Some(vec![(Some("b"), None), (Some("c"), Some(3))])]);
+
+    let mut a = GrowableMap::new(vec![&array1, &array2], false, 0);
+    a.extend(0, 0, 2);
+    a.extend(1, 0, 1);
+    a.extend_validity(1);
+
+    let result: MapArray = a.into();
+
+    let expected = create_map_array(vec![
+        Some(vec![(Some("a"), Some(1))]),
+        None,
+        Some(vec![(Some("b"), None), (Some("c"), Some(3))]),
+        None,
+    ]);
+
+    assert_eq!(result, expected)
+}
@@ -4,9 +4,11 @@ mod dictionary;
 mod fixed_binary;
 mod fixed_size_list;
 mod list;
+mod map;
 mod null;
 mod primitive;
 mod struct_;
+mod union;
 mod utf8;
 
 /*
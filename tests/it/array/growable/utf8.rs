@@ -1,3 +1,5 @@
+use proptest::prelude::*;
+
 use arrow2::array::{
     growable::{Growable, GrowableUtf8},
     Utf8Array,
@@ -81,3 +83,71 @@ fn null_offset_validity() {
     let expected = Utf8Array::<i32>::from(&[None, Some("defh"), None]);
     assert_eq!(result, expected);
 }
+
+/// Returns a strategy of a [`Utf8Array`] sliced from a random offset alongside random
+/// `(start, len)` ranges within it
+fn sliced_array_strategy() -> impl Strategy<Value = (Utf8Array<i32>, Vec<(usize, usize)>)> {
+    prop::collection::vec(prop::option::of("[a-z]{0,5}"), 1..100)
+        .prop_flat_map(|values| {
+            let len = values.len();
+            (Just(values), 0..len)
+        })
+        .prop_flat_map(|(values, offset)| {
+            let len = values.len() - offset;
+            (
+                Just(values),
+                Just(offset),
+                prop::collection::vec((0..=len, 0..=len), 0..10),
+            )
+        })
+        .prop_map(|(values, offset, ranges)| {
+            let array = Utf8Array::<i32>::from(&values);
+            let len = array.len() - offset;
+            let ranges = ranges
+                .into_iter()
+                .map(|(start, length)| (start, length.min(len - start)))
+                .collect();
+            (array.slice(offset, len), ranges)
+        })
+}
+
+/// extends row by row, as `GrowableUtf8` used to
+fn naive(array: &Utf8Array<i32>, ranges: &[(usize, usize)]) -> (Vec<i32>, Vec<u8>) {
+    let offsets = array.offsets();
+    let mut new_offsets = vec![0];
+    let mut last_offset = 0;
+    let mut values = vec![];
+    for &(start, len) in ranges {
+        offsets[start..start + len + 1].windows(2).for_each(|w| {
+            last_offset += w[1] - w[0];
+            new_offsets.push(last_offset);
+        });
+        values.extend_from_slice(
+            &array.values()[offsets[start] as usize..offsets[start + len] as usize],
+        );
+    }
+    (new_offsets, values)
+}
+
+proptest! {
+    /// Asserts that extending from sliced arrays yields the same buffers as extending row by row
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn extend_sliced((array, ranges) in sliced_array_strategy()) {
+        let mut a = GrowableUtf8::new(vec![&array], false, 0);
+        for &(start, len) in &ranges {
+            a.extend(0, start, len);
+        }
+        let result: Utf8Array<i32> = a.into();
+
+        let (offsets, values) = naive(&array, &ranges);
+        assert_eq!(result.offsets().as_slice(), offsets.as_slice());
+        assert_eq!(result.values().as_slice(), values.as_slice());
+
+        let expected = ranges
+            .iter()
+            .flat_map(|&(start, len)| array.iter().skip(start).take(len))
+            .collect::<Utf8Array<i32>>();
+        assert_eq!(result, expected);
+    }
+}
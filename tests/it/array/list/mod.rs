@@ -73,3 +73,40 @@ fn test_nested_display() {
     let expected = "ListArray[\nListArray[\nInt32[1, 2],\nInt32[3, 4]\n],\nListArray[\nInt32[5, 6, 7],\nInt32[],\nInt32[8]\n],\nListArray[\nInt32[9, 10]\n]\n]";
     assert_eq!(format!("{:?}", nested), expected);
 }
+
+#[test]
+fn try_new() {
+    let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let values = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+
+    let array = ListArray::<i32>::try_new(
+        data_type.clone(),
+        Buffer::from_slice([0, 2, 3]),
+        values.clone(),
+        None,
+    )
+    .unwrap();
+    assert_eq!(array.len(), 2);
+
+    let cases: Vec<Vec<i32>> = vec![vec![0, 2, 1], vec![0, 4], vec![-1, 1], vec![]];
+    for offsets in cases {
+        let result =
+            ListArray::<i32>::try_new(data_type.clone(), offsets.into(), values.clone(), None);
+        assert!(result.is_err());
+    }
+
+    // wrong child type
+    let other = ListArray::<i32>::default_datatype(DataType::Int64);
+    let result = ListArray::<i32>::try_new(other, Buffer::from_slice([0, 3]), values.clone(), None);
+    assert!(result.is_err());
+
+    // wrong offset type
+    let large = ListArray::<i64>::default_datatype(DataType::Int32);
+    let result = ListArray::<i32>::try_new(large, Buffer::from_slice([0, 3]), values.clone(), None);
+    assert!(result.is_err());
+
+    // wrong validity length
+    let validity = Some(arrow2::bitmap::Bitmap::from([true, false]));
+    let result = ListArray::<i32>::try_new(data_type, Buffer::from_slice([0, 3]), values, validity);
+    assert!(result.is_err());
+}
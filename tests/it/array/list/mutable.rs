@@ -1,6 +1,12 @@
 use std::sync::Arc;
 
-use arrow2::{array::*, bitmap::Bitmap, buffer::Buffer, datatypes::DataType};
+use arrow2::{
+    array::*,
+    bitmap::{Bitmap, MutableBitmap},
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
 
 #[test]
 fn basics() {
@@ -50,3 +56,117 @@ fn push() {
     assert_eq!(array.offsets().as_ref(), [0, 3]);
     assert_eq!(array.validity(), None);
 }
+
+#[test]
+fn push_utf8_rows() {
+    let mut array = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_push(Some(vec![Some("a"), None])).unwrap();
+    array.try_push(None::<Vec<Option<&str>>>).unwrap();
+    array.try_push(Some(vec![Some("bc")])).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let values = Utf8Array::<i32>::from(&[Some("a"), None, Some("bc")]);
+    let data_type = ListArray::<i32>::default_datatype(DataType::Utf8);
+    let expected = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from_slice([0, 2, 2, 3]),
+        Arc::new(values),
+        Some(Bitmap::from([true, false, true])),
+    );
+    assert_eq!(expected, array);
+}
+
+#[test]
+fn extend_from_lengths() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.mut_values().extend_from_slice(&[1, 2, 3]);
+    array
+        .try_extend_from_lengths(vec![Some(2), None, Some(1), Some(0)])
+        .unwrap();
+    assert_eq!(array.offsets().as_slice(), [0, 2, 2, 3, 3]);
+    assert_eq!(
+        array.validity(),
+        Some(&MutableBitmap::from([true, false, true, true]))
+    );
+}
+
+#[test]
+fn extend_from_lengths_overflow() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    let result = array.try_extend_from_lengths(vec![Some(i32::MAX as usize), Some(1)]);
+    assert!(matches!(result, Err(ArrowError::Overflow)));
+}
+
+#[test]
+fn extend_from_lengths_mismatch() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.mut_values().extend_from_slice(&[1, 2, 3]);
+    let result = array.try_extend_from_lengths(vec![Some(2), None, Some(2)]);
+    assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+    // the array is left untouched
+    assert_eq!(array.offsets().as_slice(), [0]);
+    assert_eq!(array.validity(), None);
+
+    array.try_extend_from_lengths(vec![Some(3)]).unwrap();
+    assert_eq!(array.offsets().as_slice(), [0, 3]);
+}
+
+/// A [`MutableArray`] that only counts its values, so that offsets can overflow without
+/// allocating them.
+#[derive(Debug)]
+struct CountingArray {
+    len: usize,
+}
+
+impl MutableArray for CountingArray {
+    fn data_type(&self) -> &DataType {
+        &DataType::Null
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(NullArray::from_data(DataType::Null, self.len))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.len += 1;
+    }
+
+    fn shrink_to_fit(&mut self) {}
+}
+
+impl TryExtend<Option<usize>> for CountingArray {
+    /// Each item counts as that many values.
+    fn try_extend<I: IntoIterator<Item = Option<usize>>>(&mut self, iter: I) -> Result<()> {
+        self.len += iter.into_iter().flatten().sum::<usize>();
+        Ok(())
+    }
+}
+
+#[test]
+fn try_push_overflow() {
+    let values = CountingArray { len: 0 };
+    let mut array = MutableListArray::<i32, CountingArray>::new_with_capacity(values, 0);
+    array.try_push(Some([Some(i32::MAX as usize)])).unwrap();
+
+    let error = array.try_push(Some([Some(1)])).unwrap_err();
+    assert!(matches!(error, ArrowError::Overflow));
+    assert!(error.to_string().contains("LargeList"));
+    // the entry that overflowed is not added
+    assert_eq!(array.len(), 1);
+}
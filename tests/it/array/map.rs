@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::{DataType, Field},
+    error::Result,
+};
+
+fn create_map(data: Vec<Option<Vec<(&str, Option<i32>)>>>) -> MapArray {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array
+        .try_extend(data.into_iter().map(|x| {
+            x.map(|entries| {
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (Some(k), v))
+                    .collect::<Vec<_>>()
+            })
+        }))
+        .unwrap();
+    array.into()
+}
+
+#[test]
+fn basics() {
+    let array = create_map(vec![
+        Some(vec![("a", Some(1)), ("b", None)]),
+        None,
+        Some(vec![("c", Some(3))]),
+    ]);
+
+    let data_type =
+        MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::default_datatype(
+            DataType::Utf8,
+            DataType::Int32,
+        );
+    let field = if let DataType::Map(field, _) = &data_type {
+        field.data_type().clone()
+    } else {
+        unreachable!()
+    };
+    let entries = StructArray::from_data(
+        field,
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])),
+        ],
+        None,
+    );
+    let expected = MapArray::from_data(
+        data_type,
+        Buffer::from_slice([0, 2, 2, 3]),
+        Arc::new(entries),
+        Some(Bitmap::from([true, false, true])),
+    );
+    assert_eq!(array, expected);
+    assert_eq!(
+        array.keys().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+    assert!(!array.keys_sorted());
+}
+
+#[test]
+fn push_mismatched_lengths() {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array.mut_keys().push(Some("a"));
+    assert!(array.try_push_valid().is_err());
+}
+
+#[test]
+fn push() -> Result<()> {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array.try_push(Some(vec![(Some("a"), Some(1))]))?;
+    array.try_push(None::<Vec<(Option<&str>, Option<i32>)>>)?;
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.offsets().as_slice(), [0, 1, 1]);
+    let array: MapArray = array.into();
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, false])));
+    Ok(())
+}
+
+#[test]
+fn push_rollback() -> Result<()> {
+    // an `i8` dictionary only fits 128 distinct values
+    type Values = MutableDictionaryArray<i8, MutableUtf8Array<i32>>;
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, Values>::new();
+    let entries = (0..128)
+        .map(|i| (Some(i.to_string()), Some(i.to_string())))
+        .collect::<Vec<_>>();
+    array.try_push(Some(entries))?;
+
+    // the key of the second entry is pushed but its value overflows the dictionary
+    let entries = vec![(Some("a"), Some("0")), (Some("b"), Some("b"))];
+    assert!(array.try_push(Some(entries)).is_err());
+    assert_eq!(array.len(), 1);
+    assert_eq!(array.keys().len(), 128);
+    assert_eq!(array.values().len(), 128);
+
+    array.try_push(Some(vec![(Some("a"), Some("0"))]))?;
+    assert_eq!(array.offsets().as_slice(), [0, 128, 129]);
+    Ok(())
+}
+
+#[test]
+fn with_validity() {
+    let array = create_map(vec![Some(vec![("a", Some(1))]), Some(vec![])]);
+    let array = array.with_validity(Some(Bitmap::from([false, true])));
+    assert_eq!(array.null_count(), 1);
+    assert!(array.is_null(0));
+}
+
+#[test]
+fn debug() {
+    let array = create_map(vec![Some(vec![("a", Some(1)), ("b", None)]), None]);
+    assert_eq!(
+        format!("{:?}", array),
+        "MapArray[\nStructArray{\nkey: Utf8[a, b],\nvalue: Int32[1, None],\n},\nNone\n]"
+    );
+}
+
+#[test]
+fn display() {
+    let array = create_map(vec![Some(vec![("a", Some(1)), ("b", None)]), None]);
+    let display = get_display(&array);
+    assert_eq!(display(0), "{a: 1, b: }");
+}
+
+#[test]
+fn field() {
+    let array = create_map(vec![Some(vec![("a", Some(1))])]);
+    assert_eq!(
+        array.data_type(),
+        &DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8, false),
+                    Field::new("value", DataType::Int32, true),
+                ]),
+                false
+            )),
+            false
+        )
+    );
+}
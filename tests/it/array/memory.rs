@@ -0,0 +1,120 @@
+use std::mem::size_of;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::{DataType, Field};
+
+fn vec_with_capacity<T: Clone>(values: &[T], capacity: usize) -> Vec<T> {
+    let mut vec = Vec::with_capacity(capacity);
+    vec.extend_from_slice(values);
+    vec
+}
+
+// {"a": Int32, "b": Utf8, "c": List<Int64>, "d": the same array as "a"}, where "a"'s validity
+// is shared with the struct
+fn nested() -> StructArray {
+    let validity = Bitmap::from_u8_vec(vec![0b1011], 4);
+
+    let ints = Int32Array::from_data(
+        DataType::Int32,
+        Buffer::from(vec_with_capacity(&[1, 2, 3, 4], 8)),
+        Some(validity.clone()),
+    );
+    let ints = Arc::new(ints) as Arc<dyn Array>;
+
+    let strings = Utf8Array::<i32>::from_data(
+        DataType::Utf8,
+        Buffer::from(vec![0, 1, 3, 3, 6]),
+        Buffer::from(b"abbccc".to_vec()),
+        None,
+    );
+
+    let list = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int64),
+        Buffer::from(vec![0, 1, 1, 3, 3]),
+        Arc::new(Int64Array::from_slice([1, 2, 3])),
+        None,
+    );
+
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", list.data_type().clone(), true),
+        Field::new("d", DataType::Int32, true),
+    ]);
+    StructArray::from_data(
+        data_type,
+        vec![ints.clone(), Arc::new(strings), Arc::new(list), ints],
+        Some(validity),
+    )
+}
+
+// ints (8 * 4), validity (1), utf8 offsets (5 * 4) and values (6), list offsets (5 * 4) and values (3 * 8)
+const NESTED_BUFFERS: usize = 32 + 1 + 20 + 6 + 20 + 24;
+
+#[test]
+fn buffer_memory_size() {
+    let array = nested();
+    assert_eq!(array.get_buffer_memory_size(), NESTED_BUFFERS);
+
+    // slices keep the whole regions alive
+    assert_eq!(array.slice(1, 2).get_buffer_memory_size(), NESTED_BUFFERS);
+    assert_eq!(
+        array.values()[1].slice(0, 1).get_buffer_memory_size(),
+        20 + 6
+    );
+}
+
+#[test]
+fn array_memory_size() {
+    let array = nested();
+    let structs = size_of::<StructArray>()
+        + size_of::<Int32Array>()
+        + size_of::<Utf8Array<i32>>()
+        + size_of::<ListArray<i32>>()
+        + size_of::<Int64Array>();
+    assert_eq!(array.get_array_memory_size(), NESTED_BUFFERS + structs);
+
+    let array = &array as &dyn Array;
+    assert_eq!(array.get_array_memory_size(), NESTED_BUFFERS + structs);
+}
+
+#[test]
+fn dictionary() {
+    let keys = UInt8Array::from_data(
+        DataType::UInt8,
+        Buffer::from(vec![0, 0, 1]),
+        Some(Bitmap::from_u8_vec(vec![0b101], 3)),
+    );
+    let values = Utf8Array::<i64>::from_data(
+        DataType::LargeUtf8,
+        Buffer::from(vec![0, 1, 3]),
+        Buffer::from(b"abb".to_vec()),
+        None,
+    );
+    let values = Arc::new(values) as Arc<dyn Array>;
+    let array = DictionaryArray::<u8>::from_data(keys, values);
+
+    // keys (3), validity (1), offsets (3 * 8) and values (3)
+    assert_eq!(array.get_buffer_memory_size(), 3 + 1 + 24 + 3);
+    assert_eq!(
+        array.get_array_memory_size(),
+        3 + 1 + 24 + 3 + size_of::<DictionaryArray<u8>>() + size_of::<Utf8Array<i64>>()
+    );
+}
+
+#[test]
+fn boolean_and_null() {
+    let array = BooleanArray::from_data(
+        DataType::Boolean,
+        Bitmap::from_u8_vec(vec_with_capacity(&[0b101], 4), 3),
+        Some(Bitmap::from_u8_vec(vec![0b011], 3)),
+    );
+    assert_eq!(array.get_buffer_memory_size(), 4 + 1);
+
+    let array = NullArray::from_data(DataType::Null, 10);
+    assert_eq!(array.get_buffer_memory_size(), 0);
+    assert_eq!(array.get_array_memory_size(), size_of::<NullArray>());
+}
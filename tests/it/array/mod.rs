@@ -1,20 +1,23 @@
 mod binary;
 mod boolean;
 mod dictionary;
+mod downcast;
 mod equal;
 mod fixed_size_binary;
 mod fixed_size_list;
 mod growable;
 mod list;
+mod map;
+mod memory;
 mod ord;
 mod primitive;
 mod struct_;
 mod union;
 mod utf8;
 
-use arrow2::array::{clone, new_empty_array, new_null_array, Array, PrimitiveArray};
+use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
-use arrow2::datatypes::{DataType, Field, UnionMode};
+use arrow2::datatypes::{DataType, Field, IntegerType, TimeUnit, UnionMode};
 
 #[test]
 fn nulls() {
@@ -49,6 +52,49 @@ fn nulls() {
     assert!(a);
 }
 
+#[test]
+fn sliced_null_count() {
+    let validity = (0..20).map(|x| x % 3 != 0);
+
+    let boolean = BooleanArray::from_data(
+        DataType::Boolean,
+        Bitmap::from(vec![true; 20]),
+        Some(validity.clone().collect()),
+    );
+    let utf8 = validity
+        .clone()
+        .map(|x| if x { Some("a") } else { None })
+        .collect::<Utf8Array<i32>>();
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(validity.map(|x| if x { Some(vec![Some(1)]) } else { None }))
+        .unwrap();
+    let list: ListArray<i32> = list.into();
+
+    let arrays: Vec<&dyn Array> = vec![&boolean, &utf8, &list];
+    for array in arrays {
+        assert_eq!(array.null_count(), 7);
+        // nulls at 3, 6, 9 and 12
+        assert_eq!(array.slice(3, 10).null_count(), 4);
+        assert_eq!(array.slice(1, 2).null_count(), 0);
+        // re-slicing counts only the new window
+        let sliced = array.slice(5, 14);
+        assert_eq!(sliced.slice(1, 5).null_count(), 2);
+    }
+}
+
+#[test]
+fn null_count_all_valid() {
+    let array = PrimitiveArray::<i32>::from_slice(&[1, 2]);
+    assert_eq!(array.null_count(), 0);
+    // a validity without unset bits has no nulls
+    let array = array.with_validity(Some(Bitmap::from([true, true])));
+    assert!(array.validity().is_some());
+    assert_eq!(array.null_count(), 0);
+    let array = array.with_validity(Some(Bitmap::from([true, false])));
+    assert_eq!(array.null_count(), 1);
+    assert_eq!(new_null_array(DataType::Null, 2).null_count(), 2);
+}
+
 #[test]
 fn empty() {
     let datatypes = vec![
@@ -72,6 +118,22 @@ fn empty() {
     assert!(a);
 }
 
+#[test]
+fn extension() {
+    let data_type = DataType::Extension(
+        "uuid".to_string(),
+        Box::new(DataType::FixedSizeBinary(16)),
+        Some("metadata".to_string()),
+    );
+    let array = new_null_array(data_type.clone(), 10);
+    assert_eq!(array.data_type(), &data_type);
+    assert_eq!(array.null_count(), 10);
+
+    let array = new_empty_array(data_type.clone());
+    assert_eq!(array.data_type(), &data_type);
+    assert_eq!(array.len(), 0);
+}
+
 #[test]
 fn test_clone() {
     let datatypes = vec![
@@ -103,3 +165,387 @@ fn test_with_validity() {
 struct A {
     array: std::sync::Arc<dyn Array>,
 }
+
+#[test]
+fn extension_display() {
+    use arrow2::array::get_display;
+
+    let data_type = DataType::Extension("ext".to_string(), Box::new(DataType::Int32), None);
+    let array = PrimitiveArray::from(&[Some(1i32), None]).to(data_type);
+    let display = get_display(&array);
+    assert_eq!(display(0), "1");
+    assert_eq!(display(1), "");
+}
+
+#[test]
+fn to_data_type_primitive() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]).slice(1, 3);
+    let result = to_data_type(&array, DataType::Date32).unwrap();
+    assert_eq!(result.data_type(), &DataType::Date32);
+
+    let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(result.values().as_ptr(), array.values().as_ptr());
+    let (validity, offset, _) = result.validity().unwrap().as_slice();
+    let (expected, expected_offset, _) = array.validity().unwrap().as_slice();
+    assert_eq!(validity.as_ptr(), expected.as_ptr());
+    assert_eq!(offset, expected_offset);
+
+    let result = array
+        .clone()
+        .try_to(DataType::Time32(TimeUnit::Second))
+        .unwrap();
+    assert_eq!(result.values().as_ptr(), array.values().as_ptr());
+}
+
+#[test]
+fn to_data_type_utf8_extension() {
+    let data_type = DataType::Extension("ext".to_string(), Box::new(DataType::Utf8), None);
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("bc")]);
+    let result = to_data_type(&array, data_type.clone()).unwrap();
+    assert_eq!(result.data_type(), &data_type);
+
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert_eq!(result.offsets().as_ptr(), array.offsets().as_ptr());
+    assert_eq!(result.values().as_ptr(), array.values().as_ptr());
+    assert_eq!(result.value(2), "bc");
+}
+
+#[test]
+fn to_data_type_nested() {
+    let values = Int64Array::from_slice(&[1, 2, 3]);
+    let keys = PrimitiveArray::from(&[Some(2i32), None, Some(0)]);
+    let array = DictionaryArray::<i32>::from_data(keys, std::sync::Arc::new(values));
+
+    let data_type = DataType::Dictionary(
+        IntegerType::Int32,
+        Box::new(DataType::Timestamp(TimeUnit::Millisecond, None)),
+        false,
+    );
+    let result = to_data_type(&array, data_type.clone()).unwrap();
+    assert_eq!(result.data_type(), &data_type);
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(
+        result.keys().values().as_ptr(),
+        array.keys().values().as_ptr()
+    );
+    let values = result
+        .values()
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(
+        values.values().as_ptr(),
+        array
+            .values()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .values()
+            .as_ptr()
+    );
+
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        vec![0, 1, 3].into(),
+        std::sync::Arc::new(Int32Array::from_slice(&[1, 2, 3])),
+        None,
+    );
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Date32, true)));
+    let result = to_data_type(&array, data_type.clone()).unwrap();
+    assert_eq!(result.data_type(), &data_type);
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(result.values().data_type(), &DataType::Date32);
+    assert_eq!(result.offsets().as_ptr(), array.offsets().as_ptr());
+}
+
+#[test]
+fn to_data_type_incompatible() {
+    let array = Int32Array::from_slice(&[1, 2]);
+    let error = to_data_type(&array, DataType::Int64)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Int32"));
+    assert!(error.contains("Int64"));
+    assert!(array.clone().try_to(DataType::Float32).is_err());
+
+    let array = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(2),
+        vec![1, 2, 3, 4].into(),
+        None,
+    );
+    let error = array
+        .try_to(DataType::FixedSizeBinary(4))
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("FixedSizeBinary(2)"));
+    assert!(error.contains("FixedSizeBinary(4)"));
+
+    // the child types of nested arrays must also be compatible
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        vec![0, 1].into(),
+        std::sync::Arc::new(Int32Array::from_slice(&[1])),
+        None,
+    );
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Utf8, true)));
+    assert!(to_data_type(&array, data_type).is_err());
+}
+
+/// Builds `array` via [`mutable_from_data_type`] and [`MutableArray::try_push_scalar`] of each
+/// of its slots, without downcasting.
+fn push_scalars(array: &dyn Array) -> Box<dyn Array> {
+    let mut mutable = mutable_from_data_type(array.data_type(), array.len()).unwrap();
+    for index in 0..array.len() {
+        mutable
+            .try_push_scalar(arrow2::scalar::new_scalar(array, index).as_ref())
+            .unwrap();
+    }
+    assert_eq!(mutable.data_type(), array.data_type());
+    mutable.as_box()
+}
+
+#[test]
+fn push_scalar() -> arrow2::error::Result<()> {
+    use std::sync::Arc;
+
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![Some(vec![Some(1), None]), None, Some(vec![])])?;
+    let list: ListArray<i32> = list.into();
+
+    let mut fixed_list =
+        MutableFixedSizeListArray::<MutableUtf8Array<i64>>::new(MutableUtf8Array::new(), 2);
+    fixed_list.try_extend(vec![
+        Some(vec![Some("a"), None]),
+        None,
+        Some(vec![None, None]),
+    ])?;
+    let fixed_list: FixedSizeListArray = fixed_list.into();
+
+    let mut dictionary = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    dictionary.try_extend(vec![Some("a"), None, Some("b"), Some("a")])?;
+    let dictionary: DictionaryArray<u8> = dictionary.into();
+
+    let fields = vec![
+        Field::new("a", DataType::Boolean, true),
+        Field::new("b", list.data_type().clone(), true),
+    ];
+    let structs = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(BooleanArray::from(&[Some(true), None, Some(false)])),
+            Arc::new(list.clone()),
+        ],
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    let arrays: Vec<Box<dyn Array>> = vec![
+        Box::new(BooleanArray::from(&[Some(true), None, Some(false)])),
+        Box::new(Int8Array::from(&[Some(1), None, Some(-1)])),
+        Box::new(Int128Array::from(&[Some(1), None]).to(DataType::Decimal(10, 2))),
+        Box::new(Int64Array::from(&[Some(1), None]).to(DataType::Timestamp(
+            TimeUnit::Second,
+            Some("UTC".to_string()),
+        ))),
+        Box::new(Float64Array::from(&[Some(1.5), None])),
+        Box::new(Utf8Array::<i32>::from(&[Some("a"), None, Some("")])),
+        Box::new(Utf8Array::<i64>::from(&[Some("a"), None])),
+        Box::new(BinaryArray::<i32>::from(&[Some(b"a".as_ref()), None])),
+        Box::new(BinaryArray::<i64>::from(&[Some(b"a".as_ref()), None])),
+        Box::new(FixedSizeBinaryArray::from_data(
+            DataType::FixedSizeBinary(2),
+            vec![1, 2, 3, 4].into(),
+            Some(Bitmap::from([true, false])),
+        )),
+        Box::new(list),
+        Box::new(fixed_list),
+        Box::new(dictionary),
+        Box::new(structs),
+    ];
+    for array in arrays {
+        assert_eq!(push_scalars(array.as_ref()).as_ref(), array.as_ref());
+        // sliced arrays
+        let sliced = array.slice(1, array.len() - 1);
+        assert_eq!(push_scalars(sliced.as_ref()).as_ref(), sliced.as_ref());
+    }
+    Ok(())
+}
+
+#[test]
+fn push_scalar_errors() {
+    use arrow2::scalar::*;
+
+    let mut array = mutable_from_data_type(&DataType::Int32, 0).unwrap();
+    // a different logical and physical type
+    assert!(array
+        .try_push_scalar(&PrimitiveScalar::new(DataType::Date32, Some(1i32)))
+        .is_err());
+    assert!(array
+        .try_push_scalar(&PrimitiveScalar::new(DataType::Int64, Some(1i64)))
+        .is_err());
+    assert!(array.try_push_scalar(&NullScalar::new()).is_err());
+    assert_eq!(array.len(), 0);
+
+    // struct fields must match the data type of the struct
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let mut array = mutable_from_data_type(&data_type, 0).unwrap();
+    let value = StructScalar::new(
+        data_type,
+        Some(vec![
+            std::sync::Arc::new(PrimitiveScalar::new(DataType::Int32, Some(1i32))),
+            std::sync::Arc::new(PrimitiveScalar::new(DataType::Int32, Some(1i32))),
+        ]),
+    );
+    assert!(array.try_push_scalar(&value).is_err());
+    assert_eq!(array.len(), 0);
+}
+
+#[test]
+fn push_scalar_rollback() {
+    use arrow2::scalar::*;
+    use std::sync::Arc;
+
+    // 200 distinct values, that fit in the keys of a dictionary only once
+    let dictionary = |prefix: &str| -> Arc<dyn Array> {
+        let mut values = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+        values
+            .try_extend((0..200).map(|x| Some(format!("{}{}", prefix, x))))
+            .unwrap();
+        values.into_arc()
+    };
+    let dictionary_type = DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), false);
+    let list_type = DataType::List(Box::new(Field::new("item", dictionary_type.clone(), true)));
+    let fixed_list_type =
+        DataType::FixedSizeList(Box::new(Field::new("item", dictionary_type, true)), 200);
+    let scalars: Vec<[Box<dyn Scalar>; 2]> = vec![
+        [
+            Box::new(ListScalar::<i32>::new(
+                list_type.clone(),
+                Some(dictionary("a")),
+            )),
+            Box::new(ListScalar::<i32>::new(list_type, Some(dictionary("b")))),
+        ],
+        [
+            Box::new(FixedSizeListScalar::new(
+                fixed_list_type.clone(),
+                Some(dictionary("a")),
+            )),
+            Box::new(FixedSizeListScalar::new(
+                fixed_list_type,
+                Some(dictionary("b")),
+            )),
+        ],
+    ];
+    for [first, second] in scalars {
+        let mut array = mutable_from_data_type(first.data_type(), 0).unwrap();
+        array.try_push_scalar(first.as_ref()).unwrap();
+        // the keys of the dictionary overflow half-way through the values of `second`
+        assert!(array.try_push_scalar(second.as_ref()).is_err());
+        assert_eq!(array.len(), 1);
+        array.push_null();
+
+        // the values of the failed push were rolled back
+        let array = array.as_box();
+        assert_eq!(array.len(), 2);
+        assert_eq!(new_scalar(array.as_ref(), 0).as_ref(), first.as_ref());
+        assert!(!array.is_valid(1));
+        if let Some(array) = array.as_any().downcast_ref::<ListArray<i32>>() {
+            assert_eq!(array.values().len(), 200);
+        }
+    }
+}
+
+#[test]
+fn push_scalar_rollback_struct() {
+    use arrow2::scalar::*;
+    use std::sync::Arc;
+
+    let dictionary_type = DataType::Dictionary(IntegerType::Int8, Box::new(DataType::Utf8), false);
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", dictionary_type.clone(), false),
+    ]);
+    let scalar = |x: i32| {
+        let values: Vec<Arc<dyn Scalar>> = vec![
+            Arc::new(PrimitiveScalar::from(Some(x))),
+            Arc::new(DictionaryScalar::<i8>::new(
+                dictionary_type.clone(),
+                Some(Arc::new(Utf8Scalar::<i32>::new(Some(x.to_string())))),
+            )),
+        ];
+        StructScalar::new(data_type.clone(), Some(values))
+    };
+
+    let mut array = mutable_from_data_type(&data_type, 0).unwrap();
+    for x in 0..128 {
+        array.try_push_scalar(&scalar(x)).unwrap();
+    }
+    // "a" is pushed before the keys of "b" overflow
+    assert!(array.try_push_scalar(&scalar(128)).is_err());
+    assert_eq!(array.len(), 128);
+
+    let array = array.as_box();
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    assert!(array.values().iter().all(|x| x.len() == 128));
+}
+
+#[test]
+fn mutable_from_data_type_unsupported() {
+    let map = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        )),
+        false,
+    );
+    let data_types = vec![
+        DataType::Null,
+        DataType::Union(
+            vec![Field::new("a", DataType::Int32, true)],
+            None,
+            UnionMode::Dense,
+        ),
+        map.clone(),
+        DataType::List(Box::new(Field::new("item", map, true))),
+        DataType::Struct(vec![Field::new("a", DataType::Null, true)]),
+    ];
+    for data_type in data_types {
+        assert!(matches!(
+            mutable_from_data_type(&data_type, 0),
+            Err(arrow2::error::ArrowError::NotYetImplemented(_))
+        ));
+    }
+}
+
+#[test]
+fn extend_nulls() {
+    let data_types = vec![
+        DataType::Boolean,
+        DataType::Int32,
+        DataType::Utf8,
+        DataType::LargeBinary,
+        DataType::FixedSizeBinary(3),
+        DataType::List(Box::new(Field::new("a", DataType::Binary, true))),
+        DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int8, true)), 2),
+        DataType::Struct(vec![Field::new("a", DataType::Utf8, true)]),
+        DataType::Dictionary(IntegerType::Int16, Box::new(DataType::Utf8), false),
+    ];
+    for data_type in data_types {
+        let mut array = mutable_from_data_type(&data_type, 0).unwrap();
+        array.extend_nulls(3);
+        array.push_null();
+        let array = array.as_box();
+        assert_eq!(array.as_ref(), new_null_array(data_type, 4).as_ref());
+    }
+}
@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use arrow2::array::ord::build_compare;
+use arrow2::array::ord::{build_compare, build_compare_with_nans};
 use arrow2::array::*;
 use arrow2::error::Result;
 
@@ -55,6 +55,30 @@ fn f64_nan() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn f64_negative_nan() -> Result<()> {
+    let array = Float64Array::from_slice(&[f64::NEG_INFINITY, -f64::NAN, f64::NAN]);
+
+    let cmp = build_compare(&array, &array)?;
+
+    // NaNs are equal and greater than any other value irrespectively of their sign
+    assert_eq!(Ordering::Less, (cmp)(0, 1));
+    assert_eq!(Ordering::Equal, (cmp)(1, 2));
+    Ok(())
+}
+
+#[test]
+fn f32_nans_least() -> Result<()> {
+    let array = Float32Array::from_slice(&[f32::NEG_INFINITY, f32::NAN, -f32::NAN]);
+
+    let cmp = build_compare_with_nans(&array, &array, false)?;
+
+    assert_eq!(Ordering::Greater, (cmp)(0, 1));
+    assert_eq!(Ordering::Greater, (cmp)(0, 2));
+    assert_eq!(Ordering::Equal, (cmp)(1, 2));
+    Ok(())
+}
+
 #[test]
 fn f64_zeros() -> Result<()> {
     let array = Float64Array::from_slice(&[-0.0, 0.0]);
@@ -203,6 +203,18 @@ fn display_timestamp_tz_ns() {
     );
 }
 
+#[test]
+fn display_timestamp_invalid_tz() {
+    let array = Int64Array::from(&[Some(1), None]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("invalid".to_string()),
+    ));
+    assert_eq!(
+        format!("{:?}", array),
+        "Timestamp(Second, Some(\"invalid\"))[1970-01-01 00:00:01 UTC, None]"
+    );
+}
+
 #[test]
 fn display_duration_ms() {
     let array =
@@ -263,7 +275,7 @@ fn display_interval_days_ms() {
     let array = DaysMsArray::from(&[Some(days_ms::new(1, 1)), None, Some(days_ms::new(2, 2))]);
     assert_eq!(
         format!("{:?}", array),
-        "Interval(DayTime)[1d1ms, None, 2d2ms]"
+        "Interval(DayTime)[1d 1ms, None, 2d 2ms]"
     );
 }
 
@@ -279,7 +291,7 @@ fn debug_months_days_ns() {
 
     assert_eq!(
         format!("{:?}", array),
-        "Interval(MonthDayNano)[1m1d2ns, None, 2m3d3ns]"
+        "Interval(MonthDayNano)[1m 1d 2ns, None, 2m 3d 3ns]"
     );
 }
 
@@ -303,3 +315,62 @@ fn wrong_data_type() {
     let values = Buffer::from_slice(b"abbb");
     PrimitiveArray::from_data(DataType::Utf8, values, None);
 }
+
+#[test]
+fn into_mut() {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]);
+    let shared = array.clone();
+    let array = array.into_mut().unwrap_left();
+    drop(shared);
+
+    let mut array = array.into_mut().unwrap_right();
+    array.push(Some(4));
+    let array: Int32Array = array.into();
+    assert_eq!(array, Int32Array::from(&[Some(1), None, Some(3), Some(4)]));
+
+    // shared validity
+    let validity = Bitmap::from([true, false]);
+    let array = Int32Array::from_data(DataType::Int32, vec![1, 2].into(), Some(validity.clone()));
+    let array = array.into_mut().unwrap_left();
+    assert_eq!(array, Int32Array::from(&[Some(1), None]));
+    drop(validity);
+    assert!(array.into_mut().is_right());
+
+    // sliced
+    let array = Int32Array::from_slice(&[1, 2, 3]).slice(1, 2);
+    assert!(array.into_mut().is_left());
+}
+
+#[test]
+fn into_vec() {
+    let vec = vec![1, 2, 3];
+    let ptr = vec.as_ptr();
+    let array = Int32Array::from_vec(vec);
+    let vec = array.into_vec().unwrap();
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec, vec![1, 2, 3]);
+
+    // a validity without nulls
+    let validity = Some(Bitmap::from([true, true, true]));
+    let array = Int32Array::from_data(DataType::Int32, vec.into(), validity);
+    assert_eq!(array.into_vec().unwrap().as_ptr(), ptr);
+
+    // nulls
+    let array = Int32Array::from(&[Some(1), None]);
+    let array = array.into_vec().unwrap_err();
+    assert_eq!(array, Int32Array::from(&[Some(1), None]));
+
+    // shared
+    let array = Int32Array::from_slice(&[1, 2]);
+    let shared = array.clone();
+    let array = array.into_vec().unwrap_err();
+    drop(shared);
+    assert_eq!(array.into_vec().unwrap(), vec![1, 2]);
+
+    // sliced
+    let array = Int32Array::from_slice(&[1, 2, 3]).slice(1, 2);
+    assert_eq!(
+        array.into_vec().unwrap_err(),
+        Int32Array::from_slice(&[2, 3])
+    );
+}
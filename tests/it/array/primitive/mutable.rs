@@ -20,6 +20,21 @@ fn from_and_into_data() {
     assert_eq!(c, Some(MutableBitmap::from([true, false])));
 }
 
+#[test]
+fn into_vec() {
+    let mut a = MutablePrimitiveArray::<i32>::with_capacity(2);
+    a.push(Some(1));
+    a.push(Some(2));
+    let ptr = a.values().as_ptr();
+    let vec = a.into_vec().unwrap();
+    assert_eq!(vec, vec![1, 2]);
+    assert_eq!(vec.as_ptr(), ptr);
+
+    let a = MutablePrimitiveArray::<i32>::from([Some(1), None]);
+    let a = a.into_vec().unwrap_err();
+    assert_eq!(a.len(), 2);
+}
+
 #[test]
 fn to() {
     let a = MutablePrimitiveArray::from_data(
@@ -231,3 +246,27 @@ fn wrong_data_type() {
     let values = vec![1u8];
     MutablePrimitiveArray::from_data(DataType::Utf8, values, None);
 }
+
+#[test]
+fn extend_constant_and_push() {
+    let mut a = MutablePrimitiveArray::<i32>::new();
+    a.extend_constant(2, Some(1));
+    a.push(Some(2));
+    a.extend_constant(2, None);
+    a.push(Some(3));
+    a.extend_constant(0, None);
+    a.extend_constant(1, Some(4));
+    let a: PrimitiveArray<i32> = a.into();
+    assert_eq!(
+        a,
+        Int32Array::from(&[Some(1), Some(1), Some(2), None, None, Some(3), Some(4)])
+    );
+}
+
+#[test]
+fn apply_values() {
+    let mut a = MutablePrimitiveArray::<i32>::from([Some(1), None, Some(3)]);
+    a.apply_values(|values| values.iter_mut().for_each(|x| *x *= 2));
+    let a: PrimitiveArray<i32> = a.into();
+    assert_eq!(a, Int32Array::from(&[Some(2), None, Some(6)]));
+}
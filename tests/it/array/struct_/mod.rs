@@ -1 +1,91 @@
 mod iterator;
+mod mutable;
+
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::*;
+
+fn array() -> StructArray {
+    let boolean = Arc::new(BooleanArray::from(&[
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+    ])) as Arc<dyn Array>;
+    let int = Arc::new(Int32Array::from_slice(&[42, 28, 19, 31])) as Arc<dyn Array>;
+
+    let fields = vec![
+        Field::new("b", DataType::Boolean, true),
+        Field::new("c", DataType::Int32, false),
+    ];
+    StructArray::from_data(
+        DataType::Struct(fields),
+        vec![boolean, int],
+        Some(Bitmap::from([true, true, false, true])),
+    )
+}
+
+#[test]
+fn field_by_name() {
+    let array = array();
+    assert_eq!(
+        array.field_by_name("c").unwrap().as_ref(),
+        &Int32Array::from_slice(&[42, 28, 19, 31]) as &dyn Array
+    );
+    assert!(array.field_by_name("d").is_none());
+}
+
+#[test]
+fn flatten() {
+    let columns = array().flatten();
+    assert_eq!(columns.len(), 4);
+    assert_eq!(
+        columns[0].as_ref(),
+        &BooleanArray::from(&[Some(true), None, None, Some(true)]) as &dyn Array
+    );
+    assert_eq!(
+        columns[1].as_ref(),
+        &Int32Array::from(&[Some(42), Some(28), None, Some(31)]) as &dyn Array
+    );
+}
+
+#[test]
+fn flatten_nested() {
+    let inner = Arc::new(array()) as Arc<dyn Array>;
+    let data_type = DataType::Struct(vec![Field::new("a", inner.data_type().clone(), true)]);
+    let outer = StructArray::from_data(
+        data_type,
+        vec![inner],
+        Some(Bitmap::from([false, true, true, true])),
+    );
+
+    let columns = outer.flatten();
+    let inner = columns[0].as_any().downcast_ref::<StructArray>().unwrap();
+    // the validity is pushed to the top-level fields only
+    assert_eq!(
+        inner.validity(),
+        Some(&Bitmap::from([false, true, false, true]))
+    );
+    assert_eq!(inner.values()[1].null_count(), 0);
+
+    let columns = inner.flatten();
+    assert_eq!(
+        columns[1].as_ref(),
+        &Int32Array::from(&[None, Some(28), None, Some(31)]) as &dyn Array
+    );
+}
+
+#[test]
+fn flatten_sliced() {
+    let columns = array().slice(1, 2).flatten();
+    assert_eq!(
+        columns[0].as_ref(),
+        &BooleanArray::from(&[None, None]) as &dyn Array
+    );
+    assert_eq!(
+        columns[1].as_ref(),
+        &Int32Array::from(&[Some(28), None]) as &dyn Array
+    );
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    bitmap::Bitmap,
+    datatypes::{DataType, Field},
+};
+
+fn data_type() -> DataType {
+    DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ])
+}
+
+fn new_array() -> MutableStructArray {
+    let values: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ];
+    MutableStructArray::new(data_type(), values)
+}
+
+#[test]
+fn push() {
+    let mut array = new_array();
+
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(Some(1));
+    array
+        .value::<MutableUtf8Array<i32>>(1)
+        .unwrap()
+        .push(Some("a"));
+    array.push(true);
+
+    // a null row whose children received default values
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(Some(0));
+    array
+        .value::<MutableUtf8Array<i32>>(1)
+        .unwrap()
+        .push(Some(""));
+    array.push(false);
+
+    // a null row whose children received nulls
+    array.push_null();
+    assert_eq!(array.len(), 3);
+
+    let array: StructArray = array
+        .as_box()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap()
+        .clone();
+
+    let expected = StructArray::from_data(
+        data_type(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), Some(0), None])),
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some(""), None])),
+        ],
+        Some(Bitmap::from([true, false, false])),
+    );
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn push_different_lengths() {
+    let mut array = new_array();
+    array.value_builders_mut()[0].push_null();
+    assert!(array.try_push(true).is_err());
+}
+
+#[test]
+fn push_without_values() {
+    let mut array = new_array();
+    assert!(array.try_push(false).is_err());
+    assert!(array.try_push(true).is_err());
+    assert_eq!(array.len(), 0);
+    assert_eq!(array.validity(), None);
+
+    array.push_null();
+    // every child must receive a new item for every row
+    assert!(array.try_push(true).is_err());
+    assert_eq!(array.len(), 1);
+}
+
+#[test]
+fn list_of_struct() {
+    let mut array = MutableListArray::<i32, _>::new_with_field(new_array(), "item", true);
+
+    let values = array.mut_values();
+    for i in 0..2 {
+        values
+            .value::<MutablePrimitiveArray<i32>>(0)
+            .unwrap()
+            .push(Some(i));
+        values
+            .value::<MutableUtf8Array<i32>>(1)
+            .unwrap()
+            .push(Some("a"));
+        values.push(true);
+    }
+    array.try_push_valid().unwrap();
+    array.mut_values().push_null();
+    array.try_push_valid().unwrap();
+
+    let array: ListArray<i32> = array.into();
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.offsets().as_slice(), [0, 2, 3]);
+
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(values.validity(), Some(&Bitmap::from([true, true, false])));
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use arrow2::{array::*, buffer::Buffer, datatypes::*, error::Result, scalar::*};
+
+mod mutable;
+
+#[test]
+fn debug() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+    let types = Buffer::from_slice([0, 0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type, types, fields, None);
+
+    assert_eq!(format!("{:?}", array), "UnionArray[1, None, c]");
+
+    Ok(())
+}
+
+#[test]
+fn slice() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+    let types = Buffer::from_slice([0, 0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type.clone(), types, fields.clone(), None);
+
+    let result = array.slice(1, 2);
+
+    let types = Buffer::from_slice([0, 1]);
+    let fields = fields.iter().map(|x| x.slice(1, 2).into()).collect();
+    let expected = UnionArray::from_data(data_type, types, fields, None);
+
+    assert_eq!(expected, result);
+    Ok(())
+}
+
+#[test]
+fn slice_dense() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let types = Buffer::from_slice([0, 0, 1]);
+    let offsets = Buffer::from_slice([0, 1, 0]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("c")])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type.clone(), types, fields.clone(), Some(offsets));
+
+    let result = array.slice(1, 2);
+
+    let types = Buffer::from_slice([0, 1]);
+    let offsets = Buffer::from_slice([1, 0]);
+    let expected = UnionArray::from_data(data_type, types, fields, Some(offsets));
+
+    assert_eq!(expected, result);
+    assert_eq!(format!("{:?}", result), "UnionArray[None, c]");
+    Ok(())
+}
+
+#[test]
+fn value() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![3, 7]), UnionMode::Sparse);
+    let types = Buffer::from_slice([3, 3, 7]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type.clone(), types, fields, None);
+    let array = array.slice(1, 2);
+
+    let value = array.value(1);
+    let value = value.as_any().downcast_ref::<UnionScalar>().unwrap();
+    assert_eq!(value.type_(), 7);
+    let expected: Arc<dyn Scalar> = Arc::new(Utf8Scalar::<i32>::new(Some("c")));
+    assert_eq!(value.value(), &expected);
+    assert_eq!(value.data_type(), &data_type);
+
+    assert!(!array.value(0).is_valid());
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use std::sync::Arc;
+
+use arrow2::{array::*, buffer::Buffer, datatypes::*, scalar::*};
+
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]
+}
+
+fn new_array(data_type: DataType) -> MutableUnionArray {
+    let fields: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ];
+    MutableUnionArray::new(data_type, fields)
+}
+
+/// Pushes `[1, "a", None, 2]` to `array`, whose types are `ids`.
+fn push(array: &mut MutableUnionArray, ids: [i8; 2]) {
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(Some(1));
+    array.push(ids[0]);
+    array
+        .value::<MutableUtf8Array<i32>>(1)
+        .unwrap()
+        .push(Some("a"));
+    array.push(ids[1]);
+    array.push_null();
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(Some(2));
+    array.push(ids[0]);
+}
+
+#[test]
+fn sparse() {
+    let data_type = DataType::Union(fields(), None, UnionMode::Sparse);
+    let mut array = new_array(data_type.clone());
+    push(&mut array, [0, 1]);
+    assert_eq!(array.len(), 4);
+    assert!(!array.is_valid(2));
+
+    let array: UnionArray = array.into();
+
+    let expected = UnionArray::from_data(
+        data_type,
+        Buffer::from_slice([0, 1, 0, 0]),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None, None, Some(2)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[None, Some("a"), None, None])),
+        ],
+        None,
+    );
+    assert_eq!(array, expected);
+    assert_eq!(array.fields(), expected.fields());
+}
+
+#[test]
+fn dense() {
+    let data_type = DataType::Union(fields(), None, UnionMode::Dense);
+    let mut array = new_array(data_type.clone());
+    push(&mut array, [0, 1]);
+    assert_eq!(array.offsets(), Some([0, 0, 1, 2].as_ref()));
+
+    let array: UnionArray = array.into();
+
+    let expected = UnionArray::from_data(
+        data_type,
+        Buffer::from_slice([0, 1, 0, 0]),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(&["a"])),
+        ],
+        Some(Buffer::from_slice([0, 0, 1, 2])),
+    );
+    assert_eq!(array, expected);
+    assert_eq!(array.fields(), expected.fields());
+}
+
+#[test]
+fn type_ids() {
+    let data_type = DataType::Union(fields(), Some(vec![5, 7]), UnionMode::Dense);
+    let mut array = new_array(data_type.clone());
+    push(&mut array, [5, 7]);
+    assert!(array.try_push(0).is_err());
+
+    let array = array.as_box();
+    let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(array.types().as_slice(), &[5, 7, 5, 5]);
+    assert_eq!(
+        array.value(1).as_ref(),
+        &UnionScalar::new(data_type, 7, Arc::new(Utf8Scalar::<i32>::new(Some("a")))) as &dyn Scalar
+    );
+}
+
+#[test]
+fn push_without_item() {
+    let mut array = new_array(DataType::Union(fields(), None, UnionMode::Sparse));
+    assert!(array.try_push(0).is_err());
+
+    // an item pushed to a child of another type
+    array
+        .value::<MutableUtf8Array<i32>>(1)
+        .unwrap()
+        .push(Some("a"));
+    assert!(array.try_push(0).is_err());
+    assert_eq!(array.len(), 0);
+}
+
+#[test]
+fn push_scalar() {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(fields(), None, mode);
+        let array = UnionArray::from_data(
+            data_type.clone(),
+            Buffer::from_slice([0, 1, 0]),
+            vec![
+                Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), None])),
+            ],
+            (!mode.is_sparse()).then(|| Buffer::from_slice([0, 1, 2])),
+        );
+
+        let mut mutable = new_array(data_type.clone());
+        for i in 0..array.len() {
+            mutable.try_push_scalar(array.value(i).as_ref()).unwrap();
+        }
+        // the value of a scalar must be of the data type of its type's field
+        let invalid = UnionScalar::new(data_type, 0, Arc::new(Utf8Scalar::<i32>::new(Some("c"))));
+        assert!(mutable.try_push_scalar(&invalid).is_err());
+        assert_eq!(mutable.len(), 3);
+
+        assert_eq!(mutable.as_box().as_ref(), &array as &dyn Array);
+    }
+}
+
+#[test]
+fn truncate() {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(fields(), None, mode);
+        let mut array = new_array(data_type.clone());
+        push(&mut array, [0, 1]);
+        array.truncate(2);
+        assert_eq!(array.len(), 2);
+        // the children keep the items of the remaining slots only
+        let lengths = array.fields().iter().map(|x| x.len()).collect::<Vec<_>>();
+        if mode.is_sparse() {
+            assert_eq!(lengths, [2, 2]);
+        } else {
+            assert_eq!(lengths, [1, 1]);
+        }
+
+        // the array can still be pushed to
+        array
+            .value::<MutablePrimitiveArray<i32>>(0)
+            .unwrap()
+            .push(Some(3));
+        array.push(0);
+
+        let mut expected = new_array(data_type);
+        expected
+            .value::<MutablePrimitiveArray<i32>>(0)
+            .unwrap()
+            .push(Some(1));
+        expected.push(0);
+        expected
+            .value::<MutableUtf8Array<i32>>(1)
+            .unwrap()
+            .push(Some("a"));
+        expected.push(1);
+        expected
+            .value::<MutablePrimitiveArray<i32>>(0)
+            .unwrap()
+            .push(Some(3));
+        expected.push(0);
+        assert_eq!(array.as_box(), expected.as_box());
+    }
+}
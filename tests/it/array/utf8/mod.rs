@@ -165,6 +165,67 @@ fn decreasing_offset_and_utf8_panics() {
     let _ = Utf8Array::<i32>::from_data(DataType::Utf8, offsets, values, None);
 }
 
+#[test]
+fn try_new() {
+    let offsets = Buffer::from_slice([0, 2, 4]);
+    let values = Buffer::from_slice([207, 128, 207, 128]);
+    let array = Utf8Array::<i32>::try_new(DataType::Utf8, offsets, values, None).unwrap();
+    assert_eq!(array.value(1), "π");
+
+    let offsets = Buffer::from_slice([0, 1]);
+    let values = Buffer::from_slice(b"a");
+    let validity = Some(Bitmap::from([true]));
+    assert!(Utf8Array::<i64>::try_new(DataType::LargeUtf8, offsets, values, validity).is_ok());
+}
+
+#[test]
+fn try_new_errors() {
+    let cases: Vec<(Vec<i32>, Vec<u8>)> = vec![
+        // invalid utf8
+        (vec![0, 4], vec![0, 159, 146, 150]),
+        // valid utf8, but offsets not on char boundaries
+        (vec![0, 1, 2], vec![207, 128]),
+        // decreasing offsets
+        (vec![0, 2, 1], b"abbb".to_vec()),
+        (vec![0, 2, 4, 2], vec![207, 128, 207, 128, 207, 128]),
+        // out of bounds
+        (vec![0, 10, 11], b"abbb".to_vec()),
+        // negative first offset
+        (vec![-1, 2], b"abbb".to_vec()),
+        // empty offsets
+        (vec![], b"abbb".to_vec()),
+    ];
+    for (offsets, values) in cases {
+        let result = Utf8Array::<i32>::try_new(DataType::Utf8, offsets.into(), values.into(), None);
+        assert!(result.is_err());
+    }
+
+    let offsets = Buffer::from_slice([0, 1]);
+    let values = Buffer::from_slice(b"a");
+    assert!(
+        Utf8Array::<i32>::try_new(DataType::Int8, offsets.clone(), values.clone(), None).is_err()
+    );
+
+    let validity = Some(Bitmap::from([true, false]));
+    assert!(Utf8Array::<i32>::try_new(DataType::Utf8, offsets, values, validity).is_err());
+}
+
+#[test]
+fn try_new_unchecked_utf8() {
+    let offsets = Buffer::from_slice([0, 2, 4]);
+    let values = Buffer::from_slice([207, 128, 207, 128]);
+    let array = unsafe {
+        Utf8Array::<i32>::try_new_unchecked_utf8(DataType::Utf8, offsets, values, None).unwrap()
+    };
+    assert_eq!(array.value(0), "π");
+
+    let offsets = Buffer::from_slice([0, 2, 1]);
+    let values = Buffer::from_slice(b"ab");
+    let result =
+        unsafe { Utf8Array::<i32>::try_new_unchecked_utf8(DataType::Utf8, offsets, values, None) };
+    assert!(result.is_err());
+}
+
 #[test]
 #[should_panic]
 fn index_out_of_bounds_panics() {
@@ -80,3 +80,62 @@ fn test_extend_trusted_len() {
         Some(&Bitmap::from_u8_slice(&[0b00011011], 5))
     );
 }
+
+#[test]
+fn reserve() {
+    let mut a = MutableUtf8Array::<i32>::new();
+    a.reserve(10, 100);
+    assert!(a.offsets_capacity() >= 11);
+    assert!(a.values_capacity() >= 100);
+}
+
+#[test]
+fn push_unchecked() {
+    let mut a = MutableUtf8Array::<i32>::new();
+    unsafe {
+        a.push_unchecked(Some("a".as_bytes()));
+        a.push_unchecked::<&[u8]>(None);
+        a.push_unchecked(Some("bb"));
+    }
+    let array: Utf8Array<i32> = a.into();
+    assert_eq!(
+        array,
+        Utf8Array::<i32>::from(&[Some("a"), None, Some("bb")])
+    );
+}
+
+#[test]
+fn extend_trusted_len() {
+    let mut a = MutableUtf8Array::<i32>::new();
+    a.push(Some("a"));
+    a.extend_trusted_len(vec![Some("b"), None].into_iter());
+    a.extend_trusted_len_values(vec!["c"].into_iter());
+    let array: Utf8Array<i32> = a.into();
+    assert_eq!(
+        array,
+        Utf8Array::<i32>::from(&[Some("a"), Some("b"), None, Some("c")])
+    );
+}
+
+#[test]
+fn extend_constant() {
+    let mut a = MutableUtf8Array::<i32>::new();
+    a.extend_constant(2, Some("ab"));
+    a.push(Some("c"));
+    a.extend_constant(2, None::<&str>);
+    a.push(Some("d"));
+    a.extend_constant(1, Some(""));
+    let array: Utf8Array<i32> = a.into();
+    assert_eq!(
+        array,
+        Utf8Array::<i32>::from(&[
+            Some("ab"),
+            Some("ab"),
+            Some("c"),
+            None,
+            None,
+            Some("d"),
+            Some("")
+        ])
+    );
+}
@@ -1,9 +1,36 @@
 use proptest::prelude::*;
 
-use arrow2::bitmap::Bitmap;
+use arrow2::bitmap::{binary_assign, Bitmap, MutableBitmap};
 
 use crate::bitmap::bitmap_strategy;
 
+/// Returns a strategy of two [`Bitmap`]s with the same length, each sliced from a random offset in `0..=70`
+fn two_bitmaps_strategy() -> impl Strategy<Value = (Bitmap, Bitmap)> {
+    (0..=70usize, 0..=70usize, 0..300usize)
+        .prop_flat_map(|(lhs_offset, rhs_offset, len)| {
+            (
+                prop::collection::vec(any::<bool>(), lhs_offset + len),
+                prop::collection::vec(any::<bool>(), rhs_offset + len),
+                Just((lhs_offset, rhs_offset, len)),
+            )
+        })
+        .prop_map(|(lhs, rhs, (lhs_offset, rhs_offset, len))| {
+            (
+                Bitmap::from(&lhs).slice(lhs_offset, len),
+                Bitmap::from(&rhs).slice(rhs_offset, len),
+            )
+        })
+}
+
+fn naive<F: Fn(bool, bool) -> bool>(lhs: &Bitmap, rhs: &Bitmap, op: F) -> Bitmap {
+    lhs.iter().zip(rhs.iter()).map(|(x, y)| op(x, y)).collect()
+}
+
+fn assert_same(result: Bitmap, expected: Bitmap) {
+    assert_eq!(result.null_count(), expected.null_count());
+    assert_eq!(result, expected);
+}
+
 proptest! {
     /// Asserts that !bitmap equals all bits flipped
     #[test]
@@ -12,5 +39,47 @@ proptest! {
         let not_bitmap: Bitmap = bitmap.iter().map(|x| !x).collect();
 
         assert_eq!(!&bitmap, not_bitmap);
+        assert_eq!((!&bitmap).null_count(), not_bitmap.null_count());
+    }
+
+    /// Asserts that and, or and xor equal their per-bit counterparts for arbitrary offsets
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn binary_ops((lhs, rhs) in two_bitmaps_strategy()) {
+        assert_same(lhs.and(&rhs), naive(&lhs, &rhs, |x, y| x & y));
+        assert_same(&lhs | &rhs, naive(&lhs, &rhs, |x, y| x | y));
+        assert_same(lhs.xor(&rhs), naive(&lhs, &rhs, |x, y| x ^ y));
     }
+
+    /// Asserts that the in-place operations equal their per-bit counterparts for arbitrary offsets
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn binary_assign_ops((lhs, rhs) in two_bitmaps_strategy()) {
+        let mut result: MutableBitmap = lhs.iter().collect();
+        result &= &rhs;
+        assert_same(result.into(), naive(&lhs, &rhs, |x, y| x & y));
+
+        let mut result: MutableBitmap = lhs.iter().collect();
+        result |= &rhs;
+        assert_same(result.into(), naive(&lhs, &rhs, |x, y| x | y));
+
+        let mut result: MutableBitmap = lhs.iter().collect();
+        result ^= &rhs;
+        assert_same(result.into(), naive(&lhs, &rhs, |x, y| x ^ y));
+
+        let result: MutableBitmap = lhs.iter().collect();
+        let result = !result;
+        assert_same(result.into(), lhs.iter().map(|x| !x).collect());
+    }
+}
+
+#[test]
+fn assign_keeps_pushing() {
+    let mut lhs = MutableBitmap::from([true, true, false]);
+    let rhs = Bitmap::from([true, false, false, true]).slice(1, 3);
+    binary_assign(&mut lhs, &rhs, |x, y| x | y);
+    lhs.push(false);
+    lhs.push(true);
+    let lhs: Bitmap = lhs.into();
+    assert_eq!(lhs, Bitmap::from([true, true, true, false, true]));
 }
@@ -39,3 +39,119 @@ fn debug() {
 
     assert_eq!(format!("{:?}", b), "[0b111110__, 0b_______1]");
 }
+
+#[test]
+fn into_mut() {
+    let b = Bitmap::from([true, false, true, true, false, true, true, true, false]);
+    let shared = b.clone();
+    let b = b.into_mut().unwrap_left();
+    drop(shared);
+    let mut b = b.into_mut().unwrap_right();
+    b.push(true);
+    let b: Bitmap = b.into();
+    assert_eq!(
+        b,
+        Bitmap::from([true, false, true, true, false, true, true, true, false, true])
+    );
+
+    let b = Bitmap::from([true, false, true]).slice(1, 2);
+    assert!(b.into_mut().is_left());
+}
+
+#[test]
+fn count_zeros_ones() {
+    let b = Bitmap::from([
+        true, false, true, true, false, true, true, true, false, false,
+    ]);
+    assert_eq!(b.count_zeros(0, 10), 4);
+    assert_eq!(b.count_ones(0, 10), 6);
+    assert_eq!(b.count_zeros(1, 4), 2);
+    assert_eq!(b.count_ones(2, 6), 5);
+
+    let b = b.slice(1, 8);
+    assert_eq!(b.count_zeros(0, 8), 3);
+    assert_eq!(b.count_ones(7, 1), 0);
+}
+
+#[test]
+#[should_panic]
+fn count_zeros_out_of_bounds() {
+    let b = Bitmap::from([true, false, true]).slice(1, 2);
+    b.count_zeros(1, 2);
+}
+
+#[test]
+fn slice_null_count() {
+    let values = (0..200).map(|x| x % 3 != 0).collect::<Vec<_>>();
+    let b = Bitmap::from(&values);
+
+    // sliced multiple times, both on the small and the large chunk path
+    for (offset, length) in [(5, 190), (17, 20), (0, 3), (100, 90)] {
+        let b = b.clone().slice(3, 195).slice(offset, length);
+        let expected = values[3 + offset..3 + offset + length]
+            .iter()
+            .filter(|x| !**x)
+            .count();
+        assert_eq!(b.null_count(), expected);
+    }
+}
+
+#[test]
+fn eq_with_offset() {
+    let lhs = (0..100).map(|x| x % 3 == 0).collect::<Bitmap>();
+    let rhs = (0..170).map(|x| x % 3 == 0).collect::<Bitmap>();
+    assert!(lhs.eq_with_offset(3, &rhs, 69, 97));
+    assert!(lhs.eq_with_offset(1, &rhs.clone().slice(1, 150), 69, 70));
+    assert!(!lhs.eq_with_offset(1, &rhs, 69, 70));
+}
+
+#[test]
+fn intersects_with() {
+    let lhs = (0..100).map(|x| x % 2 == 0).collect::<Bitmap>();
+    let rhs = (0..101).map(|x| x % 2 == 0).collect::<Bitmap>();
+    assert!(lhs.intersects_with(&rhs.clone().slice(0, 100)));
+    assert!(!lhs.intersects_with(&rhs.clone().slice(1, 100)));
+
+    // the only bit in common is the last one
+    let lhs = (0..70).map(|x| x == 69).collect::<Bitmap>();
+    let rhs = (0..73).map(|x| x == 72).collect::<Bitmap>().slice(3, 70);
+    assert!(lhs.intersects_with(&rhs));
+}
+
+#[test]
+fn from_owned_ptr() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct Owner {
+        data: Vec<u8>,
+        drops: Arc<AtomicUsize>,
+    }
+
+    impl Drop for Owner {
+        fn drop(&mut self) {
+            self.drops.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let drops = Arc::new(AtomicUsize::new(0));
+    let owner = Owner {
+        data: vec![0b00001101, 0b00000001],
+        drops: drops.clone(),
+    };
+    let ptr = owner.data.as_ptr();
+    let bitmap = unsafe { Bitmap::from_owned_ptr(ptr, 9, Arc::new(owner)) }.unwrap();
+    assert_eq!(bitmap.null_count(), 5);
+
+    let sliced = bitmap.clone().slice(2, 7);
+    assert_eq!(
+        sliced,
+        Bitmap::from([true, true, false, false, false, false, true])
+    );
+    let bitmap = bitmap.into_mut().unwrap_left();
+    drop(bitmap);
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+    drop(sliced);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
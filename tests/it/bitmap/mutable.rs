@@ -144,13 +144,13 @@ fn extend_from_bitmap() {
     let mut bitmap = MutableBitmap::new();
 
     // call is optimized to perform a memcopy
-    bitmap.extend_from_bitmap(&other);
+    bitmap.extend_from_bitmap(&other, 0, other.len());
 
     assert_eq!(bitmap.len(), 3);
     assert_eq!(bitmap.as_slice()[0], 0b00000101);
 
     // this call iterates over all bits
-    bitmap.extend_from_bitmap(&other);
+    bitmap.extend_from_bitmap(&other, 0, other.len());
 
     assert_eq!(bitmap.len(), 6);
     assert_eq!(bitmap.as_slice()[0], 0b00101101);
@@ -162,7 +162,7 @@ fn extend_from_bitmap_offset() {
     let mut bitmap = MutableBitmap::from_vec(vec![1, 0, 0b00101010], 22);
 
     // call is optimized to perform a memcopy
-    bitmap.extend_from_bitmap(&other);
+    bitmap.extend_from_bitmap(&other, 0, other.len());
 
     assert_eq!(bitmap.len(), 22 + 8);
     assert_eq!(bitmap.as_slice(), &[1, 0, 0b11101010, 0b00001111]);
@@ -172,7 +172,7 @@ fn extend_from_bitmap_offset() {
     let mut bitmap = MutableBitmap::from_vec(vec![1, 0, 0b00101010], 22);
 
     // call is optimized to perform a memcopy
-    bitmap.extend_from_bitmap(&other);
+    bitmap.extend_from_bitmap(&other, 0, other.len());
 
     assert_eq!(bitmap.len(), 22 + 20);
     assert_eq!(
@@ -341,3 +341,125 @@ fn shrink_to_fit() {
     a.shrink_to_fit();
     assert!(a.capacity() < 1025);
 }
+
+fn pattern(len: usize) -> Vec<bool> {
+    (0..len).map(|x| (x * 7) % 3 == 0 || x % 11 == 0).collect()
+}
+
+#[test]
+fn extend_from_bitmap_exhaustive() {
+    let source = pattern(260);
+    let bitmap = Bitmap::from(&source);
+    for own_length in [0, 1, 7, 8, 9, 63, 64, 65] {
+        let own = pattern(own_length)
+            .into_iter()
+            .map(|x| !x)
+            .collect::<Vec<_>>();
+        for offset in 0..=130 {
+            for length in 0..=130 {
+                let mut result = MutableBitmap::from(&own);
+                result.extend_from_bitmap(&bitmap, offset, length);
+
+                let expected = own
+                    .iter()
+                    .chain(source[offset..offset + length].iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                assert_eq!(result.len(), expected.len());
+                assert_eq!(
+                    result.iter().collect::<Vec<_>>(),
+                    expected,
+                    "own_length {}, offset {}, length {}",
+                    own_length,
+                    offset,
+                    length
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn extend_from_bitmap_sliced() {
+    let source = pattern(200);
+    let bitmap = Bitmap::from(&source).slice(3, 190);
+    let mut result = MutableBitmap::from(&[true]);
+    result.extend_from_bitmap(&bitmap, 5, 100);
+    result.push(false);
+
+    let expected = std::iter::once(true)
+        .chain(source[8..108].iter().copied())
+        .chain(std::iter::once(false))
+        .collect::<Vec<_>>();
+    assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+#[should_panic]
+fn extend_from_bitmap_out_of_bounds() {
+    let bitmap = Bitmap::from(&[true, false, true]).slice(1, 2);
+    let mut result = MutableBitmap::new();
+    result.extend_from_bitmap(&bitmap, 1, 2);
+}
+
+#[test]
+fn set_range_exhaustive() {
+    let values = pattern(130);
+    for value in [true, false] {
+        for start in 0..=130 {
+            for length in 0..=130 - start {
+                let mut result = MutableBitmap::from(&values);
+                result.set_range(start, length, value);
+
+                let mut expected = values.clone();
+                expected[start..start + length]
+                    .iter_mut()
+                    .for_each(|x| *x = value);
+                assert_eq!(
+                    result.iter().collect::<Vec<_>>(),
+                    expected,
+                    "start {}, length {}",
+                    start,
+                    length
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn from_chunk_iter() {
+    let chunks = vec![u64::MAX, 0b101];
+    for length in 0..=128 {
+        let result = MutableBitmap::from_chunk_iter(chunks.iter().copied(), length);
+        let expected = (0..length)
+            .map(|i| i < 64 || i == 64 || i == 66)
+            .collect::<Vec<_>>();
+        assert_eq!(result.len(), length);
+        assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn from_chunk_iter_too_short() {
+    MutableBitmap::from_chunk_iter(std::iter::once(0u64), 65);
+}
+
+#[test]
+fn truncate() {
+    let values = (0..20).map(|i| i % 3 == 0).collect::<Vec<_>>();
+    for len in 0..=21 {
+        let mut bitmap = MutableBitmap::from(values.as_slice());
+        bitmap.truncate(len);
+        let expected = &values[..len.min(values.len())];
+        assert_eq!(bitmap.iter().collect::<Vec<_>>(), expected);
+
+        // pushing after truncating overwrites the dropped bits
+        bitmap.extend_constant(3, false);
+        assert_eq!(
+            bitmap.null_count(),
+            expected.iter().filter(|x| !**x).count() + 3
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use proptest::prelude::*;
+
+use arrow2::bitmap::utils::{combine_validities, combine_validities_with_null_count};
+use arrow2::bitmap::Bitmap;
+
+/// Returns a strategy of `n` optional [`Bitmap`]s with the same length, each sliced from a
+/// random offset in `0..=70`
+fn validities_strategy(n: usize) -> impl Strategy<Value = Vec<Option<Bitmap>>> {
+    (0..300usize).prop_flat_map(move |len| {
+        prop::collection::vec(
+            prop::option::of((0..=70usize).prop_flat_map(move |offset| {
+                prop::collection::vec(any::<bool>(), offset + len)
+                    .prop_map(move |bits| Bitmap::from(&bits).slice(offset, len))
+            })),
+            n,
+        )
+    })
+}
+
+fn naive(validities: &[Option<Bitmap>]) -> Option<Bitmap> {
+    let validities = validities.iter().flatten().collect::<Vec<_>>();
+    let len = validities.first()?.len();
+    Some(
+        (0..len)
+            .map(|i| validities.iter().all(|validity| validity.get_bit(i)))
+            .collect(),
+    )
+}
+
+fn check(validities: &[Option<Bitmap>]) {
+    let refs = validities.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+    let expected = naive(validities);
+
+    let (result, null_count) = combine_validities_with_null_count(&refs);
+    assert_eq!(result, expected);
+    assert_eq!(null_count, expected.as_ref().map_or(0, |x| x.null_count()));
+    assert_eq!(
+        result.as_ref().map(|x| x.null_count()),
+        expected.as_ref().map(|x| x.null_count())
+    );
+    assert_eq!(combine_validities(&refs), expected);
+}
+
+#[test]
+fn none() {
+    assert_eq!(combine_validities(&[]), None);
+    assert_eq!(combine_validities_with_null_count(&[None, None]), (None, 0));
+}
+
+#[test]
+fn four() {
+    let a =
+        Bitmap::from(&[true, true, true, false, true, true, true, true, true, true]).slice(1, 8);
+    let b = Bitmap::from(&[true, false, true, true, true, true, true, true]);
+    let c = Bitmap::from(&[false, true, true, true, true, true, true, true, true]).slice(1, 8);
+    let d = Bitmap::from(&[true, true, true, true, true, true, true, false]);
+
+    let (result, null_count) =
+        combine_validities_with_null_count(&[Some(&a), Some(&b), None, Some(&c), Some(&d)]);
+    let expected = Bitmap::from(&[true, false, false, true, true, true, true, false]);
+    assert_eq!(result, Some(expected));
+    assert_eq!(null_count, 3);
+}
+
+proptest! {
+    /// Asserts that combining validities with random offsets equals the per-bit `AND`
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn two(validities in validities_strategy(2)) {
+        check(&validities)
+    }
+
+    /// Asserts that combining 4 validities with random offsets equals the per-bit `AND`
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn four_random(validities in validities_strategy(4)) {
+        check(&validities)
+    }
+}
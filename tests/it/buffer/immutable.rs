@@ -74,3 +74,97 @@ fn from_vec() {
     assert_eq!(buffer.len(), 3);
     assert_eq!(buffer.as_slice(), &[0, 1, 2]);
 }
+
+#[test]
+fn into_mut() {
+    let buffer = Buffer::<i32>::from_slice([0, 1, 2]);
+    let shared = buffer.clone();
+    let buffer = buffer.into_mut().unwrap_left();
+    drop(shared);
+    assert_eq!(buffer.into_mut().unwrap_right(), vec![0, 1, 2]);
+
+    // sliced buffers are not converted.
+    let buffer = Buffer::<i32>::from_slice([0, 1, 2]).slice(1, 2);
+    assert!(buffer.into_mut().is_left());
+
+    let buffer = Buffer::<i32>::from_slice([0, 1, 2]).slice(0, 2);
+    assert_eq!(buffer.into_mut().unwrap_right(), vec![0, 1]);
+}
+
+#[test]
+fn into_vec() {
+    let vec = vec![0, 1, 2];
+    let ptr = vec.as_ptr();
+    let buffer = Buffer::<i32>::from(vec);
+    assert_eq!(buffer.as_ptr(), ptr);
+    let vec = buffer.into_vec().unwrap();
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec, vec![0, 1, 2]);
+
+    // shared
+    let buffer = Buffer::<i32>::from(vec);
+    let shared = buffer.clone();
+    let buffer = buffer.into_vec().unwrap_err();
+    drop(shared);
+    assert_eq!(buffer.into_vec().unwrap().as_ptr(), ptr);
+
+    // offsetted
+    let buffer = Buffer::<i32>::from_slice([0, 1, 2]).slice(1, 2);
+    let buffer = buffer.into_vec().unwrap_err();
+    assert_eq!(buffer.as_slice(), &[1, 2]);
+}
+
+struct DropCounter<T> {
+    data: Vec<T>,
+    drops: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl<T> Drop for DropCounter<T> {
+    fn drop(&mut self) {
+        self.drops.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn from_owned_ptr() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let drops = Arc::new(AtomicUsize::new(0));
+    let owner = DropCounter {
+        data: vec![1i64, 2, 3, 4],
+        drops: drops.clone(),
+    };
+    let ptr = owner.data.as_ptr();
+    let buffer = unsafe { Buffer::from_owned_ptr(ptr, 4, Arc::new(owner)) }.unwrap();
+    assert_eq!(buffer.as_slice(), &[1, 2, 3, 4]);
+
+    let cloned = buffer.clone();
+    let sliced = buffer.clone().slice(1, 2);
+    assert_eq!(sliced.as_slice(), &[2, 3]);
+
+    // memory not owned by Rust is never re-used
+    let buffer = buffer.into_mut().unwrap_left();
+    let buffer = buffer.into_vec().unwrap_err();
+    drop(buffer);
+    drop(cloned);
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+    assert_eq!(sliced.as_slice(), &[2, 3]);
+
+    drop(sliced);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn from_owned_ptr_errors() {
+    use std::sync::Arc;
+
+    let result = unsafe { Buffer::<i32>::from_owned_ptr(std::ptr::null(), 0, Arc::new(())) };
+    assert!(result.is_err());
+
+    let data = vec![0u8; 16];
+    let aligned = data.as_ptr().wrapping_add(data.as_ptr().align_offset(4));
+    let unaligned = aligned.wrapping_add(1) as *const u32;
+    let result = unsafe { Buffer::<u32>::from_owned_ptr(unaligned, 2, Arc::new(data)) };
+    assert!(result.is_err());
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunked::ChunkedColumns;
+use arrow2::columns::Columns;
+use arrow2::error::Result;
+
+fn columns(a: &[i32], b: &[&str]) -> Columns<Arc<dyn Array>> {
+    Columns::new(vec![
+        Arc::new(Int32Array::from_slice(a)) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(b)),
+    ])
+}
+
+fn chunked() -> ChunkedColumns {
+    ChunkedColumns::new(vec![
+        columns(&[1, 2, 3], &["a", "b", "c"]),
+        columns(&[4], &["d"]),
+        columns(&[5, 6], &["e", "f"]),
+    ])
+}
+
+#[test]
+fn basics() {
+    let chunked = chunked();
+    assert_eq!(chunked.len(), 6);
+    assert!(!chunked.is_empty());
+    assert_eq!(chunked.chunks().len(), 3);
+}
+
+#[test]
+fn try_new_different_types() {
+    let other = Columns::new(vec![
+        Arc::new(Int64Array::from_slice(&[1])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(&["a"])),
+    ]);
+    assert!(ChunkedColumns::try_new(vec![columns(&[1], &["a"]), other]).is_err());
+    assert!(ChunkedColumns::try_new(vec![columns(&[1], &["a"]), columns(&[], &[])]).is_ok());
+}
+
+#[test]
+fn slice_within_chunk() {
+    let sliced = chunked().slice(1, 2);
+    assert_eq!(sliced.len(), 2);
+    assert_eq!(sliced.chunks(), &[columns(&[2, 3], &["b", "c"])]);
+}
+
+#[test]
+fn slice_across_boundaries() {
+    let sliced = chunked().slice(2, 3);
+    assert_eq!(sliced.len(), 3);
+    assert_eq!(
+        sliced.chunks(),
+        &[
+            columns(&[3], &["c"]),
+            columns(&[4], &["d"]),
+            columns(&[5], &["e"])
+        ]
+    );
+
+    let sliced = chunked().slice(3, 3);
+    assert_eq!(
+        sliced.chunks(),
+        &[columns(&[4], &["d"]), columns(&[5, 6], &["e", "f"])]
+    );
+}
+
+#[test]
+fn slice_empty() {
+    let sliced = chunked().slice(6, 0);
+    assert_eq!(sliced.len(), 0);
+    assert!(sliced.is_empty());
+    assert!(sliced.chunks().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn slice_out_of_bounds() {
+    chunked().slice(5, 2);
+}
+
+#[test]
+fn rechunk() -> Result<()> {
+    let rechunked = chunked().rechunk(2)?;
+    assert_eq!(rechunked.len(), 6);
+    assert_eq!(
+        rechunked.chunks(),
+        &[
+            columns(&[1, 2], &["a", "b"]),
+            columns(&[3, 4], &["c", "d"]),
+            columns(&[5, 6], &["e", "f"])
+        ]
+    );
+
+    let rechunked = chunked().rechunk(4)?;
+    assert_eq!(
+        rechunked.chunks(),
+        &[
+            columns(&[1, 2, 3, 4], &["a", "b", "c", "d"]),
+            columns(&[5, 6], &["e", "f"])
+        ]
+    );
+
+    let rechunked = chunked().slice(1, 4).rechunk(10)?;
+    assert_eq!(
+        rechunked.chunks(),
+        &[columns(&[2, 3, 4, 5], &["b", "c", "d", "e"])]
+    );
+    Ok(())
+}
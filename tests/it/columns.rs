@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::columns::Columns;
+
+fn columns(a: &[i32], b: &[&str]) -> Columns<Arc<dyn Array>> {
+    Columns::new(vec![
+        Arc::new(Int32Array::from_slice(a)) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(b)),
+    ])
+}
+
+#[test]
+fn slice() {
+    let columns = columns(&[1, 2, 3], &["a", "b", "c"]);
+    assert_eq!(columns.slice(1, 2), self::columns(&[2, 3], &["b", "c"]));
+}
+
+#[test]
+fn chunks() {
+    let columns = columns(&[1, 2, 3, 4, 5], &["a", "b", "c", "d", "e"]);
+    let chunks = columns.chunks(2).collect::<Vec<_>>();
+    assert_eq!(
+        chunks,
+        vec![
+            self::columns(&[1, 2], &["a", "b"]),
+            self::columns(&[3, 4], &["c", "d"]),
+            self::columns(&[5], &["e"]),
+        ]
+    );
+
+    assert_eq!(columns.chunks(5).collect::<Vec<_>>(), vec![columns.clone()]);
+    assert_eq!(columns.chunks(10).collect::<Vec<_>>(), vec![columns]);
+}
+
+#[test]
+fn chunks_empty() {
+    let columns = columns(&[], &[]);
+    assert_eq!(columns.chunks(2).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn chunks_zero() {
+    let columns = columns(&[1], &["a"]);
+    let _ = columns.chunks(0);
+}
+
+#[test]
+fn head_tail() {
+    let columns = columns(&[1, 2, 3], &["a", "b", "c"]);
+    assert_eq!(columns.head(2), self::columns(&[1, 2], &["a", "b"]));
+    assert_eq!(columns.tail(2), self::columns(&[2, 3], &["b", "c"]));
+    assert_eq!(columns.head(5), columns);
+    assert_eq!(columns.tail(5), columns);
+    assert_eq!(columns.head(0), self::columns(&[], &[]));
+}
+
+#[test]
+fn eq() {
+    let columns = columns(&[1, 2], &["a", "b"]);
+    assert_eq!(columns, self::columns(&[1, 2], &["a", "b"]));
+    assert!(columns != self::columns(&[1, 2], &["a", "c"]));
+    assert!(columns != self::columns(&[], &[]));
+
+    // a different number of arrays
+    let first = Columns::new(vec![columns[0].clone()]);
+    assert!(columns != first);
+
+    // logical equality, irrespectively of offsets and how arrays are held
+    let sliced = Columns::new(vec![
+        Box::new(Int32Array::from_slice(&[0, 1, 2]).slice(1, 2)) as Box<dyn Array>,
+        Box::new(Utf8Array::<i32>::from_slice(&["a", "b", "c"]).slice(0, 2)),
+    ]);
+    assert_eq!(columns, sliced);
+}
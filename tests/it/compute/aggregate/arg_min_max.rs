@@ -0,0 +1,107 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{
+    arg_max, arg_max_boolean, arg_max_float, arg_max_primitive, arg_max_string, arg_min,
+    arg_min_boolean, arg_min_float, arg_min_primitive, arg_min_string,
+};
+use arrow2::datatypes::DataType;
+use arrow2::types::f16;
+
+#[test]
+fn primitive() {
+    let a = Int32Array::from([Some(5), None, Some(1), Some(9), Some(1), Some(9)]);
+    assert_eq!(arg_min_primitive(&a), Some(2));
+    assert_eq!(arg_max_primitive(&a), Some(3));
+}
+
+#[test]
+fn primitive_sliced() {
+    let a = Int32Array::from_slice([0, 5, 3, 10, 7]).slice(1, 3);
+    assert_eq!(arg_min_primitive(&a), Some(1));
+    assert_eq!(arg_max_primitive(&a), Some(2));
+}
+
+#[test]
+fn all_null() {
+    let a = Int32Array::from([None, None]);
+    assert_eq!(arg_min_primitive(&a), None);
+    assert_eq!(arg_max_primitive(&a), None);
+    assert_eq!(arg_min(&a).unwrap(), None);
+    assert_eq!(arg_max(&a).unwrap(), None);
+
+    let a = Int32Array::from_slice([]);
+    assert_eq!(arg_min_primitive(&a), None);
+    assert_eq!(arg_max_primitive(&a), None);
+}
+
+#[test]
+fn float_with_nans() {
+    let a = Float64Array::from([
+        Some(1.0),
+        Some(f64::NAN),
+        None,
+        Some(-0.0),
+        Some(0.0),
+        Some(f64::NAN),
+        Some(-0.0),
+    ]);
+    assert_eq!(arg_min_float(&a, true), Some(3));
+    assert_eq!(arg_max_float(&a, true), Some(1));
+    assert_eq!(arg_min_float(&a, false), Some(1));
+    assert_eq!(arg_max_float(&a, false), Some(0));
+
+    let a = Float64Array::from([Some(0.0), Some(-0.0), Some(0.0)]);
+    assert_eq!(arg_min_float(&a, true), Some(1));
+    assert_eq!(arg_max_float(&a, true), Some(0));
+
+    let a = Float32Array::from([None, Some(f32::NAN), Some(f32::NAN)]);
+    assert_eq!(arg_min_float(&a, true), Some(1));
+    assert_eq!(arg_max_float(&a, true), Some(1));
+}
+
+#[test]
+fn string() {
+    let a = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("c"), Some("a")]);
+    assert_eq!(arg_min_string(&a), Some(2));
+    assert_eq!(arg_max_string(&a), Some(3));
+}
+
+#[test]
+fn boolean() {
+    let a = BooleanArray::from([None, Some(true), Some(true)]);
+    assert_eq!(arg_min_boolean(&a), Some(1));
+    assert_eq!(arg_max_boolean(&a), Some(1));
+
+    let a = BooleanArray::from([None, None]);
+    assert_eq!(arg_min_boolean(&a), None);
+    assert_eq!(arg_max_boolean(&a), None);
+}
+
+#[test]
+fn dyn_() {
+    let a = Int128Array::from([Some(10), Some(-3), None, Some(20)]).to(DataType::Decimal(5, 2));
+    assert_eq!(arg_min(&a).unwrap(), Some(1));
+    assert_eq!(arg_max(&a).unwrap(), Some(3));
+
+    let a = Float32Array::from_slice([1.0, f32::NAN, -1.0]);
+    assert_eq!(arg_min(&a).unwrap(), Some(2));
+    assert_eq!(arg_max(&a).unwrap(), Some(1));
+
+    let a = PrimitiveArray::<f16>::from_slice([f16::from_f32(1.0), f16::from_f32(-1.0)]);
+    assert_eq!(arg_min(&a).unwrap(), Some(1));
+    assert_eq!(arg_max(&a).unwrap(), Some(0));
+
+    let a = Utf8Array::<i64>::from_slice(["b", "a"]);
+    assert_eq!(arg_min(&a).unwrap(), Some(1));
+    assert_eq!(arg_max(&a).unwrap(), Some(0));
+
+    let a = BinaryArray::<i32>::from_slice([b"b", b"c"]);
+    assert_eq!(arg_min(&a).unwrap(), Some(0));
+    assert_eq!(arg_max(&a).unwrap(), Some(1));
+
+    let a = BooleanArray::from_slice([true, false]);
+    assert_eq!(arg_min(&a).unwrap(), Some(1));
+    assert_eq!(arg_max(&a).unwrap(), Some(0));
+
+    let a = NullArray::from_data(DataType::Null, 2);
+    assert!(arg_min(&a).is_err());
+}
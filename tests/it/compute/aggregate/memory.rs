@@ -17,3 +17,17 @@ fn utf8() {
     let a = Utf8Array::<i32>::from_slice(&["aaa"]);
     assert_eq!(3 + 2 * std::mem::size_of::<i32>(), estimated_bytes_size(&a));
 }
+
+#[test]
+fn large_utf8() {
+    let a = Utf8Array::<i64>::from_slice(&["aaa"]);
+    assert_eq!(3 + 2 * std::mem::size_of::<i64>(), estimated_bytes_size(&a));
+}
+
+#[test]
+fn fixed_size_list() {
+    let values = std::sync::Arc::new(Int32Array::from_slice(&[1, 2, 3, 4]));
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), 2);
+    let a = FixedSizeListArray::from_data(data_type, values, None);
+    assert_eq!(4 * std::mem::size_of::<i32>(), estimated_bytes_size(&a));
+}
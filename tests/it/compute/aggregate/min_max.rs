@@ -1,7 +1,8 @@
 use arrow2::compute::aggregate::{
-    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
-    min_string,
+    max, max_binary, max_boolean, max_float, max_primitive, max_string, min_binary, min_boolean,
+    min_float, min_primitive, min_string,
 };
+use arrow2::scalar::PrimitiveScalar;
 use arrow2::{array::*, datatypes::DataType};
 
 #[test]
@@ -70,6 +71,65 @@ fn min_max_f64_nan() {
     assert_eq!(Some(1.0), max_primitive(&a));
 }
 
+#[test]
+fn min_max_float_nan() {
+    let a = Float64Array::from(&[None, Some(1.0), Some(f64::NAN)]);
+    assert_eq!(Some(1.0), min_float(&a, true));
+    assert!(max_float(&a, true).unwrap().is_nan());
+    assert!(min_float(&a, false).unwrap().is_nan());
+    assert_eq!(Some(1.0), max_float(&a, false));
+
+    let a = Float64Array::from(&[None, Some(f64::NAN)]);
+    assert!(min_float(&a, true).unwrap().is_nan());
+    assert!(max_float(&a, false).unwrap().is_nan());
+
+    let a = Float64Array::from(&[None::<f64>]);
+    assert_eq!(None, min_float(&a, true));
+}
+
+#[test]
+fn min_max_float_zeros() {
+    let a = Float32Array::from_slice(&[0.0, -0.0, 0.0]);
+    assert!(min_float(&a, true).unwrap().is_sign_negative());
+    assert!(max_float(&a, true).unwrap().is_sign_positive());
+
+    // the null slot is not considered
+    let a = Float32Array::from_slice(&[0.0, -0.0]).with_validity(Some([true, false].into()));
+    assert!(min_float(&a, true).unwrap().is_sign_positive());
+}
+
+#[test]
+fn min_max_dyn_float() {
+    let a = Float64Array::from_slice(&[1.0, f64::NAN]);
+    let max = max(&a).unwrap();
+    let max = max.as_any().downcast_ref::<PrimitiveScalar<f64>>().unwrap();
+    assert!(max.value().unwrap().is_nan());
+}
+
+#[test]
+fn min_max_f16() {
+    use arrow2::compute::aggregate::min;
+    use arrow2::types::f16;
+
+    let a = [Some(1.5), None, Some(-2.0), Some(0.5)]
+        .iter()
+        .map(|x| x.map(f16::from_f32))
+        .collect::<PrimitiveArray<f16>>();
+    let result = max(&a).unwrap();
+    assert_eq!(result.data_type(), &DataType::Float16);
+    let result = result
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<f16>>()
+        .unwrap();
+    assert_eq!(result.value(), Some(f16::from_f32(1.5)));
+    let result = min(&a).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<f16>>()
+        .unwrap();
+    assert_eq!(result.value(), Some(f16::from_f32(-2.0)));
+}
+
 #[test]
 fn min_max_f64_edge_cases() {
     let a: Float64Array = (0..100).map(|_| Some(f64::NEG_INFINITY)).collect();
@@ -1,3 +1,6 @@
+mod arg_min_max;
 mod memory;
 mod min_max;
+mod statistics;
 mod sum;
+mod variance;
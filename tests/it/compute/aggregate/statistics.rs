@@ -0,0 +1,152 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{
+    mode, mode_primitive, quantile, quantile_primitive, QuantileInterpolation,
+};
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Scalar};
+use arrow2::types::f16;
+
+use QuantileInterpolation::*;
+
+#[test]
+fn mode_basics() {
+    let a = Int32Array::from([Some(5), None, Some(1), Some(9), None, Some(9)]);
+    assert_eq!(mode_primitive(&a), Some(9));
+
+    // ties are resolved to the smallest value
+    let a = Int32Array::from_slice([3, 1, 2, 1, 3, 2]);
+    assert_eq!(mode_primitive(&a), Some(1));
+
+    let a = Int32Array::from_slice([3, 1, 2, 1, 3, 2]).slice(3, 3);
+    assert_eq!(mode_primitive(&a), Some(1));
+}
+
+#[test]
+fn mode_float() {
+    let a = Float64Array::from_slice([f64::NAN, 2.0, f64::NAN, 2.0, 1.5]);
+    assert_eq!(mode_primitive(&a), Some(2.0));
+
+    let a = Float64Array::from_slice([f64::NAN, f64::NAN, 2.0]);
+    assert!(mode_primitive(&a).unwrap().is_nan());
+
+    // `-0.0` and `0.0` are counted separately
+    let a = Float32Array::from_slice([-0.0, 0.0, 1.0, 1.0]);
+    assert_eq!(mode_primitive(&a), Some(1.0));
+}
+
+#[test]
+fn mode_empty() {
+    assert_eq!(mode_primitive(&Int32Array::from([None, None])), None);
+    assert_eq!(mode_primitive(&Int32Array::from_slice([])), None);
+}
+
+#[test]
+fn mode_dyn() {
+    let a = Int64Array::from([Some(1), Some(2), Some(2), None]).to(DataType::Date64);
+    let result = mode(&a).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &PrimitiveScalar::<i64>::new(DataType::Date64, Some(2)) as &dyn Scalar
+    );
+
+    let a = PrimitiveArray::<f16>::from_slice([f16::from_f32(1.5), f16::from_f32(1.5)]);
+    let result = mode(&a).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &PrimitiveScalar::<f16>::new(DataType::Float16, Some(f16::from_f32(1.5))) as &dyn Scalar
+    );
+
+    let a = Int8Array::from([None]);
+    assert!(!mode(&a).unwrap().is_valid());
+
+    assert!(mode(&Utf8Array::<i32>::from_slice(["a"])).is_err());
+}
+
+#[test]
+fn quantile_interpolations() {
+    // sorted non-null values: [1, 2, 4, 8, 16]
+    let a = Int32Array::from([Some(8), None, Some(1), Some(16), Some(4), Some(2)]);
+
+    // position 0.3125 * 4 = 1.25, i.e. between 2 and 4
+    assert_eq!(quantile_primitive(&a, 0.3125, Linear), Some(2.5));
+    assert_eq!(quantile_primitive(&a, 0.3125, Lower), Some(2.0));
+    assert_eq!(quantile_primitive(&a, 0.3125, Higher), Some(4.0));
+    assert_eq!(quantile_primitive(&a, 0.3125, Nearest), Some(2.0));
+    assert_eq!(quantile_primitive(&a, 0.3125, Midpoint), Some(3.0));
+
+    // position 0.6875 * 4 = 2.75, i.e. between 4 and 8
+    assert_eq!(quantile_primitive(&a, 0.6875, Linear), Some(7.0));
+    assert_eq!(quantile_primitive(&a, 0.6875, Lower), Some(4.0));
+    assert_eq!(quantile_primitive(&a, 0.6875, Nearest), Some(8.0));
+    assert_eq!(quantile_primitive(&a, 0.6875, Midpoint), Some(6.0));
+
+    // position 0.5 * 4 = 2 is exactly the value 4 for every interpolation
+    for interpolation in [Linear, Lower, Higher, Nearest, Midpoint] {
+        assert_eq!(quantile_primitive(&a, 0.5, interpolation), Some(4.0));
+    }
+}
+
+#[test]
+fn quantile_edges() {
+    let a = Float64Array::from_slice([3.0, -1.0, 2.5, 10.0]);
+    for interpolation in [Linear, Lower, Higher, Nearest, Midpoint] {
+        assert_eq!(quantile_primitive(&a, 0.0, interpolation), Some(-1.0));
+        assert_eq!(quantile_primitive(&a, 1.0, interpolation), Some(10.0));
+    }
+
+    // a single value is every quantile
+    let a = UInt8Array::from([None, Some(7)]);
+    for interpolation in [Linear, Lower, Higher, Nearest, Midpoint] {
+        assert_eq!(quantile_primitive(&a, 0.0, interpolation), Some(7.0));
+        assert_eq!(quantile_primitive(&a, 0.4, interpolation), Some(7.0));
+        assert_eq!(quantile_primitive(&a, 1.0, interpolation), Some(7.0));
+    }
+
+    // positions exactly between two values are resolved to the lower one by `Nearest`
+    let a = Int64Array::from_slice([10, 20]);
+    assert_eq!(quantile_primitive(&a, 0.5, Nearest), Some(10.0));
+    assert_eq!(quantile_primitive(&a, 0.5, Linear), Some(15.0));
+}
+
+#[test]
+fn quantile_nans() {
+    // NaNs are greater than any other value
+    let a = Float32Array::from_slice([f32::NAN, 1.0, 2.0]);
+    assert_eq!(quantile_primitive(&a, 0.5, Linear), Some(2.0));
+    assert!(quantile_primitive(&a, 1.0, Linear).unwrap().is_nan());
+}
+
+#[test]
+fn quantile_empty() {
+    assert_eq!(
+        quantile_primitive(&Int32Array::from([None, None]), 0.5, Linear),
+        None
+    );
+    assert_eq!(
+        quantile_primitive(&Int32Array::from_slice([]), 0.0, Linear),
+        None
+    );
+}
+
+#[test]
+#[should_panic]
+fn quantile_out_of_range() {
+    quantile_primitive(&Int32Array::from_slice([1]), 1.5, Linear);
+}
+
+#[test]
+fn quantile_dyn() {
+    let a = Int16Array::from_slice([1, 2, 3, 4]);
+    assert_eq!(quantile(&a, 0.5, Linear).unwrap(), Some(2.5));
+
+    let a = PrimitiveArray::<f16>::from_slice([f16::from_f32(1.0), f16::from_f32(2.0)]);
+    assert_eq!(quantile(&a, 0.5, Midpoint).unwrap(), Some(1.5));
+
+    assert_eq!(
+        quantile(&Int32Array::from([None]), 0.5, Linear).unwrap(),
+        None
+    );
+    assert!(quantile(&a, -0.1, Linear).is_err());
+    assert!(quantile(&a, f64::NAN, Linear).is_err());
+    assert!(quantile(&Utf8Array::<i32>::from_slice(["a"]), 0.5, Linear).is_err());
+}
@@ -25,6 +25,20 @@ fn test_primitive_array_float_sum() {
     assert!((16.5 - sum_primitive(&a).unwrap()).abs() < f64::EPSILON);
 }
 
+#[test]
+fn test_primitive_array_f16_sum() {
+    use arrow2::types::f16;
+
+    let a = [Some(1.5), None, Some(2.25), Some(0.5)]
+        .iter()
+        .map(|x| x.map(f16::from_f32))
+        .collect::<PrimitiveArray<f16>>();
+    assert_eq!(
+        &PrimitiveScalar::<f16>::from(Some(f16::from_f32(4.25))) as &dyn Scalar,
+        sum(&a).unwrap().as_ref()
+    );
+}
+
 #[test]
 fn test_primitive_array_sum_with_nulls() {
     let a = Int32Array::from(&[None, Some(2), Some(3), None, Some(5)]);
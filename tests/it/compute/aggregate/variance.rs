@@ -0,0 +1,128 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{
+    mean, mean_primitive, stddev, stddev_primitive, var, var_primitive, variance_accumulator,
+    VarianceAccumulator,
+};
+use arrow2::types::f16;
+
+fn assert_close(lhs: Option<f64>, rhs: f64) {
+    let lhs = lhs.unwrap();
+    assert!(
+        (lhs - rhs).abs() <= 1e-9 * rhs.abs().max(1.0),
+        "{} != {}",
+        lhs,
+        rhs
+    );
+}
+
+#[test]
+fn primitive() {
+    let a = Int32Array::from([
+        Some(2),
+        None,
+        Some(4),
+        Some(4),
+        Some(4),
+        Some(5),
+        Some(5),
+        Some(7),
+        Some(9),
+    ]);
+    assert_eq!(mean_primitive(&a), Some(5.0));
+    assert_eq!(var_primitive(&a, 0), Some(4.0));
+    assert_eq!(stddev_primitive(&a, 0), Some(2.0));
+    assert_close(var_primitive(&a, 1), 32.0 / 7.0);
+    assert_close(stddev_primitive(&a, 1), (32.0f64 / 7.0).sqrt());
+}
+
+#[test]
+fn float() {
+    let a = Float32Array::from_slice([1.5, 2.5, 3.5]).slice(1, 2);
+    assert_eq!(mean_primitive(&a), Some(3.0));
+    assert_eq!(var_primitive(&a, 0), Some(0.25));
+    assert_eq!(var_primitive(&a, 1), Some(0.5));
+}
+
+#[test]
+fn empty() {
+    let a = Int64Array::from([None, None]);
+    assert_eq!(mean_primitive(&a), None);
+    assert_eq!(var_primitive(&a, 0), None);
+
+    // the sample variance requires at least two values
+    let a = Int64Array::from([None, Some(3)]);
+    assert_eq!(mean_primitive(&a), Some(3.0));
+    assert_eq!(var_primitive(&a, 0), Some(0.0));
+    assert_eq!(var_primitive(&a, 1), None);
+    assert_eq!(stddev_primitive(&a, 1), None);
+}
+
+#[test]
+fn large_offset() {
+    // the naive `(sum(x^2) - sum(x)^2 / n) / n` is dominated by rounding errors here
+    let offset = 1e9;
+    let values = (0..1000)
+        .map(|x| offset + (x % 4) as f64)
+        .collect::<Vec<_>>();
+    let a = Float64Array::from_slice(&values);
+
+    let n = values.len() as f64;
+    let sum = values.iter().sum::<f64>();
+    let sum_squares = values.iter().map(|x| x * x).sum::<f64>();
+    let naive = (sum_squares - sum * sum / n) / n;
+    assert!((naive - 1.25).abs() > 1e-3);
+
+    assert_close(mean_primitive(&a), offset + 1.5);
+    assert_close(var_primitive(&a, 0), 1.25);
+}
+
+#[test]
+fn merge() {
+    let values = Int64Array::from_slice([1_000_000_007, 3, -20, 4, 1_000_000_007, 100, 8]);
+
+    let mut expected = VarianceAccumulator::default();
+    expected.extend_primitive(&values);
+
+    for split in 0..=values.len() {
+        let mut lhs = VarianceAccumulator::default();
+        lhs.extend_primitive(&values.slice(0, split));
+        let mut rhs = VarianceAccumulator::default();
+        rhs.extend_primitive(&values.slice(split, values.len() - split));
+        lhs.merge(&rhs);
+
+        assert_eq!(lhs.count(), expected.count());
+        assert_close(lhs.mean(), expected.mean().unwrap());
+        assert_close(lhs.variance(0), expected.variance(0).unwrap());
+    }
+
+    // merging an empty accumulator is a no-op
+    let mut lhs = expected;
+    lhs.merge(&VarianceAccumulator::default());
+    assert_eq!(lhs, expected);
+}
+
+#[test]
+fn from_parts() {
+    let accumulator = VarianceAccumulator::from_parts(4, 2.5, 5.0);
+    assert_eq!(accumulator.m2(), 5.0);
+    assert_eq!(accumulator.variance(0), Some(1.25));
+
+    let mut other = VarianceAccumulator::default();
+    other.extend_primitive(&UInt8Array::from_slice([1, 2, 3, 4]));
+    assert_eq!(other, accumulator);
+}
+
+#[test]
+fn dyn_() {
+    let a = UInt16Array::from_slice([1, 3]);
+    assert_eq!(mean(&a).unwrap(), Some(2.0));
+    assert_eq!(var(&a, 1).unwrap(), Some(2.0));
+    assert_eq!(stddev(&a, 0).unwrap(), Some(1.0));
+
+    let a = PrimitiveArray::<f16>::from([Some(f16::from_f32(1.0)), None, Some(f16::from_f32(2.0))]);
+    assert_eq!(variance_accumulator(&a).unwrap().count(), 2);
+    assert_eq!(mean(&a).unwrap(), Some(1.5));
+
+    assert_eq!(mean(&Int8Array::from([None])).unwrap(), None);
+    assert!(mean(&Utf8Array::<i32>::from_slice(["a"])).is_err());
+}
@@ -159,3 +159,29 @@ fn test_add_scalar_overflowing() {
     assert_eq!(result, expected);
     assert_eq!(overflow, Bitmap::from([false, true]));
 }
+
+#[test]
+fn test_add_assign() {
+    let mut a = MutablePrimitiveArray::from([Some(1i32), None, Some(3), Some(4)]);
+    let b = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+    add_assign(&mut a, &b);
+    let expected = Int32Array::from(&[Some(2), None, None, Some(8)]);
+    assert_eq!(Int32Array::from(a), expected);
+
+    // no validity on `lhs`
+    let mut a = MutablePrimitiveArray::from_slice([1i32, 2, 3]);
+    let b = Int32Array::from(&[Some(1), None, Some(3)]);
+    add_assign(&mut a, &b);
+    let expected = Int32Array::from(&[Some(2), None, Some(6)]);
+    assert_eq!(Int32Array::from(a), expected);
+}
+
+#[test]
+fn test_add_scalar_assign() {
+    let array = Int32Array::from(&[None, Some(6), None, Some(6)]);
+    let mut a = array.into_mut().unwrap_right();
+    add_scalar_assign(&mut a, &1);
+    add_scalar_assign(&mut a, &2);
+    let expected = Int32Array::from(&[None, Some(9), None, Some(9)]);
+    assert_eq!(Int32Array::from(a), expected);
+}
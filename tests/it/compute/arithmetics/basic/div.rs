@@ -99,3 +99,13 @@ fn test_div_scalar_checked() {
     let result = a.checked_div(&0);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_div_assign() {
+    let mut a = MutablePrimitiveArray::from([Some(10i32), Some(6), None, Some(4)]);
+    // the null slot of `b` contains a zero
+    let b = Int32Array::from(&[Some(5), None, Some(2), Some(4)]);
+    div_assign(&mut a, &b);
+    let expected = Int32Array::from(&[Some(2), None, None, Some(1)]);
+    assert_eq!(Int32Array::from(a), expected);
+}
@@ -4,3 +4,14 @@ mod mul;
 mod pow;
 mod rem;
 mod sub;
+
+#[test]
+fn test_negate_assign() {
+    use arrow2::array::{MutablePrimitiveArray, PrimitiveArray};
+    use arrow2::compute::arithmetics::basic::negate_assign;
+
+    let mut a = MutablePrimitiveArray::from([None, Some(6), None, Some(7)]);
+    negate_assign(&mut a);
+    let expected = PrimitiveArray::from([None, Some(-6), None, Some(-7)]);
+    assert_eq!(PrimitiveArray::from(a), expected);
+}
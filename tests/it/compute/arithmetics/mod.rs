@@ -7,6 +7,7 @@ use arrow2::compute::arithmetics::*;
 use arrow2::datatypes::DataType::*;
 use arrow2::datatypes::{IntervalUnit, TimeUnit};
 use arrow2::scalar::PrimitiveScalar;
+use arrow2::types::{days_ms, f16, months_days_ns};
 
 #[test]
 fn test_add() {
@@ -26,6 +27,36 @@ fn test_add_scalar() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn test_add_intervals() {
+    let a = DaysMsArray::from(&[Some(days_ms::new(1, -2)), None, Some(days_ms::new(-1, 0))])
+        .to(Interval(IntervalUnit::DayTime));
+    let b = DaysMsArray::from_slice(&[days_ms::new(2, 3), days_ms::new(1, 1), days_ms::new(0, -5)])
+        .to(Interval(IntervalUnit::DayTime));
+    let result = add(&a, &b);
+    let expected = DaysMsArray::from(&[Some(days_ms::new(3, 1)), None, Some(days_ms::new(-1, -5))])
+        .to(Interval(IntervalUnit::DayTime));
+    assert_eq!(expected, result.as_ref());
+
+    let result = sub(result.as_ref(), &b);
+    assert_eq!(a, result.as_ref());
+
+    let a = MonthsDaysNsArray::from_slice(&[months_days_ns::new(1, 2, -3)]);
+    let b = PrimitiveScalar::new(a.data_type().clone(), Some(months_days_ns::new(-1, 1, 3)));
+    let result = add_scalar(&a, &b);
+    let expected = MonthsDaysNsArray::from_slice(&[months_days_ns::new(0, 3, 0)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+#[should_panic]
+fn test_add_intervals_overflow() {
+    let a =
+        DaysMsArray::from_slice(&[days_ms::new(i32::MAX, 0)]).to(Interval(IntervalUnit::DayTime));
+    let b = DaysMsArray::from_slice(&[days_ms::new(1, 0)]).to(Interval(IntervalUnit::DayTime));
+    add(&a, &b);
+}
+
 #[test]
 fn consistency() {
     let datatypes = vec![
@@ -59,6 +90,7 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        Interval(IntervalUnit::DayTime),
         Interval(IntervalUnit::MonthDayNano),
     ];
 
@@ -93,6 +125,21 @@ fn test_neg() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn test_neg_unsupported() {
+    let a = UInt32Array::from_slice(&[1]);
+    assert!(!can_neg(a.data_type()));
+    assert!(try_neg(&a).is_err());
+
+    let a = PrimitiveArray::<f16>::from_slice(&[f16::from_f32(1.0)]);
+    assert!(!can_neg(a.data_type()));
+    assert!(try_neg(&a).is_err());
+
+    let a = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(!can_neg(a.data_type()));
+    assert!(try_neg(&a).is_err());
+}
+
 #[test]
 fn test_neg_dict() {
     let a = DictionaryArray::<u8>::from_data(
@@ -1,7 +1,8 @@
 use arrow2::array::*;
 use arrow2::compute::arithmetics::time::*;
-use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::datatypes::{DataType, IntervalUnit, TimeUnit};
 use arrow2::scalar::*;
+use arrow2::types::months_days_ns;
 
 #[test]
 fn test_adding_timestamp() {
@@ -317,3 +318,121 @@ fn test_date64() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_date32_interval() {
+    let interval = PrimitiveArray::from([
+        Some(months_days_ns::new(1, 0, 0)),
+        Some(months_days_ns::new(1, 0, 0)),
+        Some(months_days_ns::new(1, 1, 0)),
+        Some(months_days_ns::new(0, 0, 86_400_000_000_001)),
+        None,
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+    let date_32 = PrimitiveArray::from([
+        Some(18658i32), // 2021-01-31
+        Some(18292),    // 2020-01-31
+        Some(18658),    // 2021-01-31
+        Some(18627),    // 2020-12-31
+        Some(18627),
+    ])
+    .to(DataType::Date32);
+
+    let result = add_interval(&date_32, &interval).unwrap();
+    let expected = PrimitiveArray::from([
+        Some(18686i32), // 2021-02-28
+        Some(18321),    // 2020-02-29
+        Some(18687),    // 2021-03-01
+        Some(18628),    // 2021-01-01
+        None,
+    ])
+    .to(DataType::Date32);
+    assert_eq!(result, expected);
+
+    let interval = PrimitiveArray::from([
+        Some(months_days_ns::new(0, 0, 1)),
+        Some(months_days_ns::new(10, 0, 0)),
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    let date_32 = PrimitiveArray::from([
+        Some(18687i32), // 2021-03-01
+        Some(18627),    // 2020-12-31
+    ])
+    .to(DataType::Date32);
+
+    let result = subtract_interval(&date_32, &interval).unwrap();
+    let expected = PrimitiveArray::from([
+        Some(18686i32), // 2021-02-28
+        Some(18321),    // 2020-02-29
+    ])
+    .to(DataType::Date32);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_date64_interval() {
+    let interval = months_days_ns::new(1, 0, 1_000_000);
+    let interval = PrimitiveScalar::new(
+        DataType::Interval(IntervalUnit::MonthDayNano),
+        Some(interval),
+    );
+
+    // 2021-01-31
+    let date_64 = PrimitiveArray::from([Some(18658 * 86_400_000i64), None]).to(DataType::Date64);
+
+    let result = add_interval_scalar(&date_64, &interval).unwrap();
+    // 2021-02-28 00:00:00.001
+    let expected =
+        PrimitiveArray::from([Some(18686 * 86_400_000i64 + 1), None]).to(DataType::Date64);
+    assert_eq!(result, expected);
+
+    let result = sub_interval_scalar(&result, &interval).unwrap();
+    // 2021-01-28
+    let expected = PrimitiveArray::from([Some(18655 * 86_400_000i64), None]).to(DataType::Date64);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_timestamp_interval() {
+    let interval = PrimitiveArray::from([
+        Some(months_days_ns::new(1, 0, 0)),
+        Some(months_days_ns::new(-1, 0, 1_000_000_000)),
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+    // 2021-01-31 00:00:00, 2021-03-31 00:00:00
+    let timestamp = PrimitiveArray::from([Some(1612051200i64), Some(1617148800)])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let result = add_interval(&timestamp, &interval).unwrap();
+    // 2021-02-28 00:00:00, 2021-02-28 00:00:01
+    let expected = PrimitiveArray::from([Some(1614470400i64), Some(1614470401)])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_interval_overflow() {
+    let interval = PrimitiveArray::from([Some(months_days_ns::new(i32::MAX, 0, 0))])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    let date_32 = PrimitiveArray::from([Some(18658i32)]).to(DataType::Date32);
+    assert!(add_interval(&date_32, &interval).is_err());
+
+    let timestamp =
+        PrimitiveArray::from([Some(i64::MAX)]).to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    let interval = PrimitiveArray::from([Some(months_days_ns::new(0, 0, 1))])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    assert!(add_interval(&timestamp, &interval).is_err());
+
+    // the negation of the minimum overflows
+    let interval = PrimitiveArray::from([Some(months_days_ns::new(i32::MIN, 0, 0))])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    assert!(subtract_interval(&date_32, &interval).is_err());
+
+    let interval = PrimitiveScalar::new(
+        DataType::Interval(IntervalUnit::MonthDayNano),
+        Some(months_days_ns::new(0, 0, i64::MIN)),
+    );
+    assert!(sub_interval_scalar(&date_32, &interval).is_err());
+}
@@ -0,0 +1,99 @@
+use arrow2::array::*;
+use arrow2::compute::binary::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::ArrowError;
+
+#[test]
+fn hex_roundtrip() {
+    let array = BinaryArray::<i32>::from([Some(b"\x00\x7f\xff".as_ref()), None, Some(b"")]);
+    let result = hex_encode(&array).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("007fff"), None, Some("")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = hex_decode(result.as_ref(), &DataType::Binary, false).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn base64_roundtrip() {
+    let array = BinaryArray::<i64>::from([Some(b"a".as_ref()), Some(b"ab"), Some(b"abc"), None]);
+    let result = base64_encode(&array).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("YQ=="), Some("YWI="), Some("YWJj"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = base64_decode(result.as_ref(), &DataType::LargeBinary, false).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn sliced() {
+    let array = BinaryArray::<i32>::from_slice([b"\x01", b"\x02", b"\x03"]).slice(1, 2);
+    let result = encode_binary::<i32>(&array, BinaryEncoding::Hex).unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(["02", "03"]));
+}
+
+#[test]
+fn decode_lenient() {
+    let array = Utf8Array::<i32>::from([Some("0a"), Some("0"), Some("zz"), None, Some("FF")]);
+    let result = decode_binary::<i32, i32>(&array, BinaryEncoding::Hex, true).unwrap();
+    let expected =
+        BinaryArray::<i32>::from([Some(b"\x0a".as_ref()), None, None, None, Some(b"\xff")]);
+    assert_eq!(result, expected);
+
+    assert!(matches!(
+        decode_binary::<i32, i32>(&array, BinaryEncoding::Hex, false),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    let array = Utf8Array::<i32>::from_slice(["YQ==", "Y"]);
+    let result = decode_binary::<i32, i64>(&array, BinaryEncoding::Base64, true).unwrap();
+    assert_eq!(
+        result,
+        BinaryArray::<i64>::from([Some(b"a".as_ref()), None])
+    );
+    assert!(decode_binary::<i32, i64>(&array, BinaryEncoding::Base64, false).is_err());
+}
+
+#[test]
+fn fixed_size_binary() {
+    let array = FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), None, Some(b"\x00\x01")], 2);
+    let result = hex_encode(&array).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("6162"), None, Some("0001")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = hex_decode(result.as_ref(), &DataType::FixedSizeBinary(2), false).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn fixed_size_binary_validates_length() {
+    let array = Utf8Array::<i32>::from_slice(["6162", "61", "616263"]);
+    let result = decode_fixed_size_binary(&array, 2, BinaryEncoding::Hex, true).unwrap();
+    let expected = FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), None, None], 2);
+    assert_eq!(result, expected);
+
+    assert!(decode_fixed_size_binary(&array, 2, BinaryEncoding::Hex, false).is_err());
+}
+
+#[test]
+fn invalid_types() {
+    let array = Int32Array::from_slice([1]);
+    assert!(hex_encode(&array).is_err());
+    assert!(base64_decode(&array, &DataType::Binary, true).is_err());
+
+    let array = Utf8Array::<i32>::from_slice(["00"]);
+    assert!(hex_encode(&array).is_err());
+    assert!(hex_decode(&array, &DataType::Utf8, true).is_err());
+}
+
+#[test]
+fn display() {
+    let array = BinaryArray::<i32>::from([Some(b"\x01\xab".as_ref()), None]);
+    let display = get_display(&array);
+    assert_eq!(display(0), "01ab");
+    assert_eq!(display(1), "");
+    assert_eq!(format!("{}", array), "BinaryArray[01ab, None]");
+
+    let array = FixedSizeBinaryArray::from_iter(vec![Some(b"\xff")], 1);
+    assert_eq!(get_display(&array)(0), "ff");
+}
@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::columns::Columns;
+use arrow2::compute::arithmetics::add;
+use arrow2::compute::broadcast::*;
+use arrow2::compute::comparison::eq;
+use arrow2::datatypes::{DataType, Field, TimeUnit};
+use arrow2::error::{ArrowError, Result};
+use arrow2::types::f16;
+
+fn check(array: &dyn Array, expected: &dyn Array) {
+    let result = broadcast(array, expected.len()).unwrap();
+    assert_eq!(result.as_ref(), expected);
+    assert_eq!(result.data_type(), array.data_type());
+}
+
+#[test]
+fn null() {
+    check(
+        &NullArray::new_null(DataType::Null, 1),
+        &NullArray::new_null(DataType::Null, 3),
+    );
+}
+
+#[test]
+fn boolean() {
+    check(
+        &BooleanArray::from_slice(&[true]),
+        &BooleanArray::from_slice(&[true, true, true]),
+    );
+    check(
+        &BooleanArray::from(&[None]),
+        &BooleanArray::from(&[None, None]),
+    );
+}
+
+#[test]
+fn primitive() {
+    check(
+        &Int32Array::from_slice(&[3]),
+        &Int32Array::from_slice(&[3, 3, 3]),
+    );
+    check(&Int32Array::from(&[None]), &Int32Array::from(&[None, None]));
+    check(
+        &PrimitiveArray::<f16>::from_slice(&[f16::from_f32(1.5)]),
+        &PrimitiveArray::<f16>::from_slice(&[f16::from_f32(1.5); 2]),
+    );
+
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+    check(
+        &Int64Array::from_slice(&[1]).to(data_type.clone()),
+        &Int64Array::from_slice(&[1, 1]).to(data_type),
+    );
+}
+
+#[test]
+fn utf8() {
+    check(
+        &Utf8Array::<i32>::from_slice(&["ab"]),
+        &Utf8Array::<i32>::from_slice(&["ab", "ab", "ab"]),
+    );
+    check(
+        &Utf8Array::<i64>::from_slice(&[""]),
+        &Utf8Array::<i64>::from_slice(&["", ""]),
+    );
+    check(
+        &Utf8Array::<i32>::from(&[None::<&str>]),
+        &Utf8Array::<i32>::from(&[None::<&str>, None]),
+    );
+
+    // a slice of a larger array
+    let array = Utf8Array::<i32>::from_slice(&["a", "bc", "d"]).slice(1, 1);
+    check(&array, &Utf8Array::<i32>::from_slice(&["bc", "bc"]));
+}
+
+#[test]
+fn binary_arrays() {
+    check(
+        &BinaryArray::<i32>::from_slice(&[b"ab"]),
+        &BinaryArray::<i32>::from_slice(&[b"ab", b"ab"]),
+    );
+    check(
+        &BinaryArray::<i64>::from_slice(&[b"ab"]),
+        &BinaryArray::<i64>::from_slice(&[b"ab", b"ab"]),
+    );
+}
+
+#[test]
+fn offsets_overflow() {
+    let array = Utf8Array::<i32>::from_slice(&["ab"]);
+    assert!(matches!(
+        broadcast(&array, (1 << 30) + 1),
+        Err(ArrowError::Overflow)
+    ));
+}
+
+#[test]
+fn fixed_size_binary() {
+    check(
+        &FixedSizeBinaryArray::from_slice(&[[1, 2]], 2),
+        &FixedSizeBinaryArray::from_slice(&[[1, 2], [1, 2], [1, 2]], 2),
+    );
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(vec![Some("a"), Some("b"), Some("a")])?;
+    let array: DictionaryArray<i32> = array.into();
+    let array = array.slice(1, 1);
+
+    let mut expected = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    expected.try_extend(vec![Some("b"), Some("b"), Some("b")])?;
+    let expected: DictionaryArray<i32> = expected.into();
+    check(&array, &expected);
+    check(
+        &array.clone().with_is_ordered(true),
+        &expected.with_is_ordered(true),
+    );
+
+    // the values are the single broadcasted value
+    let result = broadcast(&array, 3)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(result.values().len(), 1);
+    assert_eq!(result.keys(), &Int32Array::from_slice(&[0, 0, 0]));
+
+    let array = array.slice(0, 0);
+    assert!(broadcast(&array, 3).is_err());
+    Ok(())
+}
+
+#[test]
+fn list() -> Result<()> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(vec![Some(vec![Some(1), None])])?;
+    let array: ListArray<i32> = array.into();
+
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected.try_extend(vec![Some(vec![Some(1), None]); 3])?;
+    let expected: ListArray<i32> = expected.into();
+
+    check(&array, &expected);
+    Ok(())
+}
+
+#[test]
+fn struct_() {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let array = StructArray::from_data(
+        data_type.clone(),
+        vec![
+            Arc::new(Int32Array::from(&[None])),
+            Arc::new(Utf8Array::<i32>::from_slice(&["a"])),
+        ],
+        None,
+    );
+    let expected = StructArray::from_data(
+        data_type.clone(),
+        vec![
+            Arc::new(Int32Array::from(&[None, None])),
+            Arc::new(Utf8Array::<i32>::from_slice(&["a", "a"])),
+        ],
+        None,
+    );
+    check(&array, &expected);
+
+    let array = StructArray::new_null(data_type.clone(), 1);
+    check(&array, &StructArray::new_null(data_type, 2));
+}
+
+#[test]
+fn zero_length() {
+    check(&Int32Array::from_slice(&[1]), &Int32Array::from_slice(&[]));
+}
+
+#[test]
+fn not_single_slot() {
+    assert!(broadcast(&Int32Array::from_slice(&[1, 2]), 3).is_err());
+    assert!(broadcast(&Int32Array::from_slice(&[]), 3).is_err());
+}
+
+#[test]
+fn to_dictionary() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(&["a"]);
+    let result = broadcast_to_dictionary::<u8>(&array, 3)?;
+    assert_eq!(result.keys(), &UInt8Array::from_slice(&[0, 0, 0]));
+    assert_eq!(result.values().as_ref(), &array as &dyn Array);
+
+    let array = Utf8Array::<i32>::from(&[None::<&str>]);
+    let result = broadcast_to_dictionary::<u8>(&array, 2)?;
+    assert_eq!(result.null_count(), 2);
+
+    let array = DictionaryArray::<i32>::from_data(
+        Int32Array::from_slice(&[0]),
+        Arc::new(Utf8Array::<i32>::from_slice(&["a"])),
+    );
+    assert!(broadcast_to_dictionary::<u8>(&array, 2).is_err());
+    Ok(())
+}
+
+#[test]
+fn binary_kernels() -> Result<()> {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]);
+    let scalar = Int32Array::from_slice(&[1]);
+
+    let result = binary(&array, &scalar, add)?;
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from(&[Some(2), None, Some(4)]) as &dyn Array
+    );
+    let result = binary(&scalar, &array, add)?;
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from(&[Some(2), None, Some(4)]) as &dyn Array
+    );
+
+    let result = binary(&array, &scalar, eq)?;
+    assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(false)]));
+
+    let scalar = Utf8Array::<i32>::from_slice(&["b"]);
+    let strings = Utf8Array::<i32>::from_slice(&["a", "b"]);
+    let result = binary(&scalar, &strings, eq)?;
+    assert_eq!(result, BooleanArray::from_slice(&[false, true]));
+
+    // equal lengths are not broadcasted
+    let result = binary(&array, &array, eq)?;
+    assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(true)]));
+
+    assert!(binary(&array, &Int32Array::from_slice(&[1, 2]), eq).is_err());
+    Ok(())
+}
+
+#[test]
+fn columns() -> Result<()> {
+    let columns = Columns::try_new(vec![
+        Arc::new(Int32Array::from_slice(&[1])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[None::<&str>])),
+    ])?;
+    let result = broadcast_columns(&columns, 2)?;
+    assert_eq!(result.len(), 2);
+    assert_eq!(
+        result.arrays()[0].as_ref(),
+        &Int32Array::from_slice(&[1, 1]) as &dyn Array
+    );
+    assert_eq!(result.arrays()[1].null_count(), 2);
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::cast::{
+    can_cast_types, cast, try_utf8_to_boolean, utf8_to_date32_with_format,
+    utf8_to_date64_with_format, utf8_to_timestamp, CastOptions,
+};
 use arrow2::datatypes::*;
-use arrow2::types::NativeType;
+use arrow2::types::{f16, NativeType};
 
 #[test]
 fn i32_to_f64() {
@@ -279,6 +282,96 @@ fn bool_to_f64() {
     assert_eq!(c, &expected);
 }
 
+#[test]
+fn bool_to_u8_sliced() {
+    // more than one chunk of 64 bits, a remainder, and an offset that is not a multiple of 8
+    let data = (0..150).map(|x| x % 3 == 0).collect::<Vec<_>>();
+    let array = BooleanArray::from_slice(&data).slice(5, 140);
+    let b = cast(&array, &DataType::UInt8, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
+
+    let expected = data[5..145]
+        .iter()
+        .map(|x| Some(*x as u8))
+        .collect::<UInt8Array>();
+    assert_eq!(c, &expected);
+}
+
+#[test]
+fn i64_to_bool() {
+    let array = Int64Array::from(&[Some(0), Some(1), Some(-2), None]);
+    let b = cast(&array, &DataType::Boolean, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+    let expected = BooleanArray::from(&[Some(false), Some(true), Some(true), None]);
+    assert_eq!(c, &expected);
+}
+
+#[test]
+fn utf8_to_bool() {
+    let array = Utf8Array::<i32>::from(&[
+        Some("true"),
+        Some("FALSE"),
+        Some("True"),
+        Some("1"),
+        Some("0"),
+        Some("yes"),
+        Some(""),
+        None,
+    ]);
+    let expected = BooleanArray::from(&[
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(true),
+        Some(false),
+        None,
+        None,
+        None,
+    ]);
+
+    let b = cast(&array, &DataType::Boolean, CastOptions::default()).unwrap();
+    assert_eq!(
+        b.as_any().downcast_ref::<BooleanArray>().unwrap(),
+        &expected
+    );
+
+    let array = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    let b = cast(array.as_ref(), &DataType::Boolean, CastOptions::default()).unwrap();
+    assert_eq!(
+        b.as_any().downcast_ref::<BooleanArray>().unwrap(),
+        &expected
+    );
+}
+
+#[test]
+fn try_utf8_to_bool() {
+    let array = Utf8Array::<i32>::from(&[Some("TRUE"), Some("0"), None]);
+    let expected = BooleanArray::from(&[Some(true), Some(false), None]);
+    assert_eq!(try_utf8_to_boolean(&array).unwrap(), expected);
+
+    let array = Utf8Array::<i32>::from(&[Some("true"), Some("yes")]);
+    assert!(try_utf8_to_boolean(&array).is_err());
+
+    let strict = CastOptions {
+        strict: true,
+        ..Default::default()
+    };
+    assert!(cast(&array, &DataType::Boolean, strict).is_err());
+    let b = cast(&array, &DataType::Boolean, CastOptions::default()).unwrap();
+    assert_eq!(
+        b.as_any().downcast_ref::<BooleanArray>().unwrap(),
+        &BooleanArray::from(&[Some(true), None])
+    );
+
+    let array = Utf8Array::<i64>::from(&[Some("FALSE"), Some("1"), None]);
+    let b = cast(&array, &DataType::Boolean, strict).unwrap();
+    assert_eq!(
+        b.as_any().downcast_ref::<BooleanArray>().unwrap(),
+        &BooleanArray::from(&[Some(false), Some(true), None])
+    );
+}
+
 #[test]
 fn bool_to_utf8() {
     let array = BooleanArray::from(vec![Some(true), Some(false), None]);
@@ -324,6 +417,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Timestamp(TimeUnit::Second, None),
@@ -365,6 +459,226 @@ fn consistency() {
     }
 }
 
+/// Returns a non-null array of length 3 of `data_type`
+fn non_null_array(data_type: &DataType) -> Box<dyn Array> {
+    use std::sync::Arc;
+    use DataType::*;
+    match data_type {
+        Null => Box::new(NullArray::from_data(Null, 3)),
+        Boolean => Box::new(BooleanArray::from_slice([true, false, true])),
+        Int8 => Box::new(Int8Array::from_slice([1, 2, 3])),
+        Int16 => Box::new(Int16Array::from_slice([1, 2, 3])),
+        UInt8 => Box::new(UInt8Array::from_slice([1, 2, 3])),
+        UInt16 => Box::new(UInt16Array::from_slice([1, 2, 3])),
+        UInt32 => Box::new(UInt32Array::from_slice([1, 2, 3])),
+        UInt64 => Box::new(UInt64Array::from_slice([1, 2, 3])),
+        Float16 => Box::new(PrimitiveArray::<f16>::from_slice([
+            f16::from_f32(1.0),
+            f16::from_f32(2.0),
+            f16::from_f32(3.0),
+        ])),
+        Float32 => Box::new(Float32Array::from_slice([1.0, 2.0, 3.0])),
+        Float64 => Box::new(Float64Array::from_slice([1.0, 2.0, 3.0])),
+        Int32 | Date32 | Time32(_) => {
+            Box::new(Int32Array::from_slice([1, 2, 3]).to(data_type.clone()))
+        }
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
+            Box::new(Int64Array::from_slice([1, 2, 3]).to(data_type.clone()))
+        }
+        Decimal(_, _) => Box::new(Int128Array::from_slice([1, 2, 3]).to(data_type.clone())),
+        Utf8 => Box::new(Utf8Array::<i32>::from_slice(["1", "2", "3"])),
+        LargeUtf8 => Box::new(Utf8Array::<i64>::from_slice(["1", "2", "3"])),
+        Binary => Box::new(BinaryArray::<i32>::from_slice(["1", "2", "3"])),
+        LargeBinary => Box::new(BinaryArray::<i64>::from_slice(["1", "2", "3"])),
+        List(field) => Box::new(ListArray::<i32>::from_data(
+            data_type.clone(),
+            vec![0, 1, 2, 3].into(),
+            non_null_array(field.data_type()).into(),
+            None,
+        )),
+        LargeList(field) => Box::new(ListArray::<i64>::from_data(
+            data_type.clone(),
+            vec![0, 1, 2, 3].into(),
+            non_null_array(field.data_type()).into(),
+            None,
+        )),
+        Interval(IntervalUnit::YearMonth) => {
+            Box::new(Int32Array::from_slice([1, 2, 3]).to(data_type.clone()))
+        }
+        FixedSizeBinary(_) => Box::new(FixedSizeBinaryArray::from_slice(["1", "2", "3"], 1)),
+        FixedSizeList(field, _) => Box::new(FixedSizeListArray::from_data(
+            data_type.clone(),
+            non_null_array(field.data_type()).into(),
+            None,
+        )),
+        Struct(fields) => Box::new(StructArray::from_data(
+            data_type.clone(),
+            fields
+                .iter()
+                .map(|field| non_null_array(field.data_type()).into())
+                .collect(),
+            None,
+        )),
+        Dictionary(IntegerType::UInt8, values, _) => {
+            let values: Arc<dyn Array> = non_null_array(values).into();
+            Box::new(DictionaryArray::<u8>::from_data(
+                UInt8Array::from_slice([0, 1, 2]),
+                values,
+            ))
+        }
+        Dictionary(IntegerType::Int32, values, _) => {
+            let values: Arc<dyn Array> = non_null_array(values).into();
+            Box::new(DictionaryArray::<i32>::from_data(
+                Int32Array::from_slice([0, 1, 2]),
+                values,
+            ))
+        }
+        other => unimplemented!("{:?}", other),
+    }
+}
+
+#[test]
+fn consistency_non_null() {
+    use DataType::*;
+    let datatypes = vec![
+        Null,
+        Boolean,
+        UInt8,
+        UInt16,
+        UInt32,
+        UInt64,
+        Int8,
+        Int16,
+        Int32,
+        Int64,
+        Float16,
+        Float32,
+        Float64,
+        Decimal(10, 2),
+        Timestamp(TimeUnit::Second, None),
+        Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string())),
+        Timestamp(TimeUnit::Nanosecond, None),
+        Time32(TimeUnit::Millisecond),
+        Time64(TimeUnit::Nanosecond),
+        Date32,
+        Date64,
+        Duration(TimeUnit::Second),
+        Utf8,
+        LargeUtf8,
+        Binary,
+        LargeBinary,
+        List(Box::new(Field::new("a", Int32, true))),
+        List(Box::new(Field::new("a", Utf8, true))),
+        LargeList(Box::new(Field::new("a", Int32, true))),
+        LargeList(Box::new(Field::new("a", Utf8, true))),
+        List(Box::new(Field::new(
+            "a",
+            List(Box::new(Field::new("a", Int32, true))),
+            true,
+        ))),
+        Dictionary(IntegerType::Int32, Box::new(Utf8), false),
+        Dictionary(IntegerType::Int32, Box::new(Int64), false),
+        Dictionary(IntegerType::UInt8, Box::new(Utf8), false),
+        Interval(IntervalUnit::YearMonth),
+        FixedSizeBinary(1),
+        FixedSizeList(Box::new(Field::new("a", Int32, true)), 1),
+        Struct(vec![Field::new("a", Int32, true)]),
+    ];
+    let mut disagreements = vec![];
+    for d1 in &datatypes {
+        let array = non_null_array(d1);
+        for d2 in &datatypes {
+            let expected = can_cast_types(d1, d2);
+            match cast(array.as_ref(), d2, CastOptions::default()) {
+                Ok(result) => {
+                    assert_eq!(result.data_type(), d2, "type not equal: {:?} {:?}", d1, d2);
+                    assert_eq!(result.len(), array.len());
+                    if !expected {
+                        disagreements.push(format!("{:?} -> {:?} casts but is denied", d1, d2));
+                    }
+                }
+                Err(e) => {
+                    if expected {
+                        disagreements
+                            .push(format!("{:?} -> {:?} is allowed but fails: {}", d1, d2, e));
+                    }
+                }
+            }
+        }
+    }
+    assert!(disagreements.is_empty(), "{:#?}", disagreements);
+}
+
+#[test]
+fn list_to_large_list_with_child_cast() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array
+        .try_extend(vec![Some(vec![Some(1), None]), None, Some(vec![Some(3)])])
+        .unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let mut expected = MutableListArray::<i64, MutableUtf8Array<i32>>::new();
+    expected
+        .try_extend(vec![
+            Some(vec![Some("1"), None]),
+            None,
+            Some(vec![Some("3")]),
+        ])
+        .unwrap();
+    let expected: ListArray<i64> = expected.into();
+
+    let result = cast(&array, expected.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    // and back
+    let result = cast(&expected, array.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn primitive_to_large_list() {
+    let array = Int32Array::from(&[Some(1), None]);
+    let data_type = DataType::LargeList(Box::new(Field::new("a", DataType::Int64, true)));
+    let result = cast(&array, &data_type, CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 1, 2]);
+    assert_eq!(
+        result.values().as_ref(),
+        &Int64Array::from(&[Some(1), None]) as &dyn Array
+    );
+}
+
+#[test]
+fn null_to_any() {
+    let array = NullArray::from_data(DataType::Null, 2);
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Utf8, true)]);
+    assert!(can_cast_types(array.data_type(), &data_type));
+    let result = cast(&array, &data_type, CastOptions::default()).unwrap();
+    assert_eq!(result.data_type(), &data_type);
+    assert_eq!(result.null_count(), 2);
+}
+
+#[test]
+fn any_to_null() {
+    let array = Int32Array::from(&[Some(1), None]);
+    assert!(can_cast_types(array.data_type(), &DataType::Null));
+    let result = cast(&array, &DataType::Null, CastOptions::default()).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &NullArray::new_null(DataType::Null, 2) as &dyn Array
+    );
+}
+
+#[test]
+fn null_to_i32() {
+    let array = NullArray::new_null(DataType::Null, 3);
+    let result = cast(&array, &DataType::Int32, CastOptions::default()).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::new_null(DataType::Int32, 3) as &dyn Array
+    );
+}
+
 fn test_primitive_to_primitive<I: NativeType, O: NativeType>(
     lhs: &[I],
     lhs_type: DataType,
@@ -453,7 +767,7 @@ fn timestamp_to_date64() {
     test_primitive_to_primitive(
         &[864000000005i64, 1545696000001],
         DataType::Timestamp(TimeUnit::Millisecond, Some(String::from("UTC"))),
-        &[864000000005i64, 1545696000001i64],
+        &[864000000000i64, 1545696000000i64],
         DataType::Date64,
     );
 }
@@ -478,6 +792,216 @@ fn timestamp_to_timestamp() {
     );
 }
 
+#[test]
+fn timestamp_to_date_pre_epoch() {
+    test_primitive_to_primitive(
+        &[-1i64, -86400, -86401],
+        DataType::Timestamp(TimeUnit::Second, None),
+        &[-1i32, -1, -2],
+        DataType::Date32,
+    );
+    test_primitive_to_primitive(
+        &[-1i64, -86400, -86401],
+        DataType::Timestamp(TimeUnit::Second, None),
+        &[-86400000i64, -86400000, -172800000],
+        DataType::Date64,
+    );
+}
+
+#[test]
+fn date64_to_timestamp() {
+    test_primitive_to_primitive(
+        &[864000000000i64, -86400000],
+        DataType::Date64,
+        &[864000000i64, -86400],
+        DataType::Timestamp(TimeUnit::Second, None),
+    );
+    test_primitive_to_primitive(
+        &[864000000000i64, -86400000],
+        DataType::Date64,
+        &[864000000000000000i64, -86400000000000],
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".to_string())),
+    );
+}
+
+#[test]
+fn temporal_overflow() {
+    // 2263-01-01 does not fit in an i64 of nanoseconds
+    let array = Int64Array::from_slice(&[9246182400000, 0]).to(DataType::Date64);
+    let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[None, Some(0)]).to(to_type.clone());
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+
+    let options = CastOptions {
+        wrapped: true,
+        ..Default::default()
+    };
+    let result = cast(&array, &to_type, options).unwrap();
+    let expected = Int64Array::from_slice(&[-9200561673709551616, 0]).to(to_type.clone());
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+
+    let array = Int64Array::from_slice(&[9246182400000, -1])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[None, Some(-1_000_000)]).to(to_type);
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+
+    let array =
+        Int64Array::from_slice(&[i64::MAX / 1000, -1]).to(DataType::Duration(TimeUnit::Second));
+    let to_type = DataType::Duration(TimeUnit::Microsecond);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[None, Some(-1_000_000)]).to(to_type);
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+}
+
+#[test]
+fn temporal_overflow_strict() {
+    let options = CastOptions {
+        strict: true,
+        // strict takes precedence
+        wrapped: true,
+        ..Default::default()
+    };
+
+    let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let array = Int64Array::from_slice(&[9246182400000, 0]).to(DataType::Date64);
+    assert!(cast(&array, &to_type, options).is_err());
+
+    let array = Int64Array::from(&[Some(9246182400000), Some(-1)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    assert!(cast(&array, &to_type, options).is_err());
+
+    let array =
+        Int64Array::from_slice(&[i64::MAX / 1000, -1]).to(DataType::Duration(TimeUnit::Second));
+    assert!(cast(&array, &DataType::Duration(TimeUnit::Microsecond), options).is_err());
+
+    // nulls and values that fit (e.g. 2262-01-01 and pre-epoch values) are not errors
+    let array = Int64Array::from(&[Some(9214646400000), None, Some(-1)]).to(DataType::Date64);
+    let result = cast(&array, &to_type, options).unwrap();
+    let expected =
+        Int64Array::from(&[Some(9214646400000000000), None, Some(-1_000_000)]).to(to_type);
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+}
+
+#[test]
+fn duration_to_duration() {
+    test_primitive_to_primitive(
+        &[1i64, -2],
+        DataType::Duration(TimeUnit::Second),
+        &[1000i64, -2000],
+        DataType::Duration(TimeUnit::Millisecond),
+    );
+    test_primitive_to_primitive(
+        &[1999i64, -2000],
+        DataType::Duration(TimeUnit::Nanosecond),
+        &[1i64, -2],
+        DataType::Duration(TimeUnit::Microsecond),
+    );
+}
+
+#[test]
+fn time32_to_time64() {
+    test_primitive_to_primitive(
+        &[1i32, 86399],
+        DataType::Time32(TimeUnit::Second),
+        &[1_000_000_000i64, 86_399_000_000_000],
+        DataType::Time64(TimeUnit::Nanosecond),
+    );
+    test_primitive_to_primitive(
+        &[1_500_000i64, 86_399_999_999],
+        DataType::Time64(TimeUnit::Microsecond),
+        &[1500i32, 86_399_999],
+        DataType::Time32(TimeUnit::Millisecond),
+    );
+}
+
+#[test]
+fn f16_to_f32_and_back() {
+    let array = Float32Array::from(&[Some(1.5), None, Some(-0.1), Some(1e6), Some(f32::NAN)]);
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<f16>>().unwrap();
+    assert_eq!(c.data_type(), &DataType::Float16);
+    assert_eq!(c.value(0).to_f32(), 1.5);
+    assert!(c.is_null(1));
+    // rounded to the nearest f16
+    assert_eq!(c.value(2).to_bits(), 0xAE66);
+    // out of range
+    assert_eq!(c.value(3).to_f32(), f32::INFINITY);
+    assert!(c.value(4).is_nan());
+
+    let b = cast(c, &DataType::Float64, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(c.value(0), 1.5);
+    assert_eq!(c.value(2), -0.0999755859375);
+    assert!(c.is_null(1));
+}
+
+#[test]
+fn decimal_to_f64_and_back() {
+    let array = PrimitiveArray::<i128>::from(&[Some(1_25), None, Some(-99_999)])
+        .to(DataType::Decimal(5, 2));
+    let b = cast(&array, &DataType::Float64, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(c, &Float64Array::from(&[Some(1.25), None, Some(-999.99)]));
+
+    let b = cast(c, array.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(b.as_ref(), &array as &dyn Array);
+
+    // rounded to the scale; null when out of the precision or not finite
+    let array = Float64Array::from(&[Some(0.125), Some(1000.0), Some(f64::NAN), Some(-0.004)]);
+    let b = cast(&array, &DataType::Decimal(5, 2), CastOptions::default()).unwrap();
+    let expected =
+        PrimitiveArray::<i128>::from(&[Some(13), None, None, Some(0)]).to(DataType::Decimal(5, 2));
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn f64_to_decimal_strict() {
+    let options = CastOptions {
+        strict: true,
+        ..Default::default()
+    };
+    let to_type = DataType::Decimal(38, 10);
+
+    // 2^90 (exact in f64) has 38 digits with a scale of 10
+    let array = Float64Array::from(&[Some(2f64.powi(90)), None, Some(-1.5)]);
+    let result = cast(&array, &to_type, options).unwrap();
+    let expected = PrimitiveArray::<i128>::from(&[
+        Some(2i128.pow(90) * 10i128.pow(10)),
+        None,
+        Some(-15_000_000_000),
+    ])
+    .to(to_type.clone());
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // 10^28 does not fit in 38 digits
+    let array = Float64Array::from(&[Some(1e28), Some(1.0)]);
+    assert!(cast(&array, &to_type, options).is_err());
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = PrimitiveArray::<i128>::from(&[None, Some(10_000_000_000)]).to(to_type.clone());
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = Float64Array::from_slice(&[f64::NAN]);
+    assert!(cast(&array, &to_type, options).is_err());
+}
+
+#[test]
+fn metadata_only_casts_share_buffers() {
+    let array = Int64Array::from(&[Some(1), None, Some(3)]);
+    let to_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    assert_eq!(result.data_type(), &to_type);
+    let result = result.as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(result.values().as_ptr(), array.values().as_ptr());
+
+    let to_type = DataType::Extension("ext".to_string(), Box::new(DataType::Int64), None);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(result.values().as_ptr(), array.values().as_ptr());
+}
+
 #[test]
 fn utf8_to_dict() {
     let array = Utf8Array::<i32>::from(&[Some("one"), None, Some("three"), Some("one")]);
@@ -635,6 +1159,176 @@ fn null_array_from_and_to_others() {
     typed_test!(Float64Array, Float64);
 }
 
+#[test]
+fn float_to_utf8_round_trip() {
+    let values = [0.1f64, 0.1 + 0.2, 1.0 / 3.0, -1.5e-10, 1e300, f64::MAX];
+    let array = Float64Array::from_slice(&values);
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+
+    assert_eq!(result.value(0), "0.1");
+    result.values_iter().zip(values.iter()).for_each(|(s, v)| {
+        assert_eq!(s.parse::<f64>().unwrap(), *v);
+    });
+}
+
+#[test]
+fn date_to_utf8() {
+    let array = Int32Array::from(&[Some(18687), None, Some(-1)]).to(DataType::Date32);
+    let expected = Utf8Array::<i32>::from(&[Some("2021-03-01"), None, Some("1969-12-31")]);
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    let array = Int64Array::from(&[Some(1614556800000), None]).to(DataType::Date64);
+    let expected = Utf8Array::<i64>::from(&[Some("2021-03-01"), None]);
+    let result = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn timestamp_with_nulls_to_utf8() {
+    let array = Int64Array::from(&[Some(851020797123), None, Some(0)]).to(DataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("+01:00".to_string()),
+    ));
+    let expected = Utf8Array::<i32>::from(&[
+        Some("1996-12-19T19:39:57.123+01:00"),
+        None,
+        Some("1970-01-01T01:00:00+01:00"),
+    ]);
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    let array = Int64Array::from(&[Some(851020797123), None])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let expected = Utf8Array::<i32>::from(&[Some("1996-12-19 18:39:57.123"), None]);
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8_to_date_with_format() {
+    let array = Utf8Array::<i32>::from(&[
+        Some("2021-03-01"),
+        Some("03/01/2021"),
+        Some("2021-03-01 14:00:00"),
+        Some("not a date"),
+        None,
+    ]);
+
+    let result = utf8_to_date32_with_format(&array, None);
+    let expected =
+        Int32Array::from(&[Some(18687), Some(18687), None, None, None]).to(DataType::Date32);
+    assert_eq!(result, expected);
+
+    let result = utf8_to_date64_with_format(&array, None);
+    let expected = Int64Array::from(&[
+        Some(1614556800000),
+        Some(1614556800000),
+        Some(1614607200000),
+        None,
+        None,
+    ])
+    .to(DataType::Date64);
+    assert_eq!(result, expected);
+
+    let array = Utf8Array::<i32>::from_slice(&["01.03.2021", "2021-03-01"]);
+    let result = utf8_to_date32_with_format(&array, Some("%d.%m.%Y"));
+    let expected = Int32Array::from(&[Some(18687), None]).to(DataType::Date32);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn utf8_to_timestamp_default_formats() {
+    let array = Utf8Array::<i32>::from(&[
+        Some("2021-03-01 14:00:00"),
+        Some("2021-03-01T14:00:00.123456789"),
+        Some("03/01/2021"),
+        Some("2021-03-01T14:00:00+02:00"),
+        Some("invalid"),
+        None,
+    ]);
+
+    let result = utf8_to_timestamp(&array, None, TimeUnit::Nanosecond, None).unwrap();
+    let expected = Int64Array::from(&[
+        Some(1614607200000000000),
+        Some(1614607200123456789),
+        Some(1614556800000000000),
+        // the offset is converted to UTC also for naive timestamps
+        Some(1614600000000000000),
+        None,
+        None,
+    ])
+    .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(result, expected);
+
+    let result = utf8_to_timestamp(&array, None, TimeUnit::Millisecond, None).unwrap();
+    let expected = Int64Array::from(&[
+        Some(1614607200000),
+        Some(1614607200123),
+        Some(1614556800000),
+        Some(1614600000000),
+        None,
+        None,
+    ])
+    .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn utf8_to_timestamp_with_format_and_tz() {
+    let array = Utf8Array::<i32>::from_slice(&["01.03.2021 14:00", "01.03.2021 14:00 +0100"]);
+
+    let result = utf8_to_timestamp(&array, Some("%d.%m.%Y %H:%M"), TimeUnit::Second, None).unwrap();
+    let expected =
+        Int64Array::from(&[Some(1614607200), None]).to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(result, expected);
+
+    // values without offset are interpreted in the timezone; values with offset are converted
+    let tz = Some("+02:00".to_string());
+    let result = utf8_to_timestamp(&array, None, TimeUnit::Second, tz.clone()).unwrap();
+    let expected =
+        Int64Array::from(&[None, None]).to(DataType::Timestamp(TimeUnit::Second, tz.clone()));
+    assert_eq!(result, expected);
+
+    let array = Utf8Array::<i32>::from_slice(&["2021-03-01 14:00:00", "2021-03-01 14:00:00+01:00"]);
+    let result = utf8_to_timestamp(&array, None, TimeUnit::Second, tz.clone()).unwrap();
+    let expected = Int64Array::from(&[Some(1614600000), Some(1614603600)])
+        .to(DataType::Timestamp(TimeUnit::Second, tz));
+    assert_eq!(result, expected);
+
+    let result = utf8_to_timestamp(&array, None, TimeUnit::Second, Some("invalid".to_string()));
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn utf8_to_timestamp_ambiguous() {
+    // in Europe/London, 01:30 happens twice on 2021-10-31 and not at all on 2021-03-28
+    let array = Utf8Array::<i32>::from_slice(&[
+        "2021-10-31 01:30:00",
+        "2021-03-28 01:30:00",
+        "2021-10-31 01:30:00+01:00",
+    ]);
+    let tz = Some("Europe/London".to_string());
+    let result = utf8_to_timestamp(&array, None, TimeUnit::Second, tz.clone()).unwrap();
+    let expected = Int64Array::from(&[None, None, Some(1635640200)])
+        .to(DataType::Timestamp(TimeUnit::Second, tz));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn utf8_to_timestamp_overflow() {
+    let array = Utf8Array::<i32>::from_slice(&["2500-01-01", "1500-01-01"]);
+
+    let result = utf8_to_timestamp(&array, None, TimeUnit::Nanosecond, None).unwrap();
+    assert_eq!(result.null_count(), 2);
+
+    let result = utf8_to_timestamp(&array, None, TimeUnit::Second, None).unwrap();
+    assert_eq!(result.value(0), 16725225600);
+    assert!(result.is_valid(1));
+}
+
 /*
 #[test]
 fn dict_to_dict_bad_index_value_primitive() {
@@ -769,3 +1463,37 @@ fn make_union_array() -> UnionArray {
     builder.build().unwrap()
 }
 */
+
+#[test]
+fn extension_to_storage() {
+    let data_type = DataType::Extension("ext".to_string(), Box::new(DataType::Int32), None);
+    let array = Int32Array::from(&[Some(1), None, Some(3)]).to(data_type.clone());
+
+    assert!(can_cast_types(&data_type, &DataType::Int32));
+    let result = cast(&array, &DataType::Int32, CastOptions::default()).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, Some(3)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // storage cast is applied transparently
+    assert!(can_cast_types(&data_type, &DataType::Int64));
+    let result = cast(&array, &DataType::Int64, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[Some(1), None, Some(3)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn storage_to_extension() {
+    let data_type = DataType::Extension(
+        "uuid".to_string(),
+        Box::new(DataType::Utf8),
+        Some("meta".to_string()),
+    );
+    let array = Utf8Array::<i32>::from(&[Some("a"), None]);
+
+    assert!(can_cast_types(&DataType::Utf8, &data_type));
+    let result = cast(&array, &data_type, CastOptions::default()).unwrap();
+    assert_eq!(result.data_type(), &data_type);
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert_eq!(result.value(0), "a");
+    assert!(result.is_null(1));
+}
@@ -0,0 +1,110 @@
+use arrow2::array::*;
+use arrow2::compute::clamp::*;
+use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::error::Result;
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+#[test]
+fn primitive() -> Result<()> {
+    let array = Int32Array::from(&[Some(-5), None, Some(0), Some(3), Some(5), Some(10)]);
+
+    let result = clamp_primitive(&array, Some(0), Some(5))?;
+    let expected = Int32Array::from(&[Some(0), None, Some(0), Some(3), Some(5), Some(5)]);
+    assert_eq!(result, expected);
+
+    let result = clamp_primitive(&array, Some(3), None)?;
+    let expected = Int32Array::from(&[Some(3), None, Some(3), Some(3), Some(5), Some(10)]);
+    assert_eq!(result, expected);
+
+    let result = clamp_primitive(&array, None, Some(-1))?;
+    let expected = Int32Array::from(&[Some(-5), None, Some(-1), Some(-1), Some(-1), Some(-1)]);
+    assert_eq!(result, expected);
+
+    assert_eq!(clamp_primitive(&array, None, None)?, array);
+    // equal bounds
+    let result = clamp_primitive(&array, Some(1), Some(1))?;
+    let expected = Int32Array::from(&[Some(1), None, Some(1), Some(1), Some(1), Some(1)]);
+    assert_eq!(result, expected);
+
+    assert!(clamp_primitive(&array, Some(5), Some(0)).is_err());
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let array = UInt8Array::from_slice(&[0, 1, 2, 3, 4]).slice(1, 3);
+    let result = clamp_primitive(&array, Some(2), Some(2))?;
+    assert_eq!(result, UInt8Array::from_slice(&[2, 2, 2]));
+    Ok(())
+}
+
+#[test]
+fn floats() -> Result<()> {
+    let array = Float64Array::from(&[
+        Some(f64::NAN),
+        Some(f64::NEG_INFINITY),
+        Some(-0.0),
+        Some(2.5),
+        None,
+    ]);
+    let result = clamp_primitive(&array, Some(0.0), Some(1.0))?;
+    // NaN passes through unchanged
+    assert!(result.value(0).is_nan());
+    assert_eq!(result.value(1), 0.0);
+    assert_eq!(result.value(2), 0.0);
+    assert_eq!(result.value(3), 1.0);
+    assert!(result.is_null(4));
+
+    // a NaN bound does not clip any value
+    let result = clamp_primitive(&array, Some(f64::NAN), Some(1.0))?;
+    assert_eq!(result.value(1), f64::NEG_INFINITY);
+    assert_eq!(result.value(3), 1.0);
+    Ok(())
+}
+
+#[test]
+fn decimal() -> Result<()> {
+    let data_type = DataType::Decimal(5, 2);
+    let array =
+        Int128Array::from(&[Some(-99999), Some(150), None, Some(99999)]).to(data_type.clone());
+
+    let result = clamp_decimal(&array, Some(-100), Some(100))?;
+    let expected = Int128Array::from(&[Some(-100), Some(100), None, Some(100)]).to(data_type);
+    assert_eq!(result, expected);
+
+    // bounds must fit in the precision
+    assert!(clamp_decimal(&array, Some(-100000), None).is_err());
+    assert!(clamp_decimal(&array, None, Some(100000)).is_err());
+    Ok(())
+}
+
+#[test]
+fn dynamic() -> Result<()> {
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    let array = Int64Array::from(&[Some(1), None, Some(100)]).to(data_type.clone());
+    let min = PrimitiveScalar::<i64>::new(data_type.clone(), Some(10));
+    let max = PrimitiveScalar::<i64>::new(data_type.clone(), None);
+    let result = clamp(&array, &min, &max)?;
+    let expected = Int64Array::from(&[Some(10), None, Some(100)]).to(data_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let data_type = DataType::Decimal(5, 2);
+    let array = Int128Array::from(&[Some(-500), Some(500)]).to(data_type.clone());
+    let min = PrimitiveScalar::<i128>::new(data_type.clone(), Some(0));
+    let max = PrimitiveScalar::<i128>::new(data_type.clone(), Some(100));
+    let result = clamp(&array, &min, &max)?;
+    let expected = Int128Array::from_slice(&[0, 100]).to(data_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // bounds of a different data type
+    let min = PrimitiveScalar::<i128>::new(DataType::Decimal(6, 2), Some(0));
+    assert!(clamp(&array, &min, &max).is_err());
+
+    // unsupported data types
+    let array = Utf8Array::<i32>::from_slice(&["a"]);
+    let bound = Utf8Scalar::<i32>::new(Some("a"));
+    assert!(clamp(&array, &bound, &bound).is_err());
+    assert!(!can_clamp(&DataType::Float16));
+    assert!(can_clamp(&DataType::Date32));
+    Ok(())
+}
@@ -1,7 +1,12 @@
+use std::sync::Arc;
+
+use proptest::prelude::*;
+
 use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
 use arrow2::compute::comparison::boolean::*;
 use arrow2::datatypes::DataType::*;
-use arrow2::datatypes::TimeUnit;
+use arrow2::datatypes::{Field, TimeUnit};
 use arrow2::scalar::new_scalar;
 
 #[test]
@@ -18,6 +23,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Timestamp(TimeUnit::Second, None),
@@ -58,6 +64,142 @@ fn consistency() {
     });
 }
 
+#[test]
+fn f16() {
+    use arrow2::compute::comparison::{lt, lt_scalar};
+    use arrow2::scalar::PrimitiveScalar;
+    use arrow2::types::f16;
+
+    let lhs = [Some(1.0), None, Some(-2.5), Some(3.0)]
+        .iter()
+        .map(|x| x.map(f16::from_f32))
+        .collect::<PrimitiveArray<f16>>();
+    let rhs = [Some(2.0), Some(1.0), Some(-3.0), Some(3.0)]
+        .iter()
+        .map(|x| x.map(f16::from_f32))
+        .collect::<PrimitiveArray<f16>>();
+    assert_eq!(
+        lt(&lhs, &rhs),
+        BooleanArray::from(&[Some(true), None, Some(false), Some(false)])
+    );
+
+    let scalar = PrimitiveScalar::from(Some(f16::from_f32(2.0)));
+    assert_eq!(
+        lt_scalar(&lhs, &scalar),
+        BooleanArray::from(&[Some(true), None, Some(true), Some(false)])
+    );
+}
+
+type Row = (bool, Vec<Option<Option<i32>>>);
+
+/// Returns a `List<Struct<a: Int32>>` where each row is `(is_valid, items)`. Null rows
+/// keep their items, so that values behind nulls are also exercised.
+fn list_of_struct(rows: &[Row]) -> ListArray<i32> {
+    let mut offsets = vec![0i32];
+    let mut items = vec![];
+    for (_, row) in rows {
+        items.extend(row.iter().cloned());
+        offsets.push(items.len() as i32);
+    }
+    let struct_validity: Bitmap = items.iter().map(|x| x.is_some()).collect();
+    let values = Int32Array::from(items.into_iter().map(|x| x.flatten()).collect::<Vec<_>>());
+
+    let fields = vec![Field::new("a", Int32, true)];
+    let values = StructArray::from_data(
+        Struct(fields),
+        vec![Arc::new(values)],
+        Some(struct_validity),
+    );
+    ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(values.data_type().clone()),
+        offsets.into(),
+        Arc::new(values),
+        Some(rows.iter().map(|(is_valid, _)| *is_valid).collect()),
+    )
+}
+
+#[test]
+fn eq_rows_nested() {
+    use arrow2::compute::comparison::{eq_rows, neq_rows};
+
+    let lhs = list_of_struct(&[
+        (true, vec![Some(Some(1)), Some(None), None]),
+        (true, vec![Some(Some(1)), Some(None), None]),
+        (true, vec![Some(Some(1)), Some(None)]),
+        (false, vec![Some(Some(1))]),
+        (true, vec![]),
+        (true, vec![None]),
+    ]);
+    let rhs = list_of_struct(&[
+        (true, vec![Some(Some(1)), Some(None), None]),
+        (true, vec![Some(Some(1)), None, None]),
+        (true, vec![Some(Some(1)), Some(None), None]),
+        (false, vec![Some(Some(2)), None]),
+        (false, vec![]),
+        (true, vec![Some(None)]),
+    ]);
+
+    let expected = BooleanArray::from_slice([true, false, false, true, false, false]);
+    assert_eq!(eq_rows(&lhs, &rhs), expected);
+    assert_eq!(
+        neq_rows(&lhs, &rhs),
+        arrow2::compute::boolean::not(&expected)
+    );
+}
+
+#[test]
+fn eq_rows_float() {
+    use arrow2::compute::comparison::eq_rows;
+
+    let lhs = Float64Array::from([Some(f64::NAN), Some(-0.0), Some(1.0), None]);
+    let rhs = Float64Array::from([Some(-f64::NAN), Some(0.0), Some(1.0), None]);
+    let expected = BooleanArray::from_slice([true, false, true, true]);
+    assert_eq!(eq_rows(&lhs, &rhs), expected);
+
+    let values = Arc::new(Float32Array::from_slice([f32::NAN, 1.0]));
+    let lhs = ListArray::<i32>::from_data(
+        List(Box::new(Field::new("item", Float32, true))),
+        vec![0, 2].into(),
+        values,
+        None,
+    );
+    assert_eq!(eq_rows(&lhs, &lhs), BooleanArray::from_slice([true]));
+}
+
+fn rows_strategy(len: usize) -> impl Strategy<Value = Vec<Row>> {
+    prop::collection::vec(
+        (
+            any::<bool>(),
+            prop::collection::vec(prop::option::of(prop::option::of(0..3i32)), 0..3),
+        ),
+        len,
+    )
+}
+
+/// A debug representation of a row where values behind nulls are not represented
+fn canonical((is_valid, items): &Row) -> String {
+    format!("{:?}", is_valid.then(|| items))
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn eq_rows_against_debug(
+        (lhs, rhs) in (0..20usize).prop_flat_map(|len| (rows_strategy(len), rows_strategy(len)))
+    ) {
+        let expected = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(lhs, rhs)| canonical(lhs) == canonical(rhs))
+            .collect::<Vec<_>>();
+
+        let lhs = list_of_struct(&lhs);
+        let rhs = list_of_struct(&rhs);
+        let result = arrow2::compute::comparison::eq_rows(&lhs, &rhs);
+        prop_assert_eq!(result, BooleanArray::from_slice(expected));
+    }
+}
+
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
 #[cfg(test)]
@@ -114,6 +256,19 @@ mod tests {
         assert_eq!(d, BooleanArray::from_slice(&[true, true, true]));
     }
 
+    #[test]
+    fn test_eq_with_sliced_validities() {
+        use arrow2::compute::comparison::primitive;
+
+        let a = Int32Array::from(&[Some(1), None, Some(3), Some(4), None, Some(6)]).slice(1, 5);
+        let b = Int32Array::from(&[None, None, None, Some(3), Some(5), Some(5), None]).slice(2, 5);
+        let c = primitive::eq(&a, &b);
+        assert_eq!(
+            c,
+            BooleanArray::from(&[None, Some(true), Some(false), None, None])
+        );
+    }
+
     #[test]
     fn test_neq() {
         cmp_bool!(
@@ -1,5 +1,6 @@
 use arrow2::array::*;
 use arrow2::compute::concatenate::concatenate;
+use arrow2::datatypes::DataType;
 use arrow2::error::Result;
 
 #[test]
@@ -115,3 +116,16 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn null() -> Result<()> {
+    let arr = concatenate(&[
+        &NullArray::new_null(DataType::Null, 2),
+        &NullArray::new_null(DataType::Null, 3).slice(1, 2),
+    ])?;
+    assert_eq!(
+        arr.as_ref(),
+        &NullArray::new_null(DataType::Null, 4) as &dyn Array
+    );
+    Ok(())
+}
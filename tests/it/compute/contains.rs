@@ -1,5 +1,6 @@
 use arrow2::array::*;
-use arrow2::compute::contains::contains;
+use arrow2::compute::contains::*;
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
 
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
@@ -59,3 +60,148 @@ fn test_contains_binary() {
 
     assert_eq!(result, expected);
 }
+
+fn list() -> ListArray<i32> {
+    let data = vec![
+        Some(vec![Some(1), Some(2), None]),
+        Some(vec![Some(3)]),
+        Some(vec![]),
+        None,
+    ];
+    let mut a = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    a.try_extend(data).unwrap();
+    a.into()
+}
+
+#[test]
+fn contains_scalar_primitive() {
+    let list = list();
+
+    let result = contains_scalar(&list, &PrimitiveScalar::<i32>::from(Some(1))).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), Some(false), None]);
+    assert_eq!(result, expected);
+
+    let result = contains_scalar(&list, &PrimitiveScalar::<i32>::from(None)).unwrap();
+    assert_eq!(result, BooleanArray::from(&[None, None, None, None]));
+
+    let result = contains_scalar(&list.slice(1, 3), &PrimitiveScalar::<i32>::from(Some(3)));
+    let expected = BooleanArray::from(&[Some(true), Some(false), None]);
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[test]
+fn contains_scalar_utf8() {
+    let data = vec![Some(vec![Some("a"), None]), None, Some(vec![Some("b")])];
+    let mut list = MutableListArray::<i64, MutableUtf8Array<i32>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i64> = list.into();
+
+    let result = contains_scalar(&list, &Utf8Scalar::<i32>::from(Some("b"))).unwrap();
+    let expected = BooleanArray::from(&[Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn contains_scalar_errors() {
+    let list = list();
+    assert!(contains_scalar(&list, &PrimitiveScalar::<i64>::from(Some(1))).is_err());
+    let array = Int32Array::from_slice(&[1]);
+    assert!(contains_scalar(&array, &PrimitiveScalar::<i32>::from(Some(1))).is_err());
+}
+
+#[test]
+fn in_set_primitive() {
+    let array = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+    let set = Int32Array::from_slice(&[4, 1]);
+
+    let result = in_set(&array, &set).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+
+    // the option has no effect when the set has no nulls
+    let options = InSetOptions {
+        null_if_set_has_nulls: true,
+    };
+    let result = in_set_with_options(&array, &set, options).unwrap();
+    assert_eq!(result, expected);
+}
+
+// Expected behaviour (SQL):
+// 1 IN (1, null) = true
+// 2 IN (1, null) = null
+// null IN (1, null) = null
+#[test]
+fn in_set_with_nulls() {
+    let array = Int32Array::from(&[Some(1), Some(2), None]);
+    let set = Int32Array::from(&[Some(1), None]);
+
+    let result = in_set(&array, &set).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), None]);
+    assert_eq!(result, expected);
+
+    let options = InSetOptions {
+        null_if_set_has_nulls: true,
+    };
+    let result = in_set_with_options(&array, &set, options).unwrap();
+    let expected = BooleanArray::from(&[Some(true), None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn in_set_float() {
+    let array = Float64Array::from_slice(&[1.0, f64::NAN, 2.5]);
+    let set = Float64Array::from_slice(&[f64::NAN, 2.5]);
+
+    let result = in_set(&array, &set).unwrap();
+    assert_eq!(result, BooleanArray::from_slice(&[false, true, true]));
+}
+
+#[test]
+fn in_set_utf8() {
+    let array = Utf8Array::<i64>::from(&[Some("a"), Some("b"), None, Some("c")]);
+    let set = Utf8Array::<i64>::from(&[Some("c"), None, Some("a")]);
+
+    let result = in_set(&array, &set).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+
+    let options = InSetOptions {
+        null_if_set_has_nulls: true,
+    };
+    let result = in_set_with_options(&array.slice(1, 3), &set, options).unwrap();
+    let expected = BooleanArray::from(&[None, None, Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn in_set_binary() {
+    let array = BinaryArray::<i32>::from(&[Some(b"a"), None, Some(b"b")]);
+    let set = BinaryArray::<i32>::from_slice(&[b"b"]);
+
+    let result = in_set(&array, &set).unwrap();
+    let expected = BooleanArray::from(&[Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn in_set_boolean() {
+    let array = BooleanArray::from(&[Some(true), Some(false), None]);
+    let set = BooleanArray::from(&[Some(true), None]);
+
+    let result = in_set(&array, &set).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), None]);
+    assert_eq!(result, expected);
+
+    let options = InSetOptions {
+        null_if_set_has_nulls: true,
+    };
+    let result = in_set_with_options(&array, &set, options).unwrap();
+    let expected = BooleanArray::from(&[Some(true), None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn in_set_errors() {
+    let array = Int32Array::from_slice(&[1]);
+    assert!(in_set(&array, &Int64Array::from_slice(&[1])).is_err());
+}
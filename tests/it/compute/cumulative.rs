@@ -0,0 +1,122 @@
+use arrow2::array::*;
+use arrow2::compute::cumulative::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn sum_skip_nulls() {
+    let array = Int32Array::from([Some(1), None, Some(2), None, Some(3)]);
+    let result = cum_sum(&array, true);
+    let expected = Int32Array::from([Some(1), None, Some(3), None, Some(6)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn sum_propagate_nulls() {
+    let array = Int32Array::from([Some(1), Some(2), None, Some(3)]);
+    let result = cum_sum(&array, false);
+    let expected = Int32Array::from([Some(1), Some(3), None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn sum_leading_nulls() {
+    let array = Int32Array::from([None, Some(2), Some(3)]);
+    assert_eq!(
+        cum_sum(&array, true),
+        Int32Array::from([None, Some(2), Some(5)])
+    );
+    assert_eq!(cum_sum(&array, false), Int32Array::from([None, None, None]));
+}
+
+#[test]
+fn sum_sliced() {
+    let array = Int32Array::from_slice([10, 1, 2, 3]).slice(1, 3);
+    assert_eq!(cum_sum(&array, true), Int32Array::from_slice([1, 3, 6]));
+}
+
+#[test]
+fn sum_keeps_data_type() {
+    let array =
+        Int64Array::from_slice([1, 2]).to(DataType::Duration(arrow2::datatypes::TimeUnit::Second));
+    let result = cum_sum(&array, true);
+    assert_eq!(result.data_type(), array.data_type());
+}
+
+#[test]
+fn checked_sum_overflow() {
+    let array = Int32Array::from([Some(i32::MAX - 1), Some(1), Some(1), Some(-5)]);
+    let result = checked_cum_sum(&array, true);
+    let expected = Int32Array::from([Some(i32::MAX - 1), Some(i32::MAX), None, None]);
+    assert_eq!(result, expected);
+
+    let array = Int32Array::from([Some(i32::MIN), None, Some(-1), Some(1)]);
+    let result = checked_cum_sum(&array, true);
+    let expected = Int32Array::from([Some(i32::MIN), None, None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn prod() {
+    let array = Int32Array::from([Some(2), None, Some(3), Some(4)]);
+    assert_eq!(
+        cum_prod(&array, true),
+        Int32Array::from([Some(2), None, Some(6), Some(24)])
+    );
+    assert_eq!(
+        cum_prod(&array, false),
+        Int32Array::from([Some(2), None, None, None])
+    );
+}
+
+#[test]
+fn checked_prod_overflow() {
+    let array = Int32Array::from_slice([65536, 2, 16384, 2, 1]);
+    let result = checked_cum_prod(&array, false);
+    let expected = Int32Array::from([Some(65536), Some(131072), None, None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn min_max() {
+    let array = Int32Array::from([Some(3), None, Some(5), Some(1), Some(2)]);
+    assert_eq!(
+        cum_min(&array, true),
+        Int32Array::from([Some(3), None, Some(3), Some(1), Some(1)])
+    );
+    assert_eq!(
+        cum_max(&array, true),
+        Int32Array::from([Some(3), None, Some(5), Some(5), Some(5)])
+    );
+    assert_eq!(
+        cum_min(&array, false),
+        Int32Array::from([Some(3), None, None, None, None])
+    );
+    assert_eq!(
+        cum_max(&array, false),
+        Int32Array::from([Some(3), None, None, None, None])
+    );
+}
+
+#[test]
+fn float() {
+    let array = Float64Array::from([Some(1.5), Some(f64::NAN), Some(-1.0), None, Some(0.5)]);
+    assert_eq!(
+        cum_min(&array, true),
+        Float64Array::from([Some(1.5), Some(1.5), Some(-1.0), None, Some(-1.0)])
+    );
+    assert_eq!(
+        cum_max(&array, true),
+        Float64Array::from([Some(1.5), Some(1.5), Some(1.5), None, Some(1.5)])
+    );
+    let array = Float64Array::from_slice([1.5, 0.5, -1.0]);
+    assert_eq!(
+        cum_sum(&array, true),
+        Float64Array::from_slice([1.5, 2.0, 1.0])
+    );
+}
+
+#[test]
+fn empty() {
+    let array = Int32Array::from_slice([]);
+    assert_eq!(cum_sum(&array, true), array);
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::columns::Columns;
+use arrow2::compute::dedup::*;
+use arrow2::error::Result;
+
+fn columns(arrays: Vec<Arc<dyn Array>>) -> Columns<Arc<dyn Array>> {
+    Columns::try_new(arrays).unwrap()
+}
+
+#[test]
+fn nulls_are_equal() -> Result<()> {
+    let a = Int32Array::from(&[None, None, Some(1), Some(1), None]);
+    let b = Float64Array::from(&[Some(f64::NAN), Some(f64::NAN), None, None, None]);
+    let columns = columns(vec![Arc::new(a), Arc::new(b)]);
+
+    let result = dedup_consecutive(&columns, &[0])?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, false, true, false, true])
+    );
+
+    // NaNs are equal to NaNs
+    let result = dedup_consecutive(&columns, &[1, 0])?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, false, true, false, true])
+    );
+    Ok(())
+}
+
+#[test]
+fn multiple_columns() -> Result<()> {
+    let a = Utf8Array::<i32>::from_slice(&["a", "a", "a", "b", "b"]);
+    let b = Int64Array::from(&[Some(1), Some(1), Some(2), Some(2), None]);
+    let c = Int64Array::from_slice(&[1, 2, 3, 4, 5]);
+    let columns = columns(vec![Arc::new(a), Arc::new(b), Arc::new(c)]);
+
+    let result = dedup_consecutive(&columns, &[0, 1])?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, false, true, true, true])
+    );
+    Ok(())
+}
+
+#[test]
+fn single_row() -> Result<()> {
+    let a = Int32Array::from(&[None]);
+    let result = dedup_consecutive(&columns(vec![Arc::new(a)]), &[0])?;
+    assert_eq!(result, BooleanArray::from_slice(&[true]));
+
+    let a = Int32Array::from_slice(&[]);
+    let result = dedup_consecutive(&columns(vec![Arc::new(a)]), &[0])?;
+    assert_eq!(result.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn invalid_columns() {
+    let columns = columns(vec![Arc::new(Int32Array::from_slice(&[1]))]);
+    assert!(dedup_consecutive(&columns, &[]).is_err());
+    assert!(dedup_consecutive(&columns, &[1]).is_err());
+}
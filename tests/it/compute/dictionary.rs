@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use proptest::prelude::*;
+
+use arrow2::array::*;
+use arrow2::compute::concatenate::concatenate;
+use arrow2::compute::dictionary::{decode, encode};
+use arrow2::compute::filter::filter;
+use arrow2::compute::hash::hash_with_hashes;
+use arrow2::compute::take::take;
+use arrow2::datatypes::{DataType, IntegerType};
+use arrow2::error::ArrowError;
+
+fn encode_u8(array: &dyn Array) -> DictionaryArray<u8> {
+    let encoded = encode(array, &DataType::UInt8).unwrap();
+    encoded
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("b"), Some("c")]);
+    let result = encode_u8(&array);
+
+    assert_eq!(
+        result.keys(),
+        &UInt8Array::from([Some(0), None, Some(1), Some(0), Some(2)])
+    );
+    assert_eq!(
+        result.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["b", "a", "c"]) as &dyn Array
+    );
+    assert_eq!(decode(&result).unwrap().as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn primitive() {
+    let array = Int64Array::from([Some(3), Some(1), Some(3), None, Some(1)]).to(DataType::Date64);
+    let encoded = encode(&array, &DataType::Int32).unwrap();
+    let result = encoded
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    assert_eq!(
+        result.data_type(),
+        &DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Date64), false)
+    );
+    assert_eq!(
+        result.keys(),
+        &Int32Array::from([Some(0), Some(1), Some(0), None, Some(1)])
+    );
+    assert_eq!(decode(result).unwrap().as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn float() {
+    let array = Float64Array::from_slice([f64::NAN, 0.0, -0.0, f64::NAN, 0.0]);
+    let result = encode_u8(&array);
+
+    assert_eq!(result.keys(), &UInt8Array::from_slice([0, 1, 2, 0, 1]));
+    assert_eq!(result.values().len(), 3);
+}
+
+#[test]
+fn boolean_and_binary() {
+    let array = BooleanArray::from([Some(true), Some(true), None, Some(false)]);
+    let result = encode_u8(&array);
+    assert_eq!(
+        result.keys(),
+        &UInt8Array::from([Some(0), Some(0), None, Some(1)])
+    );
+    assert_eq!(decode(&result).unwrap().as_ref(), &array as &dyn Array);
+
+    let array = BinaryArray::<i64>::from([Some(b"a".as_ref()), None, Some(b"a")]);
+    let result = encode_u8(&array);
+    assert_eq!(result.keys(), &UInt8Array::from([Some(0), None, Some(0)]));
+    assert_eq!(decode(&result).unwrap().as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn fixed_size_binary() {
+    let array =
+        FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), Some(b"cd"), None, Some(b"ab")], 2);
+    let result = encode_u8(&array);
+    assert_eq!(
+        result.keys(),
+        &UInt8Array::from([Some(0), Some(1), None, Some(0)])
+    );
+    assert_eq!(decode(&result).unwrap().as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn sliced() {
+    let array = Utf8Array::<i32>::from_slice(["a", "b", "c", "b"]).slice(1, 3);
+    let result = encode_u8(&array);
+    assert_eq!(result.keys(), &UInt8Array::from_slice([0, 1, 0]));
+    assert_eq!(decode(&result).unwrap().as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn null() {
+    let array = NullArray::from_data(DataType::Null, 3);
+    let result = encode_u8(&array);
+    assert_eq!(result.keys(), &UInt8Array::from([None, None, None]));
+    assert_eq!(result.values().len(), 0);
+}
+
+#[test]
+fn overflow() {
+    let array = Int32Array::from_values(0..257);
+    assert!(matches!(
+        encode(&array, &DataType::UInt8),
+        Err(ArrowError::Overflow)
+    ));
+    assert!(encode(&array, &DataType::Int16).is_ok());
+
+    // 256 distinct values fit in a `u8`
+    let array = Int32Array::from_values(0..256);
+    assert!(encode(&array, &DataType::UInt8).is_ok());
+}
+
+#[test]
+fn invalid() {
+    let array = Int32Array::from_slice([1]);
+    assert!(encode(&array, &DataType::Utf8).is_err());
+    assert!(decode(&array).is_err());
+}
+
+#[test]
+fn decode_nulls() {
+    // a null key and a key pointing to a null value both decode to a null
+    let keys = Int8Array::from([Some(0), None, Some(1), Some(0)]);
+    let values = Arc::new(Utf8Array::<i32>::from([Some("a"), None])) as Arc<dyn Array>;
+    let array = DictionaryArray::<i8>::from_data(keys, values);
+
+    let result = decode(&array).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Utf8Array::<i32>::from([Some("a"), None, None, Some("a")]) as &dyn Array
+    );
+}
+
+/// A dictionary whose values contain nulls, with keys pointing to any of them, a filter and
+/// indices of the same length as the dictionary.
+#[allow(clippy::type_complexity)]
+fn dictionary_strategy(
+) -> impl Strategy<Value = (Vec<Option<i32>>, Vec<Option<i32>>, Vec<bool>, Vec<i32>)> {
+    prop::collection::vec(prop::option::of(0..3i32), 1..5).prop_flat_map(|values| {
+        let n_values = values.len() as i32;
+        (0..20usize).prop_flat_map(move |len| {
+            (
+                Just(values.clone()),
+                prop::collection::vec(prop::option::of(0..n_values), len),
+                prop::collection::vec(any::<bool>(), len),
+                prop::collection::vec(0..len.max(1) as i32, len),
+            )
+        })
+    })
+}
+
+fn validity_of(array: &dyn Array) -> Vec<bool> {
+    (0..array.len()).map(|i| array.is_valid(i)).collect()
+}
+
+proptest! {
+    /// Asserts that every kernel treats a key pointing to a null value as a null slot, i.e.
+    /// that it agrees with the decoded dictionary
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn kernels_against_decoded((values, keys, mask, indices) in dictionary_strategy()) {
+        let values = Arc::new(Int32Array::from(values)) as Arc<dyn Array>;
+        let array = DictionaryArray::<i32>::from_data(Int32Array::from(keys), values);
+        let decoded = decode(&array).unwrap();
+
+        let merged = array
+            .value_validity_merged()
+            .map(|x| x.iter().collect::<Vec<_>>())
+            .unwrap_or_else(|| vec![true; array.len()]);
+        prop_assert_eq!(merged, validity_of(decoded.as_ref()));
+
+        // equality against an encoding where every null slot is a null key
+        let encoded = encode(decoded.as_ref(), &DataType::Int32).unwrap();
+        prop_assert_eq!(&array as &dyn Array, encoded.as_ref());
+
+        let display = get_display(&array);
+        let decoded_display = get_display(decoded.as_ref());
+        for i in 0..array.len() {
+            prop_assert_eq!(display(i), decoded_display(i));
+        }
+
+        let mut hashes = vec![0; array.len()];
+        hash_with_hashes(&array, &mut hashes).unwrap();
+        let mut decoded_hashes = vec![0; array.len()];
+        hash_with_hashes(decoded.as_ref(), &mut decoded_hashes).unwrap();
+        prop_assert_eq!(hashes, decoded_hashes);
+
+        let indices = Int32Array::from_slice(indices);
+        let result = take(&array, &indices).unwrap();
+        prop_assert_eq!(
+            decode(result.as_ref()).unwrap(),
+            take(decoded.as_ref(), &indices).unwrap()
+        );
+
+        let mask = BooleanArray::from_slice(mask);
+        let result = filter(&array, &mask).unwrap();
+        prop_assert_eq!(
+            decode(result.as_ref()).unwrap(),
+            filter(decoded.as_ref(), &mask).unwrap()
+        );
+
+        let result = concatenate(&[&array, &array.slice(0, array.len() / 2)]).unwrap();
+        let expected = concatenate(&[decoded.as_ref(), decoded.slice(0, array.len() / 2).as_ref()]).unwrap();
+        prop_assert_eq!(decode(result.as_ref()).unwrap(), expected);
+    }
+}
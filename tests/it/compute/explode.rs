@@ -0,0 +1,88 @@
+use arrow2::array::*;
+use arrow2::compute::explode::*;
+use arrow2::error::Result;
+
+fn list<O: Offset>() -> Result<ListArray<O>> {
+    let mut list = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![None, Some(3)]),
+    ])?;
+    Ok(list.into())
+}
+
+fn test_explode<O: Offset>() -> Result<()> {
+    let list = list::<O>()?;
+
+    let (values, indices) = explode(&list)?;
+    let expected = Int32Array::from([Some(1), Some(2), None, Some(3)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(indices, Int64Array::from_slice([0, 0, 3, 3]));
+    Ok(())
+}
+
+#[test]
+fn explode_i32() -> Result<()> {
+    test_explode::<i32>()
+}
+
+#[test]
+fn explode_i64() -> Result<()> {
+    test_explode::<i64>()
+}
+
+#[test]
+fn keep_empty() -> Result<()> {
+    let list = list::<i32>()?;
+
+    let options = ExplodeOptions { keep_empty: true };
+    let (values, indices) = explode_with_options(&list, options)?;
+    let expected = Int32Array::from([Some(1), Some(2), None, None, None, Some(3)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(indices, Int64Array::from_slice([0, 0, 1, 2, 3, 3]));
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let list = list::<i32>()?;
+    let list = list.slice(2, 2);
+
+    let (values, indices) = explode(&list)?;
+    let expected = Int32Array::from([None, Some(3)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(indices, Int64Array::from_slice([1, 1]));
+
+    let options = ExplodeOptions { keep_empty: true };
+    let (values, indices) = explode_with_options(&list, options)?;
+    let expected = Int32Array::from([None, None, Some(3)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(indices, Int64Array::from_slice([0, 1, 1]));
+    Ok(())
+}
+
+#[test]
+fn null_with_values() -> Result<()> {
+    // a null list whose offsets still span values of the child
+    let values = Int32Array::from_slice([1, 2, 3]);
+    let list = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(values.data_type().clone()),
+        vec![0, 1, 3].into(),
+        std::sync::Arc::new(values),
+        Some([true, false].into()),
+    );
+
+    let (values, indices) = explode(&list)?;
+    assert_eq!(values.as_ref(), &Int32Array::from_slice([1]) as &dyn Array);
+    assert_eq!(indices, Int64Array::from_slice([0]));
+    Ok(())
+}
+
+#[test]
+fn unsupported() {
+    let array = Int32Array::from_slice([1]);
+    assert!(!can_explode(array.data_type()));
+    assert!(explode(&array).is_err());
+}
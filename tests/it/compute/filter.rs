@@ -1,6 +1,7 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
 use arrow2::compute::filter::*;
+use arrow2::datatypes::DataType;
 
 #[test]
 fn array_slice() {
@@ -193,3 +194,126 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+#[test]
+fn null_array() {
+    let array = NullArray::new_null(DataType::Null, 4);
+    let mask = BooleanArray::from_slice([true, false, true, false]);
+    let result = filter(&array, &mask).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &NullArray::new_null(DataType::Null, 2) as &dyn Array
+    );
+}
+
+#[test]
+fn mask_to_indices_nulls() {
+    let mask = BooleanArray::from(&[Some(true), None, Some(false), Some(true), None]);
+    assert_eq!(
+        mask_to_indices::<u32>(&mask).unwrap(),
+        UInt32Array::from_slice([0, 3])
+    );
+    assert_eq!(
+        mask_to_indices::<u64>(&BooleanArray::from_slice([])).unwrap(),
+        UInt64Array::from_slice([])
+    );
+}
+
+#[test]
+fn mask_to_indices_sliced() {
+    let values = (0..200).map(|i| i % 7 == 0 || i % 11 == 0).collect::<Vec<_>>();
+    let mask = BooleanArray::from_slice(&values);
+    // offsets that are not multiple of a byte nor of a word
+    for (offset, length) in [(3, 190), (61, 70), (64, 1), (5, 0), (13, 64)] {
+        let sliced = mask.slice(offset, length);
+        let expected = (0..length as u64)
+            .filter(|i| values[offset + *i as usize])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mask_to_indices::<u64>(&sliced).unwrap(),
+            UInt64Array::from_vec(expected.clone())
+        );
+        assert_eq!(
+            indices_to_mask(&UInt64Array::from_vec(expected), length).unwrap(),
+            sliced
+        );
+    }
+}
+
+#[test]
+fn mask_to_indices_runs() {
+    let length = 1000;
+    let all_false = BooleanArray::from_slice(vec![false; length]);
+    assert_eq!(mask_to_indices::<u32>(&all_false).unwrap().len(), 0);
+    assert_eq!(selectivity_hint(&all_false), 0.0);
+
+    let all_true = BooleanArray::from_slice(vec![true; length]).slice(3, 900);
+    let indices = mask_to_indices::<u32>(&all_true).unwrap();
+    assert_eq!(indices, UInt32Array::from_vec((0..900).collect()));
+    assert_eq!(selectivity_hint(&all_true), 1.0);
+
+    // a single `true` at the end of a long run of `false`
+    let mut values = vec![false; length];
+    values[length - 1] = true;
+    let mask = BooleanArray::from_slice(values);
+    assert_eq!(
+        mask_to_indices::<u32>(&mask).unwrap(),
+        UInt32Array::from_slice([length as u32 - 1])
+    );
+    assert_eq!(selectivity_hint(&mask), 0.001);
+}
+
+#[test]
+fn indices_to_mask_errors() {
+    let indices = UInt32Array::from(&[Some(1), None, Some(1)]);
+    assert_eq!(
+        indices_to_mask(&indices, 3).unwrap(),
+        BooleanArray::from_slice([false, true, false])
+    );
+    assert!(indices_to_mask(&indices, 1).is_err());
+}
+
+#[test]
+fn selectivity_hint_nulls() {
+    let mask = BooleanArray::from(&[Some(true), None, Some(false), Some(true)]);
+    assert_eq!(selectivity_hint(&mask), 0.5);
+    assert_eq!(selectivity_hint(&BooleanArray::from_slice([])), 0.0);
+}
+
+#[test]
+fn sparse_primitive() {
+    // a selectivity low enough for the values to be gathered by indices
+    let length = 1000;
+    let values = (0..length as i64).collect::<Vec<_>>();
+    let a = Int64Array::from_slice(&values).slice(7, 900);
+    let b = BooleanArray::from_slice((0..length).map(|i| i % 50 == 1).collect::<Vec<_>>())
+        .slice(7, 900);
+    let c = filter(&a, &b).unwrap();
+
+    let expected = (7..907).filter(|i| i % 50 == 1).collect::<Vec<i64>>();
+    assert_eq!(c.as_ref(), &Int64Array::from_vec(expected) as &dyn Array);
+}
+
+#[test]
+fn low_entropy() {
+    // long runs of selected slots are copied run by run
+    let mask = (0..1000).map(|i| (i / 100) % 2 == 1).collect::<Vec<_>>();
+    let expected = (0..1000i32)
+        .filter(|i| (i / 100) % 2 == 1)
+        .map(|i| Some(i).filter(|i| i % 7 != 0))
+        .collect::<Int32Array>();
+
+    let a = (0..1000)
+        .map(|i| Some(i).filter(|i| i % 7 != 0))
+        .collect::<Int32Array>();
+    let b = BooleanArray::from_slice(mask);
+    let c = filter(&a, &b).unwrap();
+    assert_eq!(c.as_ref(), &expected as &dyn Array);
+
+    let c = filter(&a.slice(3, 990), &b.slice(3, 990)).unwrap();
+    let expected = (3..993i32)
+        .filter(|i| (i / 100) % 2 == 1)
+        .map(|i| Some(i).filter(|i| i % 7 != 0))
+        .collect::<Int32Array>();
+    assert_eq!(c.as_ref(), &expected as &dyn Array);
+}
@@ -0,0 +1,187 @@
+use arrow2::array::*;
+use arrow2::compute::find::*;
+use arrow2::error::Result;
+
+fn values<O: Offset>() -> Utf8Array<O> {
+    Utf8Array::<O>::from(&[
+        Some("arrow"),
+        None,
+        Some(""),
+        Some("héllo wörld"),
+        Some("ab"),
+        Some("ba"),
+    ])
+}
+
+fn scalar<O: Offset>() {
+    let array = values::<O>();
+
+    let result = starts_with_scalar(&array, "ar");
+    let expected = [
+        Some(true),
+        None,
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(false),
+    ];
+    assert_eq!(result, BooleanArray::from(&expected));
+
+    let result = ends_with_scalar(&array, "ld");
+    let expected = [
+        Some(false),
+        None,
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(false),
+    ];
+    assert_eq!(result, BooleanArray::from(&expected));
+
+    let result = contains_scalar(&array, "ö");
+    let expected = [
+        Some(false),
+        None,
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(false),
+    ];
+    assert_eq!(result, BooleanArray::from(&expected));
+
+    let result = strpos_scalar(&array, "ö");
+    let result = result
+        .iter()
+        .map(|x| x.map(|x| x.to_usize()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        result,
+        vec![Some(0), None, Some(0), Some(8), Some(0), Some(0)]
+    );
+}
+
+#[test]
+fn utf8_scalar() {
+    scalar::<i32>()
+}
+
+#[test]
+fn large_utf8_scalar() {
+    scalar::<i64>()
+}
+
+#[test]
+fn contains_across_rows() {
+    // "ab" only occurs across "ba" and "ab" and across "ab" and "ab"
+    let array = Utf8Array::<i32>::from_slice(&["xa", "bx", "ba", "ab", "b", "aab"]);
+    let result = contains_scalar(&array, "ab");
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[false, false, false, true, false, true])
+    );
+    let result = strpos_scalar(&array, "ab");
+    assert_eq!(result, Int32Array::from_slice(&[0, 0, 0, 1, 0, 2]));
+
+    // an occurrence after an occurrence across rows
+    let array = Utf8Array::<i32>::from_slice(&["a", "aa"]);
+    let result = contains_scalar(&array, "aa");
+    assert_eq!(result, BooleanArray::from_slice(&[false, true]));
+}
+
+#[test]
+fn empty_needle() {
+    let array = values::<i32>();
+    let expected = [
+        Some(true),
+        None,
+        Some(true),
+        Some(true),
+        Some(true),
+        Some(true),
+    ];
+    assert_eq!(contains_scalar(&array, ""), BooleanArray::from(&expected));
+    assert_eq!(
+        starts_with_scalar(&array, ""),
+        BooleanArray::from(&expected)
+    );
+    assert_eq!(ends_with_scalar(&array, ""), BooleanArray::from(&expected));
+    assert_eq!(
+        strpos_scalar(&array, ""),
+        Int32Array::from(&[Some(1), None, Some(1), Some(1), Some(1), Some(1)])
+    );
+}
+
+#[test]
+fn long_needle() {
+    let array = values::<i32>();
+    let needle = "arrow arrow";
+    let expected = [
+        Some(false),
+        None,
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(false),
+    ];
+    assert_eq!(
+        contains_scalar(&array, needle),
+        BooleanArray::from(&expected)
+    );
+    assert_eq!(
+        starts_with_scalar(&array, needle),
+        BooleanArray::from(&expected)
+    );
+    assert_eq!(
+        strpos_scalar(&array, needle),
+        Int32Array::from(&[Some(0), None, Some(0), Some(0), Some(0), Some(0)])
+    );
+}
+
+#[test]
+fn sliced() {
+    let array = Utf8Array::<i32>::from_slice(&["ab", "cab", "c", "ab"]).slice(1, 2);
+    assert_eq!(
+        contains_scalar(&array, "ab"),
+        BooleanArray::from_slice(&[true, false])
+    );
+    assert_eq!(strpos_scalar(&array, "ab"), Int32Array::from_slice(&[2, 0]));
+}
+
+#[test]
+fn arrays() -> Result<()> {
+    let lhs = Utf8Array::<i32>::from(&[
+        Some("arrow"),
+        Some("arrow"),
+        None,
+        Some("héllo"),
+        Some(""),
+        Some("ab"),
+    ]);
+    let rhs = Utf8Array::<i32>::from(&[
+        Some("ar"),
+        None,
+        Some("a"),
+        Some("llo"),
+        Some(""),
+        Some("abc"),
+    ]);
+
+    let result = starts_with(&lhs, &rhs)?;
+    let expected = [Some(true), None, None, Some(false), Some(true), Some(false)];
+    assert_eq!(result, BooleanArray::from(&expected));
+
+    let result = ends_with(&lhs, &rhs)?;
+    let expected = [Some(false), None, None, Some(true), Some(true), Some(false)];
+    assert_eq!(result, BooleanArray::from(&expected));
+
+    let result = contains(&lhs, &rhs)?;
+    let expected = [Some(true), None, None, Some(true), Some(true), Some(false)];
+    assert_eq!(result, BooleanArray::from(&expected));
+
+    let result = strpos(&lhs, &rhs)?;
+    let expected = [Some(1), None, None, Some(3), Some(1), Some(0)];
+    assert_eq!(result, Int32Array::from(&expected));
+
+    assert!(contains(&lhs, &rhs.slice(0, 2)).is_err());
+    Ok(())
+}
@@ -1,4 +1,6 @@
-use arrow2::array::new_null_array;
+use std::sync::Arc;
+
+use arrow2::array::*;
 use arrow2::compute::hash::*;
 use arrow2::datatypes::DataType::*;
 use arrow2::datatypes::TimeUnit;
@@ -47,3 +49,80 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn columns_consistent_with_hash() {
+    let a = Int32Array::from(&[Some(1), None, Some(3)]);
+    let result = hash_columns(&[&a], 7).unwrap();
+    let expected = hash(&a).unwrap();
+
+    assert_eq!(result.validity(), None);
+    assert_eq!(result.value(0), combine_hashes(7, expected.value(0)));
+    assert_eq!(result.value(2), combine_hashes(7, expected.value(2)));
+}
+
+#[test]
+fn columns_order_and_seed() {
+    let a = Int32Array::from_slice(&[1, 2, 1, 2]);
+    let b = Int32Array::from_slice(&[2, 1, 1, 2]);
+
+    let ab = hash_columns(&[&a, &b], 0).unwrap();
+    let ba = hash_columns(&[&b, &a], 0).unwrap();
+    // rows (1, 2) and (2, 1) differ
+    assert_ne!(ab.value(0), ab.value(1));
+    // the order of the columns matters
+    assert_ne!(ab.value(0), ba.value(0));
+    // (a, b) at row 0 is (b, a) at row 1
+    assert_eq!(ab.value(0), ba.value(1));
+    // equal rows hash equally
+    assert_eq!(ab.value(0), hash_columns(&[&a, &b], 0).unwrap().value(0));
+
+    assert_ne!(ab, hash_columns(&[&a, &b], 1).unwrap());
+}
+
+#[test]
+fn columns_nulls() {
+    // a null hashes the same independently of the type of its column
+    let a = Int32Array::from(&[None, Some(1)]);
+    let b = Utf8Array::<i32>::from(&[None, Some("a")]);
+    let c = new_null_array(Null, 2);
+
+    let a = hash_columns(&[&a], 0).unwrap();
+    let b = hash_columns(&[&b], 0).unwrap();
+    let c = hash_columns(&[c.as_ref()], 0).unwrap();
+    assert_eq!(a.value(0), b.value(0));
+    assert_eq!(a.value(0), c.value(0));
+    assert_eq!(c.value(0), c.value(1));
+    assert_ne!(a.value(0), a.value(1));
+}
+
+#[test]
+fn columns_dictionary() {
+    let values = Arc::new(Utf8Array::<i32>::from(&[Some("a"), None, Some("b")])) as Arc<dyn Array>;
+    let keys = UInt8Array::from(&[Some(2), Some(0), None, Some(1), Some(2)]);
+    let dictionary = DictionaryArray::<u8>::from_data(keys, values);
+    let flat = Utf8Array::<i32>::from(&[Some("b"), Some("a"), None, None, Some("b")]);
+    let other = Int64Array::from_slice(&[1, 2, 3, 4, 5]);
+
+    assert_eq!(
+        hash_columns(&[&dictionary, &other], 3).unwrap(),
+        hash_columns(&[&flat, &other], 3).unwrap()
+    );
+}
+
+#[test]
+fn with_hashes() {
+    let a = Int32Array::from_slice(&[1, 2, 3]);
+    let b = BooleanArray::from_slice(&[true, false, true]);
+
+    let mut hashes = vec![5; 3];
+    hash_with_hashes(&a, &mut hashes).unwrap();
+    hash_with_hashes(&b, &mut hashes).unwrap();
+    assert_eq!(
+        hashes,
+        hash_columns(&[&a, &b], 5).unwrap().values().as_slice()
+    );
+
+    assert!(hash_with_hashes(&a, &mut [0; 2]).is_err());
+    assert!(hash_columns(&[&a, &Int32Array::from_slice(&[1])], 0).is_err());
+}
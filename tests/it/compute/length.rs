@@ -86,3 +86,52 @@ fn consistency() {
         }
     });
 }
+
+fn list_lengths_test<O: Offset>() -> arrow2::error::Result<()> {
+    let mut list = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![None, Some(3), Some(4)]),
+    ])?;
+    let list: ListArray<O> = list.into();
+
+    let data_type = if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+    let to_expected = |x: Vec<Option<usize>>| {
+        x.into_iter()
+            .map(|x| x.map(|x| O::from_usize(x).unwrap()))
+            .collect::<PrimitiveArray<O>>()
+            .to(data_type.clone())
+    };
+
+    let result = list_lengths(&list)?;
+    let expected = to_expected(vec![Some(2), None, Some(0), Some(3)]);
+    assert_eq!(expected, result.as_ref());
+
+    // sliced lists have a non-zero offset into the values
+    let result = list_lengths(&list.slice(1, 3))?;
+    let expected = to_expected(vec![None, Some(0), Some(3)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn list_lengths_i32() -> arrow2::error::Result<()> {
+    list_lengths_test::<i32>()
+}
+
+#[test]
+fn list_lengths_i64() -> arrow2::error::Result<()> {
+    list_lengths_test::<i64>()
+}
+
+#[test]
+fn list_lengths_unsupported() {
+    let array = Int32Array::from_slice([1]);
+    assert!(list_lengths(&array).is_err());
+}
@@ -0,0 +1,324 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::list::*;
+use arrow2::compute::sort::SortOptions;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+
+fn list<O: Offset>() -> Result<ListArray<O>> {
+    let mut list = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![
+        Some(vec![Some(1), Some(2), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![None, Some(4)]),
+    ])?;
+    Ok(list.into())
+}
+
+fn expected_list<O: Offset>(data: Vec<Option<Vec<Option<i32>>>>) -> Result<ListArray<O>> {
+    let mut list = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(data)?;
+    Ok(list.into())
+}
+
+fn test_get<O: Offset>() -> Result<()> {
+    let list = list::<O>()?;
+
+    let result = get(&list, 0)?;
+    let expected = Int32Array::from(&[Some(1), None, None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get(&list, 1)?;
+    let expected = Int32Array::from(&[Some(2), None, None, Some(4)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get(&list, -1)?;
+    let expected = Int32Array::from(&[Some(3), None, None, Some(4)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // out of range
+    let result = get(&list, 3)?;
+    assert_eq!(result.null_count(), 4);
+    let result = get(&list, -4)?;
+    assert_eq!(result.null_count(), 4);
+    Ok(())
+}
+
+#[test]
+fn get_i32() -> Result<()> {
+    test_get::<i32>()
+}
+
+#[test]
+fn get_i64() -> Result<()> {
+    test_get::<i64>()
+}
+
+#[test]
+fn get_sliced() -> Result<()> {
+    let list = list::<i32>()?.slice(2, 2);
+
+    let result = get(&list, 0)?;
+    let expected = Int32Array::from(&[None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get(&list, -1)?;
+    let expected = Int32Array::from(&[None, Some(4)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+fn fixed_size_list() -> FixedSizeListArray {
+    let values = Arc::new(Int32Array::from_slice([1, 2, 3, 4, 5, 6]));
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    FixedSizeListArray::from_data(data_type, values, Some([true, false, true].into()))
+}
+
+#[test]
+fn get_fixed_size_list() -> Result<()> {
+    let list = fixed_size_list();
+
+    let result = get(&list, -1)?;
+    let expected = Int32Array::from(&[Some(2), None, Some(6)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get(&list.slice(1, 2), 0)?;
+    let expected = Int32Array::from(&[None, Some(5)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+fn test_slice<O: Offset>() -> Result<()> {
+    let list = list::<O>()?;
+
+    let result = slice(&list, 1, Some(1))?;
+    let expected = expected_list::<O>(vec![
+        Some(vec![Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4)]),
+    ])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = slice(&list, -2, None)?;
+    let expected = expected_list::<O>(vec![
+        Some(vec![Some(2), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![None, Some(4)]),
+    ])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // a negative offset beyond the length starts at the beginning of the list
+    let result = slice(&list, -10, Some(1))?;
+    let expected = expected_list::<O>(vec![
+        Some(vec![Some(1)]),
+        None,
+        Some(vec![]),
+        Some(vec![None]),
+    ])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = slice(&list, 5, None)?;
+    let expected = expected_list::<O>(vec![Some(vec![]), None, Some(vec![]), Some(vec![])])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn slice_i32() -> Result<()> {
+    test_slice::<i32>()
+}
+
+#[test]
+fn slice_i64() -> Result<()> {
+    test_slice::<i64>()
+}
+
+#[test]
+fn slice_sliced() -> Result<()> {
+    let list = list::<i32>()?.slice(1, 3);
+
+    let result = slice(&list, 0, Some(1))?;
+    let expected = expected_list::<i32>(vec![None, Some(vec![]), Some(vec![None])])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn slice_fixed_size_list() -> Result<()> {
+    let list = fixed_size_list();
+
+    let result = slice(&list, 1, None)?;
+    let expected = expected_list::<i32>(vec![Some(vec![Some(2)]), None, Some(vec![Some(6)])])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn unsupported() {
+    let array = Int32Array::from_slice([1]);
+    assert!(get(&array, 0).is_err());
+    assert!(slice(&array, 0, None).is_err());
+}
+
+fn test_sort_values<O: Offset>() -> Result<()> {
+    let mut list = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![
+        Some(vec![Some(3), None, Some(1), Some(3), None, Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(1), Some(1)]),
+        Some(vec![None]),
+    ])?;
+    let list: ListArray<O> = list.into();
+
+    let result = sort_values(&list, SortOptions::default())?;
+    let expected = expected_list::<O>(vec![
+        Some(vec![None, None, Some(1), Some(2), Some(3), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(1), Some(1)]),
+        Some(vec![None]),
+    ])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+        ..Default::default()
+    };
+    let result = sort_values(&list.slice(0, 2), options)?;
+    let expected = expected_list::<O>(vec![
+        Some(vec![Some(3), Some(3), Some(2), Some(1), None, None]),
+        None,
+    ])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // the offsets of sliced lists do not start at 0
+    let result = sort_values(&list.slice(3, 2), options)?;
+    let expected = expected_list::<O>(vec![Some(vec![Some(1), Some(1)]), Some(vec![None])])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn sort_values_i32() -> Result<()> {
+    test_sort_values::<i32>()
+}
+
+#[test]
+fn sort_values_i64() -> Result<()> {
+    test_sort_values::<i64>()
+}
+
+#[test]
+fn sort_values_utf8() -> Result<()> {
+    let mut list = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    list.try_extend(vec![Some(vec![Some("b"), None, Some("a"), Some("b")])])?;
+    let list: ListArray<i32> = list.into();
+
+    let result = sort_values(&list, SortOptions::default())?;
+    let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    expected.try_extend(vec![Some(vec![None, Some("a"), Some("b"), Some("b")])])?;
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn sort_values_fixed_size_list() -> Result<()> {
+    let values = Arc::new(Int32Array::from(&[
+        Some(2),
+        Some(1),
+        Some(4),
+        Some(3),
+        None,
+        Some(5),
+    ]));
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let list =
+        FixedSizeListArray::from_data(data_type.clone(), values, Some([true, false, true].into()));
+
+    let result = sort_values(&list, SortOptions::default())?;
+    let values = Arc::new(Int32Array::from(&[
+        Some(1),
+        Some(2),
+        Some(4),
+        Some(3),
+        None,
+        Some(5),
+    ]));
+    let expected =
+        FixedSizeListArray::from_data(data_type, values, Some([true, false, true].into()));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert!(sort_values(&Int32Array::from_slice([1]), SortOptions::default()).is_err());
+    Ok(())
+}
+
+fn test_distinct<O: Offset>() -> Result<()> {
+    let mut list = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![
+        Some(vec![
+            Some(3),
+            None,
+            Some(1),
+            Some(3),
+            None,
+            Some(1),
+            Some(2),
+        ]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(1)]),
+        Some(vec![Some(4), Some(4), Some(4)]),
+    ])?;
+    let list: ListArray<O> = list.into();
+
+    let result = distinct(&list)?;
+    let expected = expected_list::<O>(vec![
+        Some(vec![Some(3), None, Some(1), Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(1)]),
+        Some(vec![Some(4)]),
+    ])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = distinct(&list.slice(3, 2))?;
+    let expected = expected_list::<O>(vec![Some(vec![Some(1)]), Some(vec![Some(4)])])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn distinct_i32() -> Result<()> {
+    test_distinct::<i32>()
+}
+
+#[test]
+fn distinct_i64() -> Result<()> {
+    test_distinct::<i64>()
+}
+
+#[test]
+fn distinct_fixed_size_list() -> Result<()> {
+    let values = Arc::new(Int32Array::from(&[
+        Some(1),
+        Some(1),
+        Some(2),
+        Some(3),
+        None,
+        None,
+    ]));
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let list = FixedSizeListArray::from_data(data_type, values, Some([true, false, true].into()));
+
+    let result = distinct(&list)?;
+    let expected = expected_list::<i32>(vec![Some(vec![Some(1)]), None, Some(vec![None])])?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
@@ -34,6 +34,36 @@ fn merge_u32() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn merge_f64_nans() -> Result<()> {
+    let a0: &dyn Array = &Float64Array::from_slice(&[-1.0, f64::NAN]);
+    let a1: &dyn Array = &Float64Array::from_slice(&[0.0, 1.0]);
+
+    let options = SortOptions::default();
+    let arrays = vec![a0, a1];
+    let pairs = vec![(arrays.as_ref(), &options)];
+    let comparator = build_comparator(&pairs)?;
+
+    // NaNs are greater than any other value
+    let result =
+        merge_sort_slices(once(&(0, 0, 2)), once(&(1, 0, 2)), &comparator).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 0, 1), (1, 0, 2), (0, 1, 1)]);
+
+    let options = SortOptions {
+        nans_greatest: false,
+        ..options
+    };
+    let a0: &dyn Array = &Float64Array::from_slice(&[f64::NAN, -1.0]);
+    let arrays = vec![a0, a1];
+    let pairs = vec![(arrays.as_ref(), &options)];
+    let comparator = build_comparator(&pairs)?;
+
+    let result =
+        merge_sort_slices(once(&(0, 0, 2)), once(&(1, 0, 2)), &comparator).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 0, 2), (1, 0, 2)]);
+    Ok(())
+}
+
 #[test]
 fn merge_with_limit() -> Result<()> {
     let a0: &dyn Array = &Int32Array::from_slice(&[0, 2, 4, 6, 8]);
@@ -2,22 +2,38 @@
 mod aggregate;
 #[cfg(feature = "compute_arithmetics")]
 mod arithmetics;
+#[cfg(feature = "compute_binary")]
+mod binary;
 #[cfg(feature = "compute_bitwise")]
 mod bitwise;
 #[cfg(feature = "compute_boolean")]
 mod boolean;
 #[cfg(feature = "compute_boolean_kleene")]
 mod boolean_kleene;
+#[cfg(feature = "compute_broadcast")]
+mod broadcast;
 #[cfg(feature = "compute_cast")]
 mod cast;
+#[cfg(feature = "compute_clamp")]
+mod clamp;
 #[cfg(feature = "compute_comparison")]
 mod comparison;
 #[cfg(feature = "compute_concatenate")]
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(feature = "compute_cumulative")]
+mod cumulative;
+#[cfg(feature = "compute_dedup")]
+mod dedup;
+#[cfg(feature = "compute_dictionary")]
+mod dictionary;
+#[cfg(feature = "compute_explode")]
+mod explode;
 #[cfg(feature = "compute_filter")]
 mod filter;
+#[cfg(feature = "compute_find")]
+mod find;
 #[cfg(feature = "compute_hash")]
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
@@ -28,22 +44,45 @@ mod length;
 mod like;
 #[cfg(feature = "compute_limit")]
 mod limit;
+#[cfg(feature = "compute_list")]
+mod list;
 #[cfg(feature = "compute_lower")]
 mod lower;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
+#[cfg(all(
+    feature = "compute_cast",
+    feature = "compute_comparison",
+    feature = "compute_concatenate",
+    feature = "compute_filter",
+    feature = "compute_length",
+    feature = "compute_sort",
+    feature = "compute_substring",
+    feature = "compute_take"
+))]
+mod offsets;
 #[cfg(feature = "compute_partition")]
 mod partition;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
+#[cfg(feature = "compute_row")]
+mod row;
+#[cfg(feature = "compute_sample")]
+mod sample;
 #[cfg(feature = "compute_sort")]
 mod sort;
+#[cfg(feature = "compute_split")]
+mod split;
+#[cfg(feature = "compute_struct")]
+mod struct_;
 #[cfg(feature = "compute_substring")]
 mod substring;
 #[cfg(feature = "compute_take")]
 mod take;
 #[cfg(feature = "compute_temporal")]
 mod temporal;
+#[cfg(feature = "compute_topk")]
+mod topk;
 #[cfg(feature = "compute_upper")]
 mod upper;
 #[cfg(feature = "compute_window")]
@@ -0,0 +1,345 @@
+//! A battery of kernels run over arrays with both `i32` and `i64` offsets, asserting that
+//! arrays of `Large` types are supported wherever their `i32` counterparts are.
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::comparison::{can_eq, can_lt, eq, eq_scalar, lt};
+use arrow2::compute::concatenate::concatenate;
+use arrow2::compute::filter::filter;
+use arrow2::compute::length::{length, list_lengths};
+use arrow2::compute::sort::{can_sort, sort_to_indices, SortOptions};
+use arrow2::compute::substring::{can_substring, substring};
+use arrow2::compute::take::{can_take, take};
+use arrow2::datatypes::{DataType, Field, TimeUnit};
+use arrow2::scalar::{BinaryScalar, Utf8Scalar};
+
+fn utf8<O: Offset>() -> Utf8Array<O> {
+    Utf8Array::<O>::from([Some("hello"), None, Some("wörld"), Some(""), Some("a")])
+}
+
+fn binary<O: Offset>() -> BinaryArray<O> {
+    BinaryArray::<O>::from([
+        Some(b"hello".as_ref()),
+        None,
+        Some(b"w"),
+        Some(b""),
+        Some(b"a"),
+    ])
+}
+
+fn list<O: Offset>() -> ListArray<O> {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![Some(3)]),
+        Some(vec![]),
+        Some(vec![None]),
+    ];
+    let mut array = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+fn arrays<O: Offset>() -> Vec<Box<dyn Array>> {
+    vec![
+        Box::new(utf8::<O>()),
+        Box::new(binary::<O>()),
+        Box::new(list::<O>()),
+    ]
+}
+
+/// `data_type` with every variable-sized type replaced by its `Large` counterpart
+fn to_large(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Utf8 => DataType::LargeUtf8,
+        DataType::Binary => DataType::LargeBinary,
+        DataType::List(field) => DataType::LargeList(Box::new(Field::new(
+            field.name(),
+            to_large(field.data_type()),
+            field.is_nullable(),
+        ))),
+        other => other.clone(),
+    }
+}
+
+fn comparison<O: Offset>() {
+    for array in arrays::<O>() {
+        let array = array.as_ref();
+        if can_eq(array.data_type()) {
+            let result = eq(array, array);
+            let expected = (0..array.len())
+                .map(|i| array.is_valid(i).then(|| true))
+                .collect::<BooleanArray>();
+            assert_eq!(result, expected, "{:?}", array.data_type());
+        }
+        if can_lt(array.data_type()) {
+            let result = lt(array, array);
+            assert_eq!(result.values_iter().filter(|x| *x).count(), 0);
+        }
+    }
+
+    let result = eq_scalar(&utf8::<O>(), &Utf8Scalar::<O>::new(Some("a")));
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), None, Some(false), Some(false), Some(true)])
+    );
+    let result = eq_scalar(&binary::<O>(), &BinaryScalar::<O>::new(Some(b"a")));
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), None, Some(false), Some(false), Some(true)])
+    );
+}
+
+fn cast_roundtrip<O: Offset>() {
+    let options = CastOptions::default();
+    let to_types = [
+        DataType::Utf8,
+        DataType::LargeUtf8,
+        DataType::Binary,
+        DataType::LargeBinary,
+    ];
+
+    let array = utf8::<O>();
+    for to_type in to_types.iter() {
+        let result = cast(&array, to_type, options).unwrap();
+        assert_eq!(result.data_type(), to_type);
+        let back = cast(result.as_ref(), array.data_type(), options).unwrap();
+        assert_eq!(back.as_ref(), &array as &dyn Array);
+    }
+
+    let array = binary::<O>();
+    for to_type in [DataType::Binary, DataType::LargeBinary].iter() {
+        let result = cast(&array, to_type, options).unwrap();
+        let back = cast(result.as_ref(), array.data_type(), options).unwrap();
+        assert_eq!(back.as_ref(), &array as &dyn Array);
+    }
+
+    let array = Utf8Array::<O>::from([Some("1"), None, Some("-2")]);
+    let result = cast(&array, &DataType::Int32, options).unwrap();
+    let expected = Int32Array::from([Some(1), None, Some(-2)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    let back = cast(&expected, array.data_type(), options).unwrap();
+    assert_eq!(back.as_ref(), &array as &dyn Array);
+
+    let array = Utf8Array::<O>::from_slice(["1970-01-02"]);
+    let result = cast(&array, &DataType::Date32, options).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from_slice([1]).to(DataType::Date32) as &dyn Array
+    );
+
+    let array = list::<O>();
+    let to_type = if O::is_large() {
+        DataType::LargeList(Box::new(Field::new("item", DataType::Int64, true)))
+    } else {
+        DataType::List(Box::new(Field::new("item", DataType::Int64, true)))
+    };
+    let result = cast(&array, &to_type, options).unwrap();
+    assert_eq!(result.data_type(), &to_type);
+    let back = cast(result.as_ref(), array.data_type(), options).unwrap();
+    assert_eq!(back.as_ref(), &array as &dyn Array);
+}
+
+fn filter_take_concatenate<O: Offset>() {
+    let mask = BooleanArray::from_slice([true, false, true, true, false]);
+    let indices = Int32Array::from([Some(4), None, Some(0), Some(2)]);
+    for array in arrays::<O>() {
+        let array = array.as_ref();
+
+        let result = filter(array, &mask).unwrap();
+        assert_eq!(result.data_type(), array.data_type());
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result.as_ref(),
+            concatenate(&[array.slice(0, 1).as_ref(), array.slice(2, 2).as_ref()])
+                .unwrap()
+                .as_ref()
+        );
+
+        assert!(can_take(array.data_type()));
+        let result = take(array, &indices).unwrap();
+        assert_eq!(result.data_type(), array.data_type());
+        assert_eq!(result.len(), 4);
+        assert!(result.is_null(1));
+        assert_eq!(result.slice(0, 1).as_ref(), array.slice(4, 1).as_ref());
+
+        let result = concatenate(&[array, array]).unwrap();
+        assert_eq!(result.data_type(), array.data_type());
+        assert_eq!(result.slice(5, 5).as_ref(), array);
+    }
+}
+
+fn lengths<O: Offset>(lengths: &[Option<usize>]) -> PrimitiveArray<O> {
+    let array = lengths
+        .iter()
+        .map(|x| x.map(|x| O::from_usize(x).unwrap()))
+        .collect::<PrimitiveArray<O>>();
+    array.to(if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    })
+}
+
+fn length_substring<O: Offset>() {
+    let expected = lengths::<O>(&[Some(5), None, Some(6), Some(0), Some(1)]);
+    let result = length(&utf8::<O>()).unwrap();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let expected = lengths::<O>(&[Some(5), None, Some(1), Some(0), Some(1)]);
+    let result = length(&binary::<O>()).unwrap();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let expected = lengths::<O>(&[Some(2), None, Some(1), Some(0), Some(1)]);
+    let result = list_lengths(&list::<O>()).unwrap();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert!(can_substring(utf8::<O>().data_type()));
+    let result = substring(&utf8::<O>(), 1, &Some(2)).unwrap();
+    let expected = Utf8Array::<O>::from([Some("el"), None, Some("ör"), Some(""), Some("")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert!(can_substring(binary::<O>().data_type()));
+    let result = substring(&binary::<O>(), 1, &Some(2)).unwrap();
+    let expected =
+        BinaryArray::<O>::from([Some(b"el".as_ref()), None, Some(b""), Some(b""), Some(b"")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+fn sort<O: Offset>() {
+    let options = SortOptions::default();
+    let expected = UInt32Array::from_slice([1, 3, 4, 0, 2]);
+    for array in [
+        Box::new(utf8::<O>()) as Box<dyn Array>,
+        Box::new(binary::<O>()),
+    ] {
+        assert!(can_sort(array.data_type()));
+        let result = sort_to_indices::<u32>(array.as_ref(), &options, None).unwrap();
+        assert_eq!(result, expected, "{:?}", array.data_type());
+    }
+}
+
+#[cfg(all(feature = "io_csv_write", feature = "io_json"))]
+fn write<O: Offset>() {
+    use arrow2::datatypes::Schema;
+    use arrow2::io::{csv, json};
+    use arrow2::record_batch::RecordBatch;
+
+    let columns = vec![
+        Arc::new(utf8::<O>()) as Arc<dyn Array>,
+        Arc::new(binary::<O>()) as Arc<dyn Array>,
+    ];
+    let schema = Schema::new(vec![
+        Field::new("a", columns[0].data_type().clone(), true),
+        Field::new("b", columns[1].data_type().clone(), true),
+    ]);
+    let batch = RecordBatch::try_new(Arc::new(schema), columns).unwrap();
+
+    let mut writer = csv::write::WriterBuilder::new().from_writer(vec![]);
+    csv::write::write_batch(
+        &mut writer,
+        &batch,
+        &csv::write::SerializeOptions::default(),
+    )
+    .unwrap();
+    let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert_eq!(csv, "hello,hello\n,\nwörld,w\n,\na,a\n");
+
+    let columns = vec![
+        Arc::new(utf8::<O>()) as Arc<dyn Array>,
+        Arc::new(list::<O>()) as Arc<dyn Array>,
+    ];
+    let mut buffer = vec![];
+    json::write::serialize(
+        &["a", "c"],
+        &columns,
+        json::write::LineDelimited::default(),
+        &mut buffer,
+    );
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        r#"{"a":"hello","c":[1,2]}
+{"a":null,"c":null}
+{"a":"wörld","c":[3]}
+{"a":"","c":[]}
+{"a":"a","c":[null]}
+"#
+    );
+}
+
+fn battery<O: Offset>() {
+    comparison::<O>();
+    cast_roundtrip::<O>();
+    filter_take_concatenate::<O>();
+    length_substring::<O>();
+    sort::<O>();
+    #[cfg(all(feature = "io_csv_write", feature = "io_json"))]
+    write::<O>();
+}
+
+#[test]
+fn battery_i32() {
+    battery::<i32>()
+}
+
+#[test]
+fn battery_i64() {
+    battery::<i64>()
+}
+
+#[test]
+fn can_cast_large_types() {
+    let types = [
+        DataType::Boolean,
+        DataType::Int8,
+        DataType::Int32,
+        DataType::UInt64,
+        DataType::Float32,
+        DataType::Float64,
+        DataType::Date32,
+        DataType::Date64,
+        DataType::Time32(TimeUnit::Second),
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".to_string())),
+        DataType::Decimal(10, 2),
+        DataType::Utf8,
+        DataType::Binary,
+        DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+        DataType::List(Box::new(Field::new("item", DataType::Utf8, true))),
+    ];
+    for from_type in types.iter() {
+        for to_type in types.iter() {
+            let expected = can_cast_types(from_type, to_type);
+            let large_from = to_large(from_type);
+            let large_to = to_large(to_type);
+            assert_eq!(
+                can_cast_types(&large_from, to_type),
+                expected,
+                "{:?} -> {:?}",
+                large_from,
+                to_type
+            );
+            assert_eq!(
+                can_cast_types(from_type, &large_to),
+                expected,
+                "{:?} -> {:?}",
+                from_type,
+                large_to
+            );
+        }
+    }
+    for data_type in types.iter() {
+        let large = to_large(data_type);
+        assert_eq!(can_eq(&large), can_eq(data_type), "{:?}", large);
+        assert_eq!(can_lt(&large), can_lt(data_type), "{:?}", large);
+        assert_eq!(can_take(&large), can_take(data_type), "{:?}", large);
+        assert_eq!(can_sort(&large), can_sort(data_type), "{:?}", large);
+        assert_eq!(
+            can_substring(&large),
+            can_substring(data_type),
+            "{:?}",
+            large
+        );
+    }
+}
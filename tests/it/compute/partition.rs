@@ -41,6 +41,7 @@ fn lexicographical_partition_single_column() -> Result<()> {
         options: Some(SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         }),
     }];
     {
@@ -61,6 +62,7 @@ fn lexicographical_partition_all_equal_values() -> Result<()> {
         options: Some(SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         }),
     }];
 
@@ -81,6 +83,7 @@ fn lexicographical_partition_all_null_values() -> Result<()> {
             options: Some(SortOptions {
                 descending: false,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
         SortColumn {
@@ -88,6 +91,7 @@ fn lexicographical_partition_all_null_values() -> Result<()> {
             options: Some(SortOptions {
                 descending: false,
                 nulls_first: false,
+                nans_greatest: true,
             }),
         },
     ];
@@ -108,6 +112,7 @@ fn lexicographical_partition_unique_column_1() -> Result<()> {
             options: Some(SortOptions {
                 descending: false,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
         SortColumn {
@@ -115,6 +120,7 @@ fn lexicographical_partition_unique_column_1() -> Result<()> {
             options: Some(SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
     ];
@@ -139,6 +145,7 @@ fn lexicographical_partition_unique_column_2() -> Result<()> {
             options: Some(SortOptions {
                 descending: false,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
         SortColumn {
@@ -146,6 +153,7 @@ fn lexicographical_partition_unique_column_2() -> Result<()> {
             options: Some(SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
     ];
@@ -170,6 +178,7 @@ fn lexicographical_partition_non_unique_column_1() -> Result<()> {
             options: Some(SortOptions {
                 descending: false,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
         SortColumn {
@@ -177,6 +186,7 @@ fn lexicographical_partition_non_unique_column_1() -> Result<()> {
             options: Some(SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
     ];
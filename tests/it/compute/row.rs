@@ -0,0 +1,314 @@
+use std::sync::Arc;
+
+use proptest::prelude::*;
+
+use arrow2::array::*;
+use arrow2::compute::row::*;
+use arrow2::compute::sort::{lexsort_to_indices, SortColumn, SortOptions};
+use arrow2::compute::take::take;
+use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::error::Result;
+
+fn options(descending: bool, nulls_first: bool) -> SortOptions {
+    SortOptions {
+        descending,
+        nulls_first,
+        nans_greatest: true,
+    }
+}
+
+fn converter(columns: &[Arc<dyn Array>], options: &[SortOptions]) -> Result<RowConverter> {
+    RowConverter::try_new(
+        columns
+            .iter()
+            .zip(options.iter())
+            .map(|(column, options)| SortField::new(column.data_type().clone(), *options))
+            .collect(),
+    )
+}
+
+/// Asserts that ordering the rows by their bytes is the same as sorting the columns
+fn check_order(columns: &[Arc<dyn Array>], options: &[SortOptions]) -> Result<()> {
+    let rows = converter(columns, options)?.convert_columns(columns)?;
+    assert_eq!(rows.len(), columns[0].len());
+
+    let sort_columns = columns
+        .iter()
+        .zip(options.iter())
+        .map(|(column, options)| SortColumn {
+            values: column.as_ref(),
+            options: Some(*options),
+        })
+        .collect::<Vec<_>>();
+    let expected = lexsort_to_indices::<u32>(&sort_columns, None)?;
+
+    let mut result = (0..rows.len() as u32).collect::<Vec<_>>();
+    result.sort_by(|a, b| rows.row(*a as usize).cmp(rows.row(*b as usize)));
+    let result = UInt32Array::from_vec(result);
+
+    // the sort is unstable, so compare the sorted columns instead of the indices
+    for column in columns {
+        assert_eq!(
+            take(column.as_ref(), &result)?.as_ref(),
+            take(column.as_ref(), &expected)?.as_ref()
+        );
+    }
+    Ok(())
+}
+
+fn check_roundtrip(columns: &[Arc<dyn Array>], options: &[SortOptions]) -> Result<()> {
+    let converter = converter(columns, options)?;
+    let rows = converter.convert_columns(columns)?;
+    let result = converter.convert_back(&rows)?;
+    for (result, column) in result.iter().zip(columns.iter()) {
+        assert_eq!(result.as_ref(), column.as_ref());
+    }
+    Ok(())
+}
+
+fn check(columns: &[Arc<dyn Array>]) -> Result<()> {
+    for descending in [false, true] {
+        for nulls_first in [false, true] {
+            let options = vec![options(descending, nulls_first); columns.len()];
+            check_order(columns, &options)?;
+            check_roundtrip(columns, &options)?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn primitives() -> Result<()> {
+    check(&[Arc::new(Int8Array::from(&[
+        Some(-1),
+        Some(1),
+        None,
+        Some(i8::MIN),
+        Some(0),
+    ]))])?;
+    check(&[Arc::new(UInt16Array::from(&[
+        Some(256),
+        Some(1),
+        None,
+        Some(u16::MAX),
+    ]))])?;
+    check(&[Arc::new(
+        Int64Array::from(&[Some(i64::MAX), Some(-2), None, Some(i64::MIN)])
+            .to(DataType::Timestamp(TimeUnit::Second, None)),
+    )])?;
+    check(&[Arc::new(
+        Int32Array::from(&[Some(1), None, Some(-30)]).to(DataType::Date32),
+    )])?;
+    check(&[Arc::new(
+        Int128Array::from(&[
+            Some(-12345),
+            Some(1),
+            None,
+            Some(i128::MAX),
+            Some(i128::MIN),
+        ])
+        .to(DataType::Decimal(38, 2)),
+    )])
+}
+
+#[test]
+fn floats() -> Result<()> {
+    let array = Float64Array::from(&[
+        Some(f64::NAN),
+        Some(-1.5),
+        None,
+        Some(f64::INFINITY),
+        Some(-f64::NAN),
+        Some(0.0),
+        Some(f64::NEG_INFINITY),
+        Some(2.0),
+    ]);
+    let columns = [Arc::new(array) as Arc<dyn Array>];
+    let sorted = |options| -> Result<Vec<usize>> {
+        let rows = converter(&columns, &[options])?.convert_columns(&columns)?;
+        let mut result = (0..rows.len()).collect::<Vec<_>>();
+        result.sort_by(|a, b| rows.row(*a).cmp(rows.row(*b)).then(a.cmp(b)));
+        Ok(result)
+    };
+    assert_eq!(sorted(options(false, true))?, vec![2, 6, 1, 5, 7, 3, 0, 4]);
+    assert_eq!(sorted(options(true, false))?, vec![0, 4, 3, 7, 5, 1, 6, 2]);
+
+    // every NaN is encoded as the same (largest or smallest) value
+    for nans_greatest in [false, true] {
+        let options = SortOptions {
+            nans_greatest,
+            ..Default::default()
+        };
+        let rows = converter(&columns, &[options])?.convert_columns(&columns)?;
+        assert_eq!(rows.row(0), rows.row(4));
+        let nan_is_greatest = (1..rows.len())
+            .filter(|x| *x != 2 && *x != 4)
+            .all(|x| rows.row(0) > rows.row(x));
+        assert_eq!(nan_is_greatest, nans_greatest);
+    }
+
+    check(&[Arc::new(Float32Array::from(&[
+        Some(-0.5),
+        Some(1.0),
+        None,
+        Some(f32::MIN),
+        Some(f32::MAX),
+    ]))])
+}
+
+#[test]
+fn boolean() -> Result<()> {
+    check(&[Arc::new(BooleanArray::from(&[
+        Some(true),
+        None,
+        Some(false),
+    ]))])
+}
+
+#[test]
+fn variable() -> Result<()> {
+    // empty values, prefixes and zeros
+    let values = [
+        Some("a"),
+        Some(""),
+        None,
+        Some("a\0"),
+        Some("ab"),
+        Some("\0"),
+        Some("a\0b"),
+        Some("aa"),
+    ];
+    check(&[Arc::new(Utf8Array::<i32>::from(&values))])?;
+    check(&[Arc::new(Utf8Array::<i64>::from(&values))])?;
+    let values = [
+        Some(&[0u8, 255][..]),
+        Some(&[]),
+        None,
+        Some(&[0, 0]),
+        Some(&[255]),
+    ];
+    check(&[Arc::new(BinaryArray::<i32>::from(&values))])?;
+    check(&[Arc::new(BinaryArray::<i64>::from(&values))])
+}
+
+#[test]
+fn multiple_columns() -> Result<()> {
+    let a = Int32Array::from(&[Some(1), Some(1), None, Some(0), Some(1), None]);
+    let b = Utf8Array::<i32>::from(&[Some("b"), Some("a"), Some("c"), Some(""), None, None]);
+    let columns = [Arc::new(a) as Arc<dyn Array>, Arc::new(b) as Arc<dyn Array>];
+    check(&columns)?;
+    check_order(&columns, &[options(true, false), options(false, true)])
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(vec![Some("b"), None, Some("a"), Some("b"), Some("")])?;
+    let array: DictionaryArray<i32> = array.into();
+    let columns = [Arc::new(array) as Arc<dyn Array>];
+
+    let converter = converter(&columns, &[SortOptions::default()])?;
+    let rows = converter.convert_columns(&columns)?;
+    assert!(rows.row(1) < rows.row(4));
+    assert!(rows.row(4) < rows.row(2));
+    assert!(rows.row(2) < rows.row(0));
+    assert_eq!(rows.row(0), rows.row(3));
+
+    // dictionaries are converted back to their values
+    let result = converter.convert_back(&rows)?;
+    let expected = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("b"), Some("")]);
+    assert_eq!(result[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let a = Int32Array::from(&[Some(3), None, Some(1), Some(2)]).slice(1, 3);
+    let b = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None, Some("c")]).slice(1, 3);
+    check(&[Arc::new(a), Arc::new(b)])
+}
+
+#[test]
+fn empty() -> Result<()> {
+    let columns = [Arc::new(Int32Array::from_slice(&[])) as Arc<dyn Array>];
+    let converter = converter(&columns, &[SortOptions::default()])?;
+    let rows = converter.convert_columns(&columns)?;
+    assert!(rows.is_empty());
+    assert_eq!(converter.convert_back(&rows)?[0].len(), 0);
+    Ok(())
+}
+
+#[test]
+fn errors() -> Result<()> {
+    let field = |data_type| SortField::new(data_type, SortOptions::default());
+    assert!(RowConverter::try_new(vec![field(DataType::Float16)]).is_err());
+    assert!(RowConverter::try_new(vec![field(DataType::Null)]).is_err());
+    assert!(RowConverter::try_new(vec![field(DataType::Interval(
+        arrow2::datatypes::IntervalUnit::DayTime
+    ))])
+    .is_err());
+
+    let converter = RowConverter::try_new(vec![field(DataType::Int32), field(DataType::Utf8)])?;
+    let a = Arc::new(Int32Array::from_slice(&[1, 2])) as Arc<dyn Array>;
+    let b = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"])) as Arc<dyn Array>;
+    // wrong number of columns, data types and lengths
+    assert!(converter.convert_columns(std::slice::from_ref(&a)).is_err());
+    assert!(converter.convert_columns(&[b.clone(), a.clone()]).is_err());
+    assert!(converter
+        .convert_columns(&[a.clone(), b.slice(0, 1).into()])
+        .is_err());
+
+    // rows of a different converter
+    let rows = converter.convert_columns(&[a, b])?;
+    let other = RowConverter::try_new(vec![field(DataType::Int8)])?;
+    assert!(other.convert_back(&rows).is_err());
+    Ok(())
+}
+
+type Row = (Option<i32>, Option<String>, Option<f64>, Option<bool>);
+
+fn row() -> impl Strategy<Value = Row> {
+    (
+        proptest::option::of(-3i32..3),
+        proptest::option::of("[ab\0]{0,3}"),
+        // -0.0 and 0.0 are equal when sorted, but not as bytes
+        proptest::option::of((-8i32..8).prop_map(|x| x as f64 / 4.0)),
+        proptest::option::of(any::<bool>()),
+    )
+}
+
+fn columns(rows: &[Row]) -> Vec<Arc<dyn Array>> {
+    vec![
+        Arc::new(Int32Array::from(
+            rows.iter().map(|x| x.0).collect::<Vec<_>>(),
+        )),
+        Arc::new(Utf8Array::<i32>::from(
+            rows.iter().map(|x| x.1.as_ref()).collect::<Vec<_>>(),
+        )),
+        Arc::new(Float64Array::from(
+            rows.iter().map(|x| x.2).collect::<Vec<_>>(),
+        )),
+        Arc::new(BooleanArray::from(
+            rows.iter().map(|x| x.3).collect::<Vec<_>>(),
+        )),
+    ]
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn order_and_roundtrip(
+        rows in proptest::collection::vec(row(), 0..50),
+        options in proptest::collection::vec((any::<bool>(), any::<bool>()), 4),
+    ) {
+        let columns = columns(&rows);
+        let options = options
+            .into_iter()
+            .map(|(descending, nulls_first)| self::options(descending, nulls_first))
+            .collect::<Vec<_>>();
+        if !rows.is_empty() {
+            check_order(&columns, &options).unwrap();
+        }
+        check_roundtrip(&columns, &options).unwrap();
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::columns::Columns;
+use arrow2::compute::sample::{sample, sample_indices};
+
+fn new_columns(length: i32) -> Columns<Arc<dyn Array>> {
+    let a = Int32Array::from_values(0..length);
+    let b = Utf8Array::<i32>::from_iter_values((0..length).map(|x| x.to_string()));
+    Columns::new(vec![Arc::new(a) as Arc<dyn Array>, Arc::new(b)])
+}
+
+fn values(columns: &Columns<Arc<dyn Array>>) -> Vec<i32> {
+    let a = columns[0].as_any().downcast_ref::<Int32Array>().unwrap();
+    let b = columns[1]
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    // rows are taken consistently from every column
+    a.values()
+        .iter()
+        .zip(b.values_iter())
+        .for_each(|(a, b)| assert_eq!(a.to_string(), b));
+    a.values().to_vec()
+}
+
+#[test]
+fn reproducible() {
+    let columns = new_columns(1000);
+    for with_replacement in [false, true] {
+        let a = sample(&columns, 10, Some(42), with_replacement).unwrap();
+        let b = sample(&columns, 10, Some(42), with_replacement).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 10);
+
+        let c = sample(&columns, 10, Some(43), with_replacement).unwrap();
+        assert_ne!(values(&a), values(&c));
+    }
+}
+
+#[test]
+fn without_replacement() {
+    let columns = new_columns(100);
+    for seed in 0..20 {
+        let result = values(&sample(&columns, 30, Some(seed), false).unwrap());
+        assert_eq!(result.len(), 30);
+        assert_eq!(result.iter().collect::<HashSet<_>>().len(), 30);
+        assert!(result.iter().all(|x| (0..100).contains(x)));
+    }
+
+    // sampling every row is a permutation
+    let mut result = values(&sample(&columns, 100, Some(0), false).unwrap());
+    result.sort_unstable();
+    assert_eq!(result, (0..100).collect::<Vec<_>>());
+
+    assert!(sample(&columns, 101, Some(0), false).is_err());
+}
+
+#[test]
+fn with_replacement() {
+    let columns = new_columns(3);
+    let result = values(&sample(&columns, 100, Some(0), true).unwrap());
+    assert_eq!(result.len(), 100);
+    assert!(result.iter().all(|x| (0..3).contains(x)));
+    // with 100 draws out of 3 rows, every row is drawn, some more than once
+    assert_eq!(result.iter().collect::<HashSet<_>>().len(), 3);
+
+    assert!(sample(&new_columns(0), 1, Some(0), true).is_err());
+    assert_eq!(sample(&new_columns(0), 0, Some(0), true).unwrap().len(), 0);
+}
+
+#[test]
+fn indices_cover_rows() {
+    // every row is eventually drawn when sampling few rows out of many
+    let mut seen = HashSet::new();
+    for seed in 0..200 {
+        let indices = sample_indices(10, 2, Some(seed), false).unwrap();
+        assert_ne!(indices.value(0), indices.value(1));
+        seen.extend(indices.values().iter().copied());
+    }
+    assert_eq!(seen.len(), 10);
+}
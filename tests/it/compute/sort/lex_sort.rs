@@ -51,6 +51,7 @@ fn test_lex_sort_mixed_types2() {
             options: Some(SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
         SortColumn {
@@ -58,6 +59,7 @@ fn test_lex_sort_mixed_types2() {
             options: Some(SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
     ];
@@ -86,6 +88,7 @@ fn test_lex_sort_mixed_types2() {
             options: Some(SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
         SortColumn {
@@ -98,6 +101,7 @@ fn test_lex_sort_mixed_types2() {
             options: Some(SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
     ];
@@ -129,6 +133,7 @@ fn test_lex_sort_mixed_types2() {
             options: Some(SortOptions {
                 descending: true,
                 nulls_first: false,
+                nans_greatest: true,
             }),
         },
         SortColumn {
@@ -141,6 +146,7 @@ fn test_lex_sort_mixed_types2() {
             options: Some(SortOptions {
                 descending: true,
                 nulls_first: false,
+                nans_greatest: true,
             }),
         },
     ];
@@ -173,6 +179,7 @@ fn test_lex_sort_mixed_types2() {
             options: Some(SortOptions {
                 descending: false,
                 nulls_first: false,
+                nans_greatest: true,
             }),
         },
         SortColumn {
@@ -186,6 +193,7 @@ fn test_lex_sort_mixed_types2() {
             options: Some(SortOptions {
                 descending: true,
                 nulls_first: true,
+                nans_greatest: true,
             }),
         },
     ];
@@ -208,3 +216,32 @@ fn test_lex_sort_mixed_types2() {
     test_lex_sort_arrays(input, expected);
 }
 */
+
+#[test]
+fn test_lex_sort_nans() {
+    let c1 = Int64Array::from_slice(&[1, 1, 1, 0]);
+    let c2 = Float32Array::from_slice(&[1.0, f32::NAN, -1.0, f32::NAN]);
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+        nans_greatest: false,
+    };
+    let input = vec![
+        SortColumn {
+            values: &c1,
+            options: None,
+        },
+        SortColumn {
+            values: &c2,
+            options: Some(options),
+        },
+    ];
+
+    let c1 = Int64Array::from_slice(&[0, 1, 1, 1]);
+    let c2 = Float32Array::from_slice(&[f32::NAN, f32::NAN, -1.0, 1.0]);
+    let sorted = lexsort::<i32>(&input, None).unwrap();
+    assert_eq!(sorted[0].as_ref(), &c1 as &dyn Array);
+    // NaN != NaN, so compare the floats via their debug representation
+    assert_eq!(format!("{:?}", sorted[1]), format!("{:?}", c2));
+}
@@ -1,4 +1,5 @@
 mod lex_sort;
+mod scalar;
 
 use arrow2::array::*;
 use arrow2::compute::sort::*;
@@ -106,6 +107,90 @@ fn test_lex_sort_arrays(input: Vec<SortColumn>, expected_output: Vec<ArrayRef>)
 }
 */
 
+#[test]
+fn sort_options_new() {
+    assert_eq!(SortOptions::new(false, true), SortOptions::default());
+    assert!(
+        !SortOptions::default()
+            .with_nans_greatest(false)
+            .nans_greatest
+    );
+}
+
+#[test]
+fn float_nans_and_zeros() {
+    let data = &[
+        Some(f64::NAN),
+        Some(0.0),
+        None,
+        Some(-f64::NAN),
+        Some(-0.0),
+        Some(-1.0),
+    ];
+    let sorted = |descending, nans_greatest| {
+        let options = SortOptions::new(descending, false).with_nans_greatest(nans_greatest);
+        let array = Float64Array::from(data);
+        let indices = sort_to_indices::<i32>(&array, &options, None).unwrap();
+        let values = sort(&array, &options, None).unwrap();
+        let values = values.as_any().downcast_ref::<Float64Array>().unwrap();
+        // compare bits so that NaNs and signed zeros are distinguished
+        let bits = values
+            .iter()
+            .map(|x| x.map(|x| if x.is_nan() { u64::MAX } else { x.to_bits() }))
+            .collect::<Vec<_>>();
+        (indices.values().to_vec(), bits)
+    };
+    let bits = |values: &[Option<f64>]| {
+        values
+            .iter()
+            .map(|x| x.map(|x| if x.is_nan() { u64::MAX } else { x.to_bits() }))
+            .collect::<Vec<_>>()
+    };
+
+    let (indices, values) = sorted(false, true);
+    assert_eq!(&indices[..3], &[5, 4, 1]);
+    assert_eq!(indices[5], 2);
+    assert_eq!(
+        values,
+        bits(&[
+            Some(-1.0),
+            Some(-0.0),
+            Some(0.0),
+            Some(f64::NAN),
+            Some(f64::NAN),
+            None
+        ])
+    );
+
+    let (indices, values) = sorted(false, false);
+    assert_eq!(&indices[2..], &[5, 4, 1, 2]);
+    assert_eq!(
+        values,
+        bits(&[
+            Some(f64::NAN),
+            Some(f64::NAN),
+            Some(-1.0),
+            Some(-0.0),
+            Some(0.0),
+            None
+        ])
+    );
+
+    let (indices, values) = sorted(true, true);
+    assert_eq!(&indices[2..], &[1, 4, 5, 2]);
+    assert_eq!(
+        values,
+        bits(&[
+            Some(f64::NAN),
+            Some(f64::NAN),
+            Some(0.0),
+            Some(-0.0),
+            Some(-1.0),
+            None
+        ])
+    );
+}
+
 #[test]
 fn boolean() {
     // boolean
@@ -114,6 +199,7 @@ fn boolean() {
         SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[0, 5, 1, 4, 2, 3],
     );
@@ -124,6 +210,7 @@ fn boolean() {
         SortOptions {
             descending: true,
             nulls_first: false,
+            nans_greatest: true,
         },
         &[2, 3, 1, 4, 5, 0],
     );
@@ -134,6 +221,7 @@ fn boolean() {
         SortOptions {
             descending: true,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[5, 0, 2, 3, 1, 4],
     );
@@ -148,6 +236,7 @@ fn test_nans() {
         SortOptions {
             descending: true,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[None, None, Some(f64::NAN), Some(2.0), Some(0.0), Some(-1.0)],
     );
@@ -157,6 +246,7 @@ fn test_nans() {
         SortOptions {
             descending: true,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[Some(f64::NAN), Some(f64::NAN), Some(f64::NAN), Some(1.0)],
     );
@@ -167,6 +257,7 @@ fn test_nans() {
         SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[None, None, Some(-1.0), Some(0.0), Some(2.0), Some(f64::NAN)],
     );
@@ -177,6 +268,7 @@ fn test_nans() {
         SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[Some(1.0), Some(f64::NAN), Some(f64::NAN), Some(f64::NAN)],
     );
@@ -196,6 +288,7 @@ fn to_indices_strings() {
         SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         },
         // &[3, 0, 5, 1, 4, 2] is also valid
         &[0, 3, 5, 1, 4, 2],
@@ -213,6 +306,7 @@ fn to_indices_strings() {
         SortOptions {
             descending: true,
             nulls_first: false,
+            nans_greatest: true,
         },
         // &[2, 4, 1, 5, 3, 0] is also valid
         &[2, 4, 1, 5, 0, 3],
@@ -230,6 +324,7 @@ fn to_indices_strings() {
         SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         },
         // &[3, 0, 5, 1, 4, 2] is also valid
         &[0, 3, 5, 1, 4, 2],
@@ -247,6 +342,7 @@ fn to_indices_strings() {
         SortOptions {
             descending: true,
             nulls_first: true,
+            nans_greatest: true,
         },
         // &[3, 0, 2, 4, 1, 5] is also valid
         &[0, 3, 2, 4, 1, 5],
@@ -267,6 +363,7 @@ fn strings() {
         SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[
             None,
@@ -290,6 +387,7 @@ fn strings() {
         SortOptions {
             descending: true,
             nulls_first: false,
+            nans_greatest: true,
         },
         &[
             Some("sad"),
@@ -313,6 +411,7 @@ fn strings() {
         SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[
             None,
@@ -336,6 +435,7 @@ fn strings() {
         SortOptions {
             descending: true,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[
             None,
@@ -362,6 +462,7 @@ fn string_dicts() {
         SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[
             None,
@@ -385,6 +486,7 @@ fn string_dicts() {
         SortOptions {
             descending: true,
             nulls_first: false,
+            nans_greatest: true,
         },
         &[
             Some("sad"),
@@ -408,6 +510,7 @@ fn string_dicts() {
         SortOptions {
             descending: false,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[
             None,
@@ -431,6 +534,7 @@ fn string_dicts() {
         SortOptions {
             descending: true,
             nulls_first: true,
+            nans_greatest: true,
         },
         &[
             None,
@@ -443,6 +547,113 @@ fn string_dicts() {
     );
 }
 
+// checks the sorted indices and values for every limit, given the expected indices of every
+// combination of `descending` and `nulls_first`
+fn check_orders(array: &dyn Array, expected: [(bool, bool, &[i32]); 4]) {
+    for (descending, nulls_first, expected) in expected.iter() {
+        let options = SortOptions {
+            descending: *descending,
+            nulls_first: *nulls_first,
+            nans_greatest: true,
+        };
+        for limit in 0..=array.len() + 1 {
+            let length = limit.min(array.len());
+            let expected = Int32Array::from_slice(&expected[..length]);
+            let indices = sort_to_indices::<i32>(array, &options, Some(limit)).unwrap();
+            assert_eq!(indices, expected, "{:?} limit {}", options, limit);
+
+            let sorted = sort(array, &options, Some(limit)).unwrap();
+            let expected = arrow2::compute::take::take(array, &expected).unwrap();
+            assert_eq!(sorted, expected, "{:?} limit {}", options, limit);
+        }
+    }
+}
+
+#[test]
+fn orders_primitive() {
+    let array = Int32Array::from(&[Some(2), None, Some(5), Some(1), None, Some(4)]);
+    check_orders(
+        &array,
+        [
+            (false, true, &[1, 4, 3, 0, 5, 2]),
+            (false, false, &[3, 0, 5, 2, 1, 4]),
+            (true, true, &[1, 4, 2, 5, 0, 3]),
+            (true, false, &[2, 5, 0, 3, 1, 4]),
+        ],
+    );
+}
+
+#[test]
+fn orders_decimal() {
+    let array = PrimitiveArray::<i128>::from(&[Some(2), None, Some(5), Some(-1), None, Some(4)])
+        .to(DataType::Decimal(5, 2));
+    check_orders(
+        &array,
+        [
+            (false, true, &[1, 4, 3, 0, 5, 2]),
+            (false, false, &[3, 0, 5, 2, 1, 4]),
+            (true, true, &[1, 4, 2, 5, 0, 3]),
+            (true, false, &[2, 5, 0, 3, 1, 4]),
+        ],
+    );
+}
+
+#[test]
+fn orders_boolean() {
+    let array = BooleanArray::from(&[Some(true), None, Some(false), Some(true), None, Some(false)]);
+    check_orders(
+        &array,
+        [
+            (false, true, &[1, 4, 2, 5, 0, 3]),
+            (false, false, &[2, 5, 0, 3, 1, 4]),
+            (true, true, &[4, 1, 0, 3, 2, 5]),
+            (true, false, &[0, 3, 2, 5, 4, 1]),
+        ],
+    );
+
+    // sliced
+    let array = BooleanArray::from(&[None, Some(true), Some(false), Some(false)]).slice(1, 3);
+    check_orders(
+        &array,
+        [
+            (false, true, &[1, 2, 0]),
+            (false, false, &[1, 2, 0]),
+            (true, true, &[0, 1, 2]),
+            (true, false, &[0, 1, 2]),
+        ],
+    );
+}
+
+#[test]
+fn orders_dictionary() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![Some("b"), None, Some("e"), Some("a"), None, Some("d")])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+    check_orders(
+        &array,
+        [
+            (false, true, &[1, 4, 3, 0, 5, 2]),
+            (false, false, &[3, 0, 5, 2, 1, 4]),
+            (true, true, &[1, 4, 2, 5, 0, 3]),
+            (true, false, &[2, 5, 0, 3, 1, 4]),
+        ],
+    );
+
+    // keys pointing to null values are null; values of any sortable type are supported
+    let values = Int64Array::from(&[Some(10), None, Some(-1)]);
+    let keys = Int32Array::from(&[Some(0), Some(1), None, Some(2)]);
+    let array = DictionaryArray::<i32>::from_data(keys, std::sync::Arc::new(values));
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+        nans_greatest: true,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice(&[3, 0, 1, 2]));
+}
+
 /*
 #[test]
 fn list() {
@@ -456,6 +667,7 @@ fn list() {
         Some(SortOptions {
             descending: false,
             nulls_first: false,
+            nans_greatest: true,
         }),
         vec![
             Some(vec![Some(1)]),
@@ -477,6 +689,7 @@ fn list() {
         Some(SortOptions {
             descending: false,
             nulls_first: false,
+            nans_greatest: true,
         }),
         vec![
             Some(vec![Some(1), Some(0)]),
@@ -499,6 +712,7 @@ fn list() {
         Some(SortOptions {
             descending: false,
             nulls_first: false,
+            nans_greatest: true,
         }),
         vec![
             Some(vec![Some(2), Some(3), Some(4)]),
@@ -595,6 +809,7 @@ fn consistency() {
         let options = SortOptions {
             descending: true,
             nulls_first: true,
+            nans_greatest: true,
         };
         if can_sort(&d1) {
             assert!(sort(array.as_ref(), &options, None).is_ok());
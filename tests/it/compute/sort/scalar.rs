@@ -0,0 +1,156 @@
+use std::cmp::Ordering;
+
+use arrow2::array::*;
+use arrow2::compute::sort::*;
+use arrow2::datatypes::*;
+use arrow2::scalar::*;
+use arrow2::types::{days_ms, f16};
+
+fn all_options() -> Vec<SortOptions> {
+    let mut options = vec![];
+    for descending in [false, true] {
+        for nulls_first in [false, true] {
+            for nans_greatest in [false, true] {
+                options.push(SortOptions {
+                    descending,
+                    nulls_first,
+                    nans_greatest,
+                })
+            }
+        }
+    }
+    options
+}
+
+/// checks that comparing the two scalars of `array` agrees with sorting `array`
+fn check(array: &dyn Array) {
+    assert_eq!(array.len(), 2);
+    let lhs = new_scalar(array, 0);
+    let rhs = new_scalar(array, 1);
+    for options in all_options() {
+        let ordering = compare_scalars(lhs.as_ref(), rhs.as_ref(), &options).unwrap();
+        let indices = sort_to_indices::<i32>(array, &options, None).unwrap();
+        match ordering {
+            Ordering::Less => assert_eq!(indices.values().as_slice(), &[0, 1], "{:?}", options),
+            Ordering::Greater => assert_eq!(indices.values().as_slice(), &[1, 0], "{:?}", options),
+            Ordering::Equal => {
+                // both scalars are sorted the same in the other direction
+                let reverse = compare_scalars(rhs.as_ref(), lhs.as_ref(), &options).unwrap();
+                assert_eq!(reverse, Ordering::Equal)
+            }
+        }
+    }
+}
+
+#[test]
+fn primitive() {
+    check(&Int32Array::from(&[Some(2), Some(1)]));
+    check(&Int32Array::from(&[Some(1), Some(2)]));
+    check(&Int32Array::from(&[Some(1), None]));
+    check(&Int32Array::from(&[None, Some(1)]));
+    check(&Int32Array::from(&[None, None]));
+    check(&UInt64Array::from(&[Some(u64::MAX), Some(0)]));
+    check(&Int64Array::from(&[Some(-1), Some(1)]).to(DataType::Timestamp(TimeUnit::Second, None)));
+    check(&Int32Array::from(&[Some(3), Some(-3)]).to(DataType::Date32));
+}
+
+#[test]
+fn float() {
+    check(&Float64Array::from(&[Some(1.0), Some(f64::NAN)]));
+    check(&Float64Array::from(&[Some(f64::NAN), Some(-1.0)]));
+    check(&Float64Array::from(&[Some(0.0), Some(-0.0)]));
+    check(&Float32Array::from(&[Some(f32::INFINITY), None]));
+    check(&Float32Array::from(&[
+        Some(f32::NAN),
+        Some(f32::NEG_INFINITY),
+    ]));
+}
+
+#[test]
+fn boolean() {
+    check(&BooleanArray::from(&[Some(true), Some(false)]));
+    check(&BooleanArray::from(&[Some(false), None]));
+}
+
+#[test]
+fn utf8_and_binary() {
+    check(&Utf8Array::<i32>::from(&[Some("b"), Some("a")]));
+    check(&Utf8Array::<i64>::from(&[Some("a"), Some("ab")]));
+    check(&Utf8Array::<i32>::from(&[None, Some("a")]));
+    check(&BinaryArray::<i32>::from(&[Some(b"b"), Some(b"a")]));
+    check(&BinaryArray::<i64>::from(&[Some(b"a"), None]));
+}
+
+#[test]
+fn other_types() {
+    let options = SortOptions::default();
+
+    let lhs = PrimitiveScalar::<i128>::new(DataType::Decimal(10, 2), Some(-100));
+    let rhs = PrimitiveScalar::<i128>::new(DataType::Decimal(10, 2), Some(5));
+    assert_eq!(
+        compare_scalars(&lhs, &rhs, &options).unwrap(),
+        Ordering::Less
+    );
+
+    let lhs = PrimitiveScalar::<f16>::from(Some(f16::from_f32(1.5)));
+    let rhs = PrimitiveScalar::<f16>::from(Some(f16::from_f32(-1.5)));
+    assert_eq!(
+        compare_scalars(&lhs, &rhs, &options).unwrap(),
+        Ordering::Greater
+    );
+
+    let data_type = DataType::Interval(IntervalUnit::DayTime);
+    let lhs = PrimitiveScalar::<days_ms>::new(data_type.clone(), Some(days_ms::new(1, 10)));
+    let rhs = PrimitiveScalar::<days_ms>::new(data_type, Some(days_ms::new(1, 20)));
+    assert_eq!(
+        compare_scalars(&lhs, &rhs, &options).unwrap(),
+        Ordering::Less
+    );
+
+    let data_type = DataType::FixedSizeBinary(1);
+    let lhs = FixedSizeBinaryScalar::new(data_type.clone(), Some(vec![2u8]));
+    let rhs = FixedSizeBinaryScalar::new(data_type, Option::<Vec<u8>>::None);
+    assert_eq!(
+        compare_scalars(&lhs, &rhs, &options).unwrap(),
+        Ordering::Greater
+    );
+
+    assert_eq!(
+        compare_scalars(&NullScalar::new(), &NullScalar::new(), &options).unwrap(),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn errors() {
+    let options = SortOptions::default();
+    let lhs = PrimitiveScalar::<i32>::from(Some(1));
+    let rhs = PrimitiveScalar::<i64>::from(Some(1));
+    assert!(compare_scalars(&lhs, &rhs, &options).is_err());
+
+    let lhs = PrimitiveScalar::<i32>::from(Some(1)).to(DataType::Date32);
+    let rhs = PrimitiveScalar::<i32>::from(Some(1));
+    assert!(compare_scalars(&lhs, &rhs, &options).is_err());
+}
+
+#[test]
+fn hash() {
+    use std::collections::HashSet;
+
+    let set = vec![
+        PrimitiveScalar::<i32>::from(Some(1)),
+        PrimitiveScalar::<i32>::from(None),
+        PrimitiveScalar::<i32>::from(Some(1)),
+    ]
+    .into_iter()
+    .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+
+    let set = vec![
+        Utf8Scalar::<i32>::from(Some("a")),
+        Utf8Scalar::<i32>::from(Some("a")),
+    ]
+    .into_iter()
+    .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 1);
+}
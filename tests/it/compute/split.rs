@@ -0,0 +1,128 @@
+use arrow2::array::*;
+use arrow2::compute::split::*;
+use arrow2::error::Result;
+
+fn list<O: Offset>(values: Vec<Option<Vec<&str>>>) -> Result<ListArray<O>> {
+    let mut array = MutableListArray::<O, MutableUtf8Array<O>>::new();
+    array.try_extend(
+        values
+            .into_iter()
+            .map(|x| x.map(|x| x.into_iter().map(Some).collect::<Vec<_>>())),
+    )?;
+    Ok(array.into())
+}
+
+fn check<O: Offset>(
+    values: &[Option<&str>],
+    delimiter: &str,
+    limit: Option<usize>,
+    expected: Vec<Option<Vec<&str>>>,
+) -> Result<()> {
+    let array = Utf8Array::<O>::from(values);
+    let result = split(&array, delimiter, limit)?;
+    assert_eq!(result, list::<O>(expected)?);
+    Ok(())
+}
+
+fn basics<O: Offset>() -> Result<()> {
+    check::<O>(
+        &[Some("a,b,c"), None, Some("abc")],
+        ",",
+        None,
+        vec![Some(vec!["a", "b", "c"]), None, Some(vec!["abc"])],
+    )?;
+    // empty strings and consecutive delimiters
+    check::<O>(
+        &[Some(""), Some(",a,,b,"), Some(",")],
+        ",",
+        None,
+        vec![
+            Some(vec![""]),
+            Some(vec!["", "a", "", "b", ""]),
+            Some(vec!["", ""]),
+        ],
+    )?;
+    // multi-character delimiters and multi-byte characters
+    check::<O>(
+        &[Some("ä::ö::ü"), Some("ä:ö")],
+        "::",
+        None,
+        vec![Some(vec!["ä", "ö", "ü"]), Some(vec!["ä:ö"])],
+    )?;
+    // empty delimiters split characters
+    check::<O>(
+        &[Some("aöc"), Some("")],
+        "",
+        None,
+        vec![Some(vec!["a", "ö", "c"]), Some(vec![""])],
+    )
+}
+
+#[test]
+fn utf8() -> Result<()> {
+    basics::<i32>()
+}
+
+#[test]
+fn large_utf8() -> Result<()> {
+    basics::<i64>()
+}
+
+#[test]
+fn limit() -> Result<()> {
+    check::<i32>(
+        &[Some("a,b,,c"), Some("a"), Some("a,b")],
+        ",",
+        Some(2),
+        vec![
+            Some(vec!["a", "b,,c"]),
+            Some(vec!["a"]),
+            Some(vec!["a", "b"]),
+        ],
+    )?;
+    check::<i32>(&[Some("a,b,c")], ",", Some(1), vec![Some(vec!["a,b,c"])])?;
+    check::<i32>(&[Some("abc")], "", Some(2), vec![Some(vec!["a", "bc"])])?;
+
+    let array = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(split(&array, ",", Some(0)).is_err());
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let array = Utf8Array::<i32>::from(&[Some("a,b"), None, Some("c,d"), Some("e")]).slice(1, 2);
+    let result = split(&array, ",", None)?;
+    assert_eq!(result, list::<i32>(vec![None, Some(vec!["c", "d"])])?);
+    Ok(())
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex() -> Result<()> {
+    let array = Utf8Array::<i32>::from(&[Some("oneAtwoBBthreeC"), None, Some(""), Some("a1b22c")]);
+    let result = split_regex(&array, "[ABC]", None)?;
+    let expected = list::<i32>(vec![
+        Some(vec!["one", "two", "", "three", ""]),
+        None,
+        Some(vec![""]),
+        Some(vec!["a1b22c"]),
+    ])?;
+    assert_eq!(result, expected);
+
+    let result = split_regex(&array, "[0-9]+", Some(2))?;
+    let expected = list::<i32>(vec![
+        Some(vec!["oneAtwoBBthreeC"]),
+        None,
+        Some(vec![""]),
+        Some(vec!["a", "b22c"]),
+    ])?;
+    assert_eq!(result, expected);
+
+    // empty matches split characters
+    let array = Utf8Array::<i64>::from_slice(&["abc"]);
+    let result = split_regex(&array, "x*", None)?;
+    assert_eq!(result, list::<i64>(vec![Some(vec!["a", "b", "c"])])?);
+
+    assert!(split_regex(&array, "(", None).is_err());
+    Ok(())
+}
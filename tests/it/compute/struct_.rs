@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::struct_::get_field;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+
+/// `{"a": {"b": i32, "c": utf8}, "d": i32}` with nulls at each level
+fn array() -> StructArray {
+    let b = Arc::new(Int32Array::from(&[
+        Some(1),
+        Some(2),
+        None,
+        Some(4),
+        Some(5),
+    ])) as Arc<dyn Array>;
+    let c = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b", "c", "d", "e"])) as Arc<dyn Array>;
+    let inner_type = DataType::Struct(vec![
+        Field::new("b", DataType::Int32, true),
+        Field::new("c", DataType::Utf8, false),
+    ]);
+    let a = Arc::new(StructArray::from_data(
+        inner_type.clone(),
+        vec![b, c],
+        Some(Bitmap::from([true, false, true, true, true])),
+    )) as Arc<dyn Array>;
+    let d = Arc::new(Int32Array::from_slice(&[10, 20, 30, 40, 50])) as Arc<dyn Array>;
+
+    let data_type = DataType::Struct(vec![
+        Field::new("a", inner_type, true),
+        Field::new("d", DataType::Int32, false),
+    ]);
+    StructArray::from_data(
+        data_type,
+        vec![a, d],
+        Some(Bitmap::from([true, true, true, false, true])),
+    )
+}
+
+#[test]
+fn nested() -> Result<()> {
+    let array = array();
+
+    let result = get_field(&array, &["a", "b"])?;
+    let expected = Int32Array::from(&[Some(1), None, None, None, Some(5)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get_field(&array, &["a", "c"])?;
+    let expected = Utf8Array::<i32>::from(&[Some("a"), None, Some("c"), None, Some("e")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn top_level() -> Result<()> {
+    let array = array();
+
+    let result = get_field(&array, &["d"])?;
+    let expected = Int32Array::from(&[Some(10), Some(20), Some(30), None, Some(50)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get_field(&array, &["a"])?;
+    assert_eq!(
+        result.validity(),
+        Some(&Bitmap::from([true, false, true, false, true]))
+    );
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let array = array().slice(2, 3);
+
+    let result = get_field(&array, &["a", "b"])?;
+    let expected = Int32Array::from(&[None, None, Some(5)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn without_nulls() -> Result<()> {
+    let values = Arc::new(Int32Array::from_slice(&[1, 2])) as Arc<dyn Array>;
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, false)]);
+    let array = StructArray::from_data(data_type, vec![values.clone()], None);
+
+    let result = get_field(&array, &["a"])?;
+    assert_eq!(result.as_ref(), values.as_ref());
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let array = array();
+    assert!(get_field(&array, &[]).is_err());
+    assert!(get_field(&array, &["e"]).is_err());
+    assert!(get_field(&array, &["d", "a"]).is_err());
+    assert!(get_field(&Int32Array::from_slice(&[1]), &["a"]).is_err());
+}
+
+#[test]
+fn error_message() {
+    let result = get_field(&Int32Array::from_slice([1]), &["a"]);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Invalid argument error: Expected an array of type Struct, but the array is of type Int32"
+    );
+}
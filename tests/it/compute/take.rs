@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use arrow2::compute::take::{can_take, take};
+use arrow2::compute::take::{can_take, indices_to_ranges, take, take_ranges};
 use arrow2::datatypes::{DataType, Field, IntervalUnit};
 use arrow2::error::Result;
 use arrow2::{array::*, bitmap::MutableBitmap, types::NativeType};
@@ -313,3 +313,86 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn union() {
+    use arrow2::datatypes::UnionMode;
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("c")])) as Arc<dyn Array>,
+    ];
+    let array = UnionArray::from_data(
+        data_type,
+        Buffer::from_slice([0, 0, 1]),
+        fields,
+        Some(Buffer::from_slice([0, 1, 0])),
+    );
+
+    let indices = Int32Array::from(&[Some(2), None, Some(0)]);
+    let result = take(&array, &indices).unwrap();
+    let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+
+    assert_eq!(format!("{:?}", result), "UnionArray[c, None, 1]");
+}
+
+#[test]
+fn ranges() -> Result<()> {
+    let indices = UInt32Array::from_slice(&[4, 5, 6, 0, 1, 9, 2]);
+    let ranges = indices_to_ranges(&indices)?;
+    assert_eq!(ranges, vec![(4, 3), (0, 2), (9, 1), (2, 1)]);
+
+    let arrays: Vec<Box<dyn Array>> = vec![
+        Box::new(
+            (0..10)
+                .map(|x| Some(x).filter(|x| x % 3 != 0))
+                .collect::<Int32Array>(),
+        ),
+        Box::new((0..10).map(|x| Some(x % 2 == 0)).collect::<BooleanArray>()),
+        Box::new(Utf8Array::<i64>::from_iter_values(
+            (0..10).map(|x| x.to_string()),
+        )),
+        Box::new(BinaryArray::<i32>::from_iter_values(
+            (0..10).map(|x| vec![x as u8; x]),
+        )),
+    ];
+    for array in arrays {
+        let expected = take(array.as_ref(), &indices)?;
+        let result = take_ranges(array.as_ref(), &ranges)?;
+        assert_eq!(result, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn ranges_nested() -> Result<()> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(vec![
+        Some(vec![Some(1)]),
+        None,
+        Some(vec![Some(2), None]),
+        Some(vec![]),
+    ])?;
+    let array: ListArray<i32> = array.into();
+
+    let result = take_ranges(&array, &[(2, 2), (0, 0), (1, 1)])?;
+    let expected = take(&array, &Int32Array::from_slice(&[2, 3, 1]))?;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn ranges_errors() {
+    let array = Int32Array::from_slice(&[1, 2, 3]);
+    assert!(take_ranges(&array, &[(2, 2)]).is_err());
+    assert!(take_ranges(&array, &[(usize::MAX, 2)]).is_err());
+    assert_eq!(take_ranges(&array, &[]).unwrap().len(), 0);
+
+    let indices = Int32Array::from(&[Some(1), None]);
+    assert!(indices_to_ranges(&indices).is_err());
+}
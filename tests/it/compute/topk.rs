@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use proptest::prelude::*;
+
+use arrow2::array::*;
+use arrow2::compute::sort::{sort, SortOptions};
+use arrow2::compute::topk::*;
+use arrow2::datatypes::DataType;
+
+fn options(descending: bool, nulls_first: bool) -> SortOptions {
+    SortOptions {
+        descending,
+        nulls_first,
+        nans_greatest: true,
+    }
+}
+
+fn check(array: &dyn Array, k: usize, options: SortOptions, expected: &[u32]) {
+    let (values, indices) = topk(array, k, options).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice(expected), "{:?}", options);
+    let expected = sort(array, &options, Some(k)).unwrap();
+    assert_eq!(values, expected, "{:?}", options);
+}
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from(&[Some(2), None, Some(5), Some(1), None, Some(4)]);
+    check(&array, 3, options(false, true), &[1, 4, 3]);
+    check(&array, 3, options(false, false), &[3, 0, 5]);
+    check(&array, 3, options(true, true), &[1, 4, 2]);
+    check(&array, 3, options(true, false), &[2, 5, 0]);
+
+    // fewer valid slots than `k`
+    check(&array, 5, options(false, false), &[3, 0, 5, 2, 1]);
+    check(&array, 1, options(false, true), &[1]);
+    check(&array, 0, options(false, true), &[]);
+}
+
+#[test]
+fn ties_in_order_of_index() {
+    let array = Int64Array::from_slice(&[3, 1, 3, 1, 3]);
+    check(&array, 2, options(false, true), &[1, 3]);
+    check(&array, 4, options(true, true), &[0, 2, 4, 1]);
+}
+
+#[test]
+fn k_larger_than_length() {
+    let array = Int32Array::from(&[Some(2), None, Some(1)]);
+    check(&array, 3, options(false, true), &[1, 2, 0]);
+    check(&array, 10, options(true, false), &[0, 2, 1]);
+}
+
+#[test]
+fn floats() {
+    // NaNs are not equal to themselves, so only the indices are compared
+    let array = Float64Array::from(&[Some(1.0), Some(f64::NAN), None, Some(-0.0), Some(0.0)]);
+    let indices = |k, options| topk(&array, k, options).unwrap().1;
+    assert_eq!(
+        indices(2, options(false, false)),
+        UInt32Array::from_slice(&[3, 4])
+    );
+    assert_eq!(
+        indices(2, options(true, false)),
+        UInt32Array::from_slice(&[1, 0])
+    );
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+        nans_greatest: false,
+    };
+    assert_eq!(indices(2, options), UInt32Array::from_slice(&[1, 3]));
+}
+
+#[test]
+fn decimal() {
+    let array = PrimitiveArray::<i128>::from(&[Some(1_00), Some(-2_50), None, Some(3_33)])
+        .to(DataType::Decimal(5, 2));
+    check(&array, 2, options(true, false), &[3, 0]);
+    check(&array, 2, options(false, true), &[2, 1]);
+}
+
+#[test]
+fn utf8_and_binary() {
+    let array = Utf8Array::<i32>::from(&[Some("b"), Some("a"), None, Some("c"), Some("ab")]);
+    check(&array, 3, options(false, false), &[1, 4, 0]);
+    check(&array, 2, options(true, true), &[2, 3]);
+
+    let array = Utf8Array::<i64>::from(&[Some("b"), Some("a"), None, Some("c"), Some("ab")]);
+    check(&array, 3, options(false, false), &[1, 4, 0]);
+
+    let array = BinaryArray::<i32>::from(&[Some(b"b".as_ref()), None, Some(b"a")]);
+    check(&array, 1, options(false, false), &[2]);
+}
+
+#[test]
+fn boolean() {
+    let array = BooleanArray::from(&[Some(true), None, Some(false), Some(true)]);
+    check(&array, 2, options(true, false), &[0, 3]);
+    check(&array, 2, options(false, true), &[1, 2]);
+}
+
+#[test]
+fn dictionary() {
+    // the key of the slot 3 points to a null value and is thus null
+    let values = Arc::new(Utf8Array::<i32>::from(&[Some("b"), Some("a"), None])) as Arc<dyn Array>;
+    let keys = Int32Array::from(&[Some(0), None, Some(1), Some(2), Some(0)]);
+    let array = DictionaryArray::<i32>::from_data(keys, values);
+
+    check(&array, 3, options(false, false), &[2, 0, 4]);
+    check(&array, 3, options(true, true), &[1, 3, 0]);
+}
+
+#[test]
+fn unsupported() {
+    let array = NullArray::from_data(DataType::Null, 2);
+    assert!(!can_topk(array.data_type()));
+    assert!(topk(&array, 1, options(false, false)).is_err());
+}
+
+proptest! {
+    /// Asserts that the values of the top-k are the first `k` values of the sorted array.
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn against_sort(
+        values in prop::collection::vec(prop::option::of(0..10i32), 0..50),
+        k in 0..60usize,
+        descending in any::<bool>(),
+        nulls_first in any::<bool>(),
+    ) {
+        let array = Int32Array::from(values);
+        let options = options(descending, nulls_first);
+        let (values, indices) = topk(&array, k, options).unwrap();
+        prop_assert_eq!(indices.len(), k.min(array.len()));
+        prop_assert_eq!(values, sort(&array, &options, Some(k)).unwrap());
+    }
+}
@@ -138,6 +138,39 @@ fn without_nulls_large_string() -> Result<()> {
     without_nulls_utf8::<i64>()
 }
 
+#[test]
+fn ascii_reuses_offsets() {
+    let array = Utf8Array::<i32>::from(&[Some("hello"), None, Some("WoRlD")]);
+    let result = upper(&array).unwrap();
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+
+    assert_eq!(
+        result,
+        &Utf8Array::<i32>::from(&[Some("HELLO"), None, Some("WORLD")])
+    );
+    assert_eq!(
+        result.offsets().as_slice().as_ptr(),
+        array.offsets().as_slice().as_ptr()
+    );
+}
+
+#[test]
+fn sliced() {
+    let array = Utf8Array::<i64>::from(&[Some("hello"), None, Some("WoRlD"), Some("olá")]);
+
+    let result = upper(&array.slice(1, 2)).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Utf8Array::<i64>::from(&[None, Some("WORLD")]) as &dyn Array
+    );
+
+    let result = upper(&array.slice(2, 2)).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Utf8Array::<i64>::from(&[Some("WORLD"), Some("OLÁ")]) as &dyn Array
+    );
+}
+
 #[test]
 fn consistency() {
     use arrow2::datatypes::DataType::*;
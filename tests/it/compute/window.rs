@@ -27,3 +27,99 @@ fn shift_max() {
 
     assert_eq!(expected.as_ref(), result.as_ref());
 }
+
+#[test]
+fn rolling_sum_mean() {
+    use arrow2::array::Float64Array;
+
+    let array = Int32Array::from(&[Some(1), None, Some(3), Some(4), Some(5)]);
+    let result = rolling(&array, 3, 2, RollingAgg::Sum).unwrap();
+    let expected = Int32Array::from(&[None, None, Some(4), Some(7), Some(12)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rolling(&array, 3, 1, RollingAgg::Mean).unwrap();
+    let expected = Float64Array::from(&[Some(1.0), Some(1.0), Some(2.0), Some(3.5), Some(4.0)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn rolling_min_max() {
+    let array = Int32Array::from(&[Some(3), Some(1), None, Some(2), Some(5), Some(4)]);
+    let result = rolling(&array, 3, 1, RollingAgg::Min).unwrap();
+    let expected = Int32Array::from(&[Some(3), Some(1), Some(1), Some(1), Some(2), Some(2)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rolling(&array, 3, 1, RollingAgg::Max).unwrap();
+    let expected = Int32Array::from(&[Some(3), Some(3), Some(3), Some(2), Some(5), Some(5)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn rolling_propagate_nulls() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), Some(4), Some(5)]);
+    let options = RollingOptions {
+        skip_nulls: false,
+        ..RollingOptions::new(2, 1)
+    };
+    let result = rolling_sum(&array, options).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, None, Some(7), Some(9)]);
+    assert_eq!(expected, result);
+
+    let result = rolling_max(&array.to(DataType::Date32), options).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, None, Some(4), Some(5)]).to(DataType::Date32);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn rolling_invalid() {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    assert!(rolling(&array, 0, 0, RollingAgg::Sum).is_err());
+    assert!(rolling(&array, 2, 0, RollingAgg::Sum).is_err());
+    assert!(rolling(&array, 2, 3, RollingAgg::Sum).is_err());
+}
+
+#[test]
+fn rolling_sum_stability() {
+    use arrow2::array::Float64Array;
+
+    // large values entering and leaving the window must not erase the small ones
+    let mut values = vec![1e16, 1.0, -1e16];
+    values.extend(vec![0.1; 10]);
+    let array = Float64Array::from_slice(&values);
+    let result = rolling_sum(&array, RollingOptions::new(3, 1)).unwrap();
+    assert_eq!(result.value(2), 1.0);
+    // -1e16 + 1.1, rounded to the nearest f64
+    assert_eq!(result.value(3), -1e16 + 2.0);
+    for i in 5..values.len() {
+        assert!((result.value(i) - 0.3).abs() < 1e-15, "{}", result.value(i));
+    }
+
+    let result = rolling_mean(&array, RollingOptions::new(3, 1)).unwrap();
+    assert!((result.value(values.len() - 1) - 0.1).abs() < 1e-15);
+
+    // non-finite values only affect the windows that contain them
+    let array = Float64Array::from_slice([1.0, f64::INFINITY, 2.0, f64::NAN, 3.0, 4.0]);
+    let result = rolling_sum(&array, RollingOptions::new(2, 1)).unwrap();
+    assert_eq!(result.value(1), f64::INFINITY);
+    assert_eq!(result.value(2), f64::INFINITY);
+    assert!(result.value(3).is_nan());
+    assert!(result.value(4).is_nan());
+    assert_eq!(result.value(5), 7.0);
+}
+
+#[test]
+fn rolling_float_min_max() {
+    use arrow2::array::Float64Array;
+
+    let array = Float64Array::from_slice([2.0, f64::NAN, 1.0, 3.0, 0.0]);
+    let result = rolling_max(&array, RollingOptions::new(2, 1)).unwrap();
+    assert_eq!(result.value(0), 2.0);
+    assert!(result.value(1).is_nan());
+    assert!(result.value(2).is_nan());
+    assert_eq!(result.value(3), 3.0);
+    assert_eq!(result.value(4), 3.0);
+
+    let result = rolling_min(&array, RollingOptions::new(2, 1)).unwrap();
+    let expected = Float64Array::from_slice([2.0, 2.0, 1.0, 1.0, 0.0]);
+    assert_eq!(expected, result);
+}
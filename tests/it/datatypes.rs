@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+
+fn metadata(pairs: &[(&str, &str)]) -> Metadata {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn schema_metadata(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    metadata(pairs).into_iter().collect()
+}
+
+#[test]
+fn merge_metadata() -> Result<()> {
+    let lhs = Schema::new(vec![
+        Field::new("a", DataType::Int32, false).with_metadata(metadata(&[("k1", "v1")])),
+        Field::new("b", DataType::Utf8, false),
+    ])
+    .with_metadata(schema_metadata(&[("s1", "v1")]));
+    let rhs = Schema::new(vec![
+        Field::new("a", DataType::Int32, true).with_metadata(metadata(&[("k2", "v2")])),
+        Field::new("c", DataType::Boolean, false),
+    ])
+    .with_metadata(schema_metadata(&[("s1", "v1"), ("s2", "v2")]));
+
+    let expected = Schema::new(vec![
+        Field::new("a", DataType::Int32, true)
+            .with_metadata(metadata(&[("k1", "v1"), ("k2", "v2")])),
+        Field::new("b", DataType::Utf8, false),
+        Field::new("c", DataType::Boolean, false),
+    ])
+    .with_metadata(schema_metadata(&[("s1", "v1"), ("s2", "v2")]));
+
+    assert_eq!(Schema::try_merge(vec![lhs.clone(), rhs.clone()])?, expected);
+    // the order of the fields follows the order of the schemas
+    assert_eq!(
+        Schema::try_merge(vec![rhs, lhs])?
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>(),
+        vec!["a", "c", "b"]
+    );
+    Ok(())
+}
+
+#[test]
+fn merge_conflicting_field_metadata() {
+    let lhs = Schema::new(vec![
+        Field::new("a", DataType::Int32, false).with_metadata(metadata(&[("k", "v1")]))
+    ]);
+    let rhs = Schema::new(vec![
+        Field::new("a", DataType::Int32, false).with_metadata(metadata(&[("k", "v2")]))
+    ]);
+    let error = Schema::try_merge(vec![lhs, rhs]).unwrap_err().to_string();
+    assert!(error.contains("key k"), "{}", error);
+}
+
+#[test]
+fn merge_conflicting_schema_metadata() {
+    let lhs = Schema::empty().with_metadata(schema_metadata(&[("k", "v1")]));
+    let rhs = Schema::empty().with_metadata(schema_metadata(&[("k", "v2")]));
+    assert!(Schema::try_merge(vec![lhs, rhs]).is_err());
+}
+
+#[test]
+fn merge_conflicting_data_type() {
+    let lhs = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+    let rhs = Schema::new(vec![Field::new("a", DataType::Int64, false)]);
+    let error = Schema::try_merge(vec![lhs, rhs]).unwrap_err().to_string();
+    assert!(error.contains("\"a\""), "{}", error);
+}
+
+#[test]
+fn project() -> Result<()> {
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, false).with_metadata(metadata(&[("k", "v")])),
+        Field::new("b", DataType::Utf8, false),
+        Field::new("c", DataType::Boolean, true),
+    ])
+    .with_metadata(schema_metadata(&[("s", "v")]));
+
+    let projected = schema.project(&[2, 0])?;
+    assert_eq!(
+        projected,
+        Schema::new(vec![
+            Field::new("c", DataType::Boolean, true),
+            Field::new("a", DataType::Int32, false).with_metadata(metadata(&[("k", "v")])),
+        ])
+        .with_metadata(schema_metadata(&[("s", "v")]))
+    );
+
+    assert!(schema.project(&[3]).is_err());
+    Ok(())
+}
+
+#[test]
+fn index_of() {
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    assert_eq!(schema.index_of("b").unwrap(), 1);
+    let error = schema.index_of("c").unwrap_err().to_string();
+    assert!(error.contains("[\"a\", \"b\"]"), "{}", error);
+}
+
+#[test]
+fn try_from_columns() -> Result<()> {
+    use arrow2::array::*;
+    use arrow2::bitmap::Bitmap;
+    use arrow2::columns::Columns;
+
+    let columns = Columns::new(vec![
+        Box::new(Int32Array::from_slice(&[1, 2])) as Box<dyn Array>,
+        Box::new(Utf8Array::<i32>::from(&[Some("a"), None])),
+        // a validity without nulls is still nullable
+        Box::new(Int64Array::from_data(
+            DataType::Date64,
+            vec![1, 2].into(),
+            Some(Bitmap::from([true, true])),
+        )),
+        Box::new(NullArray::from_data(DataType::Null, 2)),
+    ]);
+    let schema = Schema::try_from_columns(&["a", "b", "c", "d"], &columns)?;
+    assert_eq!(
+        schema,
+        Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Date64, true),
+            Field::new("d", DataType::Null, true),
+        ])
+    );
+
+    assert!(Schema::try_from_columns(&["a"], &columns).is_err());
+    Ok(())
+}
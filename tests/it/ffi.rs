@@ -74,6 +74,16 @@ fn i64() -> Result<()> {
     test_round_trip(data)
 }
 
+#[test]
+fn f16() -> Result<()> {
+    use arrow2::types::f16;
+    let data = [Some(1.5), None, Some(-0.25), None]
+        .iter()
+        .map(|x| x.map(f16::from_f32))
+        .collect::<PrimitiveArray<f16>>();
+    test_round_trip(data)
+}
+
 #[test]
 fn utf8() -> Result<()> {
     let data = Utf8Array::<i32>::from(&vec![Some("a"), None, Some("bb"), None]);
@@ -102,6 +112,17 @@ fn timestamp_tz() -> Result<()> {
     test_round_trip(data)
 }
 
+#[test]
+fn months_days_ns() -> Result<()> {
+    use arrow2::types::months_days_ns;
+    let data = MonthsDaysNsArray::from(&[
+        Some(months_days_ns::new(1, 2, 3)),
+        None,
+        Some(months_days_ns::new(-1, 0, i64::MAX)),
+    ]);
+    test_round_trip(data)
+}
+
 #[test]
 fn large_binary() -> Result<()> {
     let data =
@@ -217,3 +238,52 @@ fn extension() -> Result<()> {
     );
     test_round_trip_schema(field)
 }
+
+#[test]
+fn union() -> Result<()> {
+    use arrow2::buffer::Buffer;
+    use arrow2::datatypes::UnionMode;
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![0, 1]), UnionMode::Sparse);
+    let types = Buffer::from_slice([0, 0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type, types, fields, None);
+
+    test_round_trip(array)
+}
+
+#[test]
+fn union_dense() -> Result<()> {
+    use arrow2::buffer::Buffer;
+    use arrow2::datatypes::UnionMode;
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![0, 1]), UnionMode::Dense);
+    let types = Buffer::from_slice([0, 0, 1]);
+    let offsets = Buffer::from_slice([0, 1, 0]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("c")])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type, types, fields, Some(offsets));
+
+    test_round_trip(array)
+}
+
+#[test]
+fn null() -> Result<()> {
+    let data = NullArray::new_null(DataType::Null, 3);
+    test_round_trip(data)
+}
@@ -187,3 +187,221 @@ fn read_deflate() -> Result<()> {
 fn read_snappy() -> Result<()> {
     test(Codec::Snappy)
 }
+
+#[test]
+fn read_error_has_context() -> Result<()> {
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    let avro_schemas = arrow2::io::avro::write::to_avro_schema(&schema)?
+        .into_iter()
+        .map(|field| field.schema)
+        .collect::<Vec<_>>();
+
+    // (1, "x"), (2, <invalid utf8>)
+    let block = arrow2::io::avro::Block::new(2, vec![2, 2, b'x', 4, 2, 0xff]);
+
+    let error = read::deserialize(&block, Arc::new(schema), &avro_schemas).unwrap_err();
+    assert!(matches!(
+        error,
+        arrow2::error::ArrowError::Deserialization { column: Some(ref column), row: Some(1), .. } if column == "b"
+    ));
+    assert!(error.to_string().contains("column \"b\" at row 1"));
+    Ok(())
+}
+
+fn write_evolved_avro() -> std::result::Result<Vec<u8>, avro_rs::Error> {
+    let raw_schema = r#"
+    {
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "a", "type": "long"},
+            {"name": "b", "type": "string", "default": "x"},
+            {"name": "c", "type": "int", "default": 0},
+            {"name": "d", "type": ["null", "float"], "default": null},
+            {"name": "e", "type": "bytes"},
+            {"name": "f", "type": {"type": "fixed", "name": "f", "size": 2}},
+            {"name": "g", "type": {
+                "type": "array",
+                "items": ["null", "string"]
+            }},
+            {"name": "h", "type": {
+                "type": "record",
+                "name": "h",
+                "fields": [
+                    {"name": "x", "type": "double"},
+                    {"name": "y", "type": "boolean"}
+                ]
+            }},
+            {"name": "i", "type": {
+                "type": "enum",
+                "name": "i",
+                "symbols" : ["SPADES", "HEARTS"]
+            }},
+            {"name": "j", "type": "int"}
+        ]
+    }
+"#;
+    let schema = AvroSchema::parse_str(raw_schema).unwrap();
+    let mut writer = Writer::new(&schema, Vec::new());
+
+    for (a, b, c, d) in [(1i64, "foo", 1i32, Some(1.5f32)), (2, "bar", 2, None)] {
+        let mut record = Record::new(writer.schema()).unwrap();
+        record.put("a", a);
+        record.put("b", b);
+        record.put("c", c);
+        record.put("d", d);
+        record.put("e", b"bytes".as_ref());
+        record.put("f", Value::Fixed(2, vec![1, 2]));
+        record.put(
+            "g",
+            Value::Array(vec![
+                Value::Union(Box::new(Value::String("a".to_string()))),
+                Value::Union(Box::new(Value::Null)),
+            ]),
+        );
+        record.put(
+            "h",
+            Value::Record(vec![
+                ("x".to_string(), Value::Double(1.0)),
+                ("y".to_string(), Value::Boolean(true)),
+            ]),
+        );
+        record.put("i", Value::Enum(1, "HEARTS".to_string()));
+        record.put("j", c * 10);
+        writer.append(record)?;
+    }
+    Ok(writer.into_inner().unwrap())
+}
+
+/// Reads `avro` with the reader's schema `reader_schema`, or with the projection of the
+/// writer's fields `names`
+fn read_projected(avro: &[u8], reader_schema: Option<&str>, names: &[&str]) -> Result<RecordBatch> {
+    let file = &mut &avro[..];
+    let (avro_schemas, schema, codec, file_marker) = read::read_metadata(file)?;
+    let projection = match reader_schema {
+        Some(reader_schema) => {
+            let reader_fields = read::parse_reader_schema(reader_schema)?;
+            read::Projection::try_new(&schema, &avro_schemas, &reader_fields)?
+        }
+        None => read::Projection::try_from_names(&schema, &avro_schemas, names)?,
+    };
+
+    let mut reader = read::Reader::new(
+        read::Decompressor::new(read::BlockStreamIterator::new(file, file_marker), codec),
+        avro_schemas,
+        Arc::new(schema),
+    )
+    .with_projection(projection);
+
+    reader.next().unwrap()
+}
+
+#[test]
+fn read_projection() -> Result<()> {
+    let avro = write_evolved_avro().unwrap();
+
+    let result = read_projected(&avro, None, &["j", "b"])?;
+
+    let schema = Schema::new(vec![
+        Field::new("j", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    let columns = vec![
+        Arc::new(Int32Array::from_slice([10, 20])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["foo", "bar"])) as Arc<dyn Array>,
+    ];
+    assert_eq!(result, RecordBatch::try_new(Arc::new(schema), columns)?);
+
+    assert!(read_projected(&avro, None, &["z"]).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_reader_schema() -> Result<()> {
+    let avro = write_evolved_avro().unwrap();
+
+    // a subset of the writer's fields, in another order, with promotions and added fields
+    let reader_schema = r#"
+    {
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "z", "type": "int", "default": 7},
+            {"name": "c", "type": "long"},
+            {"name": "i", "type": {
+                "type": "enum",
+                "name": "i",
+                "symbols" : ["SPADES", "HEARTS"]
+            }},
+            {"name": "d", "type": ["null", "double"]},
+            {"name": "y", "type": ["null", "string"], "default": null},
+            {"name": "x", "type": {"type": "array", "items": "long"}, "default": [1, 2]},
+            {"name": "j", "type": "double"}
+        ]
+    }
+    "#;
+    let result = read_projected(&avro, Some(reader_schema), &[])?;
+
+    let x_type = DataType::List(Box::new(Field::new("item", DataType::Int64, false)));
+    let x = ListArray::<i32>::from_data(
+        x_type.clone(),
+        vec![0, 2, 4].into(),
+        Arc::new(Int64Array::from_slice([1, 2, 1, 2])),
+        None,
+    );
+
+    let schema = Schema::new(vec![
+        Field::new("z", DataType::Int32, false),
+        Field::new("c", DataType::Int64, false),
+        Field::new(
+            "i",
+            DataType::Dictionary(i32::KEY_TYPE, Box::new(DataType::Utf8), false),
+            false,
+        ),
+        Field::new("d", DataType::Float64, true),
+        Field::new("y", DataType::Utf8, true),
+        Field::new("x", x_type, false),
+        Field::new("j", DataType::Float64, false),
+    ]);
+    let columns = vec![
+        Arc::new(Int32Array::from_slice([7, 7])) as Arc<dyn Array>,
+        Arc::new(Int64Array::from_slice([1, 2])) as Arc<dyn Array>,
+        Arc::new(DictionaryArray::<i32>::from_data(
+            Int32Array::from_slice([1, 1]),
+            Arc::new(Utf8Array::<i32>::from_slice(["SPADES", "HEARTS"])),
+        )) as Arc<dyn Array>,
+        Arc::new(Float64Array::from([Some(1.5), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([None::<&str>, None])) as Arc<dyn Array>,
+        Arc::new(x) as Arc<dyn Array>,
+        Arc::new(Float64Array::from_slice([10.0, 20.0])) as Arc<dyn Array>,
+    ];
+    assert_eq!(result, RecordBatch::try_new(Arc::new(schema), columns)?);
+    Ok(())
+}
+
+#[test]
+fn read_reader_schema_invalid() {
+    let avro = write_evolved_avro().unwrap();
+
+    // added field without default
+    let reader_schema =
+        r#"{"type": "record", "name": "test", "fields": [{"name": "z", "type": "int"}]}"#;
+    assert!(read_projected(&avro, Some(reader_schema), &[]).is_err());
+
+    // default of another type
+    let reader_schema = r#"{"type": "record", "name": "test", "fields": [{"name": "z", "type": "int", "default": "a"}]}"#;
+    assert!(read_projected(&avro, Some(reader_schema), &[]).is_err());
+
+    // `long` can't be read as `int`
+    let reader_schema =
+        r#"{"type": "record", "name": "test", "fields": [{"name": "a", "type": "int"}]}"#;
+    assert!(read_projected(&avro, Some(reader_schema), &[]).is_err());
+
+    // nullable values can't be read as non-nullable
+    let reader_schema =
+        r#"{"type": "record", "name": "test", "fields": [{"name": "d", "type": "float"}]}"#;
+    assert!(read_projected(&avro, Some(reader_schema), &[]).is_err());
+}
@@ -229,6 +229,288 @@ fn deserialize_timestamp() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn deserialize_timestamp_units() -> Result<()> {
+    let input = "1970-01-01T00:00:01.123456789+00:00\n1969-12-31T23:59:59.5+00:00";
+
+    for (time_unit, expected) in [
+        (TimeUnit::Second, [1, -1]),
+        (TimeUnit::Millisecond, [1_123, -500]),
+        (TimeUnit::Microsecond, [1_123_456, -500_000]),
+        (TimeUnit::Nanosecond, [1_123_456_789, -500_000_000]),
+    ] {
+        let data_type = DataType::Timestamp(time_unit, Some("UTC".to_string()));
+        let expected = Int64Array::from_slice(expected).to(data_type.clone());
+        let result = test_deserialize(input, data_type)?;
+        assert_eq!(expected, result.as_ref());
+
+        // naive timestamps are truncated the same way
+        let input = input.replace("+00:00", "");
+        let data_type = DataType::Timestamp(time_unit, None);
+        let expected = expected.to(data_type.clone());
+        let result = test_deserialize(&input, data_type)?;
+        assert_eq!(expected, result.as_ref());
+    }
+    Ok(())
+}
+
+#[test]
+fn deserialize_timestamp_offsets() -> Result<()> {
+    // 2021-01-01T00:00:00Z
+    let instant = 1_609_459_200;
+    let input = vec![
+        "2021-01-01T02:00:00+02:00",
+        "2021-01-01T00:00:00Z",
+        "2020-12-31T22:30:00-01:30",
+        // without an offset, the value is the wall-clock time in the declared timezone
+        "2021-01-01T01:00:00",
+        "not a timestamp",
+    ];
+    let input = input.join("\n");
+
+    let data_type = DataType::Timestamp(TimeUnit::Microsecond, Some("+01:00".to_string()));
+    let expected = Int64Array::from([
+        Some(instant * 1_000_000),
+        Some(instant * 1_000_000),
+        Some(instant * 1_000_000),
+        Some(instant * 1_000_000),
+        None,
+    ])
+    .to(data_type.clone());
+
+    let result = test_deserialize(&input, data_type)?;
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn deserialize_timestamp_overflow() -> Result<()> {
+    // representable in seconds, but not in nanoseconds
+    let input = "2300-01-01T00:00:00+00:00";
+
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("UTC".to_string()));
+    let result = test_deserialize(input, data_type.clone())?;
+    assert_eq!(
+        Int64Array::from_slice([10_413_792_000]).to(data_type),
+        result.as_ref()
+    );
+
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".to_string()));
+    let result = test_deserialize(input, data_type.clone())?;
+    assert_eq!(Int64Array::from([None]).to(data_type), result.as_ref());
+    Ok(())
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn deserialize_timestamp_tz() -> Result<()> {
+    let input = vec![
+        // summer time, UTC+2
+        "2021-07-01T02:00:00",
+        // winter time, UTC+1
+        "2021-01-01T01:00:00",
+        "2021-01-01T00:00:00+00:00",
+    ];
+    let input = input.join("\n");
+
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("Europe/Berlin".to_string()));
+    let expected =
+        Int64Array::from_slice([1_625_097_600, 1_609_459_200, 1_609_459_200]).to(data_type.clone());
+
+    let result = test_deserialize(&input, data_type)?;
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn deserialize_utf8_dictionary() -> Result<()> {
+    let input = vec!["a", "b", "\"\"", "a", "b"];
+    let input = input.join("\n");
+
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+
+    let mut expected = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    expected.try_extend(vec![Some("a"), Some("b"), Some(""), Some("a"), Some("b")])?;
+    let expected: DictionaryArray<i32> = expected.into();
+
+    let result = test_deserialize(&input, data_type)?;
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(result.values().len(), 3);
+    Ok(())
+}
+
+#[test]
+fn deserialize_binary_dictionary() -> Result<()> {
+    let input = vec!["aa", "bb", "aa"];
+    let input = input.join("\n");
+
+    let data_type = DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Binary), false);
+
+    let keys = UInt8Array::from_slice([0, 1, 0]);
+    let values = BinaryArray::<i32>::from_slice([b"aa", b"bb"]);
+    let expected = DictionaryArray::<u8>::from_data(keys, Arc::new(values));
+
+    let result = test_deserialize(&input, data_type)?;
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn deserialize_dictionary_overflow() -> Result<()> {
+    let input = (0..300).map(|x| x.to_string()).collect::<Vec<_>>();
+    let input = input.join("\n");
+
+    let data_type = DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), false);
+
+    let reader = std::io::Cursor::new(input);
+    let mut reader = ReaderBuilder::new().has_headers(false).from_reader(reader);
+    let mut rows = vec![ByteRecord::default(); 300];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    assert!(deserialize_column(&rows[..rows_read], 0, data_type, 0).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "compute_aggregate")]
+#[test]
+fn deserialize_dictionary_memory() -> Result<()> {
+    use arrow2::compute::aggregate::estimated_bytes_size;
+
+    let categories = ["apple", "banana", "cherry", "durian", "elderberry"];
+    let input = (0..10_000)
+        .map(|i| categories[i % categories.len()])
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let reader = std::io::Cursor::new(input);
+    let mut reader = ReaderBuilder::new().has_headers(false).from_reader(reader);
+    let mut rows = vec![ByteRecord::default(); 10_000];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let rows = &rows[..rows_read];
+
+    let utf8 = deserialize_column(rows, 0, DataType::Utf8, 0)?;
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let dictionary = deserialize_column(rows, 0, data_type, 0)?;
+
+    let utf8_size = estimated_bytes_size(utf8.as_ref());
+    let dictionary_size = estimated_bytes_size(dictionary.as_ref());
+    // each row costs an offset plus its bytes in utf8, but only a key when dictionary-encoded
+    assert!(dictionary_size * 2 < utf8_size);
+    Ok(())
+}
+
+#[test]
+fn deserialize_batch_error_has_context() -> Result<()> {
+    let input = "a,b\n1,1996-12-19T16:34:57-02:00";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(input));
+
+    let fields = vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new(
+            "b",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("invalid".to_string())),
+            true,
+        ),
+    ];
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+
+    let error =
+        deserialize_batch(&rows[..rows_read], &fields, None, 0, deserialize_column).unwrap_err();
+    assert!(error.to_string().contains("column \"b\""));
+    Ok(())
+}
+
+#[test]
+fn read_chunks() -> Result<()> {
+    let input = "a\n1\n2\n3\n4\n";
+    let reader = ReaderBuilder::new().from_reader(Cursor::new(input));
+
+    let chunks = ChunkIterator::new(reader, 2).collect::<Result<Vec<_>>>()?;
+    // no empty chunk is returned when the number of rows is a multiple of the chunk size
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].rows.len(), 2);
+    assert_eq!(chunks[0].line_number, 0);
+    assert_eq!(chunks[1].rows.len(), 2);
+    assert_eq!(chunks[1].line_number, 2);
+    Ok(())
+}
+
+#[test]
+fn deserialize_chunks() -> Result<()> {
+    let input = "a,b,c\n1,x,1.5\n2,y,2.5\n3,z,3.5\n";
+    let reader = ReaderBuilder::new().from_reader(Cursor::new(input));
+
+    let fields = vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Float64, true),
+    ];
+
+    let chunks = deserialize_iter(reader, 2, &fields, Some(&[2, 0])).collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].arrays().len(), 2);
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Float64Array::from_slice(&[1.5, 2.5]) as &dyn Array
+    );
+    assert_eq!(
+        chunks[1].arrays()[1].as_ref(),
+        &Int64Array::from_slice(&[3]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn deserialize_chunk_error_has_context() -> Result<()> {
+    let input = "a\n1996-12-19T16:34:57-02:00\n";
+    let reader = ReaderBuilder::new().from_reader(Cursor::new(input));
+
+    let fields = vec![Field::new(
+        "a",
+        DataType::Timestamp(TimeUnit::Millisecond, Some("invalid".to_string())),
+        true,
+    )];
+    let mut chunks = ChunkIterator::new(reader, 2);
+
+    let error = deserialize(&chunks.next().unwrap()?, &fields, None).unwrap_err();
+    assert!(error.to_string().contains("column \"a\""));
+    assert!(chunks.next().is_none());
+    Ok(())
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn deserialize_parallel() -> Result<()> {
+    let input = (0..1000).map(|x| format!("{}\n", x)).collect::<String>();
+    let reader = ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(Cursor::new(input));
+
+    let fields = vec![Field::new("a", DataType::Int64, true)];
+    let chunks = ChunkIterator::new(reader, 7);
+
+    let values = ParallelDeserializer::new(chunks, fields, None, 4)
+        .map(|columns| {
+            let columns = columns?;
+            let array = columns.arrays()[0]
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap()
+                .clone();
+            Ok(array.values().to_vec())
+        })
+        .collect::<Result<Vec<_>>>()?
+        .concat();
+    assert_eq!(values, (0..1000).collect::<Vec<i64>>());
+    Ok(())
+}
+
 proptest! {
     #[test]
     #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
@@ -2,6 +2,7 @@ use std::io::Cursor;
 use std::sync::Arc;
 
 use arrow2::array::*;
+use arrow2::columns::Columns;
 use arrow2::datatypes::*;
 use arrow2::error::Result;
 use arrow2::io::csv::write::*;
@@ -205,6 +206,51 @@ fn data_array(column: usize) -> (RecordBatch, Vec<&'static str>) {
                 ],
             )
         }
+        15 => {
+            let array =
+                PrimitiveArray::<i128>::from_slice([12345, -5, 100]).to(DataType::Decimal(10, 2));
+            (
+                Arc::new(array) as Arc<dyn Array>,
+                vec!["123.45", "-0.05", "1.00"],
+            )
+        }
+        16 => {
+            let array = PrimitiveArray::<i64>::from_slice([1, 20, 300])
+                .to(DataType::Duration(TimeUnit::Millisecond));
+            (
+                Arc::new(array) as Arc<dyn Array>,
+                vec!["1ms", "20ms", "300ms"],
+            )
+        }
+        17 => {
+            // a null key and a key to a null value are both serialized as empty
+            // (quoted, as the only field of a record)
+            let keys = Int32Array::from([Some(1), None, Some(0), Some(2), Some(1)]);
+            let values = Utf8Array::<i32>::from([Some("a b"), Some("c"), None]);
+            let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+            (
+                Arc::new(array) as Arc<dyn Array>,
+                vec!["c", "\"\"", "a b", "\"\"", "c"],
+            )
+        }
+        18 => {
+            let keys = Int8Array::from([Some(0), Some(0), None, Some(1)]);
+            let values = Int64Array::from_slice([-10, 20]);
+            let array = DictionaryArray::<i8>::from_data(keys, Arc::new(values));
+            (
+                Arc::new(array) as Arc<dyn Array>,
+                vec!["-10", "-10", "\"\"", "20"],
+            )
+        }
+        19 => {
+            let keys = UInt16Array::from([Some(1), None, Some(0)]);
+            let values = Int32Array::from_slice([0, 18808]).to(DataType::Date32);
+            let array = DictionaryArray::<u16>::from_data(keys, Arc::new(values));
+            (
+                Arc::new(array) as Arc<dyn Array>,
+                vec!["2021-06-30 00:00:00", "\"\"", "1970-01-01 00:00:00"],
+            )
+        }
         _ => todo!(),
     };
 
@@ -242,8 +288,175 @@ fn write_each() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn write_display_fallback() -> Result<()> {
+    write_single(15)?;
+    write_single(16)
+}
+
+#[test]
+fn write_dictionary() -> Result<()> {
+    for i in 17..=19 {
+        write_single(i)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn write_dictionary_options() -> Result<()> {
+    let keys = Int32Array::from([Some(1), None, Some(0)]);
+    let values = Int32Array::from_slice([0, 18808]).to(DataType::Date32);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+    let batch = RecordBatch::try_from_iter(vec![("c1", Arc::new(array) as Arc<dyn Array>)])?;
+
+    let mut writer = WriterBuilder::new().from_writer(Cursor::new(Vec::<u8>::new()));
+    let options = SerializeOptions {
+        date32_format: Some("%d/%m/%Y".to_string()),
+        ..Default::default()
+    };
+    write_batch(&mut writer, &batch, &options)?;
+
+    let buffer = writer.into_inner().unwrap().into_inner();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "30/06/2021\n\"\"\n01/01/1970\n"
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "chrono-tz")]
 fn write_tz_timezone() -> Result<()> {
     write_single(14)
 }
+
+#[test]
+fn write_quoted() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a,b"), Some("c \"d\""), None, Some("e\nf")]);
+    let batch = RecordBatch::try_from_iter(vec![("c1", Arc::new(array) as Arc<dyn Array>)])?;
+
+    let write = Cursor::new(Vec::<u8>::new());
+    let mut writer = WriterBuilder::new().from_writer(write);
+
+    let options = SerializeOptions::default();
+    write_batch(&mut writer, &batch, &options)?;
+
+    // check that quoting is applied and that a single null field is written as `""`
+    let buffer = writer.into_inner().unwrap().into_inner();
+    assert_eq!(
+        "\"a,b\"\n\"c \"\"d\"\"\"\n\"\"\n\"e\nf\"\n",
+        String::from_utf8(buffer).unwrap(),
+    );
+    Ok(())
+}
+
+#[test]
+fn write_custom_serializers() -> Result<()> {
+    let batch = data();
+
+    // the timestamp column "c5" as epoch milliseconds and the float column "c2" as fixed-point
+    let mut serializers: Vec<Option<CustomSerializer>> = (0..7).map(|_| None).collect();
+    serializers[1] = Some(Box::new(|array, index, buf| {
+        let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+        buf.extend_from_slice(format!("{:.2}", array.value(index)).as_bytes());
+    }));
+    serializers[4] = Some(Box::new(|array, index, buf| {
+        let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
+        buf.extend_from_slice(array.value(index).to_string().as_bytes());
+    }));
+
+    let write = Cursor::new(Vec::<u8>::new());
+    let mut writer = WriterBuilder::new().from_writer(write);
+
+    write_header(&mut writer, batch.schema())?;
+    let options = SerializeOptions::default();
+    write_batch_with_serializers(&mut writer, &batch, &options, &serializers)?;
+
+    // nulls are still written as empty fields
+    let buffer = writer.into_inner().unwrap().into_inner();
+    assert_eq!(
+        r#"c1,c2,c3,c4,c5,c6,c7
+a b,123.56,3,true,,00:20:34,d
+c,,2,false,1555584887378,06:51:20,a b
+d,-556132.25,1,,1555555555555,23:46:03,c
+"#
+        .to_string(),
+        String::from_utf8(buffer).unwrap(),
+    );
+    Ok(())
+}
+
+#[test]
+fn write_custom_serializer_binary() -> Result<()> {
+    let array = BinaryArray::<i32>::from([Some(b"\x01\xab".as_ref()), None, Some(b"")]);
+    let batch = RecordBatch::try_from_iter(vec![("c1", Arc::new(array) as Arc<dyn Array>)])?;
+
+    let hex: CustomSerializer = Box::new(|array, index, buf| {
+        let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+        array
+            .value(index)
+            .iter()
+            .for_each(|byte| buf.extend_from_slice(format!("{:02x}", byte).as_bytes()));
+    });
+
+    let mut writer = WriterBuilder::new().from_writer(vec![]);
+    let options = SerializeOptions::default();
+    write_batch_with_serializers(&mut writer, &batch, &options, &[Some(hex)])?;
+
+    let buffer = writer.into_inner().unwrap();
+    assert_eq!("01ab\n\"\"\n\"\"\n", String::from_utf8(buffer).unwrap());
+
+    // one serializer per column is required
+    let mut writer = WriterBuilder::new().from_writer(vec![]);
+    assert!(write_batch_with_serializers(&mut writer, &batch, &options, &[None, None]).is_err());
+    Ok(())
+}
+
+fn mixed_columns() -> Columns<Arc<dyn Array>> {
+    Columns::new(vec![
+        Arc::new(Utf8Array::<i32>::from([Some("a"), None])) as Arc<dyn Array>,
+        Arc::new(Int32Array::from_slice([18808, 0])),
+        Arc::new(BooleanArray::from_slice([true, false])),
+    ])
+}
+
+#[test]
+fn write_columns_with_inferred_schema() -> Result<()> {
+    let columns = mixed_columns();
+    let schema = Schema::try_from_columns(&["name", "days", "flag"], &columns)?;
+
+    let mut writer = WriterBuilder::new().from_writer(vec![]);
+    write_header(&mut writer, &schema)?;
+    write_columns(&mut writer, &schema, &columns, &SerializeOptions::default())?;
+
+    let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert_eq!(csv, "name,days,flag\na,18808,true\n,0,false\n");
+    Ok(())
+}
+
+#[test]
+fn write_columns_logical_types_from_schema() -> Result<()> {
+    let columns = mixed_columns();
+    let mut schema = Schema::try_from_columns(&["name", "date", "flag"], &columns)?;
+    schema.fields[1] = Field::new("date", DataType::Date32, false);
+
+    let mut writer = WriterBuilder::new().from_writer(vec![]);
+    write_header(&mut writer, &schema)?;
+    write_columns(&mut writer, &schema, &columns, &SerializeOptions::default())?;
+
+    let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert_eq!(
+        csv,
+        "name,date,flag\na,2021-06-30 00:00:00,true\n,1970-01-01 00:00:00,false\n"
+    );
+
+    // the physical type of a field must match the one of its array
+    schema.fields[1] = Field::new("date", DataType::Date64, false);
+    let mut writer = WriterBuilder::new().from_writer(vec![]);
+    let options = SerializeOptions::default();
+    assert!(write_columns(&mut writer, &schema, &columns, &options).is_err());
+    // and there must be one field per column
+    let schema = schema.project(&[0, 2])?;
+    assert!(write_columns(&mut writer, &schema, &columns, &options).is_err());
+    Ok(())
+}
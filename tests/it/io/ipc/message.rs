@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::columns::Columns;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::ipc::read::{decode_message, fb_to_schema, Dictionaries};
+use arrow2::io::ipc::write::{
+    columns_to_encoded_data, default_ipc_fields, schema_to_bytes, WriteOptions,
+};
+use arrow_format::ipc::Message::root_as_message;
+
+fn columns() -> Result<(Schema, Columns<Arc<dyn Array>>)> {
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary.try_extend(vec![Some("a"), None, Some("b"), Some("a")])?;
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", dictionary.data_type().clone(), true),
+    ]);
+    let columns = Columns::try_new(vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(3), Some(4)])) as Arc<dyn Array>,
+        Arc::new(dictionary),
+    ])?;
+    Ok((schema, columns))
+}
+
+fn round_trip(options: WriteOptions) -> Result<()> {
+    let (schema, columns) = columns()?;
+    let ipc_fields = default_ipc_fields(schema.fields());
+
+    // the schema is sent on its own, e.g. as the first `FlightData`
+    let schema_bytes = schema_to_bytes(&schema, &ipc_fields);
+    let (dictionaries, batch) = columns_to_encoded_data(&columns, &ipc_fields, &options)?;
+    assert_eq!(dictionaries.len(), 1);
+
+    let message = root_as_message(&schema_bytes).unwrap();
+    let (result_schema, ipc_schema) = fb_to_schema(message.header_as_schema().unwrap())?;
+    assert_eq!(result_schema, schema);
+    let schema = Arc::new(result_schema);
+
+    let mut decoded = Dictionaries::new();
+    for dictionary in &dictionaries {
+        let result = decode_message(
+            &dictionary.ipc_message,
+            &dictionary.arrow_data,
+            &schema,
+            &ipc_schema,
+            &mut decoded,
+        )?;
+        assert!(result.is_none());
+    }
+    assert_eq!(decoded.len(), 1);
+
+    let result = decode_message(
+        &batch.ipc_message,
+        &batch.arrow_data,
+        &schema,
+        &ipc_schema,
+        &mut decoded,
+    )?;
+    assert_eq!(result, Some(columns));
+    Ok(())
+}
+
+#[test]
+fn round_trip_columns() -> Result<()> {
+    round_trip(WriteOptions::default())
+}
+
+#[cfg(feature = "io_ipc_compression")]
+#[test]
+fn round_trip_compressed() -> Result<()> {
+    use arrow2::io::ipc::write::Compression;
+    round_trip(WriteOptions {
+        compression: Some(Compression::LZ4),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn every_dictionary_is_encoded() -> Result<()> {
+    let (schema, columns) = columns()?;
+    let ipc_fields = default_ipc_fields(schema.fields());
+    let options = WriteOptions::default();
+
+    let first = columns_to_encoded_data(&columns, &ipc_fields, &options)?;
+    let second = columns_to_encoded_data(&columns, &ipc_fields, &options)?;
+    assert_eq!(first, second);
+    Ok(())
+}
+
+#[test]
+fn errors() -> Result<()> {
+    let (schema, columns) = columns()?;
+    let ipc_fields = default_ipc_fields(schema.fields());
+    let message = schema_to_bytes(&schema, &ipc_fields);
+    let ipc_schema = fb_to_schema(
+        root_as_message(&message)
+            .unwrap()
+            .header_as_schema()
+            .unwrap(),
+    )?
+    .1;
+    let schema = Arc::new(schema);
+    let mut dictionaries = Dictionaries::new();
+
+    // a schema is not a batch
+    assert!(decode_message(&message, &[], &schema, &ipc_schema, &mut dictionaries).is_err());
+    // invalid header
+    assert!(decode_message(&[1, 2], &[], &schema, &ipc_schema, &mut dictionaries).is_err());
+
+    // the dictionaries must be decoded before the batch
+    let (_, batch) = columns_to_encoded_data(&columns, &ipc_fields, &WriteOptions::default())?;
+    assert!(decode_message(
+        &batch.ipc_message,
+        &batch.arrow_data,
+        &schema,
+        &ipc_schema,
+        &mut dictionaries
+    )
+    .is_err());
+    Ok(())
+}
@@ -1,5 +1,7 @@
 mod common;
+mod message;
 mod read;
+mod schema;
 mod write;
 
 pub use common::read_gzip_json;
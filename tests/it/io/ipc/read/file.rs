@@ -1,5 +1,8 @@
 use std::fs::File;
+use std::sync::Arc;
 
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field, Schema, UnionMode};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
 
@@ -180,3 +183,57 @@ fn read_projected() -> Result<()> {
     test_projection("1.0.0-littleendian", "generated_dictionary", 2)?;
     test_projection("1.0.0-littleendian", "generated_nested", 0)
 }
+
+/// Builds `[1, "a", None, 2]` as a union of an `Int32` and a `Utf8` field whose types are `ids`.
+fn union(data_type: DataType, ids: [i8; 2]) -> Arc<dyn Array> {
+    let fields: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ];
+    let mut array = MutableUnionArray::new(data_type, fields);
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(Some(1));
+    array.push(ids[0]);
+    array
+        .value::<MutableUtf8Array<i32>>(1)
+        .unwrap()
+        .push(Some("a"));
+    array.push(ids[1]);
+    array.push_null();
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(Some(2));
+    array.push(ids[0]);
+    array.as_arc()
+}
+
+/// Reads the file written by `ipc_integration/write_union.py`.
+#[test]
+fn read_pyarrow_union() -> Result<()> {
+    let mut file = File::open("fixtures/pyarrow3/ipc/union.arrow_file")?;
+    let metadata = read_file_metadata(&mut file)?;
+    let reader = FileReader::new(file, metadata, None);
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let sparse = DataType::Union(fields.clone(), Some(vec![0, 1]), UnionMode::Sparse);
+    let dense = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Dense);
+    let schema = Schema::new(vec![
+        Field::new("sparse", sparse.clone(), true),
+        Field::new("dense", dense.clone(), true),
+    ]);
+    assert_eq!(reader.schema().as_ref(), &schema);
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches.len(), 1);
+    assert_eq!(
+        batches[0].columns(),
+        &[union(sparse, [0, 1]), union(dense, [5, 7])]
+    );
+    Ok(())
+}
@@ -100,3 +100,208 @@ fn read_generated_200_compression_lz4() -> Result<()> {
 fn read_generated_200_compression_zstd() -> Result<()> {
     test_file("2.0.0-compression", "generated_zstd")
 }
+
+/// A non-blocking [`std::io::Read`] that returns a single byte per read, alternated with
+/// [`std::io::ErrorKind::WouldBlock`] after its first `blocking_from` bytes.
+struct Trickle {
+    data: Vec<u8>,
+    position: usize,
+    blocking_from: usize,
+    blocked: bool,
+}
+
+impl std::io::Read for Trickle {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.position >= self.blocking_from {
+            self.blocked = !self.blocked;
+            if self.blocked {
+                return Err(std::io::ErrorKind::WouldBlock.into());
+            }
+        }
+        if self.position == self.data.len() || buf.is_empty() {
+            return Ok(0);
+        }
+        buf[0] = self.data[self.position];
+        self.position += 1;
+        Ok(1)
+    }
+}
+
+#[test]
+fn read_byte_by_byte() -> Result<()> {
+    use std::sync::Arc;
+
+    use arrow2::array::*;
+    use arrow2::datatypes::{Field, Schema};
+    use arrow2::io::ipc::write::{default_ipc_fields, StreamWriter, WriteOptions};
+    use arrow2::record_batch::RecordBatch;
+
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary.try_extend(vec![Some("a"), None, Some("b")])?;
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", arrow2::datatypes::DataType::Int32, true),
+        Field::new("b", dictionary.data_type().clone(), true),
+    ]));
+    let ipc_fields = default_ipc_fields(schema.fields());
+    let batches = (0..3)
+        .map(|i| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from(&[Some(i), None, Some(i + 1)])),
+                    Arc::new(dictionary.clone()),
+                ],
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut data = vec![];
+    let mut writer = StreamWriter::new(&mut data, WriteOptions::default());
+    writer.start(&schema, &ipc_fields)?;
+    let schema_length = data.len();
+    let mut writer = StreamWriter::new(&mut data, WriteOptions::default());
+    for batch in &batches {
+        writer.write(batch, &ipc_fields)?;
+    }
+    writer.finish()?;
+
+    let mut reader = Trickle {
+        data,
+        position: 0,
+        blocking_from: schema_length,
+        blocked: false,
+    };
+    let metadata = read_stream_metadata(&mut reader)?;
+    let mut reader = StreamReader::new(reader, metadata);
+
+    let mut result = vec![];
+    let mut waits = 0;
+    for state in &mut reader {
+        match state? {
+            StreamState::Some(batch) => result.push(batch),
+            StreamState::Waiting => waits += 1,
+        }
+    }
+    assert!(reader.is_finished());
+    assert!(waits > 0);
+    assert_eq!(result, batches);
+    Ok(())
+}
+
+/// A [`std::io::Read`] over bytes that are appended while it is being read, e.g. a file that is
+/// being written to.
+struct Growing {
+    data: std::rc::Rc<std::cell::RefCell<Vec<u8>>>,
+    position: usize,
+}
+
+impl std::io::Read for Growing {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let data = self.data.borrow();
+        let length = buf.len().min(data.len() - self.position);
+        buf[..length].copy_from_slice(&data[self.position..self.position + length]);
+        self.position += length;
+        Ok(length)
+    }
+}
+
+#[test]
+fn read_partial_message() -> Result<()> {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use arrow2::array::*;
+    use arrow2::datatypes::{DataType, Field, Schema};
+    use arrow2::io::ipc::write::{default_ipc_fields, StreamWriter, WriteOptions};
+    use arrow2::record_batch::RecordBatch;
+
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+    let ipc_fields = default_ipc_fields(schema.fields());
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(Int64Array::from(&[Some(1), None]))],
+    )?;
+
+    let mut data = vec![];
+    let mut writer = StreamWriter::new(&mut data, WriteOptions::default());
+    writer.start(&schema, &ipc_fields)?;
+    let schema_length = data.len();
+    let mut writer = StreamWriter::new(&mut data, WriteOptions::default());
+    writer.write(&batch, &ipc_fields)?;
+    writer.finish()?;
+
+    let available = Rc::new(RefCell::new(data[..schema_length].to_vec()));
+    let mut reader = Growing {
+        data: available.clone(),
+        position: 0,
+    };
+    let metadata = read_stream_metadata(&mut reader)?;
+    let mut reader = StreamReader::new(reader, metadata);
+
+    // no bytes, a partial length, a partial header and a partial body
+    for end in [
+        schema_length,
+        schema_length + 2,
+        schema_length + 12,
+        data.len() - 12,
+    ] {
+        let start = available.borrow().len();
+        available.borrow_mut().extend_from_slice(&data[start..end]);
+        assert!(matches!(reader.next(), Some(Ok(StreamState::Waiting))));
+    }
+
+    // the rest of the batch and the end of the stream
+    available
+        .borrow_mut()
+        .extend_from_slice(&data[data.len() - 12..]);
+    let result = reader.next().unwrap()?.unwrap();
+    assert_eq!(result, batch);
+    assert!(reader.next().is_none());
+    assert!(reader.is_finished());
+    Ok(())
+}
+
+#[test]
+fn read_invalid_message() -> Result<()> {
+    use std::sync::Arc;
+
+    use arrow2::array::*;
+    use arrow2::datatypes::{DataType, Field, Schema};
+    use arrow2::io::ipc::write::{default_ipc_fields, StreamWriter, WriteOptions};
+    use arrow2::record_batch::RecordBatch;
+
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+    let ipc_fields = default_ipc_fields(schema.fields());
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(Int64Array::from(&[Some(1), None]))],
+    )?;
+
+    let mut data = vec![];
+    let mut writer = StreamWriter::new(&mut data, WriteOptions::default());
+    writer.start(&schema, &ipc_fields)?;
+    let schema_length = data.len();
+    let mut writer = StreamWriter::new(&mut data, WriteOptions::default());
+    writer.write(&batch, &ipc_fields)?;
+    writer.write(&batch, &ipc_fields)?;
+    writer.finish()?;
+
+    // a negative length and a header that is not a flatbuffer
+    let mut negative = data.clone();
+    negative[schema_length + 4..schema_length + 8].copy_from_slice(&(-8i32).to_le_bytes());
+    let mut invalid = data.clone();
+    invalid[schema_length + 8..schema_length + 16].copy_from_slice(&[0xff; 8]);
+
+    for data in [negative, invalid] {
+        let mut reader = std::io::Cursor::new(data);
+        let metadata = read_stream_metadata(&mut reader)?;
+        let mut reader = StreamReader::new(reader, metadata);
+        assert!(reader.next().unwrap().is_err());
+        // the position of the next message is unknown
+        assert!(reader.next().is_none());
+        assert!(reader.is_finished());
+    }
+    Ok(())
+}
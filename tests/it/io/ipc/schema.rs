@@ -0,0 +1,304 @@
+use std::collections::{BTreeMap, HashMap};
+
+use arrow2::datatypes::*;
+use arrow2::error::{ArrowError, Result};
+use arrow2::io::ipc::read::{fb_to_schema, read_stream_metadata};
+use arrow2::io::ipc::write::{default_ipc_fields, schema_to_bytes};
+use arrow_format::ipc::Message::root_as_message;
+
+fn round_trip(schema: Schema) -> Result<()> {
+    let ipc_fields = default_ipc_fields(&schema.fields);
+    let bytes = schema_to_bytes(&schema, &ipc_fields);
+
+    let message = root_as_message(&bytes).unwrap();
+    let (result, result_ipc_fields) = fb_to_schema(message.header_as_schema().unwrap())?;
+    assert_eq!(result, schema);
+    assert_eq!(result_ipc_fields.fields.len(), ipc_fields.len());
+    Ok(())
+}
+
+fn data_types() -> Vec<DataType> {
+    let units = [
+        TimeUnit::Second,
+        TimeUnit::Millisecond,
+        TimeUnit::Microsecond,
+        TimeUnit::Nanosecond,
+    ];
+    let mut data_types = vec![
+        DataType::Null,
+        DataType::Boolean,
+        DataType::Int8,
+        DataType::Int16,
+        DataType::Int32,
+        DataType::Int64,
+        DataType::UInt8,
+        DataType::UInt16,
+        DataType::UInt32,
+        DataType::UInt64,
+        DataType::Float16,
+        DataType::Float32,
+        DataType::Float64,
+        DataType::Date32,
+        DataType::Date64,
+        DataType::Time32(TimeUnit::Second),
+        DataType::Time32(TimeUnit::Millisecond),
+        DataType::Time64(TimeUnit::Microsecond),
+        DataType::Time64(TimeUnit::Nanosecond),
+        DataType::Interval(IntervalUnit::YearMonth),
+        DataType::Interval(IntervalUnit::DayTime),
+        DataType::Interval(IntervalUnit::MonthDayNano),
+        DataType::Binary,
+        DataType::LargeBinary,
+        DataType::FixedSizeBinary(7),
+        DataType::Utf8,
+        DataType::LargeUtf8,
+        DataType::Decimal(10, 6),
+        DataType::Decimal(38, 0),
+    ];
+    for unit in units {
+        data_types.push(DataType::Timestamp(unit, None));
+        data_types.push(DataType::Timestamp(unit, Some("+01:00".to_string())));
+        data_types.push(DataType::Duration(unit));
+    }
+    data_types
+}
+
+fn nested_data_types() -> Vec<DataType> {
+    let struct_ = DataType::Struct(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::LargeBinary, false),
+    ]);
+    let entries = DataType::Struct(vec![
+        Field::new("keys", DataType::Utf8, false),
+        Field::new("values", DataType::Duration(TimeUnit::Millisecond), true),
+    ]);
+    vec![
+        DataType::List(Box::new(Field::new("item", DataType::Int32, false))),
+        DataType::List(Box::new(Field::new("custom", DataType::Utf8, true))),
+        DataType::LargeList(Box::new(Field::new("item", DataType::Date64, true))),
+        DataType::LargeList(Box::new(Field::new("s", struct_.clone(), false))),
+        DataType::FixedSizeList(Box::new(Field::new("item", DataType::Float32, false)), 3),
+        DataType::FixedSizeList(
+            Box::new(Field::new(
+                "inner",
+                DataType::FixedSizeList(Box::new(Field::new("x", DataType::Int8, true)), 2),
+                false,
+            )),
+            5,
+        ),
+        struct_.clone(),
+        DataType::Struct(vec![
+            Field::new("nested", struct_.clone(), false),
+            Field::new(
+                "list",
+                DataType::List(Box::new(Field::new("item", struct_.clone(), true))),
+                true,
+            ),
+        ]),
+        DataType::Map(
+            Box::new(Field::new("entries", entries.clone(), false)),
+            false,
+        ),
+        DataType::Map(Box::new(Field::new("entries", entries, false)), true),
+        DataType::Union(
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ],
+            None,
+            UnionMode::Dense,
+        ),
+        DataType::Union(
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", struct_.clone(), true),
+            ],
+            Some(vec![4, 1]),
+            UnionMode::Sparse,
+        ),
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+        DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::LargeBinary), true),
+        DataType::Dictionary(IntegerType::Int64, Box::new(DataType::UInt32), false),
+        DataType::Extension(
+            "ext".to_string(),
+            Box::new(DataType::Duration(TimeUnit::Second)),
+            Some("metadata".to_string()),
+        ),
+        DataType::Extension("ext".to_string(), Box::new(DataType::Int32), None),
+        DataType::Extension("ext".to_string(), Box::new(struct_.clone()), None),
+        DataType::List(Box::new(Field::new(
+            "item",
+            DataType::Dictionary(IntegerType::UInt16, Box::new(DataType::Utf8), false),
+            true,
+        ))),
+        DataType::Dictionary(
+            IntegerType::Int16,
+            Box::new(DataType::Extension(
+                "ext".to_string(),
+                Box::new(DataType::Utf8),
+                None,
+            )),
+            false,
+        ),
+    ]
+}
+
+#[test]
+fn every_data_type() -> Result<()> {
+    let fields = data_types()
+        .into_iter()
+        .chain(nested_data_types())
+        .enumerate()
+        .map(|(i, data_type)| Field::new(&format!("f{}", i), data_type, i % 2 == 0))
+        .collect();
+    round_trip(Schema::new(fields))
+}
+
+#[test]
+fn each_data_type() -> Result<()> {
+    for data_type in data_types().into_iter().chain(nested_data_types()) {
+        for nullable in [false, true] {
+            round_trip(Schema::new(vec![Field::new(
+                "a",
+                data_type.clone(),
+                nullable,
+            )]))?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn metadata() -> Result<()> {
+    let mut field_metadata = BTreeMap::new();
+    field_metadata.insert("k".to_string(), "v".to_string());
+    let field = Field::new(
+        "a",
+        DataType::List(Box::new(
+            Field::new("item", DataType::Int32, false).with_metadata(field_metadata.clone()),
+        )),
+        true,
+    )
+    .with_metadata(field_metadata);
+
+    let mut metadata = HashMap::new();
+    metadata.insert("key".to_string(), "value".to_string());
+    round_trip(Schema::new_from(vec![field], metadata))
+}
+
+/// Returns an IPC schema message with a nullable decimal field for each
+/// `(name, precision, scale, bit_width)`, laid out as pyarrow writes
+/// `decimal128` and `decimal256` fields.
+fn decimal_schema(fields: &[(&str, i32, i32, i32)]) -> Vec<u8> {
+    use arrow_format::ipc::flatbuffers::FlatBufferBuilder;
+    use arrow_format::ipc::Message::{MessageBuilder, MessageHeader};
+    use arrow_format::ipc::Schema::{
+        DecimalBuilder, Endianness, Field as IpcField, FieldBuilder, MetadataVersion,
+        SchemaBuilder, Type,
+    };
+
+    let mut fbb = FlatBufferBuilder::new();
+    let fields = fields
+        .iter()
+        .map(|(name, precision, scale, bit_width)| {
+            let name = fbb.create_string(name);
+            let children =
+                fbb.create_vector::<arrow_format::ipc::flatbuffers::WIPOffset<IpcField>>(&[]);
+            let mut decimal = DecimalBuilder::new(&mut fbb);
+            decimal.add_precision(*precision);
+            decimal.add_scale(*scale);
+            decimal.add_bitWidth(*bit_width);
+            let decimal = decimal.finish().as_union_value();
+
+            let mut field = FieldBuilder::new(&mut fbb);
+            field.add_name(name);
+            field.add_nullable(true);
+            field.add_type_type(Type::Decimal);
+            field.add_type_(decimal);
+            field.add_children(children);
+            field.finish()
+        })
+        .collect::<Vec<_>>();
+    let fields = fbb.create_vector(&fields);
+
+    let mut schema = SchemaBuilder::new(&mut fbb);
+    schema.add_endianness(Endianness::Little);
+    schema.add_fields(fields);
+    let schema = schema.finish().as_union_value();
+
+    let mut message = MessageBuilder::new(&mut fbb);
+    message.add_version(MetadataVersion::V5);
+    message.add_header_type(MessageHeader::Schema);
+    message.add_bodyLength(0);
+    message.add_header(schema);
+    let message = message.finish();
+    fbb.finish(message, None);
+    fbb.finished_data().to_vec()
+}
+
+fn read_schema(bytes: &[u8]) -> Result<Schema> {
+    let message = root_as_message(bytes).unwrap();
+    fb_to_schema(message.header_as_schema().unwrap()).map(|x| x.0)
+}
+
+#[test]
+fn decimal_bit_width() -> Result<()> {
+    let bytes = decimal_schema(&[("a", 10, 2, 128), ("b", 38, 0, 128)]);
+    let schema = read_schema(&bytes)?;
+    assert_eq!(
+        schema.fields,
+        vec![
+            Field::new("a", DataType::Decimal(10, 2), true),
+            Field::new("b", DataType::Decimal(38, 0), true),
+        ]
+    );
+
+    // 256-bit decimals would be read with the wrong offsets
+    let bytes = decimal_schema(&[("a", 10, 2, 128), ("b", 40, 2, 256)]);
+    assert!(matches!(
+        read_schema(&bytes),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+    let bytes = decimal_schema(&[("a", 10, 2, 256)]);
+    assert!(read_schema(&bytes).is_err());
+
+    let bytes = decimal_schema(&[("a", 10, 2, 64)]);
+    assert!(matches!(read_schema(&bytes), Err(ArrowError::OutOfSpec(_))));
+    Ok(())
+}
+
+#[test]
+fn decimal_precision() -> Result<()> {
+    for precision in [0, 39, -1] {
+        let bytes = decimal_schema(&[("a", precision, 0, 128)]);
+        assert!(matches!(read_schema(&bytes), Err(ArrowError::OutOfSpec(_))));
+    }
+    let bytes = decimal_schema(&[("a", 10, -2, 128)]);
+    assert!(read_schema(&bytes).is_err());
+    Ok(())
+}
+
+/// Reads a schema message written by `ipc_integration/write_schema.py`.
+fn read_pyarrow_schema(name: &str) -> Result<Schema> {
+    let path = format!("fixtures/pyarrow3/ipc/{}.schema", name);
+    let mut file = std::fs::File::open(path).unwrap();
+    read_stream_metadata(&mut file).map(|metadata| metadata.schema.as_ref().clone())
+}
+
+#[test]
+fn decimal_pyarrow() -> Result<()> {
+    let schema = read_pyarrow_schema("decimal128")?;
+    assert_eq!(
+        schema.fields,
+        vec![
+            Field::new("a", DataType::Decimal(10, 2), true),
+            Field::new("b", DataType::Decimal(38, 0), true),
+        ]
+    );
+
+    assert!(matches!(
+        read_pyarrow_schema("decimal256"),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+    Ok(())
+}
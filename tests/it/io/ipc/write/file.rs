@@ -1,8 +1,9 @@
+use std::collections::HashMap;
 use std::io::Cursor;
 
 use arrow2::array::*;
 use arrow2::datatypes::Schema;
-use arrow2::error::Result;
+use arrow2::error::{ArrowError, Result};
 use arrow2::io::ipc::read::{read_file_metadata, FileReader};
 use arrow2::io::ipc::{write::*, IpcField};
 use arrow2::record_batch::RecordBatch;
@@ -16,7 +17,10 @@ fn write_(
     compression: Option<Compression>,
 ) -> Result<Vec<u8>> {
     let result = vec![];
-    let options = WriteOptions { compression };
+    let options = WriteOptions {
+        compression,
+        ..Default::default()
+    };
     let mut writer = FileWriter::try_new(result, schema, ipc_fields.clone(), options)?;
     for batch in batches {
         writer.write(batch, ipc_fields.as_ref().map(|x| x.as_ref()))?;
@@ -357,3 +361,412 @@ fn write_sliced_list() -> Result<()> {
     let batch = RecordBatch::try_from_iter(vec![("a", array)]).unwrap();
     round_trip(batch, None)
 }
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_sliced_union() -> Result<()> {
+    use arrow2::buffer::Buffer;
+    use arrow2::datatypes::{DataType, Field, UnionMode};
+    use std::sync::Arc;
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let fields_values = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+    ];
+    let sparse = UnionArray::from_data(
+        DataType::Union(fields.clone(), None, UnionMode::Sparse),
+        Buffer::from_slice([0, 0, 1]),
+        fields_values.clone(),
+        None,
+    );
+    let dense = UnionArray::from_data(
+        DataType::Union(fields, None, UnionMode::Dense),
+        Buffer::from_slice([0, 0, 1]),
+        fields_values,
+        Some(Buffer::from_slice([0, 1, 2])),
+    );
+
+    let batch = RecordBatch::try_from_iter(vec![
+        ("a", Arc::new(sparse.slice(1, 2)) as Arc<dyn Array>),
+        ("b", Arc::new(dense.slice(1, 2)) as Arc<dyn Array>),
+    ])?;
+    round_trip(batch, None)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_extension() -> Result<()> {
+    use arrow2::datatypes::DataType;
+    use std::sync::Arc;
+
+    let data_type = DataType::Extension(
+        "uuid".to_string(),
+        Box::new(DataType::FixedSizeBinary(2)),
+        Some("metadata".to_string()),
+    );
+    let array = FixedSizeBinaryArray::from_data(
+        data_type,
+        vec![1u8, 2, 3, 4, 5, 6].into(),
+        Some([true, false, true].into()),
+    );
+    let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as Arc<dyn Array>)])?;
+    round_trip(batch, None)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_custom_metadata() -> Result<()> {
+    use arrow2::datatypes::{DataType, Field, Metadata};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    let mut field_metadata = Metadata::new();
+    field_metadata.insert("unit".to_string(), "meters".to_string());
+    let mut child_metadata = Metadata::new();
+    child_metadata.insert("origin".to_string(), "sensor".to_string());
+    let mut schema_metadata = HashMap::new();
+    schema_metadata.insert("source".to_string(), "tests".to_string());
+
+    let child = Field::new("item", DataType::Int32, true).with_metadata(child_metadata);
+    let list = ListArray::<i32>::from_data(
+        DataType::List(Box::new(child.clone())),
+        vec![0, 1, 3].into(),
+        Arc::new(Int32Array::from_slice(&[1, 2, 3])),
+        None,
+    );
+
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, false).with_metadata(field_metadata),
+        Field::new("b", DataType::List(Box::new(child)), false),
+    ])
+    .with_metadata(schema_metadata);
+
+    let batch = RecordBatch::try_new(
+        Arc::new(schema),
+        vec![Arc::new(Int32Array::from_slice(&[1, 2])), Arc::new(list)],
+    )?;
+    round_trip(batch, None)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_ordered_dictionary() -> Result<()> {
+    use arrow2::array::to_data_type;
+    use arrow2::datatypes::{DataType, Field, IntegerType, Metadata};
+    use std::sync::Arc;
+
+    let mut metadata = Metadata::new();
+    metadata.insert("pii".to_string(), "true".to_string());
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), true);
+
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(vec![Some("a"), None, Some("b"), Some("a")])?;
+    let array: DictionaryArray<i32> = array.into();
+    let array = to_data_type(&array, data_type.clone())?;
+
+    let schema = Schema::new(vec![
+        Field::new("a", data_type, true).with_metadata(metadata)
+    ]);
+    let batch = RecordBatch::try_new(Arc::new(schema), vec![array.into()])?;
+    round_trip(batch, None)
+}
+
+#[test]
+fn write_f16() -> Result<()> {
+    use arrow2::types::f16;
+    use std::sync::Arc;
+
+    let array = [Some(1.5), None, Some(-0.25), Some(f32::INFINITY)]
+        .iter()
+        .map(|x| x.map(f16::from_f32))
+        .collect::<PrimitiveArray<f16>>();
+    let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as Arc<dyn Array>)])?;
+    round_trip(batch, None)
+}
+
+fn write_with_options(batch: &RecordBatch, options: WriteOptions) -> Result<Vec<u8>> {
+    let mut writer = FileWriter::try_new(vec![], batch.schema(), None, options)?;
+    writer.write(batch, None)?;
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn write_max_message_size() -> Result<()> {
+    use arrow2::error::ArrowError;
+    use std::sync::Arc;
+
+    let batch = RecordBatch::try_from_iter(vec![(
+        "a",
+        Arc::new(Int32Array::from_values(0..100)) as Arc<dyn Array>,
+    )])?;
+    let options = WriteOptions {
+        max_message_size: Some(128),
+        ..Default::default()
+    };
+    assert!(matches!(
+        write_with_options(&batch, options),
+        Err(ArrowError::OutOfSpec(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn write_auto_chunk() -> Result<()> {
+    use std::sync::Arc;
+
+    let array = (0..100)
+        .map(|x| if x % 7 == 0 { None } else { Some(x) })
+        .collect::<Int32Array>();
+    let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array.clone()) as Arc<dyn Array>)])?;
+    let options = WriteOptions {
+        max_message_size: Some(128),
+        auto_chunk: true,
+        ..Default::default()
+    };
+    let result = write_with_options(&batch, options)?;
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_file_metadata(&mut reader)?;
+    let batches = FileReader::new(reader, metadata, None).collect::<Result<Vec<_>>>()?;
+    assert!(batches.len() > 1);
+
+    let values = batches
+        .iter()
+        .flat_map(|batch| {
+            let array = batch.column(0);
+            let array = array.as_any().downcast_ref::<Int32Array>().unwrap();
+            array.iter().map(|x| x.copied()).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(values, array.iter().map(|x| x.copied()).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn write_auto_chunk_nested() -> Result<()> {
+    use arrow2::datatypes::{DataType, Field};
+    use std::sync::Arc;
+
+    let utf8 = Utf8Array::<i32>::from(&[Some("a"), None, Some("bbb"), Some("cc"), Some("")]);
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![
+        Some(vec![Some(1), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4), Some(5), Some(6)]),
+        Some(vec![Some(7)]),
+    ])?;
+    let list: ListArray<i32> = list.into();
+    let boolean = BooleanArray::from(&[Some(true), None, Some(false), Some(true), None]);
+    let fields = vec![
+        Field::new("b", DataType::Boolean, true),
+        Field::new("u", DataType::Utf8, true),
+    ];
+    let values: Vec<Arc<dyn Array>> = vec![Arc::new(boolean), Arc::new(utf8.clone())];
+    let structs = StructArray::from_data(DataType::Struct(fields), values, None);
+    let batch = RecordBatch::try_from_iter(vec![
+        ("a", Arc::new(utf8.slice(1, 4)) as Arc<dyn Array>),
+        ("b", Arc::new(list.slice(1, 4)) as Arc<dyn Array>),
+        ("c", Arc::new(structs.slice(1, 4)) as Arc<dyn Array>),
+    ])?;
+
+    let columns = batch.clone().into();
+    let ipc_fields = default_ipc_fields(batch.schema().fields());
+    let (_, encoded) = columns_to_encoded_data(&columns, &ipc_fields, &Default::default())?;
+    let size = encoded.arrow_data.len();
+
+    let num_batches = |max_message_size| -> Result<usize> {
+        let options = WriteOptions {
+            max_message_size: Some(max_message_size),
+            auto_chunk: true,
+            ..Default::default()
+        };
+        let result = write_with_options(&batch, options)?;
+        let mut reader = Cursor::new(result);
+        let metadata = read_file_metadata(&mut reader)?;
+        let batches = FileReader::new(reader, metadata, None).collect::<Result<Vec<_>>>()?;
+        let columns = batches
+            .iter()
+            .map(|batch| batch.num_rows())
+            .collect::<Vec<_>>();
+        assert_eq!(columns.iter().sum::<usize>(), 4);
+        Ok(batches.len())
+    };
+    // the size of a batch is known before it is encoded
+    assert_eq!(num_batches(size)?, 1);
+    assert!(num_batches(size - 8)? > 1);
+    Ok(())
+}
+
+#[test]
+fn write_duplicate_field_names() -> Result<()> {
+    use arrow2::datatypes::{DataType, Field};
+
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new(
+            "b",
+            DataType::Struct(vec![
+                Field::new("c", DataType::Int32, false),
+                Field::new("c", DataType::Utf8, false),
+            ]),
+            false,
+        ),
+    ]);
+    let options = WriteOptions {
+        unique_field_names: true,
+        ..Default::default()
+    };
+    assert!(FileWriter::try_new(vec![], &schema, None, options).is_err());
+
+    // allowed by default
+    assert!(FileWriter::try_new(vec![], &schema, None, WriteOptions::default()).is_ok());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_null() -> Result<()> {
+    use arrow2::datatypes::DataType;
+    use std::sync::Arc;
+    let array = Arc::new(NullArray::new_null(DataType::Null, 3)) as Arc<dyn Array>;
+    let values = Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let batch = RecordBatch::try_from_iter(vec![("a", array), ("b", values)])?;
+    round_trip(batch, None)
+}
+
+fn batch_with_dictionary(values: &[i32]) -> Result<RecordBatch> {
+    use std::sync::Arc;
+
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary.try_extend(
+        values
+            .iter()
+            .map(|x| Some(if x % 2 == 0 { "even" } else { "odd" })),
+    )?;
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+
+    RecordBatch::try_from_iter(vec![
+        (
+            "a",
+            Arc::new(Int32Array::from_slice(values)) as Arc<dyn Array>,
+        ),
+        ("b", Arc::new(dictionary) as Arc<dyn Array>),
+    ])
+}
+
+fn read_all(data: Vec<u8>) -> Result<(HashMap<String, String>, Vec<RecordBatch>)> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let custom_metadata = metadata.custom_metadata().clone();
+    let batches = FileReader::new(reader, metadata, None).collect::<Result<Vec<_>>>()?;
+    Ok((custom_metadata, batches))
+}
+
+#[test]
+fn write_footer_custom_metadata() -> Result<()> {
+    let batch = batch_with_dictionary(&[1, 2])?;
+    let mut custom_metadata = HashMap::new();
+    custom_metadata.insert("checkpoint".to_string(), "1".to_string());
+
+    let mut writer = FileWriter::try_new(vec![], batch.schema(), None, Default::default())?;
+    writer.set_custom_metadata(custom_metadata.clone());
+    writer.write(&batch, None)?;
+    writer.finish()?;
+
+    let (result, batches) = read_all(writer.into_inner())?;
+    assert_eq!(result, custom_metadata);
+    // the footer's metadata is not the schema's metadata
+    assert!(batches[0].schema().metadata().is_empty());
+    Ok(())
+}
+
+#[test]
+fn append() -> Result<()> {
+    let batches = [[1, 2], [3, 4], [5, 6], [7, 8]]
+        .iter()
+        .map(|values| batch_with_dictionary(values))
+        .collect::<Result<Vec<_>>>()?;
+    let mut custom_metadata = HashMap::new();
+    custom_metadata.insert("checkpoint".to_string(), "1".to_string());
+
+    let mut writer = FileWriter::try_new(vec![], batches[0].schema(), None, Default::default())?;
+    writer.set_custom_metadata(custom_metadata.clone());
+    writer.write(&batches[0], None)?;
+    writer.write(&batches[1], None)?;
+    writer.finish()?;
+
+    let mut writer =
+        FileWriter::try_from_existing(Cursor::new(writer.into_inner()), Default::default())?;
+    assert_eq!(writer.custom_metadata(), &custom_metadata);
+    custom_metadata.insert("checkpoint".to_string(), "2".to_string());
+    writer.set_custom_metadata(custom_metadata.clone());
+    writer.write(&batches[2], None)?;
+    writer.write(&batches[3], None)?;
+    writer.finish()?;
+
+    let (result_metadata, result) = read_all(writer.into_inner().into_inner())?;
+    assert_eq!(result_metadata, custom_metadata);
+    assert_eq!(result, batches);
+    Ok(())
+}
+
+#[test]
+fn append_to_empty() -> Result<()> {
+    let batch = batch_with_dictionary(&[1, 2])?;
+
+    let mut writer = FileWriter::try_new(vec![], batch.schema(), None, Default::default())?;
+    writer.finish()?;
+
+    let mut writer =
+        FileWriter::try_from_existing(Cursor::new(writer.into_inner()), Default::default())?;
+    writer.write(&batch, None)?;
+    writer.finish()?;
+
+    let (_, result) = read_all(writer.into_inner().into_inner())?;
+    assert_eq!(result, vec![batch]);
+    Ok(())
+}
+
+#[test]
+fn append_invalid() -> Result<()> {
+    use std::sync::Arc;
+
+    let batch = batch_with_dictionary(&[1, 3])?;
+    let mut writer = FileWriter::try_new(vec![], batch.schema(), None, Default::default())?;
+    writer.write(&batch, None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    // a different dictionary than the one already written
+    let mut writer = FileWriter::try_from_existing(Cursor::new(data.clone()), Default::default())?;
+    assert!(writer.write(&batch_with_dictionary(&[2])?, None).is_err());
+
+    // a different schema
+    let mut writer = FileWriter::try_from_existing(Cursor::new(data), Default::default())?;
+    let other = RecordBatch::try_from_iter(vec![(
+        "a",
+        Arc::new(Int64Array::from_slice([1])) as Arc<dyn Array>,
+    )])?;
+    assert!(writer.write(&other, None).is_err());
+
+    // not an Arrow file
+    assert!(FileWriter::try_from_existing(Cursor::new(vec![0; 16]), Default::default()).is_err());
+
+    // a file without batches whose schema message has a negative length
+    let mut writer = FileWriter::try_new(vec![], batch.schema(), None, Default::default())?;
+    writer.finish()?;
+    let mut data = writer.into_inner();
+    // the header (8 bytes) is followed by the continuation marker and the message's length
+    data[12..16].copy_from_slice(&(-1i32).to_le_bytes());
+    assert!(matches!(
+        FileWriter::try_from_existing(Cursor::new(data), Default::default()),
+        Err(ArrowError::OutOfSpec(_))
+    ));
+    Ok(())
+}
@@ -14,7 +14,10 @@ use crate::io::ipc::common::read_gzip_json;
 fn write_(schema: &Schema, ipc_fields: &[IpcField], batches: &[RecordBatch]) -> Vec<u8> {
     let mut result = vec![];
 
-    let options = WriteOptions { compression: None };
+    let options = WriteOptions {
+        compression: None,
+        ..Default::default()
+    };
     let mut writer = StreamWriter::new(&mut result, options);
     writer.start(schema, ipc_fields).unwrap();
     for batch in batches {
@@ -141,3 +144,54 @@ fn write_100_custom_metadata() {
 fn write_100_decimal() {
     test_file("1.0.0-littleendian", "generated_decimal");
 }
+
+#[test]
+fn write_auto_chunk() {
+    use std::sync::Arc;
+
+    use arrow2::array::{Array, Utf8Array};
+    use arrow2::io::ipc::write::default_ipc_fields;
+
+    let array = (0..50)
+        .map(|x| Some(x.to_string().repeat(x % 5)))
+        .collect::<Utf8Array<i32>>();
+    let batch =
+        RecordBatch::try_from_iter(vec![("a", Arc::new(array.clone()) as Arc<dyn Array>)]).unwrap();
+    let ipc_fields = default_ipc_fields(batch.schema().fields());
+
+    let mut result = vec![];
+    let options = WriteOptions {
+        max_message_size: Some(100),
+        auto_chunk: true,
+        ..Default::default()
+    };
+    let mut writer = StreamWriter::new(&mut result, options);
+    writer.start(batch.schema(), &ipc_fields).unwrap();
+    writer.write(&batch, &ipc_fields).unwrap();
+    writer.finish().unwrap();
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_stream_metadata(&mut reader).unwrap();
+    let batches = StreamReader::new(reader, metadata)
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<arrow2::error::Result<Vec<_>>>()
+        .unwrap();
+    assert!(batches.len() > 1);
+
+    let values = batches
+        .iter()
+        .flat_map(|batch| {
+            let array = batch.column(0);
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            array
+                .iter()
+                .map(|x| x.map(|x| x.to_string()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let expected = array
+        .iter()
+        .map(|x| x.map(|x| x.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(values, expected);
+}
@@ -18,7 +18,10 @@ async fn write_(
 ) -> Result<Vec<u8>> {
     let mut result = AsyncCursor::new(vec![]);
 
-    let options = stream_async::WriteOptions { compression: None };
+    let options = stream_async::WriteOptions {
+        compression: None,
+        ..Default::default()
+    };
     let mut writer = stream_async::StreamWriter::new(&mut result, options);
     writer.start(schema, Some(ipc_fields)).await?;
     for batch in batches {
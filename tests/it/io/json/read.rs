@@ -92,7 +92,7 @@ fn invalid_read_record() -> Result<()> {
 
     assert_eq!(
         batch.err().unwrap().to_string(),
-        "External error: expected value at line 1 column 1",
+        "Failed to deserialize row 0: External error: expected value at line 1 column 1",
     );
     Ok(())
 }
@@ -248,3 +248,48 @@ fn infer_schema_mixed_list() -> Result<()> {
     assert_eq!(result, fields);
     Ok(())
 }
+
+#[test]
+fn deserialize_error_has_context() {
+    let data = r#"{"a": 1, "b": 1}"#.to_string();
+    let fields = vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Interval(IntervalUnit::DayTime), true),
+    ];
+
+    let error = read_batch(data, fields).unwrap_err();
+    assert!(error.to_string().contains("column \"b\""));
+}
+
+#[test]
+fn timestamp() -> Result<()> {
+    // 2021-01-01T00:00:00Z
+    let instant = 1_609_459_200;
+    let data = r#"{"a": "2021-01-01T02:00:00.123456+02:00", "b": "2021-01-01T00:00:00.123456"}
+{"a": "2021-01-01T00:00:00", "b": null}
+{"a": 1, "b": 1}
+{"a": "2300-01-01T00:00:00Z", "b": "invalid"}"#
+        .to_string();
+    let a = DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".to_string()));
+    let b = DataType::Timestamp(TimeUnit::Millisecond, None);
+    let fields = vec![
+        Field::new("a", a.clone(), true),
+        Field::new("b", b.clone(), true),
+    ];
+
+    let batch = read_batch(data, fields)?;
+
+    let expected = Int64Array::from([
+        Some(instant * 1_000_000_000 + 123_456_000),
+        Some(instant * 1_000_000_000),
+        Some(1),
+        // not representable in nanoseconds
+        None,
+    ])
+    .to(a);
+    assert_eq!(expected, batch.columns()[0].as_ref());
+
+    let expected = Int64Array::from([Some(instant * 1_000 + 123), None, Some(1), None]).to(b);
+    assert_eq!(expected, batch.columns()[1].as_ref());
+    Ok(())
+}
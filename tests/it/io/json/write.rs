@@ -4,7 +4,8 @@ use arrow2::{
     array::*,
     bitmap::Bitmap,
     buffer::Buffer,
-    datatypes::{DataType, Field, Schema},
+    columns::Columns,
+    datatypes::{DataType, Field, Schema, TimeUnit},
     error::Result,
     record_batch::RecordBatch,
 };
@@ -307,3 +308,145 @@ fn write_escaped_utf8() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn write_dictionary() -> Result<()> {
+    // a null key and a key to a null value are both written as `null`
+    let keys = Int32Array::from([Some(1), None, Some(0), Some(2)]);
+    let values = Utf8Array::<i32>::from([Some("a"), Some("b c"), None]);
+    let c1 = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+    let keys = UInt8Array::from_slice([0, 0, 1, 0]);
+    let values = Int64Array::from_slice([-10, 20]);
+    let c2 = DictionaryArray::<u8>::from_data(keys, Arc::new(values));
+
+    let keys = Int16Array::from([Some(1), Some(0), None, Some(1)]);
+    let values = Int32Array::from_slice([0, 18808]).to(DataType::Date32);
+    let c3 = DictionaryArray::<i16>::from_data(keys, Arc::new(values));
+
+    let batch = RecordBatch::try_from_iter(vec![
+        ("c1", Arc::new(c1) as Arc<dyn Array>),
+        ("c2", Arc::new(c2) as Arc<dyn Array>),
+        ("c3", Arc::new(c3) as Arc<dyn Array>),
+    ])?;
+
+    let buf = write_batch(batch, json_write::LineDelimited::default())?;
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"{"c1":"b c","c2":-10,"c3":18808}
+{"c1":null,"c2":-10,"c3":0}
+{"c1":"a","c2":20,"c3":null}
+{"c1":null,"c2":-10,"c3":18808}
+"#
+    );
+    Ok(())
+}
+
+#[test]
+fn write_temporal() -> Result<()> {
+    let c1 = Int32Array::from([Some(18808), None]).to(DataType::Date32);
+    let c2 = Int64Array::from([None, Some(1)]).to(DataType::Timestamp(TimeUnit::Millisecond, None));
+
+    let batch = RecordBatch::try_from_iter(vec![
+        ("c1", Arc::new(c1) as Arc<dyn Array>),
+        ("c2", Arc::new(c2) as Arc<dyn Array>),
+    ])?;
+
+    let buf = write_batch(batch, json_write::LineDelimited::default())?;
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"{"c1":18808,"c2":null}
+{"c1":null,"c2":1}
+"#
+    );
+    Ok(())
+}
+
+#[test]
+fn write_columns_with_inferred_schema() -> Result<()> {
+    let columns = Columns::new(vec![
+        Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])),
+        Arc::new(Int32Array::from_slice([18808, 0]).to(DataType::Date32)),
+    ]);
+    let schema = Schema::try_from_columns(&["c1", "c2", "date"], &columns)?;
+    assert_eq!(schema.fields()[2].data_type(), &DataType::Date32);
+
+    let mut buffer = vec![];
+    json_write::serialize_columns(
+        &schema,
+        &columns,
+        json_write::LineDelimited::default(),
+        &mut buffer,
+    )?;
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        r#"{"c1":1,"c2":"a","date":"2021-06-30"}
+{"c1":null,"c2":"b","date":"1970-01-01"}
+"#
+    );
+
+    let schema = schema.project(&[0, 1])?;
+    let mut buffer = vec![];
+    let format = json_write::LineDelimited::default();
+    assert!(json_write::serialize_columns(&schema, &columns, format, &mut buffer).is_err());
+    Ok(())
+}
+
+#[test]
+fn write_columns_with_logical_types() -> Result<()> {
+    // the arrays are physical; the schema declares how they are rendered
+    let columns = Columns::new(vec![
+        Arc::new(Int32Array::from([Some(18808), None])) as Arc<dyn Array>,
+        Arc::new(Int64Array::from_slice([1625011200000, 0])),
+        Arc::new(Int64Array::from_slice([1625011200000, 0])),
+        Arc::new(Int64Array::from_slice([18808, 0])),
+    ]);
+    let schema = Schema::new(vec![
+        Field::new("date32", DataType::Date32, true),
+        Field::new("date64", DataType::Date64, false),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string())),
+            false,
+        ),
+        Field::new("int", DataType::Int64, false),
+    ]);
+
+    let mut buffer = vec![];
+    json_write::serialize_columns(
+        &schema,
+        &columns,
+        json_write::LineDelimited::default(),
+        &mut buffer,
+    )?;
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        r#"{"date32":"2021-06-30","date64":"2021-06-30","timestamp":"2021-06-30 01:00:00 +01:00","int":18808}
+{"date32":null,"date64":"1970-01-01","timestamp":"1970-01-01 01:00:00 +01:00","int":0}
+"#
+    );
+
+    let schema = Schema::new(vec![Field::new(
+        "timestamp",
+        DataType::Timestamp(TimeUnit::Second, None),
+        false,
+    )]);
+    let columns = Columns::new(vec![
+        Arc::new(Int64Array::from_slice([1625011200])) as Arc<dyn Array>
+    ]);
+    let mut buffer = vec![];
+    json_write::serialize_columns(
+        &schema,
+        &columns,
+        json_write::LineDelimited::default(),
+        &mut buffer,
+    )?;
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "{\"timestamp\":\"2021-06-30 00:00:00\"}\n"
+    );
+    Ok(())
+}
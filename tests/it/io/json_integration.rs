@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::ipc::IpcField;
+use arrow2::io::json_integration::{read, write};
+use arrow2::record_batch::RecordBatch;
+
+#[test]
+fn decimal_roundtrip() -> Result<()> {
+    let data_type = DataType::Decimal(38, 2);
+    let array =
+        Int128Array::from(&[Some(12345), None, Some(-1), Some(i128::MAX)]).to(data_type.clone());
+    let schema = Schema::new(vec![Field::new("a", data_type, true)]);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(array)])?;
+
+    let json_batch = write::from_record_batch(&batch);
+    assert_eq!(
+        json_batch.columns[0].data.as_ref().unwrap()[3],
+        serde_json::Value::String(i128::MAX.to_string())
+    );
+
+    let ipc_fields = vec![IpcField {
+        fields: vec![],
+        dictionary_id: None,
+    }];
+    let result = read::to_record_batch(&schema, &ipc_fields, &json_batch, &HashMap::new())?;
+    assert_eq!(result, batch);
+    Ok(())
+}
@@ -7,12 +7,18 @@ mod json;
 #[cfg(feature = "io_ipc")]
 mod ipc;
 
+#[cfg(feature = "io_json_integration")]
+mod json_integration;
+
 #[cfg(feature = "io_parquet")]
 mod parquet;
 
 #[cfg(feature = "io_avro")]
 mod avro;
 
+#[cfg(feature = "io_orc")]
+mod orc;
+
 #[cfg(any(
     feature = "io_csv_read",
     feature = "io_csv_write",
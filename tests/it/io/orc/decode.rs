@@ -0,0 +1,284 @@
+//! A minimal ORC decoder of the files written by `arrow2::io::orc::write`, used to verify them.
+use std::collections::HashMap;
+
+/// The fields of a protobuf message, by field number: varints or length-delimited bytes
+#[derive(Debug, Default)]
+pub struct Message {
+    pub varints: HashMap<u64, Vec<u64>>,
+    pub bytes: HashMap<u64, Vec<Vec<u8>>>,
+}
+
+impl Message {
+    pub fn uint(&self, field: u64) -> u64 {
+        self.varints.get(&field).map(|x| x[0]).unwrap_or_default()
+    }
+
+    pub fn messages(&self, field: u64) -> Vec<Message> {
+        self.bytes
+            .get(&field)
+            .map(|x| x.iter().map(|x| decode_message(x)).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn strings(&self, field: u64) -> Vec<String> {
+        self.bytes
+            .get(&field)
+            .map(|x| {
+                x.iter()
+                    .map(|x| String::from_utf8(x.clone()).unwrap())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn packed(&self, field: u64) -> Vec<u64> {
+        self.bytes
+            .get(&field)
+            .map(|x| {
+                let data = &x[0];
+                let mut offset = 0;
+                let mut values = vec![];
+                while offset < data.len() {
+                    values.push(read_varint(data, &mut offset));
+                }
+                values
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn read_varint(data: &[u8], offset: &mut usize) -> u64 {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = data[*offset];
+        *offset += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        shift += 7;
+        if byte < 0x80 {
+            return value;
+        }
+    }
+}
+
+pub fn decode_message(data: &[u8]) -> Message {
+    let mut message = Message::default();
+    let mut offset = 0;
+    while offset < data.len() {
+        let key = read_varint(data, &mut offset);
+        match key & 0x7 {
+            0 => {
+                let value = read_varint(data, &mut offset);
+                message.varints.entry(key >> 3).or_default().push(value);
+            }
+            2 => {
+                let length = read_varint(data, &mut offset) as usize;
+                let value = data[offset..offset + length].to_vec();
+                offset += length;
+                message.bytes.entry(key >> 3).or_default().push(value);
+            }
+            other => panic!("unexpected wire type {}", other),
+        }
+    }
+    message
+}
+
+pub fn decompress(data: &[u8], compression: u64) -> Vec<u8> {
+    if compression == 0 {
+        return data.to_vec();
+    }
+    assert_eq!(compression, 1);
+    let mut result = vec![];
+    let mut offset = 0;
+    while offset < data.len() {
+        let header = u32::from_le_bytes([data[offset], data[offset + 1], data[offset + 2], 0]);
+        offset += 3;
+        let length = (header >> 1) as usize;
+        let chunk = &data[offset..offset + length];
+        offset += length;
+        if header & 1 == 1 {
+            result.extend_from_slice(chunk);
+        } else {
+            use std::io::Read;
+            let mut decoder = libflate::deflate::Decoder::new(chunk);
+            decoder.read_to_end(&mut result).unwrap();
+        }
+    }
+    result
+}
+
+pub fn decode_bytes(data: &[u8]) -> Vec<u8> {
+    let mut values = vec![];
+    let mut offset = 0;
+    while offset < data.len() {
+        let control = data[offset] as i8;
+        offset += 1;
+        if control >= 0 {
+            let run = control as usize + 3;
+            values.resize(values.len() + run, data[offset]);
+            offset += 1;
+        } else {
+            let length = (-(control as i16)) as usize;
+            values.extend_from_slice(&data[offset..offset + length]);
+            offset += length;
+        }
+    }
+    values
+}
+
+pub fn decode_booleans(data: &[u8], length: usize) -> Vec<bool> {
+    decode_bytes(data)
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| byte & (1 << (7 - i)) != 0))
+        .take(length)
+        .collect()
+}
+
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+fn decode_width(code: u8) -> u32 {
+    match code {
+        0..=23 => code as u32 + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn read_packed(data: &[u8], offset: &mut usize, width: u32, length: usize) -> Vec<u64> {
+    let mut values = vec![];
+    let mut acc = 0u128;
+    let mut bits = 0;
+    while values.len() < length {
+        while bits < width {
+            acc = acc << 8 | data[*offset] as u128;
+            *offset += 1;
+            bits += 8;
+        }
+        bits -= width;
+        values.push((acc >> bits) as u64 & (u64::MAX >> (64 - width)));
+        acc &= (1 << bits) - 1;
+    }
+    values
+}
+
+/// Decodes the integer run length encoding v2, except for its patched base encoding
+pub fn decode_integers(data: &[u8], signed: bool) -> Vec<i64> {
+    let to_signed = |x: u64| if signed { unzigzag(x) } else { x as i64 };
+    let mut values = vec![];
+    let mut offset = 0;
+    while offset < data.len() {
+        let header = data[offset];
+        match header >> 6 {
+            0 => {
+                let bytes = ((header >> 3) & 0x7) as usize + 1;
+                let run = (header & 0x7) as usize + 3;
+                let mut value = 0u64;
+                for byte in &data[offset + 1..offset + 1 + bytes] {
+                    value = value << 8 | *byte as u64;
+                }
+                offset += 1 + bytes;
+                values.resize(values.len() + run, to_signed(value));
+            }
+            1 => {
+                let width = decode_width((header >> 1) & 0x1f);
+                let length = ((header as usize & 1) << 8 | data[offset + 1] as usize) + 1;
+                offset += 2;
+                let packed = read_packed(data, &mut offset, width, length);
+                values.extend(packed.into_iter().map(to_signed));
+            }
+            3 => {
+                let code = (header >> 1) & 0x1f;
+                let length = ((header as usize & 1) << 8 | data[offset + 1] as usize) + 1;
+                offset += 2;
+                let base = to_signed(read_varint(data, &mut offset));
+                let delta_base = unzigzag(read_varint(data, &mut offset));
+                values.push(base);
+                let mut last = base + delta_base;
+                values.push(last);
+                if code == 0 {
+                    for _ in 2..length {
+                        last += delta_base;
+                        values.push(last);
+                    }
+                } else {
+                    let deltas = read_packed(data, &mut offset, decode_width(code), length - 2);
+                    for delta in deltas {
+                        if delta_base < 0 {
+                            last -= delta as i64;
+                        } else {
+                            last += delta as i64;
+                        }
+                        values.push(last);
+                    }
+                }
+            }
+            _ => panic!("patched base is not written"),
+        }
+    }
+    values
+}
+
+/// A decoded ORC file: its postscript, footer and, per stripe, the decompressed streams
+/// by (column, kind)
+pub struct File {
+    pub postscript: Message,
+    pub footer: Message,
+    pub stripes: Vec<Stripe>,
+}
+
+pub struct Stripe {
+    pub footer: Message,
+    pub number_of_rows: usize,
+    pub streams: HashMap<(u64, u64), Vec<u8>>,
+}
+
+pub fn read_file(data: &[u8]) -> File {
+    assert_eq!(&data[..3], b"ORC");
+    let postscript_length = *data.last().unwrap() as usize;
+    let postscript_end = data.len() - 1;
+    let postscript = decode_message(&data[postscript_end - postscript_length..postscript_end]);
+    let compression = postscript.uint(2);
+
+    let footer_end = postscript_end - postscript_length;
+    let footer_start = footer_end - postscript.uint(1) as usize;
+    let footer = decode_message(&decompress(&data[footer_start..footer_end], compression));
+
+    let stripes = footer
+        .messages(3)
+        .iter()
+        .map(|information| {
+            let mut offset = information.uint(1) as usize;
+            let data_end = offset + information.uint(3) as usize;
+            let stripe_footer = decode_message(&decompress(
+                &data[data_end..data_end + information.uint(4) as usize],
+                compression,
+            ));
+            let mut streams = HashMap::new();
+            for stream in stripe_footer.messages(1) {
+                let length = stream.uint(3) as usize;
+                let stream_data = decompress(&data[offset..offset + length], compression);
+                offset += length;
+                streams.insert((stream.uint(2), stream.uint(1)), stream_data);
+            }
+            Stripe {
+                footer: stripe_footer,
+                number_of_rows: information.uint(5) as usize,
+                streams,
+            }
+        })
+        .collect();
+
+    File {
+        postscript,
+        footer,
+        stripes,
+    }
+}
@@ -0,0 +1,3 @@
+mod decode;
+mod read;
+mod write;
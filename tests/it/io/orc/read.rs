@@ -0,0 +1,510 @@
+use std::io::Cursor;
+use std::iter::FromIterator;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::columns::Columns;
+use arrow2::datatypes::*;
+use arrow2::error::{ArrowError, Result};
+use arrow2::io::orc::{read, write};
+
+fn schema() -> Schema {
+    Schema::new(vec![
+        Field::new("bool", DataType::Boolean, true),
+        Field::new("i8", DataType::Int8, true),
+        Field::new("i16", DataType::Int16, true),
+        Field::new("i32", DataType::Int32, true),
+        Field::new("i64", DataType::Int64, true),
+        Field::new("f32", DataType::Float32, true),
+        Field::new("f64", DataType::Float64, true),
+        Field::new("utf8", DataType::Utf8, true),
+        Field::new("binary", DataType::Binary, true),
+        Field::new("date", DataType::Date32, true),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+            true,
+        ),
+    ])
+}
+
+fn columns(offset: i64) -> Columns<Arc<dyn Array>> {
+    let ints = (0..1000)
+        .map(|x| {
+            if x % 7 == 0 {
+                None
+            } else {
+                Some(x * x - offset)
+            }
+        })
+        .collect::<Vec<_>>();
+    let strings = ints
+        .iter()
+        .map(|x| x.map(|x| format!("value {}", x)))
+        .collect::<Vec<_>>();
+    Columns::new(vec![
+        Arc::new(BooleanArray::from_iter(
+            ints.iter().map(|x| x.map(|x| x % 3 == 0)),
+        )) as Arc<dyn Array>,
+        Arc::new(Int8Array::from_iter(
+            ints.iter().map(|x| x.map(|x| x as i8)),
+        )),
+        Arc::new(Int16Array::from_iter(
+            ints.iter().map(|x| x.map(|x| x as i16)),
+        )),
+        Arc::new(Int32Array::from_iter(
+            ints.iter().map(|x| x.map(|x| -x as i32)),
+        )),
+        Arc::new(Int64Array::from_iter(
+            ints.iter().map(|x| x.map(|x| x << 40)),
+        )),
+        Arc::new(Float32Array::from_iter(
+            ints.iter().map(|x| x.map(|x| x as f32 / 3.0)),
+        )),
+        Arc::new(Float64Array::from_iter(
+            ints.iter().map(|x| x.map(|x| x as f64 / 3.0)),
+        )),
+        Arc::new(Utf8Array::<i32>::from(&strings)),
+        Arc::new(BinaryArray::<i32>::from_iter(
+            strings.iter().map(|x| x.as_ref().map(|x| x.as_bytes())),
+        )),
+        Arc::new(
+            Int32Array::from_iter(ints.iter().map(|x| x.map(|x| x as i32 - 20000)))
+                .to(DataType::Date32),
+        ),
+        Arc::new(
+            Int64Array::from_iter(
+                ints.iter()
+                    .map(|x| x.map(|x| x * 1_000_000_123 - 10i64.pow(15))),
+            )
+            .to(DataType::Timestamp(TimeUnit::Nanosecond, None)),
+        ),
+    ])
+}
+
+fn write(stripes: &[Columns<Arc<dyn Array>>], compression: write::Compression) -> Vec<u8> {
+    let mut data = vec![];
+    let options = write::WriteOptions { compression };
+    let stripes = stripes.iter().cloned().map(Ok);
+    write::write_file(&mut data, stripes, &schema(), options).unwrap();
+    data
+}
+
+fn round_trip(compression: write::Compression) -> Result<()> {
+    let expected = vec![columns(0), columns(500)];
+    let data = write(&expected, compression);
+
+    let reader = read::Reader::try_new(Cursor::new(data), None)?;
+    assert_eq!(reader.schema(), &schema());
+    assert_eq!(reader.metadata().number_of_rows, 2000);
+    assert_eq!(reader.metadata().number_of_stripes(), 2);
+
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn round_trip_uncompressed() -> Result<()> {
+    round_trip(write::Compression::Uncompressed)
+}
+
+#[test]
+fn round_trip_zlib() -> Result<()> {
+    round_trip(write::Compression::Zlib)
+}
+
+/// The columns written by `orc_integration/write_orc.py`
+fn pyorc_columns() -> Columns<Arc<dyn Array>> {
+    let values = (0..1000i64)
+        .map(|x| if x % 7 == 0 { None } else { Some(x * x) })
+        .collect::<Vec<_>>();
+    let map = |f: &dyn Fn(i64) -> i64| values.iter().map(|x| x.map(f)).collect::<Vec<_>>();
+    Columns::new(vec![
+        Arc::new(BooleanArray::from_iter(
+            values.iter().map(|x| x.map(|x| x % 3 == 0)),
+        )) as Arc<dyn Array>,
+        Arc::new(Int8Array::from_iter(
+            values.iter().map(|x| x.map(|x| x as i8)),
+        )),
+        Arc::new(Int16Array::from_iter(
+            values.iter().map(|x| x.map(|x| x as i16)),
+        )),
+        Arc::new(Int32Array::from_iter(
+            values.iter().map(|x| x.map(|x| -x as i32)),
+        )),
+        Arc::new(Int64Array::from(map(&|x| x << 40))),
+        Arc::new(Float32Array::from_iter(
+            values.iter().map(|x| x.map(|x| x as f32 / 4.0)),
+        )),
+        Arc::new(Float64Array::from_iter(
+            values.iter().map(|x| x.map(|x| x as f64 / 4.0)),
+        )),
+        Arc::new(Utf8Array::<i32>::from_iter(
+            values
+                .iter()
+                .map(|x| x.map(|x| format!("value {}", x % 100))),
+        )),
+        Arc::new(BinaryArray::<i32>::from_iter(
+            values
+                .iter()
+                .map(|x| x.map(|x| format!("value {}", x).into_bytes())),
+        )),
+        Arc::new(
+            Int32Array::from_iter(values.iter().map(|x| x.map(|x| x as i32 - 20000)))
+                .to(DataType::Date32),
+        ),
+        Arc::new(
+            Int64Array::from(map(&|x| (x * 1_000_001 - 10i64.pow(12)) * 1000))
+                .to(DataType::Timestamp(TimeUnit::Nanosecond, None)),
+        ),
+    ])
+}
+
+/// Reads a file written by the C++ ORC writer via `orc_integration/write_orc.py`
+fn read_pyorc(name: &str) -> Result<()> {
+    let path = format!("fixtures/pyorc/{}.orc", name);
+    let reader = read::Reader::try_new(std::fs::File::open(path)?, None)?;
+    assert_eq!(reader.schema(), &schema());
+    assert_eq!(reader.metadata().number_of_stripes(), 1);
+
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, vec![pyorc_columns()]);
+    Ok(())
+}
+
+#[test]
+fn pyorc_uncompressed() -> Result<()> {
+    read_pyorc("uncompressed")
+}
+
+#[test]
+fn pyorc_zlib() -> Result<()> {
+    read_pyorc("zlib")
+}
+
+#[test]
+fn pyorc_snappy() -> Result<()> {
+    read_pyorc("snappy")
+}
+
+#[test]
+fn pyorc_dictionary() -> Result<()> {
+    read_pyorc("dictionary")
+}
+
+#[test]
+fn projection() -> Result<()> {
+    let data = write(&[columns(0)], write::Compression::Uncompressed);
+
+    let reader = read::Reader::try_new(Cursor::new(data.clone()), Some(vec![7, 0]))?;
+    let fields = schema().fields().clone();
+    assert_eq!(
+        reader.schema(),
+        &Schema::new(vec![fields[7].clone(), fields[0].clone()])
+    );
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    let expected = columns(0).into_arrays();
+    assert_eq!(
+        result,
+        vec![Columns::new(vec![expected[7].clone(), expected[0].clone()])]
+    );
+
+    assert!(read::Reader::try_new(Cursor::new(data), Some(vec![11])).is_err());
+    Ok(())
+}
+
+#[test]
+fn not_orc() {
+    let mut reader = Cursor::new(b"PAR1 this is not ORC".to_vec());
+    assert!(read::read_metadata(&mut reader).is_err());
+}
+
+// Files as written by ORC's Java writer, with encodings that `write` does not use: the run
+// length encoding v1, dictionaries and patched bases.
+
+fn varint(mut value: u64, buffer: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buffer.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn uint(field: u64, value: u64, buffer: &mut Vec<u8>) {
+    varint(field << 3, buffer);
+    varint(value, buffer);
+}
+
+fn bytes(field: u64, value: &[u8], buffer: &mut Vec<u8>) {
+    varint(field << 3 | 2, buffer);
+    varint(value.len() as u64, buffer);
+    buffer.extend_from_slice(value);
+}
+
+/// Integer run length encoding v1 of `values` as literals
+fn v1_literals(values: &[i64], signed: bool) -> Vec<u8> {
+    let mut buffer = vec![-(values.len() as i8) as u8];
+    for value in values {
+        let value = if signed {
+            ((value << 1) ^ (value >> 63)) as u64
+        } else {
+            *value as u64
+        };
+        varint(value, &mut buffer);
+    }
+    buffer
+}
+
+/// An ORC column: its type, name, encoding and dictionary size, and streams
+struct FixtureColumn {
+    kind: u64,
+    name: &'static str,
+    encoding: u64,
+    dictionary_size: u64,
+    streams: Vec<(u64, Vec<u8>)>,
+}
+
+/// Writes an ORC file of a single stripe, compressing its streams and metadata with `compress`
+fn fixture(
+    columns: Vec<FixtureColumn>,
+    number_of_rows: u64,
+    compression: u64,
+    compress: fn(&[u8]) -> Vec<u8>,
+) -> Vec<u8> {
+    let mut file = b"ORC".to_vec();
+
+    let mut stripe_footer = vec![];
+    let mut encodings = vec![];
+    // the root struct
+    let mut encoding = vec![];
+    uint(1, 0, &mut encoding);
+    bytes(2, &encoding, &mut encodings);
+    for (index, column) in columns.iter().enumerate() {
+        for (kind, data) in &column.streams {
+            let data = compress(data);
+            let mut stream = vec![];
+            uint(1, *kind, &mut stream);
+            uint(2, index as u64 + 1, &mut stream);
+            uint(3, data.len() as u64, &mut stream);
+            bytes(1, &stream, &mut stripe_footer);
+            file.extend_from_slice(&data);
+        }
+        let mut encoding = vec![];
+        uint(1, column.encoding, &mut encoding);
+        uint(2, column.dictionary_size, &mut encoding);
+        bytes(2, &encoding, &mut encodings);
+    }
+    stripe_footer.extend_from_slice(&encodings);
+    bytes(3, b"America/Los_Angeles", &mut stripe_footer);
+
+    let data_length = file.len() as u64 - 3;
+    let stripe_footer = compress(&stripe_footer);
+    file.extend_from_slice(&stripe_footer);
+
+    let mut stripe = vec![];
+    uint(1, 3, &mut stripe);
+    uint(2, 0, &mut stripe);
+    uint(3, data_length, &mut stripe);
+    uint(4, stripe_footer.len() as u64, &mut stripe);
+    uint(5, number_of_rows, &mut stripe);
+
+    let mut footer = vec![];
+    uint(1, 3, &mut footer);
+    uint(2, file.len() as u64 - 3, &mut footer);
+    bytes(3, &stripe, &mut footer);
+    let mut root = vec![];
+    uint(1, 12, &mut root);
+    let subtypes = (1..=columns.len() as u8).collect::<Vec<_>>();
+    bytes(2, &subtypes, &mut root);
+    for column in &columns {
+        bytes(3, column.name.as_bytes(), &mut root);
+    }
+    bytes(4, &root, &mut footer);
+    for column in &columns {
+        let mut type_ = vec![];
+        uint(1, column.kind, &mut type_);
+        bytes(4, &type_, &mut footer);
+    }
+    uint(6, number_of_rows, &mut footer);
+    let footer = compress(&footer);
+    file.extend_from_slice(&footer);
+
+    let mut postscript = vec![];
+    uint(1, footer.len() as u64, &mut postscript);
+    uint(2, compression, &mut postscript);
+    uint(3, 262144, &mut postscript);
+    bytes(4, &[0, 12], &mut postscript);
+    uint(5, 0, &mut postscript);
+    bytes(8000, b"ORC", &mut postscript);
+    file.extend_from_slice(&postscript);
+    file.push(postscript.len() as u8);
+    file
+}
+
+fn chunk(compressed: Vec<u8>) -> Vec<u8> {
+    let header = (compressed.len() as u32) << 1;
+    let mut chunk = header.to_le_bytes()[..3].to_vec();
+    chunk.extend(compressed);
+    chunk
+}
+
+fn snappy(data: &[u8]) -> Vec<u8> {
+    chunk(snap::raw::Encoder::new().compress_vec(data).unwrap())
+}
+
+fn zlib(data: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+    let mut encoder = libflate::deflate::Encoder::new(vec![]);
+    encoder.write_all(data).unwrap();
+    chunk(encoder.finish().into_result().unwrap())
+}
+
+fn uncompressed(data: &[u8]) -> Vec<u8> {
+    data.to_vec()
+}
+
+const PRESENT: u64 = 0;
+const DATA: u64 = 1;
+const LENGTH: u64 = 2;
+const DICTIONARY_DATA: u64 = 3;
+const SECONDARY: u64 = 5;
+
+fn nulls_and_dictionary(compression: u64, compress: fn(&[u8]) -> Vec<u8>) -> Result<()> {
+    let columns = vec![
+        // 1..=5 as a run of the encoding v1
+        FixtureColumn {
+            kind: 4,
+            name: "id",
+            encoding: 0,
+            dictionary_size: 0,
+            streams: vec![(DATA, vec![0x02, 0x01, 0x02])],
+        },
+        // ["b", None, "a", "b", None] with the dictionary ["a", "b"]
+        FixtureColumn {
+            kind: 7,
+            name: "name",
+            encoding: 3,
+            dictionary_size: 2,
+            streams: vec![
+                (PRESENT, vec![0xff, 0b10110000]),
+                (DATA, vec![0x40, 0x02, 0b10100000]),
+                (DICTIONARY_DATA, b"ab".to_vec()),
+                (LENGTH, vec![0x40, 0x01, 0b11000000]),
+            ],
+        },
+        // a string column whose values are all null
+        FixtureColumn {
+            kind: 7,
+            name: "empty",
+            encoding: 1,
+            dictionary_size: 0,
+            streams: vec![(PRESENT, vec![0xff, 0]), (DATA, vec![]), (LENGTH, vec![])],
+        },
+        FixtureColumn {
+            kind: 9,
+            name: "ts",
+            encoding: 0,
+            dictionary_size: 0,
+            streams: vec![
+                (
+                    DATA,
+                    v1_literals(&[0, 1, -1420070400, 86400, -1 - 1420070400], true),
+                ),
+                (
+                    SECONDARY,
+                    v1_literals(&[0, 5 << 3 | 7, 0, 0, 5 << 3 | 7], false),
+                ),
+            ],
+        },
+    ];
+    let data = fixture(columns, 5, compression, compress);
+
+    let reader = read::Reader::try_new(Cursor::new(data), None)?;
+    let timestamp = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let expected_schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("name", DataType::Utf8, true),
+        Field::new("empty", DataType::Utf8, true),
+        Field::new("ts", timestamp.clone(), true),
+    ]);
+    assert_eq!(reader.schema(), &expected_schema);
+
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    let expected = Columns::new(vec![
+        Arc::new(Int64Array::from_slice([1, 2, 3, 4, 5])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([
+            Some("b"),
+            None,
+            Some("a"),
+            Some("b"),
+            None,
+        ])),
+        Arc::new(Utf8Array::<i32>::new_null(DataType::Utf8, 5)),
+        Arc::new(
+            Int64Array::from_slice([
+                1_420_070_400_000_000_000,
+                1_420_070_401_500_000_000,
+                0,
+                1_420_156_800_000_000_000,
+                -1_500_000_000,
+            ])
+            .to(timestamp),
+        ),
+    ]);
+    assert_eq!(result, vec![expected]);
+    Ok(())
+}
+
+#[test]
+fn nulls_and_dictionary_uncompressed() -> Result<()> {
+    nulls_and_dictionary(0, uncompressed)
+}
+
+#[test]
+fn nulls_and_dictionary_snappy() -> Result<()> {
+    nulls_and_dictionary(2, snappy)
+}
+
+#[test]
+fn patched_base() -> Result<()> {
+    // the example of https://orc.apache.org/specification/ORCv1/#run-length-encoding
+    let data = vec![
+        0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46, 0x50,
+        0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+    ];
+    let columns = vec![FixtureColumn {
+        kind: 4,
+        name: "a",
+        encoding: 2,
+        dictionary_size: 0,
+        streams: vec![(DATA, data)],
+    }];
+    let data = fixture(columns, 20, 1, zlib);
+
+    let mut reader = Cursor::new(data);
+    let metadata = read::read_metadata(&mut reader)?;
+    let result = read::read_stripe(&mut reader, &metadata, 0, None)?;
+    let expected = Int64Array::from_slice([
+        2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120, 2130,
+        2140, 2150, 2160, 2170, 2180, 2190,
+    ]);
+    assert_eq!(result.arrays()[0].as_ref(), &expected as &dyn Array);
+
+    assert!(read::read_stripe(&mut reader, &metadata, 1, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn nested_is_not_supported() {
+    let columns = vec![FixtureColumn {
+        kind: 10,
+        name: "list",
+        encoding: 2,
+        dictionary_size: 0,
+        streams: vec![],
+    }];
+    let data = fixture(columns, 0, 0, uncompressed);
+    let result = read::read_metadata(&mut Cursor::new(data));
+    assert!(matches!(result, Err(ArrowError::NotYetImplemented(_))));
+}
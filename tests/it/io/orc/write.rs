@@ -0,0 +1,341 @@
+use std::convert::TryInto;
+
+use arrow2::array::*;
+use arrow2::columns::Columns;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::orc::write::*;
+
+use super::decode::*;
+
+const PRESENT: u64 = 0;
+const DATA: u64 = 1;
+const LENGTH: u64 = 2;
+const SECONDARY: u64 = 5;
+
+fn write(
+    stripes: Vec<Columns<Box<dyn Array>>>,
+    schema: &Schema,
+    compression: Compression,
+) -> Vec<u8> {
+    let mut data = vec![];
+    let options = WriteOptions { compression };
+    let written = write_file(&mut data, stripes.into_iter().map(Ok), schema, options).unwrap();
+    assert_eq!(written as usize, data.len());
+    data
+}
+
+fn schema() -> Schema {
+    Schema::new(vec![
+        Field::new("bool", DataType::Boolean, true),
+        Field::new("i8", DataType::Int8, true),
+        Field::new("i16", DataType::Int16, false),
+        Field::new("i32", DataType::Int32, true),
+        Field::new("i64", DataType::Int64, true),
+        Field::new("f32", DataType::Float32, true),
+        Field::new("f64", DataType::Float64, false),
+        Field::new("utf8", DataType::Utf8, true),
+        Field::new("large_binary", DataType::LargeBinary, true),
+        Field::new("date", DataType::Date32, true),
+    ])
+}
+
+fn columns() -> Columns<Box<dyn Array>> {
+    Columns::new(vec![
+        Box::new(BooleanArray::from([
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+        ])) as Box<dyn Array>,
+        Box::new(Int8Array::from([Some(-1), Some(-1), None, Some(127)])),
+        Box::new(Int16Array::from_slice([1, 2, 3, -4])),
+        Box::new(Int32Array::from([
+            Some(i32::MIN),
+            None,
+            Some(0),
+            Some(i32::MAX),
+        ])),
+        Box::new(Int64Array::from([
+            None,
+            Some(i64::MIN),
+            Some(i64::MAX),
+            Some(7),
+        ])),
+        Box::new(Float32Array::from([Some(1.5), None, None, Some(-0.5)])),
+        Box::new(Float64Array::from_slice([0.1, f64::MAX, -1.0, 2.0])),
+        Box::new(Utf8Array::<i32>::from([
+            Some("a"),
+            Some(""),
+            None,
+            Some("ORC"),
+        ])),
+        Box::new(BinaryArray::<i64>::from([
+            Some(b"x".as_ref()),
+            None,
+            None,
+            Some(b"yz"),
+        ])),
+        Box::new(Int32Array::from([Some(18000), Some(-1), None, Some(0)]).to(DataType::Date32)),
+    ])
+}
+
+/// The values of a column, where the non-null values are returned by `values`
+fn with_validity<T>(stripe: &Stripe, column: u64, values: Vec<T>) -> Vec<Option<T>> {
+    let validity = stripe
+        .streams
+        .get(&(column, PRESENT))
+        .map(|x| decode_booleans(x, stripe.number_of_rows))
+        .unwrap_or_else(|| vec![true; stripe.number_of_rows]);
+    let mut values = values.into_iter();
+    validity
+        .into_iter()
+        .map(|is_valid| if is_valid { values.next() } else { None })
+        .collect()
+}
+
+fn integers(stripe: &Stripe, column: u64) -> Vec<Option<i64>> {
+    let values = decode_integers(&stripe.streams[&(column, DATA)], true);
+    with_validity(stripe, column, values)
+}
+
+fn binaries(stripe: &Stripe, column: u64) -> Vec<Option<Vec<u8>>> {
+    let data = &stripe.streams[&(column, DATA)];
+    let lengths = decode_integers(&stripe.streams[&(column, LENGTH)], false);
+    let mut offset = 0;
+    let values = lengths
+        .into_iter()
+        .map(|length| {
+            offset += length as usize;
+            data[offset - length as usize..offset].to_vec()
+        })
+        .collect();
+    with_validity(stripe, column, values)
+}
+
+fn round_trip(compression: Compression) -> Result<()> {
+    let data = write(vec![columns()], &schema(), compression);
+    let file = read_file(&data);
+    assert_eq!(file.stripes.len(), 1);
+    let stripe = &file.stripes[0];
+    assert_eq!(stripe.number_of_rows, 4);
+
+    let values = decode_booleans(&stripe.streams[&(1, DATA)], 3);
+    assert_eq!(
+        with_validity(stripe, 1, values),
+        vec![Some(true), None, Some(false), Some(true)]
+    );
+
+    let values = decode_bytes(&stripe.streams[&(2, DATA)]);
+    let values = values.into_iter().map(|x| x as i8).collect();
+    assert_eq!(
+        with_validity(stripe, 2, values),
+        vec![Some(-1), Some(-1), None, Some(127)]
+    );
+
+    assert!(!stripe.streams.contains_key(&(3, PRESENT)));
+    assert_eq!(
+        integers(stripe, 3),
+        vec![Some(1), Some(2), Some(3), Some(-4)]
+    );
+    assert_eq!(
+        integers(stripe, 4),
+        vec![Some(i32::MIN as i64), None, Some(0), Some(i32::MAX as i64)]
+    );
+    assert_eq!(
+        integers(stripe, 5),
+        vec![None, Some(i64::MIN), Some(i64::MAX), Some(7)]
+    );
+
+    let values = stripe.streams[&(6, DATA)]
+        .chunks(4)
+        .map(|x| f32::from_le_bytes(x.try_into().unwrap()))
+        .collect();
+    assert_eq!(
+        with_validity(stripe, 6, values),
+        vec![Some(1.5), None, None, Some(-0.5)]
+    );
+
+    let values = stripe.streams[&(7, DATA)]
+        .chunks(8)
+        .map(|x| f64::from_le_bytes(x.try_into().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![0.1, f64::MAX, -1.0, 2.0]);
+
+    let expected = [Some("a"), Some(""), None, Some("ORC")];
+    let expected = expected
+        .iter()
+        .map(|x| x.map(|x| x.as_bytes().to_vec()))
+        .collect::<Vec<_>>();
+    assert_eq!(binaries(stripe, 8), expected);
+
+    let expected = vec![Some(b"x".to_vec()), None, None, Some(b"yz".to_vec())];
+    assert_eq!(binaries(stripe, 9), expected);
+
+    assert_eq!(
+        integers(stripe, 10),
+        vec![Some(18000), Some(-1), None, Some(0)]
+    );
+    Ok(())
+}
+
+#[test]
+fn round_trip_uncompressed() -> Result<()> {
+    round_trip(Compression::Uncompressed)
+}
+
+#[test]
+fn round_trip_zlib() -> Result<()> {
+    round_trip(Compression::Zlib)
+}
+
+#[test]
+fn metadata() -> Result<()> {
+    let data = write(vec![columns()], &schema(), Compression::Zlib);
+    let file = read_file(&data);
+
+    // postscript
+    assert_eq!(file.postscript.strings(8000), vec!["ORC".to_string()]);
+    assert_eq!(file.postscript.uint(2), 1);
+    assert_eq!(file.postscript.packed(4), vec![0, 12]);
+
+    // footer
+    assert_eq!(file.footer.uint(1), 3);
+    assert_eq!(file.footer.uint(6), 4);
+    let types = file.footer.messages(4);
+    assert_eq!(types.len(), 11);
+    assert_eq!(types[0].uint(1), 12);
+    assert_eq!(types[0].packed(2), (1..=10).collect::<Vec<_>>());
+    let names = schema()
+        .fields()
+        .iter()
+        .map(|x| x.name().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(types[0].strings(3), names);
+    let kinds = types[1..].iter().map(|x| x.uint(1)).collect::<Vec<_>>();
+    assert_eq!(kinds, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 15]);
+
+    let statistics = file.footer.messages(7);
+    assert_eq!(statistics.len(), 11);
+    let values = statistics.iter().map(|x| x.uint(1)).collect::<Vec<_>>();
+    assert_eq!(values, vec![4, 3, 3, 4, 3, 3, 2, 4, 3, 2, 3]);
+    let has_null = statistics.iter().map(|x| x.uint(10)).collect::<Vec<_>>();
+    assert_eq!(has_null, vec![0, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1]);
+
+    // stripe footer
+    let stripe = &file.stripes[0];
+    assert_eq!(stripe.footer.strings(3), vec!["UTC".to_string()]);
+    let encodings = stripe
+        .footer
+        .messages(2)
+        .iter()
+        .map(|x| x.uint(1))
+        .collect::<Vec<_>>();
+    assert_eq!(encodings, vec![0, 0, 0, 2, 2, 2, 0, 0, 2, 2, 2]);
+    Ok(())
+}
+
+#[test]
+fn stripes() -> Result<()> {
+    let schema = Schema::new(vec![Field::new("a", DataType::Int64, false)]);
+    let first = (0..1000).collect::<Vec<i64>>();
+    let second = (0..2000).map(|x| x * x % 17 - 8).collect::<Vec<i64>>();
+    let stripes = || {
+        vec![first.clone(), vec![], second.clone()]
+            .into_iter()
+            .map(|x| Columns::new(vec![Box::new(Int64Array::from_vec(x)) as Box<dyn Array>]))
+            .collect::<Vec<_>>()
+    };
+
+    for compression in [Compression::Uncompressed, Compression::Zlib] {
+        let data = write(stripes(), &schema, compression);
+        let file = read_file(&data);
+        assert_eq!(file.footer.uint(6), 3000);
+        // empty columns are not written as stripes
+        assert_eq!(file.stripes.len(), 2);
+        assert_eq!(
+            decode_integers(&file.stripes[0].streams[&(1, DATA)], true),
+            first
+        );
+        assert_eq!(
+            decode_integers(&file.stripes[1].streams[&(1, DATA)], true),
+            second
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn timestamps() -> Result<()> {
+    let schema = Schema::new(vec![
+        Field::new("ms", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+        Field::new(
+            "ns",
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".to_string())),
+            false,
+        ),
+    ]);
+    let columns = Columns::new(vec![
+        Box::new(
+            Int64Array::from([Some(1420070400000), None, Some(1420070401500), Some(-1500)])
+                .to(schema.fields()[0].data_type().clone()),
+        ) as Box<dyn Array>,
+        Box::new(
+            Int64Array::from_slice([0, 1, -1, 1_000_000_123_400])
+                .to(schema.fields()[1].data_type().clone()),
+        ),
+    ]);
+
+    let data = write(vec![columns], &schema, Compression::Uncompressed);
+    let file = read_file(&data);
+    let stripe = &file.stripes[0];
+
+    // TIMESTAMP and TIMESTAMP_INSTANT
+    let kinds = file.footer.messages(4)[1..]
+        .iter()
+        .map(|x| x.uint(1))
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, vec![9, 18]);
+
+    let seconds = decode_integers(&stripe.streams[&(1, DATA)], true);
+    let nanos = decode_integers(&stripe.streams[&(1, SECONDARY)], false);
+    assert_eq!(seconds, vec![0, 1, -1 - 1420070400]);
+    // 500ms has 8 trailing zeros, written as 5 << 3 | (8 - 1)
+    assert_eq!(nanos, vec![0, 5 << 3 | 7, 5 << 3 | 7]);
+    assert_eq!(stripe.footer.messages(1).len(), 5);
+    assert!(stripe.streams.contains_key(&(1, PRESENT)));
+
+    let seconds = decode_integers(&stripe.streams[&(2, DATA)], true);
+    let nanos = decode_integers(&stripe.streams[&(2, SECONDARY)], false);
+    let base = -1420070400;
+    assert_eq!(seconds, vec![base, base, base, base + 1000]);
+    // readers subtract a second from negative seconds with more than 999999 nanoseconds
+    assert_eq!(nanos, vec![0, 1 << 3, 999_999_999 << 3, 1234 << 3 | 1]);
+    Ok(())
+}
+
+#[test]
+fn unsupported_type() {
+    let schema = Schema::new(vec![Field::new(
+        "a",
+        DataType::Time32(TimeUnit::Second),
+        false,
+    )]);
+    let columns = Columns::new(vec![Box::new(
+        Int32Array::from_slice([1]).to(DataType::Time32(TimeUnit::Second)),
+    ) as Box<dyn Array>]);
+    let options = WriteOptions {
+        compression: Compression::Uncompressed,
+    };
+    assert!(write_file(&mut vec![], [Ok(columns)], &schema, options).is_err());
+}
+
+#[test]
+fn schema_mismatch() {
+    let schema = Schema::new(vec![Field::new("a", DataType::Int64, false)]);
+    let columns = Columns::new(vec![Box::new(Int32Array::from_slice([1])) as Box<dyn Array>]);
+    let options = WriteOptions {
+        compression: Compression::Uncompressed,
+    };
+    assert!(write_file(&mut vec![], [Ok(columns)], &schema, options).is_err());
+}
@@ -0,0 +1,315 @@
+use std::convert::TryInto;
+
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+use super::*;
+
+fn schema() -> Schema {
+    Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("name", DataType::Utf8, false),
+        Field::new(
+            "dict",
+            DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+            true,
+        ),
+        Field::new("value", DataType::Float64, false),
+    ])
+}
+
+/// A batch whose ids and names are in `[start, start + 1000)`
+fn batch(schema: &Schema, start: i64) -> Result<RecordBatch> {
+    let ids = (start..start + 1000)
+        .map(|x| if x % 10 == 0 { None } else { Some(x) })
+        .collect::<Int64Array>();
+    let names = Utf8Array::<i32>::from_iter_values((start..start + 1000).map(|x| x.to_string()));
+    let mut dict = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dict.try_extend((start..start + 1000).map(|x| Some((x % 3).to_string())))?;
+    let dict: DictionaryArray<i32> = dict.into();
+    let values = Float64Array::from_values((start..start + 1000).map(|x| x as f64));
+    RecordBatch::try_new(
+        Arc::new(schema.clone()),
+        vec![
+            Arc::new(ids),
+            Arc::new(names),
+            Arc::new(dict),
+            Arc::new(values),
+        ],
+    )
+}
+
+const OPTIONS: WriteOptions = WriteOptions {
+    write_statistics: true,
+    compression: Compression::Snappy,
+    version: Version::V1,
+};
+
+fn row_groups(
+    schema: &Schema,
+    batches: Vec<RecordBatch>,
+    bloom_filters: Vec<Option<BloomFilterOptions>>,
+) -> Result<BloomFilterRowGroupIterator<std::vec::IntoIter<Result<RecordBatch>>>> {
+    let encodings = schema
+        .fields()
+        .iter()
+        .map(|field| match field.data_type() {
+            DataType::Dictionary(..) => Encoding::RleDictionary,
+            _ => Encoding::Plain,
+        })
+        .collect();
+
+    BloomFilterRowGroupIterator::try_new(
+        batches.into_iter().map(Ok).collect::<Vec<_>>().into_iter(),
+        schema,
+        OPTIONS,
+        encodings,
+        bloom_filters,
+    )
+}
+
+fn write(
+    schema: &Schema,
+    batches: Vec<RecordBatch>,
+    bloom_filters: Vec<Option<BloomFilterOptions>>,
+) -> Result<Vec<u8>> {
+    let options = OPTIONS;
+    let row_groups = row_groups(schema, batches, bloom_filters)?;
+    let parquet_schema = row_groups.parquet_schema().clone();
+
+    let mut writer = Cursor::new(vec![]);
+    let size = write_file_with_bloom_filters(
+        &mut writer,
+        row_groups,
+        schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    let data = writer.into_inner();
+    assert_eq!(size, data.len() as u64);
+    Ok(data)
+}
+
+fn options() -> Vec<Option<BloomFilterOptions>> {
+    let options = BloomFilterOptions {
+        ndv: 1000,
+        fpp: 0.01,
+    };
+    vec![Some(options), Some(options), Some(options), None]
+}
+
+#[test]
+fn roundtrip() -> Result<()> {
+    let schema = schema();
+    let batches = vec![batch(&schema, 0)?, batch(&schema, 1000)?];
+    let data = write(&schema, batches.clone(), options())?;
+
+    // the file is otherwise unchanged
+    let (read_schema, read_batches) = integration_read(&data)?;
+    assert_eq!(&schema, read_schema.as_ref());
+    assert_eq!(batches, read_batches);
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    assert_eq!(metadata.row_groups.len(), 2);
+    let group = &metadata.row_groups[1];
+
+    let filter = read_bloom_filter(group.column(0), &mut reader)?.unwrap();
+    assert_eq!(filter.num_bytes(), 2048);
+    // no false negatives
+    (1001..2000).filter(|x| x % 10 != 0).for_each(|x| {
+        assert!(filter.might_contain(&PrimitiveScalar::<i64>::new(DataType::Int64, Some(x))))
+    });
+    // nulls are not inserted, but may be in the column chunk
+    assert!(filter.might_contain(&PrimitiveScalar::<i64>::new(DataType::Int64, None)));
+    let false_positives = (0..1000)
+        .chain(2000..10000)
+        .filter(|x| filter.might_contain(&PrimitiveScalar::<i64>::new(DataType::Int64, Some(*x))))
+        .count();
+    assert!(false_positives < 90, "{}", false_positives);
+
+    let filter = read_bloom_filter(group.column(1), &mut reader)?.unwrap();
+    assert!(filter.might_contain(&Utf8Scalar::<i32>::new(Some("1500"))));
+    assert!(!filter.might_contain(&Utf8Scalar::<i32>::new(Some("a"))));
+
+    // dictionary-encoded columns are queried by their values
+    let filter = read_bloom_filter(group.column(2), &mut reader)?.unwrap();
+    assert!(filter.might_contain(&Utf8Scalar::<i32>::new(Some("2"))));
+    assert!(!filter.might_contain(&Utf8Scalar::<i32>::new(Some("3"))));
+
+    assert!(read_bloom_filter(group.column(3), &mut reader)?.is_none());
+    Ok(())
+}
+
+#[test]
+fn prune_row_groups() -> Result<()> {
+    let schema = schema();
+    let batches = vec![
+        batch(&schema, 0)?,
+        batch(&schema, 1000)?,
+        batch(&schema, 2000)?,
+    ];
+    let data = write(&schema, batches.clone(), options())?;
+
+    // `id = 1234`
+    let value = PrimitiveScalar::<i64>::new(DataType::Int64, Some(1234));
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let mut groups = vec![];
+    for (i, group) in metadata.row_groups.iter().enumerate() {
+        match read_bloom_filter(group.column(0), &mut reader)? {
+            Some(filter) if !filter.might_contain(&value) => {}
+            _ => groups.push(i),
+        }
+    }
+    assert_eq!(groups, vec![1]);
+
+    reader.rewind()?;
+    let mut reader = RecordReader::try_new(reader, None, None, None, None)?;
+    reader.set_groups_filter(Arc::new(move |i, _| groups.contains(&i)));
+    let read_batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(read_batches, vec![batches[1].clone()]);
+    Ok(())
+}
+
+#[test]
+fn without_bloom_filters() -> Result<()> {
+    let schema = schema();
+    let batch = batch(&schema, 0)?;
+
+    // files written without bloom filters have none
+    let data = integration_write(&schema, &[batch.clone()])?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    assert!(read_bloom_filter(metadata.row_groups[0].column(0), &mut reader)?.is_none());
+
+    let data = write(&schema, vec![batch], vec![None; 4])?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    for column in metadata.row_groups[0].columns() {
+        assert!(read_bloom_filter(column, &mut reader)?.is_none());
+    }
+    Ok(())
+}
+
+/// `write_file_with_bloom_filters` relies on `write_file` writing the footer, and only it, once
+/// the iterator of row groups returns `None`. This fails if `parquet2` stops doing so.
+#[test]
+fn footer_is_intercepted() -> Result<()> {
+    let schema = schema();
+    let batches = vec![batch(&schema, 0)?, batch(&schema, 1000)?];
+
+    let row_groups = self::row_groups(&schema, batches.clone(), vec![None; 4])?;
+    let parquet_schema = row_groups.parquet_schema().clone();
+    let row_groups = row_groups.map(|row_group| row_group.map(|(row_group, _)| row_group));
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        OPTIONS,
+        None,
+    )?;
+    let expected = writer.into_inner();
+
+    let metadata_len = i32::from_le_bytes(
+        expected[expected.len() - 8..expected.len() - 4]
+            .try_into()
+            .unwrap(),
+    );
+    let footer_start = expected.len() - 8 - metadata_len as usize;
+
+    // without bloom filters, the footer is re-written with the same length
+    let data = write(&schema, batches.clone(), vec![None; 4])?;
+    assert_eq!(data.len(), expected.len());
+    assert_eq!(data[data.len() - 8..], expected[expected.len() - 8..]);
+
+    // with bloom filters, they are written between the last column chunk and the footer
+    let data = write(&schema, batches, options())?;
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    let offsets = metadata
+        .row_groups
+        .iter()
+        .flat_map(|group| group.columns())
+        .filter_map(|column| {
+            column
+                .clone()
+                .into_thrift()
+                .meta_data
+                .and_then(|metadata| metadata.bloom_filter_offset)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(offsets.len(), 6);
+    assert_eq!(offsets[0], footer_start as i64);
+    // after every column chunk
+    for column in metadata.row_groups.iter().flat_map(|group| group.columns()) {
+        let (start, length) = column.byte_range();
+        assert!(start + length <= footer_start as u64);
+    }
+    Ok(())
+}
+
+#[test]
+fn invalid_options() -> Result<()> {
+    let schema = schema();
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let new = |schema: &Schema, bloom_filters| {
+        let encodings = vec![Encoding::Plain; schema.fields().len()];
+        BloomFilterRowGroupIterator::try_new(
+            std::iter::empty(),
+            schema,
+            options,
+            encodings,
+            bloom_filters,
+        )
+        .map(|_| ())
+    };
+
+    // one per field
+    assert!(new(&schema, vec![None; 3]).is_err());
+    // in (0, 1)
+    let fpp = Some(BloomFilterOptions { ndv: 10, fpp: 1.0 });
+    assert!(new(&schema, vec![fpp, None, None, None]).is_err());
+
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Boolean, true),
+        Field::new(
+            "b",
+            DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+            true,
+        ),
+    ]);
+    assert!(new(&schema, vec![Some(Default::default()), None]).is_err());
+    assert!(new(&schema, vec![None, Some(Default::default())]).is_err());
+    assert!(new(&schema, vec![None, None]).is_ok());
+    Ok(())
+}
+
+/// A file with bloom filters written by parquet-mr via `parquet_integration/write_bloom_filter.py`
+#[test]
+fn golden_bloom_filter() -> Result<()> {
+    let path = "fixtures/bloom_filter/spark.parquet";
+    let mut reader = std::fs::File::open(path)?;
+    let metadata = read_metadata(&mut reader)?;
+    let group = &metadata.row_groups[0];
+
+    let filter = read_bloom_filter(group.column(0), &mut reader)?.unwrap();
+    (0..1000).filter(|x| x % 10 != 0).for_each(|x| {
+        assert!(filter.might_contain(&PrimitiveScalar::<i64>::new(DataType::Int64, Some(x))))
+    });
+    let false_positives = (1000..10000)
+        .filter(|x| filter.might_contain(&PrimitiveScalar::<i64>::new(DataType::Int64, Some(*x))))
+        .count();
+    assert!(false_positives < 90, "{}", false_positives);
+
+    let filter = read_bloom_filter(group.column(1), &mut reader)?.unwrap();
+    (0..1000)
+        .for_each(|x| assert!(filter.might_contain(&Utf8Scalar::<i32>::new(Some(x.to_string())))));
+    assert!(!filter.might_contain(&Utf8Scalar::<i32>::new(Some("a"))));
+    Ok(())
+}
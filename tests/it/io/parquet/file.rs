@@ -0,0 +1,589 @@
+use super::*;
+
+fn batch(version: Version) -> Result<(Schema, RecordBatch, WriteOptions, Vec<Encoding>)> {
+    let int = Int64Array::from([Some(1), None, Some(3), Some(4)]);
+    let utf8 = Utf8Array::<i32>::from([Some("a"), Some("bb"), None, Some("ccc")]);
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![
+        Some(vec![Some(1)]),
+        None,
+        Some(vec![None]),
+        Some(vec![]),
+    ])?;
+    let list: ListArray<i32> = list.into();
+    let dict = DictionaryArray::<u32>::from_data(
+        PrimitiveArray::from([Some(0), None, Some(1), Some(0)]),
+        Arc::new(Utf8Array::<i32>::from_slice(["x", "y"])),
+    );
+
+    let columns: Vec<Arc<dyn Array>> = vec![
+        Arc::new(int),
+        Arc::new(utf8),
+        Arc::new(list),
+        Arc::new(dict),
+    ];
+    let schema = Schema::new(
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(format!("c{}", i), array.data_type().clone(), true))
+            .collect(),
+    );
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version,
+    };
+    let encodings = vec![
+        Encoding::Plain,
+        Encoding::Plain,
+        Encoding::Plain,
+        Encoding::RleDictionary,
+    ];
+    Ok((schema, batch, options, encodings))
+}
+
+fn write(
+    schema: &Schema,
+    batches: &[RecordBatch],
+    options: WriteOptions,
+    encodings: Vec<Encoding>,
+    file_options: Option<&FileOptions>,
+) -> Result<Vec<u8>> {
+    let row_groups =
+        RowGroupIterator::try_new(batches.iter().cloned().map(Ok), schema, options, encodings)?;
+    let parquet_schema = row_groups.parquet_schema().clone();
+
+    let mut writer = Cursor::new(vec![]);
+    let size = match file_options {
+        Some(file_options) => write_file_with_options(
+            &mut writer,
+            row_groups,
+            schema,
+            parquet_schema,
+            options,
+            file_options,
+            None,
+        )?,
+        None => write_file(
+            &mut writer,
+            row_groups,
+            schema,
+            parquet_schema,
+            options,
+            None,
+        )?,
+    };
+    let data = writer.into_inner();
+    assert_eq!(size, data.len() as u64);
+    Ok(data)
+}
+
+fn crc_options() -> FileOptions {
+    FileOptions {
+        write_page_crc: true,
+        ..Default::default()
+    }
+}
+
+fn round_trip_page_crc(version: Version) -> Result<()> {
+    let (schema, batch, options, encodings) = batch(version)?;
+    let batches = vec![batch.clone(), batch];
+    let data = write(&schema, &batches, options, encodings, Some(&crc_options()))?;
+
+    let (read_schema, read_batches) = integration_read(&data)?;
+    assert_eq!(read_schema.as_ref(), &schema);
+    assert_eq!(read_batches, batches);
+
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    assert_eq!(metadata.row_groups.len(), 2);
+    for group in &metadata.row_groups {
+        let columns = group.columns();
+        // a data page per column and a dictionary page for the dictionary-encoded column
+        let pages = columns
+            .iter()
+            .map(|column| verify_page_crcs(column, &mut reader))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(pages, vec![1, 1, 1, 2]);
+
+        let dictionary = &columns[3];
+        assert!(dictionary.dictionary_page_offset().unwrap() < dictionary.data_page_offset());
+        let (start, _) = dictionary.byte_range();
+        assert_eq!(start as i64, dictionary.dictionary_page_offset().unwrap());
+    }
+    Ok(())
+}
+
+#[test]
+fn page_crc_v1() -> Result<()> {
+    round_trip_page_crc(Version::V1)
+}
+
+#[test]
+fn page_crc_v2() -> Result<()> {
+    round_trip_page_crc(Version::V2)
+}
+
+#[test]
+fn page_crc_mismatch() -> Result<()> {
+    let (schema, batch, options, encodings) = batch(Version::V2)?;
+    let mut data = write(&schema, &[batch], options, encodings, Some(&crc_options()))?;
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let column = metadata.row_groups[0].column(1);
+    // the last byte of the column chunk is part of its (only) page
+    let (start, length) = column.byte_range();
+    data[(start + length - 1) as usize] ^= 1;
+
+    assert!(verify_page_crcs(column, &mut Cursor::new(&data)).is_err());
+    assert_eq!(
+        verify_page_crcs(metadata.row_groups[0].column(0), &mut Cursor::new(&data))?,
+        1
+    );
+
+    // the reader verifies the pages of the columns it reads
+    let mut reader = RecordReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    reader.set_verify_page_crcs(true);
+    assert!(reader.next().unwrap().is_err());
+    let mut reader = RecordReader::try_new(Cursor::new(&data), Some(vec![0]), None, None, None)?;
+    reader.set_verify_page_crcs(true);
+    assert_eq!(reader.next().unwrap()?.num_rows(), 4);
+    Ok(())
+}
+
+#[test]
+fn without_file_options() -> Result<()> {
+    let (schema, batch, options, encodings) = batch(Version::V1)?;
+    // without dictionary pages, whose offset `write_file` does not declare
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(schema.fields()[..3].to_vec())),
+        batch.columns()[..3].to_vec(),
+    )?;
+    let schema = batch.schema().as_ref().clone();
+    let encodings = encodings[..3].to_vec();
+
+    let expected = write(
+        &schema,
+        std::slice::from_ref(&batch),
+        options,
+        encodings.clone(),
+        None,
+    )?;
+    let data = write(
+        &schema,
+        &[batch],
+        options,
+        encodings,
+        Some(&FileOptions::default()),
+    )?;
+    // the files only differ in the order of the (unordered) encodings of the column chunks
+    assert_eq!(data.len(), expected.len());
+    let expected = read_metadata(&mut Cursor::new(&expected))?;
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    for (column, expected) in metadata.row_groups[0]
+        .columns()
+        .iter()
+        .zip(expected.row_groups[0].columns())
+    {
+        assert_eq!(column.byte_range(), expected.byte_range());
+        let statistics = |column: &arrow2::io::parquet::read::ColumnChunkMetaData| {
+            column.clone().into_thrift().meta_data.unwrap().statistics
+        };
+        assert_eq!(statistics(column), statistics(expected));
+        assert_eq!(verify_page_crcs(column, &mut Cursor::new(&data))?, 0);
+    }
+    Ok(())
+}
+
+/// A file with v2 data pages written by parquet-mr
+#[test]
+fn parquet_mr_v2_pages() -> Result<()> {
+    let path = "testing/parquet-testing/data/datapage_v2.snappy.parquet";
+    let mut reader = std::fs::File::open(path)?;
+    let metadata = read_metadata(&mut reader)?;
+    for column in metadata.row_groups[0].columns() {
+        for page in get_page_iterator(column, &mut reader, None, vec![])? {
+            assert!(matches!(page?.header(), DataPageHeader::V2(_)));
+        }
+        // parquet-mr does not write page checksums by default
+        assert_eq!(verify_page_crcs(column, &mut reader)?, 0);
+    }
+
+    let reader = RecordReader::try_new(reader, Some(vec![0, 1, 2]), None, None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches.len(), 1);
+    let batch = &batches[0];
+
+    let expected =
+        Utf8Array::<i32>::from([Some("abc"), Some("abc"), Some("abc"), None, Some("abc")]);
+    assert_eq!(batch.column(0).as_ref(), &expected as &dyn Array);
+    let expected = Int32Array::from_slice([1, 2, 3, 4, 5]);
+    assert_eq!(batch.column(1).as_ref(), &expected as &dyn Array);
+    let expected = Float64Array::from_slice([2.0, 3.0, 4.0, 5.0, 2.0]);
+    assert_eq!(batch.column(2).as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+/// Splits `array` into pages of `length` rows.
+fn paged(array: &dyn Array, length: usize) -> Vec<Arc<dyn Array>> {
+    (0..array.len())
+        .step_by(length)
+        .map(|offset| array.slice(offset, length.min(array.len() - offset)).into())
+        .collect()
+}
+
+fn list(values: Vec<Option<Vec<Option<i32>>>>) -> Result<Arc<dyn Array>> {
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(values)?;
+    let list: ListArray<i32> = list.into();
+    Ok(Arc::new(list))
+}
+
+/// Writes a single row group whose column chunks have the given pages.
+fn write_pages(
+    columns: &[Vec<Arc<dyn Array>>],
+    version: Version,
+    file_options: &FileOptions,
+) -> Result<Vec<u8>> {
+    let schema = Schema::new(
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, pages)| Field::new(format!("c{}", i), pages[0].data_type().clone(), true))
+            .collect(),
+    );
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version,
+    };
+
+    let pages = columns
+        .iter()
+        .zip(parquet_schema.columns().to_vec())
+        .map(|(pages, descriptor)| {
+            let pages = pages
+                .iter()
+                .map(|array| {
+                    let page = array_to_page(
+                        array.as_ref(),
+                        descriptor.clone(),
+                        options,
+                        Encoding::Plain,
+                    )?;
+                    Ok(page)
+                })
+                .collect::<Vec<_>>();
+            let compressed =
+                Compressor::new(DynIter::new(pages.into_iter()), options.compression, vec![]);
+            Ok(DynStreamingIterator::new(
+                compressed.map_err(ArrowError::from),
+            ))
+        })
+        .collect::<Vec<_>>();
+    let row_groups = std::iter::once(Ok(DynIter::new(pages.into_iter())));
+
+    let mut writer = Cursor::new(vec![]);
+    write_file_with_options(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        file_options,
+        None,
+    )?;
+    Ok(writer.into_inner())
+}
+
+fn round_trip_page_index(version: Version) -> Result<()> {
+    let int = Int64Array::from([
+        Some(1),
+        Some(2),
+        Some(3),
+        None,
+        None,
+        None,
+        Some(7),
+        Some(8),
+        Some(9),
+    ]);
+    let long = "g".repeat(70);
+    let longest = "é".repeat(40);
+    let utf8 = Utf8Array::<i32>::from_slice(["a", "b", "c", "d", "e", "f", &long, "h", &longest]);
+    let file_options = FileOptions {
+        write_page_index: true,
+        ..Default::default()
+    };
+    let pages = vec![paged(&int, 3), paged(&utf8, 3)];
+    let data = write_pages(&pages, version, &file_options)?;
+    let columns: Vec<Arc<dyn Array>> = vec![Arc::new(int), Arc::new(utf8)];
+
+    let (_, batches) = integration_read(&data)?;
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].columns(), columns.as_slice());
+
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    for column in metadata.row_groups[0].columns() {
+        let offset_index = read_offset_index(column, &mut reader)?.unwrap();
+        let locations = &offset_index.page_locations;
+        let pages = get_page_iterator(column, &mut reader, None, vec![])?.count();
+        assert_eq!(locations.len(), pages);
+
+        // the pages are contiguous and cover the column chunk
+        let (start, length) = column.byte_range();
+        assert_eq!(locations[0].offset, start as i64);
+        for window in locations.windows(2) {
+            assert_eq!(
+                window[0].offset + window[0].compressed_page_size as i64,
+                window[1].offset
+            );
+        }
+        let last = locations.last().unwrap();
+        assert_eq!(
+            last.offset + last.compressed_page_size as i64,
+            (start + length) as i64
+        );
+        let first_rows = locations
+            .iter()
+            .map(|location| location.first_row_index)
+            .collect::<Vec<_>>();
+        assert_eq!(first_rows, vec![0, 3, 6]);
+
+        let column_index = read_column_index(column, &mut reader)?.unwrap();
+        assert_eq!(column_index.null_pages.len(), pages);
+    }
+
+    let int_index = read_column_index(metadata.row_groups[0].column(0), &mut reader)?.unwrap();
+    assert_eq!(int_index.null_pages, vec![false, true, false]);
+    let to_bytes = |values: &[i64]| {
+        values
+            .iter()
+            .map(|x| x.to_le_bytes().to_vec())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(int_index.min_values[0], to_bytes(&[1])[0]);
+    assert_eq!(int_index.min_values[2], to_bytes(&[7])[0]);
+    assert_eq!(int_index.max_values[0], to_bytes(&[3])[0]);
+    assert_eq!(int_index.max_values[2], to_bytes(&[9])[0]);
+    assert!(int_index.min_values[1].is_empty());
+    assert_eq!(int_index.null_counts, Some(vec![0, 3, 0]));
+    assert_eq!(int_index.boundary_order, BoundaryOrder::Ascending);
+
+    let utf8_index = read_column_index(metadata.row_groups[0].column(1), &mut reader)?.unwrap();
+    assert_eq!(
+        utf8_index.min_values,
+        vec![b"a".to_vec(), b"d".to_vec(), "g".repeat(64).into_bytes()]
+    );
+    // truncated at a char boundary, with its last char incremented
+    let truncated_max = format!("{}ê", "é".repeat(31)).into_bytes();
+    assert_eq!(truncated_max.len(), 64);
+    assert_eq!(
+        utf8_index.max_values,
+        vec![b"c".to_vec(), b"f".to_vec(), truncated_max]
+    );
+    assert_eq!(utf8_index.boundary_order, BoundaryOrder::Ascending);
+    Ok(())
+}
+
+#[test]
+fn page_index_v1() -> Result<()> {
+    round_trip_page_index(Version::V1)
+}
+
+#[test]
+fn page_index_v2() -> Result<()> {
+    round_trip_page_index(Version::V2)
+}
+
+fn round_trip_page_index_nested(version: Version) -> Result<()> {
+    // pages with more values than rows
+    let lists = vec![
+        vec![Some(vec![Some(1), Some(2)]), None, Some(vec![])],
+        vec![
+            Some(vec![Some(3)]),
+            Some(vec![Some(4), Some(5), Some(6)]),
+            Some(vec![None]),
+        ],
+        vec![None, Some(vec![Some(7)]), Some(vec![Some(8), Some(9)])],
+    ];
+    let pages = lists.into_iter().map(list).collect::<Result<Vec<_>>>()?;
+
+    let file_options = FileOptions {
+        write_page_index: true,
+        ..Default::default()
+    };
+    let data = write_pages(&[pages], version, &file_options)?;
+
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    let column = metadata.row_groups[0].column(0);
+    let offset_index = read_offset_index(column, &mut reader)?.unwrap();
+    let first_rows = offset_index
+        .page_locations
+        .iter()
+        .map(|location| location.first_row_index)
+        .collect::<Vec<_>>();
+    assert_eq!(first_rows, vec![0, 3, 6]);
+
+    let column_index = read_column_index(column, &mut reader)?.unwrap();
+    assert_eq!(column_index.null_pages, vec![false, false, false]);
+    assert_eq!(column_index.null_counts, Some(vec![0, 1, 0]));
+    Ok(())
+}
+
+#[test]
+fn page_index_nested_v1() -> Result<()> {
+    round_trip_page_index_nested(Version::V1)
+}
+
+#[test]
+fn page_index_nested_v2() -> Result<()> {
+    round_trip_page_index_nested(Version::V2)
+}
+
+#[test]
+fn page_index_unordered() -> Result<()> {
+    let int = Int32Array::from_slice([3, 1, 2, 9, 8, 7, 1, 0, 0]);
+    let file_options = FileOptions {
+        write_page_index: true,
+        ..Default::default()
+    };
+    let data = write_pages(&[paged(&int, 3)], Version::V1, &file_options)?;
+
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    let index = read_column_index(metadata.row_groups[0].column(0), &mut reader)?.unwrap();
+    assert_eq!(index.boundary_order, BoundaryOrder::Unordered);
+
+    let int = Int32Array::from_slice([9, 8, 7, 6, 5, 4]);
+    let data = write_pages(&[paged(&int, 2)], Version::V1, &file_options)?;
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    let index = read_column_index(metadata.row_groups[0].column(0), &mut reader)?.unwrap();
+    assert_eq!(index.boundary_order, BoundaryOrder::Descending);
+    Ok(())
+}
+
+/// A file with a page index written by Arrow C++, whose page index equals ours
+#[test]
+fn golden_page_index() -> Result<()> {
+    let path = "fixtures/page_index/pyarrow.parquet";
+    let mut reader = std::fs::File::open(path)?;
+    let metadata = read_metadata(&mut reader)?;
+
+    let int = Int64Array::from([
+        Some(1),
+        Some(2),
+        Some(3),
+        None,
+        None,
+        None,
+        Some(7),
+        Some(8),
+        Some(9),
+    ]);
+    let utf8 = Utf8Array::<i32>::from_slice(["a", "b", "c", "d", "e", "f", "g", "h", "i"]);
+    let file_options = FileOptions {
+        write_page_index: true,
+        ..Default::default()
+    };
+    let data = write_pages(
+        &[paged(&int, 3), paged(&utf8, 3)],
+        Version::V1,
+        &file_options,
+    )?;
+    let mut expected_reader = Cursor::new(&data);
+    let expected_metadata = read_metadata(&mut expected_reader)?;
+
+    let columns = metadata.row_groups[0].columns();
+    let expected_columns = expected_metadata.row_groups[0].columns();
+    for (column, expected) in columns.iter().zip(expected_columns.iter()) {
+        let index = read_column_index(column, &mut reader)?.unwrap();
+        let expected_index = read_column_index(expected, &mut expected_reader)?.unwrap();
+        assert_eq!(index.null_pages, expected_index.null_pages);
+        assert_eq!(index.min_values, expected_index.min_values);
+        assert_eq!(index.max_values, expected_index.max_values);
+        assert_eq!(index.boundary_order, expected_index.boundary_order);
+        if let Some(null_counts) = index.null_counts {
+            assert_eq!(Some(null_counts), expected_index.null_counts);
+        }
+
+        let first_rows = read_offset_index(column, &mut reader)?
+            .unwrap()
+            .page_locations
+            .iter()
+            .map(|location| location.first_row_index)
+            .collect::<Vec<_>>();
+        assert_eq!(first_rows, vec![0, 3, 6]);
+    }
+    Ok(())
+}
+
+#[test]
+fn without_page_index() -> Result<()> {
+    let (schema, batch, options, encodings) = batch(Version::V1)?;
+    let data = write(&schema, &[batch], options, encodings, Some(&crc_options()))?;
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    for column in metadata.row_groups[0].columns() {
+        assert!(read_column_index(column, &mut reader)?.is_none());
+        assert!(read_offset_index(column, &mut reader)?.is_none());
+    }
+    Ok(())
+}
+
+#[test]
+fn sorting_columns() -> Result<()> {
+    use parquet_format_async_temp::thrift::protocol::TCompactInputProtocol;
+    use std::convert::TryInto;
+
+    let (schema, batch, options, encodings) = batch(Version::V2)?;
+    let sorting_columns = vec![
+        SortingColumn::new(0, false, true),
+        SortingColumn::new(1, true, false),
+    ];
+    let file_options = FileOptions {
+        sorting_columns: Some(sorting_columns.clone()),
+        ..Default::default()
+    };
+    let batches = vec![batch.clone(), batch];
+    let data = write(
+        &schema,
+        &batches,
+        options,
+        encodings.clone(),
+        Some(&file_options),
+    )?;
+
+    // the footer is the metadata followed by its length and the magic number
+    let length = i32::from_le_bytes(data[data.len() - 8..data.len() - 4].try_into().unwrap());
+    let mut footer = &data[data.len() - 8 - length as usize..data.len() - 8];
+    let mut protocol = TCompactInputProtocol::new(&mut footer);
+    let metadata = parquet_format_async_temp::FileMetaData::read_from_in_protocol(&mut protocol)
+        .map_err(|e| ArrowError::from(ParquetError::from(e)))?;
+    assert_eq!(metadata.row_groups.len(), 2);
+    for row_group in metadata.row_groups {
+        let written = row_group.sorting_columns.map(|columns| {
+            columns
+                .into_iter()
+                .map(|column| {
+                    SortingColumn::new(column.column_idx, column.descending, column.nulls_first)
+                })
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(written, Some(sorting_columns.clone()));
+    }
+
+    let file_options = FileOptions {
+        sorting_columns: Some(vec![SortingColumn::new(5, false, false)]),
+        ..Default::default()
+    };
+    assert!(write(&schema, &batches, options, encodings, Some(&file_options)).is_err());
+    Ok(())
+}
@@ -10,6 +10,9 @@ use arrow2::{
 
 use crate::io::ipc::read_gzip_json;
 
+mod bloom_filter;
+mod file;
+mod prune;
 mod read;
 mod write;
 
@@ -621,6 +624,91 @@ pub fn pyarrow_struct_statistics(column: usize) -> Option<Box<dyn Statistics>> {
 }
 
 /// Round-trip with parquet using the same integration files used for IPC integration tests.
+pub fn pyarrow_map(column: usize) -> Box<dyn Array> {
+    assert_eq!(column, 0);
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int64, true),
+    ]);
+    let keys = Utf8Array::<i32>::from_slice(["a", "b", "c", "a", "d", "e", "f", "g"]);
+    let values = Int64Array::from([
+        Some(1),
+        None,
+        Some(3),
+        Some(4),
+        None,
+        Some(5),
+        Some(6),
+        Some(7),
+    ]);
+    let entries_array = StructArray::from_data(
+        entries.clone(),
+        vec![Arc::new(keys), Arc::new(values)],
+        None,
+    );
+    Box::new(MapArray::from_data(
+        DataType::Map(Box::new(Field::new("entries", entries, false)), false),
+        Buffer::from_slice([0, 2, 2, 2, 3, 4, 4, 5, 5, 7, 8]),
+        Arc::new(entries_array),
+        Some(Bitmap::from([
+            true, false, true, true, true, false, true, true, true, true,
+        ])),
+    ))
+}
+
+/// Returns a [`DictionaryArray`] logically equal to `values`.
+fn dictionary_of(values: Arc<dyn Array>) -> DictionaryArray<i32> {
+    let keys = Int32Array::from_data(
+        DataType::Int32,
+        (0..values.len() as i32).collect(),
+        values.validity().cloned(),
+    );
+    DictionaryArray::<i32>::from_data(keys, values)
+}
+
+pub fn pyarrow_dict(column: usize) -> Box<dyn Array> {
+    let values = match column {
+        0 => Arc::new(Int64Array::from([
+            Some(0),
+            Some(1),
+            None,
+            Some(3),
+            None,
+            Some(1),
+            Some(0),
+            None,
+            Some(3),
+            Some(3),
+        ])) as Arc<dyn Array>,
+        1 => Arc::new(Float64Array::from([
+            Some(0.0),
+            Some(1.0),
+            None,
+            Some(3.0),
+            None,
+            Some(1.0),
+            Some(0.0),
+            None,
+            Some(3.0),
+            Some(3.0),
+        ])),
+        2 => Arc::new(Utf8Array::<i32>::from([
+            Some("Hello"),
+            None,
+            Some("aa"),
+            Some(""),
+            None,
+            Some("aa"),
+            None,
+            None,
+            Some("Hello"),
+            Some("aa"),
+        ])),
+        _ => unreachable!(),
+    };
+    Box::new(dictionary_of(values))
+}
+
 fn integration_write(schema: &Schema, batches: &[RecordBatch]) -> Result<Vec<u8>> {
     let options = WriteOptions {
         write_statistics: true,
@@ -732,3 +820,631 @@ fn arrow_type() -> Result<()> {
     assert_eq!(new_batches, vec![batch]);
     Ok(())
 }
+
+#[test]
+fn map() -> Result<()> {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(vec![
+        Some(vec![(Some("a"), Some(1)), (Some("b"), None)]),
+        None,
+        Some(vec![]),
+        Some(vec![(Some("c"), Some(3))]),
+    ])?;
+    let array: MapArray = array.into();
+
+    let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), true)]);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(array)])?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain],
+    )?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+
+    let (new_schema, new_batches) = integration_read(&writer.into_inner())?;
+
+    assert_eq!(new_schema.as_ref(), &schema);
+    assert_eq!(new_batches, vec![batch]);
+    Ok(())
+}
+
+fn columns_batch() -> Result<RecordBatch> {
+    let mut map = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    map.try_extend(vec![
+        Some(vec![(Some("a"), Some(1))]),
+        None,
+        Some(vec![(Some("c"), None)]),
+    ])?;
+    let map: MapArray = map.into();
+
+    let int = Arc::new(Int64Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let utf8 = Arc::new(Utf8Array::<i32>::from([Some("a"), Some("bb"), None])) as Arc<dyn Array>;
+
+    let schema = Schema::new(vec![
+        Field::new("a", int.data_type().clone(), true),
+        Field::new("b", utf8.data_type().clone(), true),
+        Field::new("c", map.data_type().clone(), true),
+    ]);
+    RecordBatch::try_new(Arc::new(schema), vec![int, utf8, Arc::new(map)])
+}
+
+fn columns_write(batch: &RecordBatch) -> Result<Vec<u8>> {
+    let schema = batch.schema().as_ref();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V1,
+    };
+    let parquet_schema = to_parquet_schema(schema)?;
+    let encodings = vec![Encoding::Plain; schema.fields().len()];
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch.clone())].into_iter(),
+        schema,
+        options,
+        encodings,
+    )?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn read_columns_deserialize() -> Result<()> {
+    let batch = columns_batch()?;
+    let data = columns_write(&batch)?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+
+    let columns = read_columns(&mut reader, &metadata, 0, None)?;
+    assert_eq!(columns.len(), 3);
+    assert_eq!(columns[1].field(), &batch.schema().fields()[1]);
+
+    let arrays = columns
+        .into_iter()
+        .map(|column| column.deserialize().map(Arc::from))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(arrays, batch.columns());
+
+    let columns = read_columns(&mut reader, &metadata, 0, Some(&[2, 1]))?;
+    let arrays = deserialize_parallel(columns)?;
+    assert_eq!(arrays[0].as_ref(), batch.column(2).as_ref());
+    assert_eq!(arrays[1].as_ref(), batch.column(1).as_ref());
+    Ok(())
+}
+
+#[test]
+fn read_columns_errors() -> Result<()> {
+    let batch = columns_batch()?;
+    let data = columns_write(&batch)?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+
+    assert!(read_columns(&mut reader, &metadata, 1, None).is_err());
+    assert!(read_columns(&mut reader, &metadata, 0, Some(&[3])).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_column_error_has_context() -> Result<()> {
+    let batch = columns_batch()?;
+    let data = columns_write(&batch)?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+
+    let columns = get_column_iterator(&mut reader, &metadata, 0, 0, None, vec![]);
+    let field = Field::new("a", DataType::Interval(IntervalUnit::DayTime), true);
+
+    let error = column_iter_to_array(columns, &field, vec![]).unwrap_err();
+    assert!(matches!(
+        error,
+        ArrowError::Deserialization { column: Some(ref column), row: None, .. } if column == "a"
+    ));
+    Ok(())
+}
+
+/// Writes `batches` of dictionary-encoded columns under `schema`, so that the pages
+/// of the file are dictionary-encoded regardless of `schema`.
+fn dictionary_write(schema: &Schema, batches: Vec<RecordBatch>) -> Result<Vec<u8>> {
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let batch_schema = batches[0].schema().clone();
+    let parquet_schema = to_parquet_schema(&batch_schema)?;
+    let encodings = vec![Encoding::RleDictionary; batch_schema.fields().len()];
+    let row_groups = RowGroupIterator::try_new(
+        batches.into_iter().map(Ok),
+        &batch_schema,
+        options,
+        encodings,
+    )?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn read_as_dictionary() -> Result<()> {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "bb"])) as Arc<dyn Array>;
+    let dict0 = DictionaryArray::<i32>::from_data(
+        Int32Array::from([None, Some(1), Some(0), Some(1)]),
+        values.clone(),
+    );
+    let dict1 = DictionaryArray::<i32>::from_data(Int32Array::from([Some(0), None]), values);
+
+    let batches = vec![dict0, dict1]
+        .into_iter()
+        .map(|array| {
+            let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), true)]);
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(array) as Arc<dyn Array>])
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let schema = Schema::new(vec![Field::new("a", DataType::Utf8, true)]);
+    let data = dictionary_write(&schema, batches.clone())?;
+
+    // by default, dictionary-encoded columns are unpacked
+    let reader = RecordReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    assert_eq!(reader.schema().as_ref(), &schema);
+    let arrays = reader
+        .map(|batch| batch.map(|batch| batch.column(0).clone()))
+        .collect::<Result<Vec<_>>>()?;
+    let expected = Utf8Array::<i32>::from([None, Some("bb"), Some("a"), Some("bb")]);
+    assert_eq!(arrays[0].as_ref(), &expected as &dyn Array);
+
+    let mut reader = RecordReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    reader.set_read_as_dictionary(None);
+    assert_eq!(reader.schema().as_ref(), batches[0].schema().as_ref());
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, batches);
+
+    let dict = result[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(dict.values().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn read_plain_as_dictionary() -> Result<()> {
+    let batch = columns_batch()?;
+    let data = columns_write(&batch)?;
+
+    let mut reader = RecordReader::try_new(Cursor::new(data), Some(vec![1]), None, None, None)?;
+    reader.set_read_as_dictionary(Some(&[0]));
+
+    let expected = Field::new(
+        "b",
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+        true,
+    );
+    assert_eq!(reader.schema().fields(), &[expected]);
+
+    // plain-encoded values are appended to the dictionary's values
+    let expected = DictionaryArray::<i32>::from_data(
+        Int32Array::from([Some(0), Some(1), None]),
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "bb"])),
+    );
+    let result = reader.next().unwrap()?;
+    assert_eq!(result.column(0).as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn write_v2_pages() -> Result<()> {
+    let int = Int64Array::from([Some(1), None, Some(3)]);
+    let utf8 = Utf8Array::<i32>::from([Some("a"), Some("bb"), None]);
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![Some(vec![Some(1)]), None, Some(vec![None])])?;
+    let list: ListArray<i32> = list.into();
+    let dict = DictionaryArray::<u32>::from_data(
+        PrimitiveArray::from([Some(0), None, Some(0)]),
+        Arc::new(utf8.clone()),
+    );
+
+    let columns: Vec<Arc<dyn Array>> = vec![
+        Arc::new(int),
+        Arc::new(utf8),
+        Arc::new(list),
+        Arc::new(dict),
+    ];
+    let schema = Schema::new(
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(&format!("c{}", i), array.data_type().clone(), true))
+            .collect(),
+    );
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V2,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let encodings = vec![
+        Encoding::Plain,
+        Encoding::Plain,
+        Encoding::Plain,
+        Encoding::RleDictionary,
+    ];
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch.clone())].into_iter(),
+        &schema,
+        options,
+        encodings,
+    )?;
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    let data = writer.into_inner();
+
+    // every data page is a v2 page whose (uncompressed) levels precede the compressed values
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    for column in metadata.row_groups[0].columns() {
+        let pages = get_page_iterator(column, &mut reader, None, vec![])?;
+        for page in pages {
+            match page?.header() {
+                DataPageHeader::V2(header) => {
+                    assert_eq!(header.is_compressed, Some(true));
+                    assert!(header.definition_levels_byte_length > 0);
+                }
+                DataPageHeader::V1(_) => panic!("expected a v2 data page"),
+            }
+        }
+    }
+
+    let (new_schema, new_batches) = integration_read(&data)?;
+    assert_eq!(new_schema.as_ref(), &schema);
+    assert_eq!(new_batches, vec![batch]);
+    Ok(())
+}
+
+#[test]
+fn metadata_roundtrip() -> Result<()> {
+    let mut field_metadata = Metadata::new();
+    field_metadata.insert("pii".to_string(), "true".to_string());
+    let mut schema_metadata = std::collections::HashMap::new();
+    schema_metadata.insert("owner".to_string(), "team".to_string());
+
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, true).with_metadata(field_metadata.clone()),
+        Field::new(
+            "b",
+            DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), true),
+            true,
+        )
+        .with_metadata(field_metadata),
+    ])
+    .with_metadata(schema_metadata);
+
+    let a = Int32Array::from(&[Some(1), None, Some(3)]);
+    let mut b = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    b.try_extend(vec![Some("x"), None, Some("y")])?;
+    let b: DictionaryArray<i32> = b.into();
+    let b = to_data_type(&b, schema.fields()[1].data_type().clone())?;
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(a), b.into()])?;
+
+    let data = integration_write(&schema, &[batch.clone()])?;
+    let (read_schema, read_batches) = integration_read(&data)?;
+
+    assert_eq!(&schema, read_schema.as_ref());
+    assert_eq!(vec![batch], read_batches);
+
+    // the schema's metadata is also available to readers that do not read `ARROW:schema`
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    let keys = metadata
+        .key_value_metadata()
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|x| x.key.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(keys, vec!["owner", "ARROW:schema"]);
+    let schema = schema::parquet_to_arrow_schema(metadata.schema(), metadata.key_value_metadata())?;
+    assert_eq!(schema.metadata().get("owner"), Some(&"team".to_string()));
+    Ok(())
+}
+
+#[test]
+fn map_required_keys() -> Result<()> {
+    let array = pyarrow_map(0);
+    let required = array.with_validity(None);
+
+    for (array, nullable) in [(array, true), (required, false)] {
+        let schema = Schema::new(vec![Field::new("m", array.data_type().clone(), nullable)]);
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![array.into()])?;
+        let data = columns_write(&batch)?;
+
+        let (new_schema, new_batches) = integration_read(&data)?;
+        assert_eq!(new_schema.as_ref(), &schema);
+        assert_eq!(new_batches, vec![batch]);
+
+        // without `ARROW:schema`, the map is read from its parquet logical type
+        let metadata = read_metadata(&mut Cursor::new(&data))?;
+        let parquet_schema = schema::parquet_to_arrow_schema(metadata.schema(), &None)?;
+        let entries = DataType::Struct(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int64, true),
+        ]);
+        assert_eq!(
+            parquet_schema.fields()[0].data_type(),
+            &DataType::Map(Box::new(Field::new("entries", entries, false)), false)
+        );
+        assert_eq!(parquet_schema.fields()[0].is_nullable(), nullable);
+    }
+    Ok(())
+}
+
+#[test]
+fn dictionary_primitive() -> Result<()> {
+    let float64 = DictionaryArray::<u8>::from_data(
+        UInt8Array::from([Some(2), None, Some(0), Some(2), Some(1)]),
+        Arc::new(Float64Array::from_slice([0.5, -1.0, 2.25])),
+    );
+    let uint64 = DictionaryArray::<i16>::from_data(
+        Int16Array::from([Some(1), Some(0), None, None, Some(1)]),
+        Arc::new(UInt64Array::from_slice([u64::MAX, 1])),
+    );
+    let float32 = DictionaryArray::<u32>::from_data(
+        UInt32Array::from_slice([0, 0, 1, 0, 1]),
+        Arc::new(Float32Array::from_slice([1.5, f32::MIN])),
+    );
+    let date32 = DictionaryArray::<i32>::from_data(
+        Int32Array::from_slice([1, 1, 0, 1, 0]),
+        Arc::new(Int32Array::from_slice([18_000, -1]).to(DataType::Date32)),
+    );
+
+    let columns: Vec<Arc<dyn Array>> = vec![
+        Arc::new(float64),
+        Arc::new(uint64),
+        Arc::new(float32),
+        Arc::new(date32),
+    ];
+    let schema = Schema::new(vec![
+        Field::new("a", columns[0].data_type().clone(), true),
+        Field::new("b", columns[1].data_type().clone(), true),
+        Field::new("c", columns[2].data_type().clone(), false),
+        Field::new("d", columns[3].data_type().clone(), false),
+    ]);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+    let sliced = RecordBatch::try_new(
+        Arc::new(schema.clone()),
+        batch
+            .columns()
+            .iter()
+            .map(|x| x.slice(1, 3).into())
+            .collect(),
+    )?;
+
+    let data = integration_write(&schema, &[batch.clone(), sliced.clone()])?;
+    let (new_schema, new_batches) = integration_read(&data)?;
+    assert_eq!(new_schema.as_ref(), &schema);
+    assert_eq!(new_batches, vec![batch, sliced]);
+    Ok(())
+}
+
+/// Writes `array` as a single column chunk of a dictionary page, one data page per `page_size`
+/// keys and a plain-encoded page of `plain`, as writers do when the dictionary grows too large.
+fn dictionary_fallback_write(
+    array: &DictionaryArray<i32>,
+    page_size: usize,
+    plain: &dyn Array,
+) -> Result<Vec<u8>> {
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), true)]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let descriptor = parquet_schema.columns()[0].clone();
+
+    let mut pages = vec![];
+    for offset in (0..array.len()).step_by(page_size) {
+        let length = page_size.min(array.len() - offset);
+        let slice = array.slice(offset, length);
+        let slice_pages =
+            array_to_pages(&slice, descriptor.clone(), options, Encoding::RleDictionary)?;
+        // the dictionary page is only written before the first data page
+        pages.extend(slice_pages.skip((offset > 0) as usize));
+    }
+    pages.push(Ok(array_to_page(
+        plain,
+        descriptor,
+        options,
+        Encoding::Plain,
+    )?));
+
+    let pages = DynIter::new(pages.into_iter().map(|x| Ok(x?)));
+    let compressed = Compressor::new(pages, options.compression, vec![]).map_err(ArrowError::from);
+    let column = DynStreamingIterator::new(compressed);
+    let row_groups = std::iter::once(Ok(DynIter::new(std::iter::once(Ok(column)))));
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn dictionary_fallback_to_plain() -> Result<()> {
+    let array = DictionaryArray::<i32>::from_data(
+        Int32Array::from([Some(1), None, Some(0), Some(1), Some(1), None, Some(0)]),
+        Arc::new(Int64Array::from_slice([10, 20])),
+    );
+    let plain = Int64Array::from([Some(30), None, Some(10)]);
+    let data = dictionary_fallback_write(&array, 3, &plain)?;
+
+    let (_, batches) = integration_read(&data)?;
+    let result = batches[0].column(0);
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    let expected = Int64Array::from([
+        Some(20),
+        None,
+        Some(10),
+        Some(20),
+        Some(20),
+        None,
+        Some(10),
+        Some(30),
+        None,
+        Some(10),
+    ]);
+    assert_eq!(result, &dictionary_of(Arc::new(expected)));
+    // the dictionary page is read once; plain values are appended to it
+    assert_eq!(
+        result.values().as_ref(),
+        &Int64Array::from_slice([10, 20, 30, 10]) as &dyn Array
+    );
+    Ok(())
+}
+
+/// Writes `array` as a single dictionary-encoded, uncompressed column followed by a
+/// plain-encoded page of `plain`, whose data pages are modified by `corrupt` before being written.
+fn dictionary_corrupted_write(
+    array: &DictionaryArray<i32>,
+    plain: &dyn Array,
+    corrupt: fn(&mut DataPage),
+) -> Result<Vec<u8>> {
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), false)]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let descriptor = parquet_schema.columns()[0].clone();
+
+    let pages = array_to_pages(array, descriptor.clone(), options, Encoding::RleDictionary)?;
+    let plain = array_to_page(plain, descriptor, options, Encoding::Plain)?;
+    let pages = pages.chain(std::iter::once(Ok(plain))).map(move |page| {
+        let mut page = page?;
+        if let EncodedPage::Data(ref mut page) = page {
+            corrupt(page);
+        }
+        Ok(page)
+    });
+
+    let pages = DynIter::new(pages);
+    let compressed = Compressor::new(pages, options.compression, vec![]).map_err(ArrowError::from);
+    let column = DynStreamingIterator::new(compressed);
+    let row_groups = std::iter::once(Ok(DynIter::new(std::iter::once(Ok(column)))));
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn dictionary_invalid_keys() -> Result<()> {
+    let array = DictionaryArray::<i32>::from_data(
+        Int32Array::from_slice([1, 0, 1, 1]),
+        Arc::new(Int64Array::from_slice([10, 20])),
+    );
+    let plain = Int64Array::from_slice([30, 10]);
+
+    // the page only contains the bit width of the keys
+    let data = dictionary_corrupted_write(&array, &plain, |page| {
+        if page.encoding() == Encoding::RleDictionary {
+            page.buffer_mut().truncate(1)
+        }
+    })?;
+    assert!(matches!(
+        integration_read(&data),
+        Err(ArrowError::Deserialization { source, .. }) if matches!(*source, ArrowError::OutOfSpec(_))
+    ));
+
+    // the bit width of the keys is larger than 32
+    let data = dictionary_corrupted_write(&array, &plain, |page| {
+        if page.encoding() == Encoding::RleDictionary {
+            page.buffer_mut()[0] = 33
+        }
+    })?;
+    assert!(matches!(
+        integration_read(&data),
+        Err(ArrowError::Deserialization { source, .. }) if matches!(*source, ArrowError::OutOfSpec(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn dictionary_fallback_invalid_values() -> Result<()> {
+    let array = DictionaryArray::<i32>::from_data(
+        Int32Array::from_slice([1, 0, 1, 1]),
+        Arc::new(Int64Array::from_slice([10, 20])),
+    );
+    let plain = Int64Array::from_slice([30, 10]);
+
+    // the plain-encoded page only contains the first of its two values
+    let data = dictionary_corrupted_write(&array, &plain, |page| {
+        if page.encoding() == Encoding::Plain {
+            page.buffer_mut().truncate(8)
+        }
+    })?;
+    assert!(matches!(
+        integration_read(&data),
+        Err(ArrowError::Deserialization { source, .. }) if matches!(*source, ArrowError::OutOfSpec(_))
+    ));
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use arrow2::scalar::PrimitiveScalar;
+
+use super::*;
+
+fn decimal_batch(data_type: &DataType, values: &[Option<i128>]) -> Result<RecordBatch> {
+    let schema = Schema::new(vec![Field::new("c", data_type.clone(), true)]);
+    let array = Int128Array::from(values).to(data_type.clone());
+    RecordBatch::try_new(Arc::new(schema), vec![Arc::new(array)])
+}
+
+fn decimal_statistics(data_type: DataType, values: &[Option<i128>]) -> Result<(i128, i128)> {
+    let batch = decimal_batch(&data_type, values)?;
+    let data = integration_write(batch.schema(), &[batch.clone()])?;
+
+    // the values are unchanged
+    let (_, read_batches) = integration_read(&data)?;
+    assert_eq!(read_batches, vec![batch]);
+
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    let statistics = metadata.row_groups[0].column(0).statistics().unwrap()?;
+    let statistics = deserialize_statistics(statistics.as_ref())?;
+    let statistics = statistics
+        .as_any()
+        .downcast_ref::<PrimitiveStatistics<i128>>()
+        .unwrap();
+    assert_eq!(statistics.data_type, data_type);
+    assert_eq!(statistics.null_count, Some(1));
+    Ok((statistics.min_value.unwrap(), statistics.max_value.unwrap()))
+}
+
+#[test]
+fn decimal_negative_statistics() -> Result<()> {
+    let values = [Some(-300), Some(2), None, Some(-1), Some(120)];
+    // written as i32, i64 and fixed-length byte arrays respectively
+    for precision in [9, 18, 26] {
+        let statistics = decimal_statistics(DataType::Decimal(precision, 2), &values)?;
+        assert_eq!(statistics, (-300, 120));
+    }
+    Ok(())
+}
+
+#[test]
+fn decimal_precision_38_statistics() -> Result<()> {
+    let max = 10i128.pow(38) - 1;
+    let values = [Some(-max), Some(-1), None, Some(max), Some(0)];
+    let statistics = decimal_statistics(DataType::Decimal(38, 10), &values)?;
+    assert_eq!(statistics, (-max, max));
+
+    let values = [Some(-max), Some(-max + 1), None, Some(-1)];
+    let statistics = decimal_statistics(DataType::Decimal(38, 10), &values)?;
+    assert_eq!(statistics, (-max, -1));
+    Ok(())
+}
+
+fn decimal(value: i128, scale: usize) -> PrimitiveScalar<i128> {
+    PrimitiveScalar::new(DataType::Decimal(38, scale), Some(value))
+}
+
+#[test]
+fn decimal_might_satisfy() -> Result<()> {
+    // [-3.00, 1.20]
+    let statistics = PrimitiveStatistics::<i128> {
+        data_type: DataType::Decimal(5, 2),
+        null_count: Some(0),
+        distinct_count: None,
+        min_value: Some(-300),
+        max_value: Some(120),
+    };
+    let check = |comparison, scalar: PrimitiveScalar<i128>| {
+        might_satisfy(&statistics, comparison, &scalar).unwrap()
+    };
+
+    assert!(check(Comparison::Eq, decimal(-3, 0)));
+    assert!(check(Comparison::Eq, decimal(12, 1)));
+    assert!(!check(Comparison::Eq, decimal(-3001, 3)));
+    assert!(!check(Comparison::Eq, decimal(2, 0)));
+    // more decimal places than the column
+    assert!(check(Comparison::Eq, decimal(-2999, 3)));
+    assert!(!check(Comparison::Gt, decimal(1200, 3)));
+    assert!(check(Comparison::GtEq, decimal(1200, 3)));
+    assert!(check(Comparison::Gt, decimal(1199, 3)));
+    assert!(!check(Comparison::Lt, decimal(-3, 0)));
+    assert!(check(Comparison::LtEq, decimal(-3, 0)));
+    // scalars whose rescaling overflows `i128`
+    assert!(check(Comparison::Lt, decimal(10i128.pow(37), 0)));
+    assert!(!check(Comparison::Gt, decimal(10i128.pow(37), 0)));
+    assert!(!check(Comparison::Lt, decimal(-(10i128.pow(37)), 0)));
+
+    let null = PrimitiveScalar::<i128>::new(DataType::Decimal(5, 2), None);
+    assert!(!check(Comparison::Eq, null));
+
+    let scalar = PrimitiveScalar::<i64>::new(DataType::Int64, Some(1));
+    assert!(might_satisfy(&statistics, Comparison::Eq, &scalar).is_err());
+    Ok(())
+}
+
+#[test]
+fn prune_row_groups() -> Result<()> {
+    let data_type = DataType::Decimal(26, 2);
+    let batches = vec![
+        decimal_batch(&data_type, &[Some(-500), Some(-100)])?,
+        decimal_batch(&data_type, &[Some(-99), None, Some(100)])?,
+        decimal_batch(&data_type, &[Some(101), Some(10000)])?,
+    ];
+    let data = integration_write(batches[0].schema(), &batches)?;
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+
+    // `c < -1` (with a different scale than the column)
+    let scalar = decimal(-1, 0);
+    let groups = metadata
+        .row_groups
+        .iter()
+        .map(|group| row_group_might_satisfy(group, 0, Comparison::Lt, &scalar))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(groups, vec![true, false, false]);
+
+    // `c >= 1.00`
+    let scalar = decimal(100, 2);
+    let mut reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    reader.set_groups_filter(Arc::new(move |_, group| {
+        row_group_might_satisfy(group, 0, Comparison::GtEq, &scalar).unwrap()
+    }));
+    let read_batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(read_batches, batches[1..].to_vec());
+    Ok(())
+}
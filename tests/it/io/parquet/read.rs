@@ -1,3 +1,4 @@
+use std::convert::TryInto;
 use std::fs::File;
 
 use arrow2::array::*;
@@ -6,6 +7,9 @@ use arrow2::io::parquet::read::*;
 
 use super::*;
 
+/// The columns of the basic cases that the columns of the "encoded" cases were written from
+const ENCODED_COLUMNS: [usize; 4] = [0, 1, 2, 2];
+
 fn test_pyarrow_integration(
     column: usize,
     version: usize,
@@ -37,18 +41,29 @@ fn test_pyarrow_integration(
         ("basic", false) => pyarrow_nullable(column),
         ("nested", false) => pyarrow_nested_nullable(column),
         ("struct", false) => pyarrow_struct(column),
+        ("map", false) => pyarrow_map(column),
+        ("dict", false) => pyarrow_dict(column),
+        ("encoded", true) => pyarrow_required(ENCODED_COLUMNS[column]),
+        ("encoded", false) => pyarrow_nullable(ENCODED_COLUMNS[column]),
         _ => unreachable!(),
     };
+    assert_eq!(expected.as_ref(), array.as_ref());
+
+    // statistics of maps and dictionaries are those of their parquet leaves
+    if matches!(type_, "map" | "dict") {
+        return Ok(());
+    }
 
     let expected_statistics = match (type_, required) {
         ("basic", true) => pyarrow_required_statistics(column),
         ("basic", false) => pyarrow_nullable_statistics(column),
         ("nested", false) => pyarrow_nested_nullable_statistics(column),
         ("struct", false) => pyarrow_struct_statistics(column),
+        ("encoded", true) => pyarrow_required_statistics(ENCODED_COLUMNS[column]),
+        ("encoded", false) => pyarrow_nullable_statistics(ENCODED_COLUMNS[column]),
         _ => unreachable!(),
     };
 
-    assert_eq!(expected.as_ref(), array.as_ref());
     assert_eq!(expected_statistics, statistics);
 
     Ok(())
@@ -213,6 +228,42 @@ fn v1_nested_int64_required_required() -> Result<()> {
     test_pyarrow_integration(2, 1, "nested", false, false, None)
 }
 
+#[test]
+fn v1_map() -> Result<()> {
+    test_pyarrow_integration(0, 1, "map", false, false, None)
+}
+
+#[test]
+fn v2_map() -> Result<()> {
+    test_pyarrow_integration(0, 2, "map", false, false, None)
+}
+
+#[test]
+fn v1_dict_int64() -> Result<()> {
+    test_pyarrow_integration(0, 1, "dict", true, false, None)
+}
+
+#[test]
+fn v2_dict_float64() -> Result<()> {
+    test_pyarrow_integration(1, 2, "dict", true, false, Some("snappy"))
+}
+
+#[test]
+fn v1_dict_utf8() -> Result<()> {
+    test_pyarrow_integration(2, 1, "dict", true, false, None)
+}
+
+// the pages are plain-encoded, but `ARROW:schema` declares the columns as dictionaries
+#[test]
+fn v2_dict_int64_plain() -> Result<()> {
+    test_pyarrow_integration(0, 2, "dict", false, false, None)
+}
+
+#[test]
+fn v1_dict_float64_plain() -> Result<()> {
+    test_pyarrow_integration(1, 1, "dict", false, false, None)
+}
+
 #[test]
 fn v2_nested_i16() -> Result<()> {
     test_pyarrow_integration(3, 2, "nested", false, false, None)
@@ -363,6 +414,38 @@ fn v1_struct_struct_optional() -> Result<()> {
     test_pyarrow_integration(1, 1, "struct", false, false, None)
 }
 
+#[test]
+fn v1_encoded_nullable() -> Result<()> {
+    for column in 0..ENCODED_COLUMNS.len() {
+        test_pyarrow_integration(column, 1, "encoded", false, false, None)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn v1_encoded_required() -> Result<()> {
+    for column in 0..ENCODED_COLUMNS.len() {
+        test_pyarrow_integration(column, 1, "encoded", false, true, None)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn v2_encoded_nullable() -> Result<()> {
+    for column in 0..ENCODED_COLUMNS.len() {
+        test_pyarrow_integration(column, 2, "encoded", false, false, None)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn v2_encoded_required() -> Result<()> {
+    for column in 0..ENCODED_COLUMNS.len() {
+        test_pyarrow_integration(column, 2, "encoded", false, true, None)?;
+    }
+    Ok(())
+}
+
 #[test]
 fn all_types() -> Result<()> {
     let path = "testing/parquet-testing/data/alltypes_plain.parquet";
@@ -400,5 +483,831 @@ fn all_types() -> Result<()> {
         &BinaryArray::<i32>::from_slice([[48], [49], [48], [49], [48], [49], [48], [49]])
     );
 
+    // written by Impala as INT96, without a logical or converted type
+    let result = batches[0]
+        .column(10)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(
+        result.data_type(),
+        &DataType::Timestamp(TimeUnit::Nanosecond, None)
+    );
+    let expected = [
+        "2009-03-01T00:00:00",
+        "2009-03-01T00:01:00",
+        "2009-04-01T00:00:00",
+        "2009-04-01T00:01:00",
+        "2009-02-01T00:00:00",
+        "2009-02-01T00:01:00",
+        "2009-01-01T00:00:00",
+        "2009-01-01T00:01:00",
+    ]
+    .iter()
+    .map(|x| {
+        x.parse::<chrono::NaiveDateTime>()
+            .unwrap()
+            .timestamp_nanos()
+    })
+    .collect::<Vec<_>>();
+    assert_eq!(result.values().as_slice(), expected.as_slice());
+
+    Ok(())
+}
+
+/// A file written by Spark 3.2.0 (parquet-mr 1.12.1), whose schema is only declared via
+/// parquet types and Spark's own metadata
+#[test]
+fn golden_spark() -> Result<()> {
+    let path = "fixtures/spark/spark_3_2_0_order_book.snappy.parquet";
+    let reader = std::fs::File::open(path)?;
+    let reader = RecordReader::try_new(reader, None, None, None, None)?;
+
+    let levels = DataType::List(Box::new(Field::new(
+        "element",
+        DataType::List(Box::new(Field::new("element", DataType::Float64, true))),
+        true,
+    )));
+    let data_types = reader
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        vec![DataType::Utf8, levels.clone(), levels, DataType::Int64]
+    );
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let batch = &batches[0];
+    assert_eq!(batch.num_rows(), 10);
+
+    let pr = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    assert_eq!(pr.value(0), "1INCH_USDT");
+    assert_eq!(pr.value(9), "AAVE_USDT");
+
+    let event_ms = batch
+        .column(3)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(event_ms.value(0), 1639354800605);
+
+    let asks = batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<ListArray<i32>>()
+        .unwrap();
+    let first = asks.value(0);
+    let first = first.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(first.len(), 5);
+    Ok(())
+}
+
+/// A file written by `parquet_integration/write_spark.py` with Spark configured like its
+/// versions before 3.0, whose timestamps are INT96 without a logical or converted type
+#[test]
+fn golden_spark_int96() -> Result<()> {
+    let path = "fixtures/spark/int96.parquet";
+    let reader = std::fs::File::open(path)?;
+    let reader = RecordReader::try_new(reader, None, None, None, None)?;
+    let data_types = reader
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        vec![
+            DataType::Int64,
+            DataType::Timestamp(TimeUnit::Nanosecond, None)
+        ]
+    );
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let expected = Int64Array::from(&[
+        Some(0),
+        None,
+        Some(1_600_000_000_123_456_000),
+        Some(-616_850_999_500_000_000),
+    ])
+    .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(batches[0].column(1).as_ref(), &expected as &dyn Array);
     Ok(())
 }
+
+/// `DELTA_BINARY_PACKED`-encoded columns written by parquet-mr, whose deltas are `n` bits wide
+/// in column `bitwidth{n}`. The file and its expected values are `data/delta_binary_packed*` of
+/// apache/parquet-testing, which are not part of the revision of the `testing/parquet-testing`
+/// submodule used here, and so this test requires a checkout of a more recent revision.
+#[test]
+#[ignore = "requires data/delta_binary_packed.parquet of a recent apache/parquet-testing"]
+fn golden_delta_binary_packed() -> Result<()> {
+    let path = "testing/parquet-testing/data/delta_binary_packed.parquet";
+    let expected =
+        std::fs::read_to_string("testing/parquet-testing/data/delta_binary_packed_expect.csv")?;
+    let mut lines = expected.lines();
+    let names = lines.next().unwrap().split(',').collect::<Vec<_>>();
+    let rows = lines
+        .map(|line| {
+            line.split(',')
+                .map(|x| x.parse::<i64>().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let columns = (0..names.len()).collect::<Vec<_>>();
+
+    let reader = std::fs::File::open(path)?;
+    let reader = RecordReader::try_new(reader, Some(columns.clone()), None, None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+
+    for (result, column) in batches[0].columns().iter().zip(columns) {
+        let expected = rows.iter().map(|row| row[column]).collect::<Vec<_>>();
+        let result = if let Some(array) = result.as_any().downcast_ref::<Int32Array>() {
+            array.values().iter().map(|x| *x as i64).collect::<Vec<_>>()
+        } else {
+            let array = result.as_any().downcast_ref::<Int64Array>().unwrap();
+            array.values().to_vec()
+        };
+        assert_eq!(result, expected, "{}", names[column]);
+    }
+    Ok(())
+}
+
+/// A `DELTA_LENGTH_BYTE_ARRAY`-encoded column written by parquet-mr, from
+/// `data/delta_length_byte_array.parquet` of apache/parquet-testing (see
+/// `golden_delta_binary_packed`).
+#[test]
+#[ignore = "requires data/delta_length_byte_array.parquet of a recent apache/parquet-testing"]
+fn golden_delta_length_byte_array() -> Result<()> {
+    let path = "testing/parquet-testing/data/delta_length_byte_array.parquet";
+    let reader = std::fs::File::open(path)?;
+    let reader = RecordReader::try_new(reader, None, None, None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+
+    let result = batches[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    let expected = (0..1000)
+        .map(|i| format!("apple_banana_mango{}", i * i))
+        .collect::<Vec<_>>();
+    assert_eq!(result.values_iter().collect::<Vec<_>>(), expected);
+    Ok(())
+}
+
+/// Writes a file the way old versions of Spark and Hive do: timestamps are stored as `INT96`,
+/// types are only annotated with (deprecated) converted types and there is no arrow schema
+/// in the file's metadata.
+fn legacy_file() -> Result<Vec<u8>> {
+    use parquet2::encoding::Encoding;
+    use parquet2::page::{DataPage, DataPageHeader, DataPageHeaderV1, EncodedPage};
+    use parquet2::schema::types::{PhysicalType, PrimitiveConvertedType};
+    use parquet2::schema::Repetition;
+    use parquet2::write::{Compressor, DynIter, DynStreamingIterator, Version, WriteOptions};
+
+    let field = |name: &str, physical_type, converted_type| {
+        ParquetType::try_from_primitive(
+            name.to_string(),
+            physical_type,
+            Repetition::Required,
+            converted_type,
+            None,
+            None,
+        )
+    };
+    let fields = vec![
+        field("ts", PhysicalType::Int96, None)?,
+        field(
+            "ts_ms",
+            PhysicalType::Int64,
+            Some(PrimitiveConvertedType::TimestampMillis),
+        )?,
+        field(
+            "ts_us",
+            PhysicalType::Int64,
+            Some(PrimitiveConvertedType::TimestampMicros),
+        )?,
+        field(
+            "date",
+            PhysicalType::Int32,
+            Some(PrimitiveConvertedType::Date),
+        )?,
+        field(
+            "time",
+            PhysicalType::Int32,
+            Some(PrimitiveConvertedType::TimeMillis),
+        )?,
+        field(
+            "name",
+            PhysicalType::ByteArray,
+            Some(PrimitiveConvertedType::Utf8),
+        )?,
+        field(
+            "decimal",
+            PhysicalType::Int64,
+            Some(PrimitiveConvertedType::Decimal(18, 2)),
+        )?,
+    ];
+    let schema = SchemaDescriptor::new("spark_schema".to_string(), fields);
+
+    // 1970-01-02T00:00:00.000000001 and 1969-12-31T23:59:59.999999999
+    let int96 = [[1u32, 0, 2_440_589], [2_437_873_663, 20116, 2_440_587]];
+    let int64 = [1i64, -1];
+    let int32 = [1i32, 2];
+
+    let int96_values = int96
+        .iter()
+        .flat_map(|x| x.iter().flat_map(|x| x.to_le_bytes()))
+        .collect::<Vec<_>>();
+    let int64_values = int64
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect::<Vec<_>>();
+    let int32_values = int32
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect::<Vec<_>>();
+    let utf8_values = ["a", "bb"]
+        .iter()
+        .flat_map(|x| {
+            (x.len() as u32)
+                .to_le_bytes()
+                .iter()
+                .chain(x.as_bytes())
+                .copied()
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let descriptors = schema.columns().to_vec();
+    let buffers = vec![
+        (int96_values, None),
+        (int64_values.clone(), None),
+        (int64_values.clone(), None),
+        (int32_values.clone(), None),
+        (int32_values, None),
+        (utf8_values, None),
+        (int64_values, None),
+    ];
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V1,
+    };
+    let columns = buffers.into_iter().zip(descriptors.into_iter()).map(
+        |((buffer, statistics), descriptor)| {
+            let header = DataPageHeader::V1(DataPageHeaderV1 {
+                num_values: 2,
+                encoding: Encoding::Plain.into(),
+                definition_level_encoding: Encoding::Rle.into(),
+                repetition_level_encoding: Encoding::Rle.into(),
+                statistics,
+            });
+            let page = EncodedPage::Data(DataPage::new(header, buffer, None, descriptor));
+            let pages = DynIter::new(std::iter::once(Ok(page)));
+            let compressed = Compressor::new(pages, options.compression, vec![]);
+            Ok(DynStreamingIterator::new(
+                compressed.map_err(ArrowError::from),
+            ))
+        },
+    );
+    let row_groups = std::iter::once(Ok(DynIter::new(columns)));
+
+    let mut writer = Cursor::new(vec![]);
+    parquet_write_file(&mut writer, row_groups, schema, options, None, None)?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn legacy_types() -> Result<()> {
+    let data = legacy_file()?;
+    let mut reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+
+    let expected = Schema::new(vec![
+        Field::new("ts", DataType::Timestamp(TimeUnit::Nanosecond, None), false),
+        Field::new(
+            "ts_ms",
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            false,
+        ),
+        Field::new(
+            "ts_us",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            false,
+        ),
+        Field::new("date", DataType::Date32, false),
+        Field::new("time", DataType::Time32(TimeUnit::Millisecond), false),
+        Field::new("name", DataType::Utf8, false),
+        Field::new("decimal", DataType::Decimal(18, 2), false),
+    ]);
+    assert_eq!(reader.schema().fields(), expected.fields());
+
+    let batch = reader.next().unwrap()?;
+    let int96 = PrimitiveArray::<i64>::from_slice([86_400_000_000_001, -1])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(batch.column(0).as_ref(), &int96 as &dyn Array);
+    let ts_ms = PrimitiveArray::<i64>::from_slice([1, -1])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    assert_eq!(batch.column(1).as_ref(), &ts_ms as &dyn Array);
+    let date = Int32Array::from_slice([1, 2]).to(DataType::Date32);
+    assert_eq!(batch.column(3).as_ref(), &date as &dyn Array);
+    let name = Utf8Array::<i32>::from_slice(["a", "bb"]);
+    assert_eq!(batch.column(5).as_ref(), &name as &dyn Array);
+    let decimal = PrimitiveArray::<i128>::from_slice([1, -1]).to(DataType::Decimal(18, 2));
+    assert_eq!(batch.column(6).as_ref(), &decimal as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn int96_statistics() -> Result<()> {
+    use parquet2::metadata::ColumnDescriptor;
+    use parquet2::schema::types::PhysicalType;
+    use parquet2::statistics::PrimitiveStatistics as ParquetPrimitiveStatistics;
+
+    let type_ = ParquetType::from_physical("ts".to_string(), PhysicalType::Int96);
+    let statistics = ParquetPrimitiveStatistics::<[u32; 3]> {
+        descriptor: ColumnDescriptor::new(type_.clone(), 1, 0, vec!["ts".to_string()], type_),
+        null_count: Some(1),
+        distinct_count: None,
+        // 1970-01-02T00:00:00.000000001 and 1969-12-31T23:59:59.999999999
+        max_value: Some([1, 0, 2_440_589]),
+        min_value: Some([2_437_873_663, 20116, 2_440_587]),
+    };
+    let statistics = deserialize_statistics(&statistics)?;
+
+    let expected = PrimitiveStatistics::<i64> {
+        data_type: DataType::Timestamp(TimeUnit::Nanosecond, None),
+        null_count: Some(1),
+        distinct_count: None,
+        min_value: Some(-1),
+        max_value: Some(86_400_000_000_001),
+    };
+    let statistics = statistics
+        .as_any()
+        .downcast_ref::<PrimitiveStatistics<i64>>()
+        .unwrap();
+    assert_eq!(statistics, &expected);
+    Ok(())
+}
+
+/// Writes `arrays` in a single row group where the column of each array is split in pages of
+/// `page_size` rows (except repeated columns, written in a single page).
+fn multi_page_write(arrays: &[Arc<dyn Array>], page_size: usize) -> Result<Vec<u8>> {
+    use parquet2::write::{Compressor, DynIter, DynStreamingIterator, Version, WriteOptions};
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Snappy,
+        version: Version::V1,
+    };
+    let fields = arrays
+        .iter()
+        .enumerate()
+        .map(|(i, array)| Field::new(format!("c{}", i), array.data_type().clone(), true))
+        .collect();
+    let schema = Schema::new(fields);
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let columns = arrays
+        .iter()
+        .zip(parquet_schema.columns().to_vec())
+        .map(|(array, descriptor)| {
+            let page_size = if descriptor.max_rep_level() == 0 {
+                page_size
+            } else {
+                array.len()
+            };
+            let pages = (0..array.len())
+                .step_by(page_size)
+                .map(|offset| {
+                    let length = page_size.min(array.len() - offset);
+                    let page = array_to_page(
+                        array.slice(offset, length).as_ref(),
+                        descriptor.clone(),
+                        options,
+                        Encoding::Plain,
+                    )?;
+                    Ok(page)
+                })
+                .collect::<Vec<_>>();
+            let compressed =
+                Compressor::new(DynIter::new(pages.into_iter()), options.compression, vec![]);
+            Ok(DynStreamingIterator::new(
+                compressed.map_err(ArrowError::from),
+            ))
+        })
+        .collect::<Vec<_>>();
+    let row_groups = std::iter::once(Ok(DynIter::new(columns.into_iter())));
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn read_with_skip_and_limit() -> Result<()> {
+    let int: Int32Array = (0..30)
+        .map(|x| if x % 3 == 0 { None } else { Some(x) })
+        .collect();
+    let utf8 = Utf8Array::<i32>::from_iter_values((0..30).map(|x| x.to_string()));
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend((0..30).map(|x| match x % 4 {
+        0 => None,
+        1 => Some(vec![]),
+        _ => Some(vec![Some(x), None]),
+    }))?;
+    let list: ListArray<i32> = list.into();
+
+    // 3 pages of 10 rows. parquet2 counts the rows of a nested column by its number of values,
+    // so the list is written in its own file.
+    check_skip_and_limit(&[Arc::new(int), Arc::new(utf8)])?;
+    check_skip_and_limit(&[Arc::new(list)])
+}
+
+fn check_skip_and_limit(arrays: &[Arc<dyn Array>]) -> Result<()> {
+    let data = multi_page_write(arrays, 10)?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let schema = get_schema(&metadata)?;
+
+    for skip in [0, 1, 9, 10, 11, 19, 20, 21, 29, 30, 31] {
+        for limit in [
+            None,
+            Some(0),
+            Some(1),
+            Some(9),
+            Some(10),
+            Some(11),
+            Some(21),
+            Some(40),
+        ] {
+            for (column, expected) in arrays.iter().enumerate() {
+                let columns = get_column_iterator(&mut reader, &metadata, 0, column, None, vec![]);
+                let (array, _, _) = column_iter_to_array_with_limit(
+                    columns,
+                    &schema.fields()[column],
+                    vec![],
+                    skip,
+                    limit,
+                )?;
+
+                let offset = skip.min(expected.len());
+                let length = limit.unwrap_or(usize::MAX).min(expected.len() - offset);
+                assert_eq!(
+                    array.as_ref(),
+                    expected.slice(offset, length).as_ref(),
+                    "column {}, skip {}, limit {:?}",
+                    column,
+                    skip,
+                    limit
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn record_reader_limit_within_page() -> Result<()> {
+    let int: Int32Array = (0..30)
+        .map(|x| if x % 3 == 0 { None } else { Some(x) })
+        .collect();
+    let expected = int.slice(0, 11);
+    let data = multi_page_write(&[Arc::new(int)], 10)?;
+
+    let reader = RecordReader::try_new(Cursor::new(data), None, Some(11), None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].column(0).as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+fn byte_stream_split_encode(values: &[u8], size: usize) -> Vec<u8> {
+    let length = values.len() / size;
+    (0..size)
+        .flat_map(|j| (0..length).map(move |i| values[i * size + j]))
+        .collect()
+}
+
+fn plain_binary_values(mut values: &[u8]) -> Vec<&[u8]> {
+    let mut result = vec![];
+    while !values.is_empty() {
+        let length = u32::from_le_bytes(values[..4].try_into().unwrap()) as usize;
+        result.push(&values[4..4 + length]);
+        values = &values[4 + length..];
+    }
+    result
+}
+
+fn delta_byte_array_encode(values: &[&[u8]], buffer: &mut Vec<u8>) {
+    use parquet2::encoding::{delta_bitpacked, delta_length_byte_array};
+
+    let mut previous: &[u8] = &[];
+    let mut prefixes = vec![];
+    let mut suffixes = vec![];
+    for value in values {
+        let prefix = previous
+            .iter()
+            .zip(value.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        prefixes.push(prefix as i64);
+        suffixes.push(&value[prefix..]);
+        previous = value;
+    }
+    delta_bitpacked::encode(prefixes.into_iter(), buffer);
+    delta_length_byte_array::encode(suffixes.iter(), buffer);
+}
+
+/// Writes `array` in a single page whose values are re-encoded from `PLAIN` to `encoding`,
+/// since arrow2 does not write them with this encoding.
+fn reencoded_write(array: &dyn Array, version: Version, encoding: Encoding) -> Result<Vec<u8>> {
+    use parquet2::encoding::delta_bitpacked;
+
+    write_with_values(array, version, encoding, |values, size| {
+        let mut buffer = vec![];
+        match encoding {
+            Encoding::ByteStreamSplit => buffer.extend(byte_stream_split_encode(values, size)),
+            Encoding::DeltaBinaryPacked => {
+                let values = values.chunks_exact(size).map(|x| match size {
+                    4 => i32::from_le_bytes(x.try_into().unwrap()) as i64,
+                    _ => i64::from_le_bytes(x.try_into().unwrap()),
+                });
+                delta_bitpacked::encode(values.collect::<Vec<_>>().into_iter(), &mut buffer)
+            }
+            Encoding::DeltaLengthByteArray => {
+                let values = plain_binary_values(values);
+                parquet2::encoding::delta_length_byte_array::encode(values.iter(), &mut buffer)
+            }
+            Encoding::DeltaByteArray => {
+                delta_byte_array_encode(&plain_binary_values(values), &mut buffer)
+            }
+            _ => unreachable!(),
+        }
+        buffer
+    })
+}
+
+/// Writes `array` in a single page with `encoding`, whose values are `encode(values, size)`
+/// for its `PLAIN`-encoded values of `size` bytes (0 for variable-sized values).
+fn write_with_values<F>(
+    array: &dyn Array,
+    version: Version,
+    encoding: Encoding,
+    encode: F,
+) -> Result<Vec<u8>>
+where
+    F: FnOnce(&[u8], usize) -> Vec<u8>,
+{
+    use parquet2::page::{split_buffer, DataPage, EncodedPage};
+    use parquet2::write::{Compressor, DynIter, DynStreamingIterator, WriteOptions};
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version,
+    };
+    let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), true)]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let descriptor = parquet_schema.columns()[0].clone();
+
+    let page = match array_to_page(array, descriptor.clone(), options, Encoding::Plain)? {
+        EncodedPage::Data(page) => page,
+        _ => unreachable!(),
+    };
+    let (_, _, values) = split_buffer(&page, &descriptor);
+    let mut buffer = page.buffer()[..page.buffer().len() - values.len()].to_vec();
+
+    let size = match descriptor.physical_type() {
+        PhysicalType::Int32 | PhysicalType::Float => 4,
+        PhysicalType::Int64 | PhysicalType::Double => 8,
+        _ => 0,
+    };
+    buffer.extend(encode(values, size));
+
+    let mut header = page.header().clone();
+    match &mut header {
+        DataPageHeader::V1(header) => header.encoding = encoding.into(),
+        DataPageHeader::V2(header) => header.encoding = encoding.into(),
+    }
+    let page = EncodedPage::Data(DataPage::new(header, buffer, None, descriptor));
+
+    let pages = DynIter::new(std::iter::once(Ok(page)));
+    let compressed = Compressor::new(pages, options.compression, vec![]).map_err(ArrowError::from);
+    let column = DynStreamingIterator::new(compressed);
+    let row_groups = std::iter::once(Ok(DynIter::new(std::iter::once(Ok(column)))));
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok(writer.into_inner())
+}
+
+fn check_reencoded(array: &dyn Array, encoding: Encoding) -> Result<()> {
+    for version in [Version::V1, Version::V2] {
+        let data = reencoded_write(array, version, encoding)?;
+        let (_, batches) = integration_read(&data)?;
+        assert_eq!(
+            batches[0].column(0).as_ref(),
+            array,
+            "{:?} {:?}",
+            version,
+            encoding
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn byte_stream_split() -> Result<()> {
+    let array = Float32Array::from([Some(1.5), None, Some(-0.25), Some(f32::MAX), None]);
+    check_reencoded(&array, Encoding::ByteStreamSplit)?;
+    let array = Float64Array::from_values((0..300).map(|x| x as f64 / 7.0));
+    check_reencoded(&array, Encoding::ByteStreamSplit)
+}
+
+#[test]
+fn delta_binary_packed() -> Result<()> {
+    let array = Int32Array::from([Some(-10), None, Some(5), Some(5), None, Some(1000)]);
+    check_reencoded(&array, Encoding::DeltaBinaryPacked)?;
+    // spans several blocks of 128 values
+    let array = Int64Array::from_values((0..300).map(|x| (x % 17) * 1_000 - 8_000));
+    check_reencoded(&array, Encoding::DeltaBinaryPacked)?;
+    let array = Int64Array::from_slice([i64::MAX - 3]);
+    check_reencoded(&array, Encoding::DeltaBinaryPacked)
+}
+
+/// Encodes a `DELTA_BINARY_PACKED` buffer with a single block of 4 miniblocks of 32 values.
+fn delta_block(
+    count: u64,
+    first: i64,
+    min_delta: i64,
+    bit_widths: [u8; 4],
+    miniblocks: &[u8],
+) -> Vec<u8> {
+    use parquet2::encoding::{uleb128, zigzag_leb128};
+
+    let mut buffer = vec![128, 1, 4];
+    let mut container = [0; 10];
+    let length = uleb128::encode(count, &mut container);
+    buffer.extend_from_slice(&container[..length]);
+    let (container, length) = zigzag_leb128::encode(first);
+    buffer.extend_from_slice(&container[..length]);
+    let (container, length) = zigzag_leb128::encode(min_delta);
+    buffer.extend_from_slice(&container[..length]);
+    buffer.extend_from_slice(&bit_widths);
+    buffer.extend_from_slice(miniblocks);
+    buffer
+}
+
+fn read_with_values(
+    array: &dyn Array,
+    encoding: Encoding,
+    values: &[u8],
+) -> Result<Arc<dyn Array>> {
+    let data = write_with_values(array, Version::V2, encoding, |_, _| values.to_vec())?;
+    let (_, batches) = integration_read(&data)?;
+    Ok(batches[0].column(0).clone())
+}
+
+#[test]
+fn delta_binary_packed_wide() -> Result<()> {
+    // deltas of 40 bits
+    let mut miniblock = vec![0; 32 * 40 / 8];
+    miniblock[4] = 0x80;
+    let values = delta_block(2, 0, 0, [40, 0, 0, 0], &miniblock);
+    let expected = Int64Array::from_slice([0, 1 << 39]);
+    let result = read_with_values(&expected, Encoding::DeltaBinaryPacked, &values)?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // INT32 deltas are at most 32 bits wide
+    let miniblock = vec![0; 32 * 33 / 8];
+    let values = delta_block(2, 0, 0, [33, 0, 0, 0], &miniblock);
+    let array = Int32Array::from_slice([0, 0]);
+    assert!(read_with_values(&array, Encoding::DeltaBinaryPacked, &values).is_err());
+    Ok(())
+}
+
+#[test]
+fn delta_binary_packed_overflow() -> Result<()> {
+    // the delta between `i64::MAX` and `i64::MIN` wraps around
+    let values = delta_block(2, i64::MAX, 1, [0, 0, 0, 0], &[]);
+    let expected = Int64Array::from_slice([i64::MAX, i64::MIN]);
+    let result = read_with_values(&expected, Encoding::DeltaBinaryPacked, &values)?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let values = delta_block(2, i64::MIN, i64::MIN, [0, 0, 0, 0], &[]);
+    let expected = Int64Array::from_slice([i64::MIN, 0]);
+    let result = read_with_values(&expected, Encoding::DeltaBinaryPacked, &values)?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn delta_binary_packed_invalid() -> Result<()> {
+    let array = Int64Array::from_slice([0, 1]);
+    let invalid = [
+        // the miniblock is truncated
+        delta_block(2, 0, 0, [8, 0, 0, 0], &[1; 31]),
+        // the bit widths are truncated
+        delta_block(2, 0, 0, [8, 0, 0, 0], &[])[..6].to_vec(),
+        // miniblocks are wider than 64 bits
+        delta_block(2, 0, 0, [65, 0, 0, 0], &[0; 32 * 65 / 8]),
+        // the block size is not a multiple of 128
+        vec![100, 4, 2, 0, 0, 0, 0, 0, 0],
+        // the block size does not fit in 64 bits
+        vec![0xff; 11],
+        // the block size does not fit in 32 bits
+        vec![0x80, 0x80, 0x80, 0x80, 0x80, 0x01, 4, 2, 0],
+        // more values than the page, in miniblocks of zero-width deltas without bytes
+        delta_block(1 << 40, 0, 0, [0, 0, 0, 0], &[]),
+    ];
+    for (i, values) in invalid.iter().enumerate() {
+        let result = read_with_values(&array, Encoding::DeltaBinaryPacked, values);
+        assert!(result.is_err(), "{}", i);
+    }
+
+    let array = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let values = delta_block(2, 1, 0, [65, 0, 0, 0], &[]);
+    assert!(read_with_values(&array, Encoding::DeltaLengthByteArray, &values).is_err());
+    assert!(read_with_values(&array, Encoding::DeltaByteArray, &values).is_err());
+    Ok(())
+}
+
+#[test]
+fn byte_stream_split_invalid() -> Result<()> {
+    let array = Float32Array::from_slice([1.0, 2.0]);
+    let result = read_with_values(&array, Encoding::ByteStreamSplit, &[0; 7]);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn delta_length_byte_array() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("hello"), None, Some(""), Some("world"), None]);
+    check_reencoded(&array, Encoding::DeltaLengthByteArray)?;
+    let array = BinaryArray::<i64>::from_iter_values((0..300).map(|x| x.to_string()));
+    check_reencoded(&array, Encoding::DeltaLengthByteArray)
+}
+
+#[test]
+fn delta_byte_array() -> Result<()> {
+    let array = Utf8Array::<i32>::from([
+        Some("apple"),
+        None,
+        Some("applesauce"),
+        Some("apply"),
+        Some(""),
+        None,
+        Some("banana"),
+    ]);
+    check_reencoded(&array, Encoding::DeltaByteArray)?;
+    let array = Utf8Array::<i64>::from_iter_values((0..300).map(|x| format!("prefix_{}", x)));
+    check_reencoded(&array, Encoding::DeltaByteArray)
+}
+
+#[test]
+fn delta_nested() -> Result<()> {
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    list.try_extend(vec![
+        Some(vec![Some(1), None, Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(-4)]),
+    ])?;
+    let list: ListArray<i32> = list.into();
+    check_reencoded(&list, Encoding::DeltaBinaryPacked)?;
+
+    let mut list = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    list.try_extend(vec![
+        Some(vec![Some("aa"), None, Some("ab")]),
+        None,
+        Some(vec![Some("abc")]),
+    ])?;
+    let list: ListArray<i32> = list.into();
+    check_reencoded(&list, Encoding::DeltaByteArray)?;
+    check_reencoded(&list, Encoding::DeltaLengthByteArray)
+}
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use arrow2::{
-    array::*, bitmap::Bitmap, buffer::Buffer, datatypes::*, error::Result, io::print::*,
-    record_batch::RecordBatch,
+    array::*, bitmap::Bitmap, buffer::Buffer, columns::Columns, datatypes::*, error::Result,
+    io::print::*, record_batch::RecordBatch,
 };
 
 #[test]
@@ -416,3 +416,133 @@ fn write_union() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn write_nested_and_logical_types() -> Result<()> {
+    let decimal = Int128Array::from(&[Some(12345), Some(-5), None]).to(DataType::Decimal(10, 2));
+
+    let duration =
+        Int64Array::from(&[Some(1), None, Some(3)]).to(DataType::Duration(TimeUnit::Second));
+
+    let keys = Int32Array::from(&[Some(1), Some(0), None]);
+    let values = Utf8Array::<i32>::from_slice(["x", "y"]);
+    let dictionary = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+    let list_data_type =
+        DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let list_values = Int32Array::from(&[Some(1), None, Some(3), Some(4), Some(5), Some(6)]);
+    let fixed_size_list = FixedSizeListArray::from_data(
+        list_data_type,
+        Arc::new(list_values),
+        Some(Bitmap::from(&[true, true, false])),
+    );
+
+    let batch = RecordBatch::try_from_iter(vec![
+        ("decimal", Arc::new(decimal) as Arc<dyn Array>),
+        ("duration", Arc::new(duration) as Arc<dyn Array>),
+        ("dictionary", Arc::new(dictionary) as Arc<dyn Array>),
+        ("list", Arc::new(fixed_size_list) as Arc<dyn Array>),
+    ])?;
+
+    let table = write(&[batch]);
+
+    let expected = vec![
+        "+---------+----------+------------+--------+",
+        "| decimal | duration | dictionary | list   |",
+        "+---------+----------+------------+--------+",
+        "| 123.45  | 1s       | y          | [1, ]  |",
+        "| -0.05   |          | x          | [3, 4] |",
+        "|         | 3s       |            |        |",
+        "+---------+----------+------------+--------+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+    Ok(())
+}
+
+fn schema_and_columns() -> Result<(Schema, Columns<Arc<dyn Array>>)> {
+    let decimal = Int128Array::from(&[Some(12345), Some(-5), None]).to(DataType::Decimal(10, 2));
+
+    let timestamp = Int64Array::from(&[Some(1), None, Some(86_400_000)]).to(DataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("+01:00".to_string()),
+    ));
+
+    let mut list = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    list.try_extend(vec![
+        Some(vec![Some("a"), None]),
+        None,
+        Some(vec![Some("a very long string")]),
+    ])?;
+    let list: ListArray<i32> = list.into();
+
+    let schema = Schema::new(vec![
+        Field::new("decimal", decimal.data_type().clone(), true),
+        Field::new("timestamp", timestamp.data_type().clone(), true),
+        Field::new("list", list.data_type().clone(), true),
+    ]);
+    let columns = Columns::try_new(vec![
+        Arc::new(decimal) as Arc<dyn Array>,
+        Arc::new(timestamp),
+        Arc::new(list),
+    ])?;
+    Ok((schema, columns))
+}
+
+#[test]
+fn write_schema_and_columns() -> Result<()> {
+    let (schema, columns) = schema_and_columns()?;
+
+    let table = write_with_schema(&schema, &[columns.clone(), columns]);
+    let expected = vec![
+        "+----------------+--------------------------------+----------------------+",
+        "| decimal        | timestamp                      | list                 |",
+        "| Decimal(10, 2) | Timestamp(Millisecond, +01:00) | List(Utf8)           |",
+        "+----------------+--------------------------------+----------------------+",
+        "| 123.45         | 1970-01-01 01:00:00.001 +01:00 | [a, ]                |",
+        "| -0.05          |                                |                      |",
+        "|                | 1970-01-02 01:00:00 +01:00     | [a very long string] |",
+        "| 123.45         | 1970-01-01 01:00:00.001 +01:00 | [a, ]                |",
+        "| -0.05          |                                |                      |",
+        "|                | 1970-01-02 01:00:00 +01:00     | [a very long string] |",
+        "+----------------+--------------------------------+----------------------+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+    Ok(())
+}
+
+#[test]
+fn write_with_max_width_and_rows() -> Result<()> {
+    let (schema, columns) = schema_and_columns()?;
+
+    let options = WriteOptions {
+        max_width: Some(12),
+        max_rows: Some(4),
+    };
+    let table = write_with_options(&schema, &[columns.clone(), columns], &options);
+    let expected = vec![
+        "+----------------+--------------------------------+--------------+",
+        "| decimal        | timestamp                      | list         |",
+        "| Decimal(10, 2) | Timestamp(Millisecond, +01:00) | List(Utf8)   |",
+        "+----------------+--------------------------------+--------------+",
+        "| 123.45         | 1970-01-0...                   | [a, ]        |",
+        "| -0.05          |                                |              |",
+        "|                | 1970-01-0...                   | [a very l... |",
+        "| 123.45         | 1970-01-0...                   | [a, ]        |",
+        "| ...            | ...                            | ...          |",
+        "+----------------+--------------------------------+--------------+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+    Ok(())
+}
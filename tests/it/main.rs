@@ -1,9 +1,14 @@
 mod array;
 mod bitmap;
 mod buffer;
+mod chunked;
+mod columns;
+mod datatypes;
 mod ffi;
 mod scalar;
 mod temporal_conversions;
+mod testing;
+mod types;
 
 mod io;
 mod test_util;
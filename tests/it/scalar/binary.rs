@@ -1,6 +1,8 @@
 use arrow2::{
+    array::{BinaryArray, BooleanArray},
+    compute::comparison::eq_scalar,
     datatypes::DataType,
-    scalar::{BinaryScalar, Scalar},
+    scalar::{new_scalar, BinaryScalar, Scalar},
 };
 
 #[allow(clippy::eq_op)]
@@ -31,3 +33,18 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn from_array() {
+    let array = BinaryArray::<i64>::from([Some(b"a".as_ref()), None, Some(b"b".as_ref())]);
+
+    let scalar = new_scalar(&array, 0);
+    assert_eq!(
+        scalar.as_ref(),
+        &BinaryScalar::<i64>::from(Some("a")) as &dyn Scalar
+    );
+    assert!(!new_scalar(&array, 1).is_valid());
+
+    let result = eq_scalar(&array, scalar.as_ref());
+    assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+}
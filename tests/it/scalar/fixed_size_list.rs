@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{Array, BooleanArray, FixedSizeListArray},
+    datatypes::{DataType, Field},
+    scalar::{new_scalar, FixedSizeListScalar, Scalar},
+};
+
+#[allow(clippy::eq_op)]
+#[test]
+fn equal() {
+    let dt = DataType::FixedSizeList(Box::new(Field::new("a", DataType::Boolean, true)), 2);
+    let a = FixedSizeListScalar::new(
+        dt.clone(),
+        Some(Arc::new(BooleanArray::from_slice([true, false])) as Arc<dyn Array>),
+    );
+    let b = FixedSizeListScalar::new(dt.clone(), None);
+    assert_eq!(a, a);
+    assert_eq!(b, b);
+    assert!(a != b);
+    let b = FixedSizeListScalar::new(
+        dt,
+        Some(Arc::new(BooleanArray::from_slice([true, true])) as Arc<dyn Array>),
+    );
+    assert!(a != b);
+    assert_eq!(b, b);
+}
+
+#[test]
+fn basics() {
+    let dt = DataType::FixedSizeList(Box::new(Field::new("a", DataType::Boolean, true)), 2);
+    let a = FixedSizeListScalar::new(
+        dt.clone(),
+        Some(Arc::new(BooleanArray::from_slice([true, false])) as Arc<dyn Array>),
+    );
+
+    assert_eq!(BooleanArray::from_slice([true, false]), a.values().as_ref());
+    assert_eq!(a.data_type(), &dt);
+    assert!(a.is_valid());
+
+    let _: &dyn std::any::Any = a.as_any();
+}
+
+#[test]
+fn from_array() {
+    let dt = DataType::FixedSizeList(Box::new(Field::new("a", DataType::Boolean, true)), 2);
+    let values = Arc::new(BooleanArray::from_slice([true, false, false, true]));
+    let validity = Some([true, false].into());
+    let array = FixedSizeListArray::from_data(dt.clone(), values, validity);
+
+    let scalar = new_scalar(&array, 0);
+    let expected = FixedSizeListScalar::new(
+        dt.clone(),
+        Some(Arc::new(BooleanArray::from_slice([true, false])) as Arc<dyn Array>),
+    );
+    assert_eq!(scalar.as_ref(), &expected as &dyn Scalar);
+
+    let scalar = new_scalar(&array, 1);
+    assert!(!scalar.is_valid());
+    assert_eq!(scalar.data_type(), &dt);
+}
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use arrow2::{
-    array::{Array, BooleanArray},
+    array::{Array, BooleanArray, ListArray},
+    buffer::Buffer,
     datatypes::{DataType, Field},
-    scalar::{ListScalar, Scalar},
+    scalar::{new_scalar, ListScalar, Scalar},
 };
 
 #[allow(clippy::eq_op)]
@@ -40,3 +41,27 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn from_array() {
+    let dt = DataType::List(Box::new(Field::new("a", DataType::Boolean, true)));
+    let values = Arc::new(BooleanArray::from_slice([true, false, true]));
+    let offsets = Buffer::from(vec![0, 2, 2, 3]);
+    let array = ListArray::<i32>::from_data(
+        dt.clone(),
+        offsets,
+        values,
+        Some([true, false, true].into()),
+    );
+
+    let scalar = new_scalar(&array, 0);
+    let expected = ListScalar::<i32>::new(
+        dt.clone(),
+        Some(Arc::new(BooleanArray::from_slice([true, false])) as Arc<dyn Array>),
+    );
+    assert_eq!(scalar.as_ref(), &expected as &dyn Scalar);
+    assert_eq!(
+        new_scalar(&array, 1).as_ref(),
+        &ListScalar::<i32>::new(dt, None) as &dyn Scalar
+    );
+}
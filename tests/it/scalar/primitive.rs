@@ -1,6 +1,8 @@
 use arrow2::{
-    datatypes::DataType,
-    scalar::{PrimitiveScalar, Scalar},
+    array::PrimitiveArray,
+    datatypes::{DataType, IntervalUnit},
+    scalar::{new_scalar, PrimitiveScalar, Scalar},
+    types::{days_ms, months_days_ns},
 };
 
 #[allow(clippy::eq_op)]
@@ -36,3 +38,26 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn interval() {
+    let array = PrimitiveArray::<days_ms>::from([Some(days_ms::new(1, 2)), None])
+        .to(DataType::Interval(IntervalUnit::DayTime));
+    let scalar = new_scalar(&array, 0);
+    let expected = PrimitiveScalar::new(
+        DataType::Interval(IntervalUnit::DayTime),
+        Some(days_ms::new(1, 2)),
+    );
+    assert_eq!(scalar.as_ref(), &expected as &dyn Scalar);
+    assert!(!new_scalar(&array, 1).is_valid());
+
+    let dt = DataType::Interval(IntervalUnit::MonthDayNano);
+    let array = PrimitiveArray::<months_days_ns>::from([Some(months_days_ns::new(1, 2, 3)), None])
+        .to(dt.clone());
+    let scalar = new_scalar(&array, 0);
+    let expected = PrimitiveScalar::new(dt.clone(), Some(months_days_ns::new(1, 2, 3)));
+    assert_eq!(scalar.as_ref(), &expected as &dyn Scalar);
+    let other = PrimitiveScalar::new(dt, Some(months_days_ns::new(1, 2, 4)));
+    assert!(scalar.as_ref() != &other as &dyn Scalar);
+    assert!(!new_scalar(&array, 1).is_valid());
+}
@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use arrow2::{
+    array::{Array, BooleanArray, StructArray},
     datatypes::{DataType, Field},
-    scalar::{BooleanScalar, Scalar, StructScalar},
+    scalar::{new_scalar, BooleanScalar, Scalar, StructScalar},
 };
 
 #[allow(clippy::eq_op)]
@@ -43,3 +44,24 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn from_array() {
+    let fields = vec![Field::new("a", DataType::Boolean, true)];
+    let dt = DataType::Struct(fields.clone());
+    let values = vec![Arc::new(BooleanArray::from_slice([true, false])) as Arc<dyn Array>];
+    let array = StructArray::from_data(dt.clone(), values, Some([true, false].into()));
+
+    let scalar = new_scalar(&array, 0);
+    let expected = StructScalar::new(
+        dt.clone(),
+        Some(vec![
+            Arc::new(BooleanScalar::from(Some(true))) as Arc<dyn Scalar>
+        ]),
+    );
+    assert_eq!(scalar.as_ref(), &expected as &dyn Scalar);
+    assert_eq!(
+        new_scalar(&array, 1).as_ref(),
+        &StructScalar::new(dt, None) as &dyn Scalar
+    );
+}
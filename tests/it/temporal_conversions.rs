@@ -111,6 +111,52 @@ fn add_interval_fixed_offset() {
     assert_eq!("1972-01-29 02:01:00 +01:00", format!("{}", r));
 }
 
+#[test]
+fn add_interval_clamps_month() {
+    let timestamp = 1612051200; // Sun Jan 31 2021 00:00:00 GMT+0000
+    let timeunit = TimeUnit::Second;
+
+    let r =
+        temporal_conversions::add_naive_interval(timestamp, timeunit, months_days_ns::new(1, 0, 0));
+    let r = temporal_conversions::timestamp_to_naive_datetime(r, timeunit);
+    assert_eq!("2021-02-28 00:00:00", format!("{}", r));
+
+    let r = temporal_conversions::add_naive_interval(
+        timestamp,
+        timeunit,
+        months_days_ns::new(-2, 0, 0),
+    );
+    let r = temporal_conversions::timestamp_to_naive_datetime(r, timeunit);
+    assert_eq!("2020-11-30 00:00:00", format!("{}", r));
+
+    let r = temporal_conversions::add_naive_interval(
+        timestamp,
+        timeunit,
+        months_days_ns::new(13, 0, 0),
+    );
+    let r = temporal_conversions::timestamp_to_naive_datetime(r, timeunit);
+    assert_eq!("2022-02-28 00:00:00", format!("{}", r));
+}
+
+#[test]
+fn add_interval_out_of_range() {
+    let timeunit = TimeUnit::Second;
+    let interval = months_days_ns::new(i32::MAX, 0, 0);
+    assert!(temporal_conversions::try_add_naive_interval(0, timeunit, interval).is_err());
+
+    let interval = months_days_ns::new(0, i32::MIN, 0);
+    assert!(temporal_conversions::add_interval_date32(0, interval).is_err());
+
+    // the last month in range is not clamped to the (out of range) next month
+    let date = temporal_conversions::add_interval_date32(
+        0,
+        months_days_ns::new((262_142 - 1970) * 12 + 11, 0, 0),
+    )
+    .unwrap();
+    let date = temporal_conversions::date32_to_date(date);
+    assert_eq!("+262142-12-01", format!("{}", date));
+}
+
 #[cfg(feature = "chrono-tz")]
 #[test]
 fn add_interval_timezone() {
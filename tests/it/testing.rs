@@ -0,0 +1,157 @@
+use arrow2::array::*;
+use arrow2::testing::assert_allclose;
+
+#[test]
+fn allclose_f64() {
+    let lhs = Float64Array::from(&[Some(1.0), None, Some(f64::NAN), Some(100.0)]);
+    let rhs = Float64Array::from(&[Some(1.0 + 1e-9), None, Some(f64::NAN), Some(100.1)]);
+    assert_allclose(&lhs, &rhs, 1e-2, 0.0);
+}
+
+#[test]
+fn allclose_f32_atol() {
+    let lhs = Float32Array::from_slice(&[0.0, 1.0]);
+    let rhs = Float32Array::from_slice(&[0.05, 1.05]);
+    assert_allclose(&lhs, &rhs, 0.0, 0.1);
+}
+
+#[test]
+fn allclose_infinite() {
+    let lhs = Float64Array::from_slice(&[f64::INFINITY, f64::NEG_INFINITY]);
+    assert_allclose(&lhs, &lhs, 0.0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "arrays differ at index 0")]
+fn allclose_infinite_differs() {
+    let lhs = Float64Array::from_slice(&[f64::INFINITY]);
+    let rhs = Float64Array::from_slice(&[f64::NEG_INFINITY]);
+    assert_allclose(&lhs, &rhs, 0.0, 0.0);
+}
+
+#[test]
+fn allclose_sliced() {
+    let lhs = Float64Array::from_slice(&[5.0, 1.0, 2.0]).slice(1, 2);
+    let rhs = Float64Array::from_slice(&[1.0, 2.0]);
+    assert_allclose(&lhs, &rhs, 0.0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "arrays differ at index 2")]
+fn allclose_differs() {
+    let lhs = Float64Array::from_slice(&[1.0, 2.0, 3.0, 4.0]);
+    let rhs = Float64Array::from_slice(&[1.0, 2.0, 3.5, 5.0]);
+    assert_allclose(&lhs, &rhs, 1e-3, 1e-3);
+}
+
+#[test]
+#[should_panic(expected = "arrays differ at index 1")]
+fn allclose_null_mismatch() {
+    let lhs = Float64Array::from(&[Some(1.0), None]);
+    let rhs = Float64Array::from(&[Some(1.0), Some(2.0)]);
+    assert_allclose(&lhs, &rhs, 0.0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "only supports Float32 and Float64")]
+fn allclose_not_float() {
+    let array = Int32Array::from_slice(&[1]);
+    assert_allclose(&array, &array, 0.0, 0.0);
+}
+
+#[cfg(feature = "testing")]
+mod columns {
+    use std::sync::Arc;
+
+    use arrow2::array::*;
+    use arrow2::columns::Columns;
+    use arrow2::datatypes::DataType;
+    use arrow2::error::Result;
+    use arrow2::testing::{assert_columns_eq, columns_from_csv_str};
+
+    fn expected_lines() -> Vec<&'static str> {
+        vec![
+            "+-------+------+---------+",
+            "| Int64 | Utf8 | Boolean |",
+            "+-------+------+---------+",
+            "| 1     | a    | true    |",
+            "|       |      |         |",
+            "| -3    | c d  | false   |",
+            "+-------+------+---------+",
+        ]
+    }
+
+    #[test]
+    fn from_csv_str() -> Result<()> {
+        let columns = columns_from_csv_str(
+            "1,a,true
+             ,,
+             -3, c d ,false",
+            &[DataType::Int64, DataType::Utf8, DataType::Boolean],
+        )?;
+        let expected = Columns::new(vec![
+            Arc::new(Int64Array::from(&[Some(1), None, Some(-3)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(&["a", "", "c d"])),
+            Arc::new(BooleanArray::from(&[Some(true), None, Some(false)])),
+        ]);
+        assert_eq!(columns, expected);
+        assert_columns_eq(&expected_lines(), &[columns]);
+        Ok(())
+    }
+
+    #[test]
+    fn from_csv_str_errors() {
+        // too few and too many values
+        assert!(columns_from_csv_str("1\n2,3", &[DataType::Int32, DataType::Int32]).is_err());
+        assert!(columns_from_csv_str("1,2,3", &[DataType::Int32, DataType::Int32]).is_err());
+        // invalid values
+        assert!(columns_from_csv_str("a", &[DataType::Int32]).is_err());
+        assert!(columns_from_csv_str("1,", &[DataType::Int32, DataType::Float64]).is_ok());
+        assert!(columns_from_csv_str("1,1.5.2", &[DataType::Int32, DataType::Float64]).is_err());
+    }
+
+    #[test]
+    fn from_csv_str_empty() -> Result<()> {
+        let columns = columns_from_csv_str("", &[DataType::Int32])?;
+        assert!(columns.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn assert_eq_multiple_batches() -> Result<()> {
+        let data_types = [DataType::Int64, DataType::Utf8, DataType::Boolean];
+        let first = columns_from_csv_str("1,a,true\n,,", &data_types)?;
+        let second = columns_from_csv_str("-3,c d,false", &data_types)?;
+        assert_columns_eq(&expected_lines(), &[first, second]);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "- | -3    | c d  | false   |\n+ | -3    | c d  | true    |")]
+    fn assert_eq_diff() {
+        let columns = columns_from_csv_str(
+            "1,a,true\n,,\n-3,c d,true",
+            &[DataType::Int64, DataType::Utf8, DataType::Boolean],
+        )
+        .unwrap();
+        assert_columns_eq(&expected_lines(), &[columns]);
+    }
+
+    #[test]
+    #[should_panic(expected = "+ | 2     |")]
+    fn assert_eq_extra_row() {
+        let columns = Columns::new(vec![
+            Arc::new(Int32Array::from_slice(&[1, 2])) as Arc<dyn Array>
+        ]);
+        assert_columns_eq(
+            &[
+                "+-------+",
+                "| Int32 |",
+                "+-------+",
+                "| 1     |",
+                "+-------+",
+            ],
+            &[columns],
+        );
+    }
+}
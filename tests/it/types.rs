@@ -0,0 +1,90 @@
+use std::convert::TryFrom;
+
+use arrow2::types::{days_ms, months_days_ns};
+
+#[test]
+fn days_ms_arithmetics() {
+    let a = days_ms::new(1, -1000);
+    let b = days_ms::new(-3, 500);
+    // components are independent: milliseconds do not carry over to days
+    assert_eq!(a + b, days_ms::new(-2, -500));
+    assert_eq!(a - b, days_ms::new(4, -1500));
+    assert_eq!(-a, days_ms::new(-1, 1000));
+    assert_eq!((a + b).to_string(), "-2d -500ms");
+}
+
+#[test]
+fn days_ms_overflow() {
+    let min = days_ms::new(i32::MIN, 0);
+    assert_eq!(min.checked_neg(), None);
+    assert_eq!(min.checked_sub(days_ms::new(1, 0)), None);
+    assert_eq!(min.checked_add(days_ms::new(-1, 0)), None);
+    assert_eq!(
+        min.checked_add(days_ms::new(1, -1)),
+        Some(days_ms::new(i32::MIN + 1, -1))
+    );
+    assert_eq!(
+        days_ms::new(0, i32::MAX).checked_add(days_ms::new(0, 1)),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to negate with overflow")]
+fn days_ms_neg_overflow() {
+    let _ = -days_ms::new(i32::MIN, 0);
+}
+
+#[test]
+fn months_days_ns_arithmetics() {
+    let a = months_days_ns::new(1, -2, 3);
+    let b = months_days_ns::new(-2, 2, -5);
+    assert_eq!(a + b, months_days_ns::new(-1, 0, -2));
+    assert_eq!(a - b, months_days_ns::new(3, -4, 8));
+    assert_eq!(-a, months_days_ns::new(-1, 2, -3));
+    assert_eq!(a.to_string(), "1m -2d 3ns");
+
+    assert_eq!(months_days_ns::new(0, 0, i64::MIN).checked_neg(), None);
+    assert_eq!(
+        months_days_ns::new(i32::MIN, 0, 0).checked_sub(months_days_ns::new(1, 0, 0)),
+        None
+    );
+}
+
+#[test]
+fn days_ms_duration() {
+    let interval = days_ms::new(-1, 3_600_000);
+    let duration = chrono::Duration::from(interval);
+    assert_eq!(duration, chrono::Duration::hours(-23));
+    // days are truncated towards zero
+    assert_eq!(
+        days_ms::try_from(duration).unwrap(),
+        days_ms::new(0, -82_800_000)
+    );
+
+    let interval = days_ms::new(i32::MIN, -1);
+    let duration = chrono::Duration::from(interval);
+    assert_eq!(days_ms::try_from(duration).unwrap(), interval);
+
+    // days out of range and sub-millisecond components are not representable
+    assert!(days_ms::try_from(duration - chrono::Duration::days(1)).is_err());
+    assert!(days_ms::try_from(chrono::Duration::microseconds(1)).is_err());
+}
+
+#[test]
+fn months_days_ns_duration() {
+    let interval = months_days_ns::new(0, 2, -1);
+    let duration = chrono::Duration::try_from(interval).unwrap();
+    assert_eq!(
+        duration,
+        chrono::Duration::days(2) - chrono::Duration::nanoseconds(1)
+    );
+    assert_eq!(
+        months_days_ns::try_from(duration).unwrap(),
+        months_days_ns::new(0, 1, 86_399_999_999_999)
+    );
+
+    // months have no fixed duration
+    assert!(chrono::Duration::try_from(months_days_ns::new(1, 0, 0)).is_err());
+    assert!(months_days_ns::try_from(chrono::Duration::days(i32::MIN as i64 - 1)).is_err());
+}